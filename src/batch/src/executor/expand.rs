@@ -0,0 +1,216 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{DataChunk, I64Array};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder};
+use crate::executor::{Executor, ExecutorBuilder};
+
+/// [`ExpandExecutor`] outputs every row of its child once for each of the `column_subsets`. The
+/// output columns are the columns of the child with those not in the subset set to NULL, followed
+/// by the original columns of the child and the index of the subset as `flag`.
+pub(super) struct ExpandExecutor {
+    child: BoxedExecutor,
+    column_subsets: Vec<Vec<usize>>,
+    /// The chunk being expanded, and the index of the next subset to output for it.
+    current: Option<(DataChunk, usize)>,
+    schema: Schema,
+    identity: String,
+}
+
+impl ExpandExecutor {
+    fn new(child: BoxedExecutor, column_subsets: Vec<Vec<usize>>, identity: String) -> Self {
+        let mut schema = child.schema().clone();
+        schema.fields.extend_from_slice(&child.schema().fields);
+        schema
+            .fields
+            .push(Field::with_name(DataType::Int64, "flag"));
+        Self {
+            child,
+            column_subsets,
+            current: None,
+            schema,
+            identity,
+        }
+    }
+
+    fn expand(chunk: &DataChunk, subset: &[usize], flag: usize) -> Result<DataChunk> {
+        let capacity = chunk.capacity();
+        let mut columns = Vec::with_capacity(chunk.columns().len() * 2 + 1);
+        for (i, column) in chunk.columns().iter().enumerate() {
+            if subset.contains(&i) {
+                columns.push(column.clone());
+            } else {
+                let mut builder = column.array_ref().create_builder(capacity)?;
+                for _ in 0..capacity {
+                    builder.append_null()?;
+                }
+                columns.push(Column::new(Arc::new(builder.finish()?)));
+            }
+        }
+        columns.extend_from_slice(chunk.columns());
+        let flags = I64Array::from_slice(&vec![Some(flag as i64); capacity])?;
+        columns.push(Column::new(Arc::new(flags.into())));
+        Ok(DataChunk::new(columns, chunk.visibility().clone()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for ExpandExecutor {
+    async fn open(&mut self) -> Result<()> {
+        self.child.open().await?;
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        let (chunk, index) = match self.current.take() {
+            Some(current) => current,
+            None => match self.child.next().await? {
+                Some(chunk) => (chunk, 0),
+                None => return Ok(None),
+            },
+        };
+
+        let ret = Self::expand(&chunk, &self.column_subsets[index], index)?;
+        if index + 1 < self.column_subsets.len() {
+            self.current = Some((chunk, index + 1));
+        }
+        Ok(Some(ret))
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.child.close().await?;
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+impl BoxedExecutorBuilder for ExpandExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let expand_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::Expand
+        )?;
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child = source.clone_for_plan(proto_child).build()?;
+
+        let column_subsets = expand_node
+            .get_column_subsets()
+            .iter()
+            .map(|subset| {
+                subset
+                    .get_column_indices()
+                    .iter()
+                    .map(|i| *i as usize)
+                    .collect()
+            })
+            .collect();
+
+        Ok(Box::new(
+            Self::new(
+                child,
+                column_subsets,
+                source.plan_node().get_identity().clone(),
+            )
+            .fuse(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, I32Array};
+    use risingwave_common::column_nonnull;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_expand_executor() -> Result<()> {
+        let chunk = DataChunk::builder()
+            .columns(vec![
+                column_nonnull! { I32Array, [1, 2] },
+                column_nonnull! { I32Array, [10, 20] },
+            ])
+            .build();
+
+        let schema = schema_unnamed! { DataType::Int32, DataType::Int32 };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(chunk);
+
+        let mut expand_executor = ExpandExecutor::new(
+            Box::new(mock_executor),
+            vec![vec![0, 1], vec![0], vec![]],
+            "ExpandExecutor".to_string(),
+        );
+        assert_eq!(expand_executor.schema().len(), 5);
+        expand_executor.open().await?;
+
+        for (flag, first, second) in [
+            (0, [Some(1), Some(2)], [Some(10), Some(20)]),
+            (1, [Some(1), Some(2)], [None, None]),
+            (2, [None, None], [None, None]),
+        ] {
+            let chunk = expand_executor.next().await?.unwrap();
+            assert_eq!(chunk.cardinality(), 2);
+            let column = |i: usize| {
+                chunk
+                    .column_at(i)
+                    .array_ref()
+                    .as_int32()
+                    .iter()
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(column(0), first.to_vec());
+            assert_eq!(column(1), second.to_vec());
+            assert_eq!(column(2), vec![Some(1), Some(2)]);
+            assert_eq!(column(3), vec![Some(10), Some(20)]);
+            assert_eq!(
+                chunk
+                    .column_at(4)
+                    .array_ref()
+                    .as_int64()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                vec![Some(flag); 2]
+            );
+        }
+        assert!(expand_executor.next().await?.is_none());
+        expand_executor.close().await?;
+
+        Ok(())
+    }
+}
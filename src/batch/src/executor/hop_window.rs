@@ -0,0 +1,230 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::column::Column;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{DataType, IntervalUnit};
+use risingwave_expr::vector_op::hop::{hop_window_bounds, hop_window_count};
+use risingwave_pb::plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder};
+use crate::executor::{Executor, ExecutorBuilder};
+
+/// [`HopWindowExecutor`] outputs every row of its child `window_size / window_slide` times, once
+/// for each hop window containing the row, with `window_start` and `window_end` appended.
+pub(super) struct HopWindowExecutor {
+    child: BoxedExecutor,
+    time_col_idx: usize,
+    window_slide: IntervalUnit,
+    window_size: IntervalUnit,
+    /// Number of windows every row belongs to.
+    units: usize,
+    /// The chunk being expanded, and the index of the next window to output for it.
+    current: Option<(DataChunk, usize)>,
+    schema: Schema,
+    identity: String,
+}
+
+impl HopWindowExecutor {
+    fn new(
+        child: BoxedExecutor,
+        time_col_idx: usize,
+        window_slide: IntervalUnit,
+        window_size: IntervalUnit,
+        identity: String,
+    ) -> Result<Self> {
+        let units = hop_window_count(window_slide, window_size)?;
+        let mut schema = child.schema().clone();
+        schema.fields.extend([
+            Field::with_name(DataType::Timestamp, "window_start"),
+            Field::with_name(DataType::Timestamp, "window_end"),
+        ]);
+        Ok(Self {
+            child,
+            time_col_idx,
+            window_slide,
+            window_size,
+            units,
+            current: None,
+            schema,
+            identity,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for HopWindowExecutor {
+    async fn open(&mut self) -> Result<()> {
+        self.child.open().await?;
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        let (chunk, index) = match self.current.take() {
+            Some(current) => current,
+            None => match self.child.next().await? {
+                Some(chunk) => (chunk, 0),
+                None => return Ok(None),
+            },
+        };
+
+        let (window_start, window_end) = hop_window_bounds(
+            chunk.column_at(self.time_col_idx).array_ref(),
+            self.window_slide,
+            self.window_size,
+            index,
+        )?;
+        let mut columns = chunk.columns().to_vec();
+        columns.push(Column::new(Arc::new(window_start)));
+        columns.push(Column::new(Arc::new(window_end)));
+        let ret = DataChunk::new(columns, chunk.visibility().clone());
+
+        if index + 1 < self.units {
+            self.current = Some((chunk, index + 1));
+        }
+        Ok(Some(ret))
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.child.close().await?;
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+impl BoxedExecutorBuilder for HopWindowExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let hop_window_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::HopWindow
+        )?;
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child = source.clone_for_plan(proto_child).build()?;
+
+        let time_col_idx = hop_window_node.get_time_col()?.column_idx as usize;
+        let window_slide = hop_window_node.get_window_slide()?.into();
+        let window_size = hop_window_node.get_window_size()?.into();
+
+        Ok(Box::new(
+            Self::new(
+                child,
+                time_col_idx,
+                window_slide,
+                window_size,
+                source.plan_node().get_identity().clone(),
+            )?
+            .fuse(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use risingwave_common::array::{Array, ArrayBuilder, I32Array, NaiveDateTimeArrayBuilder};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::NaiveDateTimeWrapper;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::*;
+
+    fn timestamp(h: u32, m: u32) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper(NaiveDateTime::new(
+            NaiveDate::from_ymd(2022, 2, 22),
+            NaiveTime::from_hms(h, m, 0),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_hop_window_executor() -> Result<()> {
+        let time_col = {
+            let mut builder = NaiveDateTimeArrayBuilder::new(2)?;
+            builder.append(Some(timestamp(10, 5)))?;
+            builder.append(Some(timestamp(10, 20)))?;
+            Column::new(Arc::new(builder.finish()?.into()))
+        };
+        let chunk = DataChunk::builder()
+            .columns(vec![column_nonnull! { I32Array, [1, 2] }, time_col])
+            .build();
+
+        let schema = schema_unnamed! { DataType::Int32, DataType::Timestamp };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(chunk);
+
+        let mut hop_window_executor = HopWindowExecutor::new(
+            Box::new(mock_executor),
+            1,
+            IntervalUnit::from_millis(15 * 60 * 1000),
+            IntervalUnit::from_millis(30 * 60 * 1000),
+            "HopWindowExecutor".to_string(),
+        )?;
+        assert_eq!(hop_window_executor.schema().len(), 4);
+        hop_window_executor.open().await?;
+
+        for (starts, ends) in [
+            (
+                [timestamp(10, 0), timestamp(10, 15)],
+                [timestamp(10, 30), timestamp(10, 45)],
+            ),
+            (
+                [timestamp(9, 45), timestamp(10, 0)],
+                [timestamp(10, 15), timestamp(10, 30)],
+            ),
+        ] {
+            let chunk = hop_window_executor.next().await?.unwrap();
+            assert_eq!(chunk.cardinality(), 2);
+            assert_eq!(
+                chunk
+                    .column_at(2)
+                    .array_ref()
+                    .as_naivedatetime()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                starts.map(Some).to_vec()
+            );
+            assert_eq!(
+                chunk
+                    .column_at(3)
+                    .array_ref()
+                    .as_naivedatetime()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                ends.map(Some).to_vec()
+            );
+        }
+        assert!(hop_window_executor.next().await?.is_none());
+        hop_window_executor.close().await?;
+
+        Ok(())
+    }
+}
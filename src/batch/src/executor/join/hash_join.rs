@@ -51,6 +51,8 @@ pub(super) struct EquiJoinParams {
     right_key_types: Vec<DataType>,
     /// Data types of right columns in equi join, e.g., the column types of `a1` `a2` `a3` in `a`.
     right_col_len: usize,
+    /// Whether the keys match `NULL` with `NULL`, i.e. `IS NOT DISTINCT FROM` instead of `=`.
+    null_safe: Vec<bool>,
     /// Column types of the concatenation of two input side, e.g. the column types of
     /// `a1`, `a2`, `a3`, `b1`, `b2`, `b3`.
     full_data_types: Vec<DataType>,
@@ -135,6 +137,11 @@ impl EquiJoinParams {
         &self.right_key_columns
     }
 
+    #[inline(always)]
+    pub(super) fn null_safe(&self) -> &[bool] {
+        &self.null_safe
+    }
+
     #[inline(always)]
     pub(super) fn batch_size(&self) -> usize {
         self.batch_size
@@ -418,7 +425,10 @@ impl BoxedExecutorBuilder for HashJoinExecutorBuilder {
                 .push(right_child.schema()[right_key].data_type());
         }
 
+        params.null_safe = hash_join_node.get_null_safe().clone();
+
         ensure!(params.left_key_columns.len() == params.right_key_columns.len());
+        ensure!(params.null_safe.len() == params.right_key_columns.len());
 
         let hash_key_kind = calc_hash_key_kind(&params.right_key_types);
 
@@ -705,6 +715,7 @@ mod tests {
                 right_key_columns: vec![0],
                 right_key_types: vec![self.right_types[0].clone()],
                 right_col_len,
+                null_safe: vec![false],
                 full_data_types,
                 batch_size: 2,
                 cond,
@@ -71,8 +71,9 @@ impl BuildTable {
         for (chunk_id, data_chunk) in self.build_data.iter().enumerate() {
             let keys = K::build(self.params.build_key_columns(), data_chunk)?;
             for (row_id_in_chunk, row_key) in keys.into_iter().enumerate() {
-                // In pg `null` and `null` never joins, so we should skip them in hash table.
-                if row_key.has_null() {
+                // In pg `null` and `null` never joins, so we should skip them in hash table, unless
+                // the key is null-safe (`IS NOT DISTINCT FROM`).
+                if row_key.has_null() && self.has_null_unsafe_key(data_chunk, row_id_in_chunk)? {
                     continue;
                 }
                 let current_row_id = RowId::new(chunk_id, row_id_in_chunk);
@@ -82,6 +83,27 @@ impl BuildTable {
 
         Ok((build_index, hash_map))
     }
+
+    /// Whether the row has `NULL` in any key which is not null-safe.
+    fn has_null_unsafe_key(&self, data_chunk: &DataChunk, row_id: usize) -> Result<bool> {
+        for (&key_column, &null_safe) in self
+            .params
+            .build_key_columns()
+            .iter()
+            .zip_eq(self.params.null_safe())
+        {
+            if !null_safe
+                && !data_chunk
+                    .column_at(key_column)
+                    .array_ref()
+                    .null_bitmap()
+                    .is_set(row_id)?
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
 }
 
 /// The numbers about key matched need to
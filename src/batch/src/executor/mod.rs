@@ -14,12 +14,16 @@
 
 use drop_stream::*;
 use drop_table::*;
+use expand::*;
 use filter::*;
 use generic_exchange::*;
 use hash_agg::*;
+use hop_window::*;
 use limit::*;
 use merge_sort_exchange::*;
 use order_by::*;
+use over_agg::*;
+use project_set::*;
 use projection::*;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
@@ -29,7 +33,9 @@ use risingwave_pb::plan::plan_node::NodeBody;
 use risingwave_pb::plan::PlanNode;
 pub use row_seq_scan::*;
 use sort_agg::*;
+use table_function::*;
 use top_n::*;
+use union::*;
 
 use self::fuse::FusedExecutor;
 use crate::executor::create_source::CreateSourceExecutor;
@@ -42,6 +48,7 @@ use crate::executor::join::sort_merge_join::SortMergeJoinExecutor;
 use crate::executor::join::HashJoinExecutorBuilder;
 pub use crate::executor::stream_scan::StreamScanExecutor;
 use crate::executor::trace::TraceExecutor;
+pub use crate::executor::update::UpdateExecutor;
 use crate::executor::values::ValuesExecutor;
 use crate::task::{BatchEnvironment, TaskId};
 
@@ -50,25 +57,32 @@ mod create_table;
 mod delete;
 mod drop_stream;
 mod drop_table;
+mod expand;
 mod filter;
 mod fuse;
 mod generate_series;
 mod generic_exchange;
 mod hash_agg;
+mod hop_window;
 mod insert;
 mod join;
 mod limit;
 mod merge_sort_exchange;
 pub mod monitor;
 mod order_by;
+mod over_agg;
+mod project_set;
 mod projection;
 mod row_seq_scan;
 mod sort_agg;
 mod stream_scan;
+mod table_function;
 #[cfg(test)]
 mod test_utils;
 mod top_n;
 mod trace;
+mod union;
+mod update;
 mod values;
 
 /// `Executor` is an operator in the query execution.
@@ -164,6 +178,7 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::RowSeqScan => RowSeqScanExecutorBuilder,
             NodeBody::Insert => InsertExecutor,
             NodeBody::Delete => DeleteExecutor,
+            NodeBody::Update => UpdateExecutor,
             NodeBody::DropTable => DropTableExecutor,
             NodeBody::Exchange => ExchangeExecutor,
             NodeBody::Filter => FilterExecutor,
@@ -181,7 +196,13 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::DropSource => DropStreamExecutor,
             NodeBody::HashAgg => HashAggExecutorBuilder,
             NodeBody::MergeSortExchange => MergeSortExchangeExecutor,
-            NodeBody::GenerateInt32Series => GenerateSeriesI32Executor
+            NodeBody::GenerateInt32Series => GenerateSeriesI32Executor,
+            NodeBody::Union => UnionExecutor,
+            NodeBody::HopWindow => HopWindowExecutor,
+            NodeBody::OverAgg => OverAggExecutor,
+            NodeBody::Expand => ExpandExecutor,
+            NodeBody::TableFunction => TableFunctionExecutor,
+            NodeBody::ProjectSet => ProjectSetExecutor
         }?;
         let input_desc = real_executor.identity().to_string();
         Ok(Box::new(TraceExecutor::new(real_executor, input_desc)))
@@ -0,0 +1,280 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem::take;
+
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_expr::vector_op::window_function::{evaluate_partition, WindowFunction};
+use risingwave_pb::plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder};
+use crate::executor::{Executor, ExecutorBuilder};
+
+/// [`OverAggExecutor`] evaluates window functions on each partition of its child, which must be
+/// sorted by the partition keys and then the order keys, e.g. by an `OrderByExecutor`. The rows of
+/// the child are output with one column appended for each window function.
+pub(super) struct OverAggExecutor {
+    child: BoxedExecutor,
+    partition_by: Vec<usize>,
+    order_by: Vec<usize>,
+    window_functions: Vec<WindowFunction>,
+    /// Rows of the current partition, which may continue in the next chunk.
+    partition: Vec<Row>,
+    child_done: bool,
+    schema: Schema,
+    identity: String,
+}
+
+impl OverAggExecutor {
+    fn new(
+        child: BoxedExecutor,
+        partition_by: Vec<usize>,
+        order_by: Vec<usize>,
+        window_functions: Vec<WindowFunction>,
+        identity: String,
+    ) -> Self {
+        let mut schema = child.schema().clone();
+        schema.fields.extend(
+            window_functions
+                .iter()
+                .map(|w| Field::unnamed(w.return_type())),
+        );
+        Self {
+            child,
+            partition_by,
+            order_by,
+            window_functions,
+            partition: vec![],
+            child_done: false,
+            schema,
+            identity,
+        }
+    }
+
+    fn is_same_partition(&self, row: &Row) -> bool {
+        let first = &self.partition[0];
+        self.partition_by.iter().all(|i| first.0[*i] == row.0[*i])
+    }
+
+    /// Evaluates the window functions on the current partition, and appends the output rows to
+    /// `outputs`.
+    fn flush_partition(&mut self, outputs: &mut Vec<Row>) -> Result<()> {
+        let partition = take(&mut self.partition);
+        let results = evaluate_partition(
+            &self.window_functions,
+            &partition,
+            &self.child.schema().data_types(),
+            &self.order_by,
+        )?;
+        outputs.extend(
+            partition
+                .into_iter()
+                .zip_eq(results)
+                .map(|(Row(mut row), Row(result))| {
+                    row.extend(result);
+                    Row(row)
+                }),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for OverAggExecutor {
+    async fn open(&mut self) -> Result<()> {
+        self.child.open().await?;
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        let mut outputs = vec![];
+        while outputs.is_empty() {
+            if self.child_done {
+                if self.partition.is_empty() {
+                    return Ok(None);
+                }
+                self.flush_partition(&mut outputs)?;
+                break;
+            }
+            match self.child.next().await? {
+                Some(chunk) => {
+                    for row in chunk.rows() {
+                        let row = Row::from(row);
+                        if !self.partition.is_empty() && !self.is_same_partition(&row) {
+                            self.flush_partition(&mut outputs)?;
+                        }
+                        self.partition.push(row);
+                    }
+                }
+                None => self.child_done = true,
+            }
+        }
+        Ok(Some(DataChunk::from_rows(
+            &outputs,
+            &self.schema.data_types(),
+        )?))
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.child.close().await?;
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+impl BoxedExecutorBuilder for OverAggExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let over_agg_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::OverAgg
+        )?;
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child = source.clone_for_plan(proto_child).build()?;
+
+        let partition_by = over_agg_node
+            .get_partition_by()
+            .iter()
+            .map(|i| *i as usize)
+            .collect();
+        let order_by = over_agg_node
+            .get_order_by()
+            .iter()
+            .map(|o| Ok(o.get_input_ref()?.column_idx as usize))
+            .collect::<Result<_>>()?;
+        let window_functions = over_agg_node
+            .get_window_functions()
+            .iter()
+            .map(WindowFunction::from_prost)
+            .collect::<Result<_>>()?;
+
+        Ok(Box::new(
+            Self::new(
+                child,
+                partition_by,
+                order_by,
+                window_functions,
+                source.plan_node().get_identity().clone(),
+            )
+            .fuse(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, I64Array};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::DataType;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::expr::window_frame::{Bound, BoundType, Type as FrameType};
+    use risingwave_pb::expr::window_function::Type;
+    use risingwave_pb::expr::{InputRefExpr, WindowFrame, WindowFunction as ProstWindowFunction};
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_over_agg_executor() -> Result<()> {
+        // (partition key, order key), sorted by both.
+        let schema = schema_unnamed! { DataType::Int64, DataType::Int64 };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(
+            DataChunk::builder()
+                .columns(vec![
+                    column_nonnull! { I64Array, [1, 1, 2] },
+                    column_nonnull! { I64Array, [10, 20, 30] },
+                ])
+                .build(),
+        );
+        mock_executor.add(
+            DataChunk::builder()
+                .columns(vec![
+                    column_nonnull! { I64Array, [2, 3] },
+                    column_nonnull! { I64Array, [40, 50] },
+                ])
+                .build(),
+        );
+
+        let frame = WindowFrame {
+            r#type: FrameType::Range as i32,
+            start: Some(Bound {
+                r#type: BoundType::UnboundedPreceding as i32,
+                offset: 0,
+            }),
+            end: Some(Bound {
+                r#type: BoundType::CurrentRow as i32,
+                offset: 0,
+            }),
+        };
+        let window_function = |r#type: Type| {
+            WindowFunction::from_prost(&ProstWindowFunction {
+                r#type: r#type as i32,
+                arg: Some(InputRefExpr { column_idx: 1 }),
+                offset: 1,
+                agg_call: None,
+                frame: Some(frame.clone()),
+                return_type: Some(risingwave_pb::data::DataType {
+                    type_name: TypeName::Int64 as i32,
+                    ..Default::default()
+                }),
+            })
+        };
+
+        let mut over_agg_executor = OverAggExecutor::new(
+            Box::new(mock_executor),
+            vec![0],
+            vec![1],
+            vec![
+                window_function(Type::RowNumber)?,
+                window_function(Type::Lag)?,
+            ],
+            "OverAggExecutor".to_string(),
+        );
+        assert_eq!(over_agg_executor.schema().len(), 4);
+        over_agg_executor.open().await?;
+
+        let mut row_numbers = vec![];
+        let mut lags = vec![];
+        while let Some(chunk) = over_agg_executor.next().await? {
+            row_numbers.extend(chunk.column_at(2).array_ref().as_int64().iter());
+            lags.extend(chunk.column_at(3).array_ref().as_int64().iter());
+        }
+        assert_eq!(
+            row_numbers,
+            vec![Some(1), Some(2), Some(1), Some(2), Some(1)]
+        );
+        assert_eq!(lags, vec![None, Some(10), None, Some(30), None]);
+        over_agg_executor.close().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::{eval_project_set, ProjectSetSelectItem};
+use risingwave_pb::plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder};
+use crate::executor::{Executor, ExecutorBuilder};
+
+/// [`ProjectSetExecutor`] evaluates a select list with set-returning functions on each row of its
+/// child. The row is output once for each value returned by the functions, with the index of the
+/// output row among those of the same input row as the first column `projected_row_id`.
+pub(super) struct ProjectSetExecutor {
+    child: BoxedExecutor,
+    select_list: Vec<ProjectSetSelectItem>,
+    schema: Schema,
+    identity: String,
+}
+
+impl ProjectSetExecutor {
+    fn new(child: BoxedExecutor, select_list: Vec<ProjectSetSelectItem>, identity: String) -> Self {
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+        Self {
+            child,
+            select_list,
+            schema: Schema::new(fields),
+            identity,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for ProjectSetExecutor {
+    async fn open(&mut self) -> Result<()> {
+        self.child.open().await?;
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        // Skip the chunks for which the functions return no values.
+        while let Some(chunk) = self.child.next().await? {
+            let chunk = chunk.compact()?;
+            let (output, _) = eval_project_set(&self.select_list, &chunk)?;
+            if output.cardinality() > 0 {
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.child.close().await?;
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+impl BoxedExecutorBuilder for ProjectSetExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let project_set_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::ProjectSet
+        )?;
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child = source.clone_for_plan(proto_child).build()?;
+
+        let select_list = project_set_node
+            .get_select_list()
+            .iter()
+            .map(ProjectSetSelectItem::from_prost)
+            .try_collect()?;
+
+        Ok(Box::new(Self::new(
+            child,
+            select_list,
+            source.plan_node().get_identity().clone(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{I32Array, ListValue};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_expr::table_function::TableFunction;
+    use risingwave_pb::plan::table_function_node::Type as TableFunctionType;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_project_set_executor() -> Result<()> {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let mut builder = list_type.create_array_builder(2)?;
+        builder.append_datum(&Some(
+            ListValue::new(vec![Some(1.into()), Some(2.into())]).into(),
+        ))?;
+        builder.append_datum(&Some(ListValue::new(vec![]).into()))?;
+        let chunk = DataChunk::builder()
+            .columns(vec![
+                column_nonnull! { I32Array, [10, 20] },
+                Column::new(Arc::new(builder.finish()?)),
+            ])
+            .build();
+
+        let schema = schema_unnamed! { DataType::Int32, list_type.clone() };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(chunk);
+
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(Box::new(InputRefExpression::new(DataType::Int32, 0))),
+            ProjectSetSelectItem::TableFunction(TableFunction::new(
+                TableFunctionType::Unnest,
+                vec![Box::new(InputRefExpression::new(list_type, 1))],
+                DataType::Int32,
+            )),
+        ];
+        let mut executor = ProjectSetExecutor::new(
+            Box::new(mock_executor),
+            select_list,
+            "ProjectSetExecutor".to_string(),
+        );
+        assert_eq!(executor.schema().len(), 3);
+        executor.open().await?;
+
+        // The second row is not output, as its array is empty.
+        let chunk = executor.next().await?.unwrap();
+        assert_eq!(chunk.cardinality(), 2);
+        let column = |i: usize| {
+            (0..chunk.cardinality())
+                .map(|row| chunk.column_at(i).array_ref().datum_at(row))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            column(0),
+            vec![Some(ScalarImpl::Int64(0)), Some(ScalarImpl::Int64(1))]
+        );
+        assert_eq!(column(1), vec![Some(10.into()), Some(10.into())]);
+        assert_eq!(column(2), vec![Some(1.into()), Some(2.into())]);
+        assert!(executor.next().await?.is_none());
+        executor.close().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::vec;
+
+use risingwave_common::array::column::Column;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::Datum;
+use risingwave_common::util::chunk_coalesce::DEFAULT_CHUNK_BUFFER_SIZE;
+use risingwave_expr::table_function::TableFunction;
+use risingwave_pb::plan::plan_node::NodeBody;
+
+use crate::executor::{BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder};
+
+/// `TableFunctionExecutor` evaluates a set-returning function on constant arguments, and outputs
+/// the returned values as a single column.
+pub(super) struct TableFunctionExecutor {
+    function: TableFunction,
+    /// The values to output, which are computed when the executor is polled for the first time.
+    values: Option<vec::IntoIter<Datum>>,
+    schema: Schema,
+    identity: String,
+    chunk_size: usize,
+}
+
+impl TableFunctionExecutor {
+    fn new(function: TableFunction, identity: String, chunk_size: usize) -> Self {
+        let schema = Schema::new(vec![Field::unnamed(function.return_type())]);
+        Self {
+            function,
+            values: None,
+            schema,
+            identity,
+            chunk_size,
+        }
+    }
+
+    /// Evaluates the arguments and the function.
+    fn eval(&self) -> Result<Vec<Datum>> {
+        // The arguments are constant, so any one row chunk does as the input.
+        let one_row_chunk = DataChunk::new_dummy(1);
+        Ok(self.function.eval(&one_row_chunk)?.pop().unwrap())
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for TableFunctionExecutor {
+    async fn open(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        if self.values.is_none() {
+            self.values = Some(self.eval()?.into_iter());
+        }
+        let values = self.values.as_mut().unwrap();
+        if values.is_empty() {
+            return Ok(None);
+        }
+
+        let chunk_size = self.chunk_size.min(values.len());
+        let mut builder = self.schema.fields()[0]
+            .data_type()
+            .create_array_builder(chunk_size)?;
+        for datum in values.take(chunk_size) {
+            builder.append_datum(&datum)?;
+        }
+        let columns = vec![Column::new(Arc::new(builder.finish()?))];
+        Ok(Some(DataChunk::builder().columns(columns).build()))
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+impl BoxedExecutorBuilder for TableFunctionExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::TableFunction
+        )?;
+
+        Ok(Box::new(Self::new(
+            TableFunction::from_prost(node)?,
+            source.plan_node().get_identity().clone(),
+            DEFAULT_CHUNK_BUFFER_SIZE,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_expr::expr::LiteralExpression;
+    use risingwave_pb::plan::table_function_node::Type as TableFunctionType;
+
+    use super::*;
+
+    fn jsonb_array_elements(arg: &str, chunk_size: usize) -> TableFunctionExecutor {
+        let arg = LiteralExpression::new(
+            DataType::Jsonb,
+            Some(ScalarImpl::Jsonb(arg.parse().unwrap())),
+        );
+        let function = TableFunction::new(
+            TableFunctionType::JsonbArrayElements,
+            vec![Box::new(arg)],
+            DataType::Jsonb,
+        );
+        TableFunctionExecutor::new(function, "TableFunctionExecutor".to_string(), chunk_size)
+    }
+
+    #[tokio::test]
+    async fn test_jsonb_array_elements() {
+        let mut executor = jsonb_array_elements(r#"[1, "a", {"b": null}]"#, 2);
+
+        let chunk = executor.next().await.unwrap().unwrap();
+        assert_eq!(chunk.cardinality(), 2);
+        assert_eq!(
+            chunk.column_at(0).array_ref().datum_at(1),
+            Some(ScalarImpl::Jsonb(r#""a""#.parse().unwrap()))
+        );
+        let chunk = executor.next().await.unwrap().unwrap();
+        assert_eq!(chunk.cardinality(), 1);
+        assert_eq!(
+            chunk.column_at(0).array_ref().datum_at(0),
+            Some(ScalarImpl::Jsonb(r#"{"b": null}"#.parse().unwrap()))
+        );
+        assert!(executor.next().await.unwrap().is_none());
+
+        let mut executor = jsonb_array_elements(r#"{"a": 1}"#, 2);
+        assert!(executor.next().await.is_err());
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::Result;
+use risingwave_pb::plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder};
+
+/// [`UnionExecutor`] outputs all the chunks of its children, one child after another. Duplicated
+/// rows are not removed.
+pub(super) struct UnionExecutor {
+    children: Vec<BoxedExecutor>,
+    /// Index of the child currently being read.
+    current: usize,
+    identity: String,
+}
+
+impl UnionExecutor {
+    pub fn new(children: Vec<BoxedExecutor>, identity: String) -> Self {
+        assert!(!children.is_empty());
+        assert!(children
+            .iter()
+            .all(|child| child.schema().data_types() == children[0].schema().data_types()));
+        Self {
+            children,
+            current: 0,
+            identity,
+        }
+    }
+}
+
+impl BoxedExecutorBuilder for UnionExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        ensure!(!source.plan_node().get_children().is_empty());
+        try_match_expand!(source.plan_node().get_node_body().unwrap(), NodeBody::Union)?;
+
+        let children = source
+            .plan_node()
+            .get_children()
+            .iter()
+            .map(|child_plan| source.clone_for_plan(child_plan).build())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Box::new(
+            Self::new(children, source.plan_node().get_identity().clone()).fuse(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for UnionExecutor {
+    async fn open(&mut self) -> Result<()> {
+        for child in &mut self.children {
+            child.open().await?;
+        }
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        while self.current < self.children.len() {
+            if let Some(chunk) = self.children[self.current].next().await? {
+                return Ok(Some(chunk));
+            }
+            self.current += 1;
+        }
+        Ok(None)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        for child in &mut self.children {
+            child.close().await?;
+        }
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        self.children[0].schema()
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, DataChunk, I32Array};
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    fn mock_child(chunks: Vec<DataChunk>) -> BoxedExecutor {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int32)],
+        };
+        let mut executor = MockExecutor::new(schema);
+        chunks.into_iter().for_each(|chunk| executor.add(chunk));
+        Box::new(executor)
+    }
+
+    #[tokio::test]
+    async fn test_union_executor() {
+        let chunk1 = DataChunk::builder()
+            .columns(vec![column_nonnull! { I32Array, [1, 2] }])
+            .build();
+        let chunk2 = DataChunk::builder()
+            .columns(vec![column_nonnull! { I32Array, [3] }])
+            .build();
+        let chunk3 = DataChunk::builder()
+            .columns(vec![column_nonnull! { I32Array, [2, 4] }])
+            .build();
+        let children = vec![
+            mock_child(vec![chunk1, chunk2]),
+            mock_child(vec![]),
+            mock_child(vec![chunk3]),
+        ];
+        let mut union_executor = UnionExecutor::new(children, "UnionExecutor".to_string());
+        union_executor.open().await.unwrap();
+
+        let mut result = vec![];
+        while let Some(chunk) = union_executor.next().await.unwrap() {
+            result.extend(chunk.column_at(0).array().as_int32().iter());
+        }
+        assert_eq!(result, vec![Some(1), Some(2), Some(3), Some(2), Some(4)]);
+
+        union_executor.close().await.unwrap();
+    }
+}
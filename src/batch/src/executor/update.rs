@@ -0,0 +1,341 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::future::try_join_all;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayBuilder, DataChunk, Op, PrimitiveArrayBuilder, StreamChunk};
+use risingwave_common::catalog::{Field, Schema, TableId};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::{build_from_prost, BoxedExpression};
+use risingwave_pb::plan::plan_node::NodeBody;
+use risingwave_source::SourceManagerRef;
+
+use super::BoxedExecutor;
+use crate::executor::{BoxedExecutorBuilder, Executor, ExecutorBuilder};
+
+/// [`UpdateExecutor`] implements table update with values from its child executor and given
+/// expressions.
+///
+/// Each row from the child is written to the table source as a pair of `UpdateDelete` (the old
+/// row) and `UpdateInsert` (the row evaluated by `exprs`), so that downstream materialized views
+/// can retract the old value correctly.
+// TODO: concurrent `UPDATE` may cause problems. A scheduler might be required.
+pub struct UpdateExecutor {
+    /// Target table id.
+    table_id: TableId,
+    source_manager: SourceManagerRef,
+
+    child: BoxedExecutor,
+    /// Expressions computing the new value of every column, evaluated on the child output.
+    exprs: Vec<BoxedExpression>,
+    executed: bool,
+    schema: Schema,
+    identity: String,
+}
+
+impl UpdateExecutor {
+    pub fn new(
+        table_id: TableId,
+        source_manager: SourceManagerRef,
+        child: BoxedExecutor,
+        exprs: Vec<BoxedExpression>,
+    ) -> Self {
+        assert_eq!(
+            child.schema().data_types(),
+            exprs.iter().map(|e| e.return_type()).collect_vec(),
+            "bad update schema"
+        );
+        Self {
+            table_id,
+            source_manager,
+            child,
+            exprs,
+            executed: false,
+            // TODO: support `RETURNING`
+            schema: Schema {
+                fields: vec![Field::unnamed(DataType::Int64)],
+            },
+            identity: "UpdateExecutor".to_string(),
+        }
+    }
+
+    /// Interleaves the rows of `old` and `new` into a [`StreamChunk`] with the ops
+    /// `[UpdateDelete, UpdateInsert, UpdateDelete, UpdateInsert, ...]`.
+    fn build_update_chunk(old: DataChunk, new: DataChunk) -> Result<StreamChunk> {
+        let len = old.cardinality();
+        assert_eq!(len, new.cardinality());
+
+        let columns = old
+            .columns()
+            .iter()
+            .zip_eq(new.columns())
+            .map(|(old_col, new_col)| {
+                let old_array = old_col.array_ref();
+                let new_array = new_col.array_ref();
+                let mut builder = old_array.create_builder(len * 2)?;
+                for row_idx in 0..len {
+                    builder.append_array_element(old_array, row_idx)?;
+                    builder.append_array_element(new_array, row_idx)?;
+                }
+                Ok(Column::new(Arc::new(builder.finish()?)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let ops = [Op::UpdateDelete, Op::UpdateInsert]
+            .into_iter()
+            .cycle()
+            .take(len * 2)
+            .collect();
+
+        Ok(StreamChunk::new(ops, columns, None))
+    }
+}
+
+#[async_trait::async_trait]
+impl Executor for UpdateExecutor {
+    async fn open(&mut self) -> Result<()> {
+        self.child.open().await?;
+        info!("Update executor");
+        Ok(())
+    }
+
+    async fn next(&mut self) -> Result<Option<DataChunk>> {
+        if self.executed {
+            return Ok(None);
+        }
+
+        let source_desc = self.source_manager.get_source(&self.table_id)?;
+        let source = source_desc.source.as_table_v2().expect("not table source");
+
+        let mut notifiers = Vec::new();
+
+        while let Some(child_chunk) = self.child.next().await? {
+            assert!(child_chunk.visibility().is_none());
+
+            let updated_chunk = {
+                let columns = self
+                    .exprs
+                    .iter_mut()
+                    .map(|expr| expr.eval(&child_chunk).map(Column::new))
+                    .collect::<Result<Vec<_>>>()?;
+                DataChunk::builder().columns(columns).build()
+            };
+
+            let chunk = Self::build_update_chunk(child_chunk, updated_chunk)?;
+
+            let notifier = source.write_chunk(chunk)?;
+            notifiers.push(notifier);
+        }
+
+        // Wait for all chunks to be taken / written. Every updated row is counted twice.
+        let rows_updated = try_join_all(notifiers)
+            .await
+            .map_err(|_| {
+                RwError::from(ErrorCode::InternalError(
+                    "failed to wait chunks to be written".to_owned(),
+                ))
+            })?
+            .into_iter()
+            .sum::<usize>()
+            / 2;
+
+        // create ret value
+        {
+            let mut array_builder = PrimitiveArrayBuilder::<i64>::new(1)?;
+            array_builder.append(Some(rows_updated as i64))?;
+
+            let array = array_builder.finish()?;
+            let ret_chunk = DataChunk::builder()
+                .columns(vec![Column::new(Arc::new(array.into()))])
+                .build();
+
+            self.executed = true;
+            Ok(Some(ret_chunk))
+        }
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.child.close().await?;
+        info!("Cleaning update executor.");
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+}
+
+impl BoxedExecutorBuilder for UpdateExecutor {
+    fn new_boxed_executor(source: &ExecutorBuilder) -> Result<BoxedExecutor> {
+        let update_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::Update
+        )?;
+
+        let table_id = TableId::from(&update_node.table_source_ref_id);
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child = source.clone_for_plan(proto_child).build()?;
+
+        let exprs = update_node
+            .get_exprs()
+            .iter()
+            .map(build_from_prost)
+            .collect::<Result<Vec<BoxedExpression>>>()?;
+
+        Ok(Box::new(
+            Self::new(
+                table_id,
+                source.global_batch_env().source_manager_ref(),
+                child,
+                exprs,
+            )
+            .fuse(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::{Array, I64Array};
+    use risingwave_common::catalog::{schema_test_utils, ColumnDesc, ColumnId};
+    use risingwave_common::column_nonnull;
+    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_source::{
+        MemSourceManager, Source, SourceManager, StreamSourceReader, TableV2ReaderContext,
+    };
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_update_executor() -> Result<()> {
+        let source_manager = Arc::new(MemSourceManager::new());
+
+        // Schema for mock executor.
+        let schema = schema_test_utils::ii();
+        let mut mock_executor = MockExecutor::new(schema.clone());
+
+        // Schema of the table
+        let schema = schema_test_utils::ii();
+
+        let table_columns: Vec<_> = schema
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| ColumnDesc {
+                data_type: f.data_type.clone(),
+                column_id: ColumnId::from(i as i32), // use column index as column id
+                name: f.name.clone(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            })
+            .collect();
+
+        let col1 = column_nonnull! { I64Array, [1, 3, 5] };
+        let col2 = column_nonnull! { I64Array, [2, 4, 6] };
+        let data_chunk: DataChunk = DataChunk::builder().columns(vec![col1, col2]).build();
+        mock_executor.add(data_chunk.clone());
+
+        // Update expressions, will set v2 = v1.
+        let exprs = vec![
+            Box::new(InputRefExpression::new(DataType::Int64, 0)) as BoxedExpression,
+            Box::new(InputRefExpression::new(DataType::Int64, 0)) as BoxedExpression,
+        ];
+
+        // Create the table.
+        let table_id = TableId::new(0);
+        source_manager.create_table_source_v2(&table_id, table_columns.to_vec())?;
+
+        // Create reader
+        let source_desc = source_manager.get_source(&table_id)?;
+        let source = source_desc.source.as_table_v2().unwrap();
+        let mut reader = source.stream_reader(TableV2ReaderContext, vec![0.into(), 1.into()])?;
+
+        // Update
+        let mut update_executor = UpdateExecutor::new(
+            table_id,
+            source_manager.clone(),
+            Box::new(mock_executor),
+            exprs,
+        );
+        let handle = tokio::spawn(async move {
+            update_executor.open().await.unwrap();
+            let result = update_executor.next().await.unwrap().unwrap();
+            update_executor.close().await.unwrap();
+            assert_eq!(
+                result
+                    .column_at(0)
+                    .array()
+                    .as_int64()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                vec![Some(3)] // updated rows
+            );
+        });
+
+        // Read
+        reader.open().await?;
+        let chunk = reader.next().await?;
+
+        assert_eq!(
+            chunk.ops().to_vec(),
+            vec![
+                Op::UpdateDelete,
+                Op::UpdateInsert,
+                Op::UpdateDelete,
+                Op::UpdateInsert,
+                Op::UpdateDelete,
+                Op::UpdateInsert,
+            ]
+        );
+
+        assert_eq!(
+            chunk.columns()[0]
+                .array()
+                .as_int64()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(1), Some(3), Some(3), Some(5), Some(5)]
+        );
+
+        assert_eq!(
+            chunk.columns()[1]
+                .array()
+                .as_int64()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(1), Some(4), Some(3), Some(6), Some(5)]
+        );
+
+        handle.await.unwrap();
+
+        Ok(())
+    }
+}
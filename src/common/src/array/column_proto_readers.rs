@@ -17,18 +17,20 @@ use std::io::{self, Cursor, Read};
 use byteorder::{BigEndian, ReadBytesExt};
 use paste::paste;
 use risingwave_pb::data::Array as ProstArray;
+use uuid::Uuid;
 
-use crate::array::value_reader::{PrimitiveValueReader, VarSizedValueReader};
+use crate::array::value_reader::{PrimitiveValueReader, Utf8ValueReader, VarSizedValueReader};
 use crate::array::{
-    ArrayBuilder, ArrayImpl, ArrayMeta, BoolArrayBuilder, IntervalArrayBuilder,
-    NaiveDateArrayBuilder, NaiveDateTimeArrayBuilder, NaiveTimeArrayBuilder, PrimitiveArrayBuilder,
-    PrimitiveArrayItemType,
+    Array, ArrayBuilder, ArrayImpl, ArrayMeta, BoolArrayBuilder, IntervalArrayBuilder,
+    JsonbArrayBuilder, JsonbVal, NaiveDateArrayBuilder, NaiveDateTimeArrayBuilder,
+    NaiveTimeArrayBuilder, PrimitiveArrayBuilder, PrimitiveArrayItemType, Utf8ArrayBuilder,
+    UuidArrayBuilder,
 };
 use crate::buffer::Bitmap;
 use crate::error::ErrorCode::InternalError;
 use crate::error::{Result, RwError};
 use crate::types::interval::IntervalUnit;
-use crate::types::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper};
+use crate::types::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, Scalar};
 
 // TODO: Use techniques like apache arrow flight RPC to eliminate deserialization.
 // https://arrow.apache.org/docs/format/Flight.html
@@ -117,6 +119,17 @@ pub fn read_interval_unit(cursor: &mut Cursor<&[u8]>) -> Result<IntervalUnit> {
     })
 }
 
+fn read_uuid(cursor: &mut Cursor<&[u8]>) -> Result<Uuid> {
+    let mut bytes = [0u8; 16];
+    match cursor.read_exact(&mut bytes) {
+        Ok(()) => Ok(Uuid::from_bytes(bytes)),
+        Err(e) => Err(RwError::from(InternalError(format!(
+            "Failed to read 16 bytes from Uuid buffer: {}",
+            e
+        )))),
+    }
+}
+
 macro_rules! read_one_value_array {
     ($({ $type:ident, $builder:ty }),*) => {
         paste! {
@@ -152,7 +165,8 @@ read_one_value_array! {
     { bool, BoolArrayBuilder },
     { NaiveDate, NaiveDateArrayBuilder },
     { NaiveTime, NaiveTimeArrayBuilder },
-    { NaiveDateTime, NaiveDateTimeArrayBuilder }
+    { NaiveDateTime, NaiveDateTimeArrayBuilder },
+    { Uuid, UuidArrayBuilder }
 }
 
 fn read_offset(offset_cursor: &mut Cursor<&[u8]>) -> Result<i64> {
@@ -204,3 +218,20 @@ pub fn read_string_array<B: ArrayBuilder, R: VarSizedValueReader<B>>(
     let arr = builder.finish()?;
     Ok(arr.into())
 }
+
+/// Jsonb arrays are encoded as their textual representations, see `JsonbArray::to_protobuf`.
+pub fn read_jsonb_array(array: &ProstArray, cardinality: usize) -> Result<ArrayImpl> {
+    let text_array = read_string_array::<Utf8ArrayBuilder, Utf8ValueReader>(array, cardinality)?;
+    let mut builder = JsonbArrayBuilder::new(cardinality)?;
+    for text in text_array.as_utf8().iter() {
+        match text {
+            Some(text) => {
+                let value: JsonbVal = text.parse()?;
+                builder.append(Some(value.as_scalar_ref()))?;
+            }
+            None => builder.append(None)?,
+        }
+    }
+    let arr = builder.finish()?;
+    Ok(arr.into())
+}
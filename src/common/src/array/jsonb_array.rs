@@ -0,0 +1,344 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::{Hash, Hasher};
+use std::mem::size_of;
+use std::str::FromStr;
+use std::{fmt, io};
+
+use itertools::Itertools;
+use risingwave_pb::data::buffer::CompressionType;
+use risingwave_pb::data::{Array as ProstArray, ArrayType, Buffer};
+use serde::Serialize;
+use serde_json::ser::Formatter;
+use serde_json::Value;
+
+use super::{Array, ArrayBuilder, ArrayBuilderImpl, ArrayIterator, ArrayMeta, NULL_VAL_FOR_HASH};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::{ErrorCode, Result, RwError};
+
+/// An owned jsonb value.
+///
+/// Object keys are kept sorted, so two values are equal iff their textual representations are.
+/// Values are ordered by their textual representation as well, which differs from the btree
+/// ordering of Postgres but keeps `Ord`, `Hash` and memcomparable encoding consistent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonbVal(Box<Value>);
+
+/// A reference to a jsonb value, either in a [`JsonbArray`] or a [`JsonbVal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonbRef<'a>(&'a Value);
+
+impl JsonbVal {
+    pub fn from_value(value: Value) -> Self {
+        Self(Box::new(value))
+    }
+
+    pub fn into_value(self) -> Value {
+        *self.0
+    }
+
+    pub fn as_value(&self) -> &Value {
+        &self.0
+    }
+}
+
+impl<'a> JsonbRef<'a> {
+    pub fn from_value(value: &'a Value) -> Self {
+        Self(value)
+    }
+
+    pub fn as_value(&self) -> &'a Value {
+        self.0
+    }
+}
+
+impl From<Value> for JsonbVal {
+    fn from(value: Value) -> Self {
+        Self::from_value(value)
+    }
+}
+
+impl FromStr for JsonbVal {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value = serde_json::from_str(s).map_err(|e| {
+            ErrorCode::InvalidInputSyntax(format!("invalid input syntax for type jsonb: {}", e))
+        })?;
+        Ok(Self::from_value(value))
+    }
+}
+
+/// Formats json in the same way as Postgres outputs jsonb, i.e. with a space after each `:` and
+/// `,`.
+struct JsonbFormatter;
+
+impl Formatter for JsonbFormatter {
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b", ")
+        }
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b", ")
+        }
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b": ")
+    }
+}
+
+impl fmt::Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ser = serde_json::Serializer::with_formatter(Vec::new(), JsonbFormatter);
+        self.0.serialize(&mut ser).map_err(|_| fmt::Error)?;
+        // Serializing json only produces valid utf8.
+        f.write_str(std::str::from_utf8(&ser.into_inner()).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl fmt::Display for JsonbVal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        JsonbRef(&self.0).fmt(f)
+    }
+}
+
+impl PartialOrd for JsonbRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbRef<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl PartialOrd for JsonbVal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbVal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        JsonbRef(&self.0).cmp(&JsonbRef(&other.0))
+    }
+}
+
+impl Hash for JsonbRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+    }
+}
+
+impl Hash for JsonbVal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        JsonbRef(&self.0).hash(state);
+    }
+}
+
+#[derive(Debug)]
+pub struct JsonbArray {
+    bitmap: Bitmap,
+    data: Vec<Value>,
+}
+
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Value>,
+}
+
+impl JsonbArray {
+    pub fn from_slice(data: &[Option<JsonbRef<'_>>]) -> Result<Self> {
+        let mut builder = <Self as Array>::Builder::new(data.len())?;
+        for i in data {
+            builder.append(*i)?;
+        }
+        builder.finish()
+    }
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = JsonbVal;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null(idx) {
+            Some(JsonbRef(&self.data[idx]))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayIterator::new(self)
+    }
+
+    /// Jsonb values are serialized as text in the same layout as `Utf8Array`.
+    fn to_protobuf(&self) -> ProstArray {
+        let mut offset_buffer = Vec::<u8>::with_capacity((self.len() + 1) * size_of::<u64>());
+        let mut data_buffer = Vec::<u8>::new();
+        let mut offset = 0u64;
+        for (v, not_null) in self.data.iter().zip_eq(self.null_bitmap().iter()) {
+            if not_null {
+                let s = JsonbRef(v).to_string();
+                offset_buffer.extend_from_slice(&offset.to_be_bytes());
+                data_buffer.extend_from_slice(s.as_bytes());
+                offset += s.len() as u64;
+            }
+        }
+        offset_buffer.extend_from_slice(&offset.to_be_bytes());
+
+        let values = vec![
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: offset_buffer,
+            },
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: data_buffer,
+            },
+        ];
+        let null_bitmap = self.null_bitmap().to_protobuf();
+        ProstArray {
+            null_bitmap: Some(null_bitmap),
+            values,
+            array_type: ArrayType::Jsonb as i32,
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            JsonbRef(&self.data[idx]).hash(state);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = JsonbArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Jsonb(array_builder))
+    }
+}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn new_with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        Ok(Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<JsonbRef<'_>>) -> Result<()> {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.push(x.0.clone());
+            }
+            None => {
+                self.bitmap.append(false);
+                self.data.push(Value::Null);
+            }
+        }
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &JsonbArray) -> Result<()> {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<JsonbArray> {
+        Ok(JsonbArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::ArrayImpl;
+
+    #[test]
+    fn test_jsonb_display() {
+        let v: JsonbVal = r#"{"b": [1, 2.5, "x"], "a": {"c": null, "d": true}}"#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            v.to_string(),
+            r#"{"a": {"c": null, "d": true}, "b": [1, 2.5, "x"]}"#
+        );
+        assert!("{1: 2}".parse::<JsonbVal>().is_err());
+    }
+
+    #[test]
+    fn test_jsonb_array_protobuf() {
+        let v1: JsonbVal = r#"{"a": 1}"#.parse().unwrap();
+        let v2: JsonbVal = "[1, null]".parse().unwrap();
+        let array = JsonbArray::from_slice(&[
+            Some(JsonbRef::from_value(v1.as_value())),
+            None,
+            Some(JsonbRef::from_value(v2.as_value())),
+        ])
+        .unwrap();
+        let prost = array.to_protobuf();
+        let decoded = ArrayImpl::from_protobuf(&prost, 3).unwrap();
+        let decoded: &JsonbArray = (&decoded).into();
+        assert_eq!(decoded.value_at(0).unwrap().to_string(), r#"{"a": 1}"#);
+        assert_eq!(decoded.value_at(1), None);
+        assert_eq!(decoded.value_at(2).unwrap().to_string(), "[1, null]");
+    }
+}
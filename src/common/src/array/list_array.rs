@@ -172,7 +172,7 @@ impl Array for ListArray {
 
     fn hash_at<H: std::hash::Hasher>(&self, idx: usize, state: &mut H) {
         if !self.is_null(idx) {
-            self.value.hash_at(idx, state)
+            (self.offsets[idx]..self.offsets[idx + 1]).for_each(|o| self.value.hash_at(o, state))
         } else {
             NULL_VAL_FOR_HASH.hash(state);
         }
@@ -259,8 +259,8 @@ pub struct ListValue {
 }
 
 impl fmt::Display for ListValue {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Ok(())
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_scalar_ref(), f)
     }
 }
 
@@ -280,6 +280,10 @@ impl ListValue {
     pub fn new(values: Vec<Datum>) -> Self {
         Self { values }
     }
+
+    pub fn values(&self) -> &[Datum] {
+        &self.values
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -359,12 +363,41 @@ impl Debug for ListRef<'_> {
     }
 }
 
+/// Formats the list like Postgres, e.g. `{1,NULL,3}`. Strings are quoted if they are empty or
+/// contain special characters, e.g. `{a,"b c",""}`.
 impl Display for ListRef<'_> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, datum) in self.values_ref().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            match datum {
+                None => f.write_str("NULL")?,
+                Some(ScalarRefImpl::Utf8(s)) if need_quote(s) => {
+                    f.write_str("\"")?;
+                    for c in s.chars() {
+                        if c == '"' || c == '\\' {
+                            f.write_str("\\")?;
+                        }
+                        write!(f, "{}", c)?;
+                    }
+                    f.write_str("\"")?;
+                }
+                Some(scalar) => write!(f, "{}", scalar)?,
+            }
+        }
+        f.write_str("}")
     }
 }
 
+fn need_quote(s: &str) -> bool {
+    s.is_empty()
+        || s.eq_ignore_ascii_case("null")
+        || s.chars()
+            .any(|c| c.is_whitespace() || matches!(c, '{' | '}' | ',' | '"' | '\\'))
+}
+
 impl Eq for ListRef<'_> {}
 
 impl Ord for ListRef<'_> {
@@ -624,4 +657,25 @@ mod tests {
             ListValue::new(vec![Some(1.into()), None]),
         );
     }
+
+    #[test]
+    fn test_list_value_display() {
+        let value = ListValue::new(vec![Some(1.into()), None, Some(3.into())]);
+        assert_eq!(value.to_string(), "{1,NULL,3}");
+
+        let value = ListValue::new(vec![
+            Some("a".to_string().into()),
+            Some("b c".to_string().into()),
+            Some("".to_string().into()),
+            Some("NULL".to_string().into()),
+            Some("\"d\"".to_string().into()),
+        ]);
+        assert_eq!(value.to_string(), r#"{a,"b c","","NULL","\"d\""}"#);
+
+        let value = ListValue::new(vec![
+            Some(ListValue::new(vec![Some(1.into())]).into()),
+            Some(ListValue::new(vec![]).into()),
+        ]);
+        assert_eq!(value.to_string(), "{{1},{}}");
+    }
 }
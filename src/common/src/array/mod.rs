@@ -15,6 +15,7 @@
 //! `Array` defines all in-memory representations of vectorized execution framework.
 
 mod bool_array;
+mod bytes_array;
 mod chrono_array;
 pub mod column;
 mod column_proto_readers;
@@ -23,6 +24,7 @@ pub mod data_chunk_iter;
 mod decimal_array;
 pub mod interval_array;
 mod iterator;
+pub mod jsonb_array;
 pub mod list_array;
 mod macros;
 mod primitive_array;
@@ -30,6 +32,7 @@ pub mod stream_chunk;
 mod stream_chunk_iter;
 pub mod struct_array;
 mod utf8_array;
+mod uuid_array;
 mod value_reader;
 
 use std::convert::From;
@@ -37,6 +40,7 @@ use std::hash::Hasher;
 use std::sync::Arc;
 
 pub use bool_array::{BoolArray, BoolArrayBuilder};
+pub use bytes_array::{BytesArray, BytesArrayBuilder};
 pub use chrono_array::{
     NaiveDateArray, NaiveDateArrayBuilder, NaiveDateTimeArray, NaiveDateTimeArrayBuilder,
     NaiveTimeArray, NaiveTimeArrayBuilder,
@@ -47,6 +51,7 @@ pub use data_chunk_iter::{Row, RowDeserializer, RowRef};
 pub use decimal_array::{DecimalArray, DecimalArrayBuilder};
 pub use interval_array::{IntervalArray, IntervalArrayBuilder};
 pub use iterator::ArrayIterator;
+pub use jsonb_array::{JsonbArray, JsonbArrayBuilder, JsonbRef, JsonbVal};
 pub use list_array::{ListArray, ListArrayBuilder, ListRef, ListValue};
 use paste::paste;
 pub use primitive_array::{PrimitiveArray, PrimitiveArrayBuilder, PrimitiveArrayItemType};
@@ -54,6 +59,7 @@ use risingwave_pb::data::{Array as ProstArray, ArrayType as ProstArrayType};
 pub use stream_chunk::{Op, StreamChunk};
 pub use struct_array::{StructArray, StructArrayBuilder, StructRef, StructValue};
 pub use utf8_array::*;
+pub use uuid_array::{UuidArray, UuidArrayBuilder};
 
 use crate::array::iterator::ArrayImplIterator;
 use crate::buffer::Bitmap;
@@ -248,7 +254,10 @@ macro_rules! for_all_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeArray, NaiveDateTimeArrayBuilder },
             { NaiveTime, naivetime, NaiveTimeArray, NaiveTimeArrayBuilder },
             { Struct, struct, StructArray, StructArrayBuilder },
-            { List, list, ListArray, ListArrayBuilder }
+            { List, list, ListArray, ListArrayBuilder },
+            { Jsonb, jsonb, JsonbArray, JsonbArrayBuilder },
+            { Bytea, bytea, BytesArray, BytesArrayBuilder },
+            { Uuid, uuid, UuidArray, UuidArrayBuilder }
         }
     };
 }
@@ -324,6 +333,24 @@ impl From<ListArray> for ArrayImpl {
     }
 }
 
+impl From<JsonbArray> for ArrayImpl {
+    fn from(arr: JsonbArray) -> Self {
+        Self::Jsonb(arr)
+    }
+}
+
+impl From<BytesArray> for ArrayImpl {
+    fn from(arr: BytesArray) -> Self {
+        Self::Bytea(arr)
+    }
+}
+
+impl From<UuidArray> for ArrayImpl {
+    fn from(arr: UuidArray) -> Self {
+        Self::Uuid(arr)
+    }
+}
+
 for_all_variants! { array_impl_enum }
 
 /// `impl_convert` implements several conversions for `Array` and `ArrayBuilder`.
@@ -574,6 +601,11 @@ impl ArrayImpl {
             ProstArrayType::Interval => read_interval_unit_array(array, cardinality)?,
             ProstArrayType::Struct => StructArray::from_protobuf(array)?,
             ProstArrayType::List => ListArray::from_protobuf(array)?,
+            ProstArrayType::Jsonb => read_jsonb_array(array, cardinality)?,
+            ProstArrayType::Bytea => {
+                read_string_array::<BytesArrayBuilder, BytesValueReader>(array, cardinality)?
+            }
+            ProstArrayType::Uuid => read_uuid_array(array, cardinality)?,
         };
         Ok(array)
     }
@@ -262,8 +262,8 @@ pub struct StructValue {
 }
 
 impl fmt::Display for StructValue {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Ok(())
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_scalar_ref(), f)
     }
 }
 
@@ -365,9 +365,36 @@ impl Debug for StructRef<'_> {
     }
 }
 
+/// Formats the struct like Postgres, e.g. `(1,,"a b")`. NULL fields are left empty, and strings
+/// are quoted if they are empty or contain special characters.
 impl Display for StructRef<'_> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, datum) in self.fields_ref().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if let Some(scalar) = datum {
+                let s = scalar.to_string();
+                if s.is_empty()
+                    || s.chars()
+                        .any(|c| c.is_whitespace() || matches!(c, '(' | ')' | ',' | '"' | '\\'))
+                {
+                    f.write_str("\"")?;
+                    for c in s.chars() {
+                        // Quotes and backslashes are doubled.
+                        if c == '"' || c == '\\' {
+                            write!(f, "{}", c)?;
+                        }
+                        write!(f, "{}", c)?;
+                    }
+                    f.write_str("\"")?;
+                } else {
+                    f.write_str(&s)?;
+                }
+            }
+        }
+        f.write_str(")")
     }
 }
 
@@ -463,6 +490,18 @@ mod tests {
         assert_eq!(arr.array_meta(), arr2.array_meta());
     }
 
+    #[test]
+    fn test_struct_display() {
+        let value = StructValue::new(vec![
+            Some(1.into()),
+            None,
+            Some("a b".to_string().into()),
+            Some("".to_string().into()),
+            Some("x\"y".to_string().into()),
+        ]);
+        assert_eq!(value.to_string(), r#"(1,,"a b","","x""y")"#);
+    }
+
     #[test]
     fn test_struct_value_cmp() {
         // (1, 2.0) > (1, 1.0)
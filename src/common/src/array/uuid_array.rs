@@ -0,0 +1,172 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+use std::mem::size_of;
+
+use risingwave_pb::data::buffer::CompressionType;
+use risingwave_pb::data::{Array as ProstArray, ArrayType, Buffer};
+use uuid::Uuid;
+
+use super::{ArrayMeta, NULL_VAL_FOR_HASH};
+use crate::array::{Array, ArrayBuilder, ArrayBuilderImpl, ArrayIterator};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::Result;
+
+#[derive(Debug)]
+pub struct UuidArray {
+    bitmap: Bitmap,
+    data: Vec<Uuid>,
+}
+
+#[derive(Debug)]
+pub struct UuidArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Uuid>,
+}
+
+impl UuidArray {
+    pub fn from_slice(data: &[Option<Uuid>]) -> Result<Self> {
+        let mut builder = <Self as Array>::Builder::new(data.len())?;
+        for i in data {
+            builder.append(*i)?;
+        }
+        builder.finish()
+    }
+}
+
+impl Array for UuidArray {
+    type Builder = UuidArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = Uuid;
+    type RefItem<'a> = Uuid;
+
+    fn value_at(&self, idx: usize) -> Option<Uuid> {
+        if !self.is_null(idx) {
+            Some(self.data[idx])
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayIterator::new(self)
+    }
+
+    /// Each non-null uuid is written as its 16 bytes in big endian.
+    fn to_protobuf(&self) -> ProstArray {
+        let mut output_buffer = Vec::<u8>::with_capacity(self.len() * size_of::<Uuid>());
+        for v in self.iter().flatten() {
+            output_buffer.extend_from_slice(v.as_bytes());
+        }
+        let buffer = Buffer {
+            compression: CompressionType::None as i32,
+            body: output_buffer,
+        };
+        let null_bitmap = self.null_bitmap().to_protobuf();
+        ProstArray {
+            null_bitmap: Some(null_bitmap),
+            values: vec![buffer],
+            array_type: ArrayType::Uuid as i32,
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    fn hash_at<H: std::hash::Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            self.data[idx].hash(state);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = UuidArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Uuid(array_builder))
+    }
+}
+
+impl ArrayBuilder for UuidArrayBuilder {
+    type ArrayType = UuidArray;
+
+    fn new_with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        Ok(Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<Uuid>) -> Result<()> {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.push(x);
+            }
+            None => {
+                self.bitmap.append(false);
+                self.data.push(Uuid::nil());
+            }
+        };
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &UuidArray) -> Result<()> {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<UuidArray> {
+        Ok(UuidArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::array::ArrayImpl;
+
+    #[test]
+    fn test_uuid_array_protobuf() {
+        let input = vec![
+            Some(Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef)),
+            None,
+            Some(Uuid::nil()),
+        ];
+        let array = UuidArray::from_slice(&input).unwrap();
+        let decoded = ArrayImpl::from_protobuf(&array.to_protobuf(), input.len()).unwrap();
+        let decoded: &UuidArray = (&decoded).into();
+        assert_eq!(input, decoded.iter().collect_vec());
+    }
+}
@@ -129,13 +129,14 @@ impl ColumnDesc {
         type_name: &str,
         fields: Vec<ColumnDesc>,
     ) -> Self {
-        let data_type = DataType::Struct {
-            fields: fields
+        // The field descs are named by their full paths, e.g. `country.city`.
+        let data_type = DataType::new_struct(
+            fields.iter().map(|f| f.data_type.clone()).collect_vec(),
+            fields
                 .iter()
-                .map(|f| f.data_type.clone())
-                .collect_vec()
-                .into(),
-        };
+                .map(|f| f.name.rsplit('.').next().unwrap().to_string())
+                .collect_vec(),
+        );
         Self {
             data_type,
             column_id: ColumnId::new(column_id),
@@ -147,38 +148,17 @@ impl ColumnDesc {
 }
 
 impl From<ProstColumnDesc> for ColumnDesc {
-    // Since the prost DataType struct doesn't have field, so it need to be reinit when into
-    // ColumnDesc
     fn from(prost: ProstColumnDesc) -> Self {
-        if let DataType::Struct { .. } = DataType::from(prost.column_type.as_ref().unwrap()) {
-            let descs: Vec<ColumnDesc> = prost
+        Self {
+            data_type: DataType::from(prost.column_type.as_ref().unwrap()),
+            column_id: ColumnId::new(prost.column_id),
+            name: prost.name,
+            type_name: prost.type_name,
+            field_descs: prost
                 .field_descs
                 .into_iter()
                 .map(ColumnDesc::from)
-                .collect();
-            let date_type = DataType::Struct {
-                fields: descs
-                    .clone()
-                    .into_iter()
-                    .map(|c| c.data_type)
-                    .collect_vec()
-                    .into(),
-            };
-            Self {
-                data_type: date_type,
-                column_id: ColumnId::new(prost.column_id),
-                name: prost.name,
-                type_name: prost.type_name,
-                field_descs: descs,
-            }
-        } else {
-            Self {
-                data_type: DataType::from(prost.column_type.as_ref().unwrap()),
-                column_id: ColumnId::new(prost.column_id),
-                name: prost.name,
-                type_name: prost.type_name,
-                field_descs: vec![],
-            }
+                .collect(),
         }
     }
 }
@@ -22,12 +22,12 @@ use chrono::{Datelike, Timelike};
 use itertools::Itertools;
 
 use crate::array::{
-    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, DataChunk, ListRef, Row, StructRef,
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, DataChunk, JsonbRef, ListRef, Row, StructRef,
 };
 use crate::error::Result;
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum,
+    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum, Uuid,
 };
 use crate::util::hash_util::CRC32FastBuilder;
 
@@ -386,6 +386,33 @@ impl HashKeySerDe<'_> for NaiveTimeWrapper {
     }
 }
 
+impl HashKeySerDe<'_> for Uuid {
+    type S = [u8; 16];
+
+    fn serialize(self) -> Self::S {
+        *self.as_bytes()
+    }
+
+    fn deserialize<R: Read>(source: &mut R) -> Self {
+        let value = Self::read_fixed_size_bytes::<R, 16>(source);
+        Uuid::from_bytes(value)
+    }
+}
+
+impl<'a> HashKeySerDe<'a> for &'a [u8] {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize bytes for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize bytes for hash!")
+    }
+}
+
 impl<'a> HashKeySerDe<'a> for StructRef<'a> {
     type S = Vec<u8>;
 
@@ -400,6 +427,20 @@ impl<'a> HashKeySerDe<'a> for StructRef<'a> {
     }
 }
 
+impl<'a> HashKeySerDe<'a> for JsonbRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        todo!()
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        todo!()
+    }
+}
+
 impl<'a> HashKeySerDe<'a> for ListRef<'a> {
     type S = Vec<u8>;
 
@@ -24,8 +24,9 @@ use rand::distributions::Standard;
 use rand::prelude::Distribution;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
+use uuid::Uuid;
 
-use crate::array::{Array, ArrayBuilder, ArrayRef, ListValue, StructValue};
+use crate::array::{Array, ArrayBuilder, ArrayRef, JsonbVal, ListValue, StructValue};
 use crate::types::{
     Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, NativeType,
     Scalar,
@@ -115,6 +116,25 @@ impl RandValue for ListValue {
     }
 }
 
+impl RandValue for Box<[u8]> {
+    fn rand_value<R: Rng>(rand: &mut R) -> Self {
+        let len = rand.gen_range(1..=10);
+        (0..len).map(|_| rand.gen::<u8>()).collect()
+    }
+}
+
+impl RandValue for Uuid {
+    fn rand_value<R: Rng>(rand: &mut R) -> Self {
+        Uuid::from_u128(rand.gen())
+    }
+}
+
+impl RandValue for JsonbVal {
+    fn rand_value<R: rand::Rng>(rand: &mut R) -> Self {
+        JsonbVal::from(serde_json::Value::from(rand.gen::<i64>()))
+    }
+}
+
 pub fn rand_array<A, R>(rand: &mut R, size: usize) -> A
 where
     A: Array,
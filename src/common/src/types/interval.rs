@@ -19,6 +19,7 @@ use std::ops::{Add, Sub};
 use byteorder::{BigEndian, WriteBytesExt};
 use bytes::BytesMut;
 use num_traits::{CheckedAdd, CheckedSub};
+use risingwave_pb::data::IntervalUnit as ProstIntervalUnit;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
@@ -137,6 +138,22 @@ impl IntervalUnit {
     }
 }
 
+impl From<&ProstIntervalUnit> for IntervalUnit {
+    fn from(p: &ProstIntervalUnit) -> Self {
+        Self::new(p.months, p.days, p.ms)
+    }
+}
+
+impl From<IntervalUnit> for ProstIntervalUnit {
+    fn from(i: IntervalUnit) -> Self {
+        Self {
+            months: i.months,
+            days: i.days,
+            ms: i.ms,
+        }
+    }
+}
+
 impl Add for IntervalUnit {
     type Output = Self;
 
@@ -40,9 +40,11 @@ pub use decimal::Decimal;
 pub use interval::*;
 pub use ordered_float::IntoOrdered;
 use paste::paste;
+pub use uuid::Uuid;
 
 use crate::array::{
-    ArrayBuilderImpl, ListRef, ListValue, PrimitiveArrayItemType, StructRef, StructValue,
+    ArrayBuilderImpl, JsonbRef, JsonbVal, ListRef, ListValue, PrimitiveArrayItemType, StructRef,
+    StructValue,
 };
 
 pub type OrderedF32 = ordered_float::OrderedFloat<f32>;
@@ -63,8 +65,16 @@ pub enum DataType {
     Timestamp,
     Timestampz,
     Interval,
-    Struct { fields: Arc<[DataType]> },
-    List { datatype: Box<DataType> },
+    Struct {
+        fields: Arc<[DataType]>,
+        field_names: Arc<[String]>,
+    },
+    List {
+        datatype: Box<DataType>,
+    },
+    Jsonb,
+    Bytea,
+    Uuid,
 }
 
 const DECIMAL_DEFAULT_PRECISION: u32 = 20;
@@ -98,12 +108,23 @@ impl From<&ProstDataType> for DataType {
             TypeName::Decimal => DataType::Decimal,
             TypeName::Interval => DataType::Interval,
             TypeName::Symbol => DataType::Varchar,
-            TypeName::Struct => DataType::Struct {
-                fields: Arc::new([]),
-            },
+            TypeName::Struct => DataType::new_struct(
+                proto.field_type.iter().map(DataType::from).collect(),
+                proto.field_names.clone(),
+            ),
             TypeName::List => DataType::List {
-                datatype: Box::new(DataType::Int32),
+                // The element type is missing in the plans of the legacy Java frontend.
+                datatype: Box::new(
+                    proto
+                        .field_type
+                        .first()
+                        .map(DataType::from)
+                        .unwrap_or(DataType::Int32),
+                ),
             },
+            TypeName::Jsonb => DataType::Jsonb,
+            TypeName::Bytea => DataType::Bytea,
+            TypeName::Uuid => DataType::Uuid,
         }
     }
 }
@@ -125,9 +146,13 @@ impl DataType {
             DataType::Timestamp => NaiveDateTimeArrayBuilder::new(capacity)?.into(),
             DataType::Timestampz => PrimitiveArrayBuilder::<i64>::new(capacity)?.into(),
             DataType::Interval => IntervalArrayBuilder::new(capacity)?.into(),
-            DataType::Struct { .. } => {
-                todo!()
-            }
+            DataType::Struct { fields, .. } => StructArrayBuilder::new_with_meta(
+                capacity,
+                ArrayMeta::Struct {
+                    children: fields.clone(),
+                },
+            )?
+            .into(),
             DataType::List { datatype } => ListArrayBuilder::new_with_meta(
                 capacity,
                 ArrayMeta::List {
@@ -135,6 +160,9 @@ impl DataType {
                 },
             )?
             .into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity)?.into(),
+            DataType::Bytea => BytesArrayBuilder::new(capacity)?.into(),
+            DataType::Uuid => UuidArrayBuilder::new(capacity)?.into(),
         })
     }
 
@@ -155,17 +183,43 @@ impl DataType {
             DataType::Interval => TypeName::Interval,
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
+            DataType::Jsonb => TypeName::Jsonb,
+            DataType::Bytea => TypeName::Bytea,
+            DataType::Uuid => TypeName::Uuid,
         }
     }
 
     pub fn to_protobuf(&self) -> ProstDataType {
+        let (field_type, field_names) = match self {
+            DataType::List { datatype } => (vec![datatype.to_protobuf()], vec![]),
+            DataType::Struct {
+                fields,
+                field_names,
+            } => (
+                fields.iter().map(DataType::to_protobuf).collect(),
+                field_names.to_vec(),
+            ),
+            _ => (vec![], vec![]),
+        };
         ProstDataType {
             type_name: self.prost_type_name() as i32,
             is_nullable: true,
+            field_type,
+            field_names,
             ..Default::default()
         }
     }
 
+    /// Creates a struct type. The field names may be left empty, in which case the fields can only
+    /// be accessed by position.
+    pub fn new_struct(fields: Vec<DataType>, field_names: Vec<String>) -> Self {
+        debug_assert!(field_names.is_empty() || field_names.len() == fields.len());
+        Self::Struct {
+            fields: fields.into(),
+            field_names: field_names.into(),
+        }
+    }
+
     pub fn data_size(&self) -> DataSize {
         use std::mem::size_of;
         match self {
@@ -184,6 +238,9 @@ impl DataType {
             DataType::Interval => DataSize::Variable,
             DataType::Struct { .. } => DataSize::Variable,
             DataType::List { .. } => DataSize::Variable,
+            DataType::Jsonb => DataSize::Variable,
+            DataType::Bytea => DataSize::Variable,
+            DataType::Uuid => DataSize::Fixed(size_of::<Uuid>()),
         }
     }
 
@@ -225,6 +282,9 @@ impl DataType {
             DataType::Interval => 13,
             DataType::Struct { .. } => 14,
             DataType::List { .. } => 15,
+            DataType::Jsonb => 16,
+            DataType::Bytea => 17,
+            DataType::Uuid => 18,
         }
     }
 }
@@ -303,7 +363,10 @@ macro_rules! for_all_scalar_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeWrapper, NaiveDateTimeWrapper },
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
             { Struct, struct, StructValue, StructRef<'scalar> },
-            { List, list, ListValue, ListRef<'scalar> }
+            { List, list, ListValue, ListRef<'scalar> },
+            { Jsonb, jsonb, JsonbVal, JsonbRef<'scalar> },
+            { Bytea, bytea, Box<[u8]>, &'scalar [u8] },
+            { Uuid, uuid, Uuid, Uuid }
         }
     };
 }
@@ -568,6 +631,9 @@ impl std::hash::Hash for ScalarImpl {
                     Self::NaiveTime(naivetime) => naivetime.hash(state),
                     Self::Struct(v) => v.hash(state),
                     Self::List(v) => v.hash(state),
+                    Self::Jsonb(v) => v.hash(state),
+                    Self::Bytea(v) => v.hash(state),
+                    Self::Uuid(v) => v.hash(state),
                 }
             };
         }
@@ -577,32 +643,35 @@ impl std::hash::Hash for ScalarImpl {
 
 impl Display for ScalarImpl {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        macro_rules! impl_display_fmt {
-            ([], $( { $variant_name:ident, $suffix_name:ident, $scalar:ty, $scalar_ref:ty } ),*) => {
-                match self {
-                    $( Self::$variant_name(ref inner) => {
-                        Display::fmt(inner, f)
-                    }, )*
-                }
-            }
-        }
-
-        for_all_scalar_variants! { impl_display_fmt }
+        Display::fmt(&self.as_scalar_ref_impl(), f)
     }
 }
 
 impl Display for ScalarRefImpl<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        macro_rules! impl_display_fmt {
-            ([], $( { $variant_name:ident, $suffix_name:ident, $scalar:ty, $scalar_ref:ty } ),*) => {
-                match self {
-                    $( Self::$variant_name(inner) => {
-                        Display::fmt(inner, f)
-                    }, )*
-                }
+        match self {
+            Self::Int16(v) => Display::fmt(v, f),
+            Self::Int32(v) => Display::fmt(v, f),
+            Self::Int64(v) => Display::fmt(v, f),
+            Self::Float32(v) => Display::fmt(v, f),
+            Self::Float64(v) => Display::fmt(v, f),
+            Self::Utf8(v) => Display::fmt(v, f),
+            Self::Bool(v) => Display::fmt(v, f),
+            Self::Decimal(v) => Display::fmt(v, f),
+            Self::Interval(v) => Display::fmt(v, f),
+            Self::NaiveDate(v) => Display::fmt(v, f),
+            Self::NaiveDateTime(v) => Display::fmt(v, f),
+            Self::NaiveTime(v) => Display::fmt(v, f),
+            Self::Struct(v) => Display::fmt(v, f),
+            Self::List(v) => Display::fmt(v, f),
+            Self::Jsonb(v) => Display::fmt(v, f),
+            // Same as the default `hex` output format of Postgres.
+            Self::Bytea(v) => {
+                f.write_str("\\x")?;
+                v.iter().try_for_each(|b| write!(f, "{:02x}", b))
             }
+            Self::Uuid(v) => Display::fmt(v, f),
         }
-        for_all_scalar_variants! { impl_display_fmt }
     }
 }
 
@@ -625,6 +694,30 @@ impl ScalarRefImpl<'_> {
                 ser.serialize_decimal(mantissa, scale)?;
             }
             Self::Interval(v) => v.serialize(ser)?,
+            Self::Jsonb(v) => v.to_string().serialize(ser)?,
+            Self::Bytea(v) => ser.serialize_bytea(v)?,
+            Self::Uuid(v) => ser.serialize_uuid(*v.as_bytes())?,
+            // Each element is prefixed with 1 and the list ends with 0, so that a list sorts
+            // before the lists it is a prefix of.
+            Self::List(v) => {
+                for datum in v.values_ref() {
+                    1u8.serialize(&mut *ser)?;
+                    match datum {
+                        Some(scalar) => {
+                            1u8.serialize(&mut *ser)?;
+                            scalar.serialize(&mut *ser)?;
+                        }
+                        None => 0u8.serialize(&mut *ser)?,
+                    }
+                }
+                0u8.serialize(ser)?;
+            }
+            // A struct always has the same number of fields, so they are simply concatenated.
+            Self::Struct(v) => {
+                for datum in v.fields_ref() {
+                    serialize_datum_ref_into(&datum, &mut *ser)?;
+                }
+            }
             &Self::NaiveDate(v) => ser.serialize_naivedate(v.0.num_days_from_ce())?,
             &Self::NaiveDateTime(v) => {
                 ser.serialize_naivedatetime(v.0.timestamp(), v.0.timestamp_subsec_nanos())?
@@ -673,6 +766,30 @@ impl ScalarImpl {
                 }
             }),
             Ty::Interval => Self::Interval(IntervalUnit::deserialize(de)?),
+            Ty::Jsonb => Self::Jsonb(
+                String::deserialize(de)?
+                    .parse()
+                    .map_err(|e: RwError| memcomparable::Error::Message(e.to_string()))?,
+            ),
+            Ty::Bytea => Self::Bytea(de.deserialize_bytea()?.into()),
+            Ty::Uuid => Self::Uuid(Uuid::from_bytes(de.deserialize_uuid()?)),
+            Ty::List { datatype } => {
+                let mut values = vec![];
+                loop {
+                    match u8::deserialize(&mut *de)? {
+                        0 => break,
+                        1 => values.push(deserialize_datum_from(&datatype, &mut *de)?),
+                        tag => return Err(memcomparable::Error::InvalidTagEncoding(tag as _)),
+                    }
+                }
+                Self::List(ListValue::new(values))
+            }
+            Ty::Struct { fields, .. } => Self::Struct(StructValue::new(
+                fields
+                    .iter()
+                    .map(|field| deserialize_datum_from(field, &mut *de))
+                    .collect::<memcomparable::Result<_>>()?,
+            )),
             Ty::Time => Self::NaiveTime({
                 let (secs, nano) = de.deserialize_naivetime()?;
                 NaiveTimeWrapper::new_with_secs_nano(secs, nano)?
@@ -773,4 +890,107 @@ mod tests {
         assert!(decoded_floats.is_sorted());
         assert_eq!(floats, decoded_floats);
     }
+    #[test]
+    fn test_jsonb_memcomparable() {
+        let datum = Some(ScalarImpl::Jsonb(
+            r#"{"a": [1, "x", null], "b": {"c": true}}"#.parse().unwrap(),
+        ));
+        let mut serializer = memcomparable::Serializer::new(vec![]);
+        serialize_datum_into(&datum, &mut serializer).unwrap();
+        let data = serializer.into_inner();
+        let mut deserializer = memcomparable::Deserializer::new(data.as_slice());
+        let decoded = deserialize_datum_from(&DataType::Jsonb, &mut deserializer).unwrap();
+        assert_eq!(datum, decoded);
+    }
+
+    #[test]
+    fn test_bytea_uuid_memcomparable() {
+        let data = [
+            (
+                DataType::Bytea,
+                Some(ScalarImpl::Bytea(b"\x00\x01abc\xff".to_vec().into())),
+            ),
+            (
+                DataType::Bytea,
+                Some(ScalarImpl::Bytea(b"".to_vec().into())),
+            ),
+            (
+                DataType::Uuid,
+                Some(ScalarImpl::Uuid(
+                    "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11".parse().unwrap(),
+                )),
+            ),
+        ];
+        for (ty, datum) in data {
+            let mut serializer = memcomparable::Serializer::new(vec![]);
+            serialize_datum_into(&datum, &mut serializer).unwrap();
+            let data = serializer.into_inner();
+            let mut deserializer = memcomparable::Deserializer::new(data.as_slice());
+            let decoded = deserialize_datum_from(&ty, &mut deserializer).unwrap();
+            assert_eq!(datum, decoded);
+        }
+    }
+
+    #[test]
+    fn test_bytea_uuid_display() {
+        let bytea = ScalarImpl::Bytea(b"\xde\xad\x00".to_vec().into());
+        assert_eq!(bytea.to_string(), "\\xdead00");
+        let uuid = ScalarImpl::Uuid(Uuid::from_u128(0xa0ee_bc99_9c0b_4ef8_bb6d_6bb9_bd38_0a11));
+        assert_eq!(uuid.to_string(), "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11");
+    }
+
+    #[test]
+    fn test_list_memcomparable() {
+        let ty = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let lists = [
+            ListValue::new(vec![]),
+            ListValue::new(vec![None]),
+            ListValue::new(vec![Some(1.into())]),
+            ListValue::new(vec![Some(1.into()), Some(2.into())]),
+            ListValue::new(vec![Some(2.into())]),
+        ];
+        let encoded = lists
+            .iter()
+            .map(|list| {
+                let datum = Some(ScalarImpl::List(list.clone()));
+                let mut serializer = memcomparable::Serializer::new(vec![]);
+                serialize_datum_into(&datum, &mut serializer).unwrap();
+                let data = serializer.into_inner();
+                let mut deserializer = memcomparable::Deserializer::new(data.as_slice());
+                assert_eq!(
+                    datum,
+                    deserialize_datum_from(&ty, &mut deserializer).unwrap()
+                );
+                data
+            })
+            .collect_vec();
+        assert!(encoded.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_struct_memcomparable() {
+        let ty = DataType::new_struct(
+            vec![DataType::Int32, DataType::Varchar],
+            vec!["a".to_string(), "b".to_string()],
+        );
+        let structs = [
+            StructValue::new(vec![None, Some("x".to_string().into())]),
+            StructValue::new(vec![Some(1.into()), None]),
+            StructValue::new(vec![Some(1.into()), Some("x".to_string().into())]),
+            StructValue::new(vec![Some(2.into()), Some("".to_string().into())]),
+        ];
+        for value in structs {
+            let datum = Some(ScalarImpl::Struct(value));
+            let mut serializer = memcomparable::Serializer::new(vec![]);
+            serialize_datum_into(&datum, &mut serializer).unwrap();
+            let data = serializer.into_inner();
+            let mut deserializer = memcomparable::Deserializer::new(data.as_slice());
+            assert_eq!(
+                datum,
+                deserialize_datum_from(&ty, &mut deserializer).unwrap()
+            );
+        }
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::array::jsonb_array::{JsonbRef, JsonbVal};
 use crate::array::list_array::{ListRef, ListValue};
 use crate::array::struct_array::{StructRef, StructValue};
 use crate::{for_all_native_types, for_all_scalar_variants};
@@ -94,6 +95,28 @@ impl Scalar for ListValue {
     }
 }
 
+/// Implement `Scalar` for `JsonbVal`.
+impl Scalar for JsonbVal {
+    type ScalarRefType<'a> = JsonbRef<'a>;
+
+    fn as_scalar_ref(&self) -> JsonbRef<'_> {
+        JsonbRef::from_value(self.as_value())
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Jsonb(self)
+    }
+}
+
+/// Implement `ScalarRef` for `JsonbRef`.
+impl<'a> ScalarRef<'a> for JsonbRef<'a> {
+    type ScalarType = JsonbVal;
+
+    fn to_owned_scalar(&self) -> JsonbVal {
+        JsonbVal::from_value(self.as_value().clone())
+    }
+}
+
 /// Implement `ScalarRef` for `String`.
 /// `String` could be converted to `&str`.
 impl<'a> ScalarRef<'a> for &'a str {
@@ -104,6 +127,50 @@ impl<'a> ScalarRef<'a> for &'a str {
     }
 }
 
+/// Implement `Scalar` for `Box<[u8]>`, the owned value of `bytea`.
+impl Scalar for Box<[u8]> {
+    type ScalarRefType<'a> = &'a [u8];
+
+    fn as_scalar_ref(&self) -> &[u8] {
+        self
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Bytea(self)
+    }
+}
+
+/// Implement `ScalarRef` for `&[u8]`.
+impl<'a> ScalarRef<'a> for &'a [u8] {
+    type ScalarType = Box<[u8]>;
+
+    fn to_owned_scalar(&self) -> Box<[u8]> {
+        (*self).into()
+    }
+}
+
+/// Implement `Scalar` and `ScalarRef` for `Uuid`. Like the native types, it is both owned and
+/// referenced by value.
+impl Scalar for Uuid {
+    type ScalarRefType<'a> = Uuid;
+
+    fn as_scalar_ref(&self) -> Uuid {
+        *self
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Uuid(self)
+    }
+}
+
+impl<'a> ScalarRef<'a> for Uuid {
+    type ScalarType = Uuid;
+
+    fn to_owned_scalar(&self) -> Uuid {
+        *self
+    }
+}
+
 impl ScalarPartialOrd for Decimal {
     fn scalar_cmp(&self, other: Self) -> Option<std::cmp::Ordering> {
         self.partial_cmp(&other)
@@ -27,8 +27,10 @@ use crate::types::{
 /// Serialize datum into cell bytes (Not order guarantee, used in value encoding).
 pub fn serialize_cell(cell: &Datum) -> Result<Vec<u8>> {
     let mut serializer = value_encoding::Serializer::new(vec![]);
-    if let Some(ScalarImpl::Decimal(decimal)) = cell {
-        return serialize_decimal(decimal);
+    match cell {
+        Some(ScalarImpl::Decimal(decimal)) => return serialize_decimal(decimal),
+        Some(ScalarImpl::Bytea(bytes)) => return serialize_bytea(bytes),
+        _ => {}
     }
     serialize_datum_into(cell, serializer.memcom_ser())?;
     Ok(serializer.into_inner())
@@ -37,8 +39,10 @@ pub fn serialize_cell(cell: &Datum) -> Result<Vec<u8>> {
 /// Serialize datum cannot be null into cell bytes.
 pub fn serialize_cell_not_null(cell: &Datum) -> Result<Vec<u8>> {
     let mut serializer = value_encoding::Serializer::new(vec![]);
-    if let Some(ScalarImpl::Decimal(decimal)) = cell {
-        return serialize_decimal(decimal);
+    match cell {
+        Some(ScalarImpl::Decimal(decimal)) => return serialize_decimal(decimal),
+        Some(ScalarImpl::Bytea(bytes)) => return serialize_bytea(bytes),
+        _ => {}
     }
     serialize_datum_not_null_into(cell, serializer.memcom_ser())?;
     Ok(serializer.into_inner())
@@ -51,6 +55,7 @@ pub fn deserialize_cell(
 ) -> Result<Datum> {
     match ty {
         &DataType::Decimal => deserialize_decimal(deserializer),
+        &DataType::Bytea => deserialize_bytea(deserializer),
         _ => Ok(deserialize_datum_from(ty, deserializer.memcom_de())?),
     }
 }
@@ -62,6 +67,7 @@ pub fn deserialize_cell_not_null(
 ) -> Result<Datum> {
     match ty {
         DataType::Decimal => deserialize_decimal(deserializer),
+        DataType::Bytea => deserialize_bytea(deserializer),
         _ => Ok(deserialize_datum_not_null_from(
             ty,
             deserializer.memcom_de(),
@@ -87,20 +93,41 @@ fn serialize_decimal(decimal: &Decimal) -> Result<Vec<u8>> {
     Ok(byte_array)
 }
 
-fn deserialize_decimal(deserializer: &mut value_encoding::Deserializer<impl Buf>) -> Result<Datum> {
+/// Serialize bytes with a `u32` length prefix, instead of the memcomparable format which pads
+/// every 8 bytes with a marker.
+fn serialize_bytea(bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut byte_array = Vec::with_capacity(1 + 4 + bytes.len());
+    byte_array.push(1);
+    byte_array.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    byte_array.extend_from_slice(bytes);
+    Ok(byte_array)
+}
+
+/// Returns `false` if the datum is NULL.
+fn deserialize_null_tag(deserializer: &mut value_encoding::Deserializer<impl Buf>) -> Result<bool> {
     // None denotes NULL which is a valid value while Err means invalid encoding.
     let null_tag = u8::deserialize(&mut *deserializer.memcom_de())?;
     match null_tag {
-        0 => {
-            return Ok(None);
-        }
-        1 => {}
-        _ => {
-            return Err(RwError::from(ErrorCode::InternalError(format!(
-                "Invalid null tag: {}",
-                null_tag
-            ))));
-        }
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(RwError::from(ErrorCode::InternalError(format!(
+            "Invalid null tag: {}",
+            null_tag
+        )))),
+    }
+}
+
+fn deserialize_bytea(deserializer: &mut value_encoding::Deserializer<impl Buf>) -> Result<Datum> {
+    if !deserialize_null_tag(deserializer)? {
+        return Ok(None);
+    }
+    let bytes = deserializer.read_bytes_v2()?;
+    Ok(Some(ScalarImpl::Bytea(bytes.into())))
+}
+
+fn deserialize_decimal(deserializer: &mut value_encoding::Deserializer<impl Buf>) -> Result<Datum> {
+    if !deserialize_null_tag(deserializer)? {
+        return Ok(None);
     }
     let bytes = deserializer.read_decimal_v2()?;
     let mut scale = bytes[0];
@@ -122,3 +149,23 @@ fn deserialize_decimal(deserializer: &mut value_encoding::Deserializer<impl Buf>
         scale as u32,
     ))))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytea_cell() {
+        for datum in [
+            None,
+            Some(ScalarImpl::Bytea(b"\x00\x01abcdefghij".to_vec().into())),
+        ] {
+            let bytes = serialize_cell(&datum).unwrap();
+            let mut deserializer = value_encoding::Deserializer::new(&bytes[..]);
+            assert_eq!(
+                deserialize_cell(&mut deserializer, &DataType::Bytea).unwrap(),
+                datum
+            );
+        }
+    }
+}
@@ -242,7 +242,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             assert_eq!(col_row_id.value_at(0).unwrap(), 0);
             assert_eq!(col_row_id.value_at(1).unwrap(), 1);
         }
-        Message::Barrier(_) => panic!(),
+        Message::Barrier(_) | Message::Watermark(_) => panic!(),
     }
 
     // Send a barrier and poll again, should write changes to storage
@@ -310,7 +310,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             let col_row_id = c.columns()[1].array_ref().as_int64();
             assert_eq!(col_row_id.value_at(0).unwrap(), 0);
         }
-        Message::Barrier(_) => panic!(),
+        Message::Barrier(_) | Message::Watermark(_) => panic!(),
     }
 
     // Send a barrier and poll again, should write changes to storage
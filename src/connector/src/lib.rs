@@ -33,6 +33,7 @@ mod filesystem;
 mod kafka;
 pub mod kinesis;
 mod pulsar;
+pub mod sink;
 mod utils;
 
 pub use base::*;
@@ -0,0 +1,141 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use risingwave_common::array::StreamChunk;
+use serde_json::json;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+
+use crate::sink::{RecordFormatter, Sink, SinkFormat};
+
+const FILE_SINK_PATH_KEY: &str = "path";
+
+/// Appends the records as JSON lines to a local file. In upsert format every line is an object
+/// with `key` and `value`, in append-only format it is the row itself.
+pub struct FileSink {
+    path: String,
+    formatter: RecordFormatter,
+    file: Option<File>,
+    buffer: Vec<u8>,
+}
+
+impl FileSink {
+    pub fn new(properties: &HashMap<String, String>, formatter: RecordFormatter) -> Result<Self> {
+        let path = properties
+            .get(FILE_SINK_PATH_KEY)
+            .ok_or_else(|| anyhow!("file sink property `{}` not found", FILE_SINK_PATH_KEY))?;
+        Ok(Self {
+            path: path.clone(),
+            formatter,
+            file: None,
+            buffer: vec![],
+        })
+    }
+}
+
+#[async_trait]
+impl Sink for FileSink {
+    async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
+        for record in self.formatter.format_chunk(&chunk) {
+            let line = match self.formatter.format() {
+                SinkFormat::AppendOnly => record.value.unwrap_or_default(),
+                SinkFormat::Upsert => json!({"key": record.key, "value": record.value}),
+            };
+            serde_json::to_writer(&mut self.buffer, &line)?;
+            self.buffer.push(b'\n');
+        }
+        Ok(())
+    }
+
+    async fn commit(&mut self, _epoch: u64) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        if self.file.is_none() {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .await?;
+            self.file = Some(file);
+        }
+        let file = self.file.as_mut().unwrap();
+        file.write_all(&self.buffer).await?;
+        file.sync_data().await?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I32Array, Op};
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::sink::build_sink;
+
+    #[tokio::test]
+    async fn test_file_sink() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sink.json");
+        let properties = HashMap::from([
+            ("connector".to_string(), "file".to_string()),
+            ("path".to_string(), path.to_str().unwrap().to_string()),
+        ]);
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Int32, "v"),
+        ]);
+        let mut sink = build_sink(&properties, schema, vec![0]).unwrap();
+
+        let chunk = StreamChunk::new(
+            vec![Op::Insert, Op::Insert],
+            vec![
+                column_nonnull!(I32Array, [1, 2]),
+                column_nonnull!(I32Array, [10, 20]),
+            ],
+            None,
+        );
+        sink.write_batch(chunk).await.unwrap();
+        // Nothing is visible before commit.
+        assert!(!path.exists());
+        sink.commit(1).await.unwrap();
+
+        let chunk = StreamChunk::new(
+            vec![Op::Delete],
+            vec![
+                column_nonnull!(I32Array, [1]),
+                column_nonnull!(I32Array, [10]),
+            ],
+            None,
+        );
+        sink.write_batch(chunk).await.unwrap();
+        sink.commit(2).await.unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "{\"key\":{\"id\":1},\"value\":{\"id\":1,\"v\":10}}\n\
+             {\"key\":{\"id\":2},\"value\":{\"id\":2,\"v\":20}}\n\
+             {\"key\":{\"id\":1},\"value\":null}\n"
+        );
+    }
+}
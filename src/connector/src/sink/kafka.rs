@@ -0,0 +1,99 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::ClientConfig;
+use risingwave_common::array::StreamChunk;
+
+use crate::kafka::{KAFKA_CONFIG_BROKER_KEY, KAFKA_CONFIG_TOPIC_KEY};
+use crate::sink::{RecordFormatter, Sink};
+
+const KAFKA_SINK_QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Produces the records to a Kafka topic, with the JSON encoded key and value as message key and
+/// payload. A deleted row in upsert format becomes a tombstone message without payload.
+pub struct KafkaSink {
+    topic: String,
+    producer: FutureProducer,
+    formatter: RecordFormatter,
+}
+
+impl KafkaSink {
+    pub fn new(properties: &HashMap<String, String>, formatter: RecordFormatter) -> Result<Self> {
+        let broker_address = properties.get(KAFKA_CONFIG_BROKER_KEY).ok_or_else(|| {
+            anyhow!(
+                "kafka sink property `{}` not found",
+                KAFKA_CONFIG_BROKER_KEY
+            )
+        })?;
+        let topic = properties
+            .get(KAFKA_CONFIG_TOPIC_KEY)
+            .ok_or_else(|| anyhow!("kafka sink property `{}` not found", KAFKA_CONFIG_TOPIC_KEY))?;
+
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", broker_address)
+            .create()
+            .map_err(|e| anyhow!("failed to create kafka producer: {}", e))?;
+
+        Ok(Self {
+            topic: topic.clone(),
+            producer,
+            formatter,
+        })
+    }
+}
+
+#[async_trait]
+impl Sink for KafkaSink {
+    async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
+        let messages = self
+            .formatter
+            .format_chunk(&chunk)
+            .into_iter()
+            .map(|record| {
+                let key = record.key.map(|key| key.to_string());
+                let payload = record.value.map(|value| value.to_string());
+                (key, payload)
+            })
+            .collect::<Vec<_>>();
+
+        // Wait for the acknowledgements of all messages, so that they are delivered once the
+        // epoch is committed.
+        try_join_all(messages.iter().map(|(key, payload)| {
+            let mut record = FutureRecord::<String, String>::to(&self.topic);
+            if let Some(key) = key {
+                record = record.key(key);
+            }
+            if let Some(payload) = payload {
+                record = record.payload(payload);
+            }
+            self.producer.send(record, KAFKA_SINK_QUEUE_TIMEOUT)
+        }))
+        .await
+        .map_err(|(e, _)| anyhow!("failed to deliver to kafka: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn commit(&mut self, _epoch: u64) -> Result<()> {
+        // All messages have been acknowledged in `write_batch`.
+        Ok(())
+    }
+}
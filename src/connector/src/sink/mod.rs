@@ -0,0 +1,274 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sinks deliver the changes of a streaming job to external systems. The sink executor buffers
+//! the changes between two barriers, then writes them with [`Sink::write_batch`] and calls
+//! [`Sink::commit`] once the epoch is checkpointed.
+
+mod file;
+mod kafka;
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+pub use file::FileSink;
+pub use kafka::KafkaSink;
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::catalog::Schema;
+use risingwave_common::types::{DatumRef, ScalarRefImpl};
+use serde_json::{Map, Value};
+
+pub const SINK_CONNECTOR_KEY: &str = "connector";
+pub const SINK_FORMAT_KEY: &str = "format";
+
+const KAFKA_SINK: &str = "kafka";
+const FILE_SINK: &str = "file";
+
+const SINK_FORMAT_APPEND_ONLY: &str = "append_only";
+const SINK_FORMAT_UPSERT: &str = "upsert";
+
+#[async_trait]
+pub trait Sink: Send + 'static {
+    /// Writes the changes of a chunk. They are not required to be visible before the following
+    /// [`Sink::commit`].
+    async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()>;
+
+    /// Makes everything written since the last commit durable. `epoch` is the checkpointed epoch.
+    async fn commit(&mut self, epoch: u64) -> Result<()>;
+}
+
+pub type BoxedSink = Box<dyn Sink>;
+
+/// Builds a sink from the `WITH` properties of `CREATE SINK`. `schema` and `pk_indices` describe
+/// the rows the sink receives.
+pub fn build_sink(
+    properties: &HashMap<String, String>,
+    schema: Schema,
+    pk_indices: Vec<usize>,
+) -> Result<BoxedSink> {
+    let formatter =
+        RecordFormatter::new(SinkFormat::from_properties(properties)?, schema, pk_indices);
+    let connector = properties
+        .get(SINK_CONNECTOR_KEY)
+        .ok_or_else(|| anyhow!("sink property `{}` not found", SINK_CONNECTOR_KEY))?;
+    match connector.to_lowercase().as_str() {
+        KAFKA_SINK => Ok(Box::new(KafkaSink::new(properties, formatter)?)),
+        FILE_SINK => Ok(Box::new(FileSink::new(properties, formatter)?)),
+        other => Err(anyhow!("unknown sink connector: {}", other)),
+    }
+}
+
+/// How the changes are turned into records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinkFormat {
+    /// Only inserted rows are emitted, retractions are dropped.
+    AppendOnly,
+    /// Every change is keyed by the primary key. A deleted row is emitted as a record without
+    /// value, i.e. a tombstone.
+    Upsert,
+}
+
+impl SinkFormat {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self> {
+        match properties.get(SINK_FORMAT_KEY).map(|f| f.to_lowercase()) {
+            None => Ok(Self::Upsert),
+            Some(f) if f == SINK_FORMAT_UPSERT => Ok(Self::Upsert),
+            Some(f) if f == SINK_FORMAT_APPEND_ONLY => Ok(Self::AppendOnly),
+            Some(f) => Err(anyhow!("unknown sink format: {}", f)),
+        }
+    }
+}
+
+/// A record to deliver. `key` is only set in upsert format, and `value` is `None` for a deleted
+/// row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SinkRecord {
+    pub key: Option<Value>,
+    pub value: Option<Value>,
+}
+
+/// Formats the rows of stream chunks as JSON objects keyed by column names.
+pub struct RecordFormatter {
+    format: SinkFormat,
+    schema: Schema,
+    pk_indices: Vec<usize>,
+}
+
+impl RecordFormatter {
+    pub fn new(format: SinkFormat, schema: Schema, pk_indices: Vec<usize>) -> Self {
+        Self {
+            format,
+            schema,
+            pk_indices,
+        }
+    }
+
+    pub fn format(&self) -> SinkFormat {
+        self.format
+    }
+
+    pub fn format_chunk(&self, chunk: &StreamChunk) -> Vec<SinkRecord> {
+        match self.format {
+            SinkFormat::AppendOnly => chunk
+                .rows()
+                .filter(|row| matches!(row.op(), Op::Insert | Op::UpdateInsert))
+                .map(|row| SinkRecord {
+                    key: None,
+                    value: Some(self.row_to_json((0..row.size()).map(|i| (i, row.value_at(i))))),
+                })
+                .collect(),
+            SinkFormat::Upsert => {
+                let mut records: Vec<SinkRecord> = Vec::with_capacity(chunk.cardinality());
+                for row in chunk.rows() {
+                    let key =
+                        self.row_to_json(self.pk_indices.iter().map(|&i| (i, row.value_at(i))));
+                    let value = match row.op() {
+                        Op::Insert => {
+                            Some(self.row_to_json((0..row.size()).map(|i| (i, row.value_at(i)))))
+                        }
+                        Op::UpdateInsert => {
+                            // The new value overwrites the old one, so the tombstone of an update
+                            // on the same key is useless.
+                            if matches!(records.last(), Some(last) if last.value.is_none() && last.key.as_ref() == Some(&key))
+                            {
+                                records.pop();
+                            }
+                            Some(self.row_to_json((0..row.size()).map(|i| (i, row.value_at(i)))))
+                        }
+                        Op::Delete | Op::UpdateDelete => None,
+                    };
+                    records.push(SinkRecord {
+                        key: Some(key),
+                        value,
+                    });
+                }
+                records
+            }
+        }
+    }
+
+    fn row_to_json<'a>(&self, datums: impl Iterator<Item = (usize, DatumRef<'a>)>) -> Value {
+        let map: Map<String, Value> = datums
+            .map(|(i, datum)| (self.schema.fields[i].name.clone(), datum_to_json(datum)))
+            .collect();
+        Value::Object(map)
+    }
+}
+
+fn datum_to_json(datum: DatumRef<'_>) -> Value {
+    let scalar = match datum {
+        None => return Value::Null,
+        Some(scalar) => scalar,
+    };
+    match scalar {
+        ScalarRefImpl::Int16(v) => v.into(),
+        ScalarRefImpl::Int32(v) => v.into(),
+        ScalarRefImpl::Int64(v) => v.into(),
+        ScalarRefImpl::Float32(v) => (v.into_inner() as f64).into(),
+        ScalarRefImpl::Float64(v) => v.into_inner().into(),
+        ScalarRefImpl::Bool(v) => v.into(),
+        ScalarRefImpl::Utf8(v) => v.into(),
+        ScalarRefImpl::Jsonb(v) => v.as_value().clone(),
+        // Decimals are kept as strings so that no precision is lost.
+        other => other.to_string().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I32Array, I64Array};
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::DataType;
+    use risingwave_common::{column, column_nonnull};
+    use serde_json::json;
+
+    use super::*;
+
+    fn test_chunk() -> StreamChunk {
+        StreamChunk::new(
+            vec![Op::Insert, Op::UpdateDelete, Op::UpdateInsert, Op::Delete],
+            vec![
+                column_nonnull!(I32Array, [1, 1, 1, 2]),
+                column!(I64Array, [Some(10), Some(10), None, Some(20)]),
+            ],
+            None,
+        )
+    }
+
+    fn test_schema() -> Schema {
+        Schema::new(vec![
+            Field::with_name(DataType::Int32, "id"),
+            Field::with_name(DataType::Int64, "v"),
+        ])
+    }
+
+    #[test]
+    fn test_format_upsert() {
+        let formatter = RecordFormatter::new(SinkFormat::Upsert, test_schema(), vec![0]);
+        let records = formatter.format_chunk(&test_chunk());
+        assert_eq!(
+            records,
+            vec![
+                SinkRecord {
+                    key: Some(json!({"id": 1})),
+                    value: Some(json!({"id": 1, "v": 10})),
+                },
+                SinkRecord {
+                    key: Some(json!({"id": 1})),
+                    value: Some(json!({"id": 1, "v": null})),
+                },
+                SinkRecord {
+                    key: Some(json!({"id": 2})),
+                    value: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_append_only() {
+        let formatter = RecordFormatter::new(SinkFormat::AppendOnly, test_schema(), vec![0]);
+        let records = formatter.format_chunk(&test_chunk());
+        assert_eq!(
+            records,
+            vec![
+                SinkRecord {
+                    key: None,
+                    value: Some(json!({"id": 1, "v": 10})),
+                },
+                SinkRecord {
+                    key: None,
+                    value: Some(json!({"id": 1, "v": null})),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sink_format_from_properties() {
+        let mut properties = HashMap::new();
+        assert_eq!(
+            SinkFormat::from_properties(&properties).unwrap(),
+            SinkFormat::Upsert
+        );
+        properties.insert(SINK_FORMAT_KEY.to_string(), "append_only".to_string());
+        assert_eq!(
+            SinkFormat::from_properties(&properties).unwrap(),
+            SinkFormat::AppendOnly
+        );
+        properties.insert(SINK_FORMAT_KEY.to_string(), "debezium".to_string());
+        assert!(SinkFormat::from_properties(&properties).is_err());
+    }
+}
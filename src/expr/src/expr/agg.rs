@@ -28,6 +28,7 @@ pub enum AggKind {
     Avg,
     StringAgg,
     SingleValue,
+    ArrayAgg,
 }
 
 impl std::fmt::Display for AggKind {
@@ -41,6 +42,7 @@ impl std::fmt::Display for AggKind {
             AggKind::Avg => write!(f, "avg"),
             AggKind::StringAgg => write!(f, "string_agg"),
             AggKind::SingleValue => write!(f, "single_value"),
+            AggKind::ArrayAgg => write!(f, "array_agg"),
         }
     }
 }
@@ -57,6 +59,7 @@ impl TryFrom<Type> for AggKind {
             Type::Count => Ok(AggKind::Count),
             Type::StringAgg => Ok(AggKind::StringAgg),
             Type::SingleValue => Ok(AggKind::SingleValue),
+            Type::ArrayAgg => Ok(AggKind::ArrayAgg),
             _ => Err(ErrorCode::InternalError("Unrecognized agg.".into()).into()),
         }
     }
@@ -72,6 +75,7 @@ impl AggKind {
             Self::Count => Type::Count,
             Self::StringAgg => Type::StringAgg,
             Self::SingleValue => Type::SingleValue,
+            Self::ArrayAgg => Type::ArrayAgg,
             Self::RowCount => {
                 panic!("cannot convert RowCount to prost, TODO: remove RowCount from AggKind")
             }
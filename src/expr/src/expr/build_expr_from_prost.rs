@@ -12,25 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::DataChunk;
+use risingwave_common::array::{BoolArray, DataChunk, Utf8Array};
 use risingwave_common::ensure;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_common::types::{DataType, ScalarImpl, ToOwnedDatum};
 use risingwave_pb::expr::expr_node::RexNode;
 use risingwave_pb::expr::{expr_node, ExprNode};
 
-use crate::expr::expr_binary_bytes::new_substr_start;
-use crate::expr::expr_binary_nonnull::{new_binary_expr, new_like_default};
+use crate::expr::expr_array::{ArrayAccessExpression, ArrayExpression, ArrayLengthExpression};
+use crate::expr::expr_binary_bytes::{new_substr_start, new_to_char};
+use crate::expr::expr_binary_nonnull::{new_binary_expr, new_ilike_default, new_like_default};
 use crate::expr::expr_binary_nullable::new_nullable_binary_expr;
 use crate::expr::expr_case::{CaseExpression, WhenClause};
+use crate::expr::expr_coalesce::CoalesceExpression;
+use crate::expr::expr_concat_ws::ConcatWsExpression;
+use crate::expr::expr_greatest_least::GreatestLeastExpression;
 use crate::expr::expr_in::InExpression;
+use crate::expr::expr_nullif::NullIfExpression;
+use crate::expr::expr_regexp::{
+    RegexpContext, RegexpMatchExpression, RegexpPattern, RegexpReplaceExpression,
+};
+use crate::expr::expr_struct::{FieldExpression, RowExpression};
 use crate::expr::expr_ternary_bytes::{new_replace_expr, new_substr_start_end, new_translate_expr};
+use crate::expr::expr_try_cast::TryCastExpression;
 use crate::expr::expr_unary::{
     new_length_default, new_ltrim_expr, new_rtrim_expr, new_trim_expr, new_unary_expr,
 };
-use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+use crate::expr::gen_random_uuid::GenRandomUuidExpression;
+use crate::expr::template::TernaryExpression;
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, LiteralExpression};
+use crate::sig::func::FUNC_SIG_MAP;
+use crate::sig::DataTypeName;
+use crate::vector_op::like::{ilike_escape, like_escape};
 
-fn get_return_type_and_children(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
+pub(crate) fn get_return_type_and_children(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
     let ret_type = DataType::from(prost.get_return_type()?);
     if let RexNode::FuncCall(func_call) = prost.get_rex_node()? {
         Ok((func_call.get_children().to_vec(), ret_type))
@@ -41,6 +56,24 @@ fn get_return_type_and_children(prost: &ExprNode) -> Result<(Vec<ExprNode>, Data
     }
 }
 
+/// Builds the call of a function in [`FUNC_SIG_MAP`] with the signature of the argument types.
+pub fn build_func_sig_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, _) = get_return_type_and_children(prost)?;
+    let inputs_type = children
+        .iter()
+        .map(|child| {
+            Ok(DataTypeName::from(&DataType::from(
+                child.get_return_type()?,
+            )))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let func = prost.get_expr_type()?;
+    let sig = FUNC_SIG_MAP.get(func, &inputs_type).ok_or_else(|| {
+        ErrorCode::InternalError(format!("Unsupported function: {:?}{:?}", func, inputs_type))
+    })?;
+    (sig.build)(prost)
+}
+
 pub fn build_unary_expr_prost(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     ensure!(children.len() == 1);
@@ -90,6 +123,14 @@ pub fn build_substr_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     }
 }
 
+pub fn build_to_char_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    let timestamp = expr_build_from_prost(&children[0])?;
+    let pattern = expr_build_from_prost(&children[1])?;
+    Ok(new_to_char(timestamp, pattern, ret_type))
+}
+
 pub fn build_trim_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     // TODO: add expr with the delimiter parameter
@@ -131,38 +172,189 @@ pub fn build_length_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     Ok(new_length_default(child, ret_type))
 }
 
+/// Builds `LIKE` or `ILIKE`, with an optional third argument of the escape character.
 pub fn build_like_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
-    ensure!(children.len() == 2);
+    ensure!(children.len() == 2 || children.len() == 3);
+    let case_insensitive = prost.get_expr_type()? == expr_node::Type::Ilike;
     let expr_ia1 = expr_build_from_prost(&children[0])?;
     let expr_ia2 = expr_build_from_prost(&children[1])?;
-    Ok(new_like_default(expr_ia1, expr_ia2, ret_type))
+    if children.len() == 2 {
+        return Ok(if case_insensitive {
+            new_ilike_default(expr_ia1, expr_ia2, ret_type)
+        } else {
+            new_like_default(expr_ia1, expr_ia2, ret_type)
+        });
+    }
+    let escape = expr_build_from_prost(&children[2])?;
+    let func: fn(&str, &str, &str) -> Result<bool> = if case_insensitive {
+        ilike_escape
+    } else {
+        like_escape
+    };
+    Ok(Box::new(TernaryExpression::<
+        Utf8Array,
+        Utf8Array,
+        Utf8Array,
+        BoolArray,
+        _,
+    >::new(
+        expr_ia1, expr_ia2, escape, ret_type, func
+    )))
+}
+
+pub fn build_concat_ws_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(!children.is_empty());
+    let separator = expr_build_from_prost(&children[0])?;
+    let strings = children[1..]
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(ConcatWsExpression::new(
+        separator, strings, ret_type,
+    )))
+}
+
+/// Builds the pattern and the optional flags of a regular expression function, which are compiled
+/// here if they are constant.
+fn build_regexp_pattern(pattern: &ExprNode, flags: Option<&ExprNode>) -> Result<RegexpPattern> {
+    let is_constant =
+        |node: &ExprNode| matches!(node.get_expr_type(), Ok(expr_node::Type::ConstantValue));
+    if !is_constant(pattern) || !flags.map_or(true, is_constant) {
+        return Ok(RegexpPattern::Dynamic {
+            pattern: expr_build_from_prost(pattern)?,
+            flags: flags.map(expr_build_from_prost).transpose()?,
+        });
+    }
+    let pattern = LiteralExpression::try_from(pattern)?.literal();
+    let flags = match flags {
+        Some(flags) => LiteralExpression::try_from(flags)?.literal(),
+        None => Some(ScalarImpl::Utf8(String::new())),
+    };
+    match (pattern, flags) {
+        (Some(ScalarImpl::Utf8(pattern)), Some(ScalarImpl::Utf8(flags))) => Ok(
+            RegexpPattern::Constant(Some(RegexpContext::new(&pattern, &flags)?)),
+        ),
+        _ => Ok(RegexpPattern::Constant(None)),
+    }
+}
+
+pub fn build_regexp_match_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let string = expr_build_from_prost(&children[0])?;
+    let pattern = build_regexp_pattern(&children[1], children.get(2))?;
+    Ok(Box::new(RegexpMatchExpression::new(
+        string, pattern, ret_type,
+    )))
+}
+
+pub fn build_regexp_replace_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3 || children.len() == 4);
+    let string = expr_build_from_prost(&children[0])?;
+    let pattern = build_regexp_pattern(&children[1], children.get(3))?;
+    let replacement = expr_build_from_prost(&children[2])?;
+    Ok(Box::new(RegexpReplaceExpression::new(
+        string,
+        pattern,
+        replacement,
+        ret_type,
+    )))
 }
 
 pub fn build_in_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     ensure!(ret_type == DataType::Boolean);
-    ensure!(children[0].get_expr_type()? == expr_node::Type::InputRef);
-    let input_ref_expr = expr_build_from_prost(&children[0])?;
-    for child in &children[1..] {
-        ensure!(child.get_expr_type()? == expr_node::Type::ConstantValue);
-    }
+    ensure!(!children.is_empty());
+    let input_expr = expr_build_from_prost(&children[0])?;
+    // The values of the set are constant expressions, which are evaluated once here.
     let mut data = Vec::new();
-    // Used for literal expression below to generate datum
     let data_chunk = DataChunk::new_dummy(1);
     for child in &children[1..] {
-        let literal_expr = expr_build_from_prost(child)?;
-        let array = literal_expr.eval(&data_chunk)?;
+        ensure!(child.get_expr_type()? != expr_node::Type::InputRef);
+        let const_expr = expr_build_from_prost(child)?;
+        let array = const_expr.eval(&data_chunk)?;
         let datum = array.value_at(0).to_owned_datum();
         data.push(datum);
     }
     Ok(Box::new(InExpression::new(
-        input_ref_expr,
+        input_expr,
         data.into_iter(),
         ret_type,
     )))
 }
 
+pub fn build_try_cast_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 1);
+    let child = expr_build_from_prost(&children[0])?;
+    Ok(Box::new(TryCastExpression::new(child, ret_type)?))
+}
+
+pub fn build_gen_random_uuid_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, _) = get_return_type_and_children(prost)?;
+    ensure!(children.is_empty());
+    Ok(Box::new(GenRandomUuidExpression::new()))
+}
+
+pub fn build_array_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(matches!(ret_type, DataType::List { .. }));
+    let elements = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(ArrayExpression::new(elements, ret_type)))
+}
+
+pub fn build_array_access_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    let array = expr_build_from_prost(&children[0])?;
+    let index = expr_build_from_prost(&children[1])?;
+    Ok(Box::new(ArrayAccessExpression::new(array, index, ret_type)))
+}
+
+pub fn build_array_length_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    ensure!(ret_type == DataType::Int32);
+    let array = expr_build_from_prost(&children[0])?;
+    let dimension = expr_build_from_prost(&children[1])?;
+    Ok(Box::new(ArrayLengthExpression::new(array, dimension)))
+}
+
+pub fn build_row_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(matches!(&ret_type, DataType::Struct { fields, .. } if fields.len() == children.len()));
+    let fields = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(RowExpression::new(fields, ret_type)))
+}
+
+pub fn build_field_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    let input = expr_build_from_prost(&children[0])?;
+    // The index of the field is a constant.
+    let index = match LiteralExpression::try_from(&children[1])?.literal() {
+        Some(ScalarImpl::Int32(index)) => index as usize,
+        _ => {
+            return Err(
+                ErrorCode::InternalError("the field index must be an integer".to_string()).into(),
+            )
+        }
+    };
+    ensure!(
+        matches!(input.return_type(), DataType::Struct { fields, .. } if fields.get(index) == Some(&ret_type))
+    );
+    Ok(Box::new(FieldExpression::new(input, index, ret_type)))
+}
+
 pub fn build_case_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     // children: (when, then)+, (else_clause)?
@@ -204,6 +396,37 @@ pub fn build_case_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     )))
 }
 
+pub fn build_coalesce_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(!children.is_empty());
+    let children = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(CoalesceExpression::new(ret_type, children)))
+}
+
+pub fn build_nullif_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    let left = expr_build_from_prost(&children[0])?;
+    let right = expr_build_from_prost(&children[1])?;
+    Ok(Box::new(NullIfExpression::new(ret_type, left, right)))
+}
+
+pub fn build_greatest_least_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(!children.is_empty());
+    let children = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    let greatest = prost.get_expr_type()? == expr_node::Type::Greatest;
+    Ok(Box::new(GreatestLeastExpression::new(
+        ret_type, children, greatest,
+    )))
+}
+
 pub fn build_translate_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     ensure!(children.len() == 3);
@@ -223,6 +446,7 @@ mod tests {
     use risingwave_pb::expr::{ConstantValue, ExprNode, FunctionCall, InputRefExpr};
 
     use super::*;
+    use crate::expr::test_utils::make_expression;
 
     #[test]
     fn test_build_in_expr() {
@@ -359,4 +583,13 @@ mod tests {
         };
         assert!(build_binary_expr_prost(&expr).is_ok());
     }
+
+    #[test]
+    fn test_build_func_sig_expr() {
+        let expr = make_expression(Type::Pow, &[TypeName::Decimal, TypeName::Decimal], &[0, 1]);
+        assert!(build_func_sig_expr(&expr).is_ok());
+        // There is no signature of `pow` that mixes `numeric` and `double precision`.
+        let expr = make_expression(Type::Pow, &[TypeName::Decimal, TypeName::Double], &[0, 1]);
+        assert!(build_func_sig_expr(&expr).is_err());
+    }
 }
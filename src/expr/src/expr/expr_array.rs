@@ -0,0 +1,225 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{ArrayRef, DataChunk, ListRef, ListValue};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, ScalarImpl, ScalarRefImpl};
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `ArrayExpression` constructs a list from the values of its children, i.e. `ARRAY[e1, e2, ...]`.
+#[derive(Debug)]
+pub struct ArrayExpression {
+    elements: Vec<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl ArrayExpression {
+    pub fn new(elements: Vec<BoxedExpression>, return_type: DataType) -> Self {
+        Self {
+            elements,
+            return_type,
+        }
+    }
+}
+
+impl Expression for ArrayExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays: Vec<ArrayRef> = self.elements.iter().map(|e| e.eval(input)).try_collect()?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        // The arrays of the children only contain the visible rows.
+        for idx in 0..input.cardinality() {
+            let values = arrays.iter().map(|array| array.datum_at(idx)).collect();
+            builder.append_datum(&Some(ListValue::new(values).into()))?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// `ArrayAccessExpression` returns the element of a list at an 1-based index, i.e. `array[index]`.
+/// NULL is returned if the index is out of range.
+#[derive(Debug)]
+pub struct ArrayAccessExpression {
+    array: BoxedExpression,
+    index: BoxedExpression,
+    return_type: DataType,
+}
+
+impl ArrayAccessExpression {
+    pub fn new(array: BoxedExpression, index: BoxedExpression, return_type: DataType) -> Self {
+        Self {
+            array,
+            index,
+            return_type,
+        }
+    }
+}
+
+impl Expression for ArrayAccessExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let array = self.array.eval(input)?;
+        let index = self.index.eval(input)?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let element = match (array.value_at(idx), index.value_at(idx)) {
+                (Some(ScalarRefImpl::List(list)), Some(ScalarRefImpl::Int32(index))) => {
+                    let values = list.values_ref();
+                    if index >= 1 && index as usize <= values.len() {
+                        values[index as usize - 1]
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            builder.append_datum_ref(element)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// `ArrayLengthExpression` returns the length of the requested dimension of a list, i.e.
+/// `ARRAY_LENGTH(array, dimension)`. NULL is returned if the list is empty or the dimension does
+/// not exist.
+#[derive(Debug)]
+pub struct ArrayLengthExpression {
+    array: BoxedExpression,
+    dimension: BoxedExpression,
+}
+
+impl ArrayLengthExpression {
+    pub fn new(array: BoxedExpression, dimension: BoxedExpression) -> Self {
+        Self { array, dimension }
+    }
+
+    fn array_length(list: ListRef<'_>, dimension: i32) -> Datum {
+        let values = list.values_ref();
+        if values.is_empty() || dimension < 1 {
+            return None;
+        }
+        if dimension == 1 {
+            return Some(ScalarImpl::Int32(values.len() as i32));
+        }
+        match values[0] {
+            Some(ScalarRefImpl::List(inner)) => Self::array_length(inner, dimension - 1),
+            _ => None,
+        }
+    }
+}
+
+impl Expression for ArrayLengthExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Int32
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let array = self.array.eval(input)?;
+        let dimension = self.dimension.eval(input)?;
+        let mut builder = DataType::Int32.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let length = match (array.value_at(idx), dimension.value_at(idx)) {
+                (Some(ScalarRefImpl::List(list)), Some(ScalarRefImpl::Int32(dimension))) => {
+                    Self::array_length(list, dimension)
+                }
+                _ => None,
+            };
+            builder.append_datum(&length)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::I32Array;
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    fn int_list_type() -> DataType {
+        DataType::List {
+            datatype: Box::new(DataType::Int32),
+        }
+    }
+
+    #[test]
+    fn test_array_expr() {
+        let column = column! {I32Array, [Some(1), None, Some(3)]};
+        let data_chunk = DataChunk::builder().columns(vec![column]).build();
+        let array = ArrayExpression::new(
+            vec![
+                Box::new(InputRefExpression::new(DataType::Int32, 0)),
+                Box::new(LiteralExpression::new(
+                    DataType::Int32,
+                    Some(ScalarImpl::Int32(42)),
+                )),
+            ],
+            int_list_type(),
+        );
+        let res = array.eval(&data_chunk).unwrap();
+        let expected = [
+            ListValue::new(vec![Some(1.into()), Some(42.into())]),
+            ListValue::new(vec![None, Some(42.into())]),
+            ListValue::new(vec![Some(3.into()), Some(42.into())]),
+        ];
+        for (i, list) in expected.into_iter().enumerate() {
+            assert_eq!(res.datum_at(i), Some(list.into()));
+        }
+    }
+
+    #[test]
+    fn test_array_access_and_length() {
+        let list = || {
+            Box::new(LiteralExpression::new(
+                int_list_type(),
+                Some(ListValue::new(vec![Some(1.into()), Some(2.into())]).into()),
+            ))
+        };
+        let column = column! {I32Array, [Some(0), Some(1), Some(2), Some(3), None]};
+        let data_chunk = DataChunk::builder().columns(vec![column]).build();
+
+        let access = ArrayAccessExpression::new(
+            list(),
+            Box::new(InputRefExpression::new(DataType::Int32, 0)),
+            DataType::Int32,
+        );
+        let res = access.eval(&data_chunk).unwrap();
+        let expected = [None, Some(1), Some(2), None, None];
+        for (i, value) in expected.into_iter().enumerate() {
+            assert_eq!(res.datum_at(i), value.map(ScalarImpl::Int32));
+        }
+
+        let length = ArrayLengthExpression::new(
+            list(),
+            Box::new(InputRefExpression::new(DataType::Int32, 0)),
+        );
+        let res = length.eval(&data_chunk).unwrap();
+        let expected = [None, Some(2), None, None, None];
+        for (i, value) in expected.into_iter().enumerate() {
+            assert_eq!(res.datum_at(i), value.map(ScalarImpl::Int32));
+        }
+    }
+}
@@ -14,12 +14,13 @@
 
 //! For expression that only accept two arguments + 1 bytes writer as input.
 
-use risingwave_common::array::{I32Array, Utf8Array};
+use risingwave_common::array::{I32Array, NaiveDateTimeArray, Utf8Array};
 use risingwave_common::types::DataType;
 
 use crate::expr::template::BinaryBytesExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::substr::*;
+use crate::vector_op::to_char::to_char_timestamp;
 
 pub fn new_substr_start(
     expr_ia1: BoxedExpression,
@@ -48,6 +49,21 @@ pub fn new_substr_for(
     ))
 }
 
+pub fn new_to_char(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        BinaryBytesExpression::<NaiveDateTimeArray, Utf8Array, _>::new(
+            expr_ia1,
+            expr_ia2,
+            return_type,
+            to_char_timestamp,
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::DataChunk;
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, IntervalArray, NaiveDateArray, NaiveDateTimeArray,
-    Utf8Array,
+    Array, BoolArray, BytesArray, DecimalArray, I32Array, IntervalArray, JsonbArray,
+    NaiveDateArray, NaiveDateTimeArray, Utf8Array,
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
@@ -25,10 +25,14 @@ use crate::expr::template::BinaryExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::*;
 use crate::vector_op::cmp::*;
+use crate::vector_op::date_trunc::date_trunc;
+use crate::vector_op::encode::{decode, encode};
 use crate::vector_op::extract::{extract_from_date, extract_from_timestamp};
-use crate::vector_op::like::like_default;
+use crate::vector_op::jsonb_op::jsonb_contains;
+use crate::vector_op::like::{ilike_default, like_default};
 use crate::vector_op::position::position;
 use crate::vector_op::round::round_digits;
+use crate::vector_op::to_timestamp::to_timestamp;
 use crate::vector_op::tumble::{tumble_start_date, tumble_start_date_time};
 
 /// A placeholder function that returns bool in [`gen_binary_expr_atm`]
@@ -124,6 +128,16 @@ macro_rules! gen_binary_expr_cmp {
                     $l, $r, $ret, $str_f,
                 ))
             }
+            (DataType::Bytea, DataType::Bytea) => {
+                Box::new(
+                    BinaryExpression::<BytesArray, BytesArray, BoolArray, _>::new(
+                        $l,
+                        $r,
+                        $ret,
+                        |l: &[u8], r: &[u8]| $general_f::<_, _, &[u8]>(l, r),
+                    ),
+                )
+            }
             _ => {
                 $macro! {
                     [$l, $r, $ret],
@@ -167,7 +181,8 @@ macro_rules! gen_binary_expr_cmp {
                     { date, date, date, $general_f },
                     { boolean, boolean, boolean, $general_f },
                     { timestamp, date, timestamp, $general_f },
-                    { date, timestamp, timestamp, $general_f }
+                    { date, timestamp, timestamp, $general_f },
+                    { uuid, uuid, uuid, $general_f }
                 }
             }
         }
@@ -244,6 +259,7 @@ macro_rules! gen_binary_expr_atm {
             $({ date, date, int32, $date_date_f },)?
             $({ date, interval, timestamp, $date_interval_f },)?
             $({ interval, date, timestamp, $interval_date_f },)?
+            $({ interval, interval, interval, $interval_interval_f },)?
             $({ interval, int16, interval, $interval_int_f },)?
             $({ interval, int32, interval, $interval_int_f },)?
             $({ interval, int64, interval, $interval_int_f },)?
@@ -361,6 +377,24 @@ pub fn new_binary_expr(
             }
         }
         Type::Extract => build_extract_expr(ret, l, r),
+        Type::DateTrunc => Box::new(BinaryExpression::<
+            Utf8Array,
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, date_trunc)),
+        Type::ToTimestamp => Box::new(BinaryExpression::<
+            Utf8Array,
+            Utf8Array,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, to_timestamp)),
+        Type::Age => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            IntervalArray,
+            _,
+        >::new(l, r, ret, timestamp_timestamp_age)),
         Type::RoundDigit => Box::new(
             BinaryExpression::<DecimalArray, I32Array, DecimalArray, _>::new(
                 l,
@@ -373,6 +407,20 @@ pub fn new_binary_expr(
             l, r, ret, position,
         )),
         Type::TumbleStart => new_tumble_start(l, r, ret),
+        Type::JsonbContains => Box::new(
+            BinaryExpression::<JsonbArray, JsonbArray, BoolArray, _>::new(
+                l,
+                r,
+                ret,
+                jsonb_contains,
+            ),
+        ),
+        Type::Encode => Box::new(
+            BinaryExpression::<BytesArray, Utf8Array, Utf8Array, _>::new(l, r, ret, encode),
+        ),
+        Type::Decode => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, BytesArray, _>::new(l, r, ret, decode),
+        ),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
@@ -424,6 +472,19 @@ pub fn new_like_default(
     ))
 }
 
+pub fn new_ilike_default(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        ilike_default,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -15,7 +15,8 @@
 //! For expression that only accept two nullable arguments as input.
 
 use risingwave_common::array::{
-    BoolArray, DecimalArray, F32Array, F64Array, I16Array, I32Array, I64Array,
+    Array, BoolArray, BytesArray, DecimalArray, F32Array, F64Array, I16Array, I32Array, I64Array,
+    JsonbArray, Utf8Array,
 };
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
@@ -23,7 +24,123 @@ use risingwave_pb::expr::expr_node::Type;
 
 use super::BoxedExpression;
 use crate::expr::template::BinaryNullableExpression;
+use crate::vector_op::cmp::{
+    general_is_distinct_from, general_is_not_distinct_from, str_is_distinct_from,
+    str_is_not_distinct_from,
+};
 use crate::vector_op::conjunction::{and, or};
+use crate::vector_op::jsonb_op::{
+    jsonb_access_field, jsonb_access_field_str, jsonb_access_index, jsonb_access_index_str,
+    jsonb_access_path, jsonb_access_path_str,
+};
+
+/// This macro helps create null-safe comparison expression. Its output array is a bool array.
+/// Similar to `gen_cmp_impl` in `expr_binary_nonnull`, but the scalar function also takes NULLs.
+macro_rules! gen_nullable_cmp_impl {
+    ([$l:expr, $r:expr, $ret:expr], $( { $i1:ident, $i2:ident, $cast:ident, $func:ident} ),*) => {
+        match ($l.return_type(), $r.return_type()) {
+            $(
+                ($i1! { type_match_pattern }, $i2! { type_match_pattern }) => {
+                    Box::new(
+                        BinaryNullableExpression::<
+                            $i1! { type_array },
+                            $i2! { type_array },
+                            BoolArray,
+                            _
+                        >::new(
+                            $l,
+                            $r,
+                            $ret,
+                            $func::<
+                                <$i1! { type_array } as Array>::OwnedItem,
+                                <$i2! { type_array } as Array>::OwnedItem,
+                                <$cast! { type_array } as Array>::OwnedItem
+                            >,
+                        )
+                    )
+                }
+            ),*
+            _ => {
+                unimplemented!("The expression ({:?}, {:?}) using vectorized expression framework is not supported yet!", $l.return_type(), $r.return_type())
+            }
+        }
+    };
+}
+
+/// Based on the data type of `$l`, `$r`, return null-safe comparison expression struct with
+/// scalar function inside. The supported types are the same as `gen_binary_expr_cmp`.
+/// * `general_f`: generic cmp function (require a common ``TryInto`` type for two input).
+/// * `str_f`: cmp function between str
+macro_rules! gen_binary_expr_nullable_cmp {
+    ($general_f:ident, $str_f:ident, $l:expr, $r:expr, $ret:expr) => {
+        match ($l.return_type(), $r.return_type()) {
+            (DataType::Varchar, DataType::Varchar) => Box::new(BinaryNullableExpression::<
+                Utf8Array,
+                Utf8Array,
+                BoolArray,
+                _,
+            >::new($l, $r, $ret, $str_f)),
+            (DataType::Bytea, DataType::Bytea) => Box::new(BinaryNullableExpression::<
+                BytesArray,
+                BytesArray,
+                BoolArray,
+                _,
+            >::new(
+                $l,
+                $r,
+                $ret,
+                |l: Option<&[u8]>, r: Option<&[u8]>| $general_f::<_, _, &[u8]>(l, r),
+            )),
+            _ => {
+                gen_nullable_cmp_impl! {
+                    [$l, $r, $ret],
+                    { int16, int16, int16, $general_f },
+                    { int16, int32, int32, $general_f },
+                    { int16, int64, int64, $general_f },
+                    { int16, float32, float64, $general_f },
+                    { int16, float64, float64, $general_f },
+                    { int32, int16, int32, $general_f },
+                    { int32, int32, int32, $general_f },
+                    { int32, int64, int64, $general_f },
+                    { int32, float32, float64, $general_f },
+                    { int32, float64, float64, $general_f },
+                    { int64, int16,int64, $general_f },
+                    { int64, int32,int64, $general_f },
+                    { int64, int64, int64, $general_f },
+                    { int64, float32, float64 , $general_f},
+                    { int64, float64, float64, $general_f },
+                    { float32, int16, float64, $general_f },
+                    { float32, int32, float64, $general_f },
+                    { float32, int64, float64 , $general_f},
+                    { float32, float32, float32, $general_f },
+                    { float32, float64, float64, $general_f },
+                    { float64, int16, float64, $general_f },
+                    { float64, int32, float64, $general_f },
+                    { float64, int64, float64, $general_f },
+                    { float64, float32, float64, $general_f },
+                    { float64, float64, float64, $general_f },
+                    { decimal, int16, decimal, $general_f },
+                    { decimal, int32, decimal, $general_f },
+                    { decimal, int64, decimal, $general_f },
+                    { decimal, float32, float64, $general_f },
+                    { decimal, float64, float64, $general_f },
+                    { int16, decimal, decimal, $general_f },
+                    { int32, decimal, decimal, $general_f },
+                    { int64, decimal, decimal, $general_f },
+                    { decimal, decimal, decimal, $general_f },
+                    { float32, decimal, float64, $general_f },
+                    { float64, decimal, float64, $general_f },
+                    { timestamp, timestamp, timestamp, $general_f },
+                    { date, date, date, $general_f },
+                    { boolean, boolean, boolean, $general_f },
+                    { timestamp, date, timestamp, $general_f },
+                    { date, timestamp, timestamp, $general_f },
+                    { uuid, uuid, uuid, $general_f }
+                }
+            }
+        }
+    };
+}
 
 // TODO: consider implement it using generic function.
 macro_rules! gen_stream_null_by_row_count_expr {
@@ -61,7 +178,15 @@ pub fn new_nullable_binary_expr(
     l: BoxedExpression,
     r: BoxedExpression,
 ) -> BoxedExpression {
+    use crate::expr::data_types::*;
+
     match expr_type {
+        Type::IsDistinctFrom => {
+            gen_binary_expr_nullable_cmp! {general_is_distinct_from, str_is_distinct_from, l, r, ret}
+        }
+        Type::IsNotDistinctFrom => {
+            gen_binary_expr_nullable_cmp! {general_is_not_distinct_from, str_is_not_distinct_from, l, r, ret}
+        }
         Type::StreamNullByRowCount => match l.return_type() {
             DataType::Int64 => match r.return_type() {
                 DataType::Boolean => gen_stream_null_by_row_count_expr!(l, r, ret, BoolArray),
@@ -93,6 +218,54 @@ pub fn new_nullable_binary_expr(
         Type::Or => Box::new(
             BinaryNullableExpression::<BoolArray, BoolArray, BoolArray, _>::new(l, r, ret, or),
         ),
+        Type::JsonbAccessInner => match r.return_type() {
+            DataType::Varchar => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_access_field)),
+            DataType::Int32 => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_access_index)),
+            tp => unimplemented!(
+                "The key of jsonb access must be text or integer but not {:?}",
+                tp
+            ),
+        },
+        Type::JsonbAccessStr => match r.return_type() {
+            DataType::Varchar => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_access_field_str)),
+            DataType::Int32 => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_access_index_str)),
+            tp => unimplemented!(
+                "The key of jsonb access must be text or integer but not {:?}",
+                tp
+            ),
+        },
+        Type::JsonbAccessPath => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            Utf8Array,
+            JsonbArray,
+            _,
+        >::new(l, r, ret, jsonb_access_path)),
+        Type::JsonbAccessPathStr => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            Utf8Array,
+            Utf8Array,
+            _,
+        >::new(l, r, ret, jsonb_access_path_str)),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
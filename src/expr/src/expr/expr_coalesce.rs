@@ -0,0 +1,132 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `CoalesceExpression` returns the first non-NULL argument, i.e. `COALESCE(e1, e2, ...)`. An
+/// argument is only evaluated if some rows are still NULL after the arguments before it.
+#[derive(Debug)]
+pub struct CoalesceExpression {
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+}
+
+impl CoalesceExpression {
+    pub fn new(return_type: DataType, children: Vec<BoxedExpression>) -> Self {
+        Self {
+            return_type,
+            children,
+        }
+    }
+}
+
+impl Expression for CoalesceExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        // The index of the evaluated argument which the value of each row is taken from.
+        let mut selection: Vec<Option<usize>> = vec![None; input.cardinality()];
+        let mut remaining = input.cardinality();
+        let mut arrays = Vec::with_capacity(self.children.len());
+        for child in &self.children {
+            if remaining == 0 {
+                break;
+            }
+            let array = child.eval(input)?;
+            for (idx, selected) in selection.iter_mut().enumerate() {
+                if selected.is_none() && array.value_at(idx).is_some() {
+                    *selected = Some(arrays.len());
+                    remaining -= 1;
+                }
+            }
+            arrays.push(array);
+        }
+
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for (idx, selected) in selection.into_iter().enumerate() {
+            match selected {
+                Some(i) => builder.append_datum_ref(arrays[i].value_at(idx))?,
+                None => builder.append_null()?,
+            }
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::I32Array;
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    #[test]
+    fn test_coalesce() {
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                column! {I32Array, [Some(1), None, None, None]},
+                column! {I32Array, [Some(2), Some(3), None, None]},
+                column! {I32Array, [None, Some(4), Some(5), None]},
+            ])
+            .build();
+        let coalesce = CoalesceExpression::new(
+            DataType::Int32,
+            (0..3)
+                .map(|i| Box::new(InputRefExpression::new(DataType::Int32, i)) as BoxedExpression)
+                .collect(),
+        );
+        let res = coalesce.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(1.into()));
+        assert_eq!(res.datum_at(1), Some(3.into()));
+        assert_eq!(res.datum_at(2), Some(5.into()));
+        assert_eq!(res.datum_at(3), None);
+    }
+
+    #[test]
+    fn test_coalesce_short_circuit() {
+        // The second argument is never evaluated, as all the rows are resolved by the first one.
+        let data_chunk = DataChunk::builder()
+            .columns(vec![column! {I32Array, [Some(1), Some(2)]}])
+            .build();
+        let coalesce = CoalesceExpression::new(
+            DataType::Int32,
+            vec![
+                Box::new(InputRefExpression::new(DataType::Int32, 0)),
+                Box::new(InputRefExpression::new(DataType::Int32, 1)),
+            ],
+        );
+        let res = coalesce.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(1.into()));
+        assert_eq!(res.datum_at(1), Some(2.into()));
+
+        let coalesce = CoalesceExpression::new(
+            DataType::Int32,
+            vec![
+                Box::new(LiteralExpression::new(DataType::Int32, None)),
+                Box::new(InputRefExpression::new(DataType::Int32, 0)),
+            ],
+        );
+        let res = coalesce.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(1.into()));
+    }
+}
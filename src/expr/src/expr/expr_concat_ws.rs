@@ -0,0 +1,125 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, Utf8Array, Utf8ArrayBuilder,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `ConcatWsExpression` joins the string arguments with the separator, i.e.
+/// `CONCAT_WS(separator, s1, s2, ...)`. The NULL arguments are skipped, and the result is NULL
+/// only if the separator is NULL.
+#[derive(Debug)]
+pub struct ConcatWsExpression {
+    separator: BoxedExpression,
+    strings: Vec<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl ConcatWsExpression {
+    pub fn new(
+        separator: BoxedExpression,
+        strings: Vec<BoxedExpression>,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            separator,
+            strings,
+            return_type,
+        }
+    }
+}
+
+impl Expression for ConcatWsExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let separator = self.separator.eval(input)?;
+        let separator: &Utf8Array = separator.as_ref().into();
+        let strings: Vec<ArrayRef> = self.strings.iter().map(|e| e.eval(input)).try_collect()?;
+        let strings: Vec<&Utf8Array> = strings.iter().map(|array| array.as_ref().into()).collect();
+
+        let mut builder = Utf8ArrayBuilder::new(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let separator = match separator.value_at(idx) {
+                Some(separator) => separator,
+                None => {
+                    builder.append(None)?;
+                    continue;
+                }
+            };
+            let mut writer = builder.writer().begin();
+            for (i, s) in strings
+                .iter()
+                .filter_map(|array| array.value_at(idx))
+                .enumerate()
+            {
+                if i > 0 {
+                    writer.write_ref(separator)?;
+                }
+                writer.write_ref(s)?;
+            }
+            builder = writer.finish()?.into_inner();
+        }
+        Ok(Arc::new(ArrayImpl::from(builder.finish()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::column;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    #[test]
+    fn test_concat_ws() {
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                column! {Utf8Array, [Some("a"), None, Some("c")]},
+                column! {Utf8Array, [Some("b"), Some("e"), None]},
+            ])
+            .build();
+        let concat_ws = |separator: Option<&str>| {
+            ConcatWsExpression::new(
+                Box::new(LiteralExpression::new(
+                    DataType::Varchar,
+                    separator.map(|s| ScalarImpl::Utf8(s.to_string())),
+                )),
+                vec![
+                    Box::new(InputRefExpression::new(DataType::Varchar, 0)),
+                    Box::new(InputRefExpression::new(DataType::Varchar, 1)),
+                ],
+                DataType::Varchar,
+            )
+        };
+
+        let res = concat_ws(Some(",")).eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some("a,b".to_string().into()));
+        assert_eq!(res.datum_at(1), Some("e".to_string().into()));
+        assert_eq!(res.datum_at(2), Some("c".to_string().into()));
+
+        let res = concat_ws(None).eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), None);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `GreatestLeastExpression` returns the largest or the smallest argument, i.e.
+/// `GREATEST(e1, e2, ...)` or `LEAST(e1, e2, ...)`. The NULL arguments are ignored, and the result
+/// is NULL only if all the arguments are NULL.
+#[derive(Debug)]
+pub struct GreatestLeastExpression {
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+    /// Whether it's `GREATEST` or `LEAST`.
+    greatest: bool,
+}
+
+impl GreatestLeastExpression {
+    pub fn new(return_type: DataType, children: Vec<BoxedExpression>, greatest: bool) -> Self {
+        Self {
+            return_type,
+            children,
+            greatest,
+        }
+    }
+}
+
+impl Expression for GreatestLeastExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays: Vec<ArrayRef> = self.children.iter().map(|e| e.eval(input)).try_collect()?;
+
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let values = arrays.iter().filter_map(|array| array.value_at(idx));
+            let value = if self.greatest {
+                values.max()
+            } else {
+                values.min()
+            };
+            builder.append_datum_ref(value)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::I32Array;
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    #[test]
+    fn test_greatest_least() {
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                column! {I32Array, [Some(1), None, Some(3), None]},
+                column! {I32Array, [Some(2), Some(4), Some(-1), None]},
+            ])
+            .build();
+        let expr = |greatest| {
+            GreatestLeastExpression::new(
+                DataType::Int32,
+                vec![
+                    Box::new(InputRefExpression::new(DataType::Int32, 0)),
+                    Box::new(InputRefExpression::new(DataType::Int32, 1)),
+                ],
+                greatest,
+            )
+        };
+
+        let res = expr(true).eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(2.into()));
+        assert_eq!(res.datum_at(1), Some(4.into()));
+        assert_eq!(res.datum_at(2), Some(3.into()));
+        assert_eq!(res.datum_at(3), None);
+
+        let res = expr(false).eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(1.into()));
+        assert_eq!(res.datum_at(1), Some(4.into()));
+        assert_eq!(res.datum_at(2), Some((-1).into()));
+        assert_eq!(res.datum_at(3), None);
+    }
+}
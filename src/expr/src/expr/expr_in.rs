@@ -26,6 +26,8 @@ use crate::expr::{BoxedExpression, Expression};
 pub(crate) struct InExpression {
     input_ref: BoxedExpression,
     set: HashSet<Datum>,
+    /// Whether the values contain a NULL, which is kept out of `set`.
+    has_null: bool,
     return_type: DataType,
 }
 
@@ -36,18 +38,35 @@ impl InExpression {
         return_type: DataType,
     ) -> Self {
         let mut sarg = HashSet::new();
+        let mut has_null = false;
         for datum in data {
-            sarg.insert(datum);
+            match datum {
+                Some(_) => {
+                    sarg.insert(datum);
+                }
+                None => has_null = true,
+            }
         }
         Self {
             input_ref,
             set: sarg,
+            has_null,
             return_type,
         }
     }
 
-    fn exists(&self, datum: &Datum) -> bool {
-        self.set.contains(datum)
+    /// Follows the SQL semantics, where the result is NULL if the input is NULL, or if it is not
+    /// found but there is a NULL in the values.
+    fn exists(&self, datum: &Datum) -> Option<bool> {
+        if datum.is_none() {
+            None
+        } else if self.set.contains(datum) {
+            Some(true)
+        } else if self.has_null {
+            None
+        } else {
+            Some(false)
+        }
     }
 }
 
@@ -67,13 +86,13 @@ impl Expression for InExpression {
                         continue;
                     }
                     let ret = self.exists(&data.to_owned_datum());
-                    output_array.append(Some(ret))?;
+                    output_array.append(ret)?;
                 }
             }
             None => {
                 for data in input_array.iter() {
                     let ret = self.exists(&data.to_owned_datum());
-                    output_array.append(Some(ret))?;
+                    output_array.append(ret)?;
                 }
             }
         };
@@ -106,4 +125,17 @@ mod tests {
         assert_eq!(res.datum_at(2), Some(ScalarImpl::Bool(true)));
         assert_eq!(res.datum_at(3), Some(ScalarImpl::Bool(true)));
     }
+
+    #[test]
+    fn test_search_expr_with_null() {
+        let input_ref = Box::new(InputRefExpression::new(DataType::Varchar, 0));
+        let data = vec![Some(ScalarImpl::Utf8("abc".to_string())), None];
+        let search_expr = InExpression::new(input_ref, data.into_iter(), DataType::Boolean);
+        let column = column! {Utf8Array, [Some("abc"), Some("a"), None]};
+        let data_chunk = DataChunk::builder().columns(vec![column]).build();
+        let res = search_expr.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Bool(true)));
+        assert_eq!(res.datum_at(1), None);
+        assert_eq!(res.datum_at(2), None);
+    }
 }
@@ -23,7 +23,10 @@ use risingwave_common::array::{
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, Datum, Decimal, IntervalUnit, Scalar, ScalarImpl};
+use risingwave_common::types::{
+    DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
+    NaiveTimeWrapper, Scalar, ScalarImpl, Uuid,
+};
 use risingwave_common::{ensure, for_all_variants};
 use risingwave_pb::data::data_type::IntervalType::*;
 use risingwave_pb::data::data_type::{IntervalType, TypeName};
@@ -101,6 +104,11 @@ fn literal_type_match(return_type: &DataType, literal: Option<&ScalarImpl>) -> b
                     | (DataType::Timestamp, ScalarImpl::NaiveDateTime(_))
                     | (DataType::Decimal, ScalarImpl::Decimal(_))
                     | (DataType::Interval, ScalarImpl::Interval(_))
+                    | (DataType::Jsonb, ScalarImpl::Jsonb(_))
+                    | (DataType::Bytea, ScalarImpl::Bytea(_))
+                    | (DataType::Uuid, ScalarImpl::Uuid(_))
+                    | (DataType::List { .. }, ScalarImpl::List(_))
+                    | (DataType::Struct { .. }, ScalarImpl::Struct(_))
             )
         }
         None => true,
@@ -218,6 +226,50 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                             InternalError(format!("Failed to deserialize decimal, reason: {:?}", e))
                         })?,
                 ),
+                TypeName::Date => ScalarImpl::NaiveDate(NaiveDateWrapper::from_protobuf(
+                    i32::from_be_bytes(prost_value.get_body().as_slice().try_into().map_err(
+                        |e| InternalError(format!("Failed to deserialize date, reason: {:?}", e)),
+                    )?),
+                )?),
+                TypeName::Time => ScalarImpl::NaiveTime(NaiveTimeWrapper::from_protobuf(
+                    i64::from_be_bytes(prost_value.get_body().as_slice().try_into().map_err(
+                        |e| InternalError(format!("Failed to deserialize time, reason: {:?}", e)),
+                    )?),
+                )?),
+                TypeName::Timestamp => ScalarImpl::NaiveDateTime(
+                    NaiveDateTimeWrapper::from_protobuf(i64::from_be_bytes(
+                        prost_value.get_body().as_slice().try_into().map_err(|e| {
+                            InternalError(format!(
+                                "Failed to deserialize timestamp, reason: {:?}",
+                                e
+                            ))
+                        })?,
+                    ))?,
+                ),
+                TypeName::Jsonb => ScalarImpl::Jsonb(
+                    std::str::from_utf8(prost_value.get_body())
+                        .map_err(|e| {
+                            InternalError(format!("Failed to deserialize jsonb, reason: {:?}", e))
+                        })?
+                        .parse()?,
+                ),
+                TypeName::Bytea => ScalarImpl::Bytea(prost_value.get_body().clone().into()),
+                TypeName::Uuid => ScalarImpl::Uuid(Uuid::from_bytes(
+                    prost_value.get_body().as_slice().try_into().map_err(|e| {
+                        InternalError(format!("Failed to deserialize uuid, reason: {:?}", e))
+                    })?,
+                )),
+                // Lists and structs are encoded in the memcomparable format, without the null tag.
+                TypeName::List | TypeName::Struct => ScalarImpl::deserialize(
+                    ret_type.clone(),
+                    &mut memcomparable::Deserializer::new(prost_value.get_body().as_slice()),
+                )
+                .map_err(|e| {
+                    InternalError(format!(
+                        "Failed to deserialize {:?}, reason: {:?}",
+                        ret_type, e
+                    ))
+                })?,
                 TypeName::Interval => {
                     let bytes = prost_value.get_body();
                     ScalarImpl::Interval(make_interval(
@@ -250,6 +302,7 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
 mod tests {
     use std::sync::Arc;
 
+    use chrono::NaiveDate;
     use risingwave_common::array::column::Column;
     use risingwave_common::array::{I32Array, PrimitiveArray};
     use risingwave_common::array_nonnull;
@@ -348,6 +401,21 @@ mod tests {
             IntervalUnit::from_month(v).to_scalar_value(),
             expr.literal().unwrap()
         );
+
+        let v = NaiveDateWrapper::new(NaiveDate::from_ymd(2022, 6, 1));
+        let t = TypeName::Date;
+        let mut bytes = vec![];
+        v.to_protobuf(&mut bytes).unwrap();
+        let expr = LiteralExpression::try_from(&make_expression(Some(bytes), t)).unwrap();
+        assert_eq!(v.to_scalar_value(), expr.literal().unwrap());
+
+        let v =
+            NaiveDateTimeWrapper::new(NaiveDate::from_ymd(2022, 6, 1).and_hms_milli(12, 3, 4, 5));
+        let t = TypeName::Timestamp;
+        let mut bytes = vec![];
+        v.to_protobuf(&mut bytes).unwrap();
+        let expr = LiteralExpression::try_from(&make_expression(Some(bytes), t)).unwrap();
+        assert_eq!(v.to_scalar_value(), expr.literal().unwrap());
     }
 
     fn make_expression(bytes: Option<Vec<u8>>, data_type: TypeName) -> ExprNode {
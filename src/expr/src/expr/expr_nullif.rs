@@ -0,0 +1,95 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `NullIfExpression` is NULL if the two arguments are equal, and the first argument otherwise,
+/// i.e. `NULLIF(left, right)`. Both arguments are of the return type.
+#[derive(Debug)]
+pub struct NullIfExpression {
+    return_type: DataType,
+    left: BoxedExpression,
+    right: BoxedExpression,
+}
+
+impl NullIfExpression {
+    pub fn new(return_type: DataType, left: BoxedExpression, right: BoxedExpression) -> Self {
+        Self {
+            return_type,
+            left,
+            right,
+        }
+    }
+}
+
+impl Expression for NullIfExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let left = self.left.eval(input)?;
+        // The right side is not needed if the left side is all NULL.
+        if left.null_bitmap().iter().all(|valid| !valid) {
+            return Ok(left);
+        }
+        let right = self.right.eval(input)?;
+
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let value = left.value_at(idx);
+            if value.is_some() && value == right.value_at(idx) {
+                builder.append_null()?;
+            } else {
+                builder.append_datum_ref(value)?;
+            }
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::Utf8Array;
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    #[test]
+    fn test_nullif() {
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                column! {Utf8Array, [Some("a"), Some("b"), None, Some("d")]},
+                column! {Utf8Array, [Some("a"), Some("c"), Some("c"), None]},
+            ])
+            .build();
+        let nullif = NullIfExpression::new(
+            DataType::Varchar,
+            Box::new(InputRefExpression::new(DataType::Varchar, 0)),
+            Box::new(InputRefExpression::new(DataType::Varchar, 1)),
+        );
+        let res = nullif.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), None);
+        assert_eq!(res.datum_at(1), Some("b".to_string().into()));
+        assert_eq!(res.datum_at(2), None);
+        assert_eq!(res.datum_at(3), Some("d".to_string().into()));
+    }
+}
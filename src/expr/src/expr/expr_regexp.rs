@@ -0,0 +1,352 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Regular expression functions. The pattern is compiled once when building the expression if it
+//! is a constant, and for every row otherwise.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use regex::{Regex, RegexBuilder};
+use risingwave_common::array::{ArrayRef, DataChunk, ListValue};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, Datum, ScalarImpl, ScalarRefImpl};
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// A compiled regular expression with its flags.
+#[derive(Debug, Clone)]
+pub struct RegexpContext {
+    regex: Regex,
+    /// Whether to replace all the matches rather than the first one, i.e. the `g` flag.
+    global: bool,
+}
+
+impl RegexpContext {
+    pub fn new(pattern: &str, flags: &str) -> Result<Self> {
+        let mut case_insensitive = false;
+        let mut global = false;
+        for flag in flags.chars() {
+            match flag {
+                'i' => case_insensitive = true,
+                'c' => case_insensitive = false,
+                'g' => global = true,
+                _ => {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "invalid regular expression option: \"{}\"",
+                        flag
+                    ))
+                    .into())
+                }
+            }
+        }
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| {
+                ErrorCode::InvalidInputSyntax(format!("invalid regular expression: {}", e))
+            })?;
+        Ok(Self { regex, global })
+    }
+
+    /// Returns the captured substrings of the first match, or the whole match if the pattern has
+    /// no groups. It is NULL if there is no match.
+    fn regexp_match(&self, s: &str) -> Result<Datum> {
+        if self.global {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "regexp_match() does not support the \"global\" option".to_string(),
+            )
+            .into());
+        }
+        let captures = match self.regex.captures(s) {
+            Some(captures) => captures,
+            None => return Ok(None),
+        };
+        let values = if captures.len() == 1 {
+            vec![Some(ScalarImpl::Utf8(captures[0].to_string()))]
+        } else {
+            captures
+                .iter()
+                .skip(1)
+                .map(|group| group.map(|group| ScalarImpl::Utf8(group.as_str().to_string())))
+                .collect()
+        };
+        Ok(Some(ScalarImpl::List(ListValue::new(values))))
+    }
+
+    fn regexp_replace(&self, s: &str, replacement: &str) -> String {
+        let replacement = translate_replacement(replacement);
+        if self.global {
+            self.regex.replace_all(s, replacement.as_str()).into_owned()
+        } else {
+            self.regex.replace(s, replacement.as_str()).into_owned()
+        }
+    }
+}
+
+/// Translates the replacement string of Postgres, where `\n` is the n-th captured group and `\&`
+/// is the whole match, to the syntax of the `regex` crate.
+fn translate_replacement(replacement: &str) -> String {
+    let mut translated = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(group @ '1'..='9') => {
+                    translated.push_str("${");
+                    translated.push(group);
+                    translated.push('}');
+                }
+                Some('&') => translated.push_str("${0}"),
+                Some('\\') => translated.push('\\'),
+                Some('$') => translated.push_str("\\$$"),
+                Some(c) => {
+                    translated.push('\\');
+                    translated.push(c);
+                }
+                None => translated.push('\\'),
+            },
+            '$' => translated.push_str("$$"),
+            c => translated.push(c),
+        }
+    }
+    translated
+}
+
+/// The pattern and flags of a regular expression function.
+#[derive(Debug)]
+pub enum RegexpPattern {
+    /// Both the pattern and the flags are constant. It is `None` if either of them is NULL.
+    Constant(Option<RegexpContext>),
+    Dynamic {
+        pattern: BoxedExpression,
+        flags: Option<BoxedExpression>,
+    },
+}
+
+/// The patterns of a chunk.
+enum EvaluatedPattern<'a> {
+    Constant(Option<&'a RegexpContext>),
+    Dynamic(ArrayRef, Option<ArrayRef>),
+}
+
+impl RegexpPattern {
+    fn eval(&self, input: &DataChunk) -> Result<EvaluatedPattern<'_>> {
+        match self {
+            Self::Constant(ctx) => Ok(EvaluatedPattern::Constant(ctx.as_ref())),
+            Self::Dynamic { pattern, flags } => Ok(EvaluatedPattern::Dynamic(
+                pattern.eval(input)?,
+                flags.as_ref().map(|flags| flags.eval(input)).transpose()?,
+            )),
+        }
+    }
+}
+
+impl EvaluatedPattern<'_> {
+    /// Returns the compiled pattern of the row, or `None` if the pattern or the flags is NULL.
+    fn context_at(&self, idx: usize) -> Result<Option<Cow<'_, RegexpContext>>> {
+        match self {
+            Self::Constant(ctx) => Ok(ctx.map(Cow::Borrowed)),
+            Self::Dynamic(pattern, flags) => {
+                let pattern = match pattern.value_at(idx) {
+                    Some(ScalarRefImpl::Utf8(pattern)) => pattern,
+                    _ => return Ok(None),
+                };
+                let flags = match flags.as_ref().map(|flags| flags.value_at(idx)) {
+                    None => "",
+                    Some(Some(ScalarRefImpl::Utf8(flags))) => flags,
+                    Some(_) => return Ok(None),
+                };
+                Ok(Some(Cow::Owned(RegexpContext::new(pattern, flags)?)))
+            }
+        }
+    }
+}
+
+/// `RegexpMatchExpression` returns the captured substrings of the first match, i.e.
+/// `REGEXP_MATCH(string, pattern[, flags])`.
+#[derive(Debug)]
+pub struct RegexpMatchExpression {
+    string: BoxedExpression,
+    pattern: RegexpPattern,
+    return_type: DataType,
+}
+
+impl RegexpMatchExpression {
+    pub fn new(string: BoxedExpression, pattern: RegexpPattern, return_type: DataType) -> Self {
+        Self {
+            string,
+            pattern,
+            return_type,
+        }
+    }
+}
+
+impl Expression for RegexpMatchExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let string = self.string.eval(input)?;
+        let pattern = self.pattern.eval(input)?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let datum = match string.value_at(idx) {
+                Some(ScalarRefImpl::Utf8(s)) => match pattern.context_at(idx)? {
+                    Some(ctx) => ctx.regexp_match(s)?,
+                    None => None,
+                },
+                _ => None,
+            };
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// `RegexpReplaceExpression` replaces the first match, or all the matches with the `g` flag,
+/// i.e. `REGEXP_REPLACE(string, pattern, replacement[, flags])`.
+#[derive(Debug)]
+pub struct RegexpReplaceExpression {
+    string: BoxedExpression,
+    pattern: RegexpPattern,
+    replacement: BoxedExpression,
+    return_type: DataType,
+}
+
+impl RegexpReplaceExpression {
+    pub fn new(
+        string: BoxedExpression,
+        pattern: RegexpPattern,
+        replacement: BoxedExpression,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            string,
+            pattern,
+            replacement,
+            return_type,
+        }
+    }
+}
+
+impl Expression for RegexpReplaceExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let string = self.string.eval(input)?;
+        let pattern = self.pattern.eval(input)?;
+        let replacement = self.replacement.eval(input)?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let datum = match (string.value_at(idx), replacement.value_at(idx)) {
+                (Some(ScalarRefImpl::Utf8(s)), Some(ScalarRefImpl::Utf8(replacement))) => pattern
+                    .context_at(idx)?
+                    .map(|ctx| ScalarImpl::Utf8(ctx.regexp_replace(s, replacement))),
+                _ => None,
+            };
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::Utf8Array;
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    fn varchar(s: &str) -> Datum {
+        Some(ScalarImpl::Utf8(s.to_string()))
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                column! {Utf8Array, [Some("foobarbequebaz"), Some("abc"), None]},
+            ])
+            .build();
+        let regexp_match = |pattern: &str| {
+            RegexpMatchExpression::new(
+                Box::new(InputRefExpression::new(DataType::Varchar, 0)),
+                RegexpPattern::Constant(Some(RegexpContext::new(pattern, "").unwrap())),
+                DataType::List {
+                    datatype: Box::new(DataType::Varchar),
+                },
+            )
+        };
+
+        let res = regexp_match("(bar)(beque)").eval(&data_chunk).unwrap();
+        assert_eq!(
+            res.datum_at(0),
+            Some(ListValue::new(vec![varchar("bar"), varchar("beque")]).into())
+        );
+        assert_eq!(res.datum_at(1), None);
+        assert_eq!(res.datum_at(2), None);
+
+        let res = regexp_match("b.").eval(&data_chunk).unwrap();
+        assert_eq!(
+            res.datum_at(0),
+            Some(ListValue::new(vec![varchar("ba")]).into())
+        );
+        assert_eq!(
+            res.datum_at(1),
+            Some(ListValue::new(vec![varchar("bc")]).into())
+        );
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                column! {Utf8Array, [Some("Thomas"), Some("abcabc")]},
+                column! {Utf8Array, [Some(".[mN]a."), Some("B")]},
+            ])
+            .build();
+        let expr = RegexpReplaceExpression::new(
+            Box::new(InputRefExpression::new(DataType::Varchar, 0)),
+            RegexpPattern::Dynamic {
+                pattern: Box::new(InputRefExpression::new(DataType::Varchar, 1)),
+                flags: Some(Box::new(LiteralExpression::new(
+                    DataType::Varchar,
+                    varchar("gi"),
+                ))),
+            },
+            Box::new(LiteralExpression::new(DataType::Varchar, varchar("<\\&>"))),
+            DataType::Varchar,
+        );
+        let res = expr.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), varchar("Th<omas>"));
+        assert_eq!(res.datum_at(1), varchar("a<b>ca<b>c"));
+    }
+
+    #[test]
+    fn test_translate_replacement() {
+        assert_eq!(translate_replacement("\\1-\\&"), "${1}-${0}");
+        assert_eq!(translate_replacement("$1\\\\"), "$$1\\");
+    }
+
+    #[test]
+    fn test_invalid_regexp() {
+        assert!(RegexpContext::new("(", "").is_err());
+        assert!(RegexpContext::new("a", "x").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{ArrayRef, DataChunk, StructValue};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarRefImpl};
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `RowExpression` constructs a struct from the values of its children, i.e. `ROW(e1, e2, ...)`.
+#[derive(Debug)]
+pub struct RowExpression {
+    fields: Vec<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl RowExpression {
+    pub fn new(fields: Vec<BoxedExpression>, return_type: DataType) -> Self {
+        Self {
+            fields,
+            return_type,
+        }
+    }
+}
+
+impl Expression for RowExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays: Vec<ArrayRef> = self.fields.iter().map(|e| e.eval(input)).try_collect()?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        // The arrays of the children only contain the visible rows.
+        for idx in 0..input.cardinality() {
+            let values = arrays.iter().map(|array| array.datum_at(idx)).collect();
+            builder.append_datum(&Some(StructValue::new(values).into()))?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// `FieldExpression` returns the field of a struct at the given 0-based index, i.e.
+/// `(struct).field`.
+#[derive(Debug)]
+pub struct FieldExpression {
+    input: BoxedExpression,
+    index: usize,
+    return_type: DataType,
+}
+
+impl FieldExpression {
+    pub fn new(input: BoxedExpression, index: usize, return_type: DataType) -> Self {
+        Self {
+            input,
+            index,
+            return_type,
+        }
+    }
+}
+
+impl Expression for FieldExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let array = self.input.eval(input)?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+        for idx in 0..input.cardinality() {
+            let field = match array.value_at(idx) {
+                Some(ScalarRefImpl::Struct(value)) => value.fields_ref()[self.index],
+                _ => None,
+            };
+            builder.append_datum_ref(field)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::I32Array;
+    use risingwave_common::column;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    #[test]
+    fn test_row_and_field_expr() {
+        let column = column! {I32Array, [Some(1), None, Some(3)]};
+        let data_chunk = DataChunk::builder().columns(vec![column]).build();
+        let struct_type = DataType::new_struct(
+            vec![DataType::Int32, DataType::Varchar],
+            vec!["a".to_string(), "b".to_string()],
+        );
+        let row = || {
+            Box::new(RowExpression::new(
+                vec![
+                    Box::new(InputRefExpression::new(DataType::Int32, 0)),
+                    Box::new(LiteralExpression::new(
+                        DataType::Varchar,
+                        Some(ScalarImpl::Utf8("x".to_string())),
+                    )),
+                ],
+                struct_type.clone(),
+            ))
+        };
+
+        let res = row().eval(&data_chunk).unwrap();
+        let expected = [
+            StructValue::new(vec![Some(1.into()), Some("x".to_string().into())]),
+            StructValue::new(vec![None, Some("x".to_string().into())]),
+            StructValue::new(vec![Some(3.into()), Some("x".to_string().into())]),
+        ];
+        for (i, value) in expected.into_iter().enumerate() {
+            assert_eq!(res.datum_at(i), Some(value.into()));
+        }
+
+        let res = FieldExpression::new(row(), 0, DataType::Int32)
+            .eval(&data_chunk)
+            .unwrap();
+        assert_eq!(res.datum_at(0), Some(1.into()));
+        assert_eq!(res.datum_at(1), None);
+        assert_eq!(res.datum_at(2), Some(3.into()));
+        let res = FieldExpression::new(row(), 1, DataType::Varchar)
+            .eval(&data_chunk)
+            .unwrap();
+        for i in 0..3 {
+            assert_eq!(res.datum_at(i), Some("x".to_string().into()));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayRef, DataChunk, Row};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_pb::expr::expr_node::Type;
+
+use crate::expr::expr_unary::new_unary_expr;
+use crate::expr::{BoxedExpression, Expression, InputRefExpression};
+
+/// `TryCastExpression` casts the value of its child like `CAST`, but outputs NULL for the values
+/// which fail to be cast instead of returning an error.
+#[derive(Debug)]
+pub struct TryCastExpression {
+    child: BoxedExpression,
+    /// Casts the only column of its input.
+    cast: BoxedExpression,
+    return_type: DataType,
+}
+
+impl TryCastExpression {
+    pub fn new(child: BoxedExpression, return_type: DataType) -> Result<Self> {
+        let cast = new_unary_expr(
+            Type::Cast,
+            return_type.clone(),
+            Box::new(InputRefExpression::new(child.return_type(), 0)),
+        )?;
+        Ok(Self {
+            child,
+            cast,
+            return_type,
+        })
+    }
+}
+
+impl Expression for TryCastExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let array = self.child.eval(input)?;
+        let chunk = DataChunk::new(vec![Column::new(array.clone())], input.visibility().clone());
+        if let Ok(output) = self.cast.eval(&chunk) {
+            return Ok(output);
+        }
+
+        // Some of the values fail to be cast, so cast them one by one to find out which.
+        let child_type = [self.child.return_type()];
+        let mut builder = self.return_type.create_array_builder(chunk.cardinality())?;
+        for (i, datum) in array.iter().enumerate() {
+            if let Some(visibility) = input.visibility() {
+                if !visibility.is_set(i)? {
+                    continue;
+                }
+            }
+            let row_chunk =
+                DataChunk::from_rows(&[Row(vec![datum.to_owned_datum()])], &child_type)?;
+            match self.cast.eval(&row_chunk) {
+                Ok(output) => builder.append_datum(&output.datum_at(0))?,
+                Err(_) => builder.append_null()?,
+            }
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::Utf8Array;
+    use risingwave_common::column;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    #[test]
+    fn test_try_cast() {
+        let child = Box::new(InputRefExpression::new(DataType::Varchar, 0));
+        let expr = TryCastExpression::new(child, DataType::Int32).unwrap();
+        let column = column! {Utf8Array, [Some("1"), Some("abc"), None, Some("42")]};
+        let data_chunk = DataChunk::builder().columns(vec![column]).build();
+        let res = expr.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Int32(1)));
+        assert_eq!(res.datum_at(1), None);
+        assert_eq!(res.datum_at(2), None);
+        assert_eq!(res.datum_at(3), Some(ScalarImpl::Int32(42)));
+    }
+}
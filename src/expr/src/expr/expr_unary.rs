@@ -25,16 +25,13 @@ use crate::expr::pg_sleep::PgSleepExpression;
 use crate::expr::template::UnaryNullableExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::general_neg;
-use crate::vector_op::ascii::ascii;
 use crate::vector_op::cast::*;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
 use crate::vector_op::length::length_default;
-use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
 use crate::vector_op::rtrim::rtrim;
 use crate::vector_op::trim::trim;
-use crate::vector_op::upper::upper;
 
 /// This macro helps to create cast expression.
 /// It receives all the combinations of `gen_cast` and generates corresponding match cases
@@ -88,6 +85,8 @@ macro_rules! gen_cast {
             { time, time, |x| Ok(x) },
             { boolean, boolean, |x| Ok(x) },
             { varchar, varchar, |x| Ok(x.into()) },
+            { bytea, bytea, |x| Ok(x.into()) },
+            { uuid, uuid, |x| Ok(x) },
 
             { varchar, date, str_to_date },
             { varchar, time, str_to_time },
@@ -100,8 +99,14 @@ macro_rules! gen_cast {
             { varchar, float64, str_parse },
             { varchar, decimal, str_parse },
             { varchar, boolean, str_to_bool },
+            { varchar, jsonb, str_to_jsonb },
+            { varchar, bytea, str_to_bytea },
+            { varchar, uuid, str_to_uuid },
 
             { boolean, varchar, bool_to_str },
+            { jsonb, varchar, jsonb_to_str },
+            { bytea, varchar, bytea_to_str },
+            { uuid, varchar, uuid_to_str },
 
             { int16, int32, general_cast },
             { int16, int64, general_cast },
@@ -230,26 +235,10 @@ pub fn new_unary_expr(
         }
         (ProstType::IsNull, _, _) => Box::new(IsNullExpression::new(child_expr)),
         (ProstType::IsNotNull, _, _) => Box::new(IsNotNullExpression::new(child_expr)),
-        (ProstType::Upper, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
-            child_expr,
-            return_type,
-            upper,
-        )),
-        (ProstType::Lower, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
-            child_expr,
-            return_type,
-            lower,
-        )),
-        (ProstType::Ascii, _, _) => Box::new(UnaryExpression::<Utf8Array, I32Array, _>::new(
-            child_expr,
-            return_type,
-            ascii,
-        )),
         (ProstType::Neg, _, _) => {
             gen_neg! { child_expr, return_type }
         }
         (ProstType::PgSleep, _, DataType::Decimal) => Box::new(PgSleepExpression::new(child_expr)),
-
         (expr, ret, child) => {
             return Err(ErrorCode::NotImplemented(format!(
                 "The expression {:?}({:?}) ->{:?} using vectorized expression framework is not supported yet.",
@@ -0,0 +1,72 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, UuidArrayBuilder};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Uuid};
+
+use crate::expr::Expression;
+
+/// `GEN_RANDOM_UUID` generates a version 4 (random) UUID for each row of the input.
+#[derive(Debug, Default)]
+pub struct GenRandomUuidExpression;
+
+impl GenRandomUuidExpression {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Expression for GenRandomUuidExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Uuid
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut array_builder = UuidArrayBuilder::new(input.cardinality())?;
+        for _ in 0..input.cardinality() {
+            array_builder.append(Some(Uuid::new_v4()))?;
+        }
+        let array = array_builder.finish()?;
+        Ok(Arc::new(ArrayImpl::from(array)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array;
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::I32Array;
+
+    use super::*;
+
+    #[test]
+    fn test_gen_random_uuid() -> Result<()> {
+        let expr = GenRandomUuidExpression::new();
+        let input_chunk = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(
+                array! { I32Array, [Some(1), Some(2), None] }.into(),
+            ))])
+            .build();
+        let result_array = expr.eval(&input_chunk)?;
+        assert_eq!(3, result_array.len());
+        let v0 = result_array.datum_at(0).unwrap().into_uuid();
+        let v1 = result_array.datum_at(1).unwrap().into_uuid();
+        assert_eq!(v0.get_version_num(), 4);
+        assert_ne!(v0, v1);
+        Ok(())
+    }
+}
@@ -15,18 +15,27 @@
 mod agg;
 pub mod build_expr_from_prost;
 pub mod data_types;
+mod expr_array;
 mod expr_binary_bytes;
 pub mod expr_binary_nonnull;
 pub mod expr_binary_nullable;
 mod expr_case;
+mod expr_coalesce;
+mod expr_concat_ws;
+mod expr_greatest_least;
 mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
 mod expr_literal;
+mod expr_nullif;
+mod expr_regexp;
+mod expr_struct;
 mod expr_ternary_bytes;
+mod expr_try_cast;
 pub mod expr_unary;
+mod gen_random_uuid;
 mod pg_sleep;
-mod template;
+pub(crate) mod template;
 
 use std::convert::TryFrom;
 use std::slice;
@@ -36,7 +45,6 @@ pub use agg::AggKind;
 pub use expr_input_ref::InputRefExpression;
 pub use expr_literal::*;
 use risingwave_common::array::{ArrayRef, DataChunk, Row};
-use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
 use risingwave_pb::expr::ExprNode;
@@ -62,32 +70,28 @@ pub type BoxedExpression = Box<dyn Expression>;
 pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
     use risingwave_pb::expr::expr_node::Type::*;
 
+    // The functions in the registry are built with their signatures, and the match below only
+    // covers the other expressions, which either take any number of arguments or are typed by the
+    // frontend without a signature.
     match prost.get_expr_type()? {
-        Cast | Upper | Lower | Not | PgSleep | IsTrue | IsNotTrue | IsFalse | IsNotFalse
-        | IsNull | IsNotNull | Neg | Ascii => build_unary_expr_prost(prost),
-        Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual => {
-            build_binary_expr_prost(prost)
-        }
-        Add | Subtract | Multiply | Divide | Modulus => build_binary_expr_prost(prost),
-        Extract | RoundDigit | TumbleStart | Position => build_binary_expr_prost(prost),
-        StreamNullByRowCount | And | Or => build_nullable_binary_expr_prost(prost),
-        Substr => build_substr_expr(prost),
-        Length => build_length_expr(prost),
-        Replace => build_replace_expr(prost),
-        Like => build_like_expr(prost),
-        Trim => build_trim_expr(prost),
-        Ltrim => build_ltrim_expr(prost),
-        Rtrim => build_rtrim_expr(prost),
+        Cast | PgSleep => build_unary_expr_prost(prost),
+        ConcatWs => build_concat_ws_expr(prost),
+        RegexpMatch => build_regexp_match_expr(prost),
         ConstantValue => LiteralExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
         InputRef => InputRefExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
         Case => build_case_expr(prost),
+        Coalesce => build_coalesce_expr(prost),
+        Nullif => build_nullif_expr(prost),
+        Greatest | Least => build_greatest_least_expr(prost),
         Translate => build_translate_expr(prost),
         In => build_in_expr(prost),
-        _ => Err(InternalError(format!(
-            "Unsupported expression type: {:?}",
-            prost.get_expr_type()
-        ))
-        .into()),
+        TryCast => build_try_cast_expr(prost),
+        Array => build_array_expr(prost),
+        ArrayAccess => build_array_access_expr(prost),
+        ArrayLength => build_array_length_expr(prost),
+        Row => build_row_expr(prost),
+        Field => build_field_expr(prost),
+        _ => build_func_sig_expr(prost),
     }
 }
 
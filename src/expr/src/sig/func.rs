@@ -0,0 +1,965 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The registry of function signatures. It's the single place to declare a function, and is
+//! shared by the type inference and the overload resolution of the frontend, the expression
+//! building of the backend and `SHOW FUNCTIONS`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use itertools::iproduct;
+use risingwave_common::array::{
+    BoolArray, DecimalArray, F32Array, F64Array, I16Array, I32Array, I64Array, Utf8Array,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::expr_node::Type as ExprType;
+use risingwave_pb::expr::ExprNode;
+
+use super::DataTypeName;
+use crate::expr::build_expr_from_prost::*;
+use crate::expr::template::{
+    BinaryBytesExpression, BinaryExpression, TernaryBytesExpression, UnaryBytesExpression,
+    UnaryExpression,
+};
+use crate::expr::BoxedExpression;
+use crate::vector_op::ascii::ascii;
+use crate::vector_op::concat_op::concat_op;
+use crate::vector_op::lower::lower;
+use crate::vector_op::math::*;
+use crate::vector_op::md5::md5;
+use crate::vector_op::pad::{lpad, rpad};
+use crate::vector_op::round::{ceil_decimal, ceil_f64, floor_decimal, floor_f64, round_f64};
+use crate::vector_op::split_part::split_part;
+use crate::vector_op::starts_with::starts_with;
+use crate::vector_op::upper::upper;
+
+/// Builds the expression of a function call from its protobuf.
+pub type BuildFn = fn(&ExprNode) -> Result<BoxedExpression>;
+
+/// The signature of a function with specific argument types.
+#[derive(Clone)]
+pub struct FuncSign {
+    pub func: ExprType,
+    pub inputs_type: Vec<DataTypeName>,
+    pub ret_type: DataTypeName,
+    /// The constructor of the expression, which is called by
+    /// [`build_from_prost`](crate::expr::build_from_prost) for a call with exactly these argument
+    /// types.
+    pub build: BuildFn,
+}
+
+impl std::fmt::Debug for FuncSign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FuncSign")
+            .field("func", &self.func)
+            .field("inputs_type", &self.inputs_type)
+            .field("ret_type", &self.ret_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// All the function signatures, and the names of the functions that can be called in SQL.
+#[derive(Default)]
+pub struct FuncSigMap {
+    /// The signatures grouped by the function and the number of arguments, in the order of
+    /// registration.
+    sigs: HashMap<(ExprType, usize), Vec<FuncSign>>,
+    names: BTreeMap<&'static str, ExprType>,
+}
+
+impl FuncSigMap {
+    fn insert(
+        &mut self,
+        func: ExprType,
+        inputs_type: Vec<DataTypeName>,
+        ret_type: DataTypeName,
+        build: BuildFn,
+    ) {
+        let sign = FuncSign {
+            func,
+            inputs_type,
+            ret_type,
+            build,
+        };
+        let sigs = self
+            .sigs
+            .entry((sign.func, sign.inputs_type.len()))
+            .or_default();
+        // A later signature with the same argument types overrides the earlier one.
+        match sigs.iter_mut().find(|s| s.inputs_type == sign.inputs_type) {
+            Some(s) => *s = sign,
+            None => sigs.push(sign),
+        }
+    }
+
+    fn insert_names(&mut self, names: &[(&'static str, ExprType)]) {
+        self.names.extend(names.iter().copied());
+    }
+
+    /// Returns the signature of `func` with exactly the argument types.
+    pub fn get(&self, func: ExprType, inputs_type: &[DataTypeName]) -> Option<&FuncSign> {
+        self.get_with_arg_nums(func, inputs_type.len())
+            .iter()
+            .find(|s| s.inputs_type == inputs_type)
+    }
+
+    /// Returns the signatures of `func` with `nargs` arguments, in the order of registration.
+    pub fn get_with_arg_nums(&self, func: ExprType, nargs: usize) -> &[FuncSign] {
+        self.sigs
+            .get(&(func, nargs))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the function that is called `name` in SQL.
+    pub fn func_of_name(&self, name: &str) -> Option<ExprType> {
+        self.names.get(name).copied()
+    }
+
+    /// Iterates the signatures of the functions that can be called in SQL, ordered by the name and
+    /// the number of arguments.
+    pub fn iter_named(&self) -> impl Iterator<Item = (&'static str, &FuncSign)> {
+        self.names.iter().flat_map(|(name, func)| {
+            let mut sigs = self
+                .sigs
+                .iter()
+                .filter(|((f, _), _)| f == func)
+                .collect::<Vec<_>>();
+            sigs.sort_by_key(|((_, nargs), _)| *nargs);
+            sigs.into_iter()
+                .flat_map(|(_, sigs)| sigs.iter())
+                .map(|sig| (*name, sig))
+        })
+    }
+}
+
+/// Builds the arguments of a function call, returning them with the return type of the call.
+fn build_children(prost: &ExprNode) -> Result<(DataType, Vec<BoxedExpression>)> {
+    let (children, ret) = get_return_type_and_children(prost)?;
+    let children = children
+        .iter()
+        .map(crate::expr::build_from_prost)
+        .collect::<Result<_>>()?;
+    Ok((ret, children))
+}
+
+/// Defines the kernel constructor of a function on top of an expression template. The arguments
+/// are the array types of the inputs and the output, followed by the scalar function.
+macro_rules! unary {
+    ($ia:ty, $oa:ty, $func:expr) => {{
+        fn build(prost: &ExprNode) -> Result<BoxedExpression> {
+            let (ret, children) = build_children(prost)?;
+            let mut children = children.into_iter();
+            Ok(Box::new(UnaryExpression::<$ia, $oa, _>::new(
+                children.next().unwrap(),
+                ret,
+                $func,
+            )))
+        }
+        build as BuildFn
+    }};
+}
+
+macro_rules! unary_bytes {
+    ($ia:ty, $func:expr) => {{
+        fn build(prost: &ExprNode) -> Result<BoxedExpression> {
+            let (ret, children) = build_children(prost)?;
+            let mut children = children.into_iter();
+            Ok(Box::new(UnaryBytesExpression::<$ia, _>::new(
+                children.next().unwrap(),
+                ret,
+                $func,
+            )))
+        }
+        build as BuildFn
+    }};
+}
+
+macro_rules! binary {
+    ($ia1:ty, $ia2:ty, $oa:ty, $func:expr) => {{
+        fn build(prost: &ExprNode) -> Result<BoxedExpression> {
+            let (ret, children) = build_children(prost)?;
+            let mut children = children.into_iter();
+            Ok(Box::new(BinaryExpression::<$ia1, $ia2, $oa, _>::new(
+                children.next().unwrap(),
+                children.next().unwrap(),
+                ret,
+                $func,
+            )))
+        }
+        build as BuildFn
+    }};
+}
+
+macro_rules! binary_bytes {
+    ($ia1:ty, $ia2:ty, $func:expr) => {{
+        fn build(prost: &ExprNode) -> Result<BoxedExpression> {
+            let (ret, children) = build_children(prost)?;
+            let mut children = children.into_iter();
+            Ok(Box::new(BinaryBytesExpression::<$ia1, $ia2, _>::new(
+                children.next().unwrap(),
+                children.next().unwrap(),
+                ret,
+                $func,
+            )))
+        }
+        build as BuildFn
+    }};
+}
+
+macro_rules! ternary_bytes {
+    ($ia1:ty, $ia2:ty, $ia3:ty, $func:expr) => {{
+        fn build(prost: &ExprNode) -> Result<BoxedExpression> {
+            let (ret, children) = build_children(prost)?;
+            let mut children = children.into_iter();
+            Ok(Box::new(
+                TernaryBytesExpression::<$ia1, $ia2, $ia3, _>::new(
+                    children.next().unwrap(),
+                    children.next().unwrap(),
+                    children.next().unwrap(),
+                    ret,
+                    $func,
+                ),
+            ))
+        }
+        build as BuildFn
+    }};
+}
+
+/// Comparisons are built as non-nullable binary expressions, except `IS [NOT] DISTINCT FROM`, which
+/// compares NULLs as well.
+fn build_cmp_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    match prost.get_expr_type()? {
+        ExprType::IsDistinctFrom | ExprType::IsNotDistinctFrom => {
+            build_nullable_binary_expr_prost(prost)
+        }
+        _ => build_binary_expr_prost(prost),
+    }
+}
+
+fn arithmetic_type_derive(t1: DataTypeName, t2: DataTypeName) -> DataTypeName {
+    if t2 as i32 > t1 as i32 {
+        t2
+    } else {
+        t1
+    }
+}
+
+fn build_unary_funcs(
+    map: &mut FuncSigMap,
+    exprs: &[ExprType],
+    arg1: &[DataTypeName],
+    ret: DataTypeName,
+    build: BuildFn,
+) {
+    for (expr, a1) in iproduct!(exprs, arg1) {
+        map.insert(*expr, vec![*a1], ret, build);
+    }
+}
+
+fn build_binary_funcs(
+    map: &mut FuncSigMap,
+    exprs: &[ExprType],
+    arg1: &[DataTypeName],
+    arg2: &[DataTypeName],
+    ret: DataTypeName,
+    build: BuildFn,
+) {
+    for (expr, a1, a2) in iproduct!(exprs, arg1, arg2) {
+        map.insert(*expr, vec![*a1, *a2], ret, build);
+    }
+}
+
+fn build_commutative_binary_funcs(
+    map: &mut FuncSigMap,
+    exprs: &[ExprType],
+    arg1: &[DataTypeName],
+    arg2: &[DataTypeName],
+    ret: DataTypeName,
+    build: BuildFn,
+) {
+    build_binary_funcs(map, exprs, arg1, arg2, ret, build);
+    build_binary_funcs(map, exprs, arg2, arg1, ret, build);
+}
+
+fn build_ternary_funcs(
+    map: &mut FuncSigMap,
+    exprs: &[ExprType],
+    arg1: &[DataTypeName],
+    arg2: &[DataTypeName],
+    arg3: &[DataTypeName],
+    ret: DataTypeName,
+    build: BuildFn,
+) {
+    for (expr, a1, a2, a3) in iproduct!(exprs, arg1, arg2, arg3) {
+        map.insert(*expr, vec![*a1, *a2, *a3], ret, build);
+    }
+}
+
+fn build_func_sig_map() -> FuncSigMap {
+    use {DataTypeName as T, ExprType as E};
+    let mut map = FuncSigMap::default();
+    let num_types = vec![
+        T::Int16,
+        T::Int32,
+        T::Int64,
+        T::Float32,
+        T::Float64,
+        T::Decimal,
+    ];
+    let all_types = vec![
+        T::Int16,
+        T::Int32,
+        T::Int64,
+        T::Float32,
+        T::Float64,
+        T::Boolean,
+        T::Varchar,
+        T::Decimal,
+        T::Time,
+        T::Timestamp,
+        T::Interval,
+        T::Date,
+        T::Timestampz,
+        T::Bytea,
+        T::Uuid,
+    ];
+    let str_types = vec![T::Varchar];
+    let atm_exprs = vec![E::Add, E::Subtract, E::Multiply, E::Divide, E::Modulus];
+    let cmp_exprs = vec![
+        E::Equal,
+        E::NotEqual,
+        E::LessThan,
+        E::LessThanOrEqual,
+        E::GreaterThan,
+        E::GreaterThanOrEqual,
+        E::IsDistinctFrom,
+        E::IsNotDistinctFrom,
+    ];
+    for (expr, t1, t2) in iproduct!(atm_exprs, num_types.clone(), num_types.clone()) {
+        map.insert(
+            expr,
+            vec![t1, t2],
+            arithmetic_type_derive(t1, t2),
+            build_binary_expr_prost,
+        );
+    }
+    for t in num_types.clone() {
+        map.insert(E::Neg, vec![t], t, build_unary_expr_prost);
+    }
+    build_binary_funcs(
+        &mut map,
+        &cmp_exprs,
+        &num_types,
+        &num_types,
+        T::Boolean,
+        build_cmp_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &cmp_exprs,
+        &str_types,
+        &str_types,
+        T::Boolean,
+        build_cmp_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &cmp_exprs,
+        &[T::Boolean],
+        &[T::Boolean],
+        T::Boolean,
+        build_cmp_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &cmp_exprs,
+        &[T::Bytea],
+        &[T::Bytea],
+        T::Boolean,
+        build_cmp_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &cmp_exprs,
+        &[T::Uuid],
+        &[T::Uuid],
+        T::Boolean,
+        build_cmp_expr,
+    );
+
+    // Date comparisons
+    build_binary_funcs(
+        &mut map,
+        &cmp_exprs,
+        &[T::Date, T::Timestamp],
+        &[T::Date, T::Timestamp],
+        T::Boolean,
+        build_cmp_expr,
+    );
+    // Date/Timestamp/Interval arithmetic
+    build_commutative_binary_funcs(
+        &mut map,
+        &[E::Add],
+        &[T::Timestamp, T::Date],
+        &[T::Interval],
+        T::Timestamp,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Subtract],
+        &[T::Timestamp, T::Date],
+        &[T::Interval],
+        T::Timestamp,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Subtract],
+        &[T::Timestamp],
+        &[T::Timestamp],
+        T::Interval,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Subtract],
+        &[T::Date],
+        &[T::Date],
+        T::Int32,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Add, E::Subtract],
+        &[T::Interval],
+        &[T::Interval],
+        T::Interval,
+        build_binary_expr_prost,
+    );
+    build_commutative_binary_funcs(
+        &mut map,
+        &[E::Multiply],
+        &[T::Interval],
+        &[T::Int16, T::Int32, T::Int64],
+        T::Interval,
+        build_binary_expr_prost,
+    );
+
+    build_binary_funcs(
+        &mut map,
+        &[E::And, E::Or],
+        &[T::Boolean],
+        &[T::Boolean],
+        T::Boolean,
+        build_nullable_binary_expr_prost,
+    );
+    build_unary_funcs(
+        &mut map,
+        &[E::IsTrue, E::IsNotTrue, E::IsFalse, E::IsNotFalse, E::Not],
+        &[T::Boolean],
+        T::Boolean,
+        build_unary_expr_prost,
+    );
+    build_unary_funcs(
+        &mut map,
+        &[E::IsNull, E::IsNotNull],
+        &all_types,
+        T::Boolean,
+        build_unary_expr_prost,
+    );
+    // `StreamNullByRowCount(row_count, x)` is NULL if the row count is zero, and `x` otherwise.
+    build_binary_funcs(
+        &mut map,
+        &[E::StreamNullByRowCount],
+        &[T::Int64],
+        &all_types,
+        T::Boolean,
+        build_nullable_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Substr],
+        &str_types,
+        &num_types,
+        T::Varchar,
+        build_substr_expr,
+    );
+    build_ternary_funcs(
+        &mut map,
+        &[E::Substr],
+        &str_types,
+        &num_types,
+        &num_types,
+        T::Varchar,
+        build_substr_expr,
+    );
+    build_unary_funcs(
+        &mut map,
+        &[E::Length],
+        &str_types,
+        T::Int32,
+        build_length_expr,
+    );
+    build_unary_funcs(
+        &mut map,
+        &[E::Trim],
+        &str_types,
+        T::Varchar,
+        build_trim_expr,
+    );
+    build_unary_funcs(
+        &mut map,
+        &[E::Ltrim],
+        &str_types,
+        T::Varchar,
+        build_ltrim_expr,
+    );
+    build_unary_funcs(
+        &mut map,
+        &[E::Rtrim],
+        &str_types,
+        T::Varchar,
+        build_rtrim_expr,
+    );
+    map.insert(
+        E::Upper,
+        vec![T::Varchar],
+        T::Varchar,
+        unary_bytes!(Utf8Array, upper),
+    );
+    map.insert(
+        E::Lower,
+        vec![T::Varchar],
+        T::Varchar,
+        unary_bytes!(Utf8Array, lower),
+    );
+    map.insert(
+        E::Md5,
+        vec![T::Varchar],
+        T::Varchar,
+        unary_bytes!(Utf8Array, md5),
+    );
+    map.insert(
+        E::Ascii,
+        vec![T::Varchar],
+        T::Int32,
+        unary!(Utf8Array, I32Array, ascii),
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Trim],
+        &str_types,
+        &str_types,
+        T::Varchar,
+        build_trim_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Ltrim],
+        &str_types,
+        &str_types,
+        T::Varchar,
+        build_ltrim_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Rtrim],
+        &str_types,
+        &str_types,
+        T::Varchar,
+        build_rtrim_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Position],
+        &str_types,
+        &str_types,
+        T::Varchar,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Like, E::Ilike],
+        &str_types,
+        &str_types,
+        T::Boolean,
+        build_like_expr,
+    );
+    // `LIKE` and `ILIKE` with an escape character
+    build_ternary_funcs(
+        &mut map,
+        &[E::Like, E::Ilike],
+        &str_types,
+        &str_types,
+        &str_types,
+        T::Boolean,
+        build_like_expr,
+    );
+    map.insert(
+        E::StartsWith,
+        vec![T::Varchar, T::Varchar],
+        T::Boolean,
+        binary!(Utf8Array, Utf8Array, BoolArray, starts_with),
+    );
+    map.insert(
+        E::ConcatOp,
+        vec![T::Varchar, T::Varchar],
+        T::Varchar,
+        binary_bytes!(Utf8Array, Utf8Array, concat_op),
+    );
+    map.insert(
+        E::SplitPart,
+        vec![T::Varchar, T::Varchar, T::Int32],
+        T::Varchar,
+        ternary_bytes!(Utf8Array, Utf8Array, I32Array, split_part),
+    );
+    map.insert(
+        E::Lpad,
+        vec![T::Varchar, T::Int32, T::Varchar],
+        T::Varchar,
+        ternary_bytes!(Utf8Array, I32Array, Utf8Array, lpad),
+    );
+    map.insert(
+        E::Rpad,
+        vec![T::Varchar, T::Int32, T::Varchar],
+        T::Varchar,
+        ternary_bytes!(Utf8Array, I32Array, Utf8Array, rpad),
+    );
+    // `REGEXP_REPLACE` with or without flags
+    build_ternary_funcs(
+        &mut map,
+        &[E::RegexpReplace],
+        &str_types,
+        &str_types,
+        &str_types,
+        T::Varchar,
+        build_regexp_replace_expr,
+    );
+    map.insert(
+        E::RegexpReplace,
+        vec![T::Varchar; 4],
+        T::Varchar,
+        build_regexp_replace_expr,
+    );
+    build_ternary_funcs(
+        &mut map,
+        &[E::Replace],
+        &str_types,
+        &str_types,
+        &str_types,
+        T::Varchar,
+        build_replace_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::RoundDigit],
+        &[T::Decimal],
+        &[T::Int32],
+        T::Decimal,
+        build_binary_expr_prost,
+    );
+    // `round(numeric)` is bound to `RoundDigit` with zero digits.
+    map.insert(
+        E::Round,
+        vec![T::Float64],
+        T::Float64,
+        unary!(F64Array, F64Array, round_f64),
+    );
+    map.insert(
+        E::Abs,
+        vec![T::Int16],
+        T::Int16,
+        unary!(I16Array, I16Array, int_abs),
+    );
+    map.insert(
+        E::Abs,
+        vec![T::Int32],
+        T::Int32,
+        unary!(I32Array, I32Array, int_abs),
+    );
+    map.insert(
+        E::Abs,
+        vec![T::Int64],
+        T::Int64,
+        unary!(I64Array, I64Array, int_abs),
+    );
+    map.insert(
+        E::Abs,
+        vec![T::Float32],
+        T::Float32,
+        unary!(F32Array, F32Array, float_abs),
+    );
+    map.insert(
+        E::Abs,
+        vec![T::Float64],
+        T::Float64,
+        unary!(F64Array, F64Array, float_abs),
+    );
+    map.insert(
+        E::Abs,
+        vec![T::Decimal],
+        T::Decimal,
+        unary!(DecimalArray, DecimalArray, decimal_abs),
+    );
+    // The other math functions are only implemented for `double precision` and `numeric`, and the
+    // other numeric types are cast to them implicitly. As the `double precision` ones are
+    // registered first, they are preferred when both need the same number of casts.
+    for (func, float_build, decimal_build) in [
+        (
+            E::Ceil,
+            unary!(F64Array, F64Array, ceil_f64),
+            unary!(DecimalArray, DecimalArray, ceil_decimal),
+        ),
+        (
+            E::Floor,
+            unary!(F64Array, F64Array, floor_f64),
+            unary!(DecimalArray, DecimalArray, floor_decimal),
+        ),
+        (
+            E::Sqrt,
+            unary!(F64Array, F64Array, sqrt_f64),
+            unary!(DecimalArray, DecimalArray, sqrt_decimal),
+        ),
+        (
+            E::Ln,
+            unary!(F64Array, F64Array, ln_f64),
+            unary!(DecimalArray, DecimalArray, ln_decimal),
+        ),
+        (
+            E::Log10,
+            unary!(F64Array, F64Array, log10_f64),
+            unary!(DecimalArray, DecimalArray, log10_decimal),
+        ),
+        (
+            E::Exp,
+            unary!(F64Array, F64Array, exp_f64),
+            unary!(DecimalArray, DecimalArray, exp_decimal),
+        ),
+        (
+            E::Sign,
+            unary!(F64Array, F64Array, sign_f64),
+            unary!(DecimalArray, DecimalArray, sign_decimal),
+        ),
+        (
+            E::Pow,
+            binary!(F64Array, F64Array, F64Array, pow_f64),
+            binary!(DecimalArray, DecimalArray, DecimalArray, pow_decimal),
+        ),
+    ] {
+        let nargs = if func == E::Pow { 2 } else { 1 };
+        map.insert(func, vec![T::Float64; nargs], T::Float64, float_build);
+        map.insert(func, vec![T::Decimal; nargs], T::Decimal, decimal_build);
+    }
+    build_binary_funcs(
+        &mut map,
+        &[E::Extract],
+        &[T::Varchar], // Time field, "YEAR", "DAY", etc
+        &[T::Timestamp, T::Time, T::Date],
+        T::Decimal,
+        build_binary_expr_prost,
+    );
+    // The date/time functions below only take timestamps, and dates are cast to timestamps
+    // implicitly for them.
+    build_binary_funcs(
+        &mut map,
+        &[E::DateTrunc],
+        &[T::Varchar], // Time field, "day", "month", etc
+        &[T::Timestamp],
+        T::Timestamp,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::ToChar],
+        &[T::Timestamp],
+        &str_types,
+        T::Varchar,
+        build_to_char_expr,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::ToTimestamp],
+        &str_types,
+        &str_types,
+        T::Timestamp,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Age],
+        &[T::Timestamp],
+        &[T::Timestamp],
+        T::Interval,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::TumbleStart],
+        &[T::Date, T::Timestamp],
+        &[T::Interval],
+        T::Timestamp,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::JsonbAccessInner],
+        &[T::Jsonb],
+        &[T::Varchar, T::Int32],
+        T::Jsonb,
+        build_nullable_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::JsonbAccessStr],
+        &[T::Jsonb],
+        &[T::Varchar, T::Int32],
+        T::Varchar,
+        build_nullable_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::JsonbAccessPath],
+        &[T::Jsonb],
+        &[T::Varchar],
+        T::Jsonb,
+        build_nullable_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::JsonbAccessPathStr],
+        &[T::Jsonb],
+        &[T::Varchar],
+        T::Varchar,
+        build_nullable_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::JsonbContains],
+        &[T::Jsonb],
+        &[T::Jsonb],
+        T::Boolean,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Encode],
+        &[T::Bytea],
+        &str_types,
+        T::Varchar,
+        build_binary_expr_prost,
+    );
+    build_binary_funcs(
+        &mut map,
+        &[E::Decode],
+        &str_types,
+        &str_types,
+        T::Bytea,
+        build_binary_expr_prost,
+    );
+    map.insert(
+        E::GenRandomUuid,
+        vec![],
+        T::Uuid,
+        build_gen_random_uuid_expr,
+    );
+
+    map.insert_names(&[
+        ("substr", E::Substr),
+        ("length", E::Length),
+        ("like", E::Like),
+        ("upper", E::Upper),
+        ("lower", E::Lower),
+        ("trim", E::Trim),
+        ("replace", E::Replace),
+        ("position", E::Position),
+        ("ltrim", E::Ltrim),
+        ("rtrim", E::Rtrim),
+        ("ascii", E::Ascii),
+        ("is true", E::IsTrue),
+        ("is not true", E::IsNotTrue),
+        ("is false", E::IsFalse),
+        ("is not false", E::IsNotFalse),
+        ("is null", E::IsNull),
+        ("is not null", E::IsNotNull),
+        ("round", E::Round),
+        ("abs", E::Abs),
+        ("mod", E::Modulus),
+        ("ceil", E::Ceil),
+        ("ceiling", E::Ceil),
+        ("floor", E::Floor),
+        ("pow", E::Pow),
+        ("power", E::Pow),
+        ("sqrt", E::Sqrt),
+        ("ln", E::Ln),
+        // `log` with a single argument is the base 10 logarithm.
+        ("log", E::Log10),
+        ("log10", E::Log10),
+        ("exp", E::Exp),
+        ("sign", E::Sign),
+        ("date_trunc", E::DateTrunc),
+        ("to_char", E::ToChar),
+        ("to_timestamp", E::ToTimestamp),
+        ("age", E::Age),
+        ("encode", E::Encode),
+        ("decode", E::Decode),
+        ("gen_random_uuid", E::GenRandomUuid),
+        ("split_part", E::SplitPart),
+        ("regexp_replace", E::RegexpReplace),
+        ("lpad", E::Lpad),
+        ("rpad", E::Rpad),
+        ("md5", E::Md5),
+        ("starts_with", E::StartsWith),
+    ]);
+    map
+}
+
+lazy_static::lazy_static! {
+    pub static ref FUNC_SIG_MAP: FuncSigMap = build_func_sig_map();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_functions_have_signatures() {
+        for (name, func) in &FUNC_SIG_MAP.names {
+            assert!(
+                FUNC_SIG_MAP.sigs.keys().any(|(f, _)| f == func),
+                "function {} has no signature",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        use DataTypeName as T;
+        let sig = FUNC_SIG_MAP
+            .get(ExprType::Pow, &[T::Decimal, T::Decimal])
+            .unwrap();
+        assert_eq!(sig.ret_type, T::Decimal);
+        assert!(FUNC_SIG_MAP
+            .get(ExprType::Pow, &[T::Decimal, T::Float64])
+            .is_none());
+        assert_eq!(
+            FUNC_SIG_MAP.get_with_arg_nums(ExprType::Sqrt, 1)[0].inputs_type,
+            vec![T::Float64]
+        );
+        assert_eq!(FUNC_SIG_MAP.func_of_name("ceiling"), Some(ExprType::Ceil));
+    }
+}
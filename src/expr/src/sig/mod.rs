@@ -0,0 +1,90 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metadata of expressions, like the signatures of functions.
+
+use risingwave_common::types::DataType;
+
+pub mod func;
+
+/// `DataTypeName` is designed for type derivation in the function signatures. In other
+/// scenarios, use `DataType` instead.
+///
+/// The numeric types are ordered by their precedence in arithmetic type derivation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+pub enum DataTypeName {
+    Boolean,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Decimal,
+    Date,
+    Varchar,
+    Time,
+    Timestamp,
+    Timestampz,
+    Interval,
+    Struct,
+    List,
+    Jsonb,
+    Bytea,
+    Uuid,
+}
+
+impl From<&DataType> for DataTypeName {
+    fn from(ty: &DataType) -> Self {
+        match ty {
+            DataType::Int16 => DataTypeName::Int16,
+            DataType::Int32 => DataTypeName::Int32,
+            DataType::Int64 => DataTypeName::Int64,
+            DataType::Float32 => DataTypeName::Float32,
+            DataType::Float64 => DataTypeName::Float64,
+            DataType::Boolean => DataTypeName::Boolean,
+            DataType::Varchar => DataTypeName::Varchar,
+            DataType::Date => DataTypeName::Date,
+            DataType::Time => DataTypeName::Time,
+            DataType::Timestamp => DataTypeName::Timestamp,
+            DataType::Timestampz => DataTypeName::Timestampz,
+            DataType::Decimal => DataTypeName::Decimal,
+            DataType::Interval => DataTypeName::Interval,
+            DataType::Struct { .. } => DataTypeName::Struct,
+            DataType::List { .. } => DataTypeName::List,
+            DataType::Jsonb => DataTypeName::Jsonb,
+            DataType::Bytea => DataTypeName::Bytea,
+            DataType::Uuid => DataTypeName::Uuid,
+        }
+    }
+}
+
+impl DataTypeName {
+    /// Whether a value of this type can be cast to `target` implicitly when resolving the
+    /// overloads of a function, like the implicit casts of Postgres.
+    pub fn can_implicit_cast_to(self, target: DataTypeName) -> bool {
+        use DataTypeName as T;
+        self == target
+            || matches!(
+                (self, target),
+                (
+                    T::Int16,
+                    T::Int32 | T::Int64 | T::Decimal | T::Float32 | T::Float64
+                ) | (T::Int32, T::Int64 | T::Decimal | T::Float32 | T::Float64)
+                    | (T::Int64, T::Decimal | T::Float32 | T::Float64)
+                    | (T::Decimal, T::Float32 | T::Float64)
+                    | (T::Float32, T::Float64)
+                    | (T::Date, T::Timestamp)
+            )
+    }
+}
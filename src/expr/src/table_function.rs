@@ -0,0 +1,275 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Set-returning functions, which return a set of values for each row of their input.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayRef, DataChunk, JsonbVal};
+use risingwave_common::error::ErrorCode::{InternalError, InvalidInputSyntax};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_pb::plan::project_set_select_item::SelectItem;
+use risingwave_pb::plan::table_function_node::Type as TableFunctionType;
+use risingwave_pb::plan::{ProjectSetSelectItem as ProstProjectSetSelectItem, TableFunctionNode};
+use serde_json::Value;
+
+use crate::expr::{build_from_prost, BoxedExpression};
+
+/// `TableFunction` evaluates a set-returning function, e.g. `unnest(array)`.
+#[derive(Debug)]
+pub struct TableFunction {
+    function_type: TableFunctionType,
+    args: Vec<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl TableFunction {
+    pub fn new(
+        function_type: TableFunctionType,
+        args: Vec<BoxedExpression>,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            function_type,
+            args,
+            return_type,
+        }
+    }
+
+    pub fn from_prost(prost: &TableFunctionNode) -> Result<Self> {
+        let args = prost
+            .get_args()
+            .iter()
+            .map(build_from_prost)
+            .try_collect()?;
+        Ok(Self::new(
+            prost.get_function_type()?,
+            args,
+            DataType::from(prost.get_return_type()?),
+        ))
+    }
+
+    pub fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    /// Evaluates the function on each row of `input`, which must have no invisible rows, and
+    /// returns the values of each row.
+    pub fn eval(&self, input: &DataChunk) -> Result<Vec<Vec<Datum>>> {
+        debug_assert!(input.visibility().is_none());
+        let args: Vec<ArrayRef> = self.args.iter().map(|arg| arg.eval(input)).try_collect()?;
+        (0..input.cardinality())
+            .map(|row_idx| {
+                let args = args.iter().map(|arg| arg.datum_at(row_idx)).collect_vec();
+                self.eval_row(&args)
+            })
+            .try_collect()
+    }
+
+    fn eval_row(&self, args: &[Datum]) -> Result<Vec<Datum>> {
+        match self.function_type {
+            TableFunctionType::JsonbArrayElements => match args {
+                [None] => Ok(vec![]),
+                [Some(ScalarImpl::Jsonb(v))] => jsonb_array_elements(v),
+                _ => Err(InvalidInputSyntax(
+                    "jsonb_array_elements takes a single jsonb argument".to_string(),
+                )
+                .into()),
+            },
+            TableFunctionType::Unnest => match args {
+                [None] => Ok(vec![]),
+                [Some(ScalarImpl::List(list))] => Ok(list.values().to_vec()),
+                _ => Err(
+                    InvalidInputSyntax("unnest takes a single array argument".to_string()).into(),
+                ),
+            },
+            TableFunctionType::Unspecified => unreachable!(),
+        }
+    }
+}
+
+/// An item in the select list of a `ProjectSet`, which is either a scalar expression or a
+/// set-returning function.
+#[derive(Debug)]
+pub enum ProjectSetSelectItem {
+    Expr(BoxedExpression),
+    TableFunction(TableFunction),
+}
+
+impl ProjectSetSelectItem {
+    pub fn from_prost(prost: &ProstProjectSetSelectItem) -> Result<Self> {
+        match prost.select_item.as_ref() {
+            Some(SelectItem::Expr(expr)) => Ok(Self::Expr(build_from_prost(expr)?)),
+            Some(SelectItem::TableFunction(function)) => {
+                Ok(Self::TableFunction(TableFunction::from_prost(function)?))
+            }
+            None => Err(InternalError("missing select item".to_string()).into()),
+        }
+    }
+
+    pub fn return_type(&self) -> DataType {
+        match self {
+            Self::Expr(expr) => expr.return_type(),
+            Self::TableFunction(function) => function.return_type(),
+        }
+    }
+}
+
+/// Evaluates the select list of a `ProjectSet` on `input`, which must have no invisible rows.
+///
+/// Each input row is output once for each value returned by the set-returning functions on it,
+/// up to the most values returned by any of them, and the shorter sets are padded with NULLs.
+/// The first output column is the index of the output row among those of the same input row.
+/// Returns the output along with the index of the input row of each output row.
+pub fn eval_project_set(
+    select_list: &[ProjectSetSelectItem],
+    input: &DataChunk,
+) -> Result<(DataChunk, Vec<usize>)> {
+    enum Evaluated {
+        Expr(ArrayRef),
+        TableFunction(Vec<Vec<Datum>>),
+    }
+
+    let evaluated: Vec<Evaluated> = select_list
+        .iter()
+        .map(|item| match item {
+            ProjectSetSelectItem::Expr(expr) => expr.eval(input).map(Evaluated::Expr),
+            ProjectSetSelectItem::TableFunction(function) => {
+                function.eval(input).map(Evaluated::TableFunction)
+            }
+        })
+        .try_collect()?;
+
+    let mut row_id_builder = DataType::Int64.create_array_builder(input.cardinality())?;
+    let mut builders: Vec<_> = select_list
+        .iter()
+        .map(|item| item.return_type().create_array_builder(input.cardinality()))
+        .try_collect()?;
+    let mut input_row_indices = vec![];
+    for row_idx in 0..input.cardinality() {
+        let rows = evaluated
+            .iter()
+            .filter_map(|e| match e {
+                Evaluated::TableFunction(values) => Some(values[row_idx].len()),
+                Evaluated::Expr(_) => None,
+            })
+            .max()
+            .unwrap_or(0);
+        for i in 0..rows {
+            row_id_builder.append_datum(&Some(ScalarImpl::Int64(i as i64)))?;
+            for (builder, e) in builders.iter_mut().zip_eq(evaluated.iter()) {
+                match e {
+                    Evaluated::Expr(array) => builder.append_datum_ref(array.value_at(row_idx))?,
+                    Evaluated::TableFunction(values) => match values[row_idx].get(i) {
+                        Some(datum) => builder.append_datum(datum)?,
+                        None => builder.append_null()?,
+                    },
+                }
+            }
+            input_row_indices.push(row_idx);
+        }
+    }
+
+    let columns = std::iter::once(row_id_builder)
+        .chain(builders)
+        .map(|builder| Ok(Column::new(Arc::new(builder.finish()?))))
+        .collect::<Result<Vec<_>>>()?;
+    let chunk = DataChunk::new(columns, None);
+    Ok((chunk, input_row_indices))
+}
+
+fn jsonb_array_elements(v: &JsonbVal) -> Result<Vec<Datum>> {
+    match v.as_value() {
+        Value::Array(elems) => Ok(elems
+            .iter()
+            .map(|elem| Some(ScalarImpl::Jsonb(JsonbVal::from(elem.clone()))))
+            .collect()),
+        Value::Object(_) => Err(RwError::from(InvalidInputSyntax(
+            "cannot extract elements from an object".to_string(),
+        ))),
+        _ => Err(RwError::from(InvalidInputSyntax(
+            "cannot extract elements from a scalar".to_string(),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{ListValue, Row};
+
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    #[test]
+    fn test_unnest() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let list = ListValue::new(vec![Some(1.into()), None, Some(3.into())]);
+        let unnest = TableFunction::new(
+            TableFunctionType::Unnest,
+            vec![Box::new(LiteralExpression::new(
+                list_type,
+                Some(list.into()),
+            ))],
+            DataType::Int32,
+        );
+        let values = unnest.eval(&DataChunk::new_dummy(2)).unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0], vec![Some(1.into()), None, Some(3.into())]);
+    }
+
+    #[test]
+    fn test_eval_project_set() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let list = |values: Vec<i32>| -> BoxedExpression {
+            let list = ListValue::new(values.into_iter().map(|v| Some(v.into())).collect());
+            Box::new(LiteralExpression::new(list_type.clone(), Some(list.into())))
+        };
+        let unnest = |arg| {
+            ProjectSetSelectItem::TableFunction(TableFunction::new(
+                TableFunctionType::Unnest,
+                vec![arg],
+                DataType::Int32,
+            ))
+        };
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(Box::new(LiteralExpression::new(
+                DataType::Int32,
+                Some(42.into()),
+            ))),
+            unnest(list(vec![1, 2, 3])),
+            unnest(list(vec![4])),
+        ];
+        let (chunk, input_row_indices) =
+            eval_project_set(&select_list, &DataChunk::new_dummy(2)).unwrap();
+        assert_eq!(input_row_indices, vec![0, 0, 0, 1, 1, 1]);
+        assert_eq!(chunk.cardinality(), 6);
+        let row = Row::from(chunk.row_at(2).unwrap().0);
+        assert_eq!(
+            row.0,
+            vec![
+                Some(ScalarImpl::Int64(2)),
+                Some(42.into()),
+                Some(3.into()),
+                None
+            ]
+        );
+    }
+}
@@ -18,6 +18,7 @@ use risingwave_common::types::*;
 use risingwave_pb::expr::AggCall;
 
 use crate::expr::AggKind;
+use crate::vector_op::agg::array_agg::ArrayAgg;
 use crate::vector_op::agg::count_star::CountStar;
 use crate::vector_op::agg::functions::*;
 use crate::vector_op::agg::general_agg::*;
@@ -130,6 +131,18 @@ pub fn create_agg_state_unary(
 ) -> Result<Box<dyn Aggregator>> {
     use crate::expr::data_types::*;
 
+    // `array_agg` accepts any input type, so it is not generated by `gen_arms`.
+    if *agg_type == AggKind::ArrayAgg {
+        if distinct {
+            return Err(ErrorCode::NotImplemented(
+                "array_agg with DISTINCT".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        return Ok(Box::new(ArrayAgg::new(return_type, input_col_idx)));
+    }
+
     macro_rules! gen_arms {
         [$(($agg:ident, $fn:expr, $in:tt, $ret:tt)),* $(,)?] => {
             match (
@@ -0,0 +1,135 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{ArrayBuilderImpl, DataChunk, ListValue};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum};
+
+use crate::vector_op::agg::aggregator::Aggregator;
+use crate::vector_op::agg::general_sorted_grouper::EqGroups;
+
+/// `ArrayAgg` collects the input values, including NULLs, into a list. The result is NULL if
+/// there are no input rows.
+pub struct ArrayAgg {
+    return_type: DataType,
+    input_col_idx: usize,
+    result: Option<Vec<Datum>>,
+}
+
+impl ArrayAgg {
+    pub fn new(return_type: DataType, input_col_idx: usize) -> Self {
+        Self {
+            return_type,
+            input_col_idx,
+            result: None,
+        }
+    }
+
+    fn push(&mut self, input: &DataChunk, row_id: usize) {
+        let datum = input
+            .column_at(self.input_col_idx)
+            .array_ref()
+            .datum_at(row_id);
+        self.result.get_or_insert_with(Vec::new).push(datum);
+    }
+
+    fn output_and_reset(&mut self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        self.output(builder)?;
+        self.result = None;
+        Ok(())
+    }
+}
+
+impl Aggregator for ArrayAgg {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn update_with_row(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        if let Some(visibility) = input.visibility() {
+            if !visibility.is_set(row_id)? {
+                return Ok(());
+            }
+        }
+        self.push(input, row_id);
+        Ok(())
+    }
+
+    fn update(&mut self, input: &DataChunk) -> Result<()> {
+        for row_id in 0..input.capacity() {
+            self.update_with_row(input, row_id)?;
+        }
+        Ok(())
+    }
+
+    fn output(&self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        let datum = self
+            .result
+            .as_ref()
+            .map(|values| ListValue::new(values.clone()).into());
+        builder.append_datum(&datum)
+    }
+
+    fn update_and_output_with_sorted_groups(
+        &mut self,
+        input: &DataChunk,
+        builder: &mut ArrayBuilderImpl,
+        groups: &EqGroups,
+    ) -> Result<()> {
+        // Each starting index ends the ongoing group, and the rows after the last one are the
+        // group that may continue in the following chunks.
+        let mut start = 0;
+        for &end in groups.get_starting_indices() {
+            for row_id in start..end {
+                self.push(input, row_id);
+            }
+            self.output_and_reset(builder)?;
+            start = end;
+        }
+        for row_id in start..input.cardinality() {
+            self.push(input, row_id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::I32Array;
+    use risingwave_common::column;
+
+    use super::*;
+
+    #[test]
+    fn test_array_agg() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let mut agg = ArrayAgg::new(list_type.clone(), 0);
+        let mut builder = list_type.create_array_builder(2).unwrap();
+        agg.output(&mut builder).unwrap();
+
+        let column = column! {I32Array, [Some(1), None, Some(3)]};
+        let data_chunk = DataChunk::builder().columns(vec![column]).build();
+        agg.update(&data_chunk).unwrap();
+        agg.output(&mut builder).unwrap();
+
+        let array = builder.finish().unwrap();
+        assert_eq!(array.datum_at(0), None);
+        assert_eq!(
+            array.datum_at(1),
+            Some(ListValue::new(vec![Some(1.into()), None, Some(3.into())]).into())
+        );
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod aggregator;
+mod array_agg;
 mod count_star;
 mod functions;
 mod general_agg;
@@ -145,7 +145,35 @@ pub fn timestamp_timestamp_sub<T1, T2, T3>(
     r: NaiveDateTimeWrapper,
 ) -> Result<IntervalUnit> {
     let tmp = l.0 - r.0;
-    Ok(IntervalUnit::new(0, tmp.num_days() as i32, 0))
+    let days = tmp.num_days();
+    let ms = (tmp - Duration::days(days)).num_milliseconds();
+    Ok(IntervalUnit::new(0, days as i32, ms))
+}
+
+/// Subtracts the timestamps like `timestamp_timestamp_sub`, but produces a symbolic result that
+/// uses years and months instead of just days, e.g. `age('2022-03-01', '2021-01-31')` is
+/// `1 year 1 mon 1 day`. The days borrowed from the months are counted by the month of the
+/// earlier timestamp, as in `PostgreSQL`.
+#[inline(always)]
+pub fn timestamp_timestamp_age(
+    l: NaiveDateTimeWrapper,
+    r: NaiveDateTimeWrapper,
+) -> Result<IntervalUnit> {
+    let (later, earlier) = if l >= r { (l.0, r.0) } else { (r.0, l.0) };
+    let mut months =
+        (later.year() - earlier.year()) * 12 + later.month() as i32 - earlier.month() as i32;
+    let mut days = later.day() as i32 - earlier.day() as i32;
+    let mut ms = (later.time() - earlier.time()).num_milliseconds();
+    if ms < 0 {
+        ms += 24 * 60 * 60 * 1000;
+        days -= 1;
+    }
+    if days < 0 {
+        days += get_mouth_days(earlier.year(), earlier.month() as usize);
+        months -= 1;
+    }
+    let age = IntervalUnit::new(months, days, ms);
+    Ok(if l >= r { age } else { age.negative() })
 }
 
 #[inline(always)]
@@ -260,9 +288,12 @@ where
 mod tests {
     use std::str::FromStr;
 
-    use risingwave_common::types::Decimal;
+    use chrono::NaiveDate;
+    use risingwave_common::types::{Decimal, IntervalUnit, NaiveDateTimeWrapper};
 
-    use crate::vector_op::arithmetic_op::general_add;
+    use crate::vector_op::arithmetic_op::{
+        general_add, timestamp_timestamp_age, timestamp_timestamp_sub,
+    };
 
     #[test]
     fn test() {
@@ -271,4 +302,22 @@ mod tests {
             Decimal::from_str("2").unwrap()
         );
     }
+
+    #[test]
+    fn test_timestamp_sub() {
+        let l = NaiveDateTimeWrapper::new(NaiveDate::from_ymd(2022, 3, 1).and_hms(1, 0, 0));
+        let r = NaiveDateTimeWrapper::new(NaiveDate::from_ymd(2021, 1, 31).and_hms(12, 30, 0));
+        assert_eq!(
+            timestamp_timestamp_sub::<(), (), ()>(l, r).unwrap(),
+            IntervalUnit::new(0, 393, 45_000_000)
+        );
+        assert_eq!(
+            timestamp_timestamp_age(l, r).unwrap(),
+            IntervalUnit::new(13, 0, 45_000_000)
+        );
+        assert_eq!(
+            timestamp_timestamp_age(r, l).unwrap(),
+            IntervalUnit::new(-13, 0, -45_000_000)
+        );
+    }
 }
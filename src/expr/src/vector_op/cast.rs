@@ -18,10 +18,12 @@ use std::str::FromStr;
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use num_traits::ToPrimitive;
+use risingwave_common::array::{JsonbRef, JsonbVal};
 use risingwave_common::error::ErrorCode::{InternalError, InvalidInputSyntax, ParseError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{
     Decimal, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, OrderedF32, OrderedF64,
+    ScalarRefImpl, Uuid,
 };
 
 /// String literals for bool type.
@@ -203,3 +205,89 @@ pub fn bool_to_str(input: bool) -> Result<String> {
         false => Ok("false".into()),
     }
 }
+
+#[inline(always)]
+pub fn str_to_jsonb(elem: &str) -> Result<JsonbVal> {
+    elem.parse()
+}
+
+#[inline(always)]
+pub fn jsonb_to_str(elem: JsonbRef<'_>) -> Result<String> {
+    Ok(elem.to_string())
+}
+
+/// Parses a bytea in either the hex format (`\x` followed by pairs of hex digits) or the escape
+/// format.
+///
+/// See [`https://www.postgresql.org/docs/current/datatype-binary.html`]
+#[inline(always)]
+pub fn str_to_bytea(elem: &str) -> Result<Box<[u8]>> {
+    if let Some(hex) = elem.strip_prefix("\\x") {
+        parse_bytea_hex(hex)
+    } else {
+        parse_bytea_escape(elem)
+    }
+}
+
+/// Parses pairs of hex digits, which may be separated by whitespaces.
+pub(crate) fn parse_bytea_hex(input: &str) -> Result<Box<[u8]>> {
+    let digits = input
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect::<String>();
+    hex::decode(&digits)
+        .map(Vec::into_boxed_slice)
+        .map_err(|_| InvalidInputSyntax("invalid input syntax for type bytea".to_string()).into())
+}
+
+/// Parses the escape format, where a backslash is written as `\\` and an arbitrary byte as `\`
+/// followed by 3 octal digits.
+pub(crate) fn parse_bytea_escape(input: &str) -> Result<Box<[u8]>> {
+    let bytes = input.as_bytes();
+    let mut res = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            res.push(bytes[i]);
+            i += 1;
+        } else if bytes.get(i + 1) == Some(&b'\\') {
+            res.push(b'\\');
+            i += 2;
+        } else {
+            match bytes.get(i + 1..i + 4) {
+                Some(&[a, b, c])
+                    if (b'0'..=b'3').contains(&a)
+                        && (b'0'..=b'7').contains(&b)
+                        && (b'0'..=b'7').contains(&c) =>
+                {
+                    res.push(((a - b'0') << 6) | ((b - b'0') << 3) | (c - b'0'));
+                    i += 4;
+                }
+                _ => {
+                    return Err(InvalidInputSyntax(
+                        "invalid input syntax for type bytea".to_string(),
+                    )
+                    .into())
+                }
+            }
+        }
+    }
+    Ok(res.into_boxed_slice())
+}
+
+#[inline(always)]
+pub fn bytea_to_str(elem: &[u8]) -> Result<String> {
+    Ok(ScalarRefImpl::Bytea(elem).to_string())
+}
+
+#[inline(always)]
+pub fn str_to_uuid(elem: &str) -> Result<Uuid> {
+    Uuid::parse_str(elem.trim()).map_err(|_| {
+        InvalidInputSyntax(format!("invalid input syntax for type uuid: \"{}\"", elem)).into()
+    })
+}
+
+#[inline(always)]
+pub fn uuid_to_str(elem: Uuid) -> Result<String> {
+    Ok(elem.to_string())
+}
@@ -104,6 +104,35 @@ where
     general_cmp(l, r, |a, b| a < b)
 }
 
+/// Null-safe inequality: NULL is distinct from any non-NULL value, and not distinct from NULL.
+#[inline(always)]
+pub fn general_is_distinct_from<T1, T2, T3>(l: Option<T1>, r: Option<T2>) -> Result<Option<bool>>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: Ord,
+{
+    match (l, r) {
+        (Some(l), Some(r)) => general_ne::<T1, T2, T3>(l, r).map(Some),
+        (None, None) => Ok(Some(false)),
+        _ => Ok(Some(true)),
+    }
+}
+
+/// Null-safe equality: NULL equals NULL, and is not equal to any non-NULL value.
+#[inline(always)]
+pub fn general_is_not_distinct_from<T1, T2, T3>(
+    l: Option<T1>,
+    r: Option<T2>,
+) -> Result<Option<bool>>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: Ord,
+{
+    general_is_distinct_from::<T1, T2, T3>(l, r).map(|v| v.map(|v| !v))
+}
+
 #[inline(always)]
 fn str_cmp<F>(l: &str, r: &str, func: F) -> Result<bool>
 where
@@ -142,6 +171,16 @@ pub fn str_lt(l: &str, r: &str) -> Result<bool> {
     str_cmp(l, r, |a, b| a < b)
 }
 
+#[inline(always)]
+pub fn str_is_distinct_from(l: Option<&str>, r: Option<&str>) -> Result<Option<bool>> {
+    Ok(Some(l != r))
+}
+
+#[inline(always)]
+pub fn str_is_not_distinct_from(l: Option<&str>, r: Option<&str>) -> Result<Option<bool>> {
+    Ok(Some(l == r))
+}
+
 #[inline(always)]
 pub fn is_true(v: Option<bool>) -> Result<Option<bool>> {
     Ok(Some(v == Some(true)))
@@ -184,4 +223,27 @@ mod tests {
     fn test_deci_f() {
         assert!(general_eq::<_, _, Decimal>(Decimal::from_str("1.1").unwrap(), 1.1f32).unwrap())
     }
+
+    #[test]
+    fn test_is_distinct_from() {
+        let cases = [
+            (Some(1), Some(1), false),
+            (Some(1), Some(2), true),
+            (Some(1), None, true),
+            (None, Some(1), true),
+            (None, None, false),
+        ];
+        for (l, r, expected) in cases {
+            assert_eq!(
+                general_is_distinct_from::<i32, i64, i64>(l, r.map(i64::from)).unwrap(),
+                Some(expected)
+            );
+            assert_eq!(
+                general_is_not_distinct_from::<i32, i64, i64>(l, r.map(i64::from)).unwrap(),
+                Some(!expected)
+            );
+        }
+        assert_eq!(str_is_distinct_from(Some("a"), None).unwrap(), Some(true));
+        assert_eq!(str_is_not_distinct_from(None, None).unwrap(), Some(true));
+    }
 }
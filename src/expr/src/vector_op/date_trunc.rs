@@ -0,0 +1,97 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// Truncates the timestamp to the precision of `field`, e.g. `date_trunc('hour', '2022-06-01
+/// 12:34:56')` is `2022-06-01 12:00:00`. Weeks start on Monday, and centuries and millenniums
+/// start on years like 2001, as in `PostgreSQL`.
+#[inline(always)]
+pub fn date_trunc(field: &str, timestamp: NaiveDateTimeWrapper) -> Result<NaiveDateTimeWrapper> {
+    let time = timestamp.0;
+    let date = time.date();
+    let year = date.year();
+    let first_day_of_year = |year: i32| NaiveDate::from_ymd(year, 1, 1).and_hms(0, 0, 0);
+    let truncated = match field.to_ascii_lowercase().as_str() {
+        "microseconds" => time
+            .with_nanosecond(time.nanosecond() / 1_000 * 1_000)
+            .unwrap(),
+        "milliseconds" => time
+            .with_nanosecond(time.nanosecond() / 1_000_000 * 1_000_000)
+            .unwrap(),
+        "second" => time.with_nanosecond(0).unwrap(),
+        "minute" => date.and_hms(time.hour(), time.minute(), 0),
+        "hour" => date.and_hms(time.hour(), 0, 0),
+        "day" => date.and_hms(0, 0, 0),
+        "week" => {
+            (date - Duration::days(date.weekday().num_days_from_monday() as i64)).and_hms(0, 0, 0)
+        }
+        "month" => NaiveDate::from_ymd(year, date.month(), 1).and_hms(0, 0, 0),
+        "quarter" => NaiveDate::from_ymd(year, (date.month() - 1) / 3 * 3 + 1, 1).and_hms(0, 0, 0),
+        "year" => first_day_of_year(year),
+        "decade" => first_day_of_year(year.div_euclid(10) * 10),
+        "century" => first_day_of_year((year - 1).div_euclid(100) * 100 + 1),
+        "millennium" => first_day_of_year((year - 1).div_euclid(1000) * 1000 + 1),
+        _ => {
+            return Err(RwError::from(InvalidInputSyntax(format!(
+                "timestamp units \"{}\" not recognized",
+                field
+            ))))
+        }
+    };
+    Ok(NaiveDateTimeWrapper::new(truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    #[test]
+    fn test_date_trunc() {
+        let timestamp = NaiveDateTimeWrapper::new(
+            NaiveDateTime::parse_from_str("2022-06-16 12:34:56.789012", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap(),
+        );
+        let cases = [
+            ("microseconds", "2022-06-16 12:34:56.789012"),
+            ("milliseconds", "2022-06-16 12:34:56.789"),
+            ("second", "2022-06-16 12:34:56"),
+            ("MINUTE", "2022-06-16 12:34:00"),
+            ("hour", "2022-06-16 12:00:00"),
+            ("day", "2022-06-16 00:00:00"),
+            ("week", "2022-06-13 00:00:00"),
+            ("month", "2022-06-01 00:00:00"),
+            ("quarter", "2022-04-01 00:00:00"),
+            ("year", "2022-01-01 00:00:00"),
+            ("decade", "2020-01-01 00:00:00"),
+            ("century", "2001-01-01 00:00:00"),
+            ("millennium", "2001-01-01 00:00:00"),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(
+                date_trunc(field, timestamp).unwrap().to_string(),
+                expected,
+                "date_trunc('{}', {})",
+                field,
+                timestamp
+            );
+        }
+        assert!(date_trunc("fortnight", timestamp).is_err());
+    }
+}
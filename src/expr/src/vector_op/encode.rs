@@ -0,0 +1,104 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+
+use crate::vector_op::cast::{parse_bytea_escape, parse_bytea_hex};
+
+/// The line length of the `base64` format, the same as Postgres.
+const BASE64_LINE_LENGTH: usize = 76;
+
+/// Encodes binary data into a textual representation with the given `format`, which can be
+/// `hex`, `base64` or `escape`.
+///
+/// See [`https://www.postgresql.org/docs/current/functions-binarystring.html`]
+#[inline(always)]
+pub fn encode(data: &[u8], format: &str) -> Result<String> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => Ok(hex::encode(data)),
+        "base64" => {
+            let encoded = base64::encode(data);
+            let lines = encoded.as_bytes().chunks(BASE64_LINE_LENGTH);
+            Ok(lines
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        "escape" => {
+            let mut res = String::with_capacity(data.len());
+            for &b in data {
+                match b {
+                    b'\\' => res.push_str("\\\\"),
+                    0 | 0x80..=0xff => res.push_str(&format!("\\{:03o}", b)),
+                    _ => res.push(b as char),
+                }
+            }
+            Ok(res)
+        }
+        _ => Err(unrecognized_format(format)),
+    }
+}
+
+/// Decodes binary data from the textual representation produced by [`encode`].
+#[inline(always)]
+pub fn decode(data: &str, format: &str) -> Result<Box<[u8]>> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => parse_bytea_hex(data),
+        "base64" => {
+            let data = data
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect::<String>();
+            base64::decode(data)
+                .map(Vec::into_boxed_slice)
+                .map_err(|e| InvalidInputSyntax(format!("invalid base64 input: {}", e)).into())
+        }
+        "escape" => parse_bytea_escape(data),
+        _ => Err(unrecognized_format(format)),
+    }
+}
+
+fn unrecognized_format(format: &str) -> RwError {
+    InvalidInputSyntax(format!("unrecognized encoding: \"{}\"", format)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let data = b"\x00\\abc\xff";
+        let cases = [
+            ("hex", "005c616263ff"),
+            ("base64", "AFxhYmP/"),
+            ("escape", "\\000\\\\abc\\377"),
+        ];
+        for (format, encoded) in cases {
+            assert_eq!(encode(data, format).unwrap(), encoded);
+            assert_eq!(&*decode(encoded, format).unwrap(), data);
+        }
+        assert!(encode(data, "utf8").is_err());
+        assert!(decode("zz", "hex").is_err());
+    }
+
+    #[test]
+    fn test_encode_base64_wrap() {
+        let data = [0u8; 60];
+        let encoded = encode(&data, "base64").unwrap();
+        assert_eq!(encoded.lines().next().unwrap().len(), BASE64_LINE_LENGTH);
+        assert_eq!(&*decode(&encoded, "base64").unwrap(), &data[..]);
+    }
+}
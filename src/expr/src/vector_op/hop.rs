@@ -0,0 +1,154 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Duration, NaiveDateTime};
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, NaiveDateTimeArray, NaiveDateTimeArrayBuilder,
+};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{IntervalUnit, NaiveDateTimeWrapper};
+
+use super::cast::date_to_timestamp;
+
+fn interval_to_millis(interval: IntervalUnit) -> Result<i64> {
+    if interval.get_months() != 0 {
+        return Err(RwError::from(InternalError(
+            "unimplemented: hop window only support days or milliseconds".to_string(),
+        )));
+    }
+    Ok(interval.get_days() as i64 * 24 * 60 * 60 * 1000 + interval.get_ms())
+}
+
+/// Returns the number of hop windows every row belongs to, i.e. `window_size / window_slide`.
+pub fn hop_window_count(window_slide: IntervalUnit, window_size: IntervalUnit) -> Result<usize> {
+    let slide = interval_to_millis(window_slide)?;
+    let size = interval_to_millis(window_size)?;
+    if slide <= 0 || size <= 0 || size % slide != 0 {
+        return Err(RwError::from(InternalError(format!(
+            "window size {} must be a positive multiple of window slide {}",
+            window_size, window_slide
+        ))));
+    }
+    Ok((size / slide) as usize)
+}
+
+/// Returns the start of the `index`-th latest hop window containing `time`. The 0-th window
+/// starts at the latest multiple of `window_slide` that is not later than `time`, and each of the
+/// following windows starts one `window_slide` earlier.
+#[inline(always)]
+pub fn hop_window_start(
+    time: NaiveDateTimeWrapper,
+    window_slide: IntervalUnit,
+    index: usize,
+) -> Result<NaiveDateTimeWrapper> {
+    let slide = interval_to_millis(window_slide)?;
+    let millis = time.0.timestamp_millis();
+    let start = millis.div_euclid(slide) * slide - index as i64 * slide;
+    Ok(NaiveDateTimeWrapper(NaiveDateTime::from_timestamp(
+        start.div_euclid(1000),
+        (start.rem_euclid(1000) * 1_000_000) as u32,
+    )))
+}
+
+/// Computes the `window_start` and `window_end` columns of the `index`-th hop window for every
+/// row of `time`, which must be a `DATE` or `TIMESTAMP` array. `NULL` time leads to `NULL`
+/// windows.
+pub fn hop_window_bounds(
+    time: &ArrayImpl,
+    window_slide: IntervalUnit,
+    window_size: IntervalUnit,
+    index: usize,
+) -> Result<(ArrayImpl, ArrayImpl)> {
+    let size = Duration::milliseconds(interval_to_millis(window_size)?);
+    let mut start_builder = NaiveDateTimeArrayBuilder::new(time.len())?;
+    let mut end_builder = NaiveDateTimeArrayBuilder::new(time.len())?;
+
+    let mut append = |time: Option<NaiveDateTimeWrapper>| -> Result<()> {
+        let start = time
+            .map(|time| hop_window_start(time, window_slide, index))
+            .transpose()?;
+        start_builder.append(start)?;
+        end_builder.append(start.map(|start| NaiveDateTimeWrapper(start.0 + size)))
+    };
+    match time {
+        ArrayImpl::NaiveDateTime(array) => {
+            for time in array.iter() {
+                append(time)?;
+            }
+        }
+        ArrayImpl::NaiveDate(array) => {
+            for time in array.iter() {
+                append(time.map(date_to_timestamp).transpose()?)?;
+            }
+        }
+        _ => {
+            return Err(RwError::from(InternalError(format!(
+                "unsupported time column of hop window: {}",
+                time.get_ident()
+            ))))
+        }
+    }
+
+    let start: NaiveDateTimeArray = start_builder.finish()?;
+    let end: NaiveDateTimeArray = end_builder.finish()?;
+    Ok((start.into(), end.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use risingwave_common::types::{IntervalUnit, NaiveDateTimeWrapper};
+
+    use super::*;
+
+    fn timestamp(h: u32, m: u32, s: u32) -> NaiveDateTimeWrapper {
+        let d = NaiveDate::from_ymd(2022, 2, 22);
+        let t = NaiveTime::from_hms(h, m, s);
+        NaiveDateTimeWrapper(NaiveDateTime::new(d, t))
+    }
+
+    #[test]
+    fn test_hop_window_start() {
+        let slide = IntervalUnit::new(0, 0, 15 * 60 * 1000);
+        let size = IntervalUnit::new(0, 0, 45 * 60 * 1000);
+        assert_eq!(hop_window_count(slide, size).unwrap(), 3);
+
+        let time = timestamp(22, 22, 22);
+        assert_eq!(
+            hop_window_start(time, slide, 0).unwrap(),
+            timestamp(22, 15, 0)
+        );
+        assert_eq!(
+            hop_window_start(time, slide, 1).unwrap(),
+            timestamp(22, 0, 0)
+        );
+        assert_eq!(
+            hop_window_start(time, slide, 2).unwrap(),
+            timestamp(21, 45, 0)
+        );
+    }
+
+    #[test]
+    fn test_hop_window_count() {
+        let slide = IntervalUnit::new(0, 0, 20 * 60 * 1000);
+        let size = IntervalUnit::new(0, 0, 30 * 60 * 1000);
+        assert!(hop_window_count(slide, size).is_err());
+        assert!(hop_window_count(IntervalUnit::from_month(1), size).is_err());
+        assert_eq!(
+            hop_window_count(slide, IntervalUnit::from_days(1)).unwrap(),
+            72
+        );
+    }
+}
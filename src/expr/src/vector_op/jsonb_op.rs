@@ -0,0 +1,227 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Operators on jsonb values. Following `PostgreSQL`, accessing a missing key or an out-of-range
+//! index yields NULL instead of an error.
+
+use risingwave_common::array::{JsonbRef, JsonbVal};
+use risingwave_common::error::Result;
+use serde_json::Value;
+
+fn get_field<'a>(v: &'a Value, key: &str) -> Option<&'a Value> {
+    v.as_object().and_then(|obj| obj.get(key))
+}
+
+/// Gets an array element. Negative indexes count from the end of the array.
+fn get_index(v: &Value, idx: i32) -> Option<&Value> {
+    let arr = v.as_array()?;
+    let idx = if idx < 0 {
+        arr.len().checked_sub(idx.unsigned_abs() as usize)?
+    } else {
+        idx as usize
+    };
+    arr.get(idx)
+}
+
+/// Follows a path like `{a,0,b}`, where each element is either an object key or an array index.
+fn get_path<'a>(v: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.trim();
+    let path = path.strip_prefix('{')?.strip_suffix('}')?.trim();
+    if path.is_empty() {
+        return Some(v);
+    }
+    path.split(',').try_fold(v, |v, elem| {
+        let elem = elem.trim();
+        match v {
+            Value::Object(obj) => obj.get(elem),
+            Value::Array(_) => get_index(v, elem.parse().ok()?),
+            _ => None,
+        }
+    })
+}
+
+/// Converts the value to text as `->>` does: strings are unquoted and json `null` is NULL.
+fn to_text(v: &Value) -> Option<String> {
+    match v {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        v => Some(JsonbRef::from_value(v).to_string()),
+    }
+}
+
+/// `jsonb -> text`
+pub fn jsonb_access_field(v: Option<JsonbRef<'_>>, key: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| get_field(v.as_value(), key))
+        .map(|v| JsonbVal::from_value(v.clone())))
+}
+
+/// `jsonb -> integer`
+pub fn jsonb_access_index(v: Option<JsonbRef<'_>>, idx: Option<i32>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| get_index(v.as_value(), idx))
+        .map(|v| JsonbVal::from_value(v.clone())))
+}
+
+/// `jsonb ->> text`
+pub fn jsonb_access_field_str(
+    v: Option<JsonbRef<'_>>,
+    key: Option<&str>,
+) -> Result<Option<String>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| get_field(v.as_value(), key))
+        .and_then(to_text))
+}
+
+/// `jsonb ->> integer`
+pub fn jsonb_access_index_str(v: Option<JsonbRef<'_>>, idx: Option<i32>) -> Result<Option<String>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| get_index(v.as_value(), idx))
+        .and_then(to_text))
+}
+
+/// `jsonb #> text`, where the path is written like a text array, e.g. `'{a,0}'`.
+pub fn jsonb_access_path(v: Option<JsonbRef<'_>>, path: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(path)
+        .and_then(|(v, path)| get_path(v.as_value(), path))
+        .map(|v| JsonbVal::from_value(v.clone())))
+}
+
+/// `jsonb #>> text`
+pub fn jsonb_access_path_str(
+    v: Option<JsonbRef<'_>>,
+    path: Option<&str>,
+) -> Result<Option<String>> {
+    Ok(v.zip(path)
+        .and_then(|(v, path)| get_path(v.as_value(), path))
+        .and_then(to_text))
+}
+
+fn contains(l: &Value, r: &Value) -> bool {
+    match (l, r) {
+        (Value::Object(l), Value::Object(r)) => r
+            .iter()
+            .all(|(k, rv)| l.get(k).map_or(false, |lv| contains(lv, rv))),
+        (Value::Array(l), Value::Array(r)) => {
+            r.iter().all(|rv| l.iter().any(|lv| contains(lv, rv)))
+        }
+        (l, r) => l == r,
+    }
+}
+
+/// `jsonb @> jsonb`. As a special case, an array at the top level contains a primitive value if
+/// any of its elements equals the value.
+pub fn jsonb_contains(l: JsonbRef<'_>, r: JsonbRef<'_>) -> Result<bool> {
+    let (l, r) = (l.as_value(), r.as_value());
+    Ok(match (l, r) {
+        (Value::Array(arr), r) if !r.is_array() && !r.is_object() => arr.contains(r),
+        (l, r) => contains(l, r),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        s.parse().unwrap()
+    }
+
+    fn r(v: &JsonbVal) -> Option<JsonbRef<'_>> {
+        Some(JsonbRef::from_value(v.as_value()))
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = jsonb(r#"{"a": {"b": [1, "x", null]}, "c": "str"}"#);
+        assert_eq!(
+            jsonb_access_field(r(&v), Some("a")).unwrap(),
+            Some(jsonb(r#"{"b": [1, "x", null]}"#))
+        );
+        assert_eq!(jsonb_access_field(r(&v), Some("z")).unwrap(), None);
+        assert_eq!(jsonb_access_field(r(&v), None).unwrap(), None);
+        assert_eq!(
+            jsonb_access_field_str(r(&v), Some("c")).unwrap(),
+            Some("str".to_string())
+        );
+
+        let arr = jsonb(r#"[1, "x", null, {"k": 2}]"#);
+        assert_eq!(
+            jsonb_access_index(r(&arr), Some(0)).unwrap(),
+            Some(jsonb("1"))
+        );
+        assert_eq!(
+            jsonb_access_index(r(&arr), Some(-1)).unwrap(),
+            Some(jsonb(r#"{"k": 2}"#))
+        );
+        assert_eq!(jsonb_access_index(r(&arr), Some(4)).unwrap(), None);
+        assert_eq!(jsonb_access_index(r(&arr), Some(-5)).unwrap(), None);
+        assert_eq!(jsonb_access_index(r(&v), Some(0)).unwrap(), None);
+        assert_eq!(
+            jsonb_access_index_str(r(&arr), Some(1)).unwrap(),
+            Some("x".to_string())
+        );
+        assert_eq!(jsonb_access_index_str(r(&arr), Some(2)).unwrap(), None);
+        assert_eq!(
+            jsonb_access_index_str(r(&arr), Some(3)).unwrap(),
+            Some(r#"{"k": 2}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_jsonb_access_path() {
+        let v = jsonb(r#"{"a": {"b": [1, "x", null]}}"#);
+        assert_eq!(
+            jsonb_access_path(r(&v), Some("{a,b,0}")).unwrap(),
+            Some(jsonb("1"))
+        );
+        assert_eq!(
+            jsonb_access_path_str(r(&v), Some("{a, b, -2}")).unwrap(),
+            Some("x".to_string())
+        );
+        assert_eq!(
+            jsonb_access_path(r(&v), Some("{}")).unwrap(),
+            Some(v.clone())
+        );
+        assert_eq!(jsonb_access_path(r(&v), Some("{a,c}")).unwrap(), None);
+        assert_eq!(jsonb_access_path(r(&v), Some("{a,b,z}")).unwrap(), None);
+        assert_eq!(jsonb_access_path(r(&v), Some("a")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_jsonb_contains() {
+        let cases = [
+            (
+                r#"{"a": 1, "b": {"c": [1, 2]}}"#,
+                r#"{"b": {"c": [2]}}"#,
+                true,
+            ),
+            (r#"{"a": 1, "b": 2}"#, r#"{"a": 1, "c": 3}"#, false),
+            (r#"[1, [2, 3]]"#, r#"[[3]]"#, true),
+            (r#"[1, [2, 3]]"#, r#"[3]"#, false),
+            (r#"[1, 2, 3]"#, r#"[3, 1, 1]"#, true),
+            (r#"["a", "b"]"#, r#""a""#, true),
+            (r#"[["a"]]"#, r#""a""#, false),
+            (r#"{"a": 1}"#, r#"{}"#, true),
+            (r#""a""#, r#"["a"]"#, false),
+        ];
+        for (l, rhs, expected) in cases {
+            let (l, rhs) = (jsonb(l), jsonb(rhs));
+            assert_eq!(
+                jsonb_contains(r(&l).unwrap(), r(&rhs).unwrap()).unwrap(),
+                expected
+            );
+        }
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 
 #[inline(always)]
 pub fn like_default(s: &str, p: &str) -> Result<bool> {
@@ -55,9 +55,104 @@ pub fn like_default(s: &str, p: &str) -> Result<bool> {
     Ok(true)
 }
 
+/// `s ILIKE p`, which is `LIKE` ignoring the case.
+#[inline(always)]
+pub fn ilike_default(s: &str, p: &str) -> Result<bool> {
+    like_default(&s.to_lowercase(), &p.to_lowercase())
+}
+
+enum PatternItem {
+    /// `%`
+    Any,
+    /// `_`
+    One,
+    Char(char),
+}
+
+/// Parses the pattern with the escape character, which makes the following `%`, `_` or escape
+/// character match itself. An empty escape string disables escaping.
+fn parse_pattern(p: &str, escape: &str) -> Result<Vec<PatternItem>> {
+    let mut escape_chars = escape.chars();
+    let escape = escape_chars.next();
+    if escape_chars.next().is_some() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "invalid escape string: escape string must be empty or one character".to_string(),
+        )
+        .into());
+    }
+    let mut items = Vec::with_capacity(p.len());
+    let mut chars = p.chars();
+    while let Some(c) = chars.next() {
+        let item = match c {
+            c if Some(c) == escape => match chars.next() {
+                Some(c) => PatternItem::Char(c),
+                None => {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "LIKE pattern must not end with escape character".to_string(),
+                    )
+                    .into())
+                }
+            },
+            '%' => PatternItem::Any,
+            '_' => PatternItem::One,
+            c => PatternItem::Char(c),
+        };
+        items.push(item);
+    }
+    Ok(items)
+}
+
+/// `s LIKE p ESCAPE escape`
+#[inline(always)]
+pub fn like_escape(s: &str, p: &str, escape: &str) -> Result<bool> {
+    let pattern = parse_pattern(p, escape)?;
+    let s = s.chars().collect::<Vec<_>>();
+    let (mut px, mut sx) = (0, 0);
+    // The position of the last `%` in the pattern, and the position in the string it is matched
+    // up to, to backtrack to.
+    let mut backtrack = None;
+    while sx < s.len() {
+        match pattern.get(px) {
+            Some(PatternItem::One) => {
+                px += 1;
+                sx += 1;
+                continue;
+            }
+            Some(PatternItem::Char(c)) if *c == s[sx] => {
+                px += 1;
+                sx += 1;
+                continue;
+            }
+            Some(PatternItem::Any) => {
+                backtrack = Some((px, sx));
+                px += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match backtrack {
+            Some((any_px, any_sx)) => {
+                backtrack = Some((any_px, any_sx + 1));
+                px = any_px + 1;
+                sx = any_sx + 1;
+            }
+            None => return Ok(false),
+        }
+    }
+    Ok(pattern[px..]
+        .iter()
+        .all(|item| matches!(item, PatternItem::Any)))
+}
+
+/// `s ILIKE p ESCAPE escape`
+#[inline(always)]
+pub fn ilike_escape(s: &str, p: &str, escape: &str) -> Result<bool> {
+    like_escape(&s.to_lowercase(), &p.to_lowercase(), &escape.to_lowercase())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::like_default;
+    use super::{ilike_default, like_default, like_escape};
 
     static CASES: &[(&str, &str, std::option::Option<bool>)] = &[
         (r#"ABCDE"#, r#"%abcde%"#, Some(false)),
@@ -94,4 +189,30 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_like_escape() {
+        let cases = [
+            ("100%", "100!%", "!", true),
+            ("1000", "100!%", "!", false),
+            ("a_c", "a\\_c", "\\", true),
+            ("abc", "a\\_c", "\\", false),
+            ("abc", "a_c", "", true),
+            ("a%c", "a%%c", "%", true),
+            ("like", "l%ik%e", "", true),
+            ("aaaaabbb", "a%a%a%a%a%a%b", "", false),
+        ];
+        for (target, pattern, escape, expected) in cases {
+            let output = like_escape(target, pattern, escape).unwrap();
+            assert_eq!(output, expected, "target={}, pattern={}", target, pattern);
+        }
+        assert!(like_escape("abc", "abc", "ab").is_err());
+        assert!(like_escape("abc", "ab!", "!").is_err());
+    }
+
+    #[test]
+    fn test_ilike() {
+        assert!(ilike_default("ABCDE", "%abcde%").unwrap());
+        assert!(!ilike_default("ABCDE", "%abcdf%").unwrap());
+    }
 }
@@ -0,0 +1,304 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_traits::{CheckedNeg, Float, FromPrimitive, ToPrimitive, Zero};
+use risingwave_common::error::ErrorCode::{InvalidInputSyntax, NumericValueOutOfRange};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{Decimal, OrderedF64};
+use rust_decimal::{Decimal as RustDecimal, MathematicalOps};
+
+use super::arithmetic_op::general_neg;
+
+fn invalid_argument(msg: &str) -> RwError {
+    InvalidInputSyntax(msg.to_string()).into()
+}
+
+/// Checks that a finite input doesn't produce an infinite result.
+fn check_overflow(input_is_finite: bool, result: f64) -> Result<OrderedF64> {
+    if input_is_finite && result.is_infinite() {
+        Err(NumericValueOutOfRange.into())
+    } else {
+        Ok(result.into())
+    }
+}
+
+#[inline(always)]
+pub fn int_abs<T: Zero + PartialOrd + CheckedNeg>(input: T) -> Result<T> {
+    if input < T::zero() {
+        general_neg(input)
+    } else {
+        Ok(input)
+    }
+}
+
+#[inline(always)]
+pub fn float_abs<T: Float>(input: T) -> Result<T> {
+    Ok(input.abs())
+}
+
+#[inline(always)]
+pub fn decimal_abs(input: Decimal) -> Result<Decimal> {
+    Ok(input.abs())
+}
+
+#[inline(always)]
+pub fn sign_f64(input: OrderedF64) -> Result<OrderedF64> {
+    let v = input.0;
+    Ok(if v > 0.0 {
+        1.0
+    } else if v < 0.0 {
+        -1.0
+    } else {
+        // Zero and NaN are returned as is.
+        v
+    }
+    .into())
+}
+
+#[inline(always)]
+pub fn sign_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(match input {
+        Decimal::Normalized(d) if d.is_zero() => Decimal::zero(),
+        Decimal::Normalized(d) if d.is_sign_negative() => Decimal::from(-1),
+        Decimal::Normalized(_) | Decimal::PositiveINF => Decimal::from(1),
+        Decimal::NegativeINF => Decimal::from(-1),
+        Decimal::NaN => Decimal::NaN,
+    })
+}
+
+#[inline(always)]
+pub fn sqrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if input.0 < 0.0 {
+        return Err(invalid_argument(
+            "cannot take square root of a negative number",
+        ));
+    }
+    Ok(input.0.sqrt().into())
+}
+
+#[inline(always)]
+pub fn sqrt_decimal(input: Decimal) -> Result<Decimal> {
+    match input {
+        Decimal::Normalized(d) if d.is_sign_negative() && !d.is_zero() => Err(invalid_argument(
+            "cannot take square root of a negative number",
+        )),
+        Decimal::Normalized(d) => d
+            .sqrt()
+            .map(Decimal::Normalized)
+            .ok_or_else(|| NumericValueOutOfRange.into()),
+        Decimal::NegativeINF => Err(invalid_argument(
+            "cannot take square root of a negative number",
+        )),
+        d => Ok(d),
+    }
+}
+
+fn check_log_argument_f64(input: f64) -> Result<()> {
+    if input == 0.0 {
+        Err(invalid_argument("cannot take logarithm of zero"))
+    } else if input < 0.0 {
+        Err(invalid_argument(
+            "cannot take logarithm of a negative number",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_log_argument_decimal(input: Decimal) -> Result<()> {
+    match input {
+        Decimal::Normalized(d) if d.is_zero() => {
+            Err(invalid_argument("cannot take logarithm of zero"))
+        }
+        Decimal::Normalized(d) if d.is_sign_negative() => Err(invalid_argument(
+            "cannot take logarithm of a negative number",
+        )),
+        Decimal::NegativeINF => Err(invalid_argument(
+            "cannot take logarithm of a negative number",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[inline(always)]
+pub fn ln_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_log_argument_f64(input.0)?;
+    Ok(input.0.ln().into())
+}
+
+#[inline(always)]
+pub fn ln_decimal(input: Decimal) -> Result<Decimal> {
+    check_log_argument_decimal(input)?;
+    match input {
+        Decimal::Normalized(d) => Ok(Decimal::Normalized(d.ln())),
+        d => Ok(d),
+    }
+}
+
+#[inline(always)]
+pub fn log10_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_log_argument_f64(input.0)?;
+    Ok(input.0.log10().into())
+}
+
+#[inline(always)]
+pub fn log10_decimal(input: Decimal) -> Result<Decimal> {
+    check_log_argument_decimal(input)?;
+    match input {
+        Decimal::Normalized(d) => Ok(Decimal::Normalized(d.log10())),
+        d => Ok(d),
+    }
+}
+
+#[inline(always)]
+pub fn exp_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_overflow(input.0.is_finite(), input.0.exp())
+}
+
+#[inline(always)]
+pub fn exp_decimal(input: Decimal) -> Result<Decimal> {
+    match input {
+        Decimal::Normalized(d) if d.is_sign_negative() => {
+            // A large negative exponent underflows to zero instead of an error, so it's computed
+            // as the reciprocal of the positive one.
+            Ok(match d.abs().checked_exp() {
+                Some(e) => Decimal::Normalized(RustDecimal::ONE / e),
+                None => Decimal::zero(),
+            })
+        }
+        Decimal::Normalized(d) => d
+            .checked_exp()
+            .map(Decimal::Normalized)
+            .ok_or_else(|| NumericValueOutOfRange.into()),
+        Decimal::NegativeINF => Ok(Decimal::zero()),
+        d => Ok(d),
+    }
+}
+
+#[inline(always)]
+pub fn pow_f64(base: OrderedF64, exponent: OrderedF64) -> Result<OrderedF64> {
+    let (x, y) = (base.0, exponent.0);
+    if x == 0.0 && y < 0.0 {
+        return Err(invalid_argument(
+            "zero raised to a negative power is undefined",
+        ));
+    }
+    if x < 0.0 && y.is_finite() && y.fract() != 0.0 {
+        return Err(invalid_argument(
+            "a negative number raised to a non-integer power yields a complex result",
+        ));
+    }
+    check_overflow(x.is_finite() && y.is_finite(), x.powf(y))
+}
+
+#[inline(always)]
+pub fn pow_decimal(base: Decimal, exponent: Decimal) -> Result<Decimal> {
+    match (base, exponent) {
+        (Decimal::Normalized(x), Decimal::Normalized(y)) => {
+            if x.is_zero() && y.is_sign_negative() && !y.is_zero() {
+                return Err(invalid_argument(
+                    "zero raised to a negative power is undefined",
+                ));
+            }
+            if x.is_sign_negative() && !x.is_zero() && !y.fract().is_zero() {
+                return Err(invalid_argument(
+                    "a negative number raised to a non-integer power yields a complex result",
+                ));
+            }
+            x.checked_powd(y)
+                .map(Decimal::Normalized)
+                .ok_or_else(|| NumericValueOutOfRange.into())
+        }
+        // Infinities and NaN follow the rules of `double precision`.
+        (base, exponent) => {
+            let result = pow_f64(
+                base.to_f64().unwrap().into(),
+                exponent.to_f64().unwrap().into(),
+            )?;
+            Ok(Decimal::from_f64(result.0).unwrap())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(int_abs(-5i32).unwrap(), 5);
+        assert!(int_abs(i16::MIN).is_err());
+        assert_eq!(float_abs(OrderedF64::from(-1.5)).unwrap().0, 1.5);
+        assert_eq!(decimal_abs(dec("-1.5")).unwrap(), dec("1.5"));
+        assert_eq!(
+            decimal_abs(Decimal::NegativeINF).unwrap(),
+            Decimal::PositiveINF
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(sign_f64((-3.0).into()).unwrap().0, -1.0);
+        assert_eq!(sign_f64(0.0.into()).unwrap().0, 0.0);
+        assert!(sign_f64(f64::NAN.into()).unwrap().0.is_nan());
+        assert_eq!(sign_decimal(dec("2.5")).unwrap(), Decimal::from(1));
+        assert_eq!(sign_decimal(dec("0")).unwrap(), Decimal::zero());
+        assert_eq!(
+            sign_decimal(Decimal::NegativeINF).unwrap(),
+            Decimal::from(-1)
+        );
+    }
+
+    #[test]
+    fn test_sqrt_log_exp() {
+        assert_eq!(sqrt_f64(16.0.into()).unwrap().0, 4.0);
+        assert!(sqrt_f64((-1.0).into()).is_err());
+        assert_eq!(sqrt_decimal(dec("16")).unwrap(), dec("4"));
+        assert!(sqrt_decimal(dec("-1")).is_err());
+
+        assert_eq!(log10_f64(100.0.into()).unwrap().0, 2.0);
+        assert!(ln_f64(0.0.into()).is_err());
+        assert!(ln_decimal(dec("-1")).is_err());
+        assert_eq!(
+            ln_decimal(Decimal::PositiveINF).unwrap(),
+            Decimal::PositiveINF
+        );
+
+        assert_eq!(exp_f64(0.0.into()).unwrap().0, 1.0);
+        assert!(exp_f64(1000.0.into()).is_err());
+        assert_eq!(exp_f64((-1000.0).into()).unwrap().0, 0.0);
+        assert_eq!(exp_decimal(dec("0")).unwrap(), dec("1"));
+        assert!(exp_decimal(dec("1000")).is_err());
+        assert_eq!(exp_decimal(dec("-1000")).unwrap(), Decimal::zero());
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(pow_f64(2.0.into(), 10.0.into()).unwrap().0, 1024.0);
+        assert_eq!(pow_f64((-2.0).into(), 3.0.into()).unwrap().0, -8.0);
+        assert!(pow_f64(0.0.into(), (-1.0).into()).is_err());
+        assert!(pow_f64((-2.0).into(), 0.5.into()).is_err());
+        assert!(pow_f64(10.0.into(), 1000.0.into()).is_err());
+        assert_eq!(pow_decimal(dec("2"), dec("10")).unwrap(), dec("1024"));
+        assert!(pow_decimal(dec("0"), dec("-1")).is_err());
+        assert!(pow_decimal(dec("-2"), dec("0.5")).is_err());
+        assert_eq!(pow_decimal(Decimal::NaN, dec("1")).unwrap(), Decimal::NaN);
+    }
+}
@@ -17,20 +17,33 @@ pub mod arithmetic_op;
 pub mod cast;
 pub mod cmp;
 pub mod conjunction;
+pub mod date_trunc;
+pub mod encode;
 pub mod extract;
+pub mod hop;
+pub mod jsonb_op;
+pub mod window_function;
 
 pub mod length;
 pub mod like;
+pub mod math;
+pub mod md5;
+pub mod pad;
 
 pub mod position;
 
 pub mod ascii;
+pub mod concat_op;
 pub mod lower;
 pub mod ltrim;
 pub mod replace;
 pub mod round;
 pub mod rtrim;
+pub mod split_part;
+pub mod starts_with;
 pub mod substr;
+pub mod to_char;
+pub mod to_timestamp;
 pub mod translate;
 pub mod trim;
 pub mod tumble;
@@ -0,0 +1,88 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+/// Returns the first `length` characters of the string.
+fn truncate(s: &str, length: usize) -> &str {
+    match s.char_indices().nth(length) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
+/// Extends the string to `length` characters by prepending the repeated `fill`. The string is
+/// truncated on the right if it is already longer.
+#[inline(always)]
+pub fn lpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let length = length.max(0) as usize;
+    let char_count = s.chars().count();
+    if char_count >= length || fill.is_empty() {
+        return writer.write_ref(truncate(s, length));
+    }
+    writer.write_from_char_iter(
+        fill.chars()
+            .cycle()
+            .take(length - char_count)
+            .chain(s.chars()),
+    )
+}
+
+/// Extends the string to `length` characters by appending the repeated `fill`. The string is
+/// truncated on the right if it is already longer.
+#[inline(always)]
+pub fn rpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let length = length.max(0) as usize;
+    let char_count = s.chars().count();
+    if char_count >= length || fill.is_empty() {
+        return writer.write_ref(truncate(s, length));
+    }
+    writer.write_from_char_iter(
+        s.chars()
+            .chain(fill.chars().cycle().take(length - char_count)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_pad() -> Result<()> {
+        let cases = [
+            ("hi", 5, "xy", "xyxhi", "hixyx"),
+            ("hi", 2, "xy", "hi", "hi"),
+            ("hello", 2, "xy", "he", "he"),
+            ("hi", 5, "", "hi", "hi"),
+            ("hi", -1, "xy", "", ""),
+            ("你好", 3, "呀", "呀你好", "你好呀"),
+        ];
+
+        for (s, length, fill, expected_lpad, expected_rpad) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let guard = lpad(s, length, fill, builder.writer())?;
+            let array = guard.into_inner().finish()?;
+            assert_eq!(array.value_at(0), Some(expected_lpad));
+
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let guard = rpad(s, length, fill, builder.writer())?;
+            let array = guard.into_inner().finish()?;
+            assert_eq!(array.value_at(0), Some(expected_rpad));
+        }
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use risingwave_common::error::Result;
-use risingwave_common::types::Decimal;
+use risingwave_common::types::{Decimal, OrderedF64};
 
 #[inline(always)]
 pub fn round_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal> {
@@ -25,13 +25,45 @@ pub fn round_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal>
     }
 }
 
+/// Rounds half to even like `rint` in C, which is what `PostgreSQL` does for `double precision`.
+#[inline(always)]
+pub fn round_f64(input: OrderedF64) -> Result<OrderedF64> {
+    let v = input.0;
+    let rounded = v.round();
+    if (rounded - v).abs() == 0.5 {
+        Ok((2.0 * (v / 2.0).round()).into())
+    } else {
+        Ok(rounded.into())
+    }
+}
+
+#[inline(always)]
+pub fn ceil_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.ceil().into())
+}
+
+#[inline(always)]
+pub fn floor_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.floor().into())
+}
+
+#[inline(always)]
+pub fn ceil_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.ceil())
+}
+
+#[inline(always)]
+pub fn floor_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.floor())
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use risingwave_common::types::Decimal;
 
-    use crate::vector_op::round::round_digits;
+    use crate::vector_op::round::*;
 
     fn do_test(input: &str, digits: i32, expected_output: &str) {
         let v = Decimal::from_str(input).unwrap();
@@ -46,4 +78,25 @@ mod tests {
         do_test("84818.15", 1, "84818.2");
         do_test("21.372736", -1, "0");
     }
+
+    #[test]
+    fn test_round_f64() {
+        let round = |v: f64| round_f64(v.into()).unwrap().0;
+        assert_eq!(round(2.5), 2.0);
+        assert_eq!(round(3.5), 4.0);
+        assert_eq!(round(-2.5), -2.0);
+        assert_eq!(round(1.4), 1.0);
+        assert_eq!(round(-1.6), -2.0);
+        assert!(round(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ceil_floor() {
+        assert_eq!(ceil_f64((-1.5).into()).unwrap().0, -1.0);
+        assert_eq!(floor_f64((-1.5).into()).unwrap().0, -2.0);
+        let d = Decimal::from_str("-42.8").unwrap();
+        assert_eq!(ceil_decimal(d).unwrap().to_string(), "-42");
+        assert_eq!(floor_decimal(d).unwrap().to_string(), "-43");
+        assert_eq!(ceil_decimal(Decimal::NaN).unwrap(), Decimal::NaN);
+    }
 }
@@ -0,0 +1,73 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+/// Returns the `nth` field of the string split by the delimiter, counting from the end if `nth`
+/// is negative. An out-of-range field is an empty string.
+#[inline(always)]
+pub fn split_part(s: &str, delimiter: &str, nth: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    if nth == 0 {
+        return Err(
+            ErrorCode::InvalidInputSyntax("field position must not be zero".to_string()).into(),
+        );
+    }
+    let part = if delimiter.is_empty() {
+        // The whole string is the only field.
+        if nth == 1 || nth == -1 {
+            s
+        } else {
+            ""
+        }
+    } else if nth > 0 {
+        s.split(delimiter).nth(nth as usize - 1).unwrap_or("")
+    } else {
+        s.rsplit(delimiter)
+            .nth(nth.unsigned_abs() as usize - 1)
+            .unwrap_or("")
+    };
+    writer.write_ref(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_split_part() -> Result<()> {
+        let cases = [
+            ("abc~@~def~@~ghi", "~@~", 2, "def"),
+            ("abc~@~def~@~ghi", "~@~", -1, "ghi"),
+            ("abc~@~def~@~ghi", "~@~", 4, ""),
+            ("abc,def", "", 1, "abc,def"),
+            ("abc,def", "", 2, ""),
+            ("", ",", 1, ""),
+        ];
+
+        for (s, delimiter, nth, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = split_part(s, delimiter, nth, writer)?;
+            let array = guard.into_inner().finish()?;
+            assert_eq!(array.value_at(0), Some(expected));
+        }
+
+        let builder = Utf8ArrayBuilder::new(1)?;
+        assert!(split_part("abc", ",", 0, builder.writer()).is_err());
+        Ok(())
+    }
+}
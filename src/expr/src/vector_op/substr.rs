@@ -43,7 +43,7 @@ pub fn substr_start_for(
         ))
         .into());
     }
-    let begin = max(start - 1, 0) as usize;
+    let begin = min(max(start - 1, 0) as usize, s.len());
     let end = min(max(start - 1 + count, 0) as usize, s.len());
     writer.write_ref(&s[begin..end])
 }
@@ -65,6 +65,7 @@ mod tests {
             (s.to_owned(), Some(4), Some(2), "cg"),
             (s.to_owned(), Some(-1), Some(-5), "[unused result]"),
             (s.to_owned(), Some(-1), Some(5), "cxs"),
+            (s.to_owned(), Some(12), Some(2), ""),
         ];
 
         for (s, off, len, expected) in cases {
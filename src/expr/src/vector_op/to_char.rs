@@ -0,0 +1,114 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// The `PostgreSQL` datetime format patterns and the `chrono` format specifiers they map to. A
+/// pattern must come before the other patterns that are its prefix.
+const PATTERNS: &[(&str, &str)] = &[
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("US", "%6f"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("am", "%P"),
+    ("pm", "%P"),
+    ("IYYY", "%G"),
+    ("IW", "%V"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("Month", "%B"),
+    ("Mon", "%b"),
+    ("MM", "%m"),
+    ("Day", "%A"),
+    ("Dy", "%a"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+];
+
+/// Compiles a `PostgreSQL` datetime format pattern like `YYYY-MM-DD HH24:MI:SS` to the equivalent
+/// `chrono` format string. Other characters are copied as they are, and so is the text in double
+/// quotes, without the quotes.
+pub fn compile_pattern(pattern: &str) -> String {
+    let mut format = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    let mut quoted = false;
+    while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            quoted = !quoted;
+            rest = &rest[1..];
+            continue;
+        }
+        if !quoted {
+            if let Some((pat, spec)) = PATTERNS.iter().find(|(pat, _)| rest.starts_with(pat)) {
+                format.push_str(spec);
+                rest = &rest[pat.len()..];
+                continue;
+            }
+        }
+        if c == '%' {
+            format.push_str("%%");
+        } else {
+            format.push(c);
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    format
+}
+
+#[inline(always)]
+pub fn to_char_timestamp(
+    timestamp: NaiveDateTimeWrapper,
+    pattern: &str,
+    writer: BytesWriter,
+) -> Result<BytesGuard> {
+    let format = compile_pattern(pattern);
+    writer.write_ref(&timestamp.0.format(&format).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_to_char() -> Result<()> {
+        let timestamp =
+            NaiveDateTimeWrapper::new(NaiveDate::from_ymd(2022, 6, 5).and_hms_milli(15, 4, 5, 67));
+        let cases = [
+            ("YYYY-MM-DD HH24:MI:SS", "2022-06-05 15:04:05"),
+            ("YY/MM/DD HH12:MI:SS.MS AM", "22/06/05 03:04:05.067 PM"),
+            ("Dy, DD Mon YYYY", "Sun, 05 Jun 2022"),
+            ("Day Month DDD", "Sunday June 156"),
+            ("IYYY-IW", "2022-22"),
+            ("\"Year\" YYYY, 100%", "Year 2022, 100%"),
+        ];
+        for (pattern, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = to_char_timestamp(timestamp, pattern, writer)?;
+            let array = guard.into_inner().finish()?;
+            assert_eq!(array.value_at(0).unwrap(), expected);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+use super::to_char::compile_pattern;
+
+/// Parses the text to a timestamp with a `PostgreSQL` datetime format pattern, which is the
+/// reverse of `to_char`. If the pattern has no time fields, the time is midnight.
+#[inline(always)]
+pub fn to_timestamp(s: &str, pattern: &str) -> Result<NaiveDateTimeWrapper> {
+    let format = compile_pattern(pattern);
+    NaiveDateTime::parse_from_str(s, &format)
+        .or_else(|_| NaiveDate::parse_from_str(s, &format).map(|date| date.and_hms(0, 0, 0)))
+        .map(NaiveDateTimeWrapper::new)
+        .map_err(|e| {
+            RwError::from(InvalidInputSyntax(format!(
+                "failed to parse \"{}\" with format \"{}\": {}",
+                s, pattern, e
+            )))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_timestamp() {
+        let cases = [
+            (
+                "2022-06-05 15:04:05",
+                "YYYY-MM-DD HH24:MI:SS",
+                NaiveDate::from_ymd(2022, 6, 5).and_hms(15, 4, 5),
+            ),
+            (
+                "05 Jun 2022 03:04 PM",
+                "DD Mon YYYY HH12:MI AM",
+                NaiveDate::from_ymd(2022, 6, 5).and_hms(15, 4, 0),
+            ),
+            (
+                "2022/06/05",
+                "YYYY/MM/DD",
+                NaiveDate::from_ymd(2022, 6, 5).and_hms(0, 0, 0),
+            ),
+        ];
+        for (s, pattern, expected) in cases {
+            assert_eq!(to_timestamp(s, pattern).unwrap().0, expected);
+        }
+        assert!(to_timestamp("2022-06-05", "YYYY/MM/DD").is_err());
+    }
+}
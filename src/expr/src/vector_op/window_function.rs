@@ -0,0 +1,405 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluation of window functions on a partition whose rows are already sorted by the order keys.
+//! Shared by the batch and streaming over aggregation executors.
+
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_pb::expr::window_frame::{Bound as ProstBound, BoundType, Type as FrameType};
+use risingwave_pb::expr::window_function::Type;
+use risingwave_pb::expr::{WindowFrame as ProstWindowFrame, WindowFunction as ProstWindowFunction};
+
+use crate::vector_op::agg::{AggStateFactory, BoxedAggState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(usize),
+    CurrentRow,
+    Following(usize),
+    UnboundedFollowing,
+}
+
+impl FrameBound {
+    fn from_prost(prost: &ProstBound) -> Result<Self> {
+        let offset = prost.offset as usize;
+        Ok(match prost.get_type()? {
+            BoundType::UnboundedPreceding => Self::UnboundedPreceding,
+            BoundType::Preceding => Self::Preceding(offset),
+            BoundType::CurrentRow => Self::CurrentRow,
+            BoundType::Following => Self::Following(offset),
+            BoundType::UnboundedFollowing => Self::UnboundedFollowing,
+            BoundType::BoundInvalid => {
+                return Err(ErrorCode::InternalError("invalid window frame bound".into()).into())
+            }
+        })
+    }
+}
+
+/// The frame of a window function. A `RANGE` frame only supports `UNBOUNDED` and `CURRENT ROW`
+/// bounds, where `CURRENT ROW` includes all peers of the current row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowFrame {
+    pub rows: bool,
+    pub start: FrameBound,
+    pub end: FrameBound,
+}
+
+impl WindowFrame {
+    fn from_prost(prost: &ProstWindowFrame) -> Result<Self> {
+        let rows = match prost.get_type()? {
+            FrameType::Rows => true,
+            FrameType::Range => false,
+            FrameType::Invalid => {
+                return Err(ErrorCode::InternalError("invalid window frame type".into()).into())
+            }
+        };
+        let start = FrameBound::from_prost(prost.get_start()?)?;
+        let end = FrameBound::from_prost(prost.get_end()?)?;
+        if !rows
+            && [start, end]
+                .iter()
+                .any(|b| matches!(b, FrameBound::Preceding(_) | FrameBound::Following(_)))
+        {
+            return Err(ErrorCode::InternalError(
+                "RANGE frame with offset is not supported".into(),
+            )
+            .into());
+        }
+        Ok(Self { rows, start, end })
+    }
+
+    /// Returns the range of row indices in the frame of the `i`-th row. `peers` is the range of
+    /// the peer group of the `i`-th row. The result may be empty.
+    fn bounds(&self, i: usize, len: usize, peers: (usize, usize)) -> (usize, usize) {
+        let start = match self.start {
+            FrameBound::UnboundedPreceding => 0,
+            FrameBound::Preceding(n) => i.saturating_sub(n),
+            FrameBound::CurrentRow if self.rows => i,
+            FrameBound::CurrentRow => peers.0,
+            FrameBound::Following(n) => (i + n).min(len),
+            FrameBound::UnboundedFollowing => len,
+        };
+        let end = match self.end {
+            FrameBound::UnboundedPreceding => 0,
+            FrameBound::Preceding(n) => (i + 1).saturating_sub(n),
+            FrameBound::CurrentRow if self.rows => i + 1,
+            FrameBound::CurrentRow => peers.1,
+            FrameBound::Following(n) => (i + n + 1).min(len),
+            FrameBound::UnboundedFollowing => len,
+        };
+        (start, end.max(start))
+    }
+}
+
+enum WindowFunctionKind {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag,
+    Lead,
+    Aggregate(AggStateFactory),
+}
+
+/// A window function call, which can be evaluated with [`evaluate_partition`].
+pub struct WindowFunction {
+    kind: WindowFunctionKind,
+    arg: usize,
+    offset: usize,
+    frame: WindowFrame,
+    return_type: DataType,
+}
+
+impl WindowFunction {
+    pub fn from_prost(prost: &ProstWindowFunction) -> Result<Self> {
+        let kind = match prost.get_type()? {
+            Type::RowNumber => WindowFunctionKind::RowNumber,
+            Type::Rank => WindowFunctionKind::Rank,
+            Type::DenseRank => WindowFunctionKind::DenseRank,
+            Type::Lag => WindowFunctionKind::Lag,
+            Type::Lead => WindowFunctionKind::Lead,
+            Type::Aggregate => {
+                WindowFunctionKind::Aggregate(AggStateFactory::new(prost.get_agg_call()?)?)
+            }
+            Type::Invalid => {
+                return Err(ErrorCode::InternalError("invalid window function".into()).into())
+            }
+        };
+        let arg = match kind {
+            WindowFunctionKind::Lag | WindowFunctionKind::Lead => {
+                prost.get_arg()?.get_column_idx() as usize
+            }
+            _ => 0,
+        };
+        Ok(Self {
+            kind,
+            arg,
+            offset: prost.offset as usize,
+            frame: WindowFrame::from_prost(prost.get_frame()?)?,
+            return_type: DataType::from(prost.get_return_type()?),
+        })
+    }
+
+    pub fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn evaluate(
+        &self,
+        rows: &[Row],
+        chunk: &DataChunk,
+        peers: &[(usize, usize)],
+        dense_ranks: &[usize],
+    ) -> Result<Vec<Datum>> {
+        let len = rows.len();
+        let int = |v: usize| Some(ScalarImpl::Int64(v as i64));
+        let outputs = match &self.kind {
+            WindowFunctionKind::RowNumber => (0..len).map(|i| int(i + 1)).collect(),
+            WindowFunctionKind::Rank => peers.iter().map(|(start, _)| int(start + 1)).collect(),
+            WindowFunctionKind::DenseRank => dense_ranks.iter().map(|r| int(*r)).collect(),
+            WindowFunctionKind::Lag => (0..len)
+                .map(|i| {
+                    i.checked_sub(self.offset)
+                        .and_then(|j| rows[j].0[self.arg].clone())
+                })
+                .collect(),
+            WindowFunctionKind::Lead => (0..len)
+                .map(|i| {
+                    rows.get(i + self.offset)
+                        .and_then(|row| row.0[self.arg].clone())
+                })
+                .collect(),
+            WindowFunctionKind::Aggregate(factory) => {
+                self.evaluate_aggregate(factory, len, chunk, peers)?
+            }
+        };
+        Ok(outputs)
+    }
+
+    fn evaluate_aggregate(
+        &self,
+        factory: &AggStateFactory,
+        len: usize,
+        chunk: &DataChunk,
+        peers: &[(usize, usize)],
+    ) -> Result<Vec<Datum>> {
+        let output = |state: &BoxedAggState| -> Result<Datum> {
+            let mut builder = self.return_type.create_array_builder(1)?;
+            state.output(&mut builder)?;
+            Ok(builder.finish()?.datum_at(0))
+        };
+
+        let mut outputs = Vec::with_capacity(len);
+        if self.frame.start == FrameBound::UnboundedPreceding {
+            // The end of the frame never moves backwards, so the state can be updated
+            // incrementally.
+            let mut state = factory.create_agg_state()?;
+            let mut updated = 0;
+            for (i, peer) in peers.iter().enumerate() {
+                let (_, end) = self.frame.bounds(i, len, *peer);
+                for row_id in updated..end {
+                    state.update_with_row(chunk, row_id)?;
+                }
+                updated = updated.max(end);
+                outputs.push(output(&state)?);
+            }
+        } else {
+            for (i, peer) in peers.iter().enumerate() {
+                let (start, end) = self.frame.bounds(i, len, *peer);
+                let mut state = factory.create_agg_state()?;
+                for row_id in start..end {
+                    state.update_with_row(chunk, row_id)?;
+                }
+                outputs.push(output(&state)?);
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+/// Evaluates `calls` on all rows of a partition, which must be sorted by `order_key_indices`.
+/// Rows with equal order keys are peers. Returns one row of outputs for each input row, with one
+/// column for each call.
+pub fn evaluate_partition(
+    calls: &[WindowFunction],
+    rows: &[Row],
+    input_types: &[DataType],
+    order_key_indices: &[usize],
+) -> Result<Vec<Row>> {
+    if rows.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let is_peer = |a: &Row, b: &Row| order_key_indices.iter().all(|idx| a.0[*idx] == b.0[*idx]);
+    let mut peers = Vec::with_capacity(rows.len());
+    let mut dense_ranks = Vec::with_capacity(rows.len());
+    let mut group_start = 0;
+    while group_start < rows.len() {
+        let group_end = (group_start + 1..rows.len())
+            .find(|j| !is_peer(&rows[group_start], &rows[*j]))
+            .unwrap_or(rows.len());
+        let dense_rank = dense_ranks.last().map_or(1, |r| r + 1);
+        for _ in group_start..group_end {
+            peers.push((group_start, group_end));
+            dense_ranks.push(dense_rank);
+        }
+        group_start = group_end;
+    }
+
+    let chunk = DataChunk::from_rows(rows, input_types)?;
+    let columns = calls
+        .iter()
+        .map(|call| call.evaluate(rows, &chunk, &peers, &dense_ranks))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((0..rows.len())
+        .map(|i| Row(columns.iter().map(|col| col[i].clone()).collect()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::agg_call::{Arg, Type as AggType};
+    use risingwave_pb::expr::{AggCall, InputRefExpr};
+
+    use super::*;
+
+    fn frame(rows: bool, start: BoundType, end: BoundType, offset: u64) -> ProstWindowFrame {
+        ProstWindowFrame {
+            r#type: if rows {
+                FrameType::Rows
+            } else {
+                FrameType::Range
+            } as i32,
+            start: Some(ProstBound {
+                r#type: start as i32,
+                offset,
+            }),
+            end: Some(ProstBound {
+                r#type: end as i32,
+                offset,
+            }),
+        }
+    }
+
+    fn int64() -> ProstDataType {
+        ProstDataType {
+            type_name: TypeName::Int64 as i32,
+            ..Default::default()
+        }
+    }
+
+    fn call(r#type: Type, frame: ProstWindowFrame) -> WindowFunction {
+        let agg_call = (r#type == Type::Aggregate).then(|| AggCall {
+            r#type: AggType::Sum as i32,
+            args: vec![Arg {
+                input: Some(InputRefExpr { column_idx: 1 }),
+                r#type: Some(int64()),
+            }],
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Decimal as i32,
+                ..Default::default()
+            }),
+            distinct: false,
+        });
+        let return_type = match r#type {
+            Type::Aggregate => agg_call.as_ref().unwrap().return_type.clone(),
+            _ => Some(int64()),
+        };
+        WindowFunction::from_prost(&ProstWindowFunction {
+            r#type: r#type as i32,
+            arg: Some(InputRefExpr { column_idx: 1 }),
+            offset: 1,
+            agg_call,
+            frame: Some(frame),
+            return_type,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_evaluate_partition() {
+        // (order key, value)
+        let rows = [(1, 10), (2, 20), (2, 30), (3, 40)]
+            .into_iter()
+            .map(|(k, v)| Row(vec![Some(ScalarImpl::Int64(k)), Some(ScalarImpl::Int64(v))]))
+            .collect::<Vec<_>>();
+        let range = frame(
+            false,
+            BoundType::UnboundedPreceding,
+            BoundType::CurrentRow,
+            0,
+        );
+        let sliding = frame(true, BoundType::Preceding, BoundType::Following, 1);
+        let calls = vec![
+            call(Type::RowNumber, range.clone()),
+            call(Type::Rank, range.clone()),
+            call(Type::DenseRank, range.clone()),
+            call(Type::Lag, range.clone()),
+            call(Type::Lead, range.clone()),
+            call(Type::Aggregate, range),
+            call(Type::Aggregate, sliding),
+        ];
+        let outputs =
+            evaluate_partition(&calls, &rows, &[DataType::Int64, DataType::Int64], &[0]).unwrap();
+
+        let int = |v: i64| Some(ScalarImpl::Int64(v));
+        let dec = |v: i64| Some(ScalarImpl::Decimal(v.into()));
+        assert_eq!(
+            outputs,
+            vec![
+                Row(vec![
+                    int(1),
+                    int(1),
+                    int(1),
+                    None,
+                    int(20),
+                    dec(10),
+                    dec(30)
+                ]),
+                Row(vec![
+                    int(2),
+                    int(2),
+                    int(2),
+                    int(10),
+                    int(30),
+                    dec(60),
+                    dec(60)
+                ]),
+                Row(vec![
+                    int(3),
+                    int(2),
+                    int(2),
+                    int(20),
+                    int(40),
+                    dec(60),
+                    dec(90)
+                ]),
+                Row(vec![
+                    int(4),
+                    int(4),
+                    int(3),
+                    int(30),
+                    None,
+                    dec(100),
+                    dec(70)
+                ]),
+            ]
+        );
+    }
+}
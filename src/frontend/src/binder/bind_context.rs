@@ -18,6 +18,8 @@ use std::fmt::Display;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 
+use crate::expr::ExprImpl;
+
 #[derive(Debug)]
 pub struct ColumnBinding {
     pub table_name: String,
@@ -45,10 +47,21 @@ impl ColumnBinding {
     }
 }
 
+/// A column merged from both sides of a `JOIN ... USING` or a `NATURAL JOIN`. An unqualified
+/// reference to `name` is bound to `expr` instead of being ambiguous.
+#[derive(Debug)]
+pub struct UsingColumn {
+    pub name: String,
+    /// Indices in `BindContext::columns` of the columns merged into this one.
+    pub indices: Vec<usize>,
+    pub expr: ExprImpl,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Clause {
     Where,
     Values,
+    GroupBy,
 }
 
 impl Display for Clause {
@@ -56,6 +69,7 @@ impl Display for Clause {
         match self {
             Clause::Where => write!(f, "WHERE"),
             Clause::Values => write!(f, "VALUES"),
+            Clause::GroupBy => write!(f, "GROUP BY"),
         }
     }
 }
@@ -70,6 +84,8 @@ pub struct BindContext {
     pub range_of: HashMap<String, (usize, usize)>,
     // `clause` identifies in what clause we are binding.
     pub clause: Option<Clause>,
+    // Columns merged by `JOIN ... USING` or `NATURAL JOIN`.
+    pub using_columns: Vec<UsingColumn>,
 }
 
 impl BindContext {
@@ -90,12 +106,27 @@ impl BindContext {
             .get(column_name)
             .ok_or_else(|| ErrorCode::ItemNotFound(format!("Invalid column: {}", column_name)))?;
         if columns.len() > 1 {
-            Err(ErrorCode::InternalError("Ambiguous column name".into()).into())
+            // A column merged by `USING` is not ambiguous as long as it does not change the value.
+            match self
+                .get_using_column(column_name)
+                .map(|column| &column.expr)
+            {
+                Some(ExprImpl::InputRef(input_ref)) => Ok(input_ref.index()),
+                _ => Err(ErrorCode::InternalError("Ambiguous column name".into()).into()),
+            }
         } else {
             Ok(columns[0])
         }
     }
 
+    /// Returns the [`UsingColumn`] named `column_name` if it covers all the columns of that name.
+    pub fn get_using_column(&self, column_name: &str) -> Option<&UsingColumn> {
+        let indexes = self.indexs_of.get(column_name)?;
+        self.using_columns.iter().find(|column| {
+            column.name == column_name && indexes.iter().all(|i| column.indices.contains(i))
+        })
+    }
+
     fn get_index_with_table_name(
         &self,
         column_name: &String,
@@ -127,6 +158,7 @@ impl BindContext {
             indexs_of: HashMap::new(),
             range_of: HashMap::new(),
             clause: None,
+            using_columns: Vec::new(),
         }
     }
 }
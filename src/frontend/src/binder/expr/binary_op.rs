@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{BinaryOperator, Expr};
 
 use crate::binder::Binder;
@@ -25,8 +26,20 @@ impl Binder {
         op: BinaryOperator,
         right: Expr,
     ) -> Result<FunctionCall> {
-        let bound_left = self.bind_expr(left)?;
-        let bound_right = self.bind_expr(right)?;
+        let mut bound_left = self.bind_expr(left)?;
+        let mut bound_right = self.bind_expr(right)?;
+        if matches!(
+            op,
+            BinaryOperator::Arrow
+                | BinaryOperator::LongArrow
+                | BinaryOperator::HashArrow
+                | BinaryOperator::HashLongArrow
+        ) {
+            bound_left = Self::jsonb_literal(bound_left);
+        } else if matches!(op, BinaryOperator::AtArrow | BinaryOperator::ArrowAt) {
+            bound_left = Self::jsonb_literal(bound_left);
+            bound_right = Self::jsonb_literal(bound_right);
+        }
         let func_type = match op {
             BinaryOperator::Plus => ExprType::Add,
             BinaryOperator::Minus => ExprType::Subtract,
@@ -42,7 +55,20 @@ impl Binder {
             BinaryOperator::And => ExprType::And,
             BinaryOperator::Or => ExprType::Or,
             BinaryOperator::Like => ExprType::Like,
-            BinaryOperator::NotLike => return self.bind_not_like(bound_left, bound_right),
+            BinaryOperator::ILike => ExprType::Ilike,
+            BinaryOperator::NotLike | BinaryOperator::NotILike => {
+                return Self::bind_like(op, vec![bound_left, bound_right])
+            }
+            BinaryOperator::StringConcat => return Self::bind_concat_op(bound_left, bound_right),
+            BinaryOperator::Arrow => ExprType::JsonbAccessInner,
+            BinaryOperator::LongArrow => ExprType::JsonbAccessStr,
+            BinaryOperator::HashArrow => ExprType::JsonbAccessPath,
+            BinaryOperator::HashLongArrow => ExprType::JsonbAccessPathStr,
+            BinaryOperator::AtArrow => ExprType::JsonbContains,
+            BinaryOperator::ArrowAt => {
+                std::mem::swap(&mut bound_left, &mut bound_right);
+                ExprType::JsonbContains
+            }
             _ => return Err(ErrorCode::NotImplemented(format!("{:?}", op), 112.into()).into()),
         };
         FunctionCall::new_or_else(func_type, vec![bound_left, bound_right], |inputs| {
@@ -50,21 +76,80 @@ impl Binder {
         })
     }
 
-    /// Apply a NOT on top of LIKE.
-    fn bind_not_like(&mut self, left: ExprImpl, right: ExprImpl) -> Result<FunctionCall> {
-        Ok(FunctionCall::new(
-            ExprType::Not,
+    /// Bind `left IS [NOT] DISTINCT FROM right`, which compares NULL as an ordinary value.
+    pub(super) fn bind_distinct_from(
+        &mut self,
+        func_type: ExprType,
+        left: Expr,
+        right: Expr,
+    ) -> Result<FunctionCall> {
+        let mut bound_left = self.bind_expr(left)?;
+        let mut bound_right = self.bind_expr(right)?;
+        // An untyped NULL takes the type of the other side.
+        if bound_left.is_null() {
+            bound_left = bound_left.ensure_type(bound_right.return_type());
+        } else if bound_right.is_null() {
+            bound_right = bound_right.ensure_type(bound_left.return_type());
+        }
+        FunctionCall::new_or_else(func_type, vec![bound_left, bound_right], |inputs| {
+            let desc = format!(
+                "{:?} {:?} {:?}",
+                inputs[0].return_type(),
+                func_type,
+                inputs[1].return_type(),
+            );
+            ErrorCode::NotImplemented(desc, 112.into()).into()
+        })
+    }
+
+    /// A string literal used as the jsonb operand of a jsonb operator is taken as jsonb, like an
+    /// untyped literal in Postgres.
+    fn jsonb_literal(expr: ExprImpl) -> ExprImpl {
+        if matches!(expr, ExprImpl::Literal(_)) && expr.return_type() == DataType::Varchar {
+            expr.ensure_type(DataType::Jsonb)
+        } else {
+            expr
+        }
+    }
+
+    /// Bind `expr [NOT] [I]LIKE pattern [ESCAPE escape]`, with a NOT on top of the negated ones.
+    pub(super) fn bind_like(op: BinaryOperator, inputs: Vec<ExprImpl>) -> Result<FunctionCall> {
+        let (func_type, negated) = match op {
+            BinaryOperator::Like => (ExprType::Like, false),
+            BinaryOperator::NotLike => (ExprType::Like, true),
+            BinaryOperator::ILike => (ExprType::Ilike, false),
+            BinaryOperator::NotILike => (ExprType::Ilike, true),
+            _ => unreachable!(),
+        };
+        let like = FunctionCall::new_or_else(func_type, inputs, |inputs| {
+            Self::err_unsupported_binary_op(op.clone(), inputs)
+        })?;
+        if negated {
+            Ok(FunctionCall::new(ExprType::Not, vec![like.into()]).unwrap())
+        } else {
+            Ok(like)
+        }
+    }
+
+    /// Bind `left || right`, where a non-string operand is cast to string like in Postgres.
+    fn bind_concat_op(left: ExprImpl, right: ExprImpl) -> Result<FunctionCall> {
+        if left.return_type() != DataType::Varchar && right.return_type() != DataType::Varchar {
+            return Err(Self::err_unsupported_binary_op(
+                BinaryOperator::StringConcat,
+                &[left, right],
+            ));
+        }
+        Ok(FunctionCall::new_with_return_type(
+            ExprType::ConcatOp,
             vec![
-                FunctionCall::new_or_else(ExprType::Like, vec![left, right], |inputs| {
-                    Self::err_unsupported_binary_op(BinaryOperator::NotLike, inputs)
-                })?
-                .into(),
+                left.ensure_type(DataType::Varchar),
+                right.ensure_type(DataType::Varchar),
             ],
-        )
-        .unwrap())
+            DataType::Varchar,
+        ))
     }
 
-    fn err_unsupported_binary_op(op: BinaryOperator, inputs: &[ExprImpl]) -> RwError {
+    pub(super) fn err_unsupported_binary_op(op: BinaryOperator, inputs: &[ExprImpl]) -> RwError {
         let bound_left = inputs.get(0).unwrap();
         let bound_right = inputs.get(1).unwrap();
         let desc = format!(
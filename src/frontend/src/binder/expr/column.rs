@@ -32,6 +32,13 @@ impl Binder {
             }
         };
 
+        // An unqualified reference to a column merged by `USING` is bound to the merged value.
+        if table_name.is_none() {
+            if let Some(using_column) = self.context.get_using_column(column_name) {
+                return Ok(using_column.expr.clone());
+            }
+        }
+
         if let Ok(index) = self.context.get_column_binding(table_name, column_name) {
             let column = &self.context.columns[index];
             return Ok(InputRef::new(column.index, column.data_type.clone()).into());
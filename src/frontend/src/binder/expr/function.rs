@@ -12,15 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, NaiveDateTimeWrapper, NaiveDateWrapper, ScalarImpl};
 use risingwave_expr::expr::AggKind;
-use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr};
+use risingwave_expr::sig::func::FUNC_SIG_MAP;
+use risingwave_sqlparser::ast::{
+    Function, FunctionArg, FunctionArgExpr, WindowFrame as AstWindowFrame, WindowFrameBound,
+    WindowFrameUnits, WindowSpec,
+};
 
 use crate::binder::bind_context::Clause;
 use crate::binder::Binder;
-use crate::expr::{AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal};
+use crate::expr::{
+    AggCall, Expr, ExprImpl, ExprType, FrameBound, FunctionCall, Literal, TableFunction,
+    WindowFrame, WindowFunction, WindowFunctionType,
+};
+use crate::optimizer::property::Direction;
 
 impl Binder {
     pub(super) fn bind_function(&mut self, f: Function) -> Result<ExprImpl> {
@@ -34,41 +44,77 @@ impl Binder {
         if f.name.0.len() == 1 {
             let function_name = f.name.0.get(0).unwrap().value.as_str();
             let function_name = function_name.to_lowercase();
+            if let Some(window_spec) = f.over {
+                if f.distinct {
+                    return Err(ErrorCode::NotImplemented(
+                        "DISTINCT in window functions".to_string(),
+                        None.into(),
+                    )
+                    .into());
+                }
+                return self.bind_window_function(&function_name, inputs, window_spec);
+            }
             let agg_kind = match function_name.as_str() {
                 "count" => Some(AggKind::Count),
                 "sum" => Some(AggKind::Sum),
                 "min" => Some(AggKind::Min),
                 "max" => Some(AggKind::Max),
                 "avg" => Some(AggKind::Avg),
+                "array_agg" => Some(AggKind::ArrayAgg),
                 _ => None,
             };
             if let Some(kind) = agg_kind {
                 self.ensure_aggregate_allowed()?;
-                return Ok(ExprImpl::AggCall(Box::new(AggCall::new(kind, inputs)?)));
-            }
-            let function_type = match function_name.as_str() {
-                "substr" => ExprType::Substr,
-                "length" => ExprType::Length,
-                "like" => ExprType::Like,
-                "upper" => ExprType::Upper,
-                "lower" => ExprType::Lower,
-                "trim" => ExprType::Trim,
-                "replace" => ExprType::Replace,
-                "position" => ExprType::Position,
-                "ltrim" => ExprType::Ltrim,
-                "rtrim" => ExprType::Rtrim,
-                "case" => ExprType::Case,
-                "is true" => ExprType::IsTrue,
-                "is not true" => ExprType::IsNotTrue,
-                "is false" => ExprType::IsFalse,
-                "is not false" => ExprType::IsNotFalse,
-                "is null" => ExprType::IsNull,
-                "is not null" => ExprType::IsNotNull,
-                "round" => {
-                    inputs = Self::rewrite_round_args(inputs);
-                    ExprType::RoundDigit
+                return Ok(ExprImpl::AggCall(Box::new(AggCall::new(
+                    kind, inputs, f.distinct,
+                )?)));
+            }
+            if let Some(function_type) = TableFunction::type_from_name(&function_name) {
+                self.ensure_table_function_allowed()?;
+                return Ok(TableFunction::new(function_type, inputs)?.into());
+            }
+            if function_name == "grouping" {
+                return self.bind_grouping(inputs);
+            }
+            if function_name == "array_length" {
+                return self.bind_array_length(inputs);
+            }
+            if function_name == "concat" || function_name == "concat_ws" {
+                return Self::bind_concat_ws(&function_name, inputs);
+            }
+            if matches!(
+                function_name.as_str(),
+                "coalesce" | "nullif" | "greatest" | "least"
+            ) {
+                return Self::bind_conditional_func(&function_name, inputs);
+            }
+            if function_name == "regexp_match" {
+                return Self::bind_regexp_match(inputs);
+            }
+            if function_name == "now" {
+                if !inputs.is_empty() {
+                    return Err(Self::err_unsupported_func(&function_name, &inputs));
                 }
-                _ => {
+                return Ok(self.bind_now());
+            }
+            // The arguments of some functions are rewritten before resolving the signature.
+            match function_name.as_str() {
+                "round" => inputs = Self::rewrite_round_args(inputs),
+                "age" if inputs.len() == 1 => {
+                    // `age(timestamp)` is the age at the midnight of the current date.
+                    inputs.insert(0, self.bind_current_date());
+                }
+                "lpad" | "rpad" if inputs.len() == 2 => {
+                    // The string is padded with spaces by default.
+                    inputs.push(ExprImpl::literal_varchar(" ".to_string()));
+                }
+                _ => {}
+            }
+            let function_type = match FUNC_SIG_MAP.func_of_name(&function_name) {
+                // `round(numeric, integer)` is a function of its own.
+                Some(ExprType::Round) if inputs.len() == 2 => ExprType::RoundDigit,
+                Some(function_type) => function_type,
+                None => {
                     return Err(ErrorCode::NotImplemented(
                         format!("unsupported function: {:?}", function_name),
                         112.into(),
@@ -76,10 +122,12 @@ impl Binder {
                     .into())
                 }
             };
-            Ok(FunctionCall::new_or_else(function_type, inputs, |args| {
-                Self::err_unsupported_func(&function_name, args)
-            })?
-            .into())
+            Ok(
+                FunctionCall::new_with_implicit_cast(function_type, inputs, |args| {
+                    Self::err_unsupported_func(&function_name, args)
+                })?
+                .into(),
+            )
         } else {
             Err(ErrorCode::NotImplemented(
                 format!("unsupported function: {:?}", f.name),
@@ -89,7 +137,161 @@ impl Binder {
         }
     }
 
-    fn err_unsupported_func(function_name: &str, inputs: &[ExprImpl]) -> RwError {
+    fn bind_window_function(
+        &mut self,
+        function_name: &str,
+        mut inputs: Vec<ExprImpl>,
+        WindowSpec {
+            partition_by,
+            order_by,
+            window_frame,
+        }: WindowSpec,
+    ) -> Result<ExprImpl> {
+        self.ensure_window_function_allowed()?;
+        if inputs
+            .iter()
+            .any(|e| e.has_agg_call() || e.has_window_function())
+        {
+            return Err(ErrorCode::NotImplemented(
+                format!(
+                    "aggregate or window function in arguments of window function {}",
+                    function_name
+                ),
+                None.into(),
+            )
+            .into());
+        }
+
+        let mut offset = 0;
+        let function_type = match function_name {
+            "row_number" => WindowFunctionType::RowNumber,
+            "rank" => WindowFunctionType::Rank,
+            "dense_rank" => WindowFunctionType::DenseRank,
+            "lag" | "lead" => {
+                if inputs.len() > 2 {
+                    return Err(ErrorCode::NotImplemented(
+                        format!("{} with a default value", function_name),
+                        None.into(),
+                    )
+                    .into());
+                }
+                offset = match inputs.get(1) {
+                    None => 1,
+                    Some(ExprImpl::Literal(literal)) => match literal.get_data() {
+                        Some(ScalarImpl::Int16(v)) if *v >= 0 => *v as u64,
+                        Some(ScalarImpl::Int32(v)) if *v >= 0 => *v as u64,
+                        Some(ScalarImpl::Int64(v)) if *v >= 0 => *v as u64,
+                        _ => {
+                            return Err(ErrorCode::BindError(format!(
+                                "offset of {} must be a non-negative integer",
+                                function_name
+                            ))
+                            .into())
+                        }
+                    },
+                    Some(_) => {
+                        return Err(ErrorCode::NotImplemented(
+                            format!("non-constant offset of {}", function_name),
+                            None.into(),
+                        )
+                        .into())
+                    }
+                };
+                inputs.truncate(1);
+                if function_name == "lag" {
+                    WindowFunctionType::Lag
+                } else {
+                    WindowFunctionType::Lead
+                }
+            }
+            "count" => WindowFunctionType::Aggregate(AggKind::Count),
+            "sum" => WindowFunctionType::Aggregate(AggKind::Sum),
+            "min" => WindowFunctionType::Aggregate(AggKind::Min),
+            "max" => WindowFunctionType::Aggregate(AggKind::Max),
+            "avg" => WindowFunctionType::Aggregate(AggKind::Avg),
+            _ => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("unsupported window function: {:?}", function_name),
+                    None.into(),
+                )
+                .into())
+            }
+        };
+
+        let partition_by: Vec<ExprImpl> = partition_by
+            .into_iter()
+            .map(|expr| self.bind_expr(expr))
+            .try_collect()?;
+        let order_by: Vec<(ExprImpl, Direction)> = order_by
+            .into_iter()
+            .map(|order_by_expr| {
+                let direct = match order_by_expr.asc {
+                    None | Some(true) => Direction::Asc,
+                    Some(false) => Direction::Desc,
+                };
+                Ok((self.bind_expr(order_by_expr.expr)?, direct))
+            })
+            .collect::<Result<_>>()?;
+        let frame = match window_frame {
+            None => WindowFrame::range_to_current_row(),
+            Some(frame) => Self::bind_window_frame(frame)?,
+        };
+
+        Ok(
+            WindowFunction::new(function_type, inputs, offset, partition_by, order_by, frame)?
+                .into(),
+        )
+    }
+
+    fn bind_window_frame(
+        AstWindowFrame {
+            units,
+            start_bound,
+            end_bound,
+        }: AstWindowFrame,
+    ) -> Result<WindowFrame> {
+        let rows = match units {
+            WindowFrameUnits::Rows => true,
+            WindowFrameUnits::Range => false,
+            WindowFrameUnits::Groups => {
+                return Err(ErrorCode::NotImplemented("GROUPS frame".into(), None.into()).into())
+            }
+        };
+        let bind_bound = |bound: WindowFrameBound| match bound {
+            WindowFrameBound::CurrentRow => FrameBound::CurrentRow,
+            WindowFrameBound::Preceding(None) => FrameBound::UnboundedPreceding,
+            WindowFrameBound::Preceding(Some(n)) => FrameBound::Preceding(n),
+            WindowFrameBound::Following(None) => FrameBound::UnboundedFollowing,
+            WindowFrameBound::Following(Some(n)) => FrameBound::Following(n),
+        };
+        let start = bind_bound(start_bound);
+        let end = end_bound.map_or(FrameBound::CurrentRow, bind_bound);
+
+        if start == FrameBound::UnboundedFollowing {
+            return Err(
+                ErrorCode::BindError("frame start cannot be UNBOUNDED FOLLOWING".into()).into(),
+            );
+        }
+        if end == FrameBound::UnboundedPreceding {
+            return Err(
+                ErrorCode::BindError("frame end cannot be UNBOUNDED PRECEDING".into()).into(),
+            );
+        }
+        if !rows
+            && [start, end]
+                .iter()
+                .any(|b| matches!(b, FrameBound::Preceding(_) | FrameBound::Following(_)))
+        {
+            return Err(ErrorCode::NotImplemented(
+                "RANGE frame with offset PRECEDING or FOLLOWING".into(),
+                None.into(),
+            )
+            .into());
+        }
+        Ok(WindowFrame { rows, start, end })
+    }
+
+    pub(super) fn err_unsupported_func(function_name: &str, inputs: &[ExprImpl]) -> RwError {
         let args = inputs
             .iter()
             .map(|i| format!("{:?}", i.return_type()))
@@ -101,6 +303,34 @@ impl Binder {
         .into()
     }
 
+    /// Binds `coalesce`, `nullif`, `greatest` and `least`. The arguments are cast to their common
+    /// type, which is also the return type. NULL arguments are ignored when finding the type, and
+    /// it's `varchar` if all the arguments are NULL, like in Postgres.
+    fn bind_conditional_func(function_name: &str, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let func_type = match function_name {
+            "coalesce" => ExprType::Coalesce,
+            "nullif" => ExprType::Nullif,
+            "greatest" => ExprType::Greatest,
+            _ => ExprType::Least,
+        };
+        if inputs.is_empty() || (func_type == ExprType::Nullif && inputs.len() != 2) {
+            return Err(Self::err_unsupported_func(function_name, &inputs));
+        }
+        let mut return_type = None;
+        for input in inputs.iter().filter(|input| !input.is_null()) {
+            return_type = Some(match return_type {
+                Some(ty) => Self::find_compat(ty, input.return_type())?,
+                None => input.return_type(),
+            });
+        }
+        let return_type = return_type.unwrap_or(DataType::Varchar);
+        let inputs = inputs
+            .into_iter()
+            .map(|input| input.ensure_type(return_type.clone()))
+            .collect();
+        Ok(FunctionCall::new_with_return_type(func_type, inputs, return_type).into())
+    }
+
     /// Rewrite the arguments to be consistent with the `round` signature:
     /// - round(Decimal, Int32) -> Decimal
     /// - round(Decimal) -> Decimal
@@ -125,9 +355,144 @@ impl Binder {
         }
     }
 
+    /// Returns the value of `now()` in the statement, which is taken when it is first called.
+    /// The precision is microseconds, the same as the `timestamp` type.
+    fn now(&mut self) -> NaiveDateTimeWrapper {
+        *self.now.get_or_insert_with(|| {
+            let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            NaiveDateTimeWrapper::new_with_secs_nsecs(
+                since_epoch.as_secs() as i64,
+                since_epoch.subsec_micros() * 1000,
+            )
+            .unwrap()
+        })
+    }
+
+    /// Binds `now()` or `CURRENT_TIMESTAMP` to a `timestamp` literal of the current UTC time.
+    ///
+    /// The time is taken once per statement like in `PostgreSQL`, rather than evaluated per row
+    /// or per epoch, so a materialized view can not use it.
+    pub(super) fn bind_now(&mut self) -> ExprImpl {
+        Literal::new(
+            Some(ScalarImpl::NaiveDateTime(self.now())),
+            DataType::Timestamp,
+        )
+        .into()
+    }
+
+    /// Binds `CURRENT_DATE` to a `date` literal of the date of `now()`.
+    pub(super) fn bind_current_date(&mut self) -> ExprImpl {
+        let date = NaiveDateWrapper::new(self.now().0.date());
+        Literal::new(Some(ScalarImpl::NaiveDate(date)), DataType::Date).into()
+    }
+
+    fn ensure_table_function_allowed(&self) -> Result<()> {
+        if let Some(clause) = self.context.clause {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "set-returning functions are not allowed in {}",
+                clause
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Binds `array_length(array, dimension)`, which is the length of the requested dimension of
+    /// the array.
+    fn bind_array_length(&mut self, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        match inputs.iter().map(ExprImpl::return_type).collect_vec()[..] {
+            [DataType::List { .. }, DataType::Int16 | DataType::Int32 | DataType::Int64] => {
+                let mut inputs = inputs;
+                let dimension = inputs.pop().unwrap().ensure_type(DataType::Int32);
+                inputs.push(dimension);
+                Ok(FunctionCall::new_with_return_type(
+                    ExprType::ArrayLength,
+                    inputs,
+                    DataType::Int32,
+                )
+                .into())
+            }
+            _ => Err(Self::err_unsupported_func("array_length", &inputs)),
+        }
+    }
+
+    /// Binds `concat_ws(separator, args...)`, and `concat(args...)` as one with an empty
+    /// separator. The arguments of any type are cast to strings.
+    fn bind_concat_ws(function_name: &str, mut inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let separator = match function_name {
+            "concat" if !inputs.is_empty() => ExprImpl::literal_varchar(String::new()),
+            "concat_ws" if inputs.get(0).map(ExprImpl::return_type) == Some(DataType::Varchar) => {
+                inputs.remove(0)
+            }
+            _ => return Err(Self::err_unsupported_func(function_name, &inputs)),
+        };
+        let inputs = std::iter::once(separator)
+            .chain(inputs)
+            .map(|input| input.ensure_type(DataType::Varchar))
+            .collect();
+        Ok(
+            FunctionCall::new_with_return_type(ExprType::ConcatWs, inputs, DataType::Varchar)
+                .into(),
+        )
+    }
+
+    /// Binds `regexp_match(string, pattern[, flags])`, which returns the captured substrings as
+    /// an array of strings.
+    fn bind_regexp_match(inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        if !(2..=3).contains(&inputs.len())
+            || inputs
+                .iter()
+                .any(|input| input.return_type() != DataType::Varchar)
+        {
+            return Err(Self::err_unsupported_func("regexp_match", &inputs));
+        }
+        Ok(FunctionCall::new_with_return_type(
+            ExprType::RegexpMatch,
+            inputs,
+            DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+        )
+        .into())
+    }
+
+    fn ensure_window_function_allowed(&self) -> Result<()> {
+        if let Some(clause) = self.context.clause {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "window functions are not allowed in {}",
+                clause
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Binds `GROUPING(args...)`, whose result is a bit mask of the arguments not included in the
+    /// current grouping set, with the last argument corresponding to the least significant bit.
+    ///
+    /// The arguments are only checked to be grouping expressions when planning the aggregation.
+    fn bind_grouping(&mut self, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        if let Some(clause) = self.context.clause {
+            if matches!(clause, Clause::Values | Clause::Where | Clause::GroupBy) {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "grouping operations are not allowed in {}",
+                    clause
+                ))
+                .into());
+            }
+        }
+        if inputs.is_empty() || inputs.len() > 31 {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "GROUPING must have between 1 and 31 arguments".into(),
+            )
+            .into());
+        }
+        Ok(FunctionCall::new_with_return_type(ExprType::Grouping, inputs, DataType::Int32).into())
+    }
+
     fn ensure_aggregate_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
-            if clause == Clause::Values || clause == Clause::Where {
+            if matches!(clause, Clause::Values | Clause::Where | Clause::GroupBy) {
                 return Err(ErrorCode::InvalidInputSyntax(format!(
                     "aggregate functions are not allowed in {}",
                     clause
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use itertools::zip_eq;
+use std::iter;
+
+use itertools::{zip_eq, Itertools};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{
-    BinaryOperator, DataType as AstDataType, DateTimeField, Expr, TrimWhereField, UnaryOperator,
+    BinaryOperator, DataType as AstDataType, DateTimeField, Expr, Ident, TrimWhereField,
+    UnaryOperator,
 };
 
 use crate::binder::Binder;
@@ -49,6 +52,12 @@ impl Binder {
             Expr::IsNotFalse(expr) => Ok(ExprImpl::FunctionCall(Box::new(
                 self.bind_is_operator(ExprType::IsNotFalse, *expr)?,
             ))),
+            Expr::IsDistinctFrom(left, right) => Ok(ExprImpl::FunctionCall(Box::new(
+                self.bind_distinct_from(ExprType::IsDistinctFrom, *left, *right)?,
+            ))),
+            Expr::IsNotDistinctFrom(left, right) => Ok(ExprImpl::FunctionCall(Box::new(
+                self.bind_distinct_from(ExprType::IsNotDistinctFrom, *left, *right)?,
+            ))),
             Expr::Case {
                 operand,
                 conditions,
@@ -63,20 +72,87 @@ impl Binder {
             Expr::Trim { expr, trim_where } => Ok(ExprImpl::FunctionCall(Box::new(
                 self.bind_trim(*expr, trim_where)?,
             ))),
+            // `CURRENT_TIMESTAMP` and `CURRENT_DATE` are parsed as identifiers.
+            Expr::Identifier(ident)
+                if ident.quote_style.is_none()
+                    && ident.value.eq_ignore_ascii_case("current_timestamp") =>
+            {
+                Ok(self.bind_now())
+            }
+            Expr::Identifier(ident)
+                if ident.quote_style.is_none()
+                    && ident.value.eq_ignore_ascii_case("current_date") =>
+            {
+                Ok(self.bind_current_date())
+            }
             Expr::Identifier(ident) => self.bind_column(&[ident]),
             Expr::CompoundIdentifier(idents) => self.bind_column(&idents),
             Expr::Value(v) => Ok(ExprImpl::Literal(Box::new(self.bind_value(v)?))),
+            Expr::BinaryOp { left, op, right }
+                if matches!(*right, Expr::AnySubquery(_) | Expr::AllSubquery(_)) =>
+            {
+                match *right {
+                    Expr::AnySubquery(q) => self.bind_set_comparison(*left, op, *q, false),
+                    Expr::AllSubquery(q) => self.bind_set_comparison(*left, op, *q, true),
+                    _ => unreachable!(),
+                }
+            }
             Expr::BinaryOp { left, op, right } => Ok(ExprImpl::FunctionCall(Box::new(
                 self.bind_binary_op(*left, op, *right)?,
             ))),
+            Expr::LikeEscape {
+                expr,
+                op,
+                pattern,
+                escape,
+            } => {
+                let inputs = vec![
+                    self.bind_expr(*expr)?,
+                    self.bind_expr(*pattern)?,
+                    self.bind_expr(*escape)?,
+                ];
+                Ok(Self::bind_like(op, inputs)?.into())
+            }
             Expr::UnaryOp { op, expr } => Ok(self.bind_unary_expr(op, *expr)?),
             Expr::Nested(expr) => self.bind_expr(*expr),
             Expr::Cast { expr, data_type } => Ok(ExprImpl::FunctionCall(Box::new(
                 self.bind_cast(*expr, data_type)?,
             ))),
+            Expr::TryCast { expr, data_type } => Ok(ExprImpl::FunctionCall(Box::new(
+                self.bind_try_cast(*expr, data_type)?,
+            ))),
+            Expr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+            } => Ok(ExprImpl::FunctionCall(Box::new(self.bind_substring(
+                *expr,
+                substring_from,
+                substring_for,
+            )?))),
             Expr::Function(f) => Ok(self.bind_function(f)?),
             Expr::Subquery(q) => Ok(self.bind_subquery_expr(*q, SubqueryKind::Scalar)?),
             Expr::Exists(q) => Ok(self.bind_subquery_expr(*q, SubqueryKind::Existential)?),
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => self.bind_in_list(*expr, list, negated),
+            Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => {
+                let in_subquery =
+                    self.bind_set_comparison(*expr, BinaryOperator::Eq, *subquery, false)?;
+                if negated {
+                    Ok(FunctionCall::new(ExprType::Not, vec![in_subquery])
+                        .unwrap()
+                        .into())
+                } else {
+                    Ok(in_subquery)
+                }
+            }
             Expr::TypedString { data_type, value } => Ok(ExprImpl::FunctionCall(Box::new(
                 FunctionCall::new_with_return_type(
                     ExprType::Cast,
@@ -93,6 +169,10 @@ impl Binder {
                 self.bind_between(*expr, negated, *low, *high)?,
             ))),
             Expr::Extract { field, expr } => self.bind_extract(field, *expr),
+            Expr::Array(exprs) => self.bind_array(exprs),
+            Expr::MapAccess { column, keys } => self.bind_array_access(*column, keys),
+            Expr::Row(exprs) => self.bind_row(exprs),
+            Expr::FieldIdentifier(expr, fields) => self.bind_field_access(*expr, fields),
             _ => Err(ErrorCode::NotImplemented(
                 format!("unsupported expression {:?}", expr),
                 112.into(),
@@ -123,6 +203,129 @@ impl Binder {
         .into())
     }
 
+    /// Bind `ARRAY[e1, e2, ...]`, whose elements are cast to a common type.
+    pub(super) fn bind_array(&mut self, exprs: Vec<Expr>) -> Result<ExprImpl> {
+        if exprs.is_empty() {
+            return Err(ErrorCode::BindError("cannot determine type of empty array".into()).into());
+        }
+        let exprs: Vec<ExprImpl> = exprs
+            .into_iter()
+            .map(|expr| self.bind_expr(expr))
+            .try_collect()?;
+        let mut element_type = None;
+        for expr in exprs.iter().filter(|expr| !expr.is_null()) {
+            element_type = Some(match element_type {
+                None => expr.return_type(),
+                Some(ty) => Self::find_compat(ty, expr.return_type())?,
+            });
+        }
+        // Like a bare `NULL`, an array of NULLs is taken as an array of strings.
+        let element_type = element_type.unwrap_or(DataType::Varchar);
+        let inputs = exprs
+            .into_iter()
+            .map(|expr| expr.ensure_type(element_type.clone()))
+            .collect();
+        Ok(FunctionCall::new_with_return_type(
+            ExprType::Array,
+            inputs,
+            DataType::List {
+                datatype: Box::new(element_type),
+            },
+        )
+        .into())
+    }
+
+    /// Bind `array[i][j]...` to nested `ArrayAccess` calls. The indices are 1-based.
+    pub(super) fn bind_array_access(
+        &mut self,
+        array: Expr,
+        indices: Vec<Expr>,
+    ) -> Result<ExprImpl> {
+        let mut expr = self.bind_expr(array)?;
+        for index in indices {
+            let element_type = match expr.return_type() {
+                DataType::List { datatype } => *datatype,
+                t => {
+                    return Err(ErrorCode::BindError(format!(
+                        "cannot subscript type {:?} because it is not an array",
+                        t
+                    ))
+                    .into())
+                }
+            };
+            let index = self.bind_expr(index)?;
+            if !matches!(
+                index.return_type(),
+                DataType::Int16 | DataType::Int32 | DataType::Int64
+            ) {
+                return Err(
+                    ErrorCode::BindError("array subscript must have type integer".into()).into(),
+                );
+            }
+            expr = FunctionCall::new_with_return_type(
+                ExprType::ArrayAccess,
+                vec![expr, index.ensure_type(DataType::Int32)],
+                element_type,
+            )
+            .into();
+        }
+        Ok(expr)
+    }
+
+    /// Bind `ROW(e1, e2, ...)` or `(e1, e2, ...)` to a struct, whose fields are named `f1`, `f2`,
+    /// and so on.
+    pub(super) fn bind_row(&mut self, exprs: Vec<Expr>) -> Result<ExprImpl> {
+        let inputs: Vec<ExprImpl> = exprs
+            .into_iter()
+            .map(|expr| self.bind_expr(expr))
+            .try_collect()?;
+        let return_type = DataType::new_struct(
+            inputs.iter().map(|input| input.return_type()).collect(),
+            (1..=inputs.len()).map(|i| format!("f{}", i)).collect(),
+        );
+        Ok(FunctionCall::new_with_return_type(ExprType::Row, inputs, return_type).into())
+    }
+
+    /// Bind `(expr).field1.field2...` to nested `Field` calls, each taking the index of the
+    /// accessed field.
+    pub(super) fn bind_field_access(&mut self, expr: Expr, fields: Vec<Ident>) -> Result<ExprImpl> {
+        let mut expr = self.bind_expr(expr)?;
+        for field in fields {
+            let (index, field_type) = match expr.return_type() {
+                DataType::Struct {
+                    fields: field_types,
+                    field_names,
+                } => {
+                    let index = field_names
+                        .iter()
+                        .position(|name| *name == field.value)
+                        .ok_or_else(|| {
+                            ErrorCode::BindError(format!(
+                                "column \"{}\" not found in data type {:?}",
+                                field.value,
+                                expr.return_type()
+                            ))
+                        })?;
+                    (index, field_types[index].clone())
+                }
+                t => {
+                    return Err(ErrorCode::BindError(format!(
+                        "column notation .{} applied to type {:?}, which is not a composite type",
+                        field.value, t
+                    ))
+                    .into())
+                }
+            };
+            expr = FunctionCall::new_with_return_type(
+                ExprType::Field,
+                vec![expr, ExprImpl::literal_int(index as i32)],
+                field_type,
+            )
+            .into();
+        }
+        Ok(expr)
+    }
+
     pub(super) fn bind_unary_expr(&mut self, op: UnaryOperator, expr: Expr) -> Result<ExprImpl> {
         let func_type = match op {
             UnaryOperator::Not => ExprType::Not,
@@ -243,6 +446,65 @@ impl Binder {
         Ok(func_call)
     }
 
+    /// Bind `expr (not) in (list)`. A list of constants is evaluated as a set lookup, otherwise it
+    /// is rewritten to `expr = item_1 or expr = item_2 or ...`.
+    pub(super) fn bind_in_list(
+        &mut self,
+        expr: Expr,
+        list: Vec<Expr>,
+        negated: bool,
+    ) -> Result<ExprImpl> {
+        let expr = self.bind_expr(expr)?;
+        let list: Vec<ExprImpl> = list
+            .into_iter()
+            .map(|item| self.bind_expr(item))
+            .try_collect()?;
+
+        let in_expr: ExprImpl = if list.iter().all(ExprImpl::is_const) {
+            // The values are cast to a common type to be compared with each other.
+            let mut return_type = None;
+            for item in iter::once(&expr)
+                .chain(&list)
+                .filter(|item| !item.is_null())
+            {
+                return_type = Some(match return_type {
+                    None => item.return_type(),
+                    Some(ty) => Self::find_compat(ty, item.return_type())?,
+                });
+            }
+            let return_type = return_type.unwrap_or(DataType::Boolean);
+            let inputs = iter::once(expr)
+                .chain(list)
+                .map(|item| item.ensure_type(return_type.clone()))
+                .collect();
+            FunctionCall::new_with_return_type(ExprType::In, inputs, DataType::Boolean).into()
+        } else {
+            list.into_iter()
+                .map(|item| {
+                    FunctionCall::new_or_else(ExprType::Equal, vec![expr.clone(), item], |inputs| {
+                        Self::err_unsupported_binary_op(BinaryOperator::Eq, inputs)
+                    })
+                    .map(ExprImpl::from)
+                })
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .reduce(|left, right| {
+                    FunctionCall::new(ExprType::Or, vec![left, right])
+                        .unwrap()
+                        .into()
+                })
+                .unwrap()
+        };
+
+        if negated {
+            Ok(FunctionCall::new(ExprType::Not, vec![in_expr])
+                .unwrap()
+                .into())
+        } else {
+            Ok(in_expr)
+        }
+    }
+
     pub(super) fn bind_case(
         &mut self,
         operand: Option<Box<Expr>>,
@@ -304,6 +566,42 @@ impl Binder {
             bind_data_type(&data_type)?,
         ))
     }
+
+    /// Bind `TRY_CAST(expr AS data_type)`, which is NULL if `expr` can not be cast.
+    pub(super) fn bind_try_cast(
+        &mut self,
+        expr: Expr,
+        data_type: AstDataType,
+    ) -> Result<FunctionCall> {
+        Ok(FunctionCall::new_with_return_type(
+            ExprType::TryCast,
+            vec![self.bind_expr(expr)?],
+            bind_data_type(&data_type)?,
+        ))
+    }
+
+    /// Bind `SUBSTRING(expr [FROM start] [FOR count])` as `substr(expr, start, count)`, where
+    /// `start` defaults to 1.
+    pub(super) fn bind_substring(
+        &mut self,
+        expr: Expr,
+        substring_from: Option<Box<Expr>>,
+        substring_for: Option<Box<Expr>>,
+    ) -> Result<FunctionCall> {
+        let mut inputs = vec![
+            self.bind_expr(expr)?,
+            match substring_from {
+                Some(start) => self.bind_expr(*start)?,
+                None => ExprImpl::literal_int(1),
+            },
+        ];
+        if let Some(count) = substring_for {
+            inputs.push(self.bind_expr(*count)?);
+        }
+        FunctionCall::new_or_else(ExprType::Substr, inputs, |inputs| {
+            Self::err_unsupported_func("substring", inputs)
+        })
+    }
 }
 
 pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
@@ -321,9 +619,26 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
         AstDataType::Timestamp(false) => DataType::Timestamp,
         AstDataType::Timestamp(true) => DataType::Timestampz,
         AstDataType::Interval => DataType::Interval,
+        AstDataType::Bytea => DataType::Bytea,
+        AstDataType::Uuid => DataType::Uuid,
         AstDataType::Array(datatype) => DataType::List {
             datatype: Box::new(bind_data_type(datatype)?),
         },
+        AstDataType::Struct(fields) => DataType::new_struct(
+            fields
+                .iter()
+                .map(|field| bind_data_type(&field.data_type))
+                .try_collect()?,
+            fields
+                .iter()
+                .map(|field| field.name.value.clone())
+                .collect(),
+        ),
+        AstDataType::Custom(name)
+            if matches!(name.to_string().to_lowercase().as_str(), "json" | "jsonb") =>
+        {
+            DataType::Jsonb
+        }
         _ => {
             return Err(ErrorCode::NotImplemented(
                 format!("unsupported data type: {:?}", data_type),
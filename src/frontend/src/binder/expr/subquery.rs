@@ -1,8 +1,22 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_sqlparser::ast::Query;
+use risingwave_sqlparser::ast::{BinaryOperator, Expr, Query};
 
 use crate::binder::Binder;
-use crate::expr::{ExprImpl, Subquery, SubqueryKind};
+use crate::expr::{infer_type, Expr as _, ExprImpl, ExprType, Subquery, SubqueryKind};
 
 impl Binder {
     pub(super) fn bind_subquery_expr(
@@ -10,18 +24,61 @@ impl Binder {
         query: Query,
         kind: SubqueryKind,
     ) -> Result<ExprImpl> {
-        let r = self.bind_query(query);
-        if let Ok(query) = r {
-            // uncorrelated subquery
-            if kind == SubqueryKind::Scalar && query.data_types().len() != 1 {
-                return Err(ErrorCode::BindError(
-                    "subquery must return only one column".to_string(),
-                )
-                .into());
-            }
-            return Ok(Subquery::new(query, kind).into());
+        let query = self.bind_query(query)?;
+        if kind == SubqueryKind::Scalar && query.data_types().len() != 1 {
+            return Err(
+                ErrorCode::BindError("subquery must return only one column".to_string()).into(),
+            );
         }
+        Ok(Subquery::new(query, kind).into())
+    }
 
-        Err(ErrorCode::NotImplemented("correlated subquery".to_string(), 1343.into()).into())
+    /// Binds `left op ANY (query)` or `left op ALL (query)`.
+    pub(super) fn bind_set_comparison(
+        &mut self,
+        left: Expr,
+        op: BinaryOperator,
+        query: Query,
+        all: bool,
+    ) -> Result<ExprImpl> {
+        let left = self.bind_expr(left)?;
+        let query = self.bind_query(query)?;
+        let data_types = query.data_types();
+        if data_types.len() != 1 {
+            return Err(
+                ErrorCode::BindError("subquery must return only one column".to_string()).into(),
+            );
+        }
+        let func_type = match op {
+            BinaryOperator::Eq => ExprType::Equal,
+            BinaryOperator::NotEq => ExprType::NotEqual,
+            BinaryOperator::Lt => ExprType::LessThan,
+            BinaryOperator::LtEq => ExprType::LessThanOrEqual,
+            BinaryOperator::Gt => ExprType::GreaterThan,
+            BinaryOperator::GtEq => ExprType::GreaterThanOrEqual,
+            _ => {
+                return Err(ErrorCode::BindError(format!(
+                    "operator {} does not support ANY/ALL",
+                    op
+                ))
+                .into())
+            }
+        };
+        if infer_type(func_type, vec![left.return_type(), data_types[0].clone()]).is_none() {
+            return Err(ErrorCode::NotImplemented(
+                format!("{:?} {:?} {:?}", left.return_type(), op, data_types[0]),
+                112.into(),
+            )
+            .into());
+        }
+        Ok(Subquery::new(
+            query,
+            SubqueryKind::SetComparison {
+                left,
+                op: func_type,
+                all,
+            },
+        )
+        .into())
     }
 }
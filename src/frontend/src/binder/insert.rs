@@ -52,6 +52,7 @@ impl Binder {
                     order: vec![],
                     limit,
                     offset,
+                    extra_order_exprs: 0,
                 }
             }
 
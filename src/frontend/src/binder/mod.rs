@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use risingwave_common::error::Result;
+use risingwave_common::types::NaiveDateTimeWrapper;
 use risingwave_sqlparser::ast::Statement;
 
 mod bind_context;
@@ -23,7 +26,10 @@ mod query;
 mod relation;
 mod select;
 mod set_expr;
+mod source_watermark;
 mod statement;
+mod table_function;
+mod update;
 mod values;
 mod window_table_function;
 
@@ -35,6 +41,7 @@ pub use relation::{BoundBaseTable, BoundJoin, BoundSource, BoundTableSource, Rel
 pub use select::BoundSelect;
 pub use set_expr::BoundSetExpr;
 pub use statement::BoundStatement;
+pub use update::BoundUpdate;
 pub use values::BoundValues;
 pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKind};
 
@@ -52,6 +59,14 @@ pub struct Binder {
     upper_contexts: Vec<BindContext>,
 
     next_subquery_id: usize,
+
+    /// Ids of the tables, sources and views referenced by the statement, which are the
+    /// dependencies of a view defined by it.
+    included_relations: HashSet<u32>,
+
+    /// The value of `now()` in the statement. It is taken when `now()` is first bound, so that
+    /// it is the same everywhere in the statement.
+    now: Option<NaiveDateTimeWrapper>,
 }
 
 impl Binder {
@@ -62,6 +77,8 @@ impl Binder {
             context: BindContext::new(),
             upper_contexts: vec![],
             next_subquery_id: 0,
+            included_relations: HashSet::new(),
+            now: None,
         }
     }
 
@@ -70,6 +87,16 @@ impl Binder {
         self.bind_statement(stmt)
     }
 
+    pub fn included_relations(&self) -> &HashSet<u32> {
+        &self.included_relations
+    }
+
+    /// Whether the statement depends on the time when it is bound, by calling `now()` and the
+    /// like.
+    pub fn uses_now(&self) -> bool {
+        self.now.is_some()
+    }
+
     fn push_context(&mut self) {
         let new_context = std::mem::take(&mut self.context);
         self.upper_contexts.push(new_context);
@@ -12,11 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
-
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{Expr, OrderByExpr, Query};
+use risingwave_sqlparser::ast::{Expr, OrderByExpr, Query, Value};
 
 use crate::binder::{Binder, BoundSetExpr};
 use crate::optimizer::property::{Direction, FieldOrder};
@@ -29,17 +28,24 @@ pub struct BoundQuery {
     pub order: Vec<FieldOrder>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    /// The number of hidden columns at the end of `body`, which are the ORDER BY expressions not
+    /// in the select list.
+    pub extra_order_exprs: usize,
 }
 
 impl BoundQuery {
     /// The names returned by this [`BoundQuery`].
     pub fn names(&self) -> Vec<String> {
-        self.body.names()
+        let mut names = self.body.names();
+        names.truncate(names.len() - self.extra_order_exprs);
+        names
     }
 
     /// The types returned by this [`BoundQuery`].
     pub fn data_types(&self) -> Vec<DataType> {
-        self.body.data_types()
+        let mut data_types = self.body.data_types();
+        data_types.truncate(data_types.len() - self.extra_order_exprs);
+        data_types
     }
 
     pub fn is_correlated(&self) -> bool {
@@ -65,49 +71,138 @@ impl Binder {
     pub(super) fn bind_query_inner(&mut self, query: Query) -> Result<BoundQuery> {
         let limit = query.get_limit_value();
         let offset = query.get_offset_value();
-        let body = self.bind_set_expr(query.body)?;
-        let mut name_to_index = HashMap::new();
-        match &body {
-            BoundSetExpr::Select(s) => s.aliases.iter().enumerate().for_each(|(index, alias)| {
-                if let Some(name) = alias {
-                    name_to_index.insert(name.clone(), index);
-                }
-            }),
-            BoundSetExpr::Values(_) => {}
-        };
+        let mut body = self.bind_set_expr(query.body)?;
+        let output_names = body.names();
         let order = query
             .order_by
             .into_iter()
-            .map(|order_by_expr| self.bind_order_by_expr(order_by_expr, &name_to_index))
+            .map(|order_by_expr| self.bind_order_by_expr(order_by_expr, &mut body, &output_names))
             .collect::<Result<_>>()?;
+        let extra_order_exprs = body.names().len() - output_names.len();
         Ok(BoundQuery {
             body,
             order,
             limit,
             offset,
+            extra_order_exprs,
         })
     }
 
+    /// Bind an ORDER BY item following the rules of Postgres:
+    /// - A name is the output column of that name, or an input column if there is none.
+    /// - An integer constant is the ordinal position of an output column, starting from 1.
+    /// - Other expressions are bound over the input columns. They are appended to the select list
+    ///   as hidden columns, unless the same expression is already in it.
     fn bind_order_by_expr(
         &mut self,
         order_by_expr: OrderByExpr,
-        name_to_index: &HashMap<String, usize>,
+        body: &mut BoundSetExpr,
+        output_names: &[String],
     ) -> Result<FieldOrder> {
         let direct = match order_by_expr.asc {
             None | Some(true) => Direction::Asc,
             Some(false) => Direction::Desc,
         };
-        let name = match order_by_expr.expr {
-            Expr::Identifier(name) => name.value,
+        if let Expr::Identifier(name) = &order_by_expr.expr
+            && let Some(index) = Self::resolve_output_name(&name.value, body, output_names)?
+        {
+            return Ok(FieldOrder { index, direct });
+        }
+        let index = match order_by_expr.expr {
+            Expr::Value(Value::Number(number, _)) => match number.parse::<usize>() {
+                Ok(position) if (1..=output_names.len()).contains(&position) => position - 1,
+                _ => {
+                    return Err(ErrorCode::BindError(format!(
+                        "ORDER BY position {} is not in select list",
+                        number
+                    ))
+                    .into())
+                }
+            },
             expr => {
-                return Err(
-                    ErrorCode::NotImplemented(format!("ORDER BY {:?}", expr), 1635.into()).into(),
-                )
+                let select = match body {
+                    BoundSetExpr::Select(select) => select,
+                    BoundSetExpr::Values(_) | BoundSetExpr::SetOperation { .. } => {
+                        return Err(ErrorCode::BindError(format!(
+                            "invalid ORDER BY expression \"{}\", only result column names or \
+                             numbers can be used here",
+                            expr
+                        ))
+                        .into())
+                    }
+                };
+                // A name that is neither an output column nor an input column.
+                let name = match &expr {
+                    Expr::Identifier(name) => Some(name.value.clone()),
+                    _ => None,
+                };
+                let expr = self.bind_expr(expr).map_err(|err| match name {
+                    Some(name) if matches!(err.inner(), ErrorCode::ItemNotFound(_)) => {
+                        ErrorCode::ItemNotFound(format!("output column \"{}\"", name)).into()
+                    }
+                    _ => err,
+                })?;
+                // Subqueries can't be compared, so they are never deduplicated.
+                let position = match expr.has_subquery() {
+                    true => None,
+                    false => select.select_items.iter().position(|item| *item == expr),
+                };
+                match position {
+                    Some(index) => index,
+                    None => {
+                        if select.distinct {
+                            return Err(ErrorCode::BindError(
+                                "for SELECT DISTINCT, ORDER BY expressions must appear in select \
+                                 list"
+                                    .to_string(),
+                            )
+                            .into());
+                        }
+                        select.select_items.push(expr);
+                        select.aliases.push(None);
+                        select.select_items.len() - 1
+                    }
+                }
             }
         };
-        let index = *name_to_index
-            .get(&name)
-            .ok_or_else(|| ErrorCode::ItemNotFound(format!("output column \"{}\"", name)))?;
         Ok(FieldOrder { index, direct })
     }
+
+    /// Returns the index of the output column named `name`, or `None` if there is no such column.
+    fn resolve_output_name(
+        name: &str,
+        body: &BoundSetExpr,
+        output_names: &[String],
+    ) -> Result<Option<usize>> {
+        let indices = output_names
+            .iter()
+            .enumerate()
+            .filter(|(_, output_name)| *output_name == name)
+            .map(|(index, _)| index)
+            .collect_vec();
+        let index = match indices[..] {
+            [] => return Ok(None),
+            [index] => index,
+            [index, ..] => {
+                // The same column selected more than once is not ambiguous.
+                let is_same_column = match body {
+                    BoundSetExpr::Select(select) => {
+                        let item = &select.select_items[index];
+                        !item.has_subquery()
+                            && indices.iter().all(|i| select.select_items[*i] == *item)
+                    }
+                    BoundSetExpr::Values(_) | BoundSetExpr::SetOperation { .. } => false,
+                };
+                if !is_same_column {
+                    return Err(ErrorCode::BindError(format!(
+                        "ORDER BY \"{}\" is ambiguous",
+                        name
+                    ))
+                    .into());
+                }
+                index
+            }
+        };
+        Ok(Some(index))
+    }
 }
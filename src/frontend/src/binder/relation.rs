@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::ops::Range;
 use std::str::FromStr;
 
 use itertools::Itertools;
@@ -21,16 +22,20 @@ use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 use risingwave_pb::plan::JoinType;
 use risingwave_sqlparser::ast::{
-    JoinConstraint, JoinOperator, ObjectName, Query, TableAlias, TableFactor, TableWithJoins,
+    JoinConstraint, JoinOperator, ObjectName, Query, Statement, TableAlias, TableFactor,
+    TableWithJoins,
 };
+use risingwave_sqlparser::parser::Parser;
 
-use super::bind_context::ColumnBinding;
+use super::bind_context::{ColumnBinding, UsingColumn};
 use super::{BoundQuery, BoundWindowTableFunction, WindowTableFunctionKind, UNNAMED_SUBQUERY};
 use crate::binder::Binder;
 use crate::catalog::source_catalog::SourceCatalog;
 use crate::catalog::table_catalog::TableCatalog;
+use crate::catalog::view_catalog::ViewCatalog;
 use crate::catalog::{CatalogError, TableId};
-use crate::expr::{Expr, ExprImpl};
+use crate::expr::{Expr, ExprImpl, ExprType, FunctionCall, InputRef, TableFunction};
+use crate::utils::Condition;
 
 /// A validated item that refers to a table-like entity, including base table, subquery, join, etc.
 /// It is usually part of the `from` clause.
@@ -41,6 +46,7 @@ pub enum Relation {
     Subquery(Box<BoundSubquery>),
     Join(Box<BoundJoin>),
     WindowTableFunction(Box<BoundWindowTableFunction>),
+    TableFunction(Box<TableFunction>),
 }
 
 #[derive(Debug)]
@@ -116,8 +122,10 @@ impl Binder {
     }
 
     fn bind_table_with_joins(&mut self, table: TableWithJoins) -> Result<Relation> {
+        let begin = self.context.columns.len();
         let mut root = self.bind_table_factor(table.relation)?;
         for join in table.joins {
+            let right_begin = self.context.columns.len();
             let right = self.bind_table_factor(join.relation)?;
             let (constraint, join_type) = match join.join_operator {
                 JoinOperator::Inner(constraint) => (constraint, JoinType::Inner),
@@ -127,7 +135,13 @@ impl Binder {
                 // Cross join equals to inner join with with no constraint.
                 JoinOperator::CrossJoin => (JoinConstraint::None, JoinType::Inner),
             };
-            let cond = self.bind_join_constraint(constraint)?;
+            let right_end = self.context.columns.len();
+            let cond = self.bind_join_constraint(
+                constraint,
+                join_type,
+                begin..right_begin,
+                right_begin..right_end,
+            )?;
             let join = BoundJoin {
                 join_type,
                 left: root,
@@ -140,11 +154,34 @@ impl Binder {
         Ok(root)
     }
 
-    fn bind_join_constraint(&mut self, constraint: JoinConstraint) -> Result<ExprImpl> {
+    /// Binds the join condition. `left` and `right` are the ranges of the columns of both sides
+    /// in the current context.
+    fn bind_join_constraint(
+        &mut self,
+        constraint: JoinConstraint,
+        join_type: JoinType,
+        left: Range<usize>,
+        right: Range<usize>,
+    ) -> Result<ExprImpl> {
         Ok(match constraint {
             JoinConstraint::None => ExprImpl::literal_bool(true),
             JoinConstraint::Natural => {
-                return Err(ErrorCode::NotImplemented("Natural join".into(), 1633.into()).into())
+                // The visible column names that appear on both sides, in the order of the left
+                // side.
+                let visible_names = |range: Range<usize>| {
+                    self.context.columns[range]
+                        .iter()
+                        .filter(|column| !column.is_hidden)
+                        .map(|column| column.column_name.clone())
+                        .unique()
+                        .collect_vec()
+                };
+                let right_names = visible_names(right.clone());
+                let names = visible_names(left.clone())
+                    .into_iter()
+                    .filter(|name| right_names.contains(name))
+                    .collect();
+                self.bind_using_columns(names, join_type, left, right)?
             }
             JoinConstraint::On(expr) => {
                 let bound_expr = self.bind_expr(expr)?;
@@ -157,29 +194,144 @@ impl Binder {
                 }
                 bound_expr
             }
-            JoinConstraint::Using(_columns) => {
-                return Err(ErrorCode::NotImplemented("USING".into(), 1636.into()).into())
-            }
+            JoinConstraint::Using(columns) => self.bind_using_columns(
+                columns.into_iter().map(|column| column.value).collect(),
+                join_type,
+                left,
+                right,
+            )?,
         })
     }
 
+    /// Binds the columns of `JOIN ... USING` to an equi-condition, and merges each pair of columns
+    /// into a [`UsingColumn`], which is the left one for inner and left joins, the right one for
+    /// right joins, and the non-null one of them for full joins.
+    fn bind_using_columns(
+        &mut self,
+        names: Vec<String>,
+        join_type: JoinType,
+        left: Range<usize>,
+        right: Range<usize>,
+    ) -> Result<ExprImpl> {
+        if let Some(name) = names.iter().duplicates().next() {
+            return Err(ErrorCode::BindError(format!(
+                "column \"{}\" appears more than once in USING clause",
+                name
+            ))
+            .into());
+        }
+
+        let mut conjunctions = vec![];
+        for name in names {
+            let (left_expr, left_indices) =
+                self.resolve_using_column(&name, left.clone(), "left")?;
+            let (right_expr, right_indices) =
+                self.resolve_using_column(&name, right.clone(), "right")?;
+            let data_type = Self::find_compat(left_expr.return_type(), right_expr.return_type())?;
+            let left_expr = left_expr.ensure_type(data_type.clone());
+            let right_expr = right_expr.ensure_type(data_type.clone());
+
+            conjunctions.push(
+                FunctionCall::new(ExprType::Equal, vec![left_expr.clone(), right_expr.clone()])
+                    .ok_or_else(|| {
+                        ErrorCode::BindError(format!(
+                            "JOIN/USING types {:?} and {:?} cannot be matched",
+                            left_expr.return_type(),
+                            right_expr.return_type()
+                        ))
+                    })?
+                    .into(),
+            );
+
+            let expr = match join_type {
+                JoinType::RightOuter => right_expr,
+                JoinType::FullOuter => FunctionCall::new_with_return_type(
+                    ExprType::Coalesce,
+                    vec![left_expr, right_expr],
+                    data_type,
+                )
+                .into(),
+                _ => left_expr,
+            };
+            let indices = left_indices.into_iter().chain(right_indices).collect_vec();
+            // The merged columns of the inputs are replaced by the merged column of this join.
+            self.context.using_columns.retain(|column| {
+                column.name != name || !column.indices.iter().any(|i| indices.contains(i))
+            });
+            self.context.using_columns.push(UsingColumn {
+                name,
+                indices,
+                expr,
+            });
+        }
+        Ok(Condition { conjunctions }.to_expr())
+    }
+
+    /// Finds the column `name` of one side of a join, whose columns are in `range`. Returns the
+    /// column and the indices of the columns merged into it.
+    fn resolve_using_column(
+        &self,
+        name: &str,
+        range: Range<usize>,
+        side: &str,
+    ) -> Result<(ExprImpl, Vec<usize>)> {
+        let indices = self
+            .context
+            .indexs_of
+            .get(name)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|i| range.contains(i))
+            .collect_vec();
+        if let Some(column) = self.context.using_columns.iter().find(|column| {
+            column.name == name
+                && !indices.is_empty()
+                && indices.iter().all(|i| column.indices.contains(i))
+        }) {
+            return Ok((column.expr.clone(), column.indices.clone()));
+        }
+        match indices.as_slice() {
+            [] => Err(ErrorCode::BindError(format!(
+                "column \"{}\" specified in USING clause does not exist in {} table",
+                name, side
+            ))
+            .into()),
+            [index] => {
+                let column = &self.context.columns[*index];
+                Ok((
+                    InputRef::new(column.index, column.data_type.clone()).into(),
+                    indices,
+                ))
+            }
+            _ => Err(ErrorCode::BindError(format!(
+                "common column name \"{}\" appears more than once in {} table",
+                name, side
+            ))
+            .into()),
+        }
+    }
+
     pub(super) fn bind_table_factor(&mut self, table_factor: TableFactor) -> Result<Relation> {
         match table_factor {
             TableFactor::Table { name, alias, args } => {
                 if args.is_empty() {
                     let (schema_name, table_name) = Self::resolve_table_name(name)?;
                     self.bind_table_or_source(&schema_name, &table_name, alias)
-                } else {
-                    let kind =
-                        WindowTableFunctionKind::from_str(&name.0[0].value).map_err(|_| {
-                            ErrorCode::NotImplemented(
-                                format!("unknown window function kind: {}", name.0[0].value),
-                                1191.into(),
-                            )
-                        })?;
+                } else if let Ok(kind) = WindowTableFunctionKind::from_str(&name.0[0].value) {
                     Ok(Relation::WindowTableFunction(Box::new(
                         self.bind_window_table_function(kind, args)?,
                     )))
+                } else if let Some(table_function) =
+                    self.bind_table_function(&name.0[0].value, args, alias)?
+                {
+                    Ok(Relation::TableFunction(Box::new(table_function)))
+                } else {
+                    Err(ErrorCode::NotImplemented(
+                        format!("unknown table function: {}", name.0[0].value),
+                        1191.into(),
+                    )
+                    .into())
                 }
             }
             TableFactor::Derived {
@@ -225,16 +377,30 @@ impl Binder {
             .into());
         }
 
+        if let Ok(view) = self
+            .catalog
+            .get_view_by_name(&self.db_name, schema_name, table_name)
+        {
+            let view = view.clone();
+            return self.bind_view(&view, alias);
+        }
+
         let (ret, columns) = {
             let catalog = &self.catalog;
 
             catalog
                 .get_table_by_name(&self.db_name, schema_name, table_name)
-                .map(|t| (Relation::BaseTable(Box::new(t.into())), t.columns.clone()))
+                .map(|t| {
+                    self.included_relations.insert(t.id().table_id);
+                    (Relation::BaseTable(Box::new(t.into())), t.columns.clone())
+                })
                 .or_else(|_| {
                     catalog
                         .get_source_by_name(&self.db_name, schema_name, table_name)
-                        .map(|s| (Relation::Source(Box::new(s.into())), s.columns.clone()))
+                        .map(|s| {
+                            self.included_relations.insert(s.id);
+                            (Relation::Source(Box::new(s.into())), s.columns.clone())
+                        })
                 })
                 .map_err(|_| {
                     RwError::from(CatalogError::NotFound(
@@ -255,6 +421,36 @@ impl Binder {
         Ok(ret)
     }
 
+    /// Binds a view by expanding its query as a subquery named after the view.
+    fn bind_view(&mut self, view: &ViewCatalog, alias: Option<TableAlias>) -> Result<Relation> {
+        let query = match Parser::parse_sql(&view.sql)
+            .map_err(|e| ErrorCode::InternalError(format!("invalid view {}: {}", view.name, e)))?
+            .pop()
+        {
+            Some(Statement::Query(query)) => *query,
+            _ => {
+                return Err(ErrorCode::InternalError(format!(
+                    "view {} is not defined by a query",
+                    view.name
+                ))
+                .into())
+            }
+        };
+        self.included_relations.insert(view.id);
+
+        let query = self.bind_query(query)?;
+        self.bind_context(
+            view.columns
+                .iter()
+                .map(|field| field.name.clone())
+                .zip_eq(query.data_types().into_iter())
+                .map(|(x, y)| (x, y, false)),
+            view.name.clone(),
+            alias,
+        )?;
+        Ok(Relation::Subquery(Box::new(BoundSubquery { query })))
+    }
+
     pub(super) fn bind_table(
         &mut self,
         schema_name: &str,
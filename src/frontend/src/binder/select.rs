@@ -25,6 +25,9 @@ use crate::binder::{Binder, Relation};
 use crate::catalog::check_valid_column_name;
 use crate::expr::{Expr as _, ExprImpl, InputRef};
 
+/// The maximum number of elements in a `CUBE`, which expands to `2 ^ n` grouping sets.
+const MAX_CUBE_ELEMENTS: usize = 12;
+
 #[derive(Debug)]
 pub struct BoundSelect {
     pub distinct: bool,
@@ -33,6 +36,9 @@ pub struct BoundSelect {
     pub from: Option<Relation>,
     pub where_clause: Option<ExprImpl>,
     pub group_by: Vec<ExprImpl>,
+    /// The grouping sets of `GROUPING SETS`, `ROLLUP` and `CUBE`, each of which is a list of
+    /// indices into `group_by`. Empty for a plain `GROUP BY`, which groups by all of `group_by`.
+    pub grouping_sets: Vec<Vec<usize>>,
 }
 
 impl BoundSelect {
@@ -64,6 +70,10 @@ impl BoundSelect {
 
 impl Binder {
     pub(super) fn bind_select(&mut self, select: Select) -> Result<BoundSelect> {
+        if select.having.is_some() {
+            return Err(ErrorCode::NotImplemented("HAVING clause".into(), 112.into()).into());
+        }
+
         // Bind FROM clause.
         let from = self.bind_vec_table_with_joins(select.from)?;
 
@@ -87,11 +97,9 @@ impl Binder {
         }
 
         // Bind GROUP BY clause.
-        let group_by = select
-            .group_by
-            .into_iter()
-            .map(|expr| self.bind_expr(expr))
-            .try_collect()?;
+        self.context.clause = Some(Clause::GroupBy);
+        let (group_by, grouping_sets) = self.bind_group_by(select.group_by)?;
+        self.context.clause = None;
 
         // Bind SELECT clause.
         let (select_items, aliases) = self.bind_project(select.projection)?;
@@ -103,9 +111,103 @@ impl Binder {
             from,
             where_clause: selection,
             group_by,
+            grouping_sets,
         })
     }
 
+    /// Binds the items of the GROUP BY clause, returning the distinct group expressions and the
+    /// grouping sets over them.
+    ///
+    /// The grouping sets of the items are combined by cross product, e.g. `GROUP BY a, ROLLUP (b,
+    /// c)` groups by `(a, b, c)`, `(a, b)` and `(a)`.
+    fn bind_group_by(&mut self, items: Vec<Expr>) -> Result<(Vec<ExprImpl>, Vec<Vec<usize>>)> {
+        if !items.iter().any(|item| {
+            matches!(
+                item,
+                Expr::GroupingSets(_) | Expr::Rollup(_) | Expr::Cube(_)
+            )
+        }) {
+            let group_by = items
+                .into_iter()
+                .map(|expr| self.bind_expr(expr))
+                .try_collect()?;
+            return Ok((group_by, vec![]));
+        }
+
+        let mut group_by = vec![];
+        let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+        for item in items {
+            let item_sets: Vec<Vec<usize>> = match item {
+                Expr::GroupingSets(sets) => sets
+                    .into_iter()
+                    .map(|set| self.bind_grouping_set(set, &mut group_by))
+                    .try_collect()?,
+                Expr::Rollup(elements) => {
+                    let elements: Vec<_> = elements
+                        .into_iter()
+                        .map(|element| self.bind_grouping_set(element, &mut group_by))
+                        .try_collect()?;
+                    (0..=elements.len())
+                        .rev()
+                        .map(|len| elements[..len].concat())
+                        .collect()
+                }
+                Expr::Cube(elements) => {
+                    if elements.len() > MAX_CUBE_ELEMENTS {
+                        return Err(ErrorCode::InvalidInputSyntax(format!(
+                            "CUBE is limited to {} elements",
+                            MAX_CUBE_ELEMENTS
+                        ))
+                        .into());
+                    }
+                    let elements: Vec<_> = elements
+                        .into_iter()
+                        .map(|element| self.bind_grouping_set(element, &mut group_by))
+                        .try_collect()?;
+                    let len = elements.len();
+                    (0..1usize << len)
+                        .rev()
+                        .map(|mask| {
+                            (0..len)
+                                .filter(|i| mask & (1 << (len - 1 - i)) != 0)
+                                .flat_map(|i| elements[i].clone())
+                                .collect()
+                        })
+                        .collect()
+                }
+                expr => vec![self.bind_grouping_set(vec![expr], &mut group_by)?],
+            };
+            grouping_sets = grouping_sets
+                .iter()
+                .cartesian_product(item_sets.iter())
+                .map(|(left, right)| left.iter().chain(right.iter()).copied().unique().collect())
+                .collect();
+        }
+        Ok((group_by, grouping_sets))
+    }
+
+    /// Binds the expressions of a grouping set, returning their indices into `group_by`. New
+    /// expressions are appended to `group_by`.
+    fn bind_grouping_set(
+        &mut self,
+        exprs: Vec<Expr>,
+        group_by: &mut Vec<ExprImpl>,
+    ) -> Result<Vec<usize>> {
+        exprs
+            .into_iter()
+            .map(|expr| {
+                let expr = self.bind_expr(expr)?;
+                Ok(match group_by.iter().position(|e| *e == expr) {
+                    Some(index) => index,
+                    None => {
+                        group_by.push(expr);
+                        group_by.len() - 1
+                    }
+                })
+            })
+            .try_collect()
+    }
+
     pub fn bind_project(
         &mut self,
         select_items: Vec<SelectItem>,
@@ -120,6 +222,9 @@ impl Binder {
                         Expr::CompoundIdentifier(idents) => {
                             idents.last().map(|ident| ident.value.clone())
                         }
+                        Expr::FieldIdentifier(_, idents) => {
+                            idents.last().map(|ident| ident.value.clone())
+                        }
                         _ => None,
                     };
                     let expr = self.bind_expr(expr)?;
@@ -144,9 +249,23 @@ impl Binder {
                 }
                 SelectItem::ExprQualifiedWildcard(_, _) => todo!(),
                 SelectItem::Wildcard => {
+                    // Columns merged by `USING` come first, and each of them appears only once.
+                    let using_columns = &self.context.using_columns;
+                    select_list.extend(using_columns.iter().map(|column| column.expr.clone()));
+                    aliases.extend(using_columns.iter().map(|column| Some(column.name.clone())));
                     let (exprs, names) = Self::bind_visible_columns(&self.context.columns[..])?;
-                    select_list.extend(exprs);
-                    aliases.extend(names);
+                    for (expr, name) in exprs.into_iter().zip_eq(names) {
+                        let is_merged = match &expr {
+                            ExprImpl::InputRef(input_ref) => using_columns
+                                .iter()
+                                .any(|column| column.indices.contains(&input_ref.index())),
+                            _ => false,
+                        };
+                        if !is_merged {
+                            select_list.push(expr);
+                            aliases.push(name);
+                        }
+                    }
                 }
             }
         }
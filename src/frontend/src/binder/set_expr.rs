@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::SetExpr;
+use risingwave_sqlparser::ast::{SetExpr, SetOperator};
 
 use crate::binder::{Binder, BoundSelect, BoundValues};
+use crate::expr::ExprImpl;
 
 /// Part of a validated query, without order or limit clause. It may be composed of smaller
 /// `BoundSetExpr`s via set operators (e.g. union).
@@ -24,6 +26,13 @@ use crate::binder::{Binder, BoundSelect, BoundValues};
 pub enum BoundSetExpr {
     Select(Box<BoundSelect>),
     Values(Box<BoundValues>),
+    /// `UNION`, `INTERSECT` or `EXCEPT`. Both sides have been cast to the same column types.
+    SetOperation {
+        op: SetOperator,
+        all: bool,
+        left: Box<BoundSetExpr>,
+        right: Box<BoundSetExpr>,
+    },
 }
 
 impl BoundSetExpr {
@@ -32,6 +41,7 @@ impl BoundSetExpr {
         match self {
             BoundSetExpr::Select(s) => s.names(),
             BoundSetExpr::Values(v) => v.schema.fields().iter().map(|f| f.name.clone()).collect(),
+            BoundSetExpr::SetOperation { left, .. } => left.names(),
         }
     }
 
@@ -45,6 +55,7 @@ impl BoundSetExpr {
                 .iter()
                 .map(|f| f.data_type.clone())
                 .collect(),
+            BoundSetExpr::SetOperation { left, .. } => left.data_types(),
         }
     }
 
@@ -52,6 +63,40 @@ impl BoundSetExpr {
         match self {
             BoundSetExpr::Select(s) => s.is_correlated(),
             BoundSetExpr::Values(_) => false,
+            BoundSetExpr::SetOperation { left, right, .. } => {
+                left.is_correlated() || right.is_correlated()
+            }
+        }
+    }
+
+    /// Cast the output columns of this [`BoundSetExpr`] to `types`.
+    fn cast_to(&mut self, types: &[DataType]) {
+        match self {
+            BoundSetExpr::Select(s) => {
+                s.select_items = std::mem::take(&mut s.select_items)
+                    .into_iter()
+                    .zip_eq(types.iter().cloned())
+                    .map(|(expr, ty)| expr.ensure_type(ty))
+                    .collect();
+            }
+            BoundSetExpr::Values(v) => {
+                v.rows = std::mem::take(&mut v.rows)
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .zip_eq(types.iter().cloned())
+                            .map(|(expr, ty)| expr.ensure_type(ty))
+                            .collect::<Vec<ExprImpl>>()
+                    })
+                    .collect();
+                for (field, ty) in v.schema.fields.iter_mut().zip_eq(types.iter()) {
+                    field.data_type = ty.clone();
+                }
+            }
+            BoundSetExpr::SetOperation { left, right, .. } => {
+                left.cast_to(types);
+                right.cast_to(types);
+            }
         }
     }
 }
@@ -61,7 +106,57 @@ impl Binder {
         match set_expr {
             SetExpr::Select(s) => Ok(BoundSetExpr::Select(Box::new(self.bind_select(*s)?))),
             SetExpr::Values(v) => Ok(BoundSetExpr::Values(Box::new(self.bind_values(v, None)?))),
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => self.bind_set_operation(op, all, *left, *right),
             _ => Err(ErrorCode::NotImplemented(format!("{:?}", set_expr), None.into()).into()),
         }
     }
+
+    /// Bind a set operation. Each side is bound in a fresh [`BindContext`](super::BindContext), as
+    /// the columns of one side are not visible to the other.
+    fn bind_set_operation(
+        &mut self,
+        op: SetOperator,
+        all: bool,
+        left: SetExpr,
+        right: SetExpr,
+    ) -> Result<BoundSetExpr> {
+        let mut left = self.bind_set_expr_in_new_context(left)?;
+        let mut right = self.bind_set_expr_in_new_context(right)?;
+
+        let left_types = left.data_types();
+        let right_types = right.data_types();
+        if left_types.len() != right_types.len() {
+            return Err(ErrorCode::BindError(format!(
+                "each {} query must have the same number of columns",
+                op
+            ))
+            .into());
+        }
+        let types = left_types
+            .into_iter()
+            .zip_eq(right_types)
+            .map(|(l, r)| Self::find_compat(l, r))
+            .collect::<Result<Vec<_>>>()?;
+        left.cast_to(&types);
+        right.cast_to(&types);
+
+        Ok(BoundSetExpr::SetOperation {
+            op,
+            all,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+
+    fn bind_set_expr_in_new_context(&mut self, set_expr: SetExpr) -> Result<BoundSetExpr> {
+        let old_context = std::mem::take(&mut self.context);
+        let result = self.bind_set_expr(set_expr);
+        self.context = old_context;
+        result
+    }
 }
@@ -0,0 +1,35 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::Expr;
+
+use super::Binder;
+use crate::expr::ExprImpl;
+
+impl Binder {
+    /// Binds the expression of `WATERMARK FOR <column> AS <expr>` of a source, in which only the
+    /// watermark column can be referenced, as the 0-th input.
+    pub fn bind_source_watermark(
+        &mut self,
+        source_name: String,
+        column_name: String,
+        data_type: DataType,
+        expr: Expr,
+    ) -> Result<ExprImpl> {
+        self.bind_context([(column_name, data_type, false)], source_name, None)?;
+        self.bind_expr(expr)
+    }
+}
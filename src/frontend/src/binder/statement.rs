@@ -16,12 +16,13 @@ use risingwave_common::error::{ErrorCode, Result};
 use risingwave_sqlparser::ast::Statement;
 
 use super::delete::BoundDelete;
-use crate::binder::{Binder, BoundInsert, BoundQuery};
+use crate::binder::{Binder, BoundInsert, BoundQuery, BoundUpdate};
 
 #[derive(Debug)]
 pub enum BoundStatement {
     Insert(Box<BoundInsert>),
     Delete(Box<BoundDelete>),
+    Update(Box<BoundUpdate>),
     Query(Box<BoundQuery>),
 }
 
@@ -43,6 +44,14 @@ impl Binder {
                 self.bind_delete(table_name, selection)?.into(),
             )),
 
+            Statement::Update {
+                table,
+                assignments,
+                selection,
+            } => Ok(BoundStatement::Update(
+                self.bind_update(table, assignments, selection)?.into(),
+            )),
+
             Statement::Query(q) => Ok(BoundStatement::Query(self.bind_query(*q)?.into())),
 
             _ => Err(ErrorCode::NotImplemented(
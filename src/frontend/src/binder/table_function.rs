@@ -0,0 +1,66 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{FunctionArg, TableAlias};
+
+use super::Binder;
+use crate::expr::{Expr, ExprImpl, TableFunction, TableFunctionType};
+
+impl Binder {
+    /// Binds a set-returning function in the `FROM` clause, e.g. `jsonb_array_elements(...)`, or
+    /// returns `None` if there's no table function called `name`. Its arguments are constant, and
+    /// it outputs a single column of the values it returns.
+    pub(super) fn bind_table_function(
+        &mut self,
+        name: &str,
+        args: Vec<FunctionArg>,
+        alias: Option<TableAlias>,
+    ) -> Result<Option<TableFunction>> {
+        let Some(function_type) = TableFunction::type_from_name(name) else {
+            return Ok(None);
+        };
+        let column_name = match function_type {
+            TableFunctionType::JsonbArrayElements => "value",
+            TableFunctionType::Unnest => "unnest",
+            TableFunctionType::Unspecified => unreachable!(),
+        };
+
+        // The arguments can't refer to the other relations in the `FROM` clause.
+        self.push_context();
+        let args: Result<Vec<ExprImpl>> = args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect();
+        self.pop_context();
+        let args = args?;
+        if !args.iter().all(ExprImpl::is_const) {
+            return Err(ErrorCode::NotImplemented(
+                format!("non-constant arguments of table function {}", name),
+                None.into(),
+            )
+            .into());
+        }
+
+        let table_function = TableFunction::new(function_type, args)?;
+        self.bind_context(
+            [(column_name.to_string(), table_function.return_type(), false)],
+            name.to_string(),
+            alias,
+        )?;
+        Ok(Some(table_function))
+    }
+}
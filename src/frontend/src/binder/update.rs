@@ -0,0 +1,136 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{Assignment, Expr, TableFactor, TableWithJoins};
+
+use super::{Binder, BoundBaseTable, BoundTableSource};
+use crate::expr::{ExprImpl, InputRef};
+
+#[derive(Debug)]
+pub struct BoundUpdate {
+    /// Used for injecting new chunks to the source.
+    pub table_source: BoundTableSource,
+
+    /// Used for scanning the records to update with the `selection`.
+    pub table: BoundBaseTable,
+
+    pub selection: Option<ExprImpl>,
+
+    /// Expression used to project to the updated row. The assigned columns will use the new
+    /// expression, and the other columns will be simply `InputRef`.
+    pub exprs: Vec<ExprImpl>,
+}
+
+impl Binder {
+    pub(super) fn bind_update(
+        &mut self,
+        table: TableWithJoins,
+        assignments: Vec<Assignment>,
+        selection: Option<Expr>,
+    ) -> Result<BoundUpdate> {
+        let (source_name, alias) = match table {
+            TableWithJoins {
+                relation: TableFactor::Table { name, alias, args },
+                joins,
+            } if args.is_empty() && joins.is_empty() => (name, alias),
+            _ => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("unsupported target of UPDATE: {}", table),
+                    None.into(),
+                )
+                .into())
+            }
+        };
+
+        let (schema_name, table_name) = Self::resolve_table_name(source_name.clone())?;
+        let table_source = self.bind_table_source(source_name)?;
+        let table = self.bind_table(&schema_name, &table_name, alias)?;
+
+        let selection = selection.map(|expr| self.bind_expr(expr)).transpose()?;
+
+        let mut assignment_exprs = HashMap::new();
+        for Assignment { id, value } in assignments {
+            // TODO: support qualified column names like `t.v1`.
+            let column_name = match id.as_slice() {
+                [column] => &column.value,
+                _ => {
+                    return Err(ErrorCode::NotImplemented(
+                        format!(
+                            "unsupported column in UPDATE: {}",
+                            id.iter().map(|ident| &ident.value).join(".")
+                        ),
+                        None.into(),
+                    )
+                    .into())
+                }
+            };
+            let index = table
+                .table_catalog
+                .columns()
+                .iter()
+                .position(|c| !c.is_hidden && c.name() == column_name)
+                .ok_or_else(|| {
+                    ErrorCode::ItemNotFound(format!(
+                        "column \"{}\" of relation \"{}\"",
+                        column_name, table_name
+                    ))
+                })?;
+
+            let value = self.bind_expr(value)?;
+            if value.has_agg_call() || value.has_subquery() {
+                return Err(ErrorCode::NotImplemented(
+                    format!(
+                        "aggregate or subquery in UPDATE of column \"{}\"",
+                        column_name
+                    ),
+                    None.into(),
+                )
+                .into());
+            }
+
+            if assignment_exprs.insert(index, value).is_some() {
+                return Err(ErrorCode::BindError(format!(
+                    "multiple assignments to same column \"{}\"",
+                    column_name
+                ))
+                .into());
+            }
+        }
+
+        let exprs = table
+            .table_catalog
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let data_type = c.data_type().clone();
+                match assignment_exprs.remove(&i) {
+                    Some(expr) => expr.ensure_type(data_type),
+                    None => InputRef::new(i, data_type).into(),
+                }
+            })
+            .collect();
+
+        Ok(BoundUpdate {
+            table_source,
+            table,
+            selection,
+            exprs,
+        })
+    }
+}
@@ -20,7 +20,8 @@ use risingwave_common::catalog::{CatalogVersion, TableId};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Schema as ProstSchema, Sink as ProstSink, Source as ProstSource,
+    Table as ProstTable, View as ProstView,
 };
 use risingwave_pb::stream_plan::StreamNode;
 use risingwave_rpc_client::MetaClient;
@@ -68,7 +69,18 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn drop_materialized_view(&self, table_id: TableId) -> Result<()>;
 
+    /// Replace the materialized view of `table.id` with a new one of the same output schema.
+    async fn replace_materialized_view(&self, table: ProstTable, plan: StreamNode) -> Result<()>;
+
     async fn drop_source(&self, source_id: u32) -> Result<()>;
+
+    async fn create_view(&self, view: ProstView) -> Result<()>;
+
+    async fn drop_view(&self, view_id: u32) -> Result<()>;
+
+    async fn create_sink(&self, sink: ProstSink, graph: StreamNode) -> Result<()>;
+
+    async fn drop_sink(&self, sink_id: u32) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -142,10 +154,38 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
+    async fn replace_materialized_view(&self, table: ProstTable, plan: StreamNode) -> Result<()> {
+        let (_, version) = self
+            .meta_client
+            .replace_materialized_view(table, plan)
+            .await?;
+        self.wait_version(version).await
+    }
+
     async fn drop_source(&self, source_id: u32) -> Result<()> {
         let version = self.meta_client.drop_source(source_id).await?;
         self.wait_version(version).await
     }
+
+    async fn create_view(&self, view: ProstView) -> Result<()> {
+        let (_id, version) = self.meta_client.create_view(view).await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_view(&self, view_id: u32) -> Result<()> {
+        let version = self.meta_client.drop_view(view_id).await?;
+        self.wait_version(version).await
+    }
+
+    async fn create_sink(&self, sink: ProstSink, graph: StreamNode) -> Result<()> {
+        let (_id, version) = self.meta_client.create_sink(sink, graph).await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_sink(&self, sink_id: u32) -> Result<()> {
+        let version = self.meta_client.drop_sink(sink_id).await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
@@ -22,12 +22,18 @@ pub(crate) mod column_catalog;
 pub(crate) mod database_catalog;
 pub(crate) mod root_catalog;
 pub(crate) mod schema_catalog;
+pub(crate) mod sink_catalog;
 pub(crate) mod source_catalog;
 pub(crate) mod table_catalog;
+pub(crate) mod view_catalog;
 
 #[allow(dead_code)]
 pub(crate) type SourceId = u32;
 
+pub(crate) type ViewId = u32;
+
+pub(crate) type SinkId = u32;
+
 pub(crate) type DatabaseId = u32;
 pub(crate) type SchemaId = u32;
 pub(crate) type TableId = risingwave_common::catalog::TableId;
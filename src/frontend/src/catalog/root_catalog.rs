@@ -18,11 +18,14 @@ use itertools::Itertools;
 use risingwave_common::catalog::{CatalogVersion, TableId};
 use risingwave_common::error::Result;
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Schema as ProstSchema, Sink as ProstSink, Source as ProstSource,
+    Table as ProstTable, View as ProstView,
 };
 
+use super::sink_catalog::SinkCatalog;
 use super::source_catalog::SourceCatalog;
-use super::{CatalogError, SourceId};
+use super::view_catalog::ViewCatalog;
+use super::{CatalogError, SinkId, SourceId, ViewId};
 use crate::catalog::database_catalog::DatabaseCatalog;
 use crate::catalog::schema_catalog::SchemaCatalog;
 use crate::catalog::table_catalog::TableCatalog;
@@ -90,6 +93,14 @@ impl Catalog {
             .create_table(proto);
     }
 
+    pub fn replace_table(&mut self, proto: &ProstTable) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .replace_table(proto);
+    }
+
     pub fn create_source(&mut self, proto: ProstSource) {
         self.get_database_mut(proto.database_id)
             .unwrap()
@@ -98,6 +109,22 @@ impl Catalog {
             .create_source(proto);
     }
 
+    pub fn create_view(&mut self, proto: &ProstView) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .create_view(proto);
+    }
+
+    pub fn create_sink(&mut self, proto: &ProstSink) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .create_sink(proto);
+    }
+
     pub fn drop_database(&mut self, db_id: DatabaseId) {
         let name = self.db_name_by_id.remove(&db_id).unwrap();
         let _database = self.database_by_name.remove(&name).unwrap();
@@ -123,6 +150,22 @@ impl Catalog {
             .drop_source(source_id);
     }
 
+    pub fn drop_view(&mut self, db_id: DatabaseId, schema_id: SchemaId, view_id: ViewId) {
+        self.get_database_mut(db_id)
+            .unwrap()
+            .get_schema_mut(schema_id)
+            .unwrap()
+            .drop_view(view_id);
+    }
+
+    pub fn drop_sink(&mut self, db_id: DatabaseId, schema_id: SchemaId, sink_id: SinkId) {
+        self.get_database_mut(db_id)
+            .unwrap()
+            .get_schema_mut(schema_id)
+            .unwrap()
+            .drop_sink(sink_id);
+    }
+
     pub fn get_database_by_name(&self, db_name: &str) -> Result<&DatabaseCatalog> {
         self.database_by_name
             .get(db_name)
@@ -141,6 +184,18 @@ impl Catalog {
             .get_all_mv_names())
     }
 
+    pub fn get_all_view_names(&self, db_name: &str, schema_name: &str) -> Result<Vec<String>> {
+        Ok(self
+            .get_schema_by_name(db_name, schema_name)?
+            .get_all_view_names())
+    }
+
+    pub fn get_all_sink_names(&self, db_name: &str, schema_name: &str) -> Result<Vec<String>> {
+        Ok(self
+            .get_schema_by_name(db_name, schema_name)?
+            .get_all_sink_names())
+    }
+
     pub fn get_all_schema_names(&self, db_name: &str) -> Result<Vec<String>> {
         Ok(self.get_database_by_name(db_name)?.get_all_schema_names())
     }
@@ -177,7 +232,29 @@ impl Catalog {
             .ok_or_else(|| CatalogError::NotFound("source", source_name.to_string()).into())
     }
 
-    /// Check the name if duplicated with existing table, materialized view or source.
+    pub fn get_view_by_name(
+        &self,
+        db_name: &str,
+        schema_name: &str,
+        view_name: &str,
+    ) -> Result<&ViewCatalog> {
+        self.get_schema_by_name(db_name, schema_name)?
+            .get_view_by_name(view_name)
+            .ok_or_else(|| CatalogError::NotFound("view", view_name.to_string()).into())
+    }
+
+    pub fn get_sink_by_name(
+        &self,
+        db_name: &str,
+        schema_name: &str,
+        sink_name: &str,
+    ) -> Result<&SinkCatalog> {
+        self.get_schema_by_name(db_name, schema_name)?
+            .get_sink_by_name(sink_name)
+            .ok_or_else(|| CatalogError::NotFound("sink", sink_name.to_string()).into())
+    }
+
+    /// Check the name if duplicated with existing table, materialized view, view, sink or source.
     pub fn check_relation_name_duplicated(
         &self,
         db_name: &str,
@@ -200,6 +277,10 @@ impl Catalog {
             }
         } else if let Some(_table) = schema.get_table_by_name(relation_name) {
             Err(CatalogError::Duplicated("materialized view", relation_name.to_string()).into())
+        } else if let Some(_view) = schema.get_view_by_name(relation_name) {
+            Err(CatalogError::Duplicated("view", relation_name.to_string()).into())
+        } else if let Some(_sink) = schema.get_sink_by_name(relation_name) {
+            Err(CatalogError::Duplicated("sink", relation_name.to_string()).into())
         } else {
             Ok((db.id(), schema.id()))
         }
@@ -17,11 +17,16 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
 use risingwave_meta::manager::SourceId;
-use risingwave_pb::catalog::{Schema as ProstSchema, Source as ProstSource, Table as ProstTable};
+use risingwave_pb::catalog::{
+    Schema as ProstSchema, Sink as ProstSink, Source as ProstSource, Table as ProstTable,
+    View as ProstView,
+};
 
+use super::sink_catalog::SinkCatalog;
 use super::source_catalog::SourceCatalog;
+use super::view_catalog::ViewCatalog;
 use crate::catalog::table_catalog::TableCatalog;
-use crate::catalog::SchemaId;
+use crate::catalog::{SchemaId, SinkId, ViewId};
 
 #[derive(Clone, Debug)]
 pub struct SchemaCatalog {
@@ -32,6 +37,10 @@ pub struct SchemaCatalog {
     table_name_by_id: HashMap<TableId, String>,
     source_by_name: HashMap<String, SourceCatalog>,
     source_name_by_id: HashMap<SourceId, String>,
+    view_by_name: HashMap<String, ViewCatalog>,
+    view_name_by_id: HashMap<ViewId, String>,
+    sink_by_name: HashMap<String, SinkCatalog>,
+    sink_name_by_id: HashMap<SinkId, String>,
 }
 
 impl SchemaCatalog {
@@ -44,6 +53,17 @@ impl SchemaCatalog {
         self.table_name_by_id.try_insert(id, name).unwrap();
     }
 
+    /// Replace the table of the same name with `prost`, which has a different id.
+    pub fn replace_table(&mut self, prost: &ProstTable) {
+        let name = prost.name.clone();
+        let id = prost.id.into();
+        let table: TableCatalog = prost.into();
+
+        let old_table = self.table_by_name.insert(name.clone(), table).unwrap();
+        self.table_name_by_id.remove(&old_table.id()).unwrap();
+        self.table_name_by_id.try_insert(id, name).unwrap();
+    }
+
     pub fn drop_table(&mut self, id: TableId) {
         let name = self.table_name_by_id.remove(&id).unwrap();
         self.table_by_name.remove(&name).unwrap();
@@ -64,6 +84,36 @@ impl SchemaCatalog {
         self.source_by_name.remove(&name).unwrap();
     }
 
+    pub fn create_view(&mut self, prost: &ProstView) {
+        let name = prost.name.clone();
+        let id = prost.id;
+
+        self.view_by_name
+            .try_insert(name.clone(), ViewCatalog::from(prost))
+            .unwrap();
+        self.view_name_by_id.try_insert(id, name).unwrap();
+    }
+
+    pub fn drop_view(&mut self, id: ViewId) {
+        let name = self.view_name_by_id.remove(&id).unwrap();
+        self.view_by_name.remove(&name).unwrap();
+    }
+
+    pub fn create_sink(&mut self, prost: &ProstSink) {
+        let name = prost.name.clone();
+        let id = prost.id;
+
+        self.sink_by_name
+            .try_insert(name.clone(), SinkCatalog::from(prost))
+            .unwrap();
+        self.sink_name_by_id.try_insert(id, name).unwrap();
+    }
+
+    pub fn drop_sink(&mut self, id: SinkId) {
+        let name = self.sink_name_by_id.remove(&id).unwrap();
+        self.sink_by_name.remove(&name).unwrap();
+    }
+
     // Use associated source to filter table.
     pub fn get_all_table_names(&self) -> Vec<String> {
         self.table_by_name
@@ -82,6 +132,14 @@ impl SchemaCatalog {
             .collect_vec()
     }
 
+    pub fn get_all_view_names(&self) -> Vec<String> {
+        self.view_by_name.keys().cloned().collect_vec()
+    }
+
+    pub fn get_all_sink_names(&self) -> Vec<String> {
+        self.sink_by_name.keys().cloned().collect_vec()
+    }
+
     pub fn get_table_by_name(&self, table_name: &str) -> Option<&TableCatalog> {
         self.table_by_name.get(table_name)
     }
@@ -90,6 +148,14 @@ impl SchemaCatalog {
         self.source_by_name.get(source_name)
     }
 
+    pub fn get_view_by_name(&self, view_name: &str) -> Option<&ViewCatalog> {
+        self.view_by_name.get(view_name)
+    }
+
+    pub fn get_sink_by_name(&self, sink_name: &str) -> Option<&SinkCatalog> {
+        self.sink_by_name.get(sink_name)
+    }
+
     pub fn id(&self) -> SchemaId {
         self.id
     }
@@ -104,6 +170,10 @@ impl From<&ProstSchema> for SchemaCatalog {
             table_name_by_id: HashMap::new(),
             source_by_name: HashMap::new(),
             source_name_by_id: HashMap::new(),
+            view_by_name: HashMap::new(),
+            view_name_by_id: HashMap::new(),
+            sink_by_name: HashMap::new(),
+            sink_name_by_id: HashMap::new(),
         }
     }
 }
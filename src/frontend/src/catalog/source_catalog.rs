@@ -26,13 +26,15 @@ pub struct SourceCatalog {
     pub columns: Vec<ColumnCatalog>,
     pub pk_col_ids: Vec<ColumnId>,
     pub source_type: SourceType,
+    /// The column with `WATERMARK FOR` of a stream source.
+    pub watermark_col_id: Option<ColumnId>,
 }
 
 impl From<&ProstSource> for SourceCatalog {
     fn from(prost: &ProstSource) -> Self {
         let id = prost.id;
         let name = prost.name.clone();
-        let (source_type, prost_columns, pk_col_ids) = match &prost.info {
+        let (source_type, prost_columns, pk_col_ids, watermark_col_id) = match &prost.info {
             Some(Info::StreamSource(source)) => (
                 SourceType::Source,
                 source.columns.clone(),
@@ -41,11 +43,16 @@ impl From<&ProstSource> for SourceCatalog {
                     .iter()
                     .map(|id| ColumnId::new(*id))
                     .collect(),
+                source
+                    .watermark_desc
+                    .as_ref()
+                    .map(|desc| ColumnId::new(desc.column_id)),
             ),
             Some(Info::TableSource(source)) => (
                 SourceType::Table,
                 source.columns.clone(),
                 vec![TABLE_SOURCE_PK_COLID],
+                None,
             ),
             None => unreachable!(),
         };
@@ -56,6 +63,7 @@ impl From<&ProstSource> for SourceCatalog {
             columns,
             pk_col_ids,
             source_type,
+            watermark_col_id,
         }
     }
 }
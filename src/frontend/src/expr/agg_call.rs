@@ -24,6 +24,7 @@ pub struct AggCall {
     agg_kind: AggKind,
     return_type: DataType,
     inputs: Vec<ExprImpl>,
+    distinct: bool,
 }
 
 impl std::fmt::Debug for AggCall {
@@ -33,7 +34,18 @@ impl std::fmt::Debug for AggCall {
                 .field("agg_kind", &self.agg_kind)
                 .field("return_type", &self.return_type)
                 .field("inputs", &self.inputs)
+                .field("distinct", &self.distinct)
                 .finish()
+        } else if self.distinct {
+            write!(
+                f,
+                "{}(distinct {})",
+                self.agg_kind,
+                self.inputs
+                    .iter()
+                    .map(|child| format!("{:?}", child))
+                    .join(", ")
+            )
         } else {
             let mut builder = f.debug_tuple(&format!("{}", self.agg_kind));
             self.inputs.iter().for_each(|child| {
@@ -70,6 +82,10 @@ impl AggCall {
                 _ => return None,
             },
             (AggKind::Count, _) => DataType::Int64,
+            (AggKind::SingleValue, [input]) => input.clone(),
+            (AggKind::ArrayAgg, [input]) => DataType::List {
+                datatype: Box::new(input.clone()),
+            },
             (other_kind, other_inputs) => {
                 todo!(
                     "Unsupported aggregate function: {:?} with {} inputs",
@@ -83,7 +99,7 @@ impl AggCall {
 
     /// Returns error if the function name matches with an existing function
     /// but with illegal arguments.
-    pub fn new(agg_kind: AggKind, inputs: Vec<ExprImpl>) -> Result<Self> {
+    pub fn new(agg_kind: AggKind, inputs: Vec<ExprImpl>, distinct: bool) -> Result<Self> {
         // TODO(TaoWu): Add arguments validator.
         let data_types = inputs.iter().map(ExprImpl::return_type).collect_vec();
         let return_type = Self::infer_return_type(&agg_kind, &data_types).ok_or_else(|| {
@@ -93,15 +109,29 @@ impl AggCall {
                 None.into(),
             ))
         })?;
+        let distinct = match agg_kind {
+            // Duplicated values don't change the extremes.
+            AggKind::Min | AggKind::Max => false,
+            // The states of these aggregations keep all the values, and aren't deduplicated.
+            AggKind::ArrayAgg | AggKind::StringAgg if distinct => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("{} with DISTINCT", agg_kind),
+                    None.into(),
+                )
+                .into())
+            }
+            _ => distinct,
+        };
         Ok(AggCall {
             agg_kind,
             return_type,
             inputs,
+            distinct,
         })
     }
 
-    pub fn decompose(self) -> (AggKind, Vec<ExprImpl>) {
-        (self.agg_kind, self.inputs)
+    pub fn decompose(self) -> (AggKind, Vec<ExprImpl>, bool) {
+        (self.agg_kind, self.inputs, self.distinct)
     }
 
     pub fn agg_kind(&self) -> AggKind {
@@ -112,6 +142,10 @@ impl AggCall {
     pub fn inputs(&self) -> &[ExprImpl] {
         self.inputs.as_ref()
     }
+
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
 }
 impl Expr for AggCall {
     fn return_type(&self) -> DataType {
@@ -12,10 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::DataType;
 
-use super::{infer_type, Expr, ExprImpl};
+use super::{infer_type, resolve_func_sig, Expr, ExprImpl};
 use crate::expr::ExprType;
 
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -58,6 +59,12 @@ impl std::fmt::Debug for FunctionCall {
                     self.inputs[0].fmt(f)?;
                     return write!(f, "::{:?}", self.return_type);
                 }
+                ExprType::TryCast => {
+                    assert_eq!(self.inputs.len(), 1);
+                    write!(f, "TryCast(")?;
+                    self.inputs[0].fmt(f)?;
+                    return write!(f, "::{:?})", self.return_type);
+                }
                 ExprType::Add => debug_binary_op(f, "+", &self.inputs),
                 ExprType::Subtract => debug_binary_op(f, "-", &self.inputs),
                 ExprType::Multiply => debug_binary_op(f, "*", &self.inputs),
@@ -69,6 +76,10 @@ impl std::fmt::Debug for FunctionCall {
                 ExprType::LessThanOrEqual => debug_binary_op(f, "<=", &self.inputs),
                 ExprType::GreaterThan => debug_binary_op(f, ">", &self.inputs),
                 ExprType::GreaterThanOrEqual => debug_binary_op(f, ">=", &self.inputs),
+                ExprType::IsDistinctFrom => debug_binary_op(f, "IS DISTINCT FROM", &self.inputs),
+                ExprType::IsNotDistinctFrom => {
+                    debug_binary_op(f, "IS NOT DISTINCT FROM", &self.inputs)
+                }
                 ExprType::And => debug_binary_op(f, "AND", &self.inputs),
                 ExprType::Or => debug_binary_op(f, "OR", &self.inputs),
                 _ => {
@@ -98,6 +109,30 @@ impl FunctionCall {
         .map(|return_type| Self::new_with_return_type(func_type, inputs, return_type))
     }
 
+    /// Like [`Self::new_or_else`], but if there is no signature matching the input types exactly,
+    /// the inputs are cast implicitly to the overload resolved by [`resolve_func_sig`].
+    pub fn new_with_implicit_cast<F>(
+        func_type: ExprType,
+        inputs: Vec<ExprImpl>,
+        err_f: F,
+    ) -> Result<Self>
+    where
+        F: FnOnce(&Vec<ExprImpl>) -> RwError,
+    {
+        let inputs_type = inputs
+            .iter()
+            .map(|expr| (!expr.is_null()).then(|| expr.return_type()))
+            .collect_vec();
+        let (target_types, return_type) =
+            resolve_func_sig(func_type, &inputs_type).ok_or_else(|| err_f(&inputs))?;
+        let inputs = inputs
+            .into_iter()
+            .zip_eq(target_types)
+            .map(|(expr, ty)| expr.ensure_type(ty))
+            .collect();
+        Ok(Self::new_with_return_type(func_type, inputs, return_type))
+    }
+
     pub fn new(func_type: ExprType, inputs: Vec<ExprImpl>) -> Option<Self> {
         let return_type = infer_type(
             func_type,
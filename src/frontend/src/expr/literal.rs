@@ -33,9 +33,18 @@ impl std::fmt::Debug for Literal {
         } else {
             match &self.data {
                 None => write!(f, "null"),
-                // Add single quotation marks for string and interval literals
+                // Add single quotation marks for string, interval, date/time, jsonb, bytea, uuid,
+                // list and struct literals
                 Some(ScalarImpl::Utf8(v)) => write!(f, "'{}'", v),
                 Some(ScalarImpl::Interval(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::NaiveDate(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::NaiveDateTime(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::NaiveTime(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Jsonb(v)) => write!(f, "'{}'", v),
+                Some(v @ ScalarImpl::Bytea(_)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Uuid(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::List(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Struct(v)) => write!(f, "'{}'", v),
                 Some(v) => write!(f, "{}", v),
             }?;
             write!(f, ":{:?}", self.data_type)
@@ -89,11 +98,30 @@ fn literal_to_protobuf(d: &Datum) -> Option<RexNode> {
         ScalarImpl::Bool(v) => (*v as i8).to_be_bytes().to_vec(),
         ScalarImpl::Decimal(v) => v.to_string().as_bytes().to_vec(),
         ScalarImpl::Interval(v) => v.to_protobuf_owned(),
-        ScalarImpl::NaiveDate(_) => todo!(),
-        ScalarImpl::NaiveDateTime(_) => todo!(),
-        ScalarImpl::NaiveTime(_) => todo!(),
-        ScalarImpl::Struct(_) => todo!(),
-        ScalarImpl::List(_) => todo!(),
+        ScalarImpl::NaiveDate(v) => {
+            let mut body = vec![];
+            v.to_protobuf(&mut body).unwrap();
+            body
+        }
+        ScalarImpl::NaiveDateTime(v) => {
+            let mut body = vec![];
+            v.to_protobuf(&mut body).unwrap();
+            body
+        }
+        ScalarImpl::NaiveTime(v) => {
+            let mut body = vec![];
+            v.to_protobuf(&mut body).unwrap();
+            body
+        }
+        // Lists and structs are encoded in the memcomparable format, without the null tag.
+        ScalarImpl::List(_) | ScalarImpl::Struct(_) => {
+            let mut serializer = memcomparable::Serializer::new(vec![]);
+            d.serialize(&mut serializer).unwrap();
+            serializer.into_inner()
+        }
+        ScalarImpl::Jsonb(v) => v.to_string().into_bytes(),
+        ScalarImpl::Bytea(v) => v.to_vec(),
+        ScalarImpl::Uuid(v) => v.as_bytes().to_vec(),
     };
     Some(RexNode::Constant(ConstantValue { body }))
 }
@@ -25,6 +25,8 @@ mod function_call;
 mod input_ref;
 mod literal;
 mod subquery;
+mod table_function;
+mod window_function;
 
 mod expr_rewriter;
 mod expr_visitor;
@@ -34,15 +36,20 @@ mod utils;
 pub use agg_call::AggCall;
 pub use correlated_input_ref::CorrelatedInputRef;
 pub use function_call::FunctionCall;
-pub use input_ref::{as_alias_display, input_ref_to_column_indices, InputRef, InputRefDisplay};
+pub use input_ref::{
+    as_alias_display, column_idx_to_inputref_proto, input_ref_to_column_indices, InputRef,
+    InputRefDisplay,
+};
 pub use literal::Literal;
 pub use subquery::{Subquery, SubqueryKind};
+pub use table_function::{TableFunction, TableFunctionType};
+pub use window_function::{FrameBound, WindowFrame, WindowFunction, WindowFunctionType};
 
 pub type ExprType = risingwave_pb::expr::expr_node::Type;
 
 pub use expr_rewriter::ExprRewriter;
 pub use expr_visitor::ExprVisitor;
-pub use type_inference::infer_type;
+pub use type_inference::{infer_type, resolve_func_sig};
 pub use utils::*;
 
 /// the trait of bound exprssions
@@ -63,6 +70,8 @@ pub enum ExprImpl {
     FunctionCall(Box<FunctionCall>),
     AggCall(Box<AggCall>),
     Subquery(Box<Subquery>),
+    WindowFunction(Box<WindowFunction>),
+    TableFunction(Box<TableFunction>),
 }
 
 impl ExprImpl {
@@ -78,10 +87,16 @@ impl ExprImpl {
         Literal::new(Some(v.to_scalar_value()), DataType::Boolean).into()
     }
 
+    /// A literal varchar value.
+    #[inline(always)]
+    pub fn literal_varchar(v: String) -> Self {
+        Literal::new(Some(v.to_scalar_value()), DataType::Varchar).into()
+    }
+
     /// A `count(*)` aggregate function.
     #[inline(always)]
     pub fn count_star() -> Self {
-        AggCall::new(AggKind::Count, vec![]).unwrap().into()
+        AggCall::new(AggKind::Count, vec![], false).unwrap().into()
     }
 
     /// Collect all `InputRef`s' indexes in the expression.
@@ -99,6 +114,19 @@ impl ExprImpl {
         matches!(self, ExprImpl::Literal(literal) if literal.get_data().is_none())
     }
 
+    /// Check whether self is a constant, i.e. it consists of only literals and function calls which
+    /// are not volatile like `gen_random_uuid()`.
+    pub fn is_const(&self) -> bool {
+        match self {
+            ExprImpl::Literal(_) => true,
+            ExprImpl::FunctionCall(func_call) => {
+                func_call.get_expr_type() != ExprType::GenRandomUuid
+                    && func_call.inputs().iter().all(ExprImpl::is_const)
+            }
+            _ => false,
+        }
+    }
+
     /// Check if cast needs to be inserted.
     /// TODO: check castiblility with context.
     pub fn ensure_type(self, ty: DataType) -> ExprImpl {
@@ -106,6 +134,19 @@ impl ExprImpl {
             ExprImpl::Literal(Box::new(Literal::new(None, ty)))
         } else if ty == self.return_type() {
             self
+        } else if let DataType::Struct { fields, .. } = &ty
+            && let ExprImpl::FunctionCall(func_call) = &self
+            && func_call.get_expr_type() == ExprType::Row
+            && func_call.inputs().len() == fields.len()
+        {
+            // A row constructor is coerced field by field, as there is no cast between structs.
+            let (_, inputs, _) = self.into_function_call().unwrap().decompose();
+            let inputs = inputs
+                .into_iter()
+                .zip(fields.iter())
+                .map(|(input, field)| input.ensure_type(field.clone()))
+                .collect();
+            FunctionCall::new_with_return_type(ExprType::Row, inputs, ty).into()
         } else {
             ExprImpl::FunctionCall(Box::new(FunctionCall::new_with_return_type(
                 ExprType::Cast,
@@ -148,7 +189,7 @@ macro_rules! impl_has_variant {
     };
 }
 
-impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery}
+impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery, WindowFunction, TableFunction}
 
 impl ExprImpl {
     // We need to traverse inside subqueries.
@@ -173,7 +214,36 @@ impl ExprImpl {
                         .chain(select.where_clause.iter())
                         .for_each(|expr| self.visit_expr(expr)),
                     BoundSetExpr::Values(_) => {}
+                    BoundSetExpr::SetOperation { left, right, .. } => {
+                        if left.is_correlated() || right.is_correlated() {
+                            self.has = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut visitor = Has { has: false };
+        visitor.visit_expr(self);
+        visitor.has
+    }
+
+    /// Checks whether the expression contains a `GROUPING` call, which can only be evaluated
+    /// together with the aggregation.
+    pub fn has_grouping(&self) -> bool {
+        struct Has {
+            has: bool,
+        }
+
+        impl ExprVisitor for Has {
+            fn visit_function_call(&mut self, func_call: &FunctionCall) {
+                if func_call.get_expr_type() == ExprType::Grouping {
+                    self.has = true;
                 }
+                func_call
+                    .inputs()
+                    .iter()
+                    .for_each(|expr| self.visit_expr(expr))
             }
         }
 
@@ -192,6 +262,8 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(expr) => expr.return_type(),
             ExprImpl::Subquery(expr) => expr.return_type(),
             ExprImpl::CorrelatedInputRef(expr) => expr.return_type(),
+            ExprImpl::WindowFunction(expr) => expr.return_type(),
+            ExprImpl::TableFunction(expr) => expr.return_type(),
         }
     }
 
@@ -203,6 +275,8 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(e) => e.to_protobuf(),
             ExprImpl::Subquery(e) => e.to_protobuf(),
             ExprImpl::CorrelatedInputRef(e) => e.to_protobuf(),
+            ExprImpl::WindowFunction(e) => e.to_protobuf(),
+            ExprImpl::TableFunction(e) => e.to_protobuf(),
         }
     }
 }
@@ -243,6 +317,18 @@ impl From<CorrelatedInputRef> for ExprImpl {
     }
 }
 
+impl From<WindowFunction> for ExprImpl {
+    fn from(window_function: WindowFunction) -> Self {
+        ExprImpl::WindowFunction(Box::new(window_function))
+    }
+}
+
+impl From<TableFunction> for ExprImpl {
+    fn from(table_function: TableFunction) -> Self {
+        ExprImpl::TableFunction(Box::new(table_function))
+    }
+}
+
 /// A custom Debug implementation that is more concise and suitable to use with
 /// [`std::fmt::Formatter::debug_list`] in plan nodes. If the verbose output is preferred, it is
 /// still available via `{:#?}`.
@@ -258,6 +344,8 @@ impl std::fmt::Debug for ExprImpl {
                 Self::CorrelatedInputRef(arg0) => {
                     f.debug_tuple("CorrelatedInputRef").field(arg0).finish()
                 }
+                Self::WindowFunction(arg0) => f.debug_tuple("WindowFunction").field(arg0).finish(),
+                Self::TableFunction(arg0) => f.debug_tuple("TableFunction").field(arg0).finish(),
             };
         }
         match self {
@@ -267,6 +355,8 @@ impl std::fmt::Debug for ExprImpl {
             Self::AggCall(x) => write!(f, "{:?}", x),
             Self::Subquery(x) => write!(f, "{:?}", x),
             Self::CorrelatedInputRef(x) => write!(f, "{:?}", x),
+            Self::WindowFunction(x) => write!(f, "{:?}", x),
+            Self::TableFunction(x) => write!(f, "{:?}", x),
         }
     }
 }
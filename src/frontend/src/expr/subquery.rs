@@ -16,7 +16,7 @@ use std::hash::Hash;
 
 use risingwave_common::types::DataType;
 
-use super::Expr;
+use super::{Expr, ExprImpl, ExprType};
 use crate::binder::BoundQuery;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -25,8 +25,13 @@ pub enum SubqueryKind {
     Scalar,
     /// `EXISTS` | `NOT EXISTS` subquery (semi/anti-semi join). Returns a boolean.
     Existential,
-    /// `IN` | `NOT IN` | `SOME` | `ALL` subquery. Returns a boolean.
-    SetComparison,
+    /// `left op ANY (subquery)` or `left op ALL (subquery)`. `IN` is `= ANY` and `SOME` is the
+    /// same as `ANY`. Returns a boolean.
+    SetComparison {
+        left: ExprImpl,
+        op: ExprType,
+        all: bool,
+    },
 }
 
 /// Subquery expression.
@@ -74,7 +79,7 @@ impl Expr for Subquery {
                 types[0].clone()
             }
             SubqueryKind::Existential => DataType::Boolean,
-            SubqueryKind::SetComparison => DataType::Boolean,
+            SubqueryKind::SetComparison { .. } => DataType::Boolean,
         }
     }
 
@@ -0,0 +1,131 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_pb::plan::TableFunctionNode;
+
+use super::{Expr, ExprImpl};
+
+pub type TableFunctionType = risingwave_pb::plan::table_function_node::Type;
+
+/// A set-returning function, e.g. `unnest(array)`. In the `FROM` clause it's planned as a
+/// `LogicalTableFunction`, and in the select list it's planned as a `LogicalProjectSet`.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TableFunction {
+    function_type: TableFunctionType,
+    args: Vec<ExprImpl>,
+    return_type: DataType,
+}
+
+impl std::fmt::Debug for TableFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("TableFunction")
+                .field("function_type", &self.function_type)
+                .field("return_type", &self.return_type)
+                .field("args", &self.args)
+                .finish()
+        } else {
+            let mut builder = f.debug_tuple(&format!("{:?}", self.function_type));
+            self.args.iter().for_each(|child| {
+                builder.field(child);
+            });
+            builder.finish()
+        }
+    }
+}
+
+impl TableFunction {
+    /// Returns the type of the table function called `name`, if any.
+    pub fn type_from_name(name: &str) -> Option<TableFunctionType> {
+        match name.to_lowercase().as_str() {
+            "jsonb_array_elements" => Some(TableFunctionType::JsonbArrayElements),
+            "unnest" => Some(TableFunctionType::Unnest),
+            _ => None,
+        }
+    }
+
+    /// The name of the function in SQL.
+    pub fn name(function_type: TableFunctionType) -> &'static str {
+        match function_type {
+            TableFunctionType::JsonbArrayElements => "jsonb_array_elements",
+            TableFunctionType::Unnest => "unnest",
+            TableFunctionType::Unspecified => unreachable!(),
+        }
+    }
+
+    /// Checks the arguments and infers the type of the returned values.
+    pub fn new(function_type: TableFunctionType, args: Vec<ExprImpl>) -> Result<Self> {
+        let name = Self::name(function_type);
+        let [arg]: [ExprImpl; 1] = args
+            .try_into()
+            .map_err(|_| ErrorCode::BindError(format!("{} takes exactly one argument", name)))?;
+        let (arg, return_type) = match (function_type, arg.return_type()) {
+            (TableFunctionType::JsonbArrayElements, DataType::Jsonb) => (arg, DataType::Jsonb),
+            // A string literal is taken as jsonb.
+            (TableFunctionType::JsonbArrayElements, DataType::Varchar) => {
+                (arg.ensure_type(DataType::Jsonb), DataType::Jsonb)
+            }
+            (TableFunctionType::Unnest, DataType::List { datatype }) => (arg, *datatype),
+            (_, t) => {
+                return Err(ErrorCode::BindError(format!(
+                    "function {}({:?}) does not exist",
+                    name, t
+                ))
+                .into())
+            }
+        };
+        Ok(Self {
+            function_type,
+            args: vec![arg],
+            return_type,
+        })
+    }
+
+    pub fn function_type(&self) -> TableFunctionType {
+        self.function_type
+    }
+
+    pub fn args(&self) -> &[ExprImpl] {
+        &self.args
+    }
+
+    pub fn decompose(self) -> (TableFunctionType, Vec<ExprImpl>, DataType) {
+        (self.function_type, self.args, self.return_type)
+    }
+
+    /// Serializes the function to be evaluated by a table function or project set executor.
+    pub fn to_table_function_prost(&self) -> TableFunctionNode {
+        TableFunctionNode {
+            function_type: self.function_type as i32,
+            args: self.args.iter().map(Expr::to_protobuf).collect(),
+            return_type: Some(self.return_type.to_protobuf()),
+        }
+    }
+}
+
+impl Expr for TableFunction {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn to_protobuf(&self) -> risingwave_pb::expr::ExprNode {
+        // Like `AggCall`, table functions must have been planned as `LogicalProjectSet`.
+        unreachable!(
+            "TableFunction {:?} has not been rewritten to a project set operator",
+            self
+        )
+    }
+}
@@ -14,63 +14,16 @@
 
 //! This type inference is just to infer the return type of function calls, and make sure the
 //! functionCall expressions have same input type requirement and return type definition as backend.
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::vec;
+//! The signatures are looked up in the function registry of the backend.
 
-use itertools::iproduct;
 use risingwave_common::types::DataType;
+use risingwave_expr::sig::func::{FuncSign, FUNC_SIG_MAP};
+use risingwave_expr::sig::DataTypeName;
 
 use crate::expr::ExprType;
 
-/// `DataTypeName` is designed for type derivation here. In other scenarios,
-/// use `DataType` instead.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
-enum DataTypeName {
-    Boolean,
-    Int16,
-    Int32,
-    Int64,
-    Float32,
-    Float64,
-    Decimal,
-    Date,
-    Varchar,
-    Time,
-    Timestamp,
-    Timestampz,
-    Interval,
-    Struct,
-    List,
-}
-
-fn name_of(ty: &DataType) -> DataTypeName {
-    match ty {
-        DataType::Int16 => DataTypeName::Int16,
-        DataType::Int32 => DataTypeName::Int32,
-        DataType::Int64 => DataTypeName::Int64,
-        DataType::Float32 => DataTypeName::Float32,
-        DataType::Float64 => DataTypeName::Float64,
-        DataType::Boolean => DataTypeName::Boolean,
-        DataType::Varchar => DataTypeName::Varchar,
-        DataType::Date => DataTypeName::Date,
-        DataType::Time => DataTypeName::Time,
-        DataType::Timestamp => DataTypeName::Timestamp,
-        DataType::Timestampz => DataTypeName::Timestampz,
-        DataType::Decimal => DataTypeName::Decimal,
-        DataType::Interval => DataTypeName::Interval,
-        DataType::Struct { .. } => DataTypeName::Struct,
-        DataType::List { .. } => DataTypeName::List,
-    }
-}
-
-/// Infers the return type of a function. Returns `None` if the function with specified data types
-/// is not supported on backend.
-pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Option<DataType> {
-    // With our current simplified type system, where all types are nullable and not parameterized
-    // by things like length or precision, the inference can be done with a map lookup.
-    let input_type_names = inputs_type.iter().map(name_of).collect();
-    infer_type_name(func_type, input_type_names).map(|type_name| match type_name {
+fn type_of(type_name: DataTypeName) -> DataType {
+    match type_name {
         DataTypeName::Int16 => DataType::Int16,
         DataTypeName::Int32 => DataType::Int32,
         DataTypeName::Int64 => DataType::Int64,
@@ -84,294 +37,73 @@ pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Option<Dat
         DataTypeName::Timestampz => DataType::Timestampz,
         DataTypeName::Decimal => DataType::Decimal,
         DataTypeName::Interval => DataType::Interval,
-        DataTypeName::Struct => DataType::Struct {
-            fields: Arc::new([]),
-        },
+        DataTypeName::Struct => DataType::new_struct(vec![], vec![]),
         DataTypeName::List => DataType::List {
             datatype: Box::new(DataType::Int32),
         },
-    })
+        DataTypeName::Jsonb => DataType::Jsonb,
+        DataTypeName::Bytea => DataType::Bytea,
+        DataTypeName::Uuid => DataType::Uuid,
+    }
 }
 
-/// Infer the return type name without parameters like length or precision.
-fn infer_type_name(func_type: ExprType, inputs_type: Vec<DataTypeName>) -> Option<DataTypeName> {
+/// Infers the return type of a function. Returns `None` if the function with specified data types
+/// is not supported on backend.
+pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Option<DataType> {
+    // With our current simplified type system, where all types are nullable and not parameterized
+    // by things like length or precision, the inference can be done with a map lookup.
+    let input_type_names = inputs_type
+        .iter()
+        .map(DataTypeName::from)
+        .collect::<Vec<_>>();
     FUNC_SIG_MAP
-        .get(&FuncSign {
-            func: func_type,
-            inputs_type,
-        })
-        .cloned()
+        .get(func_type, &input_type_names)
+        .map(|sig| type_of(sig.ret_type))
 }
 
-#[derive(PartialEq, Hash)]
-struct FuncSign {
-    func: ExprType,
-    inputs_type: Vec<DataTypeName>,
-}
-
-impl Eq for FuncSign {}
-
-#[allow(dead_code)]
-impl FuncSign {
-    pub fn new(func: ExprType, inputs_type: Vec<DataTypeName>) -> Self {
-        FuncSign { func, inputs_type }
-    }
-
-    pub fn new_no_input(func: ExprType) -> Self {
-        FuncSign {
-            func,
-            inputs_type: vec![],
-        }
-    }
-
-    pub fn new_unary(func: ExprType, p1: DataTypeName) -> Self {
-        FuncSign {
-            func,
-            inputs_type: vec![p1],
-        }
-    }
-
-    pub fn new_binary(func: ExprType, p1: DataTypeName, p2: DataTypeName) -> Self {
-        FuncSign {
-            func,
-            inputs_type: vec![p1, p2],
-        }
-    }
-
-    pub fn new_ternary(
-        func: ExprType,
-        p1: DataTypeName,
-        p2: DataTypeName,
-        p3: DataTypeName,
-    ) -> Self {
-        FuncSign {
-            func,
-            inputs_type: vec![p1, p2, p3],
+/// Resolves the overload of a function that the inputs can be implicitly cast to, where `None`
+/// stands for a NULL input of unknown type. An exact match is always preferred. Otherwise, the
+/// signature which needs the fewest casts is chosen, and the one registered first wins a tie.
+///
+/// Returns the types that the inputs should be cast to and the return type.
+pub fn resolve_func_sig(
+    func_type: ExprType,
+    inputs_type: &[Option<DataType>],
+) -> Option<(Vec<DataType>, DataType)> {
+    let input_type_names = inputs_type
+        .iter()
+        .map(|t| t.as_ref().map(DataTypeName::from))
+        .collect::<Vec<_>>();
+    let casts_needed = |sig: &FuncSign| -> Option<usize> {
+        let mut casts = 0;
+        for (input, param) in input_type_names.iter().zip(&sig.inputs_type) {
+            match input {
+                None => {}
+                Some(input) if input == param => {}
+                Some(input) if input.can_implicit_cast_to(*param) => casts += 1,
+                Some(_) => return None,
+            }
         }
-    }
-}
-
-fn arithmetic_type_derive(t1: DataTypeName, t2: DataTypeName) -> DataTypeName {
-    if t2 as i32 > t1 as i32 {
-        t2
-    } else {
-        t1
-    }
-}
-
-fn build_unary_funcs(
-    map: &mut HashMap<FuncSign, DataTypeName>,
-    exprs: &[ExprType],
-    arg1: &[DataTypeName],
-    ret: DataTypeName,
-) {
-    for (expr, a1) in iproduct!(exprs, arg1) {
-        map.insert(FuncSign::new_unary(*expr, *a1), ret);
-    }
-}
-
-fn build_binary_funcs(
-    map: &mut HashMap<FuncSign, DataTypeName>,
-    exprs: &[ExprType],
-    arg1: &[DataTypeName],
-    arg2: &[DataTypeName],
-    ret: DataTypeName,
-) {
-    for (expr, a1, a2) in iproduct!(exprs, arg1, arg2) {
-        map.insert(FuncSign::new_binary(*expr, *a1, *a2), ret);
-    }
-}
-
-fn build_commutative_binary_funcs(
-    map: &mut HashMap<FuncSign, DataTypeName>,
-    exprs: &[ExprType],
-    arg1: &[DataTypeName],
-    arg2: &[DataTypeName],
-    ret: DataTypeName,
-) {
-    build_binary_funcs(map, exprs, arg1, arg2, ret);
-    build_binary_funcs(map, exprs, arg2, arg1, ret);
-}
-
-fn build_ternary_funcs(
-    map: &mut HashMap<FuncSign, DataTypeName>,
-    exprs: &[ExprType],
-    arg1: &[DataTypeName],
-    arg2: &[DataTypeName],
-    arg3: &[DataTypeName],
-    ret: DataTypeName,
-) {
-    for (expr, a1, a2, a3) in iproduct!(exprs, arg1, arg2, arg3) {
-        map.insert(FuncSign::new_ternary(*expr, *a1, *a2, *a3), ret);
-    }
-}
-
-fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
-    use {DataTypeName as T, ExprType as E};
-    let mut map = HashMap::new();
-    let num_types = vec![
-        T::Int16,
-        T::Int32,
-        T::Int64,
-        T::Float32,
-        T::Float64,
-        T::Decimal,
-    ];
-    let all_types = vec![
-        T::Int16,
-        T::Int32,
-        T::Int64,
-        T::Float32,
-        T::Float64,
-        T::Boolean,
-        T::Varchar,
-        T::Decimal,
-        T::Time,
-        T::Timestamp,
-        T::Interval,
-        T::Date,
-        T::Timestampz,
-    ];
-    let str_types = vec![T::Varchar];
-    let atm_exprs = vec![E::Add, E::Subtract, E::Multiply, E::Divide, E::Modulus];
-    let cmp_exprs = vec![
-        E::Equal,
-        E::NotEqual,
-        E::LessThan,
-        E::LessThanOrEqual,
-        E::GreaterThan,
-        E::GreaterThanOrEqual,
-    ];
-    for (expr, t1, t2) in iproduct!(atm_exprs, num_types.clone(), num_types.clone()) {
-        map.insert(
-            FuncSign::new_binary(expr, t1, t2),
-            arithmetic_type_derive(t1, t2),
-        );
-    }
-    for t in num_types.clone() {
-        map.insert(FuncSign::new_unary(E::Neg, t), t);
-    }
-    build_binary_funcs(&mut map, &cmp_exprs, &num_types, &num_types, T::Boolean);
-    build_binary_funcs(&mut map, &cmp_exprs, &str_types, &str_types, T::Boolean);
-    build_binary_funcs(
-        &mut map,
-        &cmp_exprs,
-        &[T::Boolean],
-        &[T::Boolean],
-        T::Boolean,
-    );
-
-    // Date comparisons
-    build_binary_funcs(
-        &mut map,
-        &cmp_exprs,
-        &[T::Date, T::Timestamp],
-        &[T::Date, T::Timestamp],
-        T::Boolean,
-    );
-    // Date/Timestamp/Interval arithmetic
-    build_commutative_binary_funcs(
-        &mut map,
-        &[E::Add],
-        &[T::Timestamp, T::Date],
-        &[T::Interval],
-        T::Timestamp,
-    );
-    build_binary_funcs(
-        &mut map,
-        &[E::Subtract],
-        &[T::Timestamp, T::Date],
-        &[T::Interval],
-        T::Timestamp,
-    );
-    build_commutative_binary_funcs(
-        &mut map,
-        &[E::Multiply],
-        &[T::Interval],
-        &[T::Int16, T::Int32, T::Int64],
-        T::Interval,
-    );
-
-    build_binary_funcs(
-        &mut map,
-        &[E::And, E::Or],
-        &[T::Boolean],
-        &[T::Boolean],
-        T::Boolean,
-    );
-    build_unary_funcs(
-        &mut map,
-        &[E::IsTrue, E::IsNotTrue, E::IsFalse, E::IsNotFalse, E::Not],
-        &[T::Boolean],
-        T::Boolean,
-    );
-    build_unary_funcs(
-        &mut map,
-        &[E::IsNull, E::IsNotNull, E::StreamNullByRowCount],
-        &all_types,
-        T::Boolean,
-    );
-    build_binary_funcs(&mut map, &[E::Substr], &str_types, &num_types, T::Varchar);
-    build_ternary_funcs(
-        &mut map,
-        &[E::Substr],
-        &str_types,
-        &num_types,
-        &num_types,
-        T::Varchar,
-    );
-    build_unary_funcs(&mut map, &[E::Length], &str_types, T::Int32);
-    build_unary_funcs(
-        &mut map,
-        &[E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper],
-        &str_types,
-        T::Varchar,
-    );
-    build_binary_funcs(
-        &mut map,
-        &[E::Trim, E::Ltrim, E::Rtrim, E::Position],
-        &str_types,
-        &str_types,
-        T::Varchar,
-    );
-    build_binary_funcs(&mut map, &[E::Like], &str_types, &str_types, T::Boolean);
-    build_ternary_funcs(
-        &mut map,
-        &[E::Replace],
-        &str_types,
-        &str_types,
-        &str_types,
-        T::Varchar,
-    );
-    build_binary_funcs(
-        &mut map,
-        &[E::RoundDigit],
-        &[T::Decimal],
-        &[T::Int32],
-        T::Decimal,
-    );
-    build_binary_funcs(
-        &mut map,
-        &[E::Extract],
-        &[T::Varchar], // Time field, "YEAR", "DAY", etc
-        &[T::Timestamp, T::Time, T::Date],
-        T::Decimal,
-    );
-    build_binary_funcs(
-        &mut map,
-        &[E::TumbleStart],
-        &[T::Date, T::Timestamp],
-        &[T::Interval],
-        T::Timestamp,
-    );
-    map
-}
-
-lazy_static::lazy_static! {
-    static ref FUNC_SIG_MAP: HashMap<FuncSign, DataTypeName> = {
-        build_type_derive_map()
+        Some(casts)
     };
+    let sig = FUNC_SIG_MAP
+        .get_with_arg_nums(func_type, inputs_type.len())
+        .iter()
+        .filter_map(|sig| casts_needed(sig).map(|casts| (casts, sig)))
+        .min_by_key(|(casts, _)| *casts)?
+        .1;
+    let target_types = inputs_type
+        .iter()
+        .zip(&sig.inputs_type)
+        .map(|(input, param)| match input {
+            // Types like structs are kept as is, as their names don't carry the fields.
+            Some(input) if DataTypeName::from(input) == *param => input.clone(),
+            _ => type_of(*param),
+        })
+        .collect();
+    Some((target_types, type_of(sig.ret_type)))
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -474,4 +206,154 @@ mod tests {
             test_infer_type_not_exist(expr, vec![num_t, DataType::Boolean]);
         }
     }
+
+    #[test]
+    fn test_date_time() {
+        use DataType::*;
+        let cases = vec![
+            (ExprType::Add, vec![Timestamp, Interval], Timestamp),
+            (ExprType::Add, vec![Interval, Date], Timestamp),
+            (ExprType::Add, vec![Interval, Interval], Interval),
+            (ExprType::Subtract, vec![Timestamp, Timestamp], Interval),
+            (ExprType::Subtract, vec![Date, Date], Int32),
+            (ExprType::Subtract, vec![Interval, Interval], Interval),
+            (ExprType::DateTrunc, vec![Varchar, Timestamp], Timestamp),
+            (ExprType::ToChar, vec![Timestamp, Varchar], Varchar),
+            (ExprType::ToTimestamp, vec![Varchar, Varchar], Timestamp),
+            (ExprType::Age, vec![Timestamp, Timestamp], Interval),
+        ];
+        for (expr, inputs, ret) in cases {
+            test_simple_infer_type(expr, inputs, ret);
+        }
+        test_infer_type_not_exist(ExprType::Subtract, vec![Interval, Timestamp]);
+        test_infer_type_not_exist(ExprType::DateTrunc, vec![Varchar, Date]);
+    }
+
+    #[test]
+    fn test_jsonb() {
+        use DataType::*;
+        let cases = vec![
+            (ExprType::JsonbAccessInner, vec![Jsonb, Varchar], Jsonb),
+            (ExprType::JsonbAccessInner, vec![Jsonb, Int32], Jsonb),
+            (ExprType::JsonbAccessStr, vec![Jsonb, Int32], Varchar),
+            (ExprType::JsonbAccessPath, vec![Jsonb, Varchar], Jsonb),
+            (ExprType::JsonbAccessPathStr, vec![Jsonb, Varchar], Varchar),
+            (ExprType::JsonbContains, vec![Jsonb, Jsonb], Boolean),
+        ];
+        for (expr, inputs, ret) in cases {
+            test_simple_infer_type(expr, inputs, ret);
+        }
+        test_infer_type_not_exist(ExprType::JsonbAccessInner, vec![Jsonb, Int64]);
+        test_infer_type_not_exist(ExprType::JsonbContains, vec![Jsonb, Varchar]);
+    }
+
+    #[test]
+    fn test_bytea_uuid() {
+        use DataType::*;
+        let cases = vec![
+            (ExprType::Encode, vec![Bytea, Varchar], Varchar),
+            (ExprType::Decode, vec![Varchar, Varchar], Bytea),
+            (ExprType::GenRandomUuid, vec![], Uuid),
+            (ExprType::Equal, vec![Bytea, Bytea], Boolean),
+            (ExprType::LessThan, vec![Uuid, Uuid], Boolean),
+            (ExprType::IsNull, vec![Uuid], Boolean),
+        ];
+        for (expr, inputs, ret) in cases {
+            test_simple_infer_type(expr, inputs, ret);
+        }
+        test_infer_type_not_exist(ExprType::Encode, vec![Varchar, Varchar]);
+        test_infer_type_not_exist(ExprType::Equal, vec![Uuid, Varchar]);
+    }
+
+    #[test]
+    fn test_math_functions() {
+        use DataType::*;
+        let cases = vec![
+            (ExprType::Abs, vec![Int16], Int16),
+            (ExprType::Abs, vec![Float32], Float32),
+            (ExprType::Abs, vec![Decimal], Decimal),
+            (ExprType::Ceil, vec![Float64], Float64),
+            (ExprType::Floor, vec![Decimal], Decimal),
+            (ExprType::Round, vec![Float64], Float64),
+            (ExprType::Sqrt, vec![Decimal], Decimal),
+            (ExprType::Ln, vec![Float64], Float64),
+            (ExprType::Exp, vec![Decimal], Decimal),
+            (ExprType::Sign, vec![Float64], Float64),
+            (ExprType::Pow, vec![Float64, Float64], Float64),
+            (ExprType::Pow, vec![Decimal, Decimal], Decimal),
+        ];
+        for (expr, inputs, ret) in cases {
+            test_simple_infer_type(expr, inputs, ret);
+        }
+        test_infer_type_not_exist(ExprType::Sqrt, vec![Int32]);
+        test_infer_type_not_exist(ExprType::Pow, vec![Float64, Decimal]);
+        test_infer_type_not_exist(ExprType::Abs, vec![Varchar]);
+    }
+
+    #[test]
+    fn test_string_functions() {
+        use DataType::*;
+        let cases = vec![
+            (ExprType::ConcatOp, vec![Varchar, Varchar], Varchar),
+            (ExprType::SplitPart, vec![Varchar, Varchar, Int32], Varchar),
+            (ExprType::Lpad, vec![Varchar, Int32, Varchar], Varchar),
+            (
+                ExprType::RegexpReplace,
+                vec![Varchar, Varchar, Varchar],
+                Varchar,
+            ),
+            (
+                ExprType::RegexpReplace,
+                vec![Varchar, Varchar, Varchar, Varchar],
+                Varchar,
+            ),
+            (ExprType::Md5, vec![Varchar], Varchar),
+            (ExprType::StartsWith, vec![Varchar, Varchar], Boolean),
+            (ExprType::Ilike, vec![Varchar, Varchar], Boolean),
+            (ExprType::Like, vec![Varchar, Varchar, Varchar], Boolean),
+        ];
+        for (expr, inputs, ret) in cases {
+            test_simple_infer_type(expr, inputs, ret);
+        }
+        test_infer_type_not_exist(ExprType::SplitPart, vec![Varchar, Varchar, Varchar]);
+        test_infer_type_not_exist(ExprType::Md5, vec![Int32]);
+    }
+
+    #[test]
+    fn test_resolve_func_sig() {
+        use DataType::*;
+        let cases = vec![
+            // An exact match needs no cast.
+            (ExprType::Sqrt, vec![Some(Decimal)], vec![Decimal], Decimal),
+            // Integers are cast to the overload registered first among the ties.
+            (ExprType::Sqrt, vec![Some(Int32)], vec![Float64], Float64),
+            (
+                ExprType::Pow,
+                vec![Some(Int32), Some(Decimal)],
+                vec![Decimal, Decimal],
+                Decimal,
+            ),
+            (
+                ExprType::Pow,
+                vec![Some(Float32), Some(Decimal)],
+                vec![Float64, Float64],
+                Float64,
+            ),
+            (
+                ExprType::DateTrunc,
+                vec![Some(Varchar), Some(Date)],
+                vec![Varchar, Timestamp],
+                Timestamp,
+            ),
+            (ExprType::Upper, vec![None], vec![Varchar], Varchar),
+        ];
+        for (expr, inputs, casts, ret) in cases {
+            assert_eq!(resolve_func_sig(expr, &inputs), Some((casts, ret)));
+        }
+        assert_eq!(resolve_func_sig(ExprType::Sqrt, &[Some(Varchar)]), None);
+        assert_eq!(
+            resolve_func_sig(ExprType::Sqrt, &[Some(Float64), Some(Float64)]),
+            None
+        );
+    }
 }
@@ -0,0 +1,279 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::AggKind;
+use risingwave_pb::expr::window_frame::{Bound as ProstBound, BoundType, Type as ProstFrameType};
+use risingwave_pb::expr::WindowFrame as ProstWindowFrame;
+
+use super::{AggCall, Expr, ExprImpl};
+use crate::optimizer::property::Direction;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowFunctionType {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag,
+    Lead,
+    Aggregate(AggKind),
+}
+
+impl fmt::Display for WindowFunctionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RowNumber => write!(f, "row_number"),
+            Self::Rank => write!(f, "rank"),
+            Self::DenseRank => write!(f, "dense_rank"),
+            Self::Lag => write!(f, "lag"),
+            Self::Lead => write!(f, "lead"),
+            Self::Aggregate(kind) => write!(f, "{}", kind),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+impl FrameBound {
+    fn to_protobuf(self) -> ProstBound {
+        let (r#type, offset) = match self {
+            Self::UnboundedPreceding => (BoundType::UnboundedPreceding, 0),
+            Self::Preceding(n) => (BoundType::Preceding, n),
+            Self::CurrentRow => (BoundType::CurrentRow, 0),
+            Self::Following(n) => (BoundType::Following, n),
+            Self::UnboundedFollowing => (BoundType::UnboundedFollowing, 0),
+        };
+        ProstBound {
+            r#type: r#type as i32,
+            offset,
+        }
+    }
+}
+
+impl fmt::Display for FrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            Self::Preceding(n) => write!(f, "{} PRECEDING", n),
+            Self::CurrentRow => write!(f, "CURRENT ROW"),
+            Self::Following(n) => write!(f, "{} FOLLOWING", n),
+            Self::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+/// The frame of a window function. With `rows == false`, it is a `RANGE` frame, where
+/// `CURRENT ROW` includes all peers of the current row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub rows: bool,
+    pub start: FrameBound,
+    pub end: FrameBound,
+}
+
+impl WindowFrame {
+    /// The default frame, i.e. `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`, which is the
+    /// whole partition if there is no `ORDER BY`.
+    pub fn range_to_current_row() -> Self {
+        Self {
+            rows: false,
+            start: FrameBound::UnboundedPreceding,
+            end: FrameBound::CurrentRow,
+        }
+    }
+
+    pub fn to_protobuf(self) -> ProstWindowFrame {
+        ProstWindowFrame {
+            r#type: if self.rows {
+                ProstFrameType::Rows
+            } else {
+                ProstFrameType::Range
+            } as i32,
+            start: Some(self.start.to_protobuf()),
+            end: Some(self.end.to_protobuf()),
+        }
+    }
+}
+
+impl fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} BETWEEN {} AND {}",
+            if self.rows { "ROWS" } else { "RANGE" },
+            self.start,
+            self.end
+        )
+    }
+}
+
+/// A function call with an `OVER` clause, e.g. `row_number() OVER (PARTITION BY a ORDER BY b)`.
+///
+/// Like [`AggCall`], it only appears in the select list and is planned as a
+/// [`crate::optimizer::plan_node::LogicalOverAgg`].
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct WindowFunction {
+    function_type: WindowFunctionType,
+    return_type: DataType,
+    args: Vec<ExprImpl>,
+    /// The offset of `lag` and `lead`.
+    offset: u64,
+    partition_by: Vec<ExprImpl>,
+    order_by: Vec<(ExprImpl, Direction)>,
+    frame: WindowFrame,
+}
+
+impl fmt::Debug for WindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("WindowFunction")
+                .field("function_type", &self.function_type)
+                .field("return_type", &self.return_type)
+                .field("args", &self.args)
+                .field("offset", &self.offset)
+                .field("partition_by", &self.partition_by)
+                .field("order_by", &self.order_by)
+                .field("frame", &self.frame)
+                .finish()
+        } else {
+            let mut builder = f.debug_tuple(&format!("{}", self.function_type));
+            self.args.iter().for_each(|child| {
+                builder.field(child);
+            });
+            builder.finish()?;
+            write!(
+                f,
+                " OVER(PARTITION BY {:?} ORDER BY {:?} {})",
+                self.partition_by, self.order_by, self.frame
+            )
+        }
+    }
+}
+
+impl WindowFunction {
+    /// Returns error if the function can not be called with `args`.
+    pub fn new(
+        function_type: WindowFunctionType,
+        args: Vec<ExprImpl>,
+        offset: u64,
+        partition_by: Vec<ExprImpl>,
+        order_by: Vec<(ExprImpl, Direction)>,
+        frame: WindowFrame,
+    ) -> Result<Self> {
+        let err = || -> RwError {
+            let args = args
+                .iter()
+                .map(|e| format!("{:?}", e.return_type()))
+                .join(", ");
+            ErrorCode::BindError(format!(
+                "function {}({}) does not exist",
+                function_type, args
+            ))
+            .into()
+        };
+        let return_type = match (&function_type, args.as_slice()) {
+            (
+                WindowFunctionType::RowNumber
+                | WindowFunctionType::Rank
+                | WindowFunctionType::DenseRank,
+                [],
+            ) => DataType::Int64,
+            (WindowFunctionType::Lag | WindowFunctionType::Lead, [arg]) => arg.return_type(),
+            (WindowFunctionType::Aggregate(kind), _) => {
+                AggCall::new(kind.clone(), args.clone(), false)?.return_type()
+            }
+            _ => return Err(err()),
+        };
+        Ok(Self {
+            function_type,
+            return_type,
+            args,
+            offset,
+            partition_by,
+            order_by,
+            frame,
+        })
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn decompose(
+        self,
+    ) -> (
+        WindowFunctionType,
+        Vec<ExprImpl>,
+        u64,
+        Vec<ExprImpl>,
+        Vec<(ExprImpl, Direction)>,
+        WindowFrame,
+    ) {
+        (
+            self.function_type,
+            self.args,
+            self.offset,
+            self.partition_by,
+            self.order_by,
+            self.frame,
+        )
+    }
+
+    pub fn function_type(&self) -> &WindowFunctionType {
+        &self.function_type
+    }
+
+    pub fn args(&self) -> &[ExprImpl] {
+        &self.args
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn partition_by(&self) -> &[ExprImpl] {
+        &self.partition_by
+    }
+
+    pub fn order_by(&self) -> &[(ExprImpl, Direction)] {
+        &self.order_by
+    }
+
+    pub fn frame(&self) -> WindowFrame {
+        self.frame
+    }
+}
+
+impl Expr for WindowFunction {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn to_protobuf(&self) -> risingwave_pb::expr::ExprNode {
+        // Like `AggCall`, window functions must have been rewritten to `LogicalOverAgg` during
+        // planning.
+        unreachable!(
+            "WindowFunction {:?} has not been rewritten to physical window operators",
+            self
+        )
+    }
+}
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::Table as ProstTable;
 use risingwave_sqlparser::ast::{ObjectName, Query};
 
 use crate::binder::Binder;
+use crate::catalog::column_catalog::ColumnCatalog;
+use crate::catalog::table_catalog::TableCatalog;
+use crate::catalog::{DatabaseId, SchemaId};
 use crate::optimizer::property::Distribution;
 use crate::optimizer::PlanRef;
 use crate::planner::Planner;
@@ -37,12 +41,35 @@ pub fn gen_create_mv_plan(
         .read_guard()
         .check_relation_name_duplicated(session.database(), &schema_name, &table_name)?;
 
+    gen_mv_plan(session, context, query, table_name, database_id, schema_id)
+}
+
+fn gen_mv_plan(
+    session: &SessionImpl,
+    context: OptimizerContextRef,
+    query: Box<Query>,
+    table_name: String,
+    database_id: DatabaseId,
+    schema_id: SchemaId,
+) -> Result<(PlanRef, ProstTable)> {
     let bound = {
         let mut binder = Binder::new(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
         );
-        binder.bind_query(*query)?
+        let bound = binder.bind_query(*query)?;
+        // TODO: `now()` in streaming should be bound per barrier epoch, e.g. by a stream node that
+        // appends the timestamp of the epoch as a column for filters and projects to refer to, and
+        // the rows it filtered should be retracted as the time goes by. Until then, reject it
+        // rather than binding it to the time of creation, which is wrong for the rows coming later.
+        if binder.uses_now() {
+            return Err(ErrorCode::NotImplemented(
+                "now() in materialized views, which should be bound per barrier epoch".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        bound
     };
 
     let mut plan_root = Planner::new(context).plan_query(bound)?;
@@ -76,3 +103,151 @@ pub async fn handle_create_mv(
         StatementType::CREATE_MATERIALIZED_VIEW,
     ))
 }
+
+/// Handle `CREATE OR REPLACE MATERIALIZED VIEW`. If the materialized view exists, it is replaced
+/// in place by the new query, which must produce the same columns.
+pub async fn handle_create_or_replace_mv(
+    context: OptimizerContext,
+    name: ObjectName,
+    query: Box<Query>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+    let (schema_name, table_name) = Binder::resolve_table_name(name.clone())?;
+
+    let old_table = {
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let schema = catalog_reader.get_schema_by_name(session.database(), &schema_name)?;
+        match schema.get_table_by_name(&table_name) {
+            Some(table) if table.associated_source_id().is_none() => {
+                let database_id = catalog_reader
+                    .get_database_by_name(session.database())?
+                    .id();
+                Some((table.clone(), database_id, schema.id()))
+            }
+            // Report the name as duplicated if it is taken by a relation of another kind.
+            _ => {
+                catalog_reader.check_relation_name_duplicated(
+                    session.database(),
+                    &schema_name,
+                    &table_name,
+                )?;
+                None
+            }
+        }
+    };
+    let (old_table, database_id, schema_id) = match old_table {
+        Some(old_table) => old_table,
+        None => return handle_create_mv(context, name, query).await,
+    };
+
+    let (mut table, stream_plan) = {
+        let (plan, table) = gen_mv_plan(
+            &session,
+            context.into(),
+            query,
+            table_name,
+            database_id,
+            schema_id,
+        )?;
+        let stream_plan = plan.to_stream_prost();
+        (table, stream_plan)
+    };
+
+    let visible_columns = |columns: &[ColumnCatalog]| {
+        columns
+            .iter()
+            .filter(|c| !c.is_hidden())
+            .map(|c| (c.name().to_string(), c.data_type().clone()))
+            .collect_vec()
+    };
+    let old_columns = visible_columns(old_table.columns());
+    let new_columns = visible_columns(TableCatalog::from(&table).columns());
+    if old_columns != new_columns {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "cannot change the output columns of materialized view \"{}\" from ({}) to ({})",
+            old_table.name(),
+            old_columns
+                .iter()
+                .map(|(name, data_type)| format!("{} {:?}", name, data_type))
+                .join(", "),
+            new_columns
+                .iter()
+                .map(|(name, data_type)| format!("{} {:?}", name, data_type))
+                .join(", "),
+        ))
+        .into());
+    }
+
+    // The id tells meta which materialized view to replace.
+    table.id = old_table.id().table_id;
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .replace_materialized_view(table, stream_plan)
+        .await?;
+
+    Ok(PgResponse::empty_result(
+        StatementType::CREATE_MATERIALIZED_VIEW,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_create_or_replace_mv_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create or replace materialized view mv as select v1 from t")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let mv_id = || {
+            session
+                .env()
+                .catalog_reader()
+                .read_guard()
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "mv")
+                .unwrap()
+                .id()
+        };
+        let old_id = mv_id();
+
+        frontend
+            .run_sql("create or replace materialized view mv as select v2 as v1 from t")
+            .await
+            .unwrap();
+        assert_ne!(mv_id(), old_id);
+
+        let err = frontend
+            .run_sql("create or replace materialized view mv as select v1, v2 from t")
+            .await
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("cannot change the output columns"));
+    }
+
+    #[tokio::test]
+    async fn test_create_mv_with_now_not_implemented() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 timestamp)")
+            .await
+            .unwrap();
+        let err = frontend
+            .run_sql("create materialized view mv as select * from t where v1 > now()")
+            .await
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("Feature is not yet implemented: now() in materialized views"));
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_connector::sink::{SinkFormat, SINK_CONNECTOR_KEY};
+use risingwave_pb::catalog::Sink as ProstSink;
+use risingwave_sqlparser::ast::{CreateSinkStatement, Statement, Value, WithProperties};
+use risingwave_sqlparser::parser::Parser;
+
+use crate::binder::Binder;
+use crate::catalog::CatalogError;
+use crate::optimizer::property::Distribution;
+use crate::optimizer::PlanRef;
+use crate::planner::Planner;
+use crate::session::{OptimizerContext, OptimizerContextRef, SessionImpl};
+
+/// Unlike `HashMap::from(WithProperties)`, string values are taken without quotes, as they are
+/// passed to the connector as they are.
+fn sink_properties(with_properties: WithProperties) -> HashMap<String, String> {
+    with_properties
+        .0
+        .into_iter()
+        .map(|option| {
+            let value = match option.value {
+                Value::SingleQuotedString(s) => s,
+                value => value.to_string(),
+            };
+            (option.name.value, value)
+        })
+        .collect()
+}
+
+/// Generate the plan delivering all changes of the materialized view to the sink, return plan and
+/// sink info.
+pub fn gen_sink_plan(
+    session: &SessionImpl,
+    context: OptimizerContextRef,
+    stmt: CreateSinkStatement,
+) -> Result<(PlanRef, ProstSink)> {
+    let (schema_name, sink_name) = Binder::resolve_table_name(stmt.sink_name)?;
+    let (mv_schema_name, mv_name) = Binder::resolve_table_name(stmt.materialized_view.clone())?;
+    let (database_id, schema_id) = {
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        catalog_reader
+            .get_schema_by_name(session.database(), &mv_schema_name)?
+            .get_table_by_name(&mv_name)
+            .ok_or_else(|| CatalogError::NotFound("materialized view", mv_name.clone()))?;
+        catalog_reader.check_relation_name_duplicated(
+            session.database(),
+            &schema_name,
+            &sink_name,
+        )?
+    };
+
+    let properties = sink_properties(stmt.with_properties);
+    if !properties.contains_key(SINK_CONNECTOR_KEY) {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "sink property `{}` is required",
+            SINK_CONNECTOR_KEY
+        ))
+        .into());
+    }
+    SinkFormat::from_properties(&properties)
+        .map_err(|e| ErrorCode::InvalidInputSyntax(e.to_string()))?;
+
+    // A sink delivers every change of the materialized view, so it is planned as a streaming query
+    // of all its columns.
+    let query = match Parser::parse_sql(&format!("SELECT * FROM {}", stmt.materialized_view))
+        .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+        .pop()
+    {
+        Some(Statement::Query(query)) => query,
+        _ => unreachable!(),
+    };
+    let bound = {
+        let mut binder = Binder::new(
+            session.env().catalog_reader().read_guard(),
+            session.database().to_string(),
+        );
+        binder.bind_query(*query)?
+    };
+
+    let mut plan_root = Planner::new(context).plan_query(bound)?;
+    plan_root.set_required_dist(Distribution::any().clone());
+    let sink_plan = plan_root.gen_create_sink_plan(properties.clone())?;
+
+    let sink = ProstSink {
+        id: 0,
+        schema_id,
+        database_id,
+        name: sink_name,
+        properties,
+        dependent_relations: vec![],
+    };
+
+    Ok((sink_plan.into(), sink))
+}
+
+pub async fn handle_create_sink(
+    context: OptimizerContext,
+    stmt: CreateSinkStatement,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+
+    if stmt.if_not_exists {
+        let (schema_name, sink_name) = Binder::resolve_table_name(stmt.sink_name.clone())?;
+        if session
+            .env()
+            .catalog_reader()
+            .read_guard()
+            .get_sink_by_name(session.database(), &schema_name, &sink_name)
+            .is_ok()
+        {
+            return Ok(PgResponse::empty_result(StatementType::CREATE_SINK));
+        }
+    }
+
+    let (sink, stream_plan) = {
+        let (plan, sink) = gen_sink_plan(&session, context.into(), stmt)?;
+        (sink, plan.to_stream_prost())
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_sink(sink, stream_plan).await?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_SINK))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_create_sink_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create materialized view mv as select v1, sum(v2) as s from t group by v1")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create sink snk from mv with (connector = 'file', path = '/tmp/snk.json')")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+        let sink = catalog_reader
+            .read_guard()
+            .get_sink_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "snk")
+            .unwrap()
+            .clone();
+        assert_eq!(sink.properties["connector"], "file");
+        assert_eq!(sink.properties["path"], "/tmp/snk.json");
+
+        // The name is taken.
+        assert!(frontend
+            .run_sql("create sink snk from mv with (connector = 'file', path = '/tmp/snk.json')")
+            .await
+            .is_err());
+        frontend
+            .run_sql("create sink if not exists snk from mv with (connector = 'file')")
+            .await
+            .unwrap();
+        // Only materialized views can be sunk, with a connector.
+        assert!(frontend
+            .run_sql("create sink snk2 from v with (connector = 'file')")
+            .await
+            .is_err());
+        assert!(frontend.run_sql("create sink snk2 from mv").await.is_err());
+        assert!(frontend
+            .run_sql("create sink snk2 from mv with (connector = 'file', format = 'csv')")
+            .await
+            .is_err());
+    }
+}
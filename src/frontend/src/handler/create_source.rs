@@ -16,16 +16,20 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
 use risingwave_pb::catalog::source::Info;
-use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo};
+use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo, WatermarkDesc};
 use risingwave_pb::plan::{ColumnCatalog as ProstColumnCatalog, RowFormatType};
 use risingwave_source::ProtobufParser;
-use risingwave_sqlparser::ast::{CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema};
+use risingwave_sqlparser::ast::{
+    CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema, SourceWatermark,
+};
 
 use super::create_table::{bind_sql_columns, gen_materialized_source_plan};
 use crate::binder::Binder;
 use crate::catalog::column_catalog::ColumnCatalog;
+use crate::expr::Expr;
 use crate::session::{OptimizerContext, SessionImpl};
 
 pub(crate) fn make_prost_source(
@@ -50,6 +54,60 @@ pub(crate) fn make_prost_source(
     })
 }
 
+/// Binds the `WATERMARK FOR` of a source on its `columns`.
+fn bind_source_watermark(
+    session: &SessionImpl,
+    source_name: String,
+    source_watermarks: Vec<SourceWatermark>,
+    columns: &[ProstColumnCatalog],
+) -> Result<Option<WatermarkDesc>> {
+    let watermark = match source_watermarks.len() {
+        0 => return Ok(None),
+        1 => source_watermarks.into_iter().next().unwrap(),
+        _ => {
+            return Err(ErrorCode::NotImplemented(
+                "more than one watermark in a source".to_string(),
+                None.into(),
+            )
+            .into())
+        }
+    };
+
+    let column_desc = columns
+        .iter()
+        .map(|column| column.get_column_desc())
+        .find_map(|desc| desc.ok().filter(|desc| desc.name == watermark.column.value))
+        .ok_or_else(|| {
+            ErrorCode::ItemNotFound(format!("Invalid column: {}", watermark.column.value))
+        })?;
+    let data_type = DataType::from(column_desc.get_column_type()?);
+
+    let mut binder = Binder::new(
+        session.env().catalog_reader().read_guard(),
+        session.database().to_string(),
+    );
+    let expr = binder.bind_source_watermark(
+        source_name,
+        watermark.column.value,
+        data_type.clone(),
+        watermark.expr,
+    )?;
+    // The rows behind the watermark are dropped by comparing the watermark with the column.
+    if expr.return_type() != data_type {
+        return Err(ErrorCode::BindError(format!(
+            "the watermark expression should return {:?}, but got {:?}",
+            data_type,
+            expr.return_type()
+        ))
+        .into());
+    }
+
+    Ok(Some(WatermarkDesc {
+        column_id: column_desc.column_id,
+        expr: Some(expr.to_protobuf()),
+    }))
+}
+
 /// Map a protobuf schema to a relational schema.
 fn extract_protobuf_table_schema(schema: &ProtobufSchema) -> Result<Vec<ProstColumnCatalog>> {
     let parser = ProtobufParser::new(&schema.row_schema_location.0, &schema.message_name.0)?;
@@ -64,15 +122,19 @@ fn extract_protobuf_table_schema(schema: &ProtobufSchema) -> Result<Vec<ProstCol
         .collect_vec())
 }
 
-pub(super) async fn handle_create_source(
+pub async fn handle_create_source(
     context: OptimizerContext,
     is_materialized: bool,
     stmt: CreateSourceStatement,
 ) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+    let source_name = stmt.source_name.to_string();
     let source = match &stmt.source_schema {
         SourceSchema::Protobuf(protobuf_schema) => {
             let mut columns = vec![ColumnCatalog::row_id_column().to_protobuf()];
             columns.extend(extract_protobuf_table_schema(protobuf_schema)?.into_iter());
+            let watermark_desc =
+                bind_source_watermark(&session, source_name, stmt.source_watermarks, &columns)?;
             StreamSourceInfo {
                 properties: HashMap::from(stmt.with_properties),
                 row_format: RowFormatType::Protobuf as i32,
@@ -80,19 +142,25 @@ pub(super) async fn handle_create_source(
                 row_id_index: 0,
                 columns,
                 pk_column_ids: vec![0],
+                watermark_desc,
+            }
+        }
+        SourceSchema::Json => {
+            let columns = bind_sql_columns(stmt.columns)?;
+            let watermark_desc =
+                bind_source_watermark(&session, source_name, stmt.source_watermarks, &columns)?;
+            StreamSourceInfo {
+                properties: HashMap::from(stmt.with_properties),
+                row_format: RowFormatType::Json as i32,
+                row_schema_location: "".to_string(),
+                row_id_index: 0,
+                columns,
+                pk_column_ids: vec![0],
+                watermark_desc,
             }
         }
-        SourceSchema::Json => StreamSourceInfo {
-            properties: HashMap::from(stmt.with_properties),
-            row_format: RowFormatType::Json as i32,
-            row_schema_location: "".to_string(),
-            row_id_index: 0,
-            columns: bind_sql_columns(stmt.columns)?,
-            pk_column_ids: vec![0],
-        },
     };
 
-    let session = context.session_ctx.clone();
     let source = make_prost_source(&session, stmt.source_name, Info::StreamSource(source))?;
     let catalog_writer = session.env().catalog_writer();
     if is_materialized {
@@ -190,9 +258,10 @@ pub mod tests {
             .map(|col| (col.name.as_str(), col.data_type.clone()))
             .collect::<HashMap<&str, DataType>>();
 
-        let city_type = DataType::Struct {
-            fields: vec![DataType::Varchar, DataType::Varchar].into(),
-        };
+        let city_type = DataType::new_struct(
+            vec![DataType::Varchar, DataType::Varchar],
+            vec!["address".to_string(), "zipcode".to_string()],
+        );
         let row_id_col_name = gen_row_id_column_name(0);
         let expected_columns = maplit::hashmap! {
             row_id_col_name.as_str() => DataType::Int64,
@@ -204,8 +273,29 @@ pub mod tests {
             "country.city" => city_type.clone(),
             "country.city.zipcode" => DataType::Varchar,
             "rate" => DataType::Float32,
-            "country" => DataType::Struct {fields:vec![DataType::Varchar,city_type,DataType::Varchar].into()},
+            "country" => DataType::new_struct(
+                vec![DataType::Varchar, city_type, DataType::Varchar],
+                vec!["address".to_string(), "city".to_string(), "zipcode".to_string()],
+            ),
         };
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_source_with_watermark() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let sql = r#"CREATE SOURCE t (v INT, ts TIMESTAMP, WATERMARK FOR ts AS ts - INTERVAL '5' SECOND)
+    WITH ('kafka.topic' = 'abc', 'kafka.servers' = 'localhost:1001') ROW FORMAT JSON"#;
+        frontend.run_sql(sql).await.unwrap();
+
+        // The watermark column must exist.
+        let sql = r#"CREATE SOURCE t2 (v INT, WATERMARK FOR ts AS ts)
+    WITH ('kafka.topic' = 'abc', 'kafka.servers' = 'localhost:1001') ROW FORMAT JSON"#;
+        assert!(frontend.run_sql(sql).await.is_err());
+
+        // The watermark must be of the same type as the column.
+        let sql = r#"CREATE SOURCE t3 (v INT, ts TIMESTAMP, WATERMARK FOR ts AS v)
+    WITH ('kafka.topic' = 'abc', 'kafka.servers' = 'localhost:1001') ROW FORMAT JSON"#;
+        assert!(frontend.run_sql(sql).await.is_err());
+    }
 }
@@ -0,0 +1,127 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::Field;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::catalog::View as ProstView;
+use risingwave_sqlparser::ast::{Ident, ObjectName, Query};
+
+use crate::binder::Binder;
+use crate::session::OptimizerContext;
+
+pub async fn handle_create_view(
+    context: OptimizerContext,
+    name: ObjectName,
+    columns: Vec<Ident>,
+    query: Box<Query>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, view_name) = Binder::resolve_table_name(name)?;
+    let (database_id, schema_id) = session
+        .env()
+        .catalog_reader()
+        .read_guard()
+        .check_relation_name_duplicated(session.database(), &schema_name, &view_name)?;
+
+    // Bind the query to check that it is valid, and to get its schema and dependencies.
+    let sql = query.to_string();
+    let (bound, dependent_relations) = {
+        let mut binder = Binder::new(
+            session.env().catalog_reader().read_guard(),
+            session.database().to_string(),
+        );
+        let bound = binder.bind_query(*query)?;
+        let dependent_relations = binder.included_relations().iter().copied().collect_vec();
+        (bound, dependent_relations)
+    };
+
+    let names = if columns.is_empty() {
+        bound.names()
+    } else if columns.len() == bound.names().len() {
+        columns.into_iter().map(|column| column.value).collect()
+    } else {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "view \"{}\" has {} columns but {} column names are specified",
+            view_name,
+            bound.names().len(),
+            columns.len()
+        ))
+        .into());
+    };
+    if let Some(name) = names.iter().duplicates().next() {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "column \"{}\" specified more than once",
+            name
+        ))
+        .into());
+    }
+    let columns = names
+        .into_iter()
+        .zip_eq(bound.data_types())
+        .map(|(name, data_type)| Field::with_name(data_type, name).to_prost())
+        .collect();
+
+    let view = ProstView {
+        id: 0,
+        schema_id,
+        database_id,
+        name: view_name,
+        sql,
+        columns,
+        dependent_relations,
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_view(view).await?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_VIEW))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+    use risingwave_common::types::DataType;
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_create_view_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 varchar)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create view v (a, b) as select v1 + 1, v2 from t")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        let view = reader
+            .get_view_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "v")
+            .unwrap();
+        assert_eq!(view.sql, "SELECT v1 + 1, v2 FROM t");
+        assert_eq!(
+            view.columns
+                .iter()
+                .map(|field| (field.name.as_str(), field.data_type.clone()))
+                .collect::<Vec<_>>(),
+            vec![("a", DataType::Int32), ("b", DataType::Varchar)]
+        );
+    }
+}
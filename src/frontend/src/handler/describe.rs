@@ -35,7 +35,8 @@ pub fn col_descs_to_rows(columns: Vec<ColumnDesc>) -> Vec<Row> {
                 .map(|c| {
                     let type_name = {
                         // If datatype is struct, use type name as struct name
-                        if let DataType::Struct { fields: _f } = c.data_type {
+                        if matches!(c.data_type, DataType::Struct { .. }) && !c.type_name.is_empty()
+                        {
                             c.type_name.clone()
                         } else {
                             format!("{:?}", &c.data_type)
@@ -0,0 +1,65 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::Result;
+use risingwave_sqlparser::ast::ObjectName;
+
+use crate::binder::Binder;
+use crate::session::OptimizerContext;
+
+pub async fn handle_drop_view(
+    context: OptimizerContext,
+    view_name: ObjectName,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, view_name) = Binder::resolve_table_name(view_name)?;
+
+    let view_id = session
+        .env()
+        .catalog_reader()
+        .read_guard()
+        .get_view_by_name(session.database(), &schema_name, &view_name)?
+        .id;
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.drop_view(view_id).await?;
+
+    Ok(PgResponse::empty_result(StatementType::DROP_VIEW))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_drop_view_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql("create table t (v1 int)").await.unwrap();
+        frontend
+            .run_sql("create view v as select v1 from t")
+            .await
+            .unwrap();
+        frontend.run_sql("drop view v").await.unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+        assert!(catalog_reader
+            .read_guard()
+            .get_view_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "v")
+            .is_err());
+    }
+}
@@ -19,6 +19,7 @@ use risingwave_common::error::Result;
 use risingwave_sqlparser::ast::Statement;
 
 use super::create_mv::gen_create_mv_plan;
+use super::create_sink::gen_sink_plan;
 use super::create_table::gen_create_table_plan;
 use crate::binder::Binder;
 use crate::planner::Planner;
@@ -46,6 +47,8 @@ pub(super) fn handle_explain(
             gen_create_table_plan(&*session, planner.ctx(), name, columns)?.0
         }
 
+        Statement::CreateSink { stmt } => gen_sink_plan(&*session, planner.ctx(), stmt)?.0,
+
         stmt => {
             let bound = {
                 let mut binder = Binder::new(
@@ -55,7 +58,7 @@ pub(super) fn handle_explain(
                 binder.bind(stmt)?
             };
             let logical = planner.plan(bound)?;
-            logical.gen_batch_query_plan()
+            logical.gen_batch_query_plan()?
         }
     };
 
@@ -21,17 +21,21 @@ use risingwave_sqlparser::ast::{DropStatement, ObjectName, ObjectType, Statement
 use crate::session::{OptimizerContext, SessionImpl};
 
 pub mod create_mv;
-mod create_source;
+pub mod create_sink;
+pub mod create_source;
 pub mod create_table;
+pub mod create_view;
 mod describe;
 pub mod drop_mv;
+pub mod drop_sink;
 pub mod drop_table;
+pub mod drop_view;
 mod explain;
 mod flush;
 #[allow(dead_code)]
 pub mod query;
 pub mod query_single;
-mod set;
+pub mod set;
 mod show;
 pub mod util;
 
@@ -61,6 +65,8 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             match object_type {
                 ObjectType::Table => drop_table::handle_drop_table(context, name).await,
                 ObjectType::MaterializedView => drop_mv::handle_drop_mv(context, name).await,
+                ObjectType::View => drop_view::handle_drop_view(context, name).await,
+                ObjectType::Sink => drop_sink::handle_drop_sink(context, name).await,
                 ObjectType::MaterializedSource => {
                     // FIXME: We currently treat MATERIALIZE SOURCE as an alias TABLE, while
                     // this assumption is not correct. DROP MATERIALIZE SOURCE should only drops
@@ -81,7 +87,7 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
                 query_single::handle_query_single(context, stmt).await
             }
         }
-        Statement::Insert { .. } | Statement::Delete { .. } => {
+        Statement::Insert { .. } | Statement::Delete { .. } | Statement::Update { .. } => {
             query_single::handle_query_single(context, stmt).await
         }
         Statement::CreateView {
@@ -91,6 +97,22 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             query,
             ..
         } => create_mv::handle_create_mv(context, name, query).await,
+        Statement::CreateView {
+            materialized: true,
+            or_replace: true,
+            name,
+            query,
+            ..
+        } => create_mv::handle_create_or_replace_mv(context, name, query).await,
+        Statement::CreateView {
+            materialized: false,
+            or_replace: false,
+            name,
+            columns,
+            query,
+            ..
+        } => create_view::handle_create_view(context, name, columns, query).await,
+        Statement::CreateSink { stmt } => create_sink::handle_create_sink(context, stmt).await,
         Statement::Flush => flush::handle_flush(context).await,
         Statement::SetVariable {
             local: _,
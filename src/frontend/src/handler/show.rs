@@ -18,6 +18,7 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
 use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
 use risingwave_common::error::Result;
+use risingwave_expr::sig::func::FUNC_SIG_MAP;
 use risingwave_sqlparser::ast::ShowCommandObject;
 
 use crate::session::OptimizerContext;
@@ -46,6 +47,19 @@ pub async fn handle_show_command(
         ShowCommandObject::MaterializedView(None) => {
             catalog_reader.get_all_mv_names(session.database(), DEFAULT_SCHEMA_NAME)?
         }
+        ShowCommandObject::View(Some(ident)) => {
+            catalog_reader.get_all_view_names(session.database(), &ident.value)?
+        }
+        ShowCommandObject::View(None) => {
+            catalog_reader.get_all_view_names(session.database(), DEFAULT_SCHEMA_NAME)?
+        }
+        ShowCommandObject::Sink(Some(ident)) => {
+            catalog_reader.get_all_sink_names(session.database(), &ident.value)?
+        }
+        ShowCommandObject::Sink(None) => {
+            catalog_reader.get_all_sink_names(session.database(), DEFAULT_SCHEMA_NAME)?
+        }
+        ShowCommandObject::Function => return Ok(show_functions()),
     };
 
     let rows = names
@@ -60,3 +74,33 @@ pub async fn handle_show_command(
         vec![PgFieldDescriptor::new("name".to_owned(), TypeOid::Varchar)],
     ))
 }
+
+/// Lists the signatures of the functions that can be called by name.
+fn show_functions() -> PgResponse {
+    let rows = FUNC_SIG_MAP
+        .iter_named()
+        .map(|(name, sig)| {
+            Row::new(vec![
+                Some(name.to_owned()),
+                Some(
+                    sig.inputs_type
+                        .iter()
+                        .map(|t| format!("{:?}", t))
+                        .join(", "),
+                ),
+                Some(format!("{:?}", sig.ret_type)),
+            ])
+        })
+        .collect_vec();
+
+    PgResponse::new(
+        StatementType::SHOW_COMMAND,
+        rows.len() as i32,
+        rows,
+        vec![
+            PgFieldDescriptor::new("name".to_owned(), TypeOid::Varchar),
+            PgFieldDescriptor::new("arguments".to_owned(), TypeOid::Varchar),
+            PgFieldDescriptor::new("return type".to_owned(), TypeOid::Varchar),
+        ],
+    )
+}
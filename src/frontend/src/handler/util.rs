@@ -80,6 +80,9 @@ pub fn data_type_to_type_oid(data_type: DataType) -> TypeOid {
         DataType::Interval => TypeOid::Varchar,
         DataType::Struct { .. } => TypeOid::Varchar,
         DataType::List { .. } => TypeOid::Varchar,
+        DataType::Jsonb => TypeOid::Jsonb,
+        DataType::Bytea => TypeOid::Bytea,
+        DataType::Uuid => TypeOid::Uuid,
     }
 }
 
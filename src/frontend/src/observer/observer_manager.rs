@@ -82,6 +82,12 @@ impl ObserverManager {
                 for source in snapshot.source {
                     catalog_guard.create_source(source)
                 }
+                for view in snapshot.view {
+                    catalog_guard.create_view(&view)
+                }
+                for sink in snapshot.sink {
+                    catalog_guard.create_sink(&sink)
+                }
                 self.worker_node_manager.refresh_worker_node(snapshot.nodes);
             }
             _ => {
@@ -136,6 +142,7 @@ impl ObserverManager {
                 Operation::Delete => {
                     catalog_guard.drop_table(table.database_id, table.schema_id, table.id.into())
                 }
+                Operation::Update => catalog_guard.replace_table(table),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
             Some(Info::Source(source)) => match resp.operation() {
@@ -145,6 +152,20 @@ impl ObserverManager {
                 }
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
+            Some(Info::View(view)) => match resp.operation() {
+                Operation::Add => catalog_guard.create_view(view),
+                Operation::Delete => {
+                    catalog_guard.drop_view(view.database_id, view.schema_id, view.id)
+                }
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
+            Some(Info::Sink(sink)) => match resp.operation() {
+                Operation::Add => catalog_guard.create_sink(sink),
+                Operation::Delete => {
+                    catalog_guard.drop_sink(sink.database_id, sink.schema_id, sink.id)
+                }
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
             Some(Info::FeSnapshot(_)) => {
                 panic!(
                     "receiving an FeSnapshot in the middle is unsupported now {:?}",
@@ -21,17 +21,26 @@ mod plan_rewriter;
 mod plan_visitor;
 mod rule;
 
+use std::collections::HashMap;
+
 use fixedbitset::FixedBitSet;
 use itertools::Itertools as _;
 use property::{Distribution, Order};
 use risingwave_common::catalog::Schema;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 
 use self::heuristic::{ApplyOrder, HeuristicOptimizer};
-use self::plan_node::{Convention, LogicalProject, StreamMaterialize};
+use self::plan_node::{
+    BatchProject, Convention, LogicalProject, LogicalTopN, StreamMaterialize, StreamSink,
+};
 use self::rule::*;
 use crate::expr::InputRef;
 
+/// If `RW_STREAMING_ENABLE_DELTA_JOIN` is on and there is a single compute node, inner joins of
+/// materialized views arranged by the join key will be planned as delta joins, see
+/// [`DeltaJoinRule`].
+pub static ENABLE_DELTA_JOIN: &str = "RW_STREAMING_ENABLE_DELTA_JOIN";
+
 /// `PlanRoot` is used to describe a plan. planner will construct a `PlanRoot` with `LogicalNode`.
 /// and required distribution and order. And `PlanRoot` can generate corresponding streaming or
 /// batch plan with optimization. the required Order and Distribution columns might be more than the
@@ -92,10 +101,21 @@ impl PlanRoot {
 
     /// Transform the [`PlanRoot`] back to a [`PlanRef`] suitable to be used as a subplan, for
     /// example as insert source or subquery. This ignores Order but retains post-Order pruning
-    /// (`out_fields`).
+    /// (`out_fields`). An Order followed by a limit is kept by turning the limit into a
+    /// [`LogicalTopN`].
     pub fn as_subplan(self) -> PlanRef {
+        let plan = match self.plan.as_logical_limit() {
+            Some(limit) if !self.required_order.field_order.is_empty() => LogicalTopN::create(
+                limit.input(),
+                limit.limit(),
+                limit.offset(),
+                self.required_order.clone(),
+                vec![],
+            ),
+            _ => self.plan,
+        };
         if self.out_fields.count_ones(..) == self.out_fields.len() {
-            return self.plan;
+            return plan;
         }
         let (exprs, expr_aliases) = self
             .out_fields
@@ -108,26 +128,49 @@ impl PlanRoot {
                 )
             })
             .unzip();
-        LogicalProject::create(self.plan, exprs, expr_aliases)
+        LogicalProject::create(plan, exprs, expr_aliases)
     }
 
     /// Apply logical optimization to the plan.
-    pub fn gen_optimized_logical_plan(&self) -> PlanRef {
+    pub fn gen_optimized_logical_plan(&self) -> Result<PlanRef> {
         let mut plan = self.plan.clone();
 
+        // Subquery Unnesting
+        plan = {
+            let rules = vec![ApplyToJoinRule::create()];
+            let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::BottomUp, rules);
+            heuristic_optimizer.optimize(plan)
+        };
+        if has_logical_apply(&plan) {
+            return Err(ErrorCode::NotImplemented(
+                "correlated subquery that can not be unnested".to_string(),
+                1343.into(),
+            )
+            .into());
+        }
+
         // Predicate Push-down
         plan = {
             let rules = vec![
                 FilterJoinRule::create(),
                 FilterProjectRule::create(),
                 FilterAggRule::create(),
+                OverAggToTopNRule::create(),
             ];
             let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::TopDown, rules);
             heuristic_optimizer.optimize(plan)
         };
 
-        // Prune Columns
-        plan = plan.prune_col(&self.out_fields);
+        // Prune Columns. The columns required by the order are kept even if they are not output,
+        // and will be pruned after the order is enforced.
+        let mut required_cols = self.out_fields.clone();
+        required_cols.extend(
+            self.required_order
+                .field_order
+                .iter()
+                .map(|field_order| field_order.index),
+        );
+        plan = plan.prune_col(&required_cols);
 
         plan = {
             let rules = vec![
@@ -138,32 +181,60 @@ impl PlanRoot {
             heuristic_optimizer.optimize(plan)
         };
 
-        plan
+        Ok(plan)
     }
 
     /// optimize and generate a batch query plan
-    pub fn gen_batch_query_plan(&self) -> PlanRef {
-        let mut plan = self.gen_optimized_logical_plan();
+    pub fn gen_batch_query_plan(&self) -> Result<PlanRef> {
+        let plan = self.gen_batch_query_plan_with_order_cols()?;
+
+        Ok(self.prune_order_cols(plan))
+    }
+
+    /// Optimize and generate a batch query plan.
+    /// Currently only used by test runner (Have distributed plan but not schedule yet).
+    /// Will be removed after dist execution.
+    pub fn gen_dist_batch_query_plan(&self) -> Result<PlanRef> {
+        let plan = self.gen_batch_query_plan_with_order_cols()?;
+        let plan = plan.to_distributed_with_required(&self.required_order, &self.required_dist);
+
+        Ok(self.prune_order_cols(plan))
+    }
+
+    /// Generate a batch query plan which is in the required order, but may still output the
+    /// columns only used by the order.
+    fn gen_batch_query_plan_with_order_cols(&self) -> Result<PlanRef> {
+        let mut plan = self.gen_optimized_logical_plan()?;
 
         // Convert to physical plan node
         plan = plan.to_batch_with_order_required(&self.required_order);
 
         // TODO: Enable this when distributed e2e is OK.
         // plan = plan.to_distributed_with_required(&self.required_order, &self.required_dist);
-        // FIXME: add a Batch Project for the Plan, to remove the unnecessary column in the result.
         // TODO: do a final column pruning after add the batch project, but now the column
         // pruning is not used in batch node, need to think.
 
-        plan
+        Ok(plan)
     }
 
-    /// Optimize and generate a batch query plan.
-    /// Currently only used by test runner (Have distributed plan but not schedule yet).
-    /// Will be removed after dist execution.
-    pub fn gen_dist_batch_query_plan(&self) -> PlanRef {
-        let plan = self.gen_batch_query_plan();
-
-        plan.to_distributed_with_required(&self.required_order, &self.required_dist)
+    /// Add a [`BatchProject`] on top of the sorted `plan` to remove the columns not in
+    /// `out_fields`, if any.
+    fn prune_order_cols(&self, plan: PlanRef) -> PlanRef {
+        if self.out_fields.count_ones(..) == self.out_fields.len() {
+            return plan;
+        }
+        let (exprs, expr_aliases) = self
+            .out_fields
+            .ones()
+            .zip_eq(self.schema.fields())
+            .map(|(index, field)| {
+                (
+                    InputRef::new(index, field.data_type()).into(),
+                    Some(field.name.clone()),
+                )
+            })
+            .unzip();
+        BatchProject::new(LogicalProject::new(plan, exprs, expr_aliases)).into()
     }
 
     /// Optimize and generate a create materialize view plan.
@@ -171,10 +242,37 @@ impl PlanRoot {
     /// The `MaterializeExecutor` won't be generated at this stage, and will be attached in
     /// `gen_create_mv_plan`.
     pub fn gen_create_mv_plan(&mut self, mv_name: String) -> Result<StreamMaterialize> {
+        let stream_plan = self.gen_stream_plan()?;
+
+        // Ignore the required_dist and required_order, as they are provided by user now.
+        // TODO: need more thinking and refactor.
+
+        // Convert to physical plan node, using distribution of the input node
+        // After that, we will need to wrap a `MaterializeExecutor` on it in `gen_create_mv_plan`.
+
+        StreamMaterialize::create(
+            stream_plan,
+            mv_name,
+            self.required_order.clone(),
+            self.out_fields.clone(),
+        )
+    }
+
+    /// Generate the stream plan delivering the changes of the query to the sink described by
+    /// `properties`.
+    pub fn gen_create_sink_plan(
+        &mut self,
+        properties: HashMap<String, String>,
+    ) -> Result<StreamSink> {
+        let stream_plan = self.gen_stream_plan()?;
+        Ok(StreamSink::new(stream_plan, properties))
+    }
+
+    fn gen_stream_plan(&mut self) -> Result<PlanRef> {
         let stream_plan = match self.plan.convention() {
             Convention::Logical => {
-                let plan = self.gen_optimized_logical_plan();
-                let (plan, out_col_change) = plan.logical_rewrite_for_stream();
+                let plan = self.gen_optimized_logical_plan()?;
+                let (plan, out_col_change) = plan.logical_rewrite_for_stream()?;
                 self.required_dist = out_col_change
                     .rewrite_required_distribution(&self.required_dist)
                     .unwrap();
@@ -183,26 +281,30 @@ impl PlanRoot {
                     .unwrap();
                 self.out_fields = out_col_change.rewrite_bitset(&self.out_fields);
                 self.schema = plan.schema().clone();
-                plan.to_stream_with_dist_required(&self.required_dist)
+                let plan = plan.to_stream_with_dist_required(&self.required_dist)?;
+
+                let session_ctx = plan.ctx().inner().session_ctx.clone();
+                let delta_join = session_ctx.get_config(ENABLE_DELTA_JOIN);
+                // The lookups of a delta join read the local storage of the materialized views,
+                // which is only guaranteed to be there when all of the fragments are placed on the
+                // same compute node.
+                let colocated = session_ctx.env().worker_node_manager().worker_node_count() <= 1;
+                match delta_join {
+                    Some(flag) if flag.is_set(false) && colocated => {
+                        let rules = vec![DeltaJoinRule::create()];
+                        let heuristic_optimizer =
+                            HeuristicOptimizer::new(ApplyOrder::BottomUp, rules);
+                        heuristic_optimizer.optimize(plan)
+                    }
+                    _ => plan,
+                }
             }
             Convention::Stream => self
                 .required_dist
                 .enforce_if_not_satisfies(self.plan.clone(), Order::any()),
             _ => panic!(),
         };
-
-        // Ignore the required_dist and required_order, as they are provided by user now.
-        // TODO: need more thinking and refactor.
-
-        // Convert to physical plan node, using distribution of the input node
-        // After that, we will need to wrap a `MaterializeExecutor` on it in `gen_create_mv_plan`.
-
-        StreamMaterialize::create(
-            stream_plan,
-            mv_name,
-            self.required_order.clone(),
-            self.out_fields.clone(),
-        )
+        Ok(stream_plan)
     }
 
     /// Set the plan root's required dist.
@@ -211,6 +313,10 @@ impl PlanRoot {
     }
 }
 
+fn has_logical_apply(plan: &PlanRef) -> bool {
+    plan.as_logical_apply().is_some() || plan.inputs().iter().any(has_logical_apply)
+}
+
 #[cfg(test)]
 mod tests {
 
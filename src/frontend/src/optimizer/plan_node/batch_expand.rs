@@ -0,0 +1,84 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_pb::plan::expand_node::Subset;
+use risingwave_pb::plan::plan_node::NodeBody;
+use risingwave_pb::plan::ExpandNode;
+
+use super::{
+    LogicalExpand, PlanBase, PlanRef, PlanTreeNodeUnary, ToBatchProst, ToDistributedBatch,
+};
+use crate::optimizer::property::Order;
+
+/// `BatchExpand` implements [`super::LogicalExpand`] to expand every row once for each column
+/// subset.
+#[derive(Debug, Clone)]
+pub struct BatchExpand {
+    pub base: PlanBase,
+    logical: LogicalExpand,
+}
+
+impl BatchExpand {
+    pub fn new(logical: LogicalExpand) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let dist = logical
+            .i2o_col_mapping()
+            .rewrite_provided_distribution(logical.input().distribution());
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
+        BatchExpand { base, logical }
+    }
+}
+
+impl fmt::Display for BatchExpand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "BatchExpand")
+    }
+}
+
+impl PlanTreeNodeUnary for BatchExpand {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { BatchExpand }
+
+impl ToDistributedBatch for BatchExpand {
+    fn to_distributed(&self) -> PlanRef {
+        let new_input = self.input().to_distributed();
+        self.clone_with_input(new_input).into()
+    }
+}
+
+impl ToBatchProst for BatchExpand {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::Expand(ExpandNode {
+            column_subsets: self
+                .logical
+                .column_subsets()
+                .iter()
+                .map(|subset| Subset {
+                    column_indices: subset.iter().map(|i| *i as u32).collect_vec(),
+                })
+                .collect(),
+        })
+    }
+}
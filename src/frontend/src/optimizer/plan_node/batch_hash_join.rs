@@ -21,6 +21,7 @@ use super::{
     EqJoinPredicate, LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, ToBatchProst,
     ToDistributedBatch,
 };
+use crate::expr::Expr;
 use crate::optimizer::property::{Distribution, Order};
 use crate::utils::ColIndexMapping;
 
@@ -140,7 +141,12 @@ impl ToBatchProst for BatchHashJoin {
                 .into_iter()
                 .map(|a| a as i32)
                 .collect(),
-            condition: None,
+            null_safe: self.eq_join_predicate.null_safes(),
+            condition: self
+                .eq_join_predicate
+                .other_cond()
+                .as_expr_unless_true()
+                .map(|x| x.to_protobuf()),
         })
     }
 }
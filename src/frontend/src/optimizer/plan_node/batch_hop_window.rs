@@ -0,0 +1,81 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::plan::plan_node::NodeBody;
+use risingwave_pb::plan::HopWindowNode;
+
+use super::{
+    LogicalHopWindow, PlanBase, PlanRef, PlanTreeNodeUnary, ToBatchProst, ToDistributedBatch,
+};
+use crate::expr::column_idx_to_inputref_proto;
+use crate::optimizer::property::Order;
+
+/// `BatchHopWindow` implements [`super::LogicalHopWindow`] to evaluate specific expressions.
+#[derive(Debug, Clone)]
+pub struct BatchHopWindow {
+    pub base: PlanBase,
+    logical: LogicalHopWindow,
+}
+
+impl BatchHopWindow {
+    pub fn new(logical: LogicalHopWindow) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let base = PlanBase::new_batch(
+            ctx,
+            logical.schema().clone(),
+            logical.input().distribution().clone(),
+            Order::any().clone(),
+        );
+        BatchHopWindow { base, logical }
+    }
+}
+
+impl fmt::Display for BatchHopWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "BatchHopWindow")
+    }
+}
+
+impl PlanTreeNodeUnary for BatchHopWindow {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { BatchHopWindow }
+
+impl ToDistributedBatch for BatchHopWindow {
+    fn to_distributed(&self) -> PlanRef {
+        let new_input = self.input().to_distributed();
+        self.clone_with_input(new_input).into()
+    }
+}
+
+impl ToBatchProst for BatchHopWindow {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::HopWindow(HopWindowNode {
+            time_col: Some(column_idx_to_inputref_proto(
+                self.logical.time_col().index(),
+            )),
+            window_slide: Some(self.logical.window_slide().into()),
+            window_size: Some(self.logical.window_size().into()),
+        })
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::plan::plan_node::NodeBody;
+use risingwave_pb::plan::NestedLoopJoinNode;
+
+use super::{LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, ToBatchProst, ToDistributedBatch};
+use crate::expr::Expr;
+use crate::optimizer::property::{Distribution, Order};
+
+/// `BatchNestedLoopJoin` implements [`super::LogicalJoin`] by checking the join condition
+/// against all pairs of rows from inner & outer side within 2 layers of loops.
+#[derive(Debug, Clone)]
+pub struct BatchNestedLoopJoin {
+    pub base: PlanBase,
+    logical: LogicalJoin,
+}
+
+impl BatchNestedLoopJoin {
+    pub fn new(logical: LogicalJoin) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let dist = match (
+            logical.left().distribution(),
+            logical.right().distribution(),
+        ) {
+            (Distribution::Single, Distribution::Single) => Distribution::Single,
+            _ => Distribution::any().clone(),
+        };
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
+
+        Self { base, logical }
+    }
+}
+
+impl fmt::Display for BatchNestedLoopJoin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BatchNestedLoopJoin {{ type: {:?}, predicate: {} }}",
+            self.logical.join_type(),
+            self.logical.on()
+        )
+    }
+}
+
+impl PlanTreeNodeBinary for BatchNestedLoopJoin {
+    fn left(&self) -> PlanRef {
+        self.logical.left()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_left_right(left, right))
+    }
+}
+
+impl_plan_tree_node_for_binary! { BatchNestedLoopJoin }
+
+impl ToDistributedBatch for BatchNestedLoopJoin {
+    fn to_distributed(&self) -> PlanRef {
+        // Without equal keys, every row of one side has to be checked against all the rows of the
+        // other side, so gather both sides to a single node.
+        let left = self
+            .left()
+            .to_distributed_with_required(Order::any(), &Distribution::Single);
+        let right = self
+            .right()
+            .to_distributed_with_required(Order::any(), &Distribution::Single);
+
+        self.clone_with_left_right(left, right).into()
+    }
+}
+
+impl ToBatchProst for BatchNestedLoopJoin {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::NestedLoopJoin(NestedLoopJoinNode {
+            join_type: self.logical.join_type() as i32,
+            join_cond: Some(self.logical.on().as_expr().to_protobuf()),
+        })
+    }
+}
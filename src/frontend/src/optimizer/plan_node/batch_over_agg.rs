@@ -0,0 +1,95 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::plan::plan_node::NodeBody;
+use risingwave_pb::plan::OverAggNode;
+
+use super::{
+    LogicalOverAgg, PlanBase, PlanRef, PlanTreeNodeUnary, PlanWindowFunction, ToBatchProst,
+    ToDistributedBatch,
+};
+
+/// `BatchOverAgg` implements [`super::LogicalOverAgg`] on an input sorted by the partition keys
+/// and then the order keys.
+#[derive(Debug, Clone)]
+pub struct BatchOverAgg {
+    pub base: PlanBase,
+    logical: LogicalOverAgg,
+}
+
+impl BatchOverAgg {
+    pub fn new(logical: LogicalOverAgg) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let input = logical.input();
+        assert!(input.order().satisfies(&logical.required_input_order()));
+        // The input columns are output as is, so are their order and distribution.
+        let base = PlanBase::new_batch(
+            ctx,
+            logical.schema().clone(),
+            input.distribution().clone(),
+            input.order().clone(),
+        );
+        BatchOverAgg { base, logical }
+    }
+}
+
+impl fmt::Display for BatchOverAgg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "BatchOverAgg")
+    }
+}
+
+impl PlanTreeNodeUnary for BatchOverAgg {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { BatchOverAgg }
+
+impl ToDistributedBatch for BatchOverAgg {
+    fn to_distributed(&self) -> PlanRef {
+        let new_input = self.input().to_distributed_with_required(
+            &self.logical.required_input_order(),
+            &self.logical.required_input_distribution(),
+        );
+        self.clone_with_input(new_input).into()
+    }
+}
+
+impl ToBatchProst for BatchOverAgg {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::OverAgg(OverAggNode {
+            partition_by: self
+                .logical
+                .partition_by()
+                .iter()
+                .map(|i| *i as u32)
+                .collect(),
+            order_by: self.logical.order_by_to_protobuf(),
+            window_functions: self
+                .logical
+                .window_functions()
+                .iter()
+                .map(PlanWindowFunction::to_protobuf)
+                .collect(),
+        })
+    }
+}
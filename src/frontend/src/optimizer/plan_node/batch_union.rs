@@ -0,0 +1,81 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::plan::plan_node::NodeBody;
+use risingwave_pb::plan::UnionNode;
+use smallvec::SmallVec;
+
+use super::{LogicalUnion, PlanBase, PlanRef, PlanTreeNode, ToBatchProst, ToDistributedBatch};
+use crate::optimizer::property::{Distribution, Order};
+
+/// `BatchUnion` implements [`super::LogicalUnion`] by outputting the rows of its inputs one input
+/// after another.
+#[derive(Debug, Clone)]
+pub struct BatchUnion {
+    pub base: PlanBase,
+    logical: LogicalUnion,
+}
+
+impl BatchUnion {
+    pub fn new(logical: LogicalUnion) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let dist = if logical
+            .inputs()
+            .iter()
+            .all(|input| *input.distribution() == Distribution::Single)
+        {
+            Distribution::Single
+        } else {
+            Distribution::any().clone()
+        };
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
+        BatchUnion { base, logical }
+    }
+}
+
+impl fmt::Display for BatchUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "BatchUnion")
+    }
+}
+
+impl PlanTreeNode for BatchUnion {
+    fn inputs(&self) -> SmallVec<[PlanRef; 2]> {
+        self.logical.inputs()
+    }
+
+    fn clone_with_inputs(&self, inputs: &[PlanRef]) -> PlanRef {
+        Self::new(LogicalUnion::new(inputs.to_vec())).into()
+    }
+}
+
+impl ToDistributedBatch for BatchUnion {
+    fn to_distributed(&self) -> PlanRef {
+        // The executor reads its inputs sequentially, so gather all of them to a single node.
+        let inputs = self
+            .inputs()
+            .iter()
+            .map(|input| input.to_distributed_with_required(Order::any(), &Distribution::Single))
+            .collect::<Vec<_>>();
+        self.clone_with_inputs(&inputs)
+    }
+}
+
+impl ToBatchProst for BatchUnion {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::Union(UnionNode {})
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use paste::paste;
+use risingwave_common::error::Result;
 
 use super::*;
 use crate::optimizer::property::{Distribution, Order};
@@ -37,15 +38,17 @@ pub trait ToStream {
     /// Now it is used to:
     /// 1. ensure every plan node's output having pk column
     /// 2. add `row_count`() in every Agg
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping);
+    ///
+    /// An error is returned if the plan can not be converted into streaming.
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)>;
 
     /// `to_stream` is equivalent to `to_stream_with_dist_required(Distribution::any())`
-    fn to_stream(&self) -> PlanRef;
+    fn to_stream(&self) -> Result<PlanRef>;
 
     /// convert the plan to streaming physical plan and satisfy the required distribution
-    fn to_stream_with_dist_required(&self, required_dist: &Distribution) -> PlanRef {
-        let ret = self.to_stream();
-        required_dist.enforce_if_not_satisfies(ret, Order::any())
+    fn to_stream_with_dist_required(&self, required_dist: &Distribution) -> Result<PlanRef> {
+        let ret = self.to_stream()?;
+        Ok(required_dist.enforce_if_not_satisfies(ret, Order::any()))
     }
 }
 
@@ -114,10 +117,10 @@ macro_rules! impl_to_stream {
     ([], $( { $convention:ident, $name:ident }),*) => {
         paste!{
             $(impl ToStream for [<$convention $name>] {
-                fn to_stream(&self) -> PlanRef {
+                fn to_stream(&self) -> Result<PlanRef> {
                     panic!("convert into stream is only allowed on logical plan")
                 }
-                fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping){
+                fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
                     panic!("convert into stream is only allowed on logical plan")
                 }
 
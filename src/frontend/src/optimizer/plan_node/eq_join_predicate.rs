@@ -25,8 +25,8 @@ pub struct EqJoinPredicate {
 
     /// The equal columns indexes(in the input schema) both sides,
     /// the first is from the left table and the second is from the right table.
-    /// now all are normal equal(not null-safe-equal),
-    eq_keys: Vec<(InputRef, InputRef)>,
+    /// The third one is whether the pair is null-safe-equal (`IS NOT DISTINCT FROM`).
+    eq_keys: Vec<(InputRef, InputRef, bool)>,
 
     left_cols_num: usize,
 }
@@ -34,11 +34,11 @@ pub struct EqJoinPredicate {
 impl fmt::Display for EqJoinPredicate {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         let mut eq_keys = self.eq_keys().iter();
-        if let Some((k1, k2)) = eq_keys.next() {
-            write!(f, "{} = {}", k1, k2)?;
+        if let Some((k1, k2, null_safe)) = eq_keys.next() {
+            write!(f, "{} {} {}", k1, eq_op(*null_safe), k2)?;
         }
-        for (k1, k2) in eq_keys {
-            write!(f, "AND {} = {}", k1, k2)?;
+        for (k1, k2, null_safe) in eq_keys {
+            write!(f, "AND {} {} {}", k1, eq_op(*null_safe), k2)?;
         }
         if !self.other_cond.always_true() {
             write!(f, "AND {}", self.other_cond)?;
@@ -48,11 +48,19 @@ impl fmt::Display for EqJoinPredicate {
     }
 }
 
+fn eq_op(null_safe: bool) -> &'static str {
+    if null_safe {
+        "IS NOT DISTINCT FROM"
+    } else {
+        "="
+    }
+}
+
 impl EqJoinPredicate {
     /// The new method for `JoinPredicate` without any analysis, check or rewrite.
     pub fn new(
         other_cond: Condition,
-        eq_keys: Vec<(InputRef, InputRef)>,
+        eq_keys: Vec<(InputRef, InputRef, bool)>,
         left_cols_num: usize,
     ) -> Self {
         Self {
@@ -89,8 +97,13 @@ impl EqJoinPredicate {
                 .eq_keys
                 .iter()
                 .cloned()
-                .map(|(l, r)| {
-                    FunctionCall::new(ExprType::Equal, vec![l.into(), r.into()])
+                .map(|(l, r, null_safe)| {
+                    let func_type = if null_safe {
+                        ExprType::IsNotDistinctFrom
+                    } else {
+                        ExprType::Equal
+                    };
+                    FunctionCall::new(func_type, vec![l.into(), r.into()])
                         .unwrap()
                         .into()
                 })
@@ -121,26 +134,42 @@ impl EqJoinPredicate {
     }
 
     /// Get a reference to the join predicate's eq keys.
-    pub fn eq_keys(&self) -> &[(InputRef, InputRef)] {
+    pub fn eq_keys(&self) -> &[(InputRef, InputRef, bool)] {
         self.eq_keys.as_ref()
     }
 
     pub fn eq_indexes(&self) -> Vec<(usize, usize)> {
         self.eq_keys
             .iter()
-            .map(|(left, right)| (left.index(), right.index() - self.left_cols_num))
+            .map(|(left, right, _)| (left.index(), right.index() - self.left_cols_num))
             .collect()
     }
 
     pub fn left_eq_indexes(&self) -> Vec<usize> {
-        self.eq_keys.iter().map(|(left, _)| left.index()).collect()
+        self.eq_keys
+            .iter()
+            .map(|(left, _, _)| left.index())
+            .collect()
     }
 
     /// return the eq keys column index **based on the right input schema**
     pub fn right_eq_indexes(&self) -> Vec<usize> {
         self.eq_keys
             .iter()
-            .map(|(_, right)| right.index() - self.left_cols_num)
+            .map(|(_, right, _)| right.index() - self.left_cols_num)
+            .collect()
+    }
+
+    /// Whether any pair of eq keys is null-safe-equal, which is only supported by hash joins.
+    pub fn has_null_safe_eq(&self) -> bool {
+        self.eq_keys.iter().any(|(_, _, null_safe)| *null_safe)
+    }
+
+    /// Whether each pair of eq keys is null-safe-equal.
+    pub fn null_safes(&self) -> Vec<bool> {
+        self.eq_keys
+            .iter()
+            .map(|(_, _, null_safe)| *null_safe)
             .collect()
     }
 }
@@ -19,7 +19,7 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_expr::expr::AggKind;
 use risingwave_pb::expr::AggCall as ProstAggCall;
 
@@ -27,8 +27,10 @@ use super::{
     BatchHashAgg, BatchSimpleAgg, ColPrunable, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
     StreamHashAgg, StreamSimpleAgg, ToBatch, ToStream,
 };
-use crate::expr::{AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef};
-use crate::optimizer::plan_node::LogicalProject;
+use crate::expr::{
+    AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal,
+};
+use crate::optimizer::plan_node::{LogicalExpand, LogicalProject};
 use crate::optimizer::property::Distribution;
 use crate::utils::ColIndexMapping;
 
@@ -43,10 +45,24 @@ pub struct PlanAggCall {
 
     /// Column indexes of input columns
     pub inputs: Vec<InputRef>,
+
+    /// Whether the inputs are deduplicated before being aggregated
+    pub distinct: bool,
 }
 
 impl fmt::Debug for PlanAggCall {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.distinct {
+            return write!(
+                f,
+                "{}(distinct {})",
+                self.agg_kind,
+                self.inputs
+                    .iter()
+                    .map(|input| format!("{:?}", input))
+                    .join(", ")
+            );
+        }
         let mut builder = f.debug_tuple(&format!("{}", self.agg_kind));
         self.inputs.iter().for_each(|child| {
             builder.field(child);
@@ -65,8 +81,7 @@ impl PlanAggCall {
                 .iter()
                 .map(InputRef::to_agg_arg_protobuf)
                 .collect(),
-            // TODO: support distinct
-            distinct: false,
+            distinct: self.distinct,
         }
     }
 
@@ -75,6 +90,7 @@ impl PlanAggCall {
             agg_kind: AggKind::Count,
             return_type: DataType::Int64,
             inputs: vec![],
+            distinct: false,
         }
     }
 }
@@ -101,6 +117,9 @@ struct ExprHandler {
     // followed by those inside aggregates (e.g. v1 + v2 for min(v1 + v2)).
     pub project: Vec<ExprImpl>,
     group_key_len: usize,
+    // The grouping sets, as indices of group exprs. Empty for a plain GROUP BY. Otherwise the id
+    // of the grouping set is an extra group column of the agg, right after the group exprs.
+    grouping_sets: Vec<Vec<usize>>,
     // When dedup (rewriting AggCall inputs), it is the index into projects.
     // When rewriting InputRef outside AggCall, where it is required to refer to a group column,
     // this is the index into LogicalAgg::schema.
@@ -112,36 +131,92 @@ struct ExprHandler {
 }
 
 impl ExprHandler {
-    fn new(group_exprs: Vec<ExprImpl>) -> Result<Self> {
-        // TODO: support more complicated expression in GROUP BY clause, because we currently
-        // assume the only thing can appear in GROUP BY clause is an input column name.
+    fn new(group_exprs: Vec<ExprImpl>, grouping_sets: Vec<Vec<usize>>) -> Result<Self> {
         let group_key_len = group_exprs.len();
 
         // Please note that we currently don't dedup columns in GROUP BY clause.
         let mut expr_index = HashMap::new();
-        group_exprs
-            .iter()
-            .enumerate()
-            .try_for_each(|(index, expr)| {
-                if matches!(expr, ExprImpl::InputRef(_)) {
-                    expr_index.insert(expr.clone(), index);
-                    Ok(())
-                } else {
-                    Err(ErrorCode::NotImplemented(
-                        "GROUP BY only supported on input column names!".into(),
-                        1637.into(),
-                    ))
-                }
-            })?;
+        for (index, expr) in group_exprs.iter().enumerate() {
+            if expr.has_subquery() {
+                return Err(
+                    ErrorCode::NotImplemented("subquery in GROUP BY".into(), 1343.into()).into(),
+                );
+            }
+            expr_index.entry(expr.clone()).or_insert(index);
+        }
 
         Ok(ExprHandler {
             project: group_exprs,
             group_key_len,
+            grouping_sets,
             expr_index,
             agg_calls: vec![],
             error: None,
         })
     }
+
+    /// The index of the last agg call in the output of the agg.
+    fn last_agg_call_index(&self) -> usize {
+        let grouping_set_id_len = if self.grouping_sets.is_empty() { 0 } else { 1 };
+        self.group_key_len + grouping_set_id_len + self.agg_calls.len() - 1
+    }
+
+    /// Returns the index of the group column that `expr` is, if any.
+    fn group_key_index(&self, expr: &ExprImpl) -> Option<usize> {
+        self.expr_index
+            .get(expr)
+            .copied()
+            .filter(|index| *index < self.group_key_len)
+    }
+
+    /// Rewrites `GROUPING(args...)`. Its value is a bit mask of the args not in the grouping set
+    /// of the row, so it is computed from the id of the grouping set.
+    fn rewrite_grouping(&mut self, inputs: Vec<ExprImpl>) -> ExprImpl {
+        let Some(indices) = inputs
+            .iter()
+            .map(|input| self.group_key_index(input))
+            .collect::<Option<Vec<_>>>() else {
+            self.error = Some(ErrorCode::InvalidInputSyntax(
+                "arguments to GROUPING must be grouping expressions of the associated query level"
+                    .into(),
+            ));
+            return ExprImpl::literal_int(0);
+        };
+        if self.grouping_sets.is_empty() {
+            return ExprImpl::literal_int(0);
+        }
+
+        let masks = self
+            .grouping_sets
+            .iter()
+            .map(|set| {
+                indices.iter().fold(0, |mask, index| {
+                    (mask << 1) | i32::from(!set.contains(index))
+                })
+            })
+            .collect_vec();
+        if masks.iter().all_equal() {
+            return ExprImpl::literal_int(masks[0]);
+        }
+        // CASE WHEN id = 0 THEN mask_0 WHEN id = 1 THEN mask_1 ... ELSE mask_n END
+        let grouping_set_id = InputRef::new(self.group_key_len, DataType::Int64);
+        let (last_mask, masks) = masks.split_last().unwrap();
+        let mut case_inputs = vec![];
+        for (id, mask) in masks.iter().enumerate() {
+            let cond = FunctionCall::new(
+                ExprType::Equal,
+                vec![
+                    grouping_set_id.clone().into(),
+                    Literal::new(Some(ScalarImpl::Int64(id as i64)), DataType::Int64).into(),
+                ],
+            )
+            .unwrap();
+            case_inputs.push(cond.into());
+            case_inputs.push(ExprImpl::literal_int(*mask));
+        }
+        case_inputs.push(ExprImpl::literal_int(*last_mask));
+        FunctionCall::new_with_return_type(ExprType::Case, case_inputs, DataType::Int32).into()
+    }
 }
 
 impl ExprRewriter for ExprHandler {
@@ -153,7 +228,12 @@ impl ExprRewriter for ExprHandler {
     // Note that the rewriter does not traverse into inputs of agg calls.
     fn rewrite_agg_call(&mut self, agg_call: AggCall) -> ExprImpl {
         let return_type = agg_call.return_type();
-        let (agg_kind, inputs) = agg_call.decompose();
+        let (agg_kind, inputs, distinct) = agg_call.decompose();
+        if inputs.iter().any(|expr| expr.has_grouping()) {
+            self.error = Some(ErrorCode::InvalidInputSyntax(
+                "grouping operations are not allowed in arguments of aggregate functions".into(),
+            ));
+        }
 
         let mut index = self.project.len();
         let mut input_refs = vec![];
@@ -180,12 +260,10 @@ impl ExprRewriter for ExprHandler {
                 agg_kind: AggKind::Sum,
                 return_type: left_return_type.clone(),
                 inputs: input_refs.clone(),
+                distinct,
             });
-            let left = ExprImpl::from(InputRef::new(
-                self.group_key_len + self.agg_calls.len() - 1,
-                left_return_type,
-            ))
-            .ensure_type(return_type);
+            let left = ExprImpl::from(InputRef::new(self.last_agg_call_index(), left_return_type))
+                .ensure_type(return_type);
 
             let right_return_type =
                 AggCall::infer_return_type(&AggKind::Count, &[input_refs[0].return_type()])
@@ -195,12 +273,10 @@ impl ExprRewriter for ExprHandler {
                 agg_kind: AggKind::Count,
                 return_type: right_return_type.clone(),
                 inputs: input_refs,
+                distinct,
             });
 
-            let right = InputRef::new(
-                self.group_key_len + self.agg_calls.len() - 1,
-                right_return_type,
-            );
+            let right = InputRef::new(self.last_agg_call_index(), right_return_type);
 
             ExprImpl::from(FunctionCall::new(ExprType::Divide, vec![left, right.into()]).unwrap())
         } else {
@@ -208,19 +284,39 @@ impl ExprRewriter for ExprHandler {
                 agg_kind,
                 return_type: return_type.clone(),
                 inputs: input_refs,
+                distinct,
             });
-            ExprImpl::from(InputRef::new(
-                self.group_key_len + self.agg_calls.len() - 1,
-                return_type,
-            ))
+            ExprImpl::from(InputRef::new(self.last_agg_call_index(), return_type))
         }
     }
 
+    // When there is a FunctionCall (outside of agg call) that is a group expr, it is rewritten as
+    // an InputRef to the group column, e.g. `date_trunc('day', ts)` for
+    // `GROUP BY date_trunc('day', ts)`.
+    fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        let expr = ExprImpl::from(func_call);
+        if let Some(index) = self.group_key_index(&expr) {
+            return InputRef::new(index, expr.return_type()).into();
+        }
+        let ExprImpl::FunctionCall(func_call) = expr else {
+            unreachable!()
+        };
+        let (func_type, inputs, ret) = func_call.decompose();
+        if func_type == ExprType::Grouping {
+            return self.rewrite_grouping(inputs);
+        }
+        let inputs = inputs
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        FunctionCall::new_with_return_type(func_type, inputs, ret).into()
+    }
+
     // When there is an InputRef (outside of agg call), it must refers to a group column.
     fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
         let expr = input_ref.into();
-        if let Some(index) = self.expr_index.get(&expr) && *index < self.group_key_len {
-            InputRef::new(*index, expr.return_type()).into()
+        if let Some(index) = self.group_key_index(&expr) {
+            InputRef::new(index, expr.return_type()).into()
         } else {
             self.error = Some(ErrorCode::InvalidInputSyntax(
                 "column must appear in the GROUP BY clause or be used in an aggregate function"
@@ -311,14 +407,22 @@ impl LogicalAgg {
     /// ```text
     /// LogicalProject -> LogicalAgg -> LogicalProject -> input
     /// ```
+    ///
+    /// With grouping sets, a [`LogicalExpand`] is inserted below the agg, which groups by the
+    /// expanded group exprs and the id of the grouping set:
+    ///
+    /// ```text
+    /// LogicalProject -> LogicalAgg -> LogicalExpand -> LogicalProject -> input
+    /// ```
     pub fn create(
         select_exprs: Vec<ExprImpl>,
         select_alias: Vec<Option<String>>,
         group_exprs: Vec<ExprImpl>,
+        grouping_sets: Vec<Vec<usize>>,
         input: PlanRef,
     ) -> Result<PlanRef> {
-        let group_keys = (0..group_exprs.len()).collect();
-        let mut expr_handler = ExprHandler::new(group_exprs)?;
+        let group_key_len = group_exprs.len();
+        let mut expr_handler = ExprHandler::new(group_exprs, grouping_sets.clone())?;
 
         let rewritten_select_exprs = select_exprs
             .into_iter()
@@ -332,17 +436,37 @@ impl LogicalAgg {
             .collect::<Result<_>>()?;
 
         // This LogicalProject focuses on the exprs in aggregates and GROUP BY clause.
-        let expr_alias = vec![None; expr_handler.project.len()];
+        let project_len = expr_handler.project.len();
+        let expr_alias = vec![None; project_len];
         let logical_project = LogicalProject::create(input, expr_handler.project, expr_alias);
 
         // This LogicalAgg foucuses on calculating the aggregates and grouping.
         let agg_call_alias = vec![None; expr_handler.agg_calls.len()];
-        let logical_agg = LogicalAgg::new(
-            expr_handler.agg_calls,
-            agg_call_alias,
-            group_keys,
-            logical_project,
-        );
+        let logical_agg = if grouping_sets.is_empty() {
+            LogicalAgg::new(
+                expr_handler.agg_calls,
+                agg_call_alias,
+                (0..group_key_len).collect(),
+                logical_project,
+            )
+        } else {
+            // The group columns not in the grouping set are NULL in the expanded copy, while the
+            // aggregates are calculated on the original copy.
+            let expand = LogicalExpand::create(logical_project, grouping_sets);
+            let agg_calls = expr_handler
+                .agg_calls
+                .into_iter()
+                .map(|mut agg_call| {
+                    agg_call
+                        .inputs
+                        .iter_mut()
+                        .for_each(|i| *i = InputRef::new(project_len + i.index(), i.return_type()));
+                    agg_call
+                })
+                .collect();
+            let group_keys = (0..group_key_len).chain([2 * project_len]).collect();
+            LogicalAgg::new(agg_calls, agg_call_alias, group_keys, expand)
+        };
 
         // This LogicalProject focus on transforming the aggregates and grouping columns to
         // InputRef.
@@ -500,12 +624,12 @@ impl ToBatch for LogicalAgg {
 }
 
 impl ToStream for LogicalAgg {
-    fn to_stream(&self) -> PlanRef {
-        if self.group_keys().is_empty() {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Ok(if self.group_keys().is_empty() {
             StreamSimpleAgg::new(
                 self.clone_with_input(
                     self.input()
-                        .to_stream_with_dist_required(&Distribution::Single),
+                        .to_stream_with_dist_required(&Distribution::Single)?,
                 ),
             )
             .into()
@@ -513,14 +637,14 @@ impl ToStream for LogicalAgg {
             StreamHashAgg::new(
                 self.clone_with_input(self.input().to_stream_with_dist_required(
                     &Distribution::HashShard(self.group_keys().to_vec()),
-                )),
+                )?),
             )
             .into()
-        }
+        })
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        let (input, input_col_change) = self.input.logical_rewrite_for_stream();
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
         let (agg, out_col_change) = self.rewrite_with_input(input, input_col_change);
 
         // To rewrite StreamAgg, there are two things to do:
@@ -537,6 +661,7 @@ impl ToStream for LogicalAgg {
                 agg_kind: AggKind::Count,
                 return_type: DataType::Int64,
                 inputs: vec![],
+                distinct: false,
             },
         );
         agg_call_alias.insert(0, None);
@@ -548,10 +673,10 @@ impl ToStream for LogicalAgg {
             }
         });
 
-        (
+        Ok((
             LogicalAgg::new(agg_calls, agg_call_alias, group_keys, input).into(),
             ColIndexMapping::new(map),
-        )
+        ))
     }
 }
 
@@ -598,8 +723,14 @@ mod tests {
                                   group_exprs|
          -> (Vec<ExprImpl>, Vec<PlanAggCall>, Vec<usize>) {
             let select_alias = vec![None; select_exprs.len()];
-            let plan =
-                LogicalAgg::create(select_exprs, select_alias, group_exprs, input.clone()).unwrap();
+            let plan = LogicalAgg::create(
+                select_exprs,
+                select_alias,
+                group_exprs,
+                vec![],
+                input.clone(),
+            )
+            .unwrap();
             let logical_project = plan.as_logical_project().unwrap();
             let exprs = logical_project.exprs();
 
@@ -627,7 +758,8 @@ mod tests {
 
         // Test case: select v1, min(v2) from test group by v1;
         {
-            let min_v2 = AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()]).unwrap();
+            let min_v2 =
+                AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()], false).unwrap();
             let select_exprs = vec![input_ref_1.clone().into(), min_v2.into()];
             let group_exprs = vec![input_ref_1.clone().into()];
 
@@ -645,8 +777,10 @@ mod tests {
 
         // Test case: select v1, min(v2) + max(v3) from t group by v1;
         {
-            let min_v2 = AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()]).unwrap();
-            let max_v3 = AggCall::new(AggKind::Max, vec![input_ref_3.clone().into()]).unwrap();
+            let min_v2 =
+                AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()], false).unwrap();
+            let max_v3 =
+                AggCall::new(AggKind::Max, vec![input_ref_3.clone().into()], false).unwrap();
             let func_call =
                 FunctionCall::new(ExprType::Add, vec![min_v2.into(), max_v3.into()]).unwrap();
             let select_exprs = vec![input_ref_1.clone().into(), ExprImpl::from(func_call)];
@@ -679,7 +813,7 @@ mod tests {
                 vec![input_ref_1.into(), input_ref_3.into()],
             )
             .unwrap();
-            let agg_call = AggCall::new(AggKind::Min, vec![v1_mult_v3.into()]).unwrap();
+            let agg_call = AggCall::new(AggKind::Min, vec![v1_mult_v3.into()], false).unwrap();
             let select_exprs = vec![input_ref_2.clone().into(), agg_call.into()];
             let group_exprs = vec![input_ref_2.into()];
 
@@ -733,6 +867,7 @@ mod tests {
             agg_kind: AggKind::Min,
             return_type: ty.clone(),
             inputs: vec![InputRef::new(2, ty.clone())],
+            distinct: false,
         };
         let agg = LogicalAgg::new(
             vec![agg_call],
@@ -801,6 +936,7 @@ mod tests {
             agg_kind: AggKind::Min,
             return_type: ty.clone(),
             inputs: vec![InputRef::new(2, ty.clone())],
+            distinct: false,
         };
         let agg = LogicalAgg::new(
             vec![agg_call],
@@ -878,11 +1014,13 @@ mod tests {
                 agg_kind: AggKind::Min,
                 return_type: ty.clone(),
                 inputs: vec![InputRef::new(2, ty.clone())],
+                distinct: false,
             },
             PlanAggCall {
                 agg_kind: AggKind::Max,
                 return_type: ty.clone(),
                 inputs: vec![InputRef::new(1, ty.clone())],
+                distinct: false,
             },
         ];
         let agg = LogicalAgg::new(
@@ -15,29 +15,42 @@
 use std::fmt;
 
 use fixedbitset::FixedBitSet;
+use risingwave_common::error::Result;
 use risingwave_pb::plan::JoinType;
 
 use super::{ColPrunable, LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, ToBatch, ToStream};
-use crate::utils::ColIndexMapping;
+use crate::utils::{ColIndexMapping, Condition};
 
 /// `LogicalApply` represents a correlated join, where the right side may refer to columns from the
 /// left side.
+///
+/// `on` is evaluated on the concatenated columns of both sides, like the condition of a
+/// [`LogicalJoin`]. It is used by set comparison subqueries, e.g. `x IN (subquery)`.
 #[derive(Debug, Clone)]
 pub struct LogicalApply {
     pub base: PlanBase,
     left: PlanRef,
     right: PlanRef,
     join_type: JoinType,
+    on: Condition,
 }
 
 impl fmt::Display for LogicalApply {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "LogicalApply {{ type: {:?} }}", &self.join_type)
+        if self.on.always_true() {
+            write!(f, "LogicalApply {{ type: {:?} }}", &self.join_type)
+        } else {
+            write!(
+                f,
+                "LogicalApply {{ type: {:?}, on: {} }}",
+                &self.join_type, &self.on
+            )
+        }
     }
 }
 
 impl LogicalApply {
-    pub(crate) fn new(left: PlanRef, right: PlanRef, join_type: JoinType) -> Self {
+    pub(crate) fn new(left: PlanRef, right: PlanRef, join_type: JoinType, on: Condition) -> Self {
         assert!(
             matches!(
                 join_type,
@@ -61,17 +74,23 @@ impl LogicalApply {
             left,
             right,
             join_type,
+            on,
         }
     }
 
-    pub fn create(left: PlanRef, right: PlanRef, join_type: JoinType) -> PlanRef {
-        Self::new(left, right, join_type).into()
+    pub fn create(left: PlanRef, right: PlanRef, join_type: JoinType, on: Condition) -> PlanRef {
+        Self::new(left, right, join_type, on).into()
     }
 
     /// Get the join type of the logical apply.
     pub fn join_type(&self) -> JoinType {
         self.join_type
     }
+
+    /// Get a reference to the logical apply's on condition.
+    pub fn on(&self) -> &Condition {
+        &self.on
+    }
 }
 
 impl PlanTreeNodeBinary for LogicalApply {
@@ -84,7 +103,7 @@ impl PlanTreeNodeBinary for LogicalApply {
     }
 
     fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
-        Self::new(left, right, self.join_type)
+        Self::new(left, right, self.join_type, self.on.clone())
     }
 }
 
@@ -103,11 +122,11 @@ impl ToBatch for LogicalApply {
 }
 
 impl ToStream for LogicalApply {
-    fn to_stream(&self) -> PlanRef {
+    fn to_stream(&self) -> Result<PlanRef> {
         panic!("LogicalApply should be unnested")
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
         panic!("LogicalApply should be unnested")
     }
 }
@@ -100,11 +100,11 @@ impl ToBatch for LogicalDelete {
 }
 
 impl ToStream for LogicalDelete {
-    fn to_stream(&self) -> PlanRef {
+    fn to_stream(&self) -> Result<PlanRef> {
         unreachable!("delete should always be converted to batch plan");
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, crate::utils::ColIndexMapping) {
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, crate::utils::ColIndexMapping)> {
         unreachable!("delete should always be converted to batch plan");
     }
 }
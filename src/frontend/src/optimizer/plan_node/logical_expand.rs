@@ -0,0 +1,252 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::{
+    BatchExpand, ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
+    StreamExpand, ToBatch, ToStream,
+};
+use crate::utils::ColIndexMapping;
+
+/// `LogicalExpand` outputs every input row once for each of the `column_subsets`, which is how
+/// the aggregation of `GROUPING SETS`, `ROLLUP` and `CUBE` is computed.
+///
+/// The output schema is the input columns, with the columns not in the current subset set to
+/// NULL, followed by a copy of the original input columns, followed by a `flag` column holding
+/// the index of the subset.
+#[derive(Debug, Clone)]
+pub struct LogicalExpand {
+    pub base: PlanBase,
+    column_subsets: Vec<Vec<usize>>,
+    input: PlanRef,
+}
+
+impl LogicalExpand {
+    pub fn new(input: PlanRef, column_subsets: Vec<Vec<usize>>) -> Self {
+        let ctx = input.ctx();
+        let input_len = input.schema().len();
+        let fields = input
+            .schema()
+            .fields()
+            .iter()
+            .chain(input.schema().fields())
+            .cloned()
+            .chain([Field::with_name(DataType::Int64, "flag")])
+            .collect();
+        // The original columns of the pk and the flag identify an output row.
+        let mut pk_indices = input
+            .pk_indices()
+            .iter()
+            .map(|i| input_len + i)
+            .collect_vec();
+        if !pk_indices.is_empty() {
+            pk_indices.push(2 * input_len);
+        }
+        let base = PlanBase::new_logical(ctx, Schema { fields }, pk_indices);
+        LogicalExpand {
+            base,
+            column_subsets,
+            input,
+        }
+    }
+
+    pub fn create(input: PlanRef, column_subsets: Vec<Vec<usize>>) -> PlanRef {
+        Self::new(input, column_subsets).into()
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ column_subsets: {:?} }}",
+            name, self.column_subsets
+        )
+    }
+
+    pub fn column_subsets(&self) -> &[Vec<usize>] {
+        &self.column_subsets
+    }
+
+    /// Maps the input columns to their original copies in the output.
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        let input_len = self.input.schema().len();
+        ColIndexMapping::with_target_size(
+            (0..input_len).map(|i| Some(input_len + i)).collect(),
+            self.schema().len(),
+        )
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalExpand {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.column_subsets.clone())
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let column_subsets = self
+            .column_subsets
+            .iter()
+            .map(|subset| {
+                subset
+                    .iter()
+                    .filter_map(|i| input_col_change.try_map(*i))
+                    .collect()
+            })
+            .collect();
+        let old_input_len = self.input.schema().len();
+        let new_input_len = input.schema().len();
+        let expand = Self::new(input, column_subsets);
+
+        let map = (0..old_input_len)
+            .map(|i| input_col_change.try_map(i))
+            .chain(
+                (0..old_input_len).map(|i| input_col_change.try_map(i).map(|j| new_input_len + j)),
+            )
+            .chain([Some(2 * new_input_len)])
+            .collect();
+        let out_col_change = ColIndexMapping::with_target_size(map, 2 * new_input_len + 1);
+        (expand, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalExpand}
+
+impl fmt::Display for LogicalExpand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalExpand")
+    }
+}
+
+impl ColPrunable for LogicalExpand {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        // An input column is required if either of its copies is required.
+        let input_len = self.input.schema().len();
+        let mut input_required_cols = FixedBitSet::with_capacity(input_len);
+        input_required_cols.extend(
+            required_cols
+                .ones()
+                .filter(|i| *i < 2 * input_len)
+                .map(|i| i % input_len),
+        );
+
+        let mapping = ColIndexMapping::with_remaining_columns(&input_required_cols);
+        let (expand, out_col_change) =
+            self.rewrite_with_input(self.input.prune_col(&input_required_cols), mapping);
+
+        let mut remaining_columns = FixedBitSet::with_capacity(expand.schema().len());
+        remaining_columns.extend(required_cols.ones().map(|i| out_col_change.map(i)));
+        if remaining_columns.count_ones(..) == expand.schema().len() {
+            expand.into()
+        } else {
+            LogicalProject::with_mapping(
+                expand.into(),
+                ColIndexMapping::with_remaining_columns(&remaining_columns),
+            )
+        }
+    }
+}
+
+impl ToBatch for LogicalExpand {
+    fn to_batch(&self) -> PlanRef {
+        let new_input = self.input().to_batch();
+        let new_logical = self.clone_with_input(new_input);
+        BatchExpand::new(new_logical).into()
+    }
+}
+
+impl ToStream for LogicalExpand {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamExpand::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (expand, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        Ok((expand.into(), out_col_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Expr;
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Pruning
+    /// ```text
+    /// Expand(column_subsets: [[0, 1], [0], []])
+    ///   TableScan(v1, v2, v3)
+    /// ```
+    /// with required columns [0, 4, 6] will result in
+    /// ```text
+    /// Project($0, $3, $4)
+    ///   Expand(column_subsets: [[0, 1], [0], []])
+    ///     TableScan(v1, v2)
+    /// ```
+    async fn test_prune_expand() {
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(DataType::Int32, "v1"),
+            Field::with_name(DataType::Int32, "v2"),
+            Field::with_name(DataType::Int32, "v3"),
+        ];
+        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let expand = LogicalExpand::new(values.into(), vec![vec![0, 1], vec![0], vec![]]);
+        assert_eq!(expand.schema().len(), 7);
+
+        let mut required_cols = FixedBitSet::with_capacity(7);
+        required_cols.extend([0, 4, 6]);
+        let plan = expand.prune_col(&required_cols);
+
+        let project = plan.as_logical_project().unwrap();
+        assert_eq!(
+            project
+                .exprs()
+                .iter()
+                .map(|e| e.as_input_ref().unwrap().index())
+                .collect_vec(),
+            vec![0, 3, 4]
+        );
+        assert_eq!(
+            project.exprs()[2].return_type(),
+            DataType::Int64,
+            "flag should be kept"
+        );
+        let expand = project.input();
+        let expand = expand.as_logical_expand().unwrap();
+        assert_eq!(expand.column_subsets(), &[vec![0, 1], vec![0], vec![]]);
+        assert_eq!(expand.input().schema().len(), 2);
+    }
+}
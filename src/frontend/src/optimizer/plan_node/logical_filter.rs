@@ -15,6 +15,7 @@
 use std::fmt;
 
 use fixedbitset::FixedBitSet;
+use risingwave_common::error::Result;
 
 use super::{
     ColPrunable, CollectInputRef, LogicalProject, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
@@ -134,16 +135,16 @@ impl ToBatch for LogicalFilter {
 }
 
 impl ToStream for LogicalFilter {
-    fn to_stream(&self) -> PlanRef {
-        let new_input = self.input().to_stream();
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
         let new_logical = self.clone_with_input(new_input);
-        StreamFilter::new(new_logical).into()
+        Ok(StreamFilter::new(new_logical).into())
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        let (input, input_col_change) = self.input.logical_rewrite_for_stream();
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
         let (filter, out_col_change) = self.rewrite_with_input(input, input_col_change);
-        (filter.into(), out_col_change)
+        Ok((filter.into(), out_col_change))
     }
 }
 
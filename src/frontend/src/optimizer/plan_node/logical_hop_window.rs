@@ -0,0 +1,242 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, IntervalUnit};
+
+use super::{
+    BatchHopWindow, ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
+    StreamHopWindow, ToBatch, ToStream,
+};
+use crate::expr::{Expr, InputRef};
+use crate::utils::ColIndexMapping;
+
+/// `LogicalHopWindow` implements Hop Table Function. Every input row is output once for each of
+/// the `window_size / window_slide` windows containing its `time_col`, with the `window_start`
+/// and `window_end` of the window appended.
+#[derive(Debug, Clone)]
+pub struct LogicalHopWindow {
+    pub base: PlanBase,
+    input: PlanRef,
+    time_col: InputRef,
+    window_slide: IntervalUnit,
+    window_size: IntervalUnit,
+}
+
+impl LogicalHopWindow {
+    pub fn new(
+        input: PlanRef,
+        time_col: InputRef,
+        window_slide: IntervalUnit,
+        window_size: IntervalUnit,
+    ) -> Self {
+        let ctx = input.ctx();
+        let mut fields = input.schema().fields().to_vec();
+        fields.extend([
+            Field::with_name(DataType::Timestamp, "window_start"),
+            Field::with_name(DataType::Timestamp, "window_end"),
+        ]);
+        // A row appears in several windows, so `window_start` is required to identify it.
+        let mut pk_indices = input.pk_indices().to_vec();
+        if !pk_indices.is_empty() {
+            pk_indices.push(input.schema().len());
+        }
+        let base = PlanBase::new_logical(ctx, Schema { fields }, pk_indices);
+        LogicalHopWindow {
+            base,
+            input,
+            time_col,
+            window_slide,
+            window_size,
+        }
+    }
+
+    pub fn create(
+        input: PlanRef,
+        time_col: InputRef,
+        window_slide: IntervalUnit,
+        window_size: IntervalUnit,
+    ) -> PlanRef {
+        Self::new(input, time_col, window_slide, window_size).into()
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ time_col: {}, slide: {}, size: {} }}",
+            name, self.time_col, self.window_slide, self.window_size
+        )
+    }
+
+    pub fn time_col(&self) -> &InputRef {
+        &self.time_col
+    }
+
+    pub fn window_slide(&self) -> IntervalUnit {
+        self.window_slide
+    }
+
+    pub fn window_size(&self) -> IntervalUnit {
+        self.window_size
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalHopWindow {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(
+            input,
+            self.time_col.clone(),
+            self.window_slide,
+            self.window_size,
+        )
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let time_col = InputRef::new(
+            input_col_change.map(self.time_col.index()),
+            self.time_col.return_type(),
+        );
+        let new_input_len = input.schema().len();
+        let hop = Self::new(input, time_col, self.window_slide, self.window_size);
+        let (mut map, _) = input_col_change.into_parts();
+        map.extend([Some(new_input_len), Some(new_input_len + 1)]);
+        let out_col_change = ColIndexMapping::with_target_size(map, new_input_len + 2);
+        (hop, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalHopWindow}
+
+impl fmt::Display for LogicalHopWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalHopWindow")
+    }
+}
+
+impl ColPrunable for LogicalHopWindow {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        let input_len = self.input.schema().len();
+        let mut input_required_cols = FixedBitSet::with_capacity(input_len);
+        input_required_cols.extend(required_cols.ones().filter(|i| *i < input_len));
+        input_required_cols.insert(self.time_col.index());
+
+        let mapping = ColIndexMapping::with_remaining_columns(&input_required_cols);
+        let (hop, out_col_change) =
+            self.rewrite_with_input(self.input.prune_col(&input_required_cols), mapping);
+
+        let mut remaining_columns = FixedBitSet::with_capacity(hop.schema().len());
+        remaining_columns.extend(required_cols.ones().map(|i| out_col_change.map(i)));
+        if remaining_columns.count_ones(..) == hop.schema().len() {
+            hop.into()
+        } else {
+            LogicalProject::with_mapping(
+                hop.into(),
+                ColIndexMapping::with_remaining_columns(&remaining_columns),
+            )
+        }
+    }
+}
+
+impl ToBatch for LogicalHopWindow {
+    fn to_batch(&self) -> PlanRef {
+        let new_input = self.input().to_batch();
+        let new_logical = self.clone_with_input(new_input);
+        BatchHopWindow::new(new_logical).into()
+    }
+}
+
+impl ToStream for LogicalHopWindow {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamHopWindow::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (hop, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        Ok((hop.into(), out_col_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Pruning
+    /// ```text
+    /// HopWindow(time_col: $1)
+    ///   TableScan(v1, v2, v3)
+    /// ```
+    /// with required columns [0, 4] will result in
+    /// ```text
+    /// Project($0, $3)
+    ///   HopWindow(time_col: $1)
+    ///     TableScan(v1, v2)
+    /// ```
+    async fn test_prune_hop_window() {
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(DataType::Int32, "v1"),
+            Field::with_name(DataType::Timestamp, "v2"),
+            Field::with_name(DataType::Int32, "v3"),
+        ];
+        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let hop = LogicalHopWindow::new(
+            values.into(),
+            InputRef::new(1, DataType::Timestamp),
+            IntervalUnit::from_millis(15 * 60 * 1000),
+            IntervalUnit::from_millis(30 * 60 * 1000),
+        );
+
+        let mut required_cols = FixedBitSet::with_capacity(5);
+        required_cols.extend([0, 4]);
+        let plan = hop.prune_col(&required_cols);
+
+        let project = plan.as_logical_project().unwrap();
+        assert_eq!(
+            project
+                .exprs()
+                .iter()
+                .map(|e| e.as_input_ref().unwrap().index())
+                .collect_vec(),
+            vec![0, 3]
+        );
+        let hop = project.input();
+        let hop = hop.as_logical_hop_window().unwrap();
+        assert_eq!(hop.time_col().index(), 1);
+        assert_eq!(hop.schema().len(), 4);
+        assert_eq!(hop.input().schema().len(), 2);
+    }
+}
@@ -16,6 +16,7 @@ use std::fmt;
 
 use fixedbitset::FixedBitSet;
 use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::plan::JoinType;
 
 use super::{
@@ -24,7 +25,8 @@ use super::{
 };
 use crate::expr::ExprImpl;
 use crate::optimizer::plan_node::{
-    BatchFilter, BatchHashJoin, CollectInputRef, EqJoinPredicate, LogicalFilter, StreamFilter,
+    BatchFilter, BatchHashJoin, BatchNestedLoopJoin, CollectInputRef, EqJoinPredicate,
+    LogicalFilter, StreamFilter,
 };
 use crate::optimizer::property::Distribution;
 use crate::utils::{ColIndexMapping, Condition};
@@ -351,13 +353,13 @@ impl ToBatch for LogicalJoin {
             }
         } else {
             // Convert to Nested-loop Join for non-equal joins
-            todo!("nested loop join")
+            BatchNestedLoopJoin::new(logical_join).into()
         }
     }
 }
 
 impl ToStream for LogicalJoin {
-    fn to_stream(&self) -> PlanRef {
+    fn to_stream(&self) -> Result<PlanRef> {
         let predicate = EqJoinPredicate::create(
             self.left.schema().len(),
             self.right.schema().len(),
@@ -365,10 +367,10 @@ impl ToStream for LogicalJoin {
         );
         let left = self
             .left()
-            .to_stream_with_dist_required(&Distribution::HashShard(predicate.left_eq_indexes()));
+            .to_stream_with_dist_required(&Distribution::HashShard(predicate.left_eq_indexes()))?;
         let right = self
             .right()
-            .to_stream_with_dist_required(&Distribution::HashShard(predicate.right_eq_indexes()));
+            .to_stream_with_dist_required(&Distribution::HashShard(predicate.right_eq_indexes()))?;
         let logical_join = self.clone_with_left_right(left, right);
 
         if predicate.has_eq() {
@@ -384,22 +386,25 @@ impl ToStream for LogicalJoin {
                 let logical_join = logical_join.clone_with_cond(eq_cond.eq_cond());
                 let hash_join = StreamHashJoin::new(logical_join, eq_cond).into();
                 let logical_filter = LogicalFilter::new(hash_join, predicate.non_eq_cond());
-                StreamFilter::new(logical_filter).into()
+                Ok(StreamFilter::new(logical_filter).into())
             } else {
-                StreamHashJoin::new(logical_join, predicate).into()
+                Ok(StreamHashJoin::new(logical_join, predicate).into())
             }
         } else {
             // Convert to Nested-loop Join for non-equal joins
-            todo!("nested loop join")
+            Err(
+                ErrorCode::NotImplemented("streaming nested loop join".to_string(), None.into())
+                    .into(),
+            )
         }
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        let (left, left_col_change) = self.left.logical_rewrite_for_stream();
-        let (right, right_col_change) = self.right.logical_rewrite_for_stream();
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (left, left_col_change) = self.left.logical_rewrite_for_stream()?;
+        let (right, right_col_change) = self.right.logical_rewrite_for_stream()?;
         let (join, out_col_change) =
             self.rewrite_with_left_right(left, left_col_change, right, right_col_change);
-        (join.into(), out_col_change)
+        Ok((join.into(), out_col_change))
     }
 }
 
@@ -15,6 +15,7 @@
 use std::fmt;
 
 use fixedbitset::FixedBitSet;
+use risingwave_common::error::{ErrorCode, Result};
 
 use super::{
     BatchLimit, ColPrunable, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary, ToBatch, ToStream,
@@ -31,7 +32,7 @@ pub struct LogicalLimit {
 }
 
 impl LogicalLimit {
-    fn new(input: PlanRef, limit: usize, offset: usize) -> Self {
+    pub fn new(input: PlanRef, limit: usize, offset: usize) -> Self {
         let ctx = input.ctx();
         let schema = input.schema().clone();
         let pk_indices = input.pk_indices().to_vec();
@@ -105,13 +106,13 @@ impl ToBatch for LogicalLimit {
 }
 
 impl ToStream for LogicalLimit {
-    fn to_stream(&self) -> PlanRef {
-        panic!("there is no limit stream operator");
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(ErrorCode::NotImplemented("LIMIT in streaming queries".to_string(), None.into()).into())
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        let (input, input_col_change) = self.input.logical_rewrite_for_stream();
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
         let (filter, out_col_change) = self.rewrite_with_input(input, input_col_change);
-        (filter.into(), out_col_change)
+        Ok((filter.into(), out_col_change))
     }
 }
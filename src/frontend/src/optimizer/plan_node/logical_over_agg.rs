@@ -0,0 +1,616 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::AggKind;
+use risingwave_pb::expr::window_function::Type as ProstWindowFunctionType;
+use risingwave_pb::expr::{AggCall as ProstAggCall, WindowFunction as ProstWindowFunction};
+use risingwave_pb::plan::ColumnOrder;
+
+use super::{
+    BatchOverAgg, ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
+    StreamOverAgg, ToBatch, ToStream,
+};
+use crate::expr::{
+    column_idx_to_inputref_proto, AggCall, Expr, ExprImpl, ExprRewriter, ExprType, ExprVisitor,
+    FunctionCall, InputRef, WindowFrame, WindowFunction, WindowFunctionType,
+};
+use crate::optimizer::property::{Direction, Distribution, FieldOrder, Order};
+use crate::utils::ColIndexMapping;
+
+/// A window function call in [`LogicalOverAgg`], whose arguments are all input columns.
+#[derive(Clone)]
+pub struct PlanWindowFunction {
+    pub function_type: WindowFunctionType,
+    pub return_type: DataType,
+    pub args: Vec<InputRef>,
+    /// The offset of `lag` and `lead`.
+    pub offset: u64,
+    pub frame: WindowFrame,
+}
+
+impl fmt::Debug for PlanWindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = f.debug_tuple(&format!("{}", self.function_type));
+        self.args.iter().for_each(|child| {
+            builder.field(child);
+        });
+        builder.finish()?;
+        match self.function_type {
+            WindowFunctionType::Lag | WindowFunctionType::Lead => {
+                write!(f, " OFFSET {}", self.offset)
+            }
+            WindowFunctionType::Aggregate(_) => write!(f, " {}", self.frame),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl PlanWindowFunction {
+    pub fn to_protobuf(&self) -> ProstWindowFunction {
+        let r#type = match self.function_type {
+            WindowFunctionType::RowNumber => ProstWindowFunctionType::RowNumber,
+            WindowFunctionType::Rank => ProstWindowFunctionType::Rank,
+            WindowFunctionType::DenseRank => ProstWindowFunctionType::DenseRank,
+            WindowFunctionType::Lag => ProstWindowFunctionType::Lag,
+            WindowFunctionType::Lead => ProstWindowFunctionType::Lead,
+            WindowFunctionType::Aggregate(_) => ProstWindowFunctionType::Aggregate,
+        };
+        let agg_call = match &self.function_type {
+            WindowFunctionType::Aggregate(kind) => Some(ProstAggCall {
+                r#type: kind.to_prost().into(),
+                args: self
+                    .args
+                    .iter()
+                    .map(InputRef::to_agg_arg_protobuf)
+                    .collect(),
+                return_type: Some(self.return_type.to_protobuf()),
+                distinct: false,
+            }),
+            _ => None,
+        };
+        let arg = match self.function_type {
+            WindowFunctionType::Lag | WindowFunctionType::Lead => {
+                Some(column_idx_to_inputref_proto(self.args[0].index()))
+            }
+            _ => None,
+        };
+        ProstWindowFunction {
+            r#type: r#type as i32,
+            arg,
+            offset: self.offset,
+            agg_call,
+            frame: Some(self.frame.to_protobuf()),
+            return_type: Some(self.return_type.to_protobuf()),
+        }
+    }
+}
+
+/// `LogicalOverAgg` computes window functions, i.e. function calls with an `OVER` clause, on each
+/// partition of its input.
+///
+/// The output schema is the input columns followed by one column for each window function. All
+/// window functions share the same partition keys and order keys.
+#[derive(Debug, Clone)]
+pub struct LogicalOverAgg {
+    pub base: PlanBase,
+    window_functions: Vec<PlanWindowFunction>,
+    partition_by: Vec<usize>,
+    order_by: Vec<FieldOrder>,
+    input: PlanRef,
+}
+
+/// `OverAggHandler` extracts window functions from the select list, in preparation for generating
+/// a plan like `LogicalProject - LogicalOverAgg - LogicalProject`.
+///
+/// As an [`ExprVisitor`], it first collects the arguments, partition keys and order keys of all
+/// window functions into `project`, after all the input columns. As an [`ExprRewriter`], it then
+/// rewrites the window functions to references to the output columns of [`LogicalOverAgg`].
+struct OverAggHandler {
+    project: Vec<ExprImpl>,
+    expr_index: HashMap<ExprImpl, usize>,
+    partition_by: Option<Vec<ExprImpl>>,
+    order_by: Vec<(ExprImpl, Direction)>,
+    window_functions: Vec<PlanWindowFunction>,
+    error: Option<ErrorCode>,
+}
+
+impl OverAggHandler {
+    fn new(input: &PlanRef) -> Self {
+        let project = input
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect_vec();
+        let expr_index = project
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, expr)| (expr, i))
+            .collect();
+        Self {
+            project,
+            expr_index,
+            partition_by: None,
+            order_by: vec![],
+            window_functions: vec![],
+            error: None,
+        }
+    }
+
+    fn add_to_project(&mut self, expr: &ExprImpl) -> usize {
+        if let Some(index) = self.expr_index.get(expr) {
+            *index
+        } else {
+            let index = self.project.len();
+            self.project.push(expr.clone());
+            self.expr_index.insert(expr.clone(), index);
+            index
+        }
+    }
+
+    fn input_ref(&self, expr: &ExprImpl) -> InputRef {
+        InputRef::new(self.expr_index[expr], expr.return_type())
+    }
+}
+
+impl ExprVisitor for OverAggHandler {
+    fn visit_window_function(&mut self, window_function: &WindowFunction) {
+        match &self.partition_by {
+            None => {
+                self.partition_by = Some(window_function.partition_by().to_vec());
+                self.order_by = window_function.order_by().to_vec();
+            }
+            Some(partition_by) => {
+                if partition_by != window_function.partition_by()
+                    || self.order_by != window_function.order_by()
+                {
+                    self.error = Some(ErrorCode::NotImplemented(
+                        "window functions with different PARTITION BY or ORDER BY".into(),
+                        None.into(),
+                    ));
+                }
+            }
+        }
+        window_function
+            .args()
+            .iter()
+            .chain(window_function.partition_by())
+            .chain(window_function.order_by().iter().map(|(expr, _)| expr))
+            .for_each(|expr| {
+                self.add_to_project(expr);
+            });
+    }
+}
+
+impl ExprRewriter for OverAggHandler {
+    fn rewrite_window_function(&mut self, window_function: WindowFunction) -> ExprImpl {
+        let return_type = window_function.return_type();
+        let (function_type, args, offset, _, _, frame) = window_function.decompose();
+        let args = args.iter().map(|expr| self.input_ref(expr)).collect_vec();
+
+        let mut push = |function_type, return_type: DataType| {
+            self.window_functions.push(PlanWindowFunction {
+                function_type,
+                return_type: return_type.clone(),
+                args: args.clone(),
+                offset,
+                frame,
+            });
+            InputRef::new(
+                self.project.len() + self.window_functions.len() - 1,
+                return_type,
+            )
+        };
+
+        if function_type == WindowFunctionType::Aggregate(AggKind::Avg) {
+            // Rewrite avg to cast(sum as avg_return_type) / count, as in `LogicalAgg`.
+            let sum_return_type =
+                AggCall::infer_return_type(&AggKind::Sum, &[args[0].return_type()]).unwrap();
+            let sum = push(WindowFunctionType::Aggregate(AggKind::Sum), sum_return_type);
+            let count = push(
+                WindowFunctionType::Aggregate(AggKind::Count),
+                DataType::Int64,
+            );
+            FunctionCall::new(
+                ExprType::Divide,
+                vec![ExprImpl::from(sum).ensure_type(return_type), count.into()],
+            )
+            .unwrap()
+            .into()
+        } else {
+            push(function_type, return_type).into()
+        }
+    }
+}
+
+impl LogicalOverAgg {
+    pub fn new(
+        window_functions: Vec<PlanWindowFunction>,
+        partition_by: Vec<usize>,
+        order_by: Vec<FieldOrder>,
+        input: PlanRef,
+    ) -> Self {
+        let ctx = input.ctx();
+        let mut fields = input.schema().fields().to_vec();
+        fields.extend(
+            window_functions
+                .iter()
+                .enumerate()
+                .map(|(i, w)| Field::with_name(w.return_type.clone(), format!("window#{}", i))),
+        );
+        let pk_indices = input.pk_indices().to_vec();
+        let base = PlanBase::new_logical(ctx, Schema { fields }, pk_indices);
+        Self {
+            base,
+            window_functions,
+            partition_by,
+            order_by,
+            input,
+        }
+    }
+
+    /// `create` will analyze the select exprs with window functions, and construct a plan like
+    ///
+    /// ```text
+    /// LogicalProject -> LogicalOverAgg -> LogicalProject -> input
+    /// ```
+    pub fn create(
+        select_exprs: Vec<ExprImpl>,
+        select_alias: Vec<Option<String>>,
+        input: PlanRef,
+    ) -> Result<PlanRef> {
+        let mut handler = OverAggHandler::new(&input);
+        select_exprs
+            .iter()
+            .for_each(|expr| handler.visit_expr(expr));
+        if let Some(error) = handler.error.take() {
+            return Err(error.into());
+        }
+
+        let partition_by = handler
+            .partition_by
+            .take()
+            .unwrap_or_default()
+            .iter()
+            .map(|expr| handler.expr_index[expr])
+            .collect();
+        let order_by = handler
+            .order_by
+            .iter()
+            .map(|(expr, direct)| FieldOrder {
+                index: handler.expr_index[expr],
+                direct: *direct,
+            })
+            .collect();
+
+        let rewritten_select_exprs = select_exprs
+            .into_iter()
+            .map(|expr| handler.rewrite_expr(expr))
+            .collect();
+
+        // This LogicalProject appends the arguments and keys of window functions to the input.
+        let expr_alias = input
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| Some(field.name.clone()))
+            .chain(std::iter::repeat(None))
+            .take(handler.project.len())
+            .collect();
+        let logical_project = LogicalProject::create(input, handler.project, expr_alias);
+
+        let over_agg = LogicalOverAgg::new(
+            handler.window_functions,
+            partition_by,
+            order_by,
+            logical_project,
+        );
+
+        Ok(LogicalProject::create(
+            over_agg.into(),
+            rewritten_select_exprs,
+            select_alias,
+        ))
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        f.debug_struct(name)
+            .field("window_functions", &self.window_functions)
+            .field("partition_by", &self.partition_by)
+            .field("order_by", &self.order_by)
+            .finish()
+    }
+
+    /// Get a reference to the logical over agg's window functions.
+    pub fn window_functions(&self) -> &[PlanWindowFunction] {
+        self.window_functions.as_ref()
+    }
+
+    /// Get a reference to the logical over agg's partition keys.
+    pub fn partition_by(&self) -> &[usize] {
+        self.partition_by.as_ref()
+    }
+
+    /// Get a reference to the logical over agg's order keys.
+    pub fn order_by(&self) -> &[FieldOrder] {
+        self.order_by.as_ref()
+    }
+
+    /// The order that the input must satisfy when evaluated in batch, i.e. sorted by the partition
+    /// keys and then the order keys.
+    pub fn required_input_order(&self) -> Order {
+        Order::new(
+            self.partition_by
+                .iter()
+                .map(|i| FieldOrder::ascending(*i))
+                .chain(self.order_by.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// The distribution that the input must satisfy, so that each partition is in one place.
+    pub fn required_input_distribution(&self) -> Distribution {
+        if self.partition_by.is_empty() {
+            Distribution::Single
+        } else {
+            Distribution::HashShard(self.partition_by.clone())
+        }
+    }
+
+    pub fn order_by_to_protobuf(&self) -> Vec<ColumnOrder> {
+        self.order_by
+            .iter()
+            .map(|field_order| {
+                let (input_ref, order_type) = field_order.to_protobuf();
+                ColumnOrder {
+                    order_type: order_type as i32,
+                    input_ref: Some(input_ref),
+                    return_type: Some(
+                        self.input.schema()[field_order.index]
+                            .data_type
+                            .to_protobuf(),
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalOverAgg {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(
+            self.window_functions.clone(),
+            self.partition_by.clone(),
+            self.order_by.clone(),
+            input,
+        )
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let window_functions = self
+            .window_functions
+            .iter()
+            .cloned()
+            .map(|mut w| {
+                w.args.iter_mut().for_each(|i| {
+                    *i = InputRef::new(input_col_change.map(i.index()), i.return_type())
+                });
+                w
+            })
+            .collect();
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|i| input_col_change.map(*i))
+            .collect();
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|o| FieldOrder {
+                index: input_col_change.map(o.index),
+                direct: o.direct,
+            })
+            .collect();
+        let new_input_len = input.schema().len();
+        let over_agg = Self::new(window_functions, partition_by, order_by, input);
+
+        let (mut map, _) = input_col_change.into_parts();
+        map.extend((0..self.window_functions.len()).map(|i| Some(new_input_len + i)));
+        let out_col_change = ColIndexMapping::with_target_size(map, over_agg.schema().len());
+        (over_agg, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalOverAgg}
+
+impl fmt::Display for LogicalOverAgg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalOverAgg")
+    }
+}
+
+impl ColPrunable for LogicalOverAgg {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        let input_len = self.input.schema().len();
+        let window_functions = required_cols
+            .ones()
+            .filter(|i| *i >= input_len)
+            .map(|i| self.window_functions[i - input_len].clone())
+            .collect_vec();
+        if window_functions.is_empty() {
+            // None of the window functions is used, e.g. the row number is only used to compute a
+            // top-n, so the over agg can be removed.
+            let mut input_required_cols = FixedBitSet::with_capacity(input_len);
+            input_required_cols.extend(required_cols.ones());
+            return self.input.prune_col(&input_required_cols);
+        }
+
+        let mut input_required_cols = FixedBitSet::with_capacity(input_len);
+        input_required_cols.extend(required_cols.ones().filter(|i| *i < input_len));
+        input_required_cols.extend(self.partition_by.iter().copied());
+        input_required_cols.extend(self.order_by.iter().map(|o| o.index));
+        window_functions
+            .iter()
+            .for_each(|w| input_required_cols.extend(w.args.iter().map(|i| i.index())));
+
+        let pruned = Self::new(
+            window_functions,
+            self.partition_by.clone(),
+            self.order_by.clone(),
+            self.input.clone(),
+        );
+        let mapping = ColIndexMapping::with_remaining_columns(&input_required_cols);
+        let (over_agg, _) =
+            pruned.rewrite_with_input(self.input.prune_col(&input_required_cols), mapping.clone());
+
+        let new_input_len = over_agg.input.schema().len();
+        let mut remaining_columns = FixedBitSet::with_capacity(over_agg.schema().len());
+        remaining_columns.extend(
+            required_cols
+                .ones()
+                .filter(|i| *i < input_len)
+                .map(|i| mapping.map(i)),
+        );
+        remaining_columns.extend(new_input_len..over_agg.schema().len());
+        if remaining_columns.count_ones(..) == over_agg.schema().len() {
+            over_agg.into()
+        } else {
+            LogicalProject::with_mapping(
+                over_agg.into(),
+                ColIndexMapping::with_remaining_columns(&remaining_columns),
+            )
+        }
+    }
+}
+
+impl ToBatch for LogicalOverAgg {
+    fn to_batch(&self) -> PlanRef {
+        let new_input = self
+            .input()
+            .to_batch_with_order_required(&self.required_input_order());
+        let new_logical = self.clone_with_input(new_input);
+        BatchOverAgg::new(new_logical).into()
+    }
+}
+
+impl ToStream for LogicalOverAgg {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self
+            .input()
+            .to_stream_with_dist_required(&self.required_input_distribution())?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamOverAgg::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (over_agg, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        Ok((over_agg.into(), out_col_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{assert_eq_input_ref, FrameBound};
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    async fn test_create() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(ty.clone(), "v1"),
+            Field::with_name(ty.clone(), "v2"),
+            Field::with_name(ty.clone(), "v3"),
+        ];
+        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let v1: ExprImpl = InputRef::new(0, ty.clone()).into();
+        let v2: ExprImpl = InputRef::new(1, ty.clone()).into();
+        let v3: ExprImpl = InputRef::new(2, ty.clone()).into();
+
+        // select v1, row_number() over (partition by v2 + v3 order by v1),
+        //   avg(v3) over (partition by v2 + v3 order by v1) from t
+        let partition_by = vec![FunctionCall::new(ExprType::Add, vec![v2, v3.clone()])
+            .unwrap()
+            .into()];
+        let order_by = vec![(v1.clone(), Direction::Asc)];
+        let frame = WindowFrame {
+            rows: true,
+            start: FrameBound::Preceding(1),
+            end: FrameBound::CurrentRow,
+        };
+        let row_number = WindowFunction::new(
+            WindowFunctionType::RowNumber,
+            vec![],
+            0,
+            partition_by.clone(),
+            order_by.clone(),
+            WindowFrame::range_to_current_row(),
+        )
+        .unwrap();
+        let avg = WindowFunction::new(
+            WindowFunctionType::Aggregate(AggKind::Avg),
+            vec![v3],
+            0,
+            partition_by,
+            order_by,
+            frame,
+        )
+        .unwrap();
+        let select_exprs = vec![v1, row_number.into(), avg.into()];
+
+        let plan = LogicalOverAgg::create(select_exprs, vec![None; 3], values.into()).unwrap();
+        let project = plan.as_logical_project().unwrap();
+        let exprs = project.exprs();
+        assert_eq_input_ref!(&exprs[0], 0);
+        assert_eq_input_ref!(&exprs[1], 4);
+        assert!(exprs[2].as_function_call().is_some());
+
+        let plan = project.input();
+        let over_agg = plan.as_logical_over_agg().unwrap();
+        assert_eq!(over_agg.partition_by(), &[3]);
+        assert_eq!(over_agg.order_by()[0].index, 0);
+        assert_eq!(over_agg.window_functions().len(), 3);
+        assert_eq!(
+            over_agg.window_functions()[1].function_type,
+            WindowFunctionType::Aggregate(AggKind::Sum)
+        );
+        assert_eq!(over_agg.window_functions()[1].args[0].index(), 2);
+        assert_eq!(over_agg.window_functions()[2].frame, frame);
+        assert_eq!(over_agg.input().schema().len(), 4);
+    }
+}
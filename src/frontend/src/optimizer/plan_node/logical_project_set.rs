@@ -0,0 +1,274 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_pb::plan::project_set_select_item::SelectItem;
+use risingwave_pb::plan::ProjectSetSelectItem;
+
+use super::{
+    BatchProjectSet, ColPrunable, CollectInputRef, LogicalProject, PlanBase, PlanNode, PlanRef,
+    PlanTreeNodeUnary, StreamProjectSet, ToBatch, ToStream,
+};
+use crate::expr::{assert_input_ref, Expr, ExprImpl, ExprVisitor, InputRef};
+use crate::utils::ColIndexMapping;
+
+/// `LogicalProjectSet` evaluates a select list with set-returning functions, e.g. `unnest(...)`,
+/// on each input row. The set-returning functions output multiple rows for each input row, while
+/// the scalar expressions are repeated on each of them.
+///
+/// The output schema is a `projected_row_id` column, which is the index of the row among those of
+/// the same input row, followed by the select list.
+#[derive(Debug, Clone)]
+pub struct LogicalProjectSet {
+    pub base: PlanBase,
+    select_list: Vec<ExprImpl>,
+    input: PlanRef,
+}
+
+impl LogicalProjectSet {
+    pub fn new(input: PlanRef, select_list: Vec<ExprImpl>) -> Self {
+        let ctx = input.ctx();
+        let input_schema = input.schema();
+        for expr in &select_list {
+            assert_input_ref!(expr, input_schema.len());
+            assert!(!expr.has_subquery());
+            assert!(!expr.has_agg_call());
+        }
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(select_list.iter().enumerate().map(|(i, expr)| {
+                let name = match expr {
+                    ExprImpl::InputRef(input_ref) => {
+                        input_schema.fields()[input_ref.index()].name.clone()
+                    }
+                    _ => format!("expr#{}", i),
+                };
+                Field::with_name(expr.return_type(), name)
+            }))
+            .collect();
+        // The input pk and `projected_row_id` identify an output row.
+        let i2o = Self::i2o_col_mapping_inner(input_schema.len(), &select_list);
+        let pk_indices = input
+            .pk_indices()
+            .iter()
+            .map(|i| i2o.try_map(*i))
+            .collect::<Option<Vec<_>>>()
+            .map(|mut pk_indices| {
+                pk_indices.push(0);
+                pk_indices
+            })
+            .unwrap_or_default();
+        let base = PlanBase::new_logical(ctx, Schema { fields }, pk_indices);
+        LogicalProjectSet {
+            base,
+            select_list,
+            input,
+        }
+    }
+
+    /// Plans the select list as a [`LogicalProjectSet`], with a [`LogicalProject`] on top to
+    /// remove the `projected_row_id` column.
+    pub fn create(
+        input: PlanRef,
+        select_list: Vec<ExprImpl>,
+        aliases: Vec<Option<String>>,
+    ) -> Result<PlanRef> {
+        for expr in &select_list {
+            let nested = match expr {
+                ExprImpl::TableFunction(table_function) => table_function
+                    .args()
+                    .iter()
+                    .any(ExprImpl::has_table_function),
+                _ => expr.has_table_function(),
+            };
+            if nested {
+                return Err(ErrorCode::NotImplemented(
+                    "set-returning functions nested in other expressions".into(),
+                    None.into(),
+                )
+                .into());
+            }
+        }
+        let project_set = Self::new(input, select_list);
+        let exprs = project_set
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        Ok(LogicalProject::create(project_set.into(), exprs, aliases))
+    }
+
+    /// Maps the input columns to the select items which are plain `InputRef`s.
+    fn i2o_col_mapping_inner(input_len: usize, select_list: &[ExprImpl]) -> ColIndexMapping {
+        let mut map = vec![None; input_len];
+        for (i, expr) in select_list.iter().enumerate() {
+            if let ExprImpl::InputRef(input_ref) = expr {
+                map[input_ref.index()] = Some(i + 1);
+            }
+        }
+        ColIndexMapping::with_target_size(map, select_list.len() + 1)
+    }
+
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        Self::i2o_col_mapping_inner(self.input.schema().len(), &self.select_list)
+    }
+
+    pub fn select_list(&self) -> &[ExprImpl] {
+        &self.select_list
+    }
+
+    pub fn select_list_to_prost(&self) -> Vec<ProjectSetSelectItem> {
+        self.select_list
+            .iter()
+            .map(|expr| ProjectSetSelectItem {
+                select_item: Some(match expr {
+                    ExprImpl::TableFunction(table_function) => {
+                        SelectItem::TableFunction(table_function.to_table_function_prost())
+                    }
+                    _ => SelectItem::Expr(expr.to_protobuf()),
+                }),
+            })
+            .collect()
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        f.debug_struct(name)
+            .field("select_list", &self.select_list)
+            .finish()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalProjectSet {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.select_list.clone())
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        mut input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let select_list = self
+            .select_list
+            .iter()
+            .cloned()
+            .map(|expr| input_col_change.rewrite_expr(expr))
+            .collect();
+        let project_set = Self::new(input, select_list);
+        // Changing the input columns does not change the output columns.
+        let out_col_change = ColIndexMapping::identity(self.schema().len());
+        (project_set, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalProjectSet}
+
+impl fmt::Display for LogicalProjectSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalProjectSet")
+    }
+}
+
+impl ColPrunable for LogicalProjectSet {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        // The set-returning functions decide the number of output rows, so they are always kept.
+        let kept_items = (0..self.select_list.len())
+            .filter(|i| required_cols.contains(i + 1) || self.select_list[*i].has_table_function())
+            .collect_vec();
+
+        let mut visitor = CollectInputRef::with_capacity(self.input.schema().len());
+        kept_items
+            .iter()
+            .for_each(|i| visitor.visit_expr(&self.select_list[*i]));
+        let input_required_cols = visitor.collect();
+        let mut mapping = ColIndexMapping::with_remaining_columns(&input_required_cols);
+        let select_list = kept_items
+            .iter()
+            .map(|i| mapping.rewrite_expr(self.select_list[*i].clone()))
+            .collect();
+        let project_set = Self::new(self.input.prune_col(&input_required_cols), select_list);
+
+        let mut out_col_change = vec![None; self.schema().len()];
+        out_col_change[0] = Some(0);
+        for (new_idx, old_idx) in kept_items.iter().enumerate() {
+            out_col_change[old_idx + 1] = Some(new_idx + 1);
+        }
+        let mut remaining_columns = FixedBitSet::with_capacity(project_set.schema().len());
+        remaining_columns.extend(required_cols.ones().map(|i| out_col_change[i].unwrap()));
+        if remaining_columns.count_ones(..) == project_set.schema().len() {
+            project_set.into()
+        } else {
+            LogicalProject::with_mapping(
+                project_set.into(),
+                ColIndexMapping::with_remaining_columns(&remaining_columns),
+            )
+        }
+    }
+}
+
+impl ToBatch for LogicalProjectSet {
+    fn to_batch(&self) -> PlanRef {
+        let new_input = self.input().to_batch();
+        let new_logical = self.clone_with_input(new_input);
+        BatchProjectSet::new(new_logical).into()
+    }
+}
+
+impl ToStream for LogicalProjectSet {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamProjectSet::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (project_set, out_col_change) =
+            self.rewrite_with_input(input.clone(), input_col_change);
+
+        // The input pk is required to identify the output rows, so add the missing pk columns at
+        // the end, which does not change the existing output columns.
+        let i2o = project_set.i2o_col_mapping();
+        let input_schema = input.schema();
+        let select_list = project_set
+            .select_list
+            .iter()
+            .cloned()
+            .chain(
+                input
+                    .pk_indices()
+                    .iter()
+                    .filter(|i| i2o.try_map(**i).is_none())
+                    .map(|i| InputRef::new(*i, input_schema.fields()[*i].data_type()).into()),
+            )
+            .collect();
+        let project_set = Self::new(input, select_list);
+        Ok((project_set.into(), out_col_change))
+    }
+}
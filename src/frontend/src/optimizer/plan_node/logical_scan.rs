@@ -158,12 +158,12 @@ impl ToBatch for LogicalScan {
 }
 
 impl ToStream for LogicalScan {
-    fn to_stream(&self) -> PlanRef {
-        StreamTableScan::new(self.clone()).into()
+    fn to_stream(&self) -> Result<PlanRef> {
+        Ok(StreamTableScan::new(self.clone()).into())
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        match self.base.pk_indices.is_empty() {
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Ok(match self.base.pk_indices.is_empty() {
             true => {
                 let mut col_ids = HashSet::new();
 
@@ -198,6 +198,6 @@ impl ToStream for LogicalScan {
                 self.clone().into(),
                 ColIndexMapping::identity(self.schema().len()),
             ),
-        }
+        })
     }
 }
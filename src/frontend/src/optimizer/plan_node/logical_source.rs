@@ -18,6 +18,7 @@ use std::rc::Rc;
 
 use fixedbitset::FixedBitSet;
 use risingwave_common::catalog::Schema;
+use risingwave_common::error::Result;
 
 use super::{
     ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef, StreamSource, ToBatch, ToStream,
@@ -96,14 +97,14 @@ impl ToBatch for LogicalSource {
 }
 
 impl ToStream for LogicalSource {
-    fn to_stream(&self) -> PlanRef {
-        StreamSource::new(self.clone()).into()
+    fn to_stream(&self) -> Result<PlanRef> {
+        Ok(StreamSource::new(self.clone()).into())
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        (
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Ok((
             self.clone().into(),
             ColIndexMapping::identity(self.schema().len()),
-        )
+        ))
     }
 }
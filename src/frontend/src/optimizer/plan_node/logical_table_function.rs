@@ -0,0 +1,115 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+
+use super::{
+    BatchTableFunction, ColPrunable, LogicalProject, PlanBase, PlanRef, ToBatch, ToStream,
+};
+use crate::expr::{ExprImpl, TableFunction, TableFunctionType};
+use crate::session::OptimizerContextRef;
+use crate::utils::ColIndexMapping;
+
+/// `LogicalTableFunction` evaluates a set-returning function on constant arguments, and outputs
+/// the returned values as a single column.
+#[derive(Debug, Clone)]
+pub struct LogicalTableFunction {
+    pub base: PlanBase,
+    function_type: TableFunctionType,
+    args: Vec<ExprImpl>,
+}
+
+impl LogicalTableFunction {
+    pub fn new(
+        function_type: TableFunctionType,
+        args: Vec<ExprImpl>,
+        return_type: DataType,
+        ctx: OptimizerContextRef,
+    ) -> Self {
+        let schema = Schema::new(vec![Field::unnamed(return_type)]);
+        let base = PlanBase::new_logical(ctx, schema, vec![]);
+        Self {
+            base,
+            function_type,
+            args,
+        }
+    }
+
+    /// Create a [`LogicalTableFunction`] node. Used by planner.
+    pub fn create(table_function: TableFunction, ctx: OptimizerContextRef) -> PlanRef {
+        let (function_type, args, return_type) = table_function.decompose();
+        Self::new(function_type, args, return_type, ctx).into()
+    }
+
+    pub fn function_type(&self) -> TableFunctionType {
+        self.function_type
+    }
+
+    pub fn args(&self) -> &[ExprImpl] {
+        &self.args
+    }
+
+    pub fn return_type(&self) -> DataType {
+        self.schema().fields()[0].data_type()
+    }
+}
+
+impl_plan_tree_node_for_leaf! { LogicalTableFunction }
+
+impl fmt::Display for LogicalTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LogicalTableFunction")
+            .field("function_type", &self.function_type)
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl ColPrunable for LogicalTableFunction {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        // The function is evaluated as a whole, so the only column is always produced.
+        self.must_contain_columns(required_cols);
+        let mapping = ColIndexMapping::with_remaining_columns(required_cols);
+        LogicalProject::with_mapping(self.clone().into(), mapping)
+    }
+}
+
+impl ToBatch for LogicalTableFunction {
+    fn to_batch(&self) -> PlanRef {
+        BatchTableFunction::new(self.clone()).into()
+    }
+}
+
+impl ToStream for LogicalTableFunction {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(ErrorCode::NotImplemented(
+            "table function in streaming queries".to_string(),
+            None.into(),
+        )
+        .into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Err(ErrorCode::NotImplemented(
+            "table function in streaming queries".to_string(),
+            None.into(),
+        )
+        .into())
+    }
+}
@@ -15,13 +15,24 @@
 use std::fmt;
 
 use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
 
-use super::{ColPrunable, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary, ToBatch, ToStream};
+use super::{
+    BatchLimit, ColPrunable, LogicalFilter, LogicalLimit, LogicalOverAgg, PlanBase, PlanNode,
+    PlanRef, PlanTreeNodeUnary, PlanWindowFunction, StreamTopN, ToBatch, ToStream,
+};
+use crate::expr::{
+    ExprImpl, ExprType, FunctionCall, InputRef, InputRefDisplay, Literal, WindowFrame,
+    WindowFunctionType,
+};
 use crate::optimizer::plan_node::LogicalProject;
-use crate::optimizer::property::{FieldOrder, Order};
-use crate::utils::ColIndexMapping;
+use crate::optimizer::property::{Distribution, FieldOrder, Order};
+use crate::utils::{ColIndexMapping, Condition};
 
-/// `LogicalTopN` sorts the input data and fetches up to `limit` rows from `offset`
+/// `LogicalTopN` sorts the input data and fetches up to `limit` rows from `offset`. If the group
+/// key is not empty, the rows are fetched from each group of the input.
 #[derive(Debug, Clone)]
 pub struct LogicalTopN {
     pub base: PlanBase,
@@ -29,10 +40,17 @@ pub struct LogicalTopN {
     limit: usize,
     offset: usize,
     order: Order,
+    group_key: Vec<usize>,
 }
 
 impl LogicalTopN {
-    fn new(input: PlanRef, limit: usize, offset: usize, order: Order) -> Self {
+    fn new(
+        input: PlanRef,
+        limit: usize,
+        offset: usize,
+        order: Order,
+        group_key: Vec<usize>,
+    ) -> Self {
         let ctx = input.ctx();
         let schema = input.schema().clone();
         let pk_indices = input.pk_indices().to_vec();
@@ -43,12 +61,121 @@ impl LogicalTopN {
             limit,
             offset,
             order,
+            group_key,
+        }
+    }
+
+    pub fn create(
+        input: PlanRef,
+        limit: usize,
+        offset: usize,
+        order: Order,
+        group_key: Vec<usize>,
+    ) -> PlanRef {
+        Self::new(input, limit, offset, order, group_key).into()
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn order(&self) -> &Order {
+        &self.order
+    }
+
+    pub fn group_key(&self) -> &[usize] {
+        &self.group_key
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ order: {}, limit: {}, offset: {}",
+            name, self.order, self.limit, self.offset
+        )?;
+        if !self.group_key.is_empty() {
+            write!(
+                f,
+                ", group_key: {:?}",
+                self.group_key
+                    .iter()
+                    .copied()
+                    .map(InputRefDisplay)
+                    .collect_vec()
+            )?;
         }
+        write!(f, " }}")
     }
 
-    /// the function will check if the cond is bool expression
-    pub fn create(input: PlanRef, limit: usize, offset: usize, order: Order) -> PlanRef {
-        Self::new(input, limit, offset, order).into()
+    /// The distribution that the input must satisfy, so that each group is in one place.
+    pub fn required_input_distribution(&self) -> Distribution {
+        if self.group_key.is_empty() {
+            Distribution::Single
+        } else {
+            Distribution::HashShard(self.group_key.clone())
+        }
+    }
+
+    /// Expands the top-n of each group into `Project - Filter - OverAgg`, which numbers the rows
+    /// of each group with `row_number()` and keeps the rows in `(offset, offset + limit]`.
+    fn expand_to_over_agg(&self) -> PlanRef {
+        let input_len = self.input.schema().len();
+        let row_number = PlanWindowFunction {
+            function_type: WindowFunctionType::RowNumber,
+            return_type: DataType::Int64,
+            args: vec![],
+            offset: 0,
+            frame: WindowFrame::range_to_current_row(),
+        };
+        let over_agg = LogicalOverAgg::new(
+            vec![row_number],
+            self.group_key.clone(),
+            self.order.field_order.clone(),
+            self.input.clone(),
+        );
+
+        let row_number_ref: ExprImpl = InputRef::new(input_len, DataType::Int64).into();
+        let bound = |expr_type, value: usize| -> ExprImpl {
+            let value = ScalarImpl::Int64(value.min(i64::MAX as usize) as i64);
+            FunctionCall::new(
+                expr_type,
+                vec![
+                    row_number_ref.clone(),
+                    Literal::new(Some(value), DataType::Int64).into(),
+                ],
+            )
+            .unwrap()
+            .into()
+        };
+        let mut conjunctions = vec![bound(
+            ExprType::LessThanOrEqual,
+            self.offset.saturating_add(self.limit),
+        )];
+        if self.offset > 0 {
+            conjunctions.push(bound(ExprType::GreaterThan, self.offset));
+        }
+        let filter = LogicalFilter::create(over_agg.into(), Condition { conjunctions });
+
+        let exprs = self
+            .input
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        let expr_alias = self
+            .input
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| Some(field.name.clone()))
+            .collect();
+        LogicalProject::create(filter, exprs, expr_alias)
     }
 }
 
@@ -58,7 +185,13 @@ impl PlanTreeNodeUnary for LogicalTopN {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        Self::new(input, self.limit, self.offset, self.order.clone())
+        Self::new(
+            input,
+            self.limit,
+            self.offset,
+            self.order.clone(),
+            self.group_key.clone(),
+        )
     }
 
     #[must_use]
@@ -75,6 +208,10 @@ impl PlanTreeNodeUnary for LogicalTopN {
                 input_col_change
                     .rewrite_required_order(&self.order)
                     .unwrap(),
+                self.group_key
+                    .iter()
+                    .map(|idx| input_col_change.map(*idx))
+                    .collect(),
             ),
             input_col_change,
         )
@@ -82,8 +219,8 @@ impl PlanTreeNodeUnary for LogicalTopN {
 }
 impl_plan_tree_node_for_unary! {LogicalTopN}
 impl fmt::Display for LogicalTopN {
-    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalTopN")
     }
 }
 
@@ -96,6 +233,7 @@ impl ColPrunable for LogicalTopN {
             .field_order
             .iter()
             .for_each(|fo| input_required_cols.insert(fo.index));
+        input_required_cols.extend(self.group_key.iter().copied());
 
         let mapping = ColIndexMapping::with_remaining_columns(&input_required_cols);
         let new_order = Order {
@@ -109,8 +247,9 @@ impl ColPrunable for LogicalTopN {
                 })
                 .collect(),
         };
-        let new_input = self.input.prune_col(required_cols);
-        let top_n = Self::new(new_input, self.limit, self.offset, new_order).into();
+        let new_group_key = self.group_key.iter().map(|idx| mapping.map(*idx)).collect();
+        let new_input = self.input.prune_col(&input_required_cols);
+        let top_n = Self::new(new_input, self.limit, self.offset, new_order, new_group_key).into();
 
         if *required_cols == input_required_cols {
             top_n
@@ -127,18 +266,26 @@ impl ColPrunable for LogicalTopN {
 
 impl ToBatch for LogicalTopN {
     fn to_batch(&self) -> PlanRef {
-        todo!()
+        if !self.group_key.is_empty() {
+            return self.expand_to_over_agg().to_batch();
+        }
+        let new_input = self.input().to_batch_with_order_required(&self.order);
+        BatchLimit::new(LogicalLimit::new(new_input, self.limit, self.offset)).into()
     }
 }
 
 impl ToStream for LogicalTopN {
-    fn to_stream(&self) -> PlanRef {
-        todo!()
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self
+            .input()
+            .to_stream_with_dist_required(&self.required_input_distribution())?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamTopN::new(new_logical).into())
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
-        let (input, input_col_change) = self.input.logical_rewrite_for_stream();
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
         let (top_n, out_col_change) = self.rewrite_with_input(input, input_col_change);
-        (top_n.into(), out_col_change)
+        Ok((top_n.into(), out_col_change))
     }
 }
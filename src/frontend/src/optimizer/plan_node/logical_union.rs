@@ -0,0 +1,181 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::error::Result;
+use smallvec::SmallVec;
+
+use super::{
+    BatchUnion, ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef, PlanTreeNode,
+    StreamUnion, ToBatch, ToStream,
+};
+use crate::expr::{ExprImpl, InputRef, Literal};
+use crate::optimizer::property::Distribution;
+use crate::utils::ColIndexMapping;
+
+/// `LogicalUnion` returns all rows of its inputs, i.e. `UNION ALL`. Duplicates are not removed,
+/// and a `UNION` without `ALL` is planned as a [`super::LogicalAgg`] grouping by all columns on
+/// top of it.
+///
+/// All inputs must have the same column types. The output columns are named after the first
+/// input.
+#[derive(Debug, Clone)]
+pub struct LogicalUnion {
+    pub base: PlanBase,
+    inputs: Vec<PlanRef>,
+}
+
+impl LogicalUnion {
+    pub fn new(inputs: Vec<PlanRef>) -> Self {
+        Self::with_pk_indices(inputs, vec![])
+    }
+
+    pub(crate) fn with_pk_indices(inputs: Vec<PlanRef>, pk_indices: Vec<usize>) -> Self {
+        assert!(!inputs.is_empty());
+        let ctx = inputs[0].ctx();
+        let schema = inputs[0].schema().clone();
+        assert!(inputs
+            .iter()
+            .all(|input| input.schema().data_types() == schema.data_types()));
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalUnion { base, inputs }
+    }
+
+    pub fn create(inputs: Vec<PlanRef>) -> PlanRef {
+        Self::new(inputs).into()
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(f, "{}", name)
+    }
+}
+
+impl PlanTreeNode for LogicalUnion {
+    fn inputs(&self) -> SmallVec<[PlanRef; 2]> {
+        self.inputs.iter().cloned().collect()
+    }
+
+    fn clone_with_inputs(&self, inputs: &[PlanRef]) -> PlanRef {
+        Self::with_pk_indices(inputs.to_vec(), self.pk_indices().to_vec()).into()
+    }
+}
+
+impl fmt::Display for LogicalUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalUnion")
+    }
+}
+
+impl ColPrunable for LogicalUnion {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.prune_col(required_cols))
+            .collect();
+        Self::new(inputs).into()
+    }
+}
+
+impl ToBatch for LogicalUnion {
+    fn to_batch(&self) -> PlanRef {
+        let inputs = self.inputs.iter().map(|input| input.to_batch()).collect();
+        BatchUnion::new(Self::new(inputs)).into()
+    }
+}
+
+impl ToStream for LogicalUnion {
+    fn to_stream(&self) -> Result<PlanRef> {
+        // Rows with the same pk always come from the same input, so shuffling every input by the
+        // pk keeps all changes of a row in the same actor.
+        let dist = Distribution::HashShard(self.pk_indices().to_vec());
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.to_stream_with_dist_required(&dist))
+            .try_collect()?;
+        Ok(StreamUnion::new(Self::with_pk_indices(inputs, self.pk_indices().to_vec())).into())
+    }
+
+    /// The pk of the union is formed by the pks of all inputs together with the index of the
+    /// input, so that rows from different inputs never conflict. Each input is projected to
+    ///
+    /// ```text
+    /// [original columns.., pk of input 0.., pk of input 1.., .., input index]
+    /// ```
+    ///
+    /// where the pk columns of the other inputs are filled with `NULL`.
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let rewritten = self
+            .inputs
+            .iter()
+            .map(|input| input.logical_rewrite_for_stream())
+            .try_collect::<_, Vec<_>, _>()?;
+
+        let pk_types = rewritten
+            .iter()
+            .map(|(input, _)| {
+                input
+                    .pk_indices()
+                    .iter()
+                    .map(|i| input.schema().fields()[*i].data_type())
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let column_num = self.schema().len();
+        let inputs = rewritten
+            .iter()
+            .enumerate()
+            .map(|(input_idx, (input, col_change))| {
+                let (exprs, expr_alias): (Vec<ExprImpl>, Vec<_>) = self
+                    .schema()
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let expr = InputRef::new(col_change.map(i), field.data_type()).into();
+                        (expr, Some(field.name.clone()))
+                    })
+                    .chain(pk_types.iter().enumerate().flat_map(|(j, types)| {
+                        types.iter().enumerate().map(move |(k, ty)| {
+                            let expr = if j == input_idx {
+                                InputRef::new(input.pk_indices()[k], ty.clone()).into()
+                            } else {
+                                Literal::new(None, ty.clone()).into()
+                            };
+                            (expr, None)
+                        })
+                    }))
+                    .chain(std::iter::once((
+                        ExprImpl::literal_int(input_idx as i32),
+                        None,
+                    )))
+                    .unzip();
+                LogicalProject::create(input.clone(), exprs, expr_alias)
+            })
+            .collect_vec();
+
+        let output_len = inputs[0].schema().len();
+        let union = Self::with_pk_indices(inputs, (column_num..output_len).collect());
+        let out_col_change =
+            ColIndexMapping::with_target_size((0..column_num).map(Some).collect(), output_len);
+        Ok((union.into(), out_col_change))
+    }
+}
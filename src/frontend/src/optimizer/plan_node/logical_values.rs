@@ -17,6 +17,7 @@ use std::{fmt, vec};
 
 use fixedbitset::FixedBitSet;
 use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result};
 
 use super::{BatchValues, ColPrunable, PlanBase, PlanNode, PlanRef, ToBatch, ToStream};
 use crate::expr::{Expr, ExprImpl};
@@ -91,12 +92,18 @@ impl ToBatch for LogicalValues {
 }
 
 impl ToStream for LogicalValues {
-    fn to_stream(&self) -> PlanRef {
-        unimplemented!("Stream values executor is unimplemented!")
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(
+            ErrorCode::NotImplemented("VALUES in streaming queries".to_string(), None.into())
+                .into(),
+        )
     }
 
-    fn logical_rewrite_for_stream(&self) -> (PlanRef, crate::utils::ColIndexMapping) {
-        unimplemented!("Stream values executor is unimplemented!")
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, crate::utils::ColIndexMapping)> {
+        Err(
+            ErrorCode::NotImplemented("VALUES in streaming queries".to_string(), None.into())
+                .into(),
+        )
     }
 }
 
@@ -210,71 +210,117 @@ pub use to_prost::*;
 
 mod batch_delete;
 mod batch_exchange;
+mod batch_expand;
 mod batch_filter;
 mod batch_hash_agg;
 mod batch_hash_join;
+mod batch_hop_window;
 mod batch_insert;
 mod batch_limit;
+mod batch_nested_loop_join;
+mod batch_over_agg;
 mod batch_project;
+mod batch_project_set;
 mod batch_seq_scan;
 mod batch_simple_agg;
 mod batch_sort;
+mod batch_table_function;
+mod batch_union;
+mod batch_update;
 mod batch_values;
 mod logical_agg;
 mod logical_apply;
 mod logical_delete;
+mod logical_expand;
 mod logical_filter;
+mod logical_hop_window;
 mod logical_insert;
 mod logical_join;
 mod logical_limit;
+mod logical_over_agg;
 mod logical_project;
+mod logical_project_set;
 mod logical_scan;
 mod logical_source;
+mod logical_table_function;
 mod logical_topn;
+mod logical_union;
+mod logical_update;
 mod logical_values;
 mod stream_exchange;
+mod stream_expand;
 mod stream_filter;
 mod stream_hash_agg;
 mod stream_hash_join;
+mod stream_hop_window;
+mod stream_lookup_join;
 mod stream_materialize;
+mod stream_over_agg;
 mod stream_project;
+mod stream_project_set;
 mod stream_simple_agg;
+mod stream_sink;
 mod stream_source;
 mod stream_table_scan;
+mod stream_topn;
+mod stream_union;
 
 pub use batch_delete::BatchDelete;
 pub use batch_exchange::BatchExchange;
+pub use batch_expand::BatchExpand;
 pub use batch_filter::BatchFilter;
 pub use batch_hash_agg::BatchHashAgg;
 pub use batch_hash_join::BatchHashJoin;
+pub use batch_hop_window::BatchHopWindow;
 pub use batch_insert::BatchInsert;
 pub use batch_limit::BatchLimit;
+pub use batch_nested_loop_join::BatchNestedLoopJoin;
+pub use batch_over_agg::BatchOverAgg;
 pub use batch_project::BatchProject;
+pub use batch_project_set::BatchProjectSet;
 pub use batch_seq_scan::BatchSeqScan;
 pub use batch_simple_agg::BatchSimpleAgg;
 pub use batch_sort::BatchSort;
+pub use batch_table_function::BatchTableFunction;
+pub use batch_union::BatchUnion;
+pub use batch_update::BatchUpdate;
 pub use batch_values::BatchValues;
 pub use logical_agg::{LogicalAgg, PlanAggCall};
 pub use logical_apply::LogicalApply;
 pub use logical_delete::LogicalDelete;
+pub use logical_expand::LogicalExpand;
 pub use logical_filter::LogicalFilter;
+pub use logical_hop_window::LogicalHopWindow;
 pub use logical_insert::LogicalInsert;
 pub use logical_join::LogicalJoin;
 pub use logical_limit::LogicalLimit;
+pub use logical_over_agg::{LogicalOverAgg, PlanWindowFunction};
 pub use logical_project::LogicalProject;
+pub use logical_project_set::LogicalProjectSet;
 pub use logical_scan::LogicalScan;
 pub use logical_source::LogicalSource;
+pub use logical_table_function::LogicalTableFunction;
 pub use logical_topn::LogicalTopN;
+pub use logical_union::LogicalUnion;
+pub use logical_update::LogicalUpdate;
 pub use logical_values::LogicalValues;
 pub use stream_exchange::StreamExchange;
+pub use stream_expand::StreamExpand;
 pub use stream_filter::StreamFilter;
 pub use stream_hash_agg::StreamHashAgg;
 pub use stream_hash_join::StreamHashJoin;
+pub use stream_hop_window::StreamHopWindow;
+pub use stream_lookup_join::StreamLookupJoin;
 pub use stream_materialize::StreamMaterialize;
+pub use stream_over_agg::StreamOverAgg;
 pub use stream_project::StreamProject;
+pub use stream_project_set::StreamProjectSet;
 pub use stream_simple_agg::StreamSimpleAgg;
+pub use stream_sink::StreamSink;
 pub use stream_source::StreamSource;
 pub use stream_table_scan::StreamTableScan;
+pub use stream_topn::StreamTopN;
+pub use stream_union::StreamUnion;
 
 use crate::session::OptimizerContextRef;
 
@@ -303,10 +349,17 @@ macro_rules! for_all_plan_nodes {
             ,{ Logical, Source }
             ,{ Logical, Insert }
             ,{ Logical, Delete }
+            ,{ Logical, Update }
             ,{ Logical, Join }
             ,{ Logical, Values }
             ,{ Logical, Limit }
             ,{ Logical, TopN }
+            ,{ Logical, Union }
+            ,{ Logical, HopWindow }
+            ,{ Logical, Expand }
+            ,{ Logical, OverAgg }
+            ,{ Logical, TableFunction }
+            ,{ Logical, ProjectSet }
             // ,{ Logical, Sort } we don't need a LogicalSort, just require the Order
             ,{ Batch, SimpleAgg }
             ,{ Batch, HashAgg }
@@ -314,12 +367,20 @@ macro_rules! for_all_plan_nodes {
             ,{ Batch, Filter }
             ,{ Batch, Insert }
             ,{ Batch, Delete }
+            ,{ Batch, Update }
             ,{ Batch, SeqScan }
             ,{ Batch, HashJoin }
+            ,{ Batch, NestedLoopJoin }
             ,{ Batch, Values }
             ,{ Batch, Sort }
             ,{ Batch, Exchange }
             ,{ Batch, Limit }
+            ,{ Batch, Union }
+            ,{ Batch, HopWindow }
+            ,{ Batch, Expand }
+            ,{ Batch, OverAgg }
+            ,{ Batch, TableFunction }
+            ,{ Batch, ProjectSet }
             ,{ Stream, Project }
             ,{ Stream, Filter }
             ,{ Stream, TableScan }
@@ -329,6 +390,14 @@ macro_rules! for_all_plan_nodes {
             ,{ Stream, HashAgg }
             ,{ Stream, SimpleAgg }
             ,{ Stream, Materialize }
+            ,{ Stream, Union }
+            ,{ Stream, HopWindow }
+            ,{ Stream, Expand }
+            ,{ Stream, OverAgg }
+            ,{ Stream, ProjectSet }
+            ,{ Stream, Sink }
+            ,{ Stream, LookupJoin }
+            ,{ Stream, TopN }
         }
     };
 }
@@ -346,10 +415,17 @@ macro_rules! for_logical_plan_nodes {
             ,{ Logical, Source }
             ,{ Logical, Insert }
             ,{ Logical, Delete }
+            ,{ Logical, Update }
             ,{ Logical, Join }
             ,{ Logical, Values }
             ,{ Logical, Limit }
             ,{ Logical, TopN }
+            ,{ Logical, Union }
+            ,{ Logical, HopWindow }
+            ,{ Logical, Expand }
+            ,{ Logical, OverAgg }
+            ,{ Logical, TableFunction }
+            ,{ Logical, ProjectSet }
             // ,{ Logical, Sort} not sure if we will support Order by clause in subquery/view/MV
             // if we dont support thatk, we don't need LogicalSort, just require the Order at the top of query
         }
@@ -368,12 +444,20 @@ macro_rules! for_batch_plan_nodes {
             ,{ Batch, Filter }
             ,{ Batch, SeqScan }
             ,{ Batch, HashJoin }
+            ,{ Batch, NestedLoopJoin }
             ,{ Batch, Values }
             ,{ Batch, Limit }
             ,{ Batch, Sort }
             ,{ Batch, Exchange }
             ,{ Batch, Insert }
             ,{ Batch, Delete }
+            ,{ Batch, Update }
+            ,{ Batch, Union }
+            ,{ Batch, HopWindow }
+            ,{ Batch, Expand }
+            ,{ Batch, OverAgg }
+            ,{ Batch, TableFunction }
+            ,{ Batch, ProjectSet }
         }
     };
 }
@@ -393,6 +477,14 @@ macro_rules! for_stream_plan_nodes {
             ,{ Stream, HashAgg }
             ,{ Stream, SimpleAgg }
             ,{ Stream, Materialize }
+            ,{ Stream, Union }
+            ,{ Stream, HopWindow }
+            ,{ Stream, Expand }
+            ,{ Stream, OverAgg }
+            ,{ Stream, ProjectSet }
+            ,{ Stream, Sink }
+            ,{ Stream, LookupJoin }
+            ,{ Stream, TopN }
         }
     };
 }
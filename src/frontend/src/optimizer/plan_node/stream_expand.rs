@@ -0,0 +1,81 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_pb::stream_plan::expand_node::Subset;
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+use risingwave_pb::stream_plan::ExpandNode;
+
+use super::{LogicalExpand, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
+
+/// [`StreamExpand`] expands every row once for each column subset, keeping the ops of the input.
+#[derive(Debug, Clone)]
+pub struct StreamExpand {
+    pub base: PlanBase,
+    logical: LogicalExpand,
+}
+
+impl StreamExpand {
+    pub fn new(logical: LogicalExpand) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let pk_indices = logical.base.pk_indices.to_vec();
+        let input = logical.input();
+        let dist = logical
+            .i2o_col_mapping()
+            .rewrite_provided_distribution(input.distribution());
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            dist,
+            input.append_only(),
+        );
+        Self { base, logical }
+    }
+}
+
+impl fmt::Display for StreamExpand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "StreamExpand")
+    }
+}
+
+impl PlanTreeNodeUnary for StreamExpand {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { StreamExpand }
+
+impl ToStreamProst for StreamExpand {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::ExpandNode(ExpandNode {
+            column_subsets: self
+                .logical
+                .column_subsets()
+                .iter()
+                .map(|subset| Subset {
+                    column_indices: subset.iter().map(|i| *i as u32).collect_vec(),
+                })
+                .collect(),
+        })
+    }
+}
@@ -68,6 +68,11 @@ impl StreamHashJoin {
         }
     }
 
+    /// Get a reference to the stream hash join's logical.
+    pub fn logical(&self) -> &LogicalJoin {
+        &self.logical
+    }
+
     /// Get a reference to the batch hash join's eq join predicate.
     pub fn eq_join_predicate(&self) -> &EqJoinPredicate {
         &self.eq_join_predicate
@@ -137,6 +142,7 @@ impl ToStreamProst for StreamHashJoin {
                 .iter()
                 .map(|v| *v as i32)
                 .collect(),
+            null_safe: self.eq_join_predicate.null_safes(),
             condition: self
                 .eq_join_predicate
                 .other_cond()
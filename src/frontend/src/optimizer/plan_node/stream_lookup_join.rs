@@ -0,0 +1,190 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_pb::expr::InputRefExpr;
+use risingwave_pb::plan::{ColumnOrder, JoinType, TableRefId};
+use risingwave_pb::stream_plan::stream_node::Node;
+use risingwave_pb::stream_plan::LookupNode;
+
+use super::{LogicalJoin, LogicalScan, PlanBase, PlanRef, PlanTreeNodeBinary, ToStreamProst};
+use crate::optimizer::plan_node::EqJoinPredicate;
+
+/// `StreamLookupJoin` implements an inner [`super::LogicalJoin`] by looking up the matched rows of
+/// the right side in the storage of the materialized view it scans, instead of building a hash
+/// table for it. The right input only delivers the changes of the materialized view, and is used
+/// to align the barriers.
+///
+/// The materialized view must be arranged by the join key, i.e. the join key must be a prefix of
+/// its primary key. See [`crate::optimizer::rule::DeltaJoinRule`] for how it is planned.
+#[derive(Debug, Clone)]
+pub struct StreamLookupJoin {
+    pub base: PlanBase,
+    logical: LogicalJoin,
+
+    /// The join condition must be equivalent to `logical.on`, and must not have non-equal parts.
+    eq_join_predicate: EqJoinPredicate,
+
+    /// The scan of the materialized view to lookup.
+    arrangement: LogicalScan,
+
+    /// Whether to lookup the materialized view of the current epoch, instead of the previous one.
+    use_current_epoch: bool,
+}
+
+impl StreamLookupJoin {
+    pub fn new(
+        logical: LogicalJoin,
+        eq_join_predicate: EqJoinPredicate,
+        arrangement: LogicalScan,
+        use_current_epoch: bool,
+    ) -> Self {
+        assert_eq!(logical.join_type(), JoinType::Inner);
+        assert!(!eq_join_predicate.has_non_eq());
+        assert!(Self::arrange_key(&arrangement, &eq_join_predicate.right_eq_indexes()).is_some());
+        // The lookup executor compares the join keys with `=` only.
+        assert!(!eq_join_predicate.has_null_safe_eq());
+
+        let ctx = logical.base.ctx.clone();
+        let append_only = logical.left().append_only() && logical.right().append_only();
+        // The output is partitioned in the same way as the stream side.
+        let dist = logical
+            .l2o_col_mapping()
+            .rewrite_provided_distribution(logical.left().distribution());
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            logical.base.pk_indices.to_vec(),
+            dist,
+            append_only,
+        );
+
+        Self {
+            base,
+            logical,
+            eq_join_predicate,
+            arrangement,
+            use_current_epoch,
+        }
+    }
+
+    /// Returns the positions of `key`, which are column indexes of `scan`, in the primary key of
+    /// the scanned table, if they make up a prefix of the primary key. Only in this case can the
+    /// table be looked up by the key.
+    pub fn arrange_key(scan: &LogicalScan, key: &[usize]) -> Option<Vec<usize>> {
+        let column_descs = scan.column_descs();
+        let pk = &scan.table_desc().pk;
+        let arrange_key = key
+            .iter()
+            .map(|idx| {
+                let column_id = column_descs[*idx].column_id;
+                pk.iter()
+                    .position(|col| col.column_desc.column_id == column_id)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut positions = arrange_key.clone();
+        positions.sort_unstable();
+        positions
+            .into_iter()
+            .enumerate()
+            .all(|(i, pos)| i == pos)
+            .then(|| arrange_key)
+    }
+
+    /// Get a reference to the stream lookup join's eq join predicate.
+    pub fn eq_join_predicate(&self) -> &EqJoinPredicate {
+        &self.eq_join_predicate
+    }
+}
+
+impl fmt::Display for StreamLookupJoin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StreamLookupJoin {{ type: {:?}, predicate: {}, arrangement: {}, use_current_epoch: {} }}",
+            self.logical.join_type(),
+            self.eq_join_predicate(),
+            self.arrangement.table_name(),
+            self.use_current_epoch
+        )
+    }
+}
+
+impl PlanTreeNodeBinary for StreamLookupJoin {
+    fn left(&self) -> PlanRef {
+        self.logical.left()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(
+            self.logical.clone_with_left_right(left, right),
+            self.eq_join_predicate.clone(),
+            self.arrangement.clone(),
+            self.use_current_epoch,
+        )
+    }
+}
+
+impl_plan_tree_node_for_binary! { StreamLookupJoin }
+
+impl ToStreamProst for StreamLookupJoin {
+    fn to_stream_prost_body(&self) -> Node {
+        let table_desc = self.arrangement.table_desc();
+        let arrange_key = Self::arrange_key(
+            &self.arrangement,
+            &self.eq_join_predicate.right_eq_indexes(),
+        )
+        .unwrap();
+
+        Node::LookupNode(LookupNode {
+            stream_key: self
+                .eq_join_predicate
+                .left_eq_indexes()
+                .iter()
+                .map(|v| *v as i32)
+                .collect(),
+            arrange_key: arrange_key.iter().map(|v| *v as i32).collect(),
+            use_current_epoch: self.use_current_epoch,
+            arrangement_table_ref_id: Some(TableRefId {
+                table_id: table_desc.table_id.table_id as i32,
+                schema_ref_id: None, // TODO: fill schema ref id
+            }),
+            arrangement_column_descs: self
+                .arrangement
+                .column_descs()
+                .iter()
+                .map(|col| col.to_protobuf())
+                .collect(),
+            arrangement_order: table_desc
+                .pk
+                .iter()
+                .zip_eq(self.arrangement.base.pk_indices.iter())
+                .map(|(col, idx)| ColumnOrder {
+                    order_type: col.order.to_prost() as i32,
+                    input_ref: Some(InputRefExpr {
+                        column_idx: *idx as i32,
+                    }),
+                    return_type: Some(col.column_desc.data_type.to_protobuf()),
+                })
+                .collect_vec(),
+        })
+    }
+}
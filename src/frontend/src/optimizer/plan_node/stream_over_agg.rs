@@ -0,0 +1,86 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+use risingwave_pb::stream_plan::OverAggNode;
+
+use super::{
+    LogicalOverAgg, PlanBase, PlanRef, PlanTreeNodeUnary, PlanWindowFunction, ToStreamProst,
+};
+
+/// [`StreamOverAgg`] maintains the window functions of each partition incrementally. The rows of
+/// every partition are kept in the state store.
+#[derive(Debug, Clone)]
+pub struct StreamOverAgg {
+    pub base: PlanBase,
+    logical: LogicalOverAgg,
+}
+
+impl StreamOverAgg {
+    pub fn new(logical: LogicalOverAgg) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let pk_indices = logical.base.pk_indices.to_vec();
+        let input = logical.input();
+        // A change of one row may change the window function results of other rows in the same
+        // partition, so the output is never append-only.
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            input.distribution().clone(),
+            false,
+        );
+        Self { base, logical }
+    }
+}
+
+impl fmt::Display for StreamOverAgg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "StreamOverAgg")
+    }
+}
+
+impl PlanTreeNodeUnary for StreamOverAgg {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { StreamOverAgg }
+
+impl ToStreamProst for StreamOverAgg {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::OverAggNode(OverAggNode {
+            partition_by: self
+                .logical
+                .partition_by()
+                .iter()
+                .map(|i| *i as u32)
+                .collect(),
+            order_by: self.logical.order_by_to_protobuf(),
+            window_functions: self
+                .logical
+                .window_functions()
+                .iter()
+                .map(PlanWindowFunction::to_protobuf)
+                .collect(),
+        })
+    }
+}
@@ -18,7 +18,7 @@ use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
 use risingwave_pb::stream_plan::ProjectNode;
 
 use super::{LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
-use crate::expr::Expr;
+use crate::expr::{Expr, ExprImpl, ExprType};
 
 /// `StreamProject` implements [`super::LogicalProject`] to evaluate specified expressions on input
 /// rows.
@@ -68,8 +68,40 @@ impl_plan_tree_node_for_unary! {StreamProject}
 
 impl ToStreamProst for StreamProject {
     fn to_stream_prost_body(&self) -> ProstStreamNode {
+        let (watermark_input_cols, watermark_output_cols) = self
+            .logical
+            .exprs()
+            .iter()
+            .enumerate()
+            .filter_map(|(output_idx, expr)| {
+                derive_watermark_input(expr).map(|input_idx| (input_idx as u32, output_idx as u32))
+            })
+            .unzip();
         ProstStreamNode::ProjectNode(ProjectNode {
             select_list: self.logical.exprs().iter().map(Expr::to_protobuf).collect(),
+            watermark_input_cols,
+            watermark_output_cols,
         })
     }
 }
+
+/// Returns the input column of `expr` if `expr` is a non-decreasing function of that single
+/// column, so that a watermark on the input column can be translated to the output one.
+fn derive_watermark_input(expr: &ExprImpl) -> Option<usize> {
+    match expr {
+        ExprImpl::InputRef(input_ref) => Some(input_ref.index()),
+        ExprImpl::FunctionCall(func_call) => match func_call.get_expr_type() {
+            ExprType::Add => match func_call.inputs() {
+                [lhs, rhs] if rhs.is_const() => derive_watermark_input(lhs),
+                [lhs, rhs] if lhs.is_const() => derive_watermark_input(rhs),
+                _ => None,
+            },
+            ExprType::Subtract | ExprType::TumbleStart => match func_call.inputs() {
+                [lhs, rhs] if rhs.is_const() => derive_watermark_input(lhs),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -55,10 +55,20 @@ impl fmt::Display for StreamSource {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "StreamSource {{ source: {},  columns: [{}] }}",
+            "StreamSource {{ source: {},  columns: [{}]",
             self.logical.source_catalog.name,
             self.column_names().join(", ")
-        )
+        )?;
+        let source_catalog = &self.logical.source_catalog;
+        if let Some(watermark_col_id) = source_catalog.watermark_col_id {
+            let watermark_col = source_catalog
+                .columns
+                .iter()
+                .find(|c| c.column_id() == watermark_col_id)
+                .unwrap();
+            write!(f, ", watermark: {}", watermark_col.name())?;
+        }
+        write!(f, " }}")
     }
 }
 
@@ -30,6 +30,8 @@ pub struct StreamTableScan {
     pub base: PlanBase,
     logical: LogicalScan,
     batch_plan_id: PlanNodeId,
+    /// Whether to only deliver the changes of the table, without its snapshot.
+    upstream_only: bool,
 }
 
 impl StreamTableScan {
@@ -49,12 +51,31 @@ impl StreamTableScan {
             base,
             logical,
             batch_plan_id,
+            upstream_only: false,
+        }
+    }
+
+    /// Create a [`StreamTableScan`] delivering only the changes of the table, e.g. for the
+    /// arrangement side of a [`super::StreamLookupJoin`].
+    pub fn new_upstream_only(logical: LogicalScan) -> Self {
+        Self {
+            upstream_only: true,
+            ..Self::new(logical)
         }
     }
 
     pub fn table_name(&self) -> &str {
         self.logical.table_name()
     }
+
+    /// Get a reference to the stream table scan's logical.
+    pub fn logical(&self) -> &LogicalScan {
+        &self.logical
+    }
+
+    pub fn upstream_only(&self) -> bool {
+        self.upstream_only
+    }
 }
 impl_plan_tree_node_for_leaf! { StreamTableScan }
 
@@ -62,11 +83,15 @@ impl fmt::Display for StreamTableScan {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "StreamTableScan {{ table: {}, columns: [{}], pk_indices: {:?} }}",
+            "StreamTableScan {{ table: {}, columns: [{}], pk_indices: {:?}",
             self.logical.table_name(),
             self.logical.column_names().join(", "),
             self.base.pk_indices
-        )
+        )?;
+        if self.upstream_only {
+            write!(f, ", upstream_only: true")?;
+        }
+        write!(f, " }}")
     }
 }
 
@@ -153,6 +178,7 @@ impl StreamTableScan {
                     .iter()
                     .map(|x| x.column_id.get_id())
                     .collect(),
+                upstream_only: self.upstream_only,
             })),
             pk_indices,
             operator_id: if auto_fields {
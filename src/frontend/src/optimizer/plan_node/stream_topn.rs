@@ -0,0 +1,112 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+use risingwave_pb::stream_plan::TopNNode;
+
+use super::{LogicalTopN, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
+use crate::optimizer::property::Direction;
+
+/// [`StreamTopN`] maintains the top-n rows of its input, or of each group of its input if the
+/// group key is not empty.
+#[derive(Debug, Clone)]
+pub struct StreamTopN {
+    pub base: PlanBase,
+    logical: LogicalTopN,
+}
+
+impl StreamTopN {
+    pub fn new(logical: LogicalTopN) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let input = logical.input();
+        // The executor keeps the rows ordered by the order keys followed by the input pk, which
+        // also identifies the output rows.
+        let mut pk_indices = vec![];
+        for idx in logical
+            .order()
+            .field_order
+            .iter()
+            .map(|field_order| field_order.index)
+            .chain(input.pk_indices().iter().copied())
+        {
+            if !pk_indices.contains(&idx) {
+                pk_indices.push(idx);
+            }
+        }
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            input.distribution().clone(),
+            false,
+        );
+        StreamTopN { base, logical }
+    }
+}
+
+impl fmt::Display for StreamTopN {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "StreamTopN")
+    }
+}
+
+impl PlanTreeNodeUnary for StreamTopN {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! { StreamTopN }
+
+impl ToStreamProst for StreamTopN {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        let field_order = &self.logical.order().field_order;
+        let order_types = self
+            .base
+            .pk_indices
+            .iter()
+            .map(|idx| {
+                field_order
+                    .iter()
+                    .find(|field_order| field_order.index == *idx)
+                    .map_or(Direction::Asc, |field_order| field_order.direct)
+                    .to_protobuf() as i32
+            })
+            .collect();
+        let distribution_keys = self
+            .logical
+            .group_key()
+            .iter()
+            .map(|idx| *idx as i32)
+            .collect::<Vec<_>>();
+        let top_n_node = TopNNode {
+            order_types,
+            limit: self.logical.limit() as u64,
+            offset: self.logical.offset() as u64,
+            distribution_keys: distribution_keys.clone(),
+            group_keys: distribution_keys,
+        };
+        if self.logical.group_key().is_empty() {
+            ProstStreamNode::TopNNode(top_n_node)
+        } else {
+            ProstStreamNode::GroupTopNNode(top_n_node)
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+use risingwave_pb::stream_plan::UnionNode;
+use smallvec::SmallVec;
+
+use super::{LogicalUnion, PlanBase, PlanRef, PlanTreeNode, ToStreamProst};
+
+/// `StreamUnion` implements [`super::LogicalUnion`] by merging the changes of its inputs, with
+/// barriers aligned.
+#[derive(Debug, Clone)]
+pub struct StreamUnion {
+    pub base: PlanBase,
+    logical: LogicalUnion,
+    /// Whether the changes of each epoch are merged input by input, in the order of the inputs.
+    ordered: bool,
+}
+
+impl StreamUnion {
+    pub fn new(logical: LogicalUnion) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let inputs = logical.inputs();
+        // All inputs are shuffled by the pk of the union, see `LogicalUnion::to_stream`.
+        let dist = inputs[0].distribution().clone();
+        let append_only = inputs.iter().all(|input| input.append_only());
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            logical.pk_indices().to_vec(),
+            dist,
+            append_only,
+        );
+        StreamUnion {
+            base,
+            logical,
+            ordered: false,
+        }
+    }
+
+    /// Creates a `StreamUnion` whose changes of each epoch are merged input by input, e.g. for a
+    /// delta join whose updated rows may be deleted by one input and inserted by another.
+    pub fn new_ordered(logical: LogicalUnion) -> Self {
+        Self {
+            ordered: true,
+            ..Self::new(logical)
+        }
+    }
+}
+
+impl fmt::Display for StreamUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.ordered {
+            write!(f, "StreamUnion {{ ordered: true }}")
+        } else {
+            self.logical.fmt_with_name(f, "StreamUnion")
+        }
+    }
+}
+
+impl PlanTreeNode for StreamUnion {
+    fn inputs(&self) -> SmallVec<[PlanRef; 2]> {
+        self.logical.inputs()
+    }
+
+    fn clone_with_inputs(&self, inputs: &[PlanRef]) -> PlanRef {
+        let logical =
+            LogicalUnion::with_pk_indices(inputs.to_vec(), self.logical.pk_indices().to_vec());
+        Self {
+            ordered: self.ordered,
+            ..Self::new(logical)
+        }
+        .into()
+    }
+}
+
+impl ToStreamProst for StreamUnion {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::UnionNode(UnionNode {
+            ordered: self.ordered,
+        })
+    }
+}
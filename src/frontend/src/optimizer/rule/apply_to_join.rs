@@ -0,0 +1,605 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::iter::once;
+
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::AggKind;
+use risingwave_pb::plan::JoinType;
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::{
+    CorrelatedInputRef, Expr, ExprImpl, ExprRewriter, ExprType, ExprVisitor, InputRef,
+};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// Transforms a [`LogicalApply`] into a [`LogicalJoin`], by pulling the correlated predicates on
+/// its right side up into the join condition.
+///
+/// - `LeftSemi` and `LeftAnti` apply (`EXISTS` and set comparison subqueries): the correlated
+///   predicates are pulled up through [`LogicalFilter`], [`LogicalProject`], grouped [`LogicalAgg`]
+///   and [`LogicalTopN`] and become part of the join condition.
+/// - `LeftOuter` apply (scalar subqueries): the right side must be a simple [`LogicalAgg`] with
+///   optional [`LogicalProject`]s on top. The agg is evaluated once per left row by joining its
+///   input to the left side and grouping by all the left columns, so the left side must have a pk.
+///   `count(*)` is rewritten to count a non-null column of the right side, so that it is 0 rather
+///   than 1 for the left rows without matches.
+///
+/// The apply is left unchanged if its right side refers to relations further outside, or can not
+/// be decorrelated in the above ways.
+pub struct ApplyToJoinRule {}
+impl Rule for ApplyToJoinRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let apply = plan.as_logical_apply()?;
+        let left = apply.left();
+        let right = apply.right();
+        let join_type = apply.join_type();
+
+        let depths = correlated_depths(&right);
+        if depths.is_empty() {
+            // The correlated subqueries inside have been decorrelated.
+            return Some(LogicalJoin::new(left, right, join_type, apply.on().clone()).into());
+        }
+        if depths.iter().any(|depth| *depth != 1) {
+            return None;
+        }
+
+        match join_type {
+            JoinType::LeftSemi | JoinType::LeftAnti => {
+                let (right, preds) = pull_correlated_predicates(right)?;
+                let mut rewriter = Decorrelator {
+                    left_len: left.schema().len(),
+                };
+                let on = apply.on().clone().and(Condition {
+                    conjunctions: preds
+                        .into_iter()
+                        .map(|pred| rewriter.rewrite_expr(pred))
+                        .collect(),
+                });
+                Some(LogicalJoin::new(left, right, join_type, on).into())
+            }
+            JoinType::LeftOuter if apply.on().always_true() => decorrelate_simple_agg(left, right),
+            _ => None,
+        }
+    }
+}
+
+impl ApplyToJoinRule {
+    pub fn create() -> BoxedRule {
+        Box::new(ApplyToJoinRule {})
+    }
+}
+
+/// Decorrelates `Apply(left, [Project]* - Agg(input))` where the agg has no group keys, into
+/// `[Project]* - Agg(group by left columns) - Join(left, input)`.
+fn decorrelate_simple_agg(left: PlanRef, right: PlanRef) -> Option<PlanRef> {
+    if left.pk_indices().is_empty() {
+        return None;
+    }
+    let mut projects = vec![];
+    let mut plan = right;
+    while let Some(project) = plan.as_logical_project().cloned() {
+        plan = project.input();
+        projects.push(project);
+    }
+    let agg = plan.as_logical_agg()?;
+    if !agg.group_keys().is_empty() {
+        return None;
+    }
+    let (input, preds) = pull_correlated_predicates(agg.input())?;
+
+    // Append a non-null column, which is NULL only for the left rows without matches.
+    let marker = input.schema().len();
+    let exprs = input_refs(&input)
+        .chain(once(ExprImpl::literal_bool(true)))
+        .collect();
+    let input = LogicalProject::create(input, exprs, vec![None; marker + 1]);
+
+    let left_len = left.schema().len();
+    let mut rewriter = Decorrelator { left_len };
+    let on = Condition {
+        conjunctions: preds
+            .into_iter()
+            .map(|pred| rewriter.rewrite_expr(pred))
+            .collect(),
+    };
+    let join = LogicalJoin::new(left.clone(), input, JoinType::LeftOuter, on);
+
+    let agg_calls = agg
+        .agg_calls()
+        .iter()
+        .map(|agg_call| {
+            let mut agg_call = agg_call.clone();
+            if agg_call.agg_kind == AggKind::Count && agg_call.inputs.is_empty() {
+                agg_call.inputs = vec![InputRef::new(marker, DataType::Boolean)];
+            }
+            for input_ref in &mut agg_call.inputs {
+                *input_ref = InputRef::new(input_ref.index() + left_len, input_ref.return_type());
+            }
+            agg_call
+        })
+        .collect();
+    let mut plan: PlanRef = LogicalAgg::new(
+        agg_calls,
+        agg.agg_call_alias().to_vec(),
+        (0..left_len).collect(),
+        join.into(),
+    )
+    .into();
+
+    for project in projects.into_iter().rev() {
+        let exprs = input_refs(&left)
+            .chain(
+                project
+                    .exprs()
+                    .iter()
+                    .map(|expr| rewriter.rewrite_expr(expr.clone())),
+            )
+            .collect();
+        let expr_alias = vec![None; left_len]
+            .into_iter()
+            .chain(project.expr_alias().iter().cloned())
+            .collect();
+        plan = LogicalProject::create(plan, exprs, expr_alias);
+    }
+    Some(plan)
+}
+
+/// Removes the correlated predicates from `plan`, and returns the new plan together with the
+/// removed predicates.
+///
+/// The new plan outputs the columns of `plan`, followed by the extra columns required by the
+/// removed predicates, which refer to the new plan by [`InputRef`]s.
+fn pull_correlated_predicates(plan: PlanRef) -> Option<(PlanRef, Vec<ExprImpl>)> {
+    if correlated_depths(&plan).is_empty() {
+        return Some((plan, vec![]));
+    }
+
+    if let Some(filter) = plan.as_logical_filter() {
+        let (input, mut preds) = pull_correlated_predicates(filter.input())?;
+        let (correlated, others): (Vec<_>, Vec<_>) = filter
+            .predicate()
+            .conjunctions
+            .iter()
+            .cloned()
+            .partition(|expr| expr.has_correlated_input_ref());
+        preds.extend(correlated);
+        let plan = LogicalFilter::create(
+            input,
+            Condition {
+                conjunctions: others,
+            },
+        );
+        return Some((plan, preds));
+    }
+
+    if let Some(project) = plan.as_logical_project() {
+        if project
+            .exprs()
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref())
+        {
+            return None;
+        }
+        let (input, preds) = pull_correlated_predicates(project.input())?;
+        let input_len = input.schema().len();
+        let mut exprs = project.exprs().clone();
+        let mut expr_alias = project.expr_alias().to_vec();
+        let mut mapping = vec![None; input_len];
+        for pred in &preds {
+            for index in pred.collect_input_refs(input_len).ones() {
+                if mapping[index].is_none() {
+                    mapping[index] = Some(exprs.len());
+                    exprs.push(
+                        InputRef::new(index, input.schema().fields()[index].data_type()).into(),
+                    );
+                    expr_alias.push(None);
+                }
+            }
+        }
+        let mut mapping = ColIndexMapping::with_target_size(mapping, exprs.len());
+        let preds = preds
+            .into_iter()
+            .map(|pred| mapping.rewrite_expr(pred))
+            .collect();
+        return Some((LogicalProject::create(input, exprs, expr_alias), preds));
+    }
+
+    if let Some(agg) = plan.as_logical_agg() {
+        // A simple agg outputs a row even if its input is empty, which can not be kept after
+        // grouping by the correlated columns.
+        if agg.group_keys().is_empty() {
+            return None;
+        }
+        let (input, preds) = pull_correlated_predicates(agg.input())?;
+
+        // Only `column = correlated column` is allowed, and the column becomes a group key.
+        let mut group_keys = agg.group_keys().to_vec();
+        for pred in &preds {
+            let index = as_eq_to_correlated(pred)?;
+            if !group_keys.contains(&index) {
+                group_keys.push(index);
+            }
+        }
+        let num_group_keys = agg.group_keys().len();
+        let num_extra_keys = group_keys.len() - num_group_keys;
+        let num_agg_calls = agg.agg_calls().len();
+        let new_agg: PlanRef = LogicalAgg::new(
+            agg.agg_calls().to_vec(),
+            agg.agg_call_alias().to_vec(),
+            group_keys.clone(),
+            input.clone(),
+        )
+        .into();
+
+        // Move the extra group keys after the agg calls.
+        let exprs = (0..num_group_keys)
+            .chain(num_group_keys + num_extra_keys..new_agg.schema().len())
+            .chain(num_group_keys..num_group_keys + num_extra_keys)
+            .map(|index| InputRef::new(index, new_agg.schema().fields()[index].data_type()).into())
+            .collect();
+        let new_project =
+            LogicalProject::create(new_agg, exprs, vec![None; group_keys.len() + num_agg_calls]);
+
+        let mut mapping = vec![None; input.schema().len()];
+        for (i, index) in group_keys.iter().enumerate() {
+            mapping[*index] = Some(if i < num_group_keys {
+                i
+            } else {
+                i + num_agg_calls
+            });
+        }
+        let mut mapping =
+            ColIndexMapping::with_target_size(mapping, group_keys.len() + num_agg_calls);
+        let preds = preds
+            .into_iter()
+            .map(|pred| mapping.rewrite_expr(pred))
+            .collect();
+        return Some((new_project, preds));
+    }
+
+    if let Some(top_n) = plan.as_logical_top_n() {
+        let (input, preds) = pull_correlated_predicates(top_n.input())?;
+
+        // Only `column = correlated column` is allowed, and the column becomes a group key, so
+        // that the top-n is computed for each outer row. The output columns of the top-n are
+        // the same as its input.
+        let mut group_key = top_n.group_key().to_vec();
+        for pred in &preds {
+            let index = as_eq_to_correlated(pred)?;
+            if !group_key.contains(&index) {
+                group_key.push(index);
+            }
+        }
+        let new_top_n = LogicalTopN::create(
+            input,
+            top_n.limit(),
+            top_n.offset(),
+            top_n.order().clone(),
+            group_key,
+        );
+        return Some((new_top_n, preds));
+    }
+
+    None
+}
+
+/// Returns the input column index if `expr` is `column = correlated column`.
+fn as_eq_to_correlated(expr: &ExprImpl) -> Option<usize> {
+    let func_call = expr.as_function_call()?;
+    if func_call.get_expr_type() != ExprType::Equal {
+        return None;
+    }
+    match func_call.inputs() {
+        [ExprImpl::InputRef(input_ref), ExprImpl::CorrelatedInputRef(_)]
+        | [ExprImpl::CorrelatedInputRef(_), ExprImpl::InputRef(input_ref)] => {
+            Some(input_ref.index())
+        }
+        _ => None,
+    }
+}
+
+fn input_refs(plan: &PlanRef) -> impl Iterator<Item = ExprImpl> + '_ {
+    plan.schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+}
+
+/// Rewrites an expression on the right side of an apply to one on the output of the join.
+struct Decorrelator {
+    left_len: usize,
+}
+
+impl ExprRewriter for Decorrelator {
+    fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
+        InputRef::new(input_ref.index() + self.left_len, input_ref.return_type()).into()
+    }
+
+    fn rewrite_correlated_input_ref(&mut self, input_ref: CorrelatedInputRef) -> ExprImpl {
+        assert_eq!(input_ref.depth(), 1);
+        InputRef::new(input_ref.index(), input_ref.return_type()).into()
+    }
+}
+
+/// Returns the depths of the [`CorrelatedInputRef`]s in `plan` which refer to the relations
+/// outside of `plan`, relative to `plan`.
+fn correlated_depths(plan: &PlanRef) -> BTreeSet<usize> {
+    let mut collector = CollectCorrelatedDepths {
+        depths: BTreeSet::new(),
+        nesting: 0,
+    };
+    collector.visit_plan(plan);
+    collector.depths
+}
+
+struct CollectCorrelatedDepths {
+    depths: BTreeSet<usize>,
+    /// The number of [`LogicalApply`]s whose right side is being visited.
+    nesting: usize,
+}
+
+impl CollectCorrelatedDepths {
+    fn visit_plan(&mut self, plan: &PlanRef) {
+        if let Some(apply) = plan.as_logical_apply() {
+            self.visit_plan(&apply.left());
+            apply.on().visit_expr(self);
+            self.nesting += 1;
+            self.visit_plan(&apply.right());
+            self.nesting -= 1;
+            return;
+        }
+        if let Some(project) = plan.as_logical_project() {
+            project
+                .exprs()
+                .iter()
+                .for_each(|expr| self.visit_expr(expr));
+        } else if let Some(filter) = plan.as_logical_filter() {
+            filter.predicate().visit_expr(self);
+        } else if let Some(join) = plan.as_logical_join() {
+            join.on().visit_expr(self);
+        } else if let Some(values) = plan.as_logical_values() {
+            values
+                .rows()
+                .iter()
+                .flatten()
+                .for_each(|expr| self.visit_expr(expr));
+        }
+        plan.inputs()
+            .iter()
+            .for_each(|input| self.visit_plan(input));
+    }
+}
+
+impl ExprVisitor for CollectCorrelatedDepths {
+    fn visit_correlated_input_ref(&mut self, input_ref: &CorrelatedInputRef) {
+        if input_ref.depth() > self.nesting {
+            self.depths.insert(input_ref.depth() - self.nesting);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{Field, Schema};
+
+    use super::*;
+    use crate::expr::{assert_eq_input_ref, FunctionCall};
+    use crate::optimizer::property::{FieldOrder, Order};
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Decorrelate
+    /// ```text
+    /// Apply(type: LeftSemi)
+    ///   Values(v1)
+    ///   Filter(cond: input_ref(0) = correlated_input_ref(0))
+    ///     Values(v2)
+    /// ```
+    /// into
+    /// ```text
+    /// Join(type: LeftSemi, cond: input_ref(1) = input_ref(0))
+    ///   Values(v1)
+    ///   Values(v2)
+    /// ```
+    async fn test_semi_apply_to_join() {
+        let ctx = OptimizerContext::mock().await;
+        let left: PlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![Field::with_name(DataType::Int32, "v1")]),
+            ctx.clone(),
+        )
+        .into();
+        let right: PlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![Field::with_name(DataType::Int32, "v2")]),
+            ctx,
+        )
+        .into();
+        let predicate: ExprImpl = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                InputRef::new(0, DataType::Int32).into(),
+                CorrelatedInputRef::new(0, DataType::Int32, 1).into(),
+            ],
+        )
+        .unwrap()
+        .into();
+        let filter = LogicalFilter::create(right, Condition::with_expr(predicate));
+        let apply = LogicalApply::create(left, filter, JoinType::LeftSemi, Condition::true_cond());
+
+        let plan = ApplyToJoinRule {}.apply(apply).unwrap();
+
+        let join = plan.as_logical_join().unwrap();
+        assert_eq!(join.join_type(), JoinType::LeftSemi);
+        assert!(join.right().as_logical_values().is_some());
+        assert_eq!(join.on().conjunctions.len(), 1);
+        match &join.on().conjunctions[0] {
+            ExprImpl::FunctionCall(call) => {
+                assert_eq!(call.get_expr_type(), ExprType::Equal);
+                assert_eq_input_ref!(&call.inputs()[0], 1);
+                assert_eq_input_ref!(&call.inputs()[1], 0);
+            }
+            other => panic!("expected function call, found {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    /// Decorrelate
+    /// ```text
+    /// Apply(type: LeftSemi)
+    ///   Values(v1)
+    ///   TopN(order: [$1 ASC], limit: 1)
+    ///     Filter(cond: input_ref(0) = correlated_input_ref(0))
+    ///       Values(v2, v3)
+    /// ```
+    /// into
+    /// ```text
+    /// Join(type: LeftSemi, cond: input_ref(1) = input_ref(0))
+    ///   Values(v1)
+    ///   TopN(order: [$1 ASC], limit: 1, group_key: [$0])
+    ///     Values(v2, v3)
+    /// ```
+    async fn test_semi_apply_top_n_to_join() {
+        let ctx = OptimizerContext::mock().await;
+        let left: PlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![Field::with_name(DataType::Int32, "v1")]),
+            ctx.clone(),
+        )
+        .into();
+        let right: PlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![
+                Field::with_name(DataType::Int32, "v2"),
+                Field::with_name(DataType::Int32, "v3"),
+            ]),
+            ctx,
+        )
+        .into();
+        let predicate: ExprImpl = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                InputRef::new(0, DataType::Int32).into(),
+                CorrelatedInputRef::new(0, DataType::Int32, 1).into(),
+            ],
+        )
+        .unwrap()
+        .into();
+        let filter = LogicalFilter::create(right, Condition::with_expr(predicate));
+        let top_n = LogicalTopN::create(
+            filter,
+            1,
+            0,
+            Order::new(vec![FieldOrder::ascending(1)]),
+            vec![],
+        );
+        let apply = LogicalApply::create(left, top_n, JoinType::LeftSemi, Condition::true_cond());
+
+        let plan = ApplyToJoinRule {}.apply(apply).unwrap();
+
+        let join = plan.as_logical_join().unwrap();
+        assert_eq!(join.join_type(), JoinType::LeftSemi);
+        let right = join.right();
+        let top_n = right.as_logical_top_n().unwrap();
+        assert_eq!(top_n.group_key(), &[0]);
+        assert_eq!(top_n.limit(), 1);
+        assert!(top_n.input().as_logical_values().is_some());
+        assert_eq!(join.on().conjunctions.len(), 1);
+        match &join.on().conjunctions[0] {
+            ExprImpl::FunctionCall(call) => {
+                assert_eq!(call.get_expr_type(), ExprType::Equal);
+                assert_eq_input_ref!(&call.inputs()[0], 1);
+                assert_eq_input_ref!(&call.inputs()[1], 0);
+            }
+            other => panic!("expected function call, found {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    /// Decorrelate
+    /// ```text
+    /// Apply(type: LeftOuter)
+    ///   Agg(group_key: [$0])
+    ///     Values(v1)
+    ///   Agg(count(*))
+    ///     Filter(cond: input_ref(0) = correlated_input_ref(0))
+    ///       Values(v2)
+    /// ```
+    /// into
+    /// ```text
+    /// Agg(group_key: [$0], count($2))
+    ///   Join(type: LeftOuter, cond: input_ref(1) = input_ref(0))
+    ///     Agg(group_key: [$0])
+    ///       Values(v1)
+    ///     Project($0, true)
+    ///       Values(v2)
+    /// ```
+    async fn test_outer_apply_count_to_join() {
+        let ctx = OptimizerContext::mock().await;
+        let values: PlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![Field::with_name(DataType::Int32, "v1")]),
+            ctx.clone(),
+        )
+        .into();
+        let left: PlanRef = LogicalAgg::new(vec![], vec![], vec![0], values).into();
+        let right: PlanRef = LogicalValues::new(
+            vec![],
+            Schema::new(vec![Field::with_name(DataType::Int32, "v2")]),
+            ctx,
+        )
+        .into();
+        let predicate: ExprImpl = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                InputRef::new(0, DataType::Int32).into(),
+                CorrelatedInputRef::new(0, DataType::Int32, 1).into(),
+            ],
+        )
+        .unwrap()
+        .into();
+        let filter = LogicalFilter::create(right, Condition::with_expr(predicate));
+        let count: PlanRef =
+            LogicalAgg::new(vec![PlanAggCall::count_star()], vec![None], vec![], filter).into();
+        let apply = LogicalApply::create(left, count, JoinType::LeftOuter, Condition::true_cond());
+
+        let plan = ApplyToJoinRule {}.apply(apply).unwrap();
+
+        // `count(*)` counts the marker column, which is NULL for the left rows without matches.
+        let agg = plan.as_logical_agg().unwrap();
+        assert_eq!(agg.group_keys(), &[0]);
+        assert_eq!(agg.agg_calls().len(), 1);
+        assert_eq!(agg.agg_calls()[0].agg_kind, AggKind::Count);
+        assert_eq!(agg.agg_calls()[0].inputs.len(), 1);
+        assert_eq!(agg.agg_calls()[0].inputs[0].index(), 2);
+
+        let input = agg.input();
+        let join = input.as_logical_join().unwrap();
+        assert_eq!(join.join_type(), JoinType::LeftOuter);
+        assert_eq!(join.right().schema().len(), 2);
+        assert_eq!(join.on().conjunctions.len(), 1);
+        match &join.on().conjunctions[0] {
+            ExprImpl::FunctionCall(call) => {
+                assert_eq!(call.get_expr_type(), ExprType::Equal);
+                assert_eq_input_ref!(&call.inputs()[0], 1);
+                assert_eq_input_ref!(&call.inputs()[1], 0);
+            }
+            other => panic!("expected function call, found {:?}", other),
+        }
+    }
+}
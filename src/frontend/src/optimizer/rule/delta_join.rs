@@ -0,0 +1,166 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::plan::JoinType;
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::{ExprImpl, InputRef};
+use crate::optimizer::property::{Distribution, Order};
+use crate::utils::ColIndexMapping;
+
+/// Rewrites an inner [`StreamHashJoin`] of two materialized views into a delta join, if both of
+/// them are arranged by the join key, i.e. the join key is a prefix of their primary keys.
+///
+/// The changes of `A join B` in an epoch are `(changes of A) join (B of the previous epoch)` plus
+/// `(A of this epoch) join (changes of B)`. Both parts are computed by [`StreamLookupJoin`]s,
+/// which lookup the materialized views in the storage instead of maintaining any join state:
+///
+/// ```text
+/// StreamUnion { ordered: true }
+///   StreamLookupJoin { arrangement: B, use_current_epoch: false }
+///     StreamExchange
+///       StreamTableScan { table: A }
+///     StreamExchange
+///       StreamTableScan { table: B, upstream_only: true }
+///   StreamExchange
+///     StreamProject (reorder the columns to | A | B |)
+///       StreamLookupJoin { arrangement: A, use_current_epoch: true }
+///         StreamExchange
+///           StreamTableScan { table: B, upstream_only: true }
+///         StreamExchange
+///           StreamTableScan { table: A, upstream_only: true }
+/// ```
+///
+/// When a row of `A` and its matches in `B` are updated in the same epoch, the old join result is
+/// deleted by the first lookup and inserted again by the second one, so the union yields the
+/// changes of the lookups one after another instead of interleaving them.
+///
+/// The lookups read the storage of the compute node they are running on, so the plan is only
+/// correct when they are placed together with the materialized views they lookup. The caller is
+/// responsible for applying the rule only when that is guaranteed.
+///
+/// Only joins of two arranged materialized views are handled. Joining a stream with an arranged
+/// table, or looking up an index on the join key, is not planned by this rule.
+pub struct DeltaJoinRule {}
+
+impl Rule for DeltaJoinRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let join = plan.as_stream_hash_join()?;
+        let predicate = join.eq_join_predicate();
+        if join.logical().join_type() != JoinType::Inner
+            || !predicate.has_eq()
+            || predicate.has_non_eq()
+            || predicate.has_null_safe_eq()
+        {
+            return None;
+        }
+
+        let left = join.left();
+        let right = join.right();
+        let left_scan = table_scan_of(&left)?;
+        let right_scan = table_scan_of(&right)?;
+        StreamLookupJoin::arrange_key(left_scan.logical(), &predicate.left_eq_indexes())?;
+        StreamLookupJoin::arrange_key(right_scan.logical(), &predicate.right_eq_indexes())?;
+
+        let left_len = left.schema().len();
+        let right_len = right.schema().len();
+
+        // Lookup the changes of the left side in the right side of the previous epoch.
+        let left_lookup: PlanRef = StreamLookupJoin::new(
+            LogicalJoin::new(
+                exchange(left_scan.clone(), left.distribution()),
+                exchange(
+                    StreamTableScan::new_upstream_only(right_scan.logical().clone()),
+                    right.distribution(),
+                ),
+                JoinType::Inner,
+                join.logical().on().clone(),
+            ),
+            predicate.clone(),
+            right_scan.logical().clone(),
+            false,
+        )
+        .into();
+
+        // Lookup the changes of the right side in the left side of the current epoch, with the
+        // sides swapped.
+        let mut swap = ColIndexMapping::new(
+            (0..left_len)
+                .map(|i| Some(i + right_len))
+                .chain((0..right_len).map(Some))
+                .collect(),
+        );
+        let swapped_on = join.logical().on().clone().rewrite_expr(&mut swap);
+        let right_lookup: PlanRef = StreamLookupJoin::new(
+            LogicalJoin::new(
+                exchange(
+                    StreamTableScan::new_upstream_only(right_scan.logical().clone()),
+                    right.distribution(),
+                ),
+                exchange(
+                    StreamTableScan::new_upstream_only(left_scan.logical().clone()),
+                    left.distribution(),
+                ),
+                JoinType::Inner,
+                swapped_on.clone(),
+            ),
+            EqJoinPredicate::create(right_len, left_len, swapped_on),
+            left_scan.logical().clone(),
+            true,
+        )
+        .into();
+        let exprs: Vec<ExprImpl> = (right_len..right_len + left_len)
+            .chain(0..right_len)
+            .map(|i| InputRef::new(i, right_lookup.schema().fields()[i].data_type()).into())
+            .collect();
+        let alias = vec![None; exprs.len()];
+        let right_lookup = StreamProject::new(LogicalProject::new(right_lookup, exprs, alias));
+        let right_lookup = join
+            .distribution()
+            .enforce_if_not_satisfies(right_lookup.into(), Order::any());
+
+        let union = LogicalUnion::with_pk_indices(
+            vec![left_lookup, right_lookup],
+            join.pk_indices().to_vec(),
+        );
+        Some(StreamUnion::new_ordered(union).into())
+    }
+}
+
+/// Returns the table scan of a join input, which may be shuffled by an exchange.
+fn table_scan_of(input: &PlanRef) -> Option<StreamTableScan> {
+    let scan = match input.as_stream_exchange() {
+        Some(exchange) => exchange.input(),
+        None => input.clone(),
+    };
+    let scan = scan.as_stream_table_scan()?;
+    // The primary key columns must be scanned to lookup the table in order.
+    if scan.upstream_only() || scan.pk_indices().is_empty() {
+        return None;
+    }
+    Some(scan.clone())
+}
+
+/// Every table scan is put into a fragment of its own, which is required by the chains of the
+/// same table.
+fn exchange(scan: StreamTableScan, dist: &Distribution) -> PlanRef {
+    StreamExchange::new(scan.into(), dist.clone()).into()
+}
+
+impl DeltaJoinRule {
+    pub fn create() -> BoxedRule {
+        Box::new(DeltaJoinRule {})
+    }
+}
@@ -37,3 +37,9 @@ mod project_elim;
 pub use project_elim::*;
 mod project_merge;
 pub use project_merge::*;
+mod apply_to_join;
+pub use apply_to_join::*;
+mod delta_join;
+pub use delta_join::*;
+mod over_agg_to_topn;
+pub use over_agg_to_topn::*;
@@ -0,0 +1,121 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::ScalarImpl;
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::{ExprImpl, ExprType, WindowFunctionType};
+use crate::optimizer::property::Order;
+use crate::utils::Condition;
+
+/// Transforms a [`LogicalFilter`] on the `row_number()` of a [`LogicalOverAgg`], e.g.
+/// `row_number() OVER (PARTITION BY a ORDER BY b) <= 3`, by adding a [`LogicalTopN`] grouped by the
+/// partition keys below the over agg.
+///
+/// Only the rows in the top-n of each partition can pass the filter, so the rows numbered by the
+/// over agg are bounded. The over agg is pruned if the row number is not used afterwards.
+pub struct OverAggToTopNRule {}
+impl Rule for OverAggToTopNRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let filter = plan.as_logical_filter()?;
+        let input = filter.input();
+        let over_agg = input.as_logical_over_agg()?;
+        // Other window functions need to see all rows of the partition.
+        if !matches!(
+            over_agg.window_functions(),
+            [window_function] if window_function.function_type == WindowFunctionType::RowNumber
+        ) {
+            return None;
+        }
+        let row_number = over_agg.input().schema().len();
+
+        let mut limit: Option<usize> = None;
+        let mut others = vec![];
+        for expr in &filter.predicate().conjunctions {
+            match as_row_number_limit(expr, row_number) {
+                Some(n) => limit = Some(limit.map_or(n, |limit| limit.min(n))),
+                None => others.push(expr.clone()),
+            }
+        }
+        let limit = limit?;
+
+        let top_n = LogicalTopN::create(
+            over_agg.input(),
+            limit,
+            0,
+            Order::new(over_agg.order_by().to_vec()),
+            over_agg.partition_by().to_vec(),
+        );
+        let new_over_agg = over_agg.clone_with_input(top_n).into();
+        Some(LogicalFilter::create(
+            new_over_agg,
+            Condition {
+                conjunctions: others,
+            },
+        ))
+    }
+}
+
+impl OverAggToTopNRule {
+    pub fn create() -> BoxedRule {
+        Box::new(OverAggToTopNRule {})
+    }
+}
+
+/// Returns the limit if `expr` is an upper bound of the row number, i.e. `row_number <= n` or
+/// `row_number < n`.
+fn as_row_number_limit(expr: &ExprImpl, row_number: usize) -> Option<usize> {
+    let func_call = expr.as_function_call()?;
+    let (expr_type, constant) = match func_call.inputs() {
+        [ExprImpl::InputRef(input_ref), constant] if input_ref.index() == row_number => {
+            (func_call.get_expr_type(), constant)
+        }
+        [constant, ExprImpl::InputRef(input_ref)] if input_ref.index() == row_number => {
+            let expr_type = match func_call.get_expr_type() {
+                ExprType::GreaterThanOrEqual => ExprType::LessThanOrEqual,
+                ExprType::GreaterThan => ExprType::LessThan,
+                _ => return None,
+            };
+            (expr_type, constant)
+        }
+        _ => return None,
+    };
+    let constant = as_int_constant(constant)?;
+    let limit = match expr_type {
+        ExprType::LessThanOrEqual => constant,
+        ExprType::LessThan => constant - 1,
+        _ => return None,
+    };
+    // A limit of 0 filters out all rows, which is left to the filter.
+    if limit > 0 {
+        Some(limit as usize)
+    } else {
+        None
+    }
+}
+
+/// Returns the value of an integer literal, which may be implicitly cast.
+fn as_int_constant(expr: &ExprImpl) -> Option<i64> {
+    let expr = match expr.as_function_call() {
+        Some(func_call) if func_call.get_expr_type() == ExprType::Cast => &func_call.inputs()[0],
+        _ => expr,
+    };
+    match expr.as_literal()?.get_data() {
+        Some(ScalarImpl::Int16(v)) => Some(*v as i64),
+        Some(ScalarImpl::Int32(v)) => Some(*v as i64),
+        Some(ScalarImpl::Int64(v)) => Some(*v),
+        _ => None,
+    }
+}
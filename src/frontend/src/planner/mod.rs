@@ -25,6 +25,7 @@ mod relation;
 mod select;
 mod set_expr;
 mod statement;
+mod update;
 mod values;
 
 /// `Planner` converts a bound statement to a [`crate::optimizer::plan_node::PlanNode`] tree
@@ -40,8 +40,9 @@ impl Planner {
             field_order: query.order,
         };
         let dist = Distribution::Single;
+        // The hidden columns of ORDER BY expressions are not output.
         let mut out_fields = FixedBitSet::with_capacity(plan.schema().len());
-        out_fields.insert_range(..);
+        out_fields.insert_range(..plan.schema().len() - query.extra_order_exprs);
         let root = PlanRoot::new(plan, dist, order, out_fields);
         Ok(root)
     }
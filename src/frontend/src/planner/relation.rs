@@ -15,7 +15,8 @@
 use std::rc::Rc;
 
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_expr::vector_op::hop::hop_window_count;
 
 use crate::binder::{
     BoundBaseTable, BoundJoin, BoundSource, BoundWindowTableFunction, Relation,
@@ -23,7 +24,8 @@ use crate::binder::{
 };
 use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
 use crate::optimizer::plan_node::{
-    LogicalJoin, LogicalProject, LogicalScan, LogicalSource, PlanRef,
+    LogicalFilter, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalSource,
+    LogicalTableFunction, PlanRef,
 };
 use crate::planner::Planner;
 
@@ -36,6 +38,7 @@ impl Planner {
             Relation::Join(join) => self.plan_join(*join),
             Relation::WindowTableFunction(tf) => self.plan_window_table_function(*tf),
             Relation::Source(s) => self.plan_source(*s),
+            Relation::TableFunction(tf) => Ok(LogicalTableFunction::create(*tf, self.ctx())),
         }
     }
 
@@ -70,11 +73,11 @@ impl Planner {
                 table_function.time_col,
                 table_function.args,
             ),
-            Hop => Err(ErrorCode::NotImplemented(
-                "HOP window function is not implemented yet".to_string(),
-                1191.into(),
-            )
-            .into()),
+            Hop => self.plan_hop_window(
+                table_function.input,
+                table_function.time_col,
+                table_function.args,
+            ),
         }
     }
 
@@ -103,7 +106,10 @@ impl Planner {
                 let window_start =
                     ExprImpl::FunctionCall(Box::new(FunctionCall::new_with_return_type(
                         ExprType::TumbleStart,
-                        vec![ExprImpl::InputRef(Box::new(time_col)), window_size.clone()],
+                        vec![
+                            ExprImpl::InputRef(Box::new(time_col.clone())),
+                            window_size.clone(),
+                        ],
                         DataType::Timestamp,
                     )));
                 // TODO: `window_end` may be optimized to avoid double calculation of
@@ -120,6 +126,7 @@ impl Planner {
                 expr_aliases.push(Some("window_start".to_string()));
                 expr_aliases.push(Some("window_end".to_string()));
                 let base = self.plan_relation(input)?;
+                let base = Self::filter_null_time_col(base, &time_col);
                 let project = LogicalProject::create(base, exprs, expr_aliases);
                 Ok(project)
             }
@@ -129,4 +136,58 @@ impl Planner {
             .into()),
         }
     }
+
+    fn plan_hop_window(
+        &mut self,
+        input: Relation,
+        time_col: InputRef,
+        args: Vec<ExprImpl>,
+    ) -> Result<PlanRef> {
+        let mut args = args.into_iter();
+        let interval_arg = |arg: Option<ExprImpl>| match arg {
+            Some(ExprImpl::Literal(literal)) => match literal.get_data() {
+                Some(ScalarImpl::Interval(interval)) => Some(*interval),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match (
+            interval_arg(args.next()),
+            interval_arg(args.next()),
+            args.next(),
+        ) {
+            (Some(window_slide), Some(window_size), None) => {
+                hop_window_count(window_slide, window_size).map_err(|_| {
+                    ErrorCode::BindError(format!(
+                        "HOP window size {} must be a positive multiple of window slide {}",
+                        window_size, window_slide
+                    ))
+                })?;
+                let input = self.plan_relation(input)?;
+                let input = Self::filter_null_time_col(input, &time_col);
+                Ok(LogicalHopWindow::create(
+                    input,
+                    time_col,
+                    window_slide,
+                    window_size,
+                ))
+            }
+            _ => Err(
+                ErrorCode::BindError("Invalid arguments for HOP window function".to_string())
+                    .into(),
+            ),
+        }
+    }
+
+    /// Filter out the rows with NULL time column, which belong to no window. They would otherwise
+    /// get NULL `window_start`s, which can't tell different rows apart in the primary key.
+    fn filter_null_time_col(input: PlanRef, time_col: &InputRef) -> PlanRef {
+        let is_not_null = FunctionCall::new_with_return_type(
+            ExprType::IsNotNull,
+            vec![time_col.clone().into()],
+            DataType::Boolean,
+        );
+        LogicalFilter::create_with_expr(input, is_not_null.into())
+    }
 }
@@ -16,6 +16,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
+use risingwave_expr::expr::AggKind;
 use risingwave_pb::plan::JoinType;
 
 use crate::binder::BoundSelect;
@@ -24,7 +25,8 @@ use crate::expr::{
 };
 pub use crate::optimizer::plan_node::LogicalFilter;
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalApply, LogicalJoin, LogicalProject, LogicalValues, PlanAggCall, PlanRef,
+    LogicalAgg, LogicalApply, LogicalJoin, LogicalOverAgg, LogicalProject, LogicalProjectSet,
+    LogicalValues, PlanAggCall, PlanRef, PlanTreeNodeUnary,
 };
 use crate::planner::Planner;
 use crate::utils::Condition;
@@ -36,6 +38,7 @@ impl Planner {
             where_clause,
             mut select_items,
             group_by,
+            grouping_sets,
             aliases,
             ..
         }: BoundSelect,
@@ -51,14 +54,44 @@ impl Planner {
         }
         // Plan the SELECT clause.
         // TODO: select-agg, group-by, having can also contain subquery exprs.
-        let has_agg_call = select_items.iter().any(|expr| expr.has_agg_call());
-        if !group_by.is_empty() || has_agg_call {
-            LogicalAgg::create(select_items, aliases, group_by, root)
+        let has_agg_call = select_items
+            .iter()
+            .any(|expr| expr.has_agg_call() || expr.has_grouping());
+        let has_window_function = select_items.iter().any(|expr| expr.has_window_function());
+        let has_table_function = select_items.iter().any(|expr| expr.has_table_function());
+        if !group_by.is_empty() || !grouping_sets.is_empty() || has_agg_call {
+            if has_window_function {
+                return Err(ErrorCode::NotImplemented(
+                    "window functions together with aggregation".into(),
+                    None.into(),
+                )
+                .into());
+            }
+            if has_table_function {
+                return Err(ErrorCode::NotImplemented(
+                    "set-returning functions together with aggregation".into(),
+                    None.into(),
+                )
+                .into());
+            }
+            LogicalAgg::create(select_items, aliases, group_by, grouping_sets, root)
         } else {
             if select_items.iter().any(|e| e.has_subquery()) {
                 (root, select_items) = self.substitute_subqueries(root, select_items)?;
             }
-            Ok(LogicalProject::create(root, select_items, aliases))
+            if has_window_function && has_table_function {
+                Err(ErrorCode::NotImplemented(
+                    "window functions together with set-returning functions".into(),
+                    None.into(),
+                )
+                .into())
+            } else if has_window_function {
+                LogicalOverAgg::create(select_items, aliases, root)
+            } else if has_table_function {
+                LogicalProjectSet::create(root, select_items, aliases)
+            } else {
+                Ok(LogicalProject::create(root, select_items, aliases))
+            }
         }
     }
 
@@ -88,8 +121,9 @@ impl Planner {
         ))
     }
 
-    /// For `... AND (NOT) subquery AND ...`, we can plan it as `LeftSemi/LeftAnti`
-    /// [`LogicalApply`] (correlated) or [`LogicalJoin`].
+    /// For `... AND (NOT) subquery AND ...`, where the subquery is an `EXISTS` or set comparison
+    /// (`IN`/`ANY`/`ALL`) subquery, we can plan it as `LeftSemi/LeftAnti` [`LogicalApply`]
+    /// (correlated) or [`LogicalJoin`].
     ///
     /// For other subqueries, we plan it as `LeftOuter` [`LogicalApply`] (correlated) or
     /// [`LogicalJoin`] using [`Self::substitute_subqueries`].
@@ -101,10 +135,10 @@ impl Planner {
         let (subquery_conjunctions, not_subquery_conjunctions, others) =
             Condition::with_expr(where_clause)
                 .group_by::<_, 3>(|expr| match expr {
-                    ExprImpl::Subquery(_) => 0,
+                    ExprImpl::Subquery(subquery) if Self::is_semi_join_subquery(subquery) => 0,
                     ExprImpl::FunctionCall(func_call)
                         if func_call.get_expr_type() == ExprType::Not
-                            && matches!(func_call.inputs()[0], ExprImpl::Subquery(_)) =>
+                            && matches!(&func_call.inputs()[0], ExprImpl::Subquery(subquery) if Self::is_semi_join_subquery(subquery)) =>
                     {
                         1
                     }
@@ -116,42 +150,14 @@ impl Planner {
 
         for expr in subquery_conjunctions {
             let subquery = expr.into_subquery().unwrap();
-            let is_correlated = subquery.is_correlated();
-
-            let join_type = match subquery.kind {
-                SubqueryKind::Existential => JoinType::LeftSemi,
-                SubqueryKind::Scalar | SubqueryKind::SetComparison => {
-                    return Err(ErrorCode::NotImplemented(
-                        format!("{:?}", subquery.kind),
-                        1343.into(),
-                    )
-                    .into())
-                }
-            };
-            let right = self.plan_query(subquery.query)?.as_subplan();
-
-            input = Self::create_apply_or_join(is_correlated, input, right, join_type);
+            input = self.plan_semi_join_subquery(input, *subquery, false)?;
         }
 
         for expr in not_subquery_conjunctions {
             let not = expr.into_function_call().unwrap();
             let (_, subquery) = not.decompose_as_unary();
             let subquery = subquery.into_subquery().unwrap();
-            let is_correlated = subquery.is_correlated();
-
-            let join_type = match subquery.kind {
-                SubqueryKind::Existential => JoinType::LeftAnti,
-                SubqueryKind::Scalar | SubqueryKind::SetComparison => {
-                    return Err(ErrorCode::NotImplemented(
-                        format!("{:?}", subquery.kind),
-                        1343.into(),
-                    )
-                    .into())
-                }
-            };
-            let right = self.plan_query(subquery.query)?.as_subplan();
-
-            input = Self::create_apply_or_join(is_correlated, input, right, join_type);
+            input = self.plan_semi_join_subquery(input, *subquery, true)?;
         }
 
         if others.always_true() {
@@ -167,6 +173,70 @@ impl Planner {
         }
     }
 
+    fn is_semi_join_subquery(subquery: &Subquery) -> bool {
+        matches!(
+            subquery.kind,
+            SubqueryKind::Existential | SubqueryKind::SetComparison { .. }
+        )
+    }
+
+    /// Plans `(NOT) subquery` as a `LeftSemi/LeftAnti` [`LogicalApply`] (correlated) or
+    /// [`LogicalJoin`], whose left side is `input`.
+    ///
+    /// `x op ANY (subquery)` is true iff `x op y` is true for some `y` of the subquery, and false
+    /// iff `x op y` is false for all `y`. Otherwise it is NULL, which is filtered out just like
+    /// false. `x op ALL (subquery)` is `NOT (x NOT op ANY (subquery))`.
+    fn plan_semi_join_subquery(
+        &mut self,
+        input: PlanRef,
+        subquery: Subquery,
+        negated: bool,
+    ) -> Result<PlanRef> {
+        let is_correlated = subquery.is_correlated();
+        let Subquery { query, kind } = subquery;
+
+        let (join_type, on) = match kind {
+            SubqueryKind::Existential if negated => {
+                (JoinType::LeftAnti, ExprImpl::literal_bool(true))
+            }
+            SubqueryKind::Existential => (JoinType::LeftSemi, ExprImpl::literal_bool(true)),
+            SubqueryKind::SetComparison { left, op, all } => {
+                if left.has_subquery() {
+                    return Err(ErrorCode::NotImplemented(
+                        "subquery in the left side of IN/ANY/ALL".into(),
+                        1343.into(),
+                    )
+                    .into());
+                }
+                let right = InputRef::new(input.schema().len(), query.data_types()[0].clone());
+                let cmp: ExprImpl = FunctionCall::new(op, vec![left, right.into()])
+                    .unwrap()
+                    .into();
+                let (join_type, test) = match (all, negated) {
+                    (false, false) => (JoinType::LeftSemi, None),
+                    (false, true) => (JoinType::LeftAnti, Some(ExprType::IsNotFalse)),
+                    (true, false) => (JoinType::LeftAnti, Some(ExprType::IsNotTrue)),
+                    (true, true) => (JoinType::LeftSemi, Some(ExprType::IsFalse)),
+                };
+                let on = match test {
+                    Some(test) => FunctionCall::new(test, vec![cmp]).unwrap().into(),
+                    None => cmp,
+                };
+                (join_type, on)
+            }
+            SubqueryKind::Scalar => unreachable!(),
+        };
+        let right = self.plan_query(query)?.as_subplan();
+
+        Ok(Self::create_apply_or_join(
+            is_correlated,
+            input,
+            right,
+            join_type,
+            on,
+        ))
+    }
+
     /// Substitutes all [`Subquery`] in `exprs`.
     ///
     /// Each time a [`Subquery`] is found, it is replaced by a new [`InputRef`]. And `root` is
@@ -205,37 +275,77 @@ impl Planner {
 
         for subquery in rewriter.subqueries {
             let is_correlated = subquery.is_correlated();
+            if let SubqueryKind::SetComparison { .. } = subquery.kind {
+                return Err(ErrorCode::NotImplemented(
+                    "IN/ANY/ALL subquery outside of the conjunctions of WHERE".into(),
+                    1343.into(),
+                )
+                .into());
+            }
             let mut right = self.plan_query(subquery.query)?.as_subplan();
 
             match subquery.kind {
-                SubqueryKind::Scalar => {}
+                SubqueryKind::Scalar => {
+                    right = Self::create_max_one_row(right);
+                }
                 SubqueryKind::Existential => {
                     right = self.create_exists(right)?;
                 }
-                SubqueryKind::SetComparison => {
-                    return Err(ErrorCode::NotImplemented(
-                        format!("{:?}", subquery.kind),
-                        1343.into(),
-                    )
-                    .into())
-                }
+                SubqueryKind::SetComparison { .. } => unreachable!(),
             }
 
-            root = Self::create_apply_or_join(is_correlated, root, right, JoinType::LeftOuter);
+            root = Self::create_apply_or_join(
+                is_correlated,
+                root,
+                right,
+                JoinType::LeftOuter,
+                ExprImpl::literal_bool(true),
+            );
         }
         Ok((root, exprs))
     }
 
+    /// Helper to make sure a scalar subquery returns at most one row. Unless it is known to, it is
+    /// wrapped as `Agg(single_value($0)) - input`, which raises an error for more than one row.
+    fn create_max_one_row(input: PlanRef) -> PlanRef {
+        if Self::is_max_one_row(&input) {
+            return input;
+        }
+        let field = input.schema().fields()[0].clone();
+        let single_value = PlanAggCall {
+            agg_kind: AggKind::SingleValue,
+            return_type: field.data_type(),
+            inputs: vec![InputRef::new(0, field.data_type())],
+            distinct: false,
+        };
+        LogicalAgg::new(vec![single_value], vec![Some(field.name)], vec![], input).into()
+    }
+
+    fn is_max_one_row(plan: &PlanRef) -> bool {
+        if let Some(agg) = plan.as_logical_agg() {
+            agg.group_keys().is_empty()
+        } else if let Some(project) = plan.as_logical_project() {
+            Self::is_max_one_row(&project.input())
+        } else if let Some(filter) = plan.as_logical_filter() {
+            Self::is_max_one_row(&filter.input())
+        } else if let Some(values) = plan.as_logical_values() {
+            values.rows().len() <= 1
+        } else {
+            false
+        }
+    }
+
     fn create_apply_or_join(
         is_correlated: bool,
         left: PlanRef,
         right: PlanRef,
         join_type: JoinType,
+        on: ExprImpl,
     ) -> PlanRef {
         if is_correlated {
-            LogicalApply::create(left, right, join_type)
+            LogicalApply::create(left, right, join_type, Condition::with_expr(on))
         } else {
-            LogicalJoin::create(left, right, join_type, ExprImpl::literal_bool(true))
+            LogicalJoin::create(left, right, join_type, on)
         }
     }
 }
@@ -12,17 +12,86 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::plan::JoinType;
+use risingwave_sqlparser::ast::SetOperator;
 
 use crate::binder::BoundSetExpr;
-use crate::optimizer::plan_node::PlanRef;
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
+use crate::optimizer::plan_node::{LogicalAgg, LogicalJoin, LogicalUnion, PlanRef};
 use crate::planner::Planner;
+use crate::utils::Condition;
 
 impl Planner {
     pub(super) fn plan_set_expr(&mut self, set_expr: BoundSetExpr) -> Result<PlanRef> {
         match set_expr {
             BoundSetExpr::Select(s) => self.plan_select(*s),
             BoundSetExpr::Values(v) => self.plan_values(*v),
+            BoundSetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => self.plan_set_operation(op, all, *left, *right),
         }
     }
+
+    /// `UNION ALL` is planned as a [`LogicalUnion`], and `INTERSECT` / `EXCEPT` as a
+    /// `LeftSemi` / `LeftAnti` [`LogicalJoin`] on all columns. Set operations without `ALL`
+    /// additionally remove duplicates with a [`LogicalAgg`] grouping by all columns.
+    fn plan_set_operation(
+        &mut self,
+        op: SetOperator,
+        all: bool,
+        left: BoundSetExpr,
+        right: BoundSetExpr,
+    ) -> Result<PlanRef> {
+        let left = self.plan_set_expr(left)?;
+        let right = self.plan_set_expr(right)?;
+
+        let plan = match op {
+            SetOperator::Union => LogicalUnion::create(vec![left, right]),
+            SetOperator::Intersect | SetOperator::Except if all => {
+                return Err(ErrorCode::NotImplemented(format!("{} ALL", op), None.into()).into())
+            }
+            SetOperator::Intersect => Self::create_set_join(left, right, JoinType::LeftSemi),
+            SetOperator::Except => Self::create_set_join(left, right, JoinType::LeftAnti),
+        };
+
+        if all {
+            Ok(plan)
+        } else {
+            Ok(Self::create_distinct(plan))
+        }
+    }
+
+    /// Join `left` and `right` with `IS NOT DISTINCT FROM` conditions on all the columns, as rows
+    /// with NULLs at the same columns are considered the same in set operations.
+    fn create_set_join(left: PlanRef, right: PlanRef, join_type: JoinType) -> PlanRef {
+        let left_len = left.schema().len();
+        let conjunctions = left
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                FunctionCall::new(
+                    ExprType::IsNotDistinctFrom,
+                    vec![
+                        InputRef::new(i, field.data_type()).into(),
+                        InputRef::new(left_len + i, field.data_type()).into(),
+                    ],
+                )
+                .unwrap()
+                .into()
+            })
+            .collect::<Vec<ExprImpl>>();
+        LogicalJoin::new(left, right, join_type, Condition { conjunctions }).into()
+    }
+
+    /// Remove duplicated rows of `input` by grouping on all its columns.
+    fn create_distinct(input: PlanRef) -> PlanRef {
+        let group_keys = (0..input.schema().len()).collect();
+        LogicalAgg::new(vec![], vec![], group_keys, input).into()
+    }
 }
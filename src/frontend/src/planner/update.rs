@@ -0,0 +1,45 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use fixedbitset::FixedBitSet;
+use risingwave_common::error::Result;
+
+use super::Planner;
+use crate::binder::BoundUpdate;
+use crate::optimizer::plan_node::{LogicalFilter, LogicalUpdate};
+use crate::optimizer::property::{Distribution, Order};
+use crate::optimizer::{PlanRef, PlanRoot};
+
+impl Planner {
+    pub(super) fn plan_update(&mut self, update: BoundUpdate) -> Result<PlanRoot> {
+        let name = update.table_source.name.clone();
+        let source_id = update.table_source.source_id;
+        let scan = self.plan_base_table(update.table)?;
+        let input = if let Some(expr) = update.selection {
+            LogicalFilter::create_with_expr(scan, expr)
+        } else {
+            scan
+        };
+        let plan: PlanRef = LogicalUpdate::create(input, name, source_id, update.exprs)?.into();
+
+        let order = Order::any().clone();
+        // For update, frontend will only schedule one task so do not need this to be single.
+        let dist = Distribution::Any;
+        let mut out_fields = FixedBitSet::with_capacity(plan.schema().len());
+        out_fields.insert_range(..);
+
+        let root = PlanRoot::new(plan, dist, order, out_fields);
+        Ok(root)
+    }
+}
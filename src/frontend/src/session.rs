@@ -42,6 +42,7 @@ use crate::handler::query::IMPLICIT_FLUSH;
 use crate::meta_client::{FrontendMetaClient, FrontendMetaClientImpl};
 use crate::observer::observer_manager::ObserverManager;
 use crate::optimizer::plan_node::PlanNodeId;
+use crate::optimizer::ENABLE_DELTA_JOIN;
 use crate::scheduler::schedule::{WorkerNodeManager, WorkerNodeManagerRef};
 use crate::scheduler::QueryManager;
 use crate::FrontendOpts;
@@ -318,6 +319,10 @@ impl SessionImpl {
             IMPLICIT_FLUSH.to_string(),
             ConfigEntry::new("false".to_string()),
         );
+        map.insert(
+            ENABLE_DELTA_JOIN.to_string(),
+            ConfigEntry::new("false".to_string()),
+        );
         RwLock::new(map)
     }
 }
@@ -24,7 +24,8 @@ use risingwave_common::catalog::{TableId, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_
 use risingwave_common::error::Result;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Schema as ProstSchema, Sink as ProstSink, Source as ProstSource,
+    Table as ProstTable, View as ProstView,
 };
 use risingwave_pb::stream_plan::StreamNode;
 use risingwave_sqlparser::ast::Statement;
@@ -83,10 +84,10 @@ impl LocalFrontend {
                 );
                 binder.bind(Statement::Query(query.clone()))?
             };
-            Ok(Planner::new(OptimizerContext::new(session).into())
+            Planner::new(OptimizerContext::new(session).into())
                 .plan(bound)
                 .unwrap()
-                .gen_batch_query_plan())
+                .gen_batch_query_plan()
         } else {
             unreachable!()
         }
@@ -181,6 +182,48 @@ impl CatalogWriter for MockCatalogWriter {
             .drop_table(database_id, schema_id, table_id);
         Ok(())
     }
+
+    async fn replace_materialized_view(
+        &self,
+        mut table: ProstTable,
+        _plan: StreamNode,
+    ) -> Result<()> {
+        self.drop_id(table.id);
+        table.id = self.gen_id();
+        self.catalog.write().replace_table(&table);
+        self.add_id(table.id, table.database_id, table.schema_id);
+        Ok(())
+    }
+
+    async fn create_view(&self, mut view: ProstView) -> Result<()> {
+        view.id = self.gen_id();
+        self.catalog.write().create_view(&view);
+        self.add_id(view.id, view.database_id, view.schema_id);
+        Ok(())
+    }
+
+    async fn drop_view(&self, view_id: u32) -> Result<()> {
+        let (database_id, schema_id) = self.drop_id(view_id);
+        self.catalog
+            .write()
+            .drop_view(database_id, schema_id, view_id);
+        Ok(())
+    }
+
+    async fn create_sink(&self, mut sink: ProstSink, _graph: StreamNode) -> Result<()> {
+        sink.id = self.gen_id();
+        self.catalog.write().create_sink(&sink);
+        self.add_id(sink.id, sink.database_id, sink.schema_id);
+        Ok(())
+    }
+
+    async fn drop_sink(&self, sink_id: u32) -> Result<()> {
+        let (database_id, schema_id) = self.drop_id(sink_id);
+        self.catalog
+            .write()
+            .drop_sink(database_id, schema_id, sink_id);
+        Ok(())
+    }
 }
 
 impl MockCatalogWriter {
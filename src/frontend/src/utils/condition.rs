@@ -159,7 +159,7 @@ impl Condition {
         self,
         left_col_num: usize,
         right_col_num: usize,
-    ) -> (Vec<(InputRef, InputRef)>, Self) {
+    ) -> (Vec<(InputRef, InputRef, bool)>, Self) {
         let left_bit_map = FixedBitSet::from_iter(0..left_col_num);
         let right_bit_map = FixedBitSet::from_iter(left_col_num..left_col_num + right_col_num);
 
@@ -171,15 +171,19 @@ impl Condition {
             } else {
                 let mut is_eq_cond = false;
                 if let ExprImpl::FunctionCall(function_call) = expr.clone()
-                    && function_call.get_expr_type() == ExprType::Equal
-                    && let (_, ExprImpl::InputRef(x), ExprImpl::InputRef(y)) =
+                    && matches!(
+                        function_call.get_expr_type(),
+                        ExprType::Equal | ExprType::IsNotDistinctFrom
+                    )
+                    && let (func_type, ExprImpl::InputRef(x), ExprImpl::InputRef(y)) =
                             function_call.decompose_as_binary()
                     {
                         is_eq_cond = true;
+                        let null_safe = func_type == ExprType::IsNotDistinctFrom;
                         if x.index() < y.index() {
-                            eq_keys.push((*x, *y));
+                            eq_keys.push((*x, *y, null_safe));
                         } else {
-                            eq_keys.push((*y, *x));
+                            eq_keys.push((*y, *x, null_safe));
                         }
                     }
                 if !is_eq_cond {
@@ -20,7 +20,9 @@ mod resolve_id;
 use anyhow::{anyhow, Result};
 pub use resolve_id::*;
 use risingwave_frontend::binder::Binder;
-use risingwave_frontend::handler::{create_mv, create_table, drop_table};
+use risingwave_frontend::handler::{
+    create_mv, create_source, create_table, create_view, drop_table, set,
+};
 use risingwave_frontend::optimizer::PlanRef;
 use risingwave_frontend::planner::Planner;
 use risingwave_frontend::session::{OptimizerContext, OptimizerContextRef};
@@ -73,6 +75,9 @@ pub struct TestCase {
 
     /// Error of optimizer
     pub optimizer_error: Option<String>,
+
+    /// Error of generating the create MV plan
+    pub stream_error: Option<String>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -105,6 +110,9 @@ pub struct TestCaseResult {
 
     /// Error of optimizer
     pub optimizer_error: Option<String>,
+
+    /// Error of generating the create MV plan
+    pub stream_error: Option<String>,
 }
 
 impl TestCaseResult {
@@ -119,6 +127,9 @@ impl TestCaseResult {
         if original_test_case.optimizer_error.is_none() && let Some(ref err) = self.optimizer_error {
             return Err(anyhow!("unexpected optimizer error: {}", err));
         }
+        if original_test_case.stream_error.is_none() && let Some(ref err) = self.stream_error {
+            return Err(anyhow!("unexpected stream error: {}", err));
+        }
 
         let case = TestCase {
             id: original_test_case.id.clone(),
@@ -134,6 +145,7 @@ impl TestCaseResult {
             planner_error: self.planner_error,
             optimizer_error: self.optimizer_error,
             binder_error: self.binder_error,
+            stream_error: self.stream_error,
         };
         Ok(case)
     }
@@ -174,6 +186,12 @@ impl TestCase {
                     Statement::CreateTable { name, columns, .. } => {
                         create_table::handle_create_table(context, name, columns).await?;
                     }
+                    Statement::CreateSource {
+                        is_materialized,
+                        stmt,
+                    } => {
+                        create_source::handle_create_source(context, is_materialized, stmt).await?;
+                    }
                     Statement::CreateView {
                         materialized: true,
                         or_replace: false,
@@ -183,11 +201,28 @@ impl TestCase {
                     } => {
                         create_mv::handle_create_mv(context, name, query).await?;
                     }
+                    Statement::CreateView {
+                        materialized: false,
+                        or_replace: false,
+                        name,
+                        columns,
+                        query,
+                        ..
+                    } => {
+                        create_view::handle_create_view(context, name, columns, query).await?;
+                    }
 
                     Statement::Drop(drop_statement) => {
                         let table_object_name = ObjectName(vec![drop_statement.name]);
                         drop_table::handle_drop_table(context, table_object_name).await?;
                     }
+                    Statement::SetVariable {
+                        local: _,
+                        variable,
+                        value,
+                    } => {
+                        set::handle_set(context, variable, value)?;
+                    }
                     _ => return Err(anyhow!("Unsupported statement type")),
                 }
             }
@@ -235,12 +270,25 @@ impl TestCase {
 
         // Only generate optimized_logical_plan if it is specified in test case
         if self.optimized_logical_plan.is_some() {
-            ret.optimized_logical_plan =
-                Some(explain_plan(&logical_plan.gen_optimized_logical_plan()));
+            match logical_plan.gen_optimized_logical_plan() {
+                Ok(optimized_logical_plan) => {
+                    ret.optimized_logical_plan = Some(explain_plan(&optimized_logical_plan));
+                }
+                Err(err) => {
+                    ret.optimizer_error = Some(err.to_string());
+                    return Ok(ret);
+                }
+            }
         }
 
         if self.batch_plan.is_some() || self.batch_plan_proto.is_some() {
-            let batch_plan = logical_plan.gen_dist_batch_query_plan();
+            let batch_plan = match logical_plan.gen_dist_batch_query_plan() {
+                Ok(batch_plan) => batch_plan,
+                Err(err) => {
+                    ret.optimizer_error = Some(err.to_string());
+                    return Ok(ret);
+                }
+            };
 
             // Only generate batch_plan if it is specified in test case
             if self.batch_plan.is_some() {
@@ -255,19 +303,28 @@ impl TestCase {
             }
         }
 
-        if self.stream_plan.is_some() || self.stream_plan_proto.is_some() {
+        if self.stream_plan.is_some()
+            || self.stream_plan_proto.is_some()
+            || self.stream_error.is_some()
+        {
             let q = if let Statement::Query(q) = stmt {
                 q.as_ref().clone()
             } else {
                 return Err(anyhow!("expect a query"));
             };
 
-            let (stream_plan, table) = create_mv::gen_create_mv_plan(
+            let (stream_plan, table) = match create_mv::gen_create_mv_plan(
                 &session,
                 context,
                 Box::new(q),
                 ObjectName(vec!["test".into()]),
-            )?;
+            ) {
+                Ok(plan) => plan,
+                Err(err) => {
+                    ret.stream_error = Some(err.to_string());
+                    return Ok(ret);
+                }
+            };
 
             // Only generate stream_plan if it is specified in test case
             if self.stream_plan.is_some() {
@@ -299,6 +356,7 @@ fn check_result(expected: &TestCase, actual: &TestCaseResult) -> Result<()> {
         &expected.optimizer_error,
         &actual.optimizer_error,
     )?;
+    check_err("stream", &expected.stream_error, &actual.stream_error)?;
     check_option_plan_eq("logical_plan", &expected.logical_plan, &actual.logical_plan)?;
     check_option_plan_eq(
         "optimized_logical_plan",
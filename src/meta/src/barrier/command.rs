@@ -12,21 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use futures::future::try_join_all;
 use risingwave_common::catalog::TableId;
 use risingwave_common::error::{Result, RwError, ToRwResult};
+use risingwave_pb::catalog::Table;
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation;
-use risingwave_pb::data::{Actors, AddMutation, NothingMutation, StopMutation};
+use risingwave_pb::data::{
+    Actors, AddMutation, NothingMutation, ReplaceMutation, StopMutation, UpstreamActors,
+};
 use risingwave_pb::stream_service::DropActorsRequest;
 use uuid::Uuid;
 
 use super::info::BarrierActorInfo;
-use crate::manager::StreamClientsRef;
+use crate::cluster::WorkerId;
+use crate::manager::{CatalogManagerRef, StreamClientsRef};
 use crate::model::{ActorId, TableFragments};
-use crate::storage::MetaStore;
+use crate::storage::{MetaStore, Transaction};
 use crate::stream::FragmentManagerRef;
 
 /// [`Command`] is the action of [`crate::barrier::GlobalBarrierManager`]. For different commands,
@@ -60,6 +64,26 @@ pub enum Command {
         table_sink_map: HashMap<TableId, Vec<ActorId>>,
         dispatches: HashMap<ActorId, Vec<ActorInfo>>,
     },
+
+    /// `ReplaceMaterializedView` command generates a `Replace` barrier, which stops the actors of
+    /// `old_table_id`, adds outputs from the sink actors of `new_table_id` to the downstream
+    /// `Chain` actors by `dispatches`, and switches the merge inputs of the downstream actors to
+    /// the new sink actors by `merge_updates`. The materialized view of `new_table_id` must have
+    /// been created and backfilled by [`Command::CreateMaterializedView`] before, with the same
+    /// output schema.
+    ///
+    /// Barriers from the actors to be dropped will STILL be collected.
+    /// After the barrier is collected, it notifies the local stream manager of compute nodes to
+    /// drop the old actors, and then swaps both the table fragments info and the catalog `table`
+    /// in one commit of meta store. If anything fails before the commit, the old materialized view
+    /// will be recovered with its downstream.
+    ReplaceMaterializedView {
+        old_table_id: TableId,
+        new_table_id: TableId,
+        table: Table,
+        dispatches: HashMap<ActorId, Vec<ActorInfo>>,
+        merge_updates: HashMap<ActorId, Vec<ActorId>>,
+    },
 }
 
 impl Command {
@@ -79,9 +103,11 @@ impl Command {
 
 /// [`CommandContext`] is used for generating barrier and doing post stuffs according to the given
 /// [`Command`].
-pub struct CommandContext<'a, S> {
+pub struct CommandContext<'a, S: MetaStore> {
     fragment_manager: FragmentManagerRef<S>,
 
+    catalog_manager: CatalogManagerRef<S>,
+
     clients: StreamClientsRef,
 
     /// Resolved info in this barrier loop.
@@ -94,9 +120,13 @@ pub struct CommandContext<'a, S> {
     command: Command,
 }
 
-impl<'a, S> CommandContext<'a, S> {
+impl<'a, S> CommandContext<'a, S>
+where
+    S: MetaStore,
+{
     pub fn new(
         fragment_manager: FragmentManagerRef<S>,
+        catalog_manager: CatalogManagerRef<S>,
         clients: StreamClientsRef,
         info: &'a BarrierActorInfo,
         prev_epoch: u64,
@@ -105,6 +135,7 @@ impl<'a, S> CommandContext<'a, S> {
     ) -> Self {
         Self {
             fragment_manager,
+            catalog_manager,
             clients,
             info,
             prev_epoch,
@@ -128,6 +159,45 @@ where
                 Mutation::Stop(StopMutation { actors })
             }
 
+            Command::ReplaceMaterializedView {
+                old_table_id,
+                dispatches,
+                merge_updates,
+                ..
+            } => {
+                let stop = self
+                    .fragment_manager
+                    .get_table_actor_ids(old_table_id)
+                    .await?;
+                let dispatchers = dispatches
+                    .iter()
+                    .map(|(&up_actor_id, down_actor_infos)| {
+                        (
+                            up_actor_id,
+                            Actors {
+                                info: down_actor_infos.to_vec(),
+                            },
+                        )
+                    })
+                    .collect();
+                let merges = merge_updates
+                    .iter()
+                    .map(|(&down_actor_id, up_actor_ids)| {
+                        (
+                            down_actor_id,
+                            UpstreamActors {
+                                actors: up_actor_ids.to_vec(),
+                            },
+                        )
+                    })
+                    .collect();
+                Mutation::Replace(ReplaceMutation {
+                    stop,
+                    dispatchers,
+                    merges,
+                })
+            }
+
             Command::CreateMaterializedView { dispatches, .. } => {
                 let actors = dispatches
                     .iter()
@@ -166,29 +236,49 @@ where
             Command::Plain(_) => {}
 
             Command::DropMaterializedView(table_id) => {
-                // Tell compute nodes to drop actors.
-                let node_actors = self.fragment_manager.table_node_actors(table_id).await?;
-                let futures = node_actors.iter().map(|(node_id, actors)| {
-                    let node = self.info.node_map.get(node_id).unwrap();
-                    let request_id = Uuid::new_v4().to_string();
-
-                    async move {
-                        let mut client = self.clients.get(node).await?;
-                        tracing::debug!(request_id = %request_id, node = node_id, actors = ?actors, "drop actors");
-                        let request = DropActorsRequest {
-                            request_id,
-                            actor_ids: actors.to_owned(),
-                        };
-                        client.drop_actors(request).await.to_rw_result()?;
-
-                        Ok::<_, RwError>(())
-                    }
-                });
-
-                try_join_all(futures).await?;
-
-                // Drop fragment info in meta store.
-                self.fragment_manager.drop_table_fragments(table_id).await?;
+                self.drop_table_actors(table_id).await?;
+            }
+
+            Command::ReplaceMaterializedView {
+                old_table_id,
+                new_table_id,
+                table,
+                dispatches,
+                merge_updates,
+            } => {
+                tracing::debug!(%old_table_id, %new_table_id, "replace materialized view");
+                let node_actors = self
+                    .fragment_manager
+                    .table_node_actors(old_table_id)
+                    .await?;
+                self.drop_actors(&node_actors).await?;
+
+                let dispatches: HashMap<ActorId, Vec<ActorId>> = dispatches
+                    .iter()
+                    .map(|(&up_actor_id, down_actor_infos)| {
+                        (
+                            up_actor_id,
+                            down_actor_infos.iter().map(|info| info.actor_id).collect(),
+                        )
+                    })
+                    .collect();
+                let mut transaction = Transaction::default();
+                let updated_tables = self
+                    .fragment_manager
+                    .replace_table_fragments_in_transaction(
+                        old_table_id,
+                        new_table_id,
+                        &dispatches,
+                        merge_updates,
+                        &mut transaction,
+                    )
+                    .await?;
+                self.catalog_manager
+                    .finish_replace_table_procedure(old_table_id.table_id(), table, transaction)
+                    .await?;
+                self.fragment_manager
+                    .finish_replace_table_fragments(old_table_id, updated_tables)
+                    .await;
             }
 
             Command::CreateMaterializedView {
@@ -224,4 +314,40 @@ where
 
         Ok(())
     }
+
+    /// Tell compute nodes to drop the actors of `table_id`, and then delete its table fragments
+    /// info from meta store.
+    async fn drop_table_actors(&self, table_id: &TableId) -> Result<()> {
+        let node_actors = self.fragment_manager.table_node_actors(table_id).await?;
+        self.drop_actors(&node_actors).await?;
+
+        // Drop fragment info in meta store.
+        self.fragment_manager.drop_table_fragments(table_id).await?;
+
+        Ok(())
+    }
+
+    /// Tell compute nodes to drop actors.
+    async fn drop_actors(&self, node_actors: &BTreeMap<WorkerId, Vec<ActorId>>) -> Result<()> {
+        let futures = node_actors.iter().map(|(node_id, actors)| {
+            let node = self.info.node_map.get(node_id).unwrap();
+            let request_id = Uuid::new_v4().to_string();
+
+            async move {
+                let mut client = self.clients.get(node).await?;
+                tracing::debug!(request_id = %request_id, node = node_id, actors = ?actors, "drop actors");
+                let request = DropActorsRequest {
+                    request_id,
+                    actor_ids: actors.to_owned(),
+                };
+                client.drop_actors(request).await.to_rw_result()?;
+
+                Ok::<_, RwError>(())
+            }
+        });
+
+        try_join_all(futures).await?;
+
+        Ok(())
+    }
 }
@@ -244,6 +244,7 @@ where
             assert!(new_epoch > state.prev_epoch);
             let command_ctx = CommandContext::new(
                 self.fragment_manager.clone(),
+                self.catalog_manager.clone(),
                 self.env.stream_clients_ref(),
                 &info,
                 state.prev_epoch,
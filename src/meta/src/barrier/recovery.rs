@@ -100,6 +100,7 @@ where
             // checkpoint, used as init barrier to initialize all executors.
             let command_ctx = CommandContext::new(
                 self.fragment_manager.clone(),
+                self.catalog_manager.clone(),
                 self.env.stream_clients_ref(),
                 &info,
                 prev_epoch,
@@ -24,7 +24,7 @@ use risingwave_common::ensure;
 use risingwave_common::error::ErrorCode::{CatalogError, InternalError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Schema, Sink, Source, Table, View};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::sync::{Mutex, MutexGuard};
 
@@ -37,9 +37,18 @@ pub type DatabaseId = u32;
 pub type SchemaId = u32;
 pub type TableId = u32;
 pub type SourceId = u32;
+pub type ViewId = u32;
+pub type SinkId = u32;
 pub type RelationId = u32;
 
-pub type Catalog = (Vec<Database>, Vec<Schema>, Vec<Table>, Vec<Source>);
+pub type Catalog = (
+    Vec<Database>,
+    Vec<Schema>,
+    Vec<Table>,
+    Vec<Source>,
+    Vec<View>,
+    Vec<Sink>,
+);
 
 pub struct CatalogManager<S: MetaStore> {
     env: MetaSrvEnv<S>,
@@ -250,6 +259,128 @@ where
         }
     }
 
+    /// Start replacing materialized view `old_table_id` with `table`, which has a new id. Only the
+    /// hidden columns of the two are allowed to differ.
+    pub async fn start_replace_table_procedure(
+        &self,
+        old_table_id: TableId,
+        table: &Table,
+    ) -> Result<()> {
+        let mut core = self.core.lock().await;
+        let key = (table.database_id, table.schema_id, table.name.clone());
+        let old_table = Table::select(self.env.meta_store(), &old_table_id)
+            .await?
+            .ok_or_else(|| RwError::from(InternalError("table doesn't exist".to_string())))?;
+        if old_table.optional_associated_source_id.is_some() {
+            return Err(CatalogError(
+                anyhow!("`{}` is a table, not a materialized view", old_table.name).into(),
+            )
+            .into());
+        }
+        if !is_schema_compatible(&old_table, table) {
+            return Err(CatalogError(
+                anyhow!(
+                    "Fail to replace materialized view `{}` because its output schema is changed.",
+                    old_table.name
+                )
+                .into(),
+            )
+            .into());
+        }
+        if core.has_in_progress_creation(&key) {
+            return Err(RwError::from(InternalError(
+                "table in creating procedure".to_string(),
+            )));
+        }
+
+        core.mark_creating(&key);
+        for &dependent_relation_id in &table.dependent_relations {
+            core.increase_ref_count(dependent_relation_id);
+        }
+        Ok(())
+    }
+
+    /// Swap materialized view `old_table_id` with `table` in catalog. Relations depending on the
+    /// old one will depend on the new one from now on.
+    ///
+    /// The changes are committed along with `transaction`, which carries the replacement of the
+    /// table fragments, so that the catalog and the streaming graph are always swapped together.
+    /// Frontends are notified by [`Self::notify_table_replaced`] afterwards.
+    pub async fn finish_replace_table_procedure(
+        &self,
+        old_table_id: TableId,
+        table: &Table,
+        mut transaction: Transaction,
+    ) -> Result<()> {
+        let mut core = self.core.lock().await;
+        let key = (table.database_id, table.schema_id, table.name.clone());
+        let old_table = Table::select(self.env.meta_store(), &old_table_id).await?;
+        if let Some(old_table) = old_table && core.has_in_progress_creation(&key) {
+            old_table.delete_in_transaction(&mut transaction)?;
+            table.upsert_in_transaction(&mut transaction)?;
+            for mut dependent_table in Table::list(self.env.meta_store()).await? {
+                if dependent_table.dependent_relations.contains(&old_table_id) {
+                    for relation_id in &mut dependent_table.dependent_relations {
+                        if *relation_id == old_table_id {
+                            *relation_id = table.id;
+                        }
+                    }
+                    dependent_table.upsert_in_transaction(&mut transaction)?;
+                }
+            }
+            for mut view in View::list(self.env.meta_store()).await? {
+                if view.dependent_relations.contains(&old_table_id) {
+                    for relation_id in &mut view.dependent_relations {
+                        if *relation_id == old_table_id {
+                            *relation_id = table.id;
+                        }
+                    }
+                    view.upsert_in_transaction(&mut transaction)?;
+                }
+            }
+            core.env.meta_store().txn(transaction).await?;
+
+            core.unmark_creating(&key);
+            if let Some(ref_count) = core.relation_ref_count.remove(&old_table_id) {
+                core.relation_ref_count.insert(table.id, ref_count);
+            }
+            for &dependent_relation_id in &old_table.dependent_relations {
+                core.decrease_ref_count(dependent_relation_id);
+            }
+
+            Ok(())
+        } else {
+            Err(RwError::from(InternalError(
+                "table doesn't exist or not in creating procedure".to_string(),
+            )))
+        }
+    }
+
+    /// Notify frontends that `table` has replaced the old one.
+    pub async fn notify_table_replaced(&self, table: &Table) -> CatalogVersion {
+        self.env
+            .notification_manager()
+            .notify_frontend(Operation::Update, &Info::TableV2(table.to_owned()))
+            .await
+            .into_inner()
+    }
+
+    pub async fn cancel_replace_table_procedure(&self, table: &Table) -> Result<()> {
+        let mut core = self.core.lock().await;
+        let key = (table.database_id, table.schema_id, table.name.clone());
+        if core.has_in_progress_creation(&key) {
+            core.unmark_creating(&key);
+            for &dependent_relation_id in &table.dependent_relations {
+                core.decrease_ref_count(dependent_relation_id);
+            }
+            Ok(())
+        } else {
+            Err(RwError::from(InternalError(
+                "table not in creating procedure".to_string(),
+            )))
+        }
+    }
+
     pub async fn create_table(&self, table: &Table) -> Result<CatalogVersion> {
         let mut core = self.core.lock().await;
         if !core.has_table(table) {
@@ -417,6 +548,148 @@ where
         }
     }
 
+    pub async fn create_view(&self, view: &View) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        if !core.has_view(view) {
+            view.insert(self.env.meta_store()).await?;
+            core.add_view(view);
+            for &dependent_relation_id in &view.dependent_relations {
+                core.increase_ref_count(dependent_relation_id);
+            }
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Add, &Info::View(view.to_owned()))
+                .await
+                .into_inner();
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "view already exists".to_string(),
+            )))
+        }
+    }
+
+    pub async fn drop_view(&self, view_id: ViewId) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let view = View::select(self.env.meta_store(), &view_id).await?;
+        if let Some(view) = view {
+            match core.get_ref_count(view_id) {
+                Some(ref_count) => Err(CatalogError(
+                    anyhow!(
+                        "Fail to delete view `{}` because {} other relation(s) depend on it.",
+                        view.name,
+                        ref_count
+                    )
+                    .into(),
+                )
+                .into()),
+                None => {
+                    View::delete(self.env.meta_store(), &view_id).await?;
+                    core.drop_view(&view);
+                    for &dependent_relation_id in &view.dependent_relations {
+                        core.decrease_ref_count(dependent_relation_id);
+                    }
+
+                    let version = self
+                        .env
+                        .notification_manager()
+                        .notify_frontend(Operation::Delete, &Info::View(view))
+                        .await
+                        .into_inner();
+
+                    Ok(version)
+                }
+            }
+        } else {
+            Err(RwError::from(InternalError(
+                "view doesn't exist".to_string(),
+            )))
+        }
+    }
+
+    pub async fn start_create_sink_procedure(&self, sink: &Sink) -> Result<()> {
+        let mut core = self.core.lock().await;
+        let key = (sink.database_id, sink.schema_id, sink.name.clone());
+        if !core.has_sink(sink) && !core.has_in_progress_creation(&key) {
+            core.mark_creating(&key);
+            for &dependent_relation_id in &sink.dependent_relations {
+                core.increase_ref_count(dependent_relation_id);
+            }
+            Ok(())
+        } else {
+            Err(RwError::from(InternalError(
+                "sink already exists or in creating procedure".to_string(),
+            )))
+        }
+    }
+
+    pub async fn finish_create_sink_procedure(&self, sink: &Sink) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let key = (sink.database_id, sink.schema_id, sink.name.clone());
+        if !core.has_sink(sink) && core.has_in_progress_creation(&key) {
+            core.unmark_creating(&key);
+            sink.insert(self.env.meta_store()).await?;
+            core.add_sink(sink);
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Add, &Info::Sink(sink.to_owned()))
+                .await
+                .into_inner();
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "sink already exist or not in creating procedure".to_string(),
+            )))
+        }
+    }
+
+    pub async fn cancel_create_sink_procedure(&self, sink: &Sink) -> Result<()> {
+        let mut core = self.core.lock().await;
+        let key = (sink.database_id, sink.schema_id, sink.name.clone());
+        if !core.has_sink(sink) && core.has_in_progress_creation(&key) {
+            core.unmark_creating(&key);
+            for &dependent_relation_id in &sink.dependent_relations {
+                core.decrease_ref_count(dependent_relation_id);
+            }
+            Ok(())
+        } else {
+            Err(RwError::from(InternalError(
+                "sink already exist or not in creating procedure".to_string(),
+            )))
+        }
+    }
+
+    pub async fn drop_sink(&self, sink_id: SinkId) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let sink = Sink::select(self.env.meta_store(), &sink_id).await?;
+        if let Some(sink) = sink {
+            Sink::delete(self.env.meta_store(), &sink_id).await?;
+            core.drop_sink(&sink);
+            for &dependent_relation_id in &sink.dependent_relations {
+                core.decrease_ref_count(dependent_relation_id);
+            }
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, &Info::Sink(sink))
+                .await
+                .into_inner();
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "sink doesn't exist".to_string(),
+            )))
+        }
+    }
+
     pub async fn start_create_materialized_source_procedure(
         &self,
         source: &Source,
@@ -589,6 +862,8 @@ type DatabaseKey = String;
 type SchemaKey = (DatabaseId, String);
 type TableKey = (DatabaseId, SchemaId, String);
 type SourceKey = (DatabaseId, SchemaId, String);
+type ViewKey = (DatabaseId, SchemaId, String);
+type SinkKey = (DatabaseId, SchemaId, String);
 type RelationKey = (DatabaseId, SchemaId, String);
 
 /// [`CatalogManagerCore`] caches meta catalog information and maintains dependent relationship
@@ -603,6 +878,10 @@ pub struct CatalogManagerCore<S: MetaStore> {
     sources: HashSet<SourceKey>,
     /// Cached table key information.
     tables: HashSet<TableKey>,
+    /// Cached view key information.
+    views: HashSet<ViewKey>,
+    /// Cached sink key information.
+    sinks: HashSet<SinkKey>,
     /// Relation refer count mapping.
     relation_ref_count: HashMap<RelationId, usize>,
 
@@ -619,6 +898,8 @@ where
         let schemas = Schema::list(env.meta_store()).await?;
         let sources = Source::list(env.meta_store()).await?;
         let tables = Table::list(env.meta_store()).await?;
+        let views = View::list(env.meta_store()).await?;
+        let sinks = Sink::list(env.meta_store()).await?;
 
         let mut relation_ref_count = HashMap::new();
 
@@ -639,6 +920,18 @@ where
             }
             (table.database_id, table.schema_id, table.name)
         }));
+        let views = HashSet::from_iter(views.into_iter().map(|view| {
+            for depend_relation_id in &view.dependent_relations {
+                *relation_ref_count.entry(*depend_relation_id).or_insert(0) += 1;
+            }
+            (view.database_id, view.schema_id, view.name)
+        }));
+        let sinks = HashSet::from_iter(sinks.into_iter().map(|sink| {
+            for depend_relation_id in &sink.dependent_relations {
+                *relation_ref_count.entry(*depend_relation_id).or_insert(0) += 1;
+            }
+            (sink.database_id, sink.schema_id, sink.name)
+        }));
 
         let in_progress_creation_tracker = HashSet::new();
 
@@ -648,6 +941,8 @@ where
             schemas,
             sources,
             tables,
+            views,
+            sinks,
             relation_ref_count,
             in_progress_creation_tracker,
         })
@@ -659,6 +954,8 @@ where
             Schema::list(self.env.meta_store()).await?,
             Table::list(self.env.meta_store()).await?,
             Source::list(self.env.meta_store()).await?,
+            View::list(self.env.meta_store()).await?,
+            Sink::list(self.env.meta_store()).await?,
         ))
     }
 
@@ -723,6 +1020,36 @@ where
             .remove(&(source.database_id, source.schema_id, source.name.clone()))
     }
 
+    fn has_view(&self, view: &View) -> bool {
+        self.views
+            .contains(&(view.database_id, view.schema_id, view.name.clone()))
+    }
+
+    fn add_view(&mut self, view: &View) {
+        self.views
+            .insert((view.database_id, view.schema_id, view.name.clone()));
+    }
+
+    fn drop_view(&mut self, view: &View) -> bool {
+        self.views
+            .remove(&(view.database_id, view.schema_id, view.name.clone()))
+    }
+
+    fn has_sink(&self, sink: &Sink) -> bool {
+        self.sinks
+            .contains(&(sink.database_id, sink.schema_id, sink.name.clone()))
+    }
+
+    fn add_sink(&mut self, sink: &Sink) {
+        self.sinks
+            .insert((sink.database_id, sink.schema_id, sink.name.clone()));
+    }
+
+    fn drop_sink(&mut self, sink: &Sink) -> bool {
+        self.sinks
+            .remove(&(sink.database_id, sink.schema_id, sink.name.clone()))
+    }
+
     fn get_ref_count(&self, relation_id: RelationId) -> Option<usize> {
         self.relation_ref_count.get(&relation_id).cloned()
     }
@@ -756,3 +1083,19 @@ where
         self.in_progress_creation_tracker.remove(&relation.clone());
     }
 }
+
+/// Returns whether `new` has the same visible columns as `old`, in names, types and order.
+fn is_schema_compatible(old: &Table, new: &Table) -> bool {
+    let visible_columns = |table: &Table| {
+        table
+            .columns
+            .iter()
+            .filter(|column| !column.is_hidden)
+            .map(|column| {
+                let desc = column.column_desc.as_ref().unwrap();
+                (desc.name.clone(), desc.column_type.clone())
+            })
+            .collect::<Vec<_>>()
+    };
+    visible_columns(old) == visible_columns(new)
+}
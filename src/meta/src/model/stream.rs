@@ -138,6 +138,17 @@ impl TableFragments {
         Self::filter_actor_ids(self, FragmentType::Sink)
     }
 
+    /// Returns the actor ids of downstream materialized views that sink actors dispatch to.
+    pub fn sink_downstream_actor_ids(&self) -> Vec<ActorId> {
+        self.fragments
+            .values()
+            .filter(|fragment| fragment.fragment_type == FragmentType::Sink as i32)
+            .flat_map(|fragment| fragment.actors.iter())
+            .flat_map(|actor| actor.dispatcher.iter())
+            .flat_map(|dispatcher| dispatcher.downstream_actor_id.iter().cloned())
+            .collect()
+    }
+
     fn contains_chain(stream_node: &StreamNode) -> bool {
         if let Some(Node::ChainNode(_)) = stream_node.node {
             return true;
@@ -177,6 +188,42 @@ impl TableFragments {
         }
     }
 
+    /// Let the `Chain` on table `old_table_id` in `stream_node` read from table `new_table_id` and
+    /// merge from `upstream_actor_ids` instead.
+    pub fn replace_chain_upstream(
+        stream_node: &mut StreamNode,
+        old_table_id: &TableId,
+        new_table_id: &TableId,
+        upstream_actor_ids: &[ActorId],
+    ) {
+        if let Some(Node::ChainNode(chain)) = stream_node.node.as_mut() {
+            if TableId::from(&chain.table_ref_id) == *old_table_id {
+                let new_table_ref_id = new_table_id.table_id() as i32;
+                if let Some(table_ref_id) = chain.table_ref_id.as_mut() {
+                    table_ref_id.table_id = new_table_ref_id;
+                }
+                for input in &mut stream_node.input {
+                    match input.node.as_mut() {
+                        Some(Node::MergeNode(merge)) => {
+                            merge.upstream_actor_id = upstream_actor_ids.to_vec();
+                        }
+                        Some(Node::BatchPlanNode(batch_plan)) => {
+                            if let Some(table_ref_id) = batch_plan.table_ref_id.as_mut() {
+                                table_ref_id.table_id = new_table_ref_id;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            return;
+        }
+
+        for child in &mut stream_node.input {
+            Self::replace_chain_upstream(child, old_table_id, new_table_id, upstream_actor_ids);
+        }
+    }
+
     /// Returns dependent table ids.
     pub fn dependent_table_ids(&self) -> HashSet<TableId> {
         let mut table_ids = HashSet::new();
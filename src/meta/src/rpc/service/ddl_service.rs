@@ -16,7 +16,8 @@
 use std::collections::HashSet;
 
 use risingwave_common::catalog::CatalogVersion;
-use risingwave_common::error::{tonic_err, Result as RwResult};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{tonic_err, Result as RwResult, RwError};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::*;
 use risingwave_pb::ddl_service::ddl_service_server::DdlService;
@@ -31,7 +32,8 @@ use crate::manager::{CatalogManagerRef, IdCategory, MetaSrvEnv, SourceId, TableI
 use crate::model::TableFragments;
 use crate::storage::MetaStore;
 use crate::stream::{
-    FragmentManagerRef, GlobalSourceManagerRef, GlobalStreamManagerRef, StreamFragmenter,
+    CreateMaterializedViewContext, FragmentManagerRef, GlobalSourceManagerRef,
+    GlobalStreamManagerRef, StreamFragmenter,
 };
 
 #[derive(Clone)]
@@ -241,36 +243,7 @@ where
         mview.id = id;
 
         // 1. Resolve the dependent relations.
-        {
-            // TODO: distinguish SourceId and TableId
-            fn resolve_dependent_relations(
-                stream_node: &StreamNode,
-                dependent_relations: &mut HashSet<TableId>,
-            ) -> RwResult<()> {
-                match stream_node.node.as_ref().unwrap() {
-                    Node::SourceNode(source_node) => {
-                        dependent_relations.insert(source_node.get_table_ref_id()?.table_id as u32);
-                    }
-                    Node::ChainNode(chain_node) => {
-                        dependent_relations.insert(chain_node.get_table_ref_id()?.table_id as u32);
-                    }
-                    _ => {}
-                }
-                for child in &stream_node.input {
-                    resolve_dependent_relations(child, dependent_relations)?;
-                }
-                Ok(())
-            }
-
-            let mut dependent_relations = Default::default();
-            resolve_dependent_relations(&stream_node, &mut dependent_relations)
-                .map_err(tonic_err)?;
-            assert!(
-                !dependent_relations.is_empty(),
-                "there should be at lease 1 dependent relation when creating materialized view"
-            );
-            mview.dependent_relations = dependent_relations.into_iter().collect();
-        }
+        mview.dependent_relations = resolve_dependent_relations(&stream_node).map_err(tonic_err)?;
 
         // 2. Mark current mview as "creating" and add reference count to dependent relations.
         self.catalog_manager
@@ -327,6 +300,26 @@ where
         }))
     }
 
+    async fn replace_materialized_view(
+        &self,
+        request: Request<ReplaceMaterializedViewRequest>,
+    ) -> Result<Response<ReplaceMaterializedViewResponse>, Status> {
+        let req = request.into_inner();
+        let mview = req.get_materialized_view().map_err(tonic_err)?.clone();
+        let stream_node = req.get_stream_node().map_err(tonic_err)?.clone();
+
+        let (table_id, version) = self
+            .replace_materialized_view_inner(mview, stream_node)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(ReplaceMaterializedViewResponse {
+            status: None,
+            table_id,
+            version,
+        }))
+    }
+
     async fn create_materialized_source(
         &self,
         request: Request<CreateMaterializedSourceRequest>,
@@ -367,6 +360,127 @@ where
             version,
         }))
     }
+
+    async fn create_view(
+        &self,
+        request: Request<CreateViewRequest>,
+    ) -> Result<Response<CreateViewResponse>, Status> {
+        let req = request.into_inner();
+        let mut view = req.get_view().map_err(tonic_err)?.clone();
+
+        // A view only lives in the catalog, so there is nothing to create on compute nodes.
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Table }>()
+            .await
+            .map_err(tonic_err)? as u32;
+        view.id = id;
+        let version = self
+            .catalog_manager
+            .create_view(&view)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(CreateViewResponse {
+            status: None,
+            view_id: id,
+            version,
+        }))
+    }
+
+    async fn drop_view(
+        &self,
+        request: Request<DropViewRequest>,
+    ) -> Result<Response<DropViewResponse>, Status> {
+        let view_id = request.into_inner().view_id;
+        // Ref count will be checked.
+        let version = self
+            .catalog_manager
+            .drop_view(view_id)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(DropViewResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn create_sink(
+        &self,
+        request: Request<CreateSinkRequest>,
+    ) -> Result<Response<CreateSinkResponse>, Status> {
+        let req = request.into_inner();
+        let mut sink = req.get_sink().map_err(tonic_err)?.clone();
+        let stream_node = req.get_stream_node().map_err(tonic_err)?.clone();
+
+        // 0. Generate an id for the sink.
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Table }>()
+            .await
+            .map_err(tonic_err)? as u32;
+        sink.id = id;
+
+        // 1. Resolve the dependent relations.
+        sink.dependent_relations = resolve_dependent_relations(&stream_node).map_err(tonic_err)?;
+
+        // 2. Mark current sink as "creating" and add reference count to dependent relations.
+        self.catalog_manager
+            .start_create_sink_procedure(&sink)
+            .await
+            .map_err(tonic_err)?;
+
+        // 3. Create sink in stream manager. The id in stream node will be filled.
+        if let Err(e) = self.create_mview_on_compute_node(stream_node, id).await {
+            self.catalog_manager
+                .cancel_create_sink_procedure(&sink)
+                .await
+                .map_err(tonic_err)?;
+            return Err(e.to_grpc_status());
+        }
+
+        // 4. Finally, update the catalog.
+        let version = self
+            .catalog_manager
+            .finish_create_sink_procedure(&sink)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(CreateSinkResponse {
+            status: None,
+            sink_id: id,
+            version,
+        }))
+    }
+
+    async fn drop_sink(
+        &self,
+        request: Request<DropSinkRequest>,
+    ) -> Result<Response<DropSinkResponse>, Status> {
+        use risingwave_common::catalog::TableId;
+
+        let sink_id = request.into_inner().sink_id;
+        // 1. Drop sink in catalog.
+        let version = self
+            .catalog_manager
+            .drop_sink(sink_id)
+            .await
+            .map_err(tonic_err)?;
+
+        // 2. Drop sink in stream manager.
+        self.stream_manager
+            .drop_materialized_view(&TableId::new(sink_id))
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(DropSinkResponse {
+            status: None,
+            version,
+        }))
+    }
 }
 
 impl<S> DdlServiceImpl<S>
@@ -375,19 +489,41 @@ where
 {
     async fn create_mview_on_compute_node(
         &self,
-        mut stream_node: StreamNode,
+        stream_node: StreamNode,
         id: TableId,
     ) -> RwResult<()> {
-        use risingwave_common::catalog::TableId;
+        let (table_fragments, ctx) = self.gen_table_fragments(stream_node, id).await?;
 
-        use crate::stream::CreateMaterializedViewContext;
+        // Create on compute node.
+        self.stream_manager
+            .create_materialized_view(table_fragments, ctx)
+            .await?;
 
-        // Fill in the correct mview id for stream node.
+        Ok(())
+    }
+
+    /// Fill in the mview id for `stream_node` and resolve its fragments.
+    async fn gen_table_fragments(
+        &self,
+        mut stream_node: StreamNode,
+        id: TableId,
+    ) -> RwResult<(TableFragments, CreateMaterializedViewContext)> {
+        use risingwave_common::catalog::TableId;
+
+        // Fill in the correct mview id for stream node. A sink is deployed the same way as an
+        // mview, with its id filled in the sink node.
         fn fill_mview_id(stream_node: &mut StreamNode, mview_id: TableId) -> usize {
             let mut mview_count = 0;
-            if let Node::MaterializeNode(materialize_node) = stream_node.node.as_mut().unwrap() {
-                materialize_node.table_ref_id = TableRefId::from(&mview_id).into();
-                mview_count += 1;
+            match stream_node.node.as_mut().unwrap() {
+                Node::MaterializeNode(materialize_node) => {
+                    materialize_node.table_ref_id = TableRefId::from(&mview_id).into();
+                    mview_count += 1;
+                }
+                Node::SinkNode(sink_node) => {
+                    sink_node.table_ref_id = TableRefId::from(&mview_id).into();
+                    mview_count += 1;
+                }
+                _ => {}
             }
             for input in &mut stream_node.input {
                 mview_count += fill_mview_id(input, mview_id);
@@ -399,7 +535,7 @@ where
         let mview_count = fill_mview_id(&mut stream_node, mview_id);
         assert_eq!(
             mview_count, 1,
-            "require exactly 1 materialize node when creating materialized view"
+            "require exactly 1 materialize or sink node when creating materialized view or sink"
         );
 
         // Resolve fragments.
@@ -413,12 +549,65 @@ where
         let graph = fragmenter.generate_graph(&stream_node, &mut ctx).await?;
         let table_fragments = TableFragments::new(mview_id, graph);
 
-        // Create on compute node.
-        self.stream_manager
-            .create_materialized_view(table_fragments, ctx)
+        Ok((table_fragments, ctx))
+    }
+
+    async fn replace_materialized_view_inner(
+        &self,
+        mut mview: Table,
+        stream_node: StreamNode,
+    ) -> RwResult<(TableId, CatalogVersion)> {
+        use risingwave_common::catalog::TableId;
+
+        // 0. The new mview is created aside with a new id, which will take place of the old one.
+        let old_id = mview.id;
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Table }>()
+            .await? as u32;
+        mview.id = id;
+
+        // 1. Resolve the dependent relations.
+        mview.dependent_relations = resolve_dependent_relations(&stream_node)?;
+        if mview.dependent_relations.contains(&old_id) {
+            return Err(RwError::from(InternalError(format!(
+                "materialized view `{}` can't be replaced by a query on itself",
+                mview.name
+            ))));
+        }
+
+        // 2. Check the schema, mark current mview as "creating" and add reference count to
+        // dependent relations.
+        self.catalog_manager
+            .start_replace_table_procedure(old_id, &mview)
             .await?;
 
-        Ok(())
+        // 3. Create the new mview and swap it with the old one, both in stream manager and in
+        // catalog.
+        let result = async {
+            let (table_fragments, ctx) = self.gen_table_fragments(stream_node, id).await?;
+            self.stream_manager
+                .replace_materialized_view(
+                    &TableId::new(old_id),
+                    mview.clone(),
+                    table_fragments,
+                    ctx,
+                )
+                .await
+        }
+        .await;
+        if let Err(e) = result {
+            self.catalog_manager
+                .cancel_replace_table_procedure(&mview)
+                .await?;
+            return Err(e);
+        }
+
+        // 4. Finally, notify frontends about the swap.
+        let version = self.catalog_manager.notify_table_replaced(&mview).await;
+
+        Ok((id, version))
     }
 
     async fn create_materialized_source_inner(
@@ -525,3 +714,34 @@ where
         Ok(version)
     }
 }
+
+/// Resolve the relations that `stream_node` reads from.
+// TODO: distinguish SourceId and TableId
+fn resolve_dependent_relations(stream_node: &StreamNode) -> RwResult<Vec<TableId>> {
+    fn resolve(
+        stream_node: &StreamNode,
+        dependent_relations: &mut HashSet<TableId>,
+    ) -> RwResult<()> {
+        match stream_node.node.as_ref().unwrap() {
+            Node::SourceNode(source_node) => {
+                dependent_relations.insert(source_node.get_table_ref_id()?.table_id as u32);
+            }
+            Node::ChainNode(chain_node) => {
+                dependent_relations.insert(chain_node.get_table_ref_id()?.table_id as u32);
+            }
+            _ => {}
+        }
+        for child in &stream_node.input {
+            resolve(child, dependent_relations)?;
+        }
+        Ok(())
+    }
+
+    let mut dependent_relations = HashSet::new();
+    resolve(stream_node, &mut dependent_relations)?;
+    assert!(
+        !dependent_relations.is_empty(),
+        "there should be at lease 1 dependent relation when creating materialized view or sink"
+    );
+    Ok(dependent_relations.into_iter().collect())
+}
@@ -131,7 +131,9 @@ where
         // Update current fragment based on the node we're visiting.
         match stream_node.get_node()? {
             Node::SourceNode(_) => current_fragment.set_fragment_type(FragmentType::Source),
-            Node::MaterializeNode(_) => current_fragment.set_fragment_type(FragmentType::Sink),
+            Node::MaterializeNode(_) | Node::SinkNode(_) => {
+                current_fragment.set_fragment_type(FragmentType::Sink)
+            }
 
             // TODO: Force singleton for TopN as a workaround. We should implement two phase TopN.
             Node::TopNNode(_) => current_fragment.set_singleton(true),
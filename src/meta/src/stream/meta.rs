@@ -220,6 +220,116 @@ where
         }
     }
 
+    /// Write the replacement of table fragments `old_table_id` by `new_table_id` in `transaction`:
+    /// 1. drop the old table fragments as [`Self::drop_table_fragments`] does.
+    /// 2. let the sink actors of the new table fragments dispatch to the actors in `dispatches`.
+    /// 3. let the downstream actors in `merge_updates` merge from the new sink actors and read
+    /// from the new table.
+    ///
+    /// Returns the updated table fragments, which should be applied by
+    /// [`Self::finish_replace_table_fragments`] after `transaction` is committed.
+    pub async fn replace_table_fragments_in_transaction(
+        &self,
+        old_table_id: &TableId,
+        new_table_id: &TableId,
+        dispatches: &HashMap<ActorId, Vec<ActorId>>,
+        merge_updates: &HashMap<ActorId, Vec<ActorId>>,
+        transaction: &mut Transaction,
+    ) -> Result<Vec<TableFragments>> {
+        let map = &self.core.read().await.table_fragments;
+        let get_table_fragments = |table_id: &TableId| {
+            map.get(table_id).ok_or_else(|| {
+                RwError::from(InternalError(format!(
+                    "table_fragment not exist: id={}",
+                    table_id
+                )))
+            })
+        };
+
+        let old_table_fragments = get_table_fragments(old_table_id)?;
+        old_table_fragments.delete_in_transaction(transaction)?;
+
+        let mut updated_tables = HashMap::new();
+        let chain_actor_ids = old_table_fragments.chain_actor_ids();
+        for dependent_table_id in old_table_fragments.dependent_table_ids() {
+            let dependent_table = match updated_tables.entry(dependent_table_id) {
+                Entry::Occupied(o) => o.into_mut(),
+                Entry::Vacant(v) => v.insert(get_table_fragments(&dependent_table_id)?.clone()),
+            };
+            for fragment in dependent_table.fragments.values_mut() {
+                if fragment.fragment_type == FragmentType::Sink as i32 {
+                    for actor in &mut fragment.actors {
+                        actor.dispatcher[0]
+                            .downstream_actor_id
+                            .retain(|x| !chain_actor_ids.contains(x));
+                    }
+                }
+            }
+        }
+
+        let new_table = match updated_tables.entry(*new_table_id) {
+            Entry::Occupied(o) => o.into_mut(),
+            Entry::Vacant(v) => v.insert(get_table_fragments(new_table_id)?.clone()),
+        };
+        for fragment in new_table.fragments.values_mut() {
+            for actor in &mut fragment.actors {
+                if let Some(downstream_actors) = dispatches.get(&actor.actor_id) {
+                    actor.dispatcher[0]
+                        .downstream_actor_id
+                        .extend(downstream_actors.iter().cloned());
+                }
+            }
+        }
+
+        let old_sink_actor_ids: HashSet<ActorId> =
+            old_table_fragments.sink_actor_ids().into_iter().collect();
+        for (table_id, table_fragments) in map {
+            if !table_fragments.dependent_table_ids().contains(old_table_id) {
+                continue;
+            }
+            let downstream_table = updated_tables
+                .entry(*table_id)
+                .or_insert_with(|| table_fragments.clone());
+            for fragment in downstream_table.fragments.values_mut() {
+                for actor in &mut fragment.actors {
+                    if let Some(upstream_actors) = merge_updates.get(&actor.actor_id) {
+                        actor
+                            .upstream_actor_id
+                            .retain(|x| !old_sink_actor_ids.contains(x));
+                        actor
+                            .upstream_actor_id
+                            .extend(upstream_actors.iter().cloned());
+                        TableFragments::replace_chain_upstream(
+                            actor.nodes.as_mut().unwrap(),
+                            old_table_id,
+                            new_table_id,
+                            upstream_actors,
+                        );
+                    }
+                }
+            }
+        }
+
+        for table_fragments in updated_tables.values() {
+            table_fragments.upsert_in_transaction(transaction)?;
+        }
+
+        Ok(updated_tables.into_values().collect())
+    }
+
+    /// Apply the replacement written by [`Self::replace_table_fragments_in_transaction`].
+    pub async fn finish_replace_table_fragments(
+        &self,
+        old_table_id: &TableId,
+        updated_tables: Vec<TableFragments>,
+    ) {
+        let map = &mut self.core.write().await.table_fragments;
+        map.remove(old_table_id);
+        for table_fragments in updated_tables {
+            map.insert(table_fragments.table_id(), table_fragments);
+        }
+    }
+
     /// Used in [`crate::barrier::GlobalBarrierManager`]
     pub async fn load_all_actors(&self, with_creating_table: Option<TableId>) -> ActorInfos {
         let mut actor_maps = HashMap::new();
@@ -279,6 +389,20 @@ where
         actor_maps
     }
 
+    /// Returns the node ids of all actors.
+    pub async fn all_actor_node_ids(&self) -> HashMap<ActorId, WorkerId> {
+        let map = &self.core.read().await.table_fragments;
+
+        map.values()
+            .flat_map(|table_fragment| table_fragment.node_actor_ids())
+            .flat_map(|(node_id, actor_ids)| {
+                actor_ids
+                    .into_iter()
+                    .map(move |actor_id| (actor_id, node_id))
+            })
+            .collect()
+    }
+
     pub async fn all_chain_actor_ids(&self) -> HashSet<ActorId> {
         let map = &self.core.read().await.table_fragments;
 
@@ -323,6 +447,20 @@ where
         }
     }
 
+    pub async fn get_table_sink_downstream_actor_ids(
+        &self,
+        table_id: &TableId,
+    ) -> Result<Vec<ActorId>> {
+        let map = &self.core.read().await.table_fragments;
+        match map.get(table_id) {
+            Some(table_fragment) => Ok(table_fragment.sink_downstream_actor_ids()),
+            None => Err(RwError::from(InternalError(format!(
+                "table_fragment not exist: id={}",
+                table_id
+            )))),
+        }
+    }
+
     // TODO(bugen): remove this.
     pub fn blocking_table_node_actors(
         &self,
@@ -21,7 +21,8 @@ use log::{debug, info};
 use risingwave_common::catalog::TableId;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, ToRwResult};
-use risingwave_pb::common::{ActorInfo, WorkerType};
+use risingwave_pb::catalog::Table;
+use risingwave_pb::common::{ActorInfo, WorkerNode, WorkerType};
 use risingwave_pb::meta::table_fragments::{ActorState, ActorStatus};
 use risingwave_pb::stream_service::{
     BroadcastActorInfoTableRequest, BuildActorsRequest, HangingChannel, UpdateActorsRequest,
@@ -281,6 +282,161 @@ where
         Ok(())
     }
 
+    /// Replace materialized view `old_table_id` with the one described by `table_fragments` and
+    /// `table`, it works as follows:
+    /// 1. create the new materialized view as [`Self::create_materialized_view`] does, which
+    /// returns after its `Chain`s have finished backfilling.
+    /// 2. initialize the channels from the new sink actors to the downstream `Chain` actors of the
+    /// old materialized view on compute nodes.
+    /// 3. stop the old actors, re-point the downstream actors to the new sink actors, and swap the
+    /// table fragments and the catalog at one barrier, check [`Command::ReplaceMaterializedView`]
+    /// for details.
+    ///
+    /// If it fails, the new materialized view is dropped and the old one keeps serving.
+    pub async fn replace_materialized_view(
+        &self,
+        old_table_id: &TableId,
+        table: Table,
+        table_fragments: TableFragments,
+        ctx: CreateMaterializedViewContext,
+    ) -> Result<()> {
+        let new_table_id = table_fragments.table_id();
+        self.create_materialized_view(table_fragments, ctx).await?;
+
+        if let Err(e) = self
+            .replace_materialized_view_inner(old_table_id, &new_table_id, table)
+            .await
+        {
+            // Keep the old one serving, drop the new one instead.
+            self.drop_materialized_view(&new_table_id).await?;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    async fn replace_materialized_view_inner(
+        &self,
+        old_table_id: &TableId,
+        new_table_id: &TableId,
+        table: Table,
+    ) -> Result<()> {
+        let downstream_actor_ids = self
+            .fragment_manager
+            .get_table_sink_downstream_actor_ids(old_table_id)
+            .await?
+            .into_iter()
+            .unique()
+            .collect_vec();
+        let sink_actor_ids = self
+            .fragment_manager
+            .get_table_sink_actor_ids(new_table_id)
+            .await?;
+
+        let mut dispatches = HashMap::new();
+        let mut merge_updates = HashMap::new();
+        if !downstream_actor_ids.is_empty() {
+            let node_locations: HashMap<WorkerId, WorkerNode> = self
+                .cluster_manager
+                .list_worker_node(
+                    WorkerType::ComputeNode,
+                    Some(risingwave_pb::common::worker_node::State::Running),
+                )
+                .await
+                .into_iter()
+                .map(|node| (node.id, node))
+                .collect();
+            let actor_node_ids = self.fragment_manager.all_actor_node_ids().await;
+            let actor_location = |actor_id: &ActorId| -> Result<(WorkerId, ActorInfo)> {
+                let node = actor_node_ids
+                    .get(actor_id)
+                    .and_then(|node_id| node_locations.get(node_id))
+                    .ok_or_else(|| {
+                        InternalError(format!("location of actor {} not exist", actor_id))
+                    })?;
+                Ok((
+                    node.id,
+                    ActorInfo {
+                        actor_id: *actor_id,
+                        host: node.host.clone(),
+                    },
+                ))
+            };
+
+            // Channels on the nodes of the new sink actors must be ready before the remote
+            // downstream actors connect to them.
+            let mut upstream_node_hanging_channels: HashMap<WorkerId, Vec<HangingChannel>> =
+                HashMap::new();
+            let mut downstream_node_hanging_channels: HashMap<WorkerId, Vec<HangingChannel>> =
+                HashMap::new();
+            for up_id in &sink_actor_ids {
+                let (up_node_id, up_info) = actor_location(up_id)?;
+                let mut down_infos = Vec::with_capacity(downstream_actor_ids.len());
+                for down_id in &downstream_actor_ids {
+                    let (down_node_id, down_info) = actor_location(down_id)?;
+                    upstream_node_hanging_channels
+                        .entry(up_node_id)
+                        .or_default()
+                        .push(HangingChannel {
+                            upstream: Some(ActorInfo {
+                                actor_id: *up_id,
+                                host: None,
+                            }),
+                            downstream: Some(down_info.clone()),
+                        });
+                    if down_node_id != up_node_id {
+                        downstream_node_hanging_channels
+                            .entry(down_node_id)
+                            .or_default()
+                            .push(HangingChannel {
+                                upstream: Some(up_info.clone()),
+                                downstream: Some(ActorInfo {
+                                    actor_id: *down_id,
+                                    host: None,
+                                }),
+                            });
+                    }
+                    down_infos.push(down_info);
+                }
+                dispatches.insert(*up_id, down_infos);
+            }
+            for down_id in &downstream_actor_ids {
+                merge_updates.insert(*down_id, sink_actor_ids.clone());
+            }
+
+            for node_hanging_channels in [
+                upstream_node_hanging_channels,
+                downstream_node_hanging_channels,
+            ] {
+                for (node_id, hanging_channels) in node_hanging_channels {
+                    let node = node_locations.get(&node_id).unwrap();
+                    let client = self.clients.get(node).await?;
+                    let request_id = Uuid::new_v4().to_string();
+
+                    client
+                        .to_owned()
+                        .update_actors(UpdateActorsRequest {
+                            request_id,
+                            actors: vec![],
+                            hanging_channels,
+                        })
+                        .await
+                        .to_rw_result_with(|| format!("failed to connect to {}", node_id))?;
+                }
+            }
+        }
+
+        self.barrier_manager
+            .run_command(Command::ReplaceMaterializedView {
+                old_table_id: *old_table_id,
+                new_table_id: *new_table_id,
+                table,
+                dispatches,
+                merge_updates,
+            })
+            .await
+    }
+
     /// Dropping materialized view is done by barrier manager. Check
     /// [`Command::DropMaterializedView`] for details.
     pub async fn drop_materialized_view(&self, table_id: &TableId) -> Result<()> {
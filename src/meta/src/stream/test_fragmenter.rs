@@ -225,6 +225,8 @@ fn make_stream_node() -> StreamNode {
                 make_inputref(0),
                 make_inputref(1),
             ],
+            watermark_input_cols: vec![],
+            watermark_output_cols: vec![],
         })),
         input: vec![simple_agg_node_1],
         pk_indices: vec![1, 2],
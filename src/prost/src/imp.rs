@@ -104,6 +104,15 @@ impl crate::plan::ColumnDesc {
             column_type: Some(DataType {
                 type_name: TypeName::Struct as i32,
                 is_nullable: true,
+                field_type: fields
+                    .iter()
+                    .map(|f| f.column_type.clone().unwrap())
+                    .collect(),
+                // The field descs are named by their full paths, e.g. `country.city`.
+                field_names: fields
+                    .iter()
+                    .map(|f| f.name.rsplit('.').next().unwrap().to_string())
+                    .collect(),
                 ..Default::default()
             }),
             column_id,
@@ -23,17 +23,20 @@ use risingwave_common::try_match_expand;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::{HummockEpoch, HummockSSTableId, HummockVersionId};
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Schema as ProstSchema, Sink as ProstSink, Source as ProstSource,
+    Table as ProstTable, View as ProstView,
 };
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
 use risingwave_pb::ddl_service::{
     CreateDatabaseRequest, CreateDatabaseResponse, CreateMaterializedSourceRequest,
     CreateMaterializedSourceResponse, CreateMaterializedViewRequest,
-    CreateMaterializedViewResponse, CreateSchemaRequest, CreateSchemaResponse, CreateSourceRequest,
-    CreateSourceResponse, DropMaterializedSourceRequest, DropMaterializedSourceResponse,
-    DropMaterializedViewRequest, DropMaterializedViewResponse, DropSourceRequest,
-    DropSourceResponse,
+    CreateMaterializedViewResponse, CreateSchemaRequest, CreateSchemaResponse, CreateSinkRequest,
+    CreateSinkResponse, CreateSourceRequest, CreateSourceResponse, CreateViewRequest,
+    CreateViewResponse, DropMaterializedSourceRequest, DropMaterializedSourceResponse,
+    DropMaterializedViewRequest, DropMaterializedViewResponse, DropSinkRequest, DropSinkResponse,
+    DropSourceRequest, DropSourceResponse, DropViewRequest, DropViewResponse,
+    ReplaceMaterializedViewRequest, ReplaceMaterializedViewResponse,
 };
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
 use risingwave_pb::hummock::{
@@ -166,6 +169,20 @@ impl MetaClient {
         Ok((resp.table_id.into(), resp.version))
     }
 
+    /// Replace the materialized view of `table.id` with `plan`. Returns the id of the new one.
+    pub async fn replace_materialized_view(
+        &self,
+        table: ProstTable,
+        plan: StreamNode,
+    ) -> Result<(TableId, CatalogVersion)> {
+        let request = ReplaceMaterializedViewRequest {
+            materialized_view: Some(table),
+            stream_node: Some(plan),
+        };
+        let resp = self.inner.replace_materialized_view(request).await?;
+        Ok((resp.table_id.into(), resp.version))
+    }
+
     pub async fn drop_materialized_view(&self, table_id: TableId) -> Result<CatalogVersion> {
         let request = DropMaterializedViewRequest {
             table_id: table_id.table_id(),
@@ -220,6 +237,37 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn create_view(&self, view: ProstView) -> Result<(u32, CatalogVersion)> {
+        let request = CreateViewRequest { view: Some(view) };
+        let resp = self.inner.create_view(request).await?;
+        Ok((resp.view_id, resp.version))
+    }
+
+    pub async fn drop_view(&self, view_id: u32) -> Result<CatalogVersion> {
+        let request = DropViewRequest { view_id };
+        let resp = self.inner.drop_view(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn create_sink(
+        &self,
+        sink: ProstSink,
+        plan: StreamNode,
+    ) -> Result<(u32, CatalogVersion)> {
+        let request = CreateSinkRequest {
+            sink: Some(sink),
+            stream_node: Some(plan),
+        };
+        let resp = self.inner.create_sink(request).await?;
+        Ok((resp.sink_id, resp.version))
+    }
+
+    pub async fn drop_sink(&self, sink_id: u32) -> Result<CatalogVersion> {
+        let request = DropSinkRequest { sink_id };
+        let resp = self.inner.drop_sink(request).await?;
+        Ok(resp.version)
+    }
+
     /// Unregister the current node to the cluster.
     pub async fn unregister(&self, addr: HostAddr) -> Result<()> {
         let request = DeleteWorkerNodeRequest {
@@ -464,7 +512,12 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, create_database, CreateDatabaseRequest, CreateDatabaseResponse }
             ,{ ddl_client, drop_materialized_source, DropMaterializedSourceRequest, DropMaterializedSourceResponse }
             ,{ ddl_client, drop_materialized_view, DropMaterializedViewRequest, DropMaterializedViewResponse }
+            ,{ ddl_client, replace_materialized_view, ReplaceMaterializedViewRequest, ReplaceMaterializedViewResponse }
             ,{ ddl_client, drop_source, DropSourceRequest, DropSourceResponse }
+            ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
+            ,{ ddl_client, drop_view, DropViewRequest, DropViewResponse }
+            ,{ ddl_client, create_sink, CreateSinkRequest, CreateSinkResponse }
+            ,{ ddl_client, drop_sink, DropSinkRequest, DropSinkResponse }
             ,{ hummock_client, pin_version, PinVersionRequest, PinVersionResponse }
             ,{ hummock_client, unpin_version, UnpinVersionRequest, UnpinVersionResponse }
             ,{ hummock_client, pin_snapshot, PinSnapshotRequest, PinSnapshotResponse }
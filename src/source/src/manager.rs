@@ -25,7 +25,7 @@ use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::DataType;
 use risingwave_connector::base::SourceReader;
 use risingwave_connector::new_connector;
-use risingwave_pb::catalog::{RowFormatType, StreamSourceInfo};
+use risingwave_pb::catalog::{RowFormatType, StreamSourceInfo, WatermarkDesc};
 
 use crate::connector_source::ConnectorSource;
 use crate::table_v2::TableSourceV2;
@@ -94,6 +94,8 @@ pub struct SourceDesc {
     pub format: SourceFormat,
     pub columns: Vec<SourceColumnDesc>,
     pub row_id_index: Option<usize>,
+    /// The watermark defined by `WATERMARK FOR` of the source, if any.
+    pub watermark_desc: Option<WatermarkDesc>,
 }
 
 pub type SourceManagerRef = Arc<dyn SourceManager>;
@@ -152,6 +154,7 @@ impl SourceManager for MemSourceManager {
             format,
             columns,
             row_id_index,
+            watermark_desc: None,
         };
         let mut tables = self.get_sources()?;
         ensure!(
@@ -237,6 +240,7 @@ impl SourceManager for MemSourceManager {
             format,
             columns,
             row_id_index,
+            watermark_desc: info.watermark_desc.clone(),
         };
 
         let mut tables = self.get_sources()?;
@@ -268,6 +272,7 @@ impl SourceManager for MemSourceManager {
             columns: source_columns,
             format: SourceFormat::Invalid,
             row_id_index: Some(0), // always use the first column as row_id
+            watermark_desc: None,
         };
 
         sources.insert(*table_id, desc);
@@ -440,6 +445,7 @@ mod tests {
             row_id_index: 0,
             pk_column_ids: vec![0],
             columns,
+            watermark_desc: None,
         };
         let source_id = TableId::default();
 
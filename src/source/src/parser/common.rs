@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use chrono::NaiveDate;
+use itertools::Itertools;
 use num_traits::FromPrimitive;
+use risingwave_common::array::{JsonbVal, StructValue};
 use risingwave_common::error::ErrorCode::{self, InternalError};
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::{DataType, Decimal, NaiveDateWrapper, ScalarImpl, ScalarRef};
+use risingwave_common::types::{DataType, Decimal, NaiveDateWrapper, ScalarImpl, ScalarRef, Uuid};
 use serde_json::Value;
 
 #[inline(always)]
@@ -27,8 +29,6 @@ pub fn str_to_date(elem: &str) -> Result<NaiveDateWrapper> {
     ))
 }
 
-use crate::SourceColumnDesc;
-
 macro_rules! make_ScalarImpl {
     ($x:expr, $y:expr) => {
         match $x {
@@ -38,11 +38,8 @@ macro_rules! make_ScalarImpl {
     };
 }
 
-pub(crate) fn json_parse_value(
-    column: &SourceColumnDesc,
-    value: Option<&Value>,
-) -> Result<ScalarImpl> {
-    match column.data_type {
+pub(crate) fn json_parse_value(data_type: &DataType, value: Option<&Value>) -> Result<ScalarImpl> {
+    match data_type {
         DataType::Boolean => {
             make_ScalarImpl!(value.and_then(|v| v.as_bool()), |x| ScalarImpl::Bool(
                 x as bool
@@ -95,6 +92,39 @@ pub(crate) fn json_parse_value(
                 Err(e) => Err(e),
             },
         },
+        DataType::Jsonb => make_ScalarImpl!(value, |v: &Value| ScalarImpl::Jsonb(JsonbVal::from(
+            v.clone()
+        ))),
+        // Debezium encodes binary columns as base64 strings by default.
+        DataType::Bytea => match value.and_then(|v| v.as_str()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(bytes_str) => match base64::decode(bytes_str) {
+                Ok(bytes) => Ok(ScalarImpl::Bytea(bytes.into())),
+                Err(e) => Err(RwError::from(InternalError(e.to_string()))),
+            },
+        },
+        DataType::Uuid => match value.and_then(|v| v.as_str()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(uuid_str) => match Uuid::parse_str(uuid_str) {
+                Ok(uuid) => Ok(ScalarImpl::Uuid(uuid)),
+                Err(e) => Err(RwError::from(InternalError(e.to_string()))),
+            },
+        },
+        // The fields of a struct are looked up by name in a JSON object. Missing or mismatched
+        // fields are NULL.
+        DataType::Struct {
+            fields,
+            field_names,
+        } => match value.and_then(|v| v.as_object()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(object) => Ok(ScalarImpl::Struct(StructValue::new(
+                fields
+                    .iter()
+                    .zip_eq(field_names.iter())
+                    .map(|(field, name)| json_parse_value(field, object.get(name)).ok())
+                    .collect(),
+            ))),
+        },
         _ => unimplemented!(),
     }
 }
@@ -59,7 +59,7 @@ impl DebeziumJsonParser {
                 if column.skip_parse {
                     None
                 } else {
-                    json_parse_value(column, map.get(&column.name)).ok()
+                    json_parse_value(&column.data_type, map.get(&column.name)).ok()
                 }
             })
             .collect::<Vec<Datum>>()
@@ -329,4 +329,40 @@ mod test {
         assert_eq!(result.rows.len(), 0);
         assert_eq!(result.ops.len(), 0);
     }
+
+    #[test]
+    fn test_debezium_json_parser_bytea_uuid() {
+        let data = r#"{"payload":{"before":null,"after":{"id":103,"data":"3q0A","uid":"a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"},"op":"c","ts_ms":1639551564960}}"#;
+        let parser = DebeziumJsonParser {};
+        let columns = vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "data".to_string(),
+                data_type: DataType::Bytea,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "uid".to_string(),
+                data_type: DataType::Uuid,
+                column_id: ColumnId::from(2),
+                skip_parse: false,
+            },
+        ];
+        let result = parser.parse(data.as_ref(), columns.as_ref()).unwrap();
+        assert_eq!(result.ops, vec![Op::Insert]);
+
+        let row = result.rows.first().unwrap();
+        assert!(row[0].eq(&Some(ScalarImpl::Int32(103))));
+        assert!(row[1].eq(&Some(ScalarImpl::Bytea(b"\xde\xad\x00".to_vec().into()))));
+        assert_eq!(
+            row[2].as_ref().unwrap().to_string(),
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"
+        );
+    }
 }
@@ -38,7 +38,7 @@ impl SourceParser for JSONParser {
                     if column.skip_parse {
                         None
                     } else {
-                        json_parse_value(column, value.get(&column.name)).ok()
+                        json_parse_value(&column.data_type, value.get(&column.name)).ok()
                     }
                 })
                 .collect::<Vec<Datum>>()],
@@ -48,6 +48,7 @@ impl SourceParser for JSONParser {
 
 #[cfg(test)]
 mod tests {
+    use risingwave_common::array::StructValue;
     use risingwave_common::catalog::ColumnId;
     use risingwave_common::types::{DataType, ScalarImpl};
 
@@ -139,4 +140,89 @@ mod tests {
         let result = parser.parse(payload, &descs);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_json_parser_jsonb() {
+        let parser = JSONParser {};
+
+        let payload = r#"{"id":1,"payload":{"tags":["a","b"],"user":{"name":"x"}}}"#.as_bytes();
+        let descs = vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "payload".to_string(),
+                data_type: DataType::Jsonb,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+        ];
+
+        let event = parser.parse(payload, &descs).unwrap();
+        let row = event.rows.first().unwrap();
+        assert_eq!(row[0], Some(ScalarImpl::Int32(1)));
+        assert_eq!(
+            row[1],
+            Some(ScalarImpl::Jsonb(
+                r#"{"tags": ["a", "b"], "user": {"name": "x"}}"#.parse().unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_json_parser_struct() {
+        let parser = JSONParser {};
+
+        let payload =
+            r#"{"id":1,"info":{"name":"x","loc":{"lat":1.5},"extra":true},"bad":[1]}"#.as_bytes();
+        let info_type = DataType::new_struct(
+            vec![
+                DataType::Varchar,
+                DataType::new_struct(
+                    vec![DataType::Float64, DataType::Float64],
+                    vec!["lat".to_string(), "lng".to_string()],
+                ),
+            ],
+            vec!["name".to_string(), "loc".to_string()],
+        );
+        let descs = vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "info".to_string(),
+                data_type: info_type.clone(),
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "bad".to_string(),
+                data_type: info_type,
+                column_id: ColumnId::from(2),
+                skip_parse: false,
+            },
+        ];
+
+        let event = parser.parse(payload, &descs).unwrap();
+        let row = event.rows.first().unwrap();
+        assert_eq!(row[0], Some(ScalarImpl::Int32(1)));
+        assert_eq!(
+            row[1],
+            Some(ScalarImpl::Struct(StructValue::new(vec![
+                Some(ScalarImpl::Utf8("x".to_string())),
+                Some(ScalarImpl::Struct(StructValue::new(vec![
+                    Some(ScalarImpl::Float64(1.5.into())),
+                    None,
+                ]))),
+            ])))
+        );
+        // A value that is not an object can't be parsed as a struct.
+        assert_eq!(row[2], None);
+    }
 }
@@ -14,9 +14,10 @@
 
 use std::path::Path;
 
+use itertools::Itertools;
 use protobuf::descriptor::FileDescriptorSet;
 use protobuf::RepeatedField;
-use risingwave_common::array::Op;
+use risingwave_common::array::{Op, StructValue};
 use risingwave_common::error::ErrorCode::{self, InternalError, ItemNotFound, ProtocolError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{DataType, Datum, Decimal, OrderedF32, OrderedF64, ScalarImpl};
@@ -206,12 +207,13 @@ fn protobuf_type_mapping(f: &FieldDescriptor, descriptors: &Descriptors) -> Resu
         FieldType::Bool => DataType::Boolean,
         FieldType::String => DataType::Varchar,
         FieldType::Message(m) => {
-            let vec = m
+            let fields = m
                 .fields()
                 .iter()
                 .map(|f| protobuf_type_mapping(f, descriptors))
                 .collect::<Result<Vec<_>>>()?;
-            DataType::Struct { fields: vec.into() }
+            let field_names = m.fields().iter().map(|f| f.name().to_string()).collect();
+            DataType::new_struct(fields, field_names)
         }
         actual_type => {
             return Err(ErrorCode::NotImplemented(
@@ -224,6 +226,70 @@ fn protobuf_type_mapping(f: &FieldDescriptor, descriptors: &Descriptors) -> Resu
     Ok(t)
 }
 
+/// Maps a decoded protobuf value to a datum of the given type. Nested messages are decoded as
+/// maps, whose fields are looked up by the field names of the struct type.
+fn protobuf_parse_value(value: Option<Value>, data_type: &DataType) -> Datum {
+    match data_type {
+        DataType::Boolean => {
+            protobuf_match_type!(value, ScalarImpl::Bool, { Bool }, bool)
+        }
+        DataType::Int16 => {
+            protobuf_match_type!(value, ScalarImpl::Int16, { I8, I16, U8 }, i16)
+        }
+        DataType::Int32 => {
+            protobuf_match_type!(value, ScalarImpl::Int32, { I8, I16, I32, U8, U16 }, i32)
+        }
+        DataType::Int64 => {
+            protobuf_match_type!(value, ScalarImpl::Int64, { I8, I16, I32, I64, U8, U16, U32 }, i64)
+        }
+        DataType::Float32 => {
+            protobuf_match_type!(value, ScalarImpl::Float32, { I8, I16, U8, U16, F32 }, OrderedF32)
+        }
+        DataType::Float64 => {
+            protobuf_match_type!(value, ScalarImpl::Float64, { I8, I16, I32, U8, U16, U32, F32, F64}, OrderedF64)
+        }
+        DataType::Decimal => {
+            protobuf_match_type!(value, ScalarImpl::Decimal, { I8, I16, I32, I64, U8, U16, U32, U64}, Decimal)
+        }
+        DataType::Varchar => {
+            protobuf_match_type!(value, ScalarImpl::Utf8, { String }, String)
+        }
+        DataType::Date => value
+            .and_then(|v| match v {
+                Value::String(b) => str_to_date(&b).ok(),
+                Value::Option(Some(boxed_value)) => match *boxed_value {
+                    Value::String(b) => str_to_date(&b).ok(),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .map(ScalarImpl::NaiveDate),
+        DataType::Struct {
+            fields,
+            field_names,
+        } => value
+            .and_then(|v| match v {
+                Value::Map(m) => Some(m),
+                Value::Option(Some(boxed_value)) => match *boxed_value {
+                    Value::Map(m) => Some(m),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .map(|mut map| {
+                let values = fields
+                    .iter()
+                    .zip_eq(field_names.iter())
+                    .map(|(field, name)| {
+                        protobuf_parse_value(map.remove(&Value::String(name.clone())), field)
+                    })
+                    .collect();
+                ScalarImpl::Struct(StructValue::new(values))
+            }),
+        _ => unimplemented!(),
+    }
+}
+
 impl SourceParser for ProtobufParser {
     fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
         let mut map = match self.decode(payload)? {
@@ -231,53 +297,19 @@ impl SourceParser for ProtobufParser {
             _ => return Err(RwError::from(ProtocolError("".to_string()))),
         };
 
-        let row = columns.iter().map(|column| {
-            if column.skip_parse {
-                return None;
-            }
+        let row = columns
+            .iter()
+            .map(|column| {
+                if column.skip_parse {
+                    return None;
+                }
 
-            let key = Value::String(column.name.clone());
+                let key = Value::String(column.name.clone());
 
-            // Use `remove` instead of `get` to take the ownership of the value
-            let value = map.remove(&key);
-            match column.data_type {
-                DataType::Boolean => {
-                    protobuf_match_type!(value, ScalarImpl::Bool, { Bool }, bool)
-                }
-                DataType::Int16 => {
-                    protobuf_match_type!(value, ScalarImpl::Int16, { I8, I16, U8 }, i16)
-                }
-                DataType::Int32 => {
-                    protobuf_match_type!(value, ScalarImpl::Int32, { I8, I16, I32, U8, U16 }, i32)
-                }
-                DataType::Int64 => {
-                    protobuf_match_type!(value, ScalarImpl::Int64, { I8, I16, I32, I64, U8, U16, U32 }, i64)
-                }
-                DataType::Float32 => {
-                    protobuf_match_type!(value, ScalarImpl::Float32, { I8, I16, U8, U16, F32 }, OrderedF32)
-                }
-                DataType::Float64 => {
-                    protobuf_match_type!(value, ScalarImpl::Float64, { I8, I16, I32, U8, U16, U32, F32, F64}, OrderedF64)
-                }
-                DataType::Decimal => {
-                    protobuf_match_type!(value, ScalarImpl::Decimal, { I8, I16, I32, I64, U8, U16, U32, U64}, Decimal)
-                }
-                DataType::Varchar => {
-                    protobuf_match_type!(value, ScalarImpl::Utf8, { String }, String)
-                }
-                DataType::Date => {
-                    value.and_then(|v| match v {
-                        Value::String(b) => str_to_date(&b).ok(),
-                        Value::Option(Some(boxed_value)) => match *boxed_value {
-                            Value::String(b) => str_to_date(&b).ok(),
-                            _ => None,
-                        }
-                        _ => None,
-                    }).map(ScalarImpl::NaiveDate)
-                }
-                _ => unimplemented!(),
-            }
-        }).collect::<Vec<Datum>>();
+                // Use `remove` instead of `get` to take the ownership of the value
+                protobuf_parse_value(map.remove(&key), &column.data_type)
+            })
+            .collect::<Vec<Datum>>();
 
         Ok(Event {
             ops: vec![Op::Insert],
@@ -476,6 +508,64 @@ mod tests {
         ))))
     }
 
+    #[test]
+    fn test_parser_parse_nested() {
+        use risingwave_common::array::StructValue;
+
+        let parser = create_parser(PROTO_NESTED_FILE_DATA).unwrap();
+        let city_type = DataType::new_struct(
+            vec![DataType::Varchar, DataType::Varchar],
+            vec!["address".to_string(), "zipcode".to_string()],
+        );
+        let descs = vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "country".to_string(),
+                data_type: DataType::new_struct(
+                    vec![DataType::Varchar, city_type, DataType::Varchar],
+                    vec![
+                        "address".to_string(),
+                        "city".to_string(),
+                        "zipcode".to_string(),
+                    ],
+                ),
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "zipcode".to_string(),
+                data_type: DataType::Int64,
+                column_id: ColumnId::from(2),
+                skip_parse: false,
+            },
+        ];
+
+        // Id: 1, Country: { Address: "a", City: { Address: "b", Zipcode: "c" }, Zipcode: "d" },
+        // Zipcode: 5
+        let payload =
+            b"\x08\x01\x1a\x0e\x0a\x01\x61\x12\x06\x0a\x01\x62\x12\x01\x63\x1a\x01\x64\x20\x05";
+        let event = parser.parse(payload, &descs).unwrap();
+        let row = event.rows.first().unwrap();
+        assert_eq!(row[0], Some(ScalarImpl::Int32(1)));
+        assert_eq!(
+            row[1],
+            Some(ScalarImpl::Struct(StructValue::new(vec![
+                Some(ScalarImpl::Utf8("a".to_string())),
+                Some(ScalarImpl::Struct(StructValue::new(vec![
+                    Some(ScalarImpl::Utf8("b".to_string())),
+                    Some(ScalarImpl::Utf8("c".to_string())),
+                ]))),
+                Some(ScalarImpl::Utf8("d".to_string())),
+            ])))
+        );
+        assert_eq!(row[2], Some(ScalarImpl::Int64(5)));
+    }
+
     #[test]
     fn test_map_to_columns() {
         use risingwave_common::types::*;
@@ -11,13 +11,13 @@
 // limitations under the License.
 
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::ast::ObjectName;
+use crate::ast::{display_comma_separated, Ident, ObjectName};
 
 /// SQL data types
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -75,6 +75,8 @@ pub enum DataType {
     Custom(ObjectName),
     /// Arrays
     Array(Box<DataType>),
+    /// Structs e.g. STRUCT<a INT, b VARCHAR>
+    Struct(Vec<StructField>),
 }
 
 impl fmt::Display for DataType {
@@ -118,10 +120,25 @@ impl fmt::Display for DataType {
             DataType::Bytea => write!(f, "BYTEA"),
             DataType::Array(ty) => write!(f, "{}[]", ty),
             DataType::Custom(ty) => write!(f, "{}", ty),
+            DataType::Struct(fields) => write!(f, "STRUCT<{}>", display_comma_separated(fields)),
         }
     }
 }
 
+/// A named field of a struct type
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StructField {
+    pub name: Ident,
+    pub data_type: DataType,
+}
+
+impl fmt::Display for StructField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
 fn format_type_with_optional_length(
     f: &mut fmt::Formatter,
     sql_type: &'static str,
@@ -256,6 +256,20 @@ impl fmt::Display for ColumnDef {
     }
 }
 
+/// Watermark definition of a source: `WATERMARK FOR <column> AS <expr>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceWatermark {
+    pub column: Ident,
+    pub expr: Expr,
+}
+
+impl fmt::Display for SourceWatermark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WATERMARK FOR {} AS {}", self.column, self.expr)
+    }
+}
+
 /// An optionally-named `ColumnOption`: `[ CONSTRAINT <name> ] <column-option>`.
 ///
 /// Note that implementations are substantially more permissive than the ANSI
@@ -30,10 +30,10 @@ use itertools::Itertools;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-pub use self::data_type::DataType;
+pub use self::data_type::{DataType, StructField};
 pub use self::ddl::{
     AlterColumnOperation, AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef,
-    ReferentialAction, TableConstraint,
+    ReferentialAction, SourceWatermark, TableConstraint,
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
@@ -219,6 +219,14 @@ pub enum Expr {
         op: BinaryOperator,
         right: Box<Expr>,
     },
+    /// `<expr> [NOT] LIKE <pattern> ESCAPE <escape>`, where `op` is one of the LIKE or ILIKE
+    /// operators. A pattern matching without ESCAPE is a `BinaryOp`.
+    LikeEscape {
+        expr: Box<Expr>,
+        op: BinaryOperator,
+        pattern: Box<Expr>,
+        escape: Box<Expr>,
+    },
     /// Unary operation e.g. `NOT foo`
     UnaryOp {
         op: UnaryOperator,
@@ -293,6 +301,12 @@ pub enum Expr {
     /// A parenthesized subquery `(SELECT ...)`, used in expression like
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
     Subquery(Box<Query>),
+    /// `ANY (SELECT ...)` or `SOME (SELECT ...)`, only valid as the right operand of a comparison,
+    /// e.g. `x > ANY (SELECT ...)`.
+    AnySubquery(Box<Query>),
+    /// `ALL (SELECT ...)`, only valid as the right operand of a comparison, e.g.
+    /// `x > ALL (SELECT ...)`.
+    AllSubquery(Box<Query>),
     /// The `GROUPING SETS` expr.
     GroupingSets(Vec<Vec<Expr>>),
     /// The `CUBE` expr.
@@ -365,6 +379,12 @@ impl fmt::Display for Expr {
                 high
             ),
             Expr::BinaryOp { left, op, right } => write!(f, "{} {} {}", left, op, right),
+            Expr::LikeEscape {
+                expr,
+                op,
+                pattern,
+                escape,
+            } => write!(f, "{} {} {} ESCAPE {}", expr, op, pattern, escape),
             Expr::UnaryOp { op, expr } => {
                 if op == &UnaryOperator::PGPostfixFactorial {
                     write!(f, "{}{}", expr, op)
@@ -404,6 +424,8 @@ impl fmt::Display for Expr {
             }
             Expr::Exists(s) => write!(f, "EXISTS ({})", s),
             Expr::Subquery(s) => write!(f, "({})", s),
+            Expr::AnySubquery(s) => write!(f, "ANY ({})", s),
+            Expr::AllSubquery(s) => write!(f, "ALL ({})", s),
             Expr::GroupingSets(sets) => {
                 write!(f, "GROUPING SETS (")?;
                 let mut sep = "";
@@ -653,6 +675,9 @@ pub enum ShowCommandObject {
     Database,
     Schema,
     MaterializedView(Option<Ident>),
+    View(Option<Ident>),
+    Sink(Option<Ident>),
+    Function,
 }
 
 impl fmt::Display for ShowCommandObject {
@@ -666,6 +691,11 @@ impl fmt::Display for ShowCommandObject {
                 write!(f, "MATERIALIZED VIEWS FROM {}", name)
             }
             ShowCommandObject::Table(Some(name)) => write!(f, "TABLES FROM {}", name),
+            ShowCommandObject::View(None) => f.write_str("VIEWS"),
+            ShowCommandObject::View(Some(name)) => write!(f, "VIEWS FROM {}", name),
+            ShowCommandObject::Sink(None) => f.write_str("SINKS"),
+            ShowCommandObject::Sink(Some(name)) => write!(f, "SINKS FROM {}", name),
+            ShowCommandObject::Function => f.write_str("FUNCTIONS"),
         }
     }
 }
@@ -769,6 +799,8 @@ pub enum Statement {
         is_materialized: bool,
         stmt: CreateSourceStatement,
     },
+    /// CREATE SINK
+    CreateSink { stmt: CreateSinkStatement },
     /// ALTER TABLE
     AlterTable {
         /// Table name
@@ -1112,6 +1144,7 @@ impl fmt::Display for Statement {
                     ""
                 }
             ),
+            Statement::CreateSink { stmt } => write!(f, "CREATE SINK {}", stmt),
             Statement::AlterTable { name, operation } => {
                 write!(f, "ALTER TABLE {} {}", name, operation)
             }
@@ -1500,6 +1533,7 @@ pub enum ObjectType {
     Schema,
     Source,
     MaterializedSource,
+    Sink,
 }
 
 impl fmt::Display for ObjectType {
@@ -1512,6 +1546,7 @@ impl fmt::Display for ObjectType {
             ObjectType::Schema => "SCHEMA",
             ObjectType::Source => "SOURCE",
             ObjectType::MaterializedSource => "MATERIALIZED SOURCE",
+            ObjectType::Sink => "SINK",
         })
     }
 }
@@ -1532,9 +1567,11 @@ impl ParseTo for ObjectType {
             ObjectType::Index
         } else if parser.parse_keyword(Keyword::SCHEMA) {
             ObjectType::Schema
+        } else if parser.parse_keyword(Keyword::SINK) {
+            ObjectType::Sink
         } else {
             return parser.expected(
-                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, MATERIALIZED SOURCE, or SCHEMA after DROP",
+                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, MATERIALIZED SOURCE, SINK or SCHEMA after DROP",
                 parser.peek_token(),
             );
         };
@@ -86,6 +86,18 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    /// JSON field access, e.g. `a -> 'b'` (PostgreSQL-specific)
+    Arrow,
+    /// JSON field access as text, e.g. `a ->> 'b'` (PostgreSQL-specific)
+    LongArrow,
+    /// JSON path access, e.g. `a #> '{b,c}'` (PostgreSQL-specific)
+    HashArrow,
+    /// JSON path access as text, e.g. `a #>> '{b,c}'` (PostgreSQL-specific)
+    HashLongArrow,
+    /// Containment, e.g. `a @> b` (PostgreSQL-specific)
+    AtArrow,
+    /// Reverse containment, e.g. `a <@ b` (PostgreSQL-specific)
+    ArrowAt,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -121,6 +133,12 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::Arrow => "->",
+            BinaryOperator::LongArrow => "->>",
+            BinaryOperator::HashArrow => "#>",
+            BinaryOperator::HashLongArrow => "#>>",
+            BinaryOperator::AtArrow => "@>",
+            BinaryOperator::ArrowAt => "<@",
         })
     }
 }
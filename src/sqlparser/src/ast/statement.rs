@@ -21,7 +21,8 @@ use serde::{Deserialize, Serialize};
 
 use super::ObjectType;
 use crate::ast::{
-    display_comma_separated, ColumnDef, Ident, ObjectName, SqlOption, TableConstraint,
+    display_comma_separated, ColumnDef, Ident, ObjectName, SourceWatermark, SqlOption,
+    TableConstraint,
 };
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
@@ -75,6 +76,7 @@ pub struct CreateSourceStatement {
     pub if_not_exists: bool,
     pub columns: Vec<ColumnDef>,
     pub constraints: Vec<TableConstraint>,
+    pub source_watermarks: Vec<SourceWatermark>,
     pub source_name: ObjectName,
     pub with_properties: WithProperties,
     pub source_schema: SourceSchema,
@@ -156,7 +158,7 @@ impl ParseTo for CreateSourceStatement {
         impl_parse_to!(source_name: ObjectName, p);
 
         // parse columns
-        let (columns, constraints) = p.parse_columns()?;
+        let (columns, constraints, source_watermarks) = p.parse_columns_with_watermark()?;
 
         impl_parse_to!(with_properties: WithProperties, p);
         impl_parse_to!([Keyword::ROW, Keyword::FORMAT], p);
@@ -165,6 +167,7 @@ impl ParseTo for CreateSourceStatement {
             if_not_exists,
             columns,
             constraints,
+            source_watermarks,
             source_name,
             with_properties,
             source_schema,
@@ -177,6 +180,17 @@ impl fmt::Display for CreateSourceStatement {
         let mut v: Vec<String> = vec![];
         impl_fmt_display!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], v, self);
         impl_fmt_display!(source_name, v, self);
+        if !self.source_watermarks.is_empty() {
+            // The columns are only displayed along with the watermarks.
+            let elems = self
+                .columns
+                .iter()
+                .map(ToString::to_string)
+                .chain(self.constraints.iter().map(ToString::to_string))
+                .chain(self.source_watermarks.iter().map(ToString::to_string))
+                .join(", ");
+            v.push(format!("({})", elems));
+        }
         impl_fmt_display!(with_properties, v, self);
         impl_fmt_display!([Keyword::ROW, Keyword::FORMAT], v);
         impl_fmt_display!(source_schema, v, self);
@@ -184,6 +198,50 @@ impl fmt::Display for CreateSourceStatement {
     }
 }
 
+// sql_grammar!(CreateSinkStatement {
+//     if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS],
+//     sink_name: Ident,
+//     [Keyword::FROM],
+//     materialized_view: Ident,
+//     with_properties: AstOption<WithProperties>,
+// });
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateSinkStatement {
+    pub if_not_exists: bool,
+    pub sink_name: ObjectName,
+    pub materialized_view: ObjectName,
+    pub with_properties: WithProperties,
+}
+
+impl ParseTo for CreateSinkStatement {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        impl_parse_to!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], p);
+        impl_parse_to!(sink_name: ObjectName, p);
+        impl_parse_to!([Keyword::FROM], p);
+        impl_parse_to!(materialized_view: ObjectName, p);
+        impl_parse_to!(with_properties: WithProperties, p);
+        Ok(Self {
+            if_not_exists,
+            sink_name,
+            materialized_view,
+            with_properties,
+        })
+    }
+}
+
+impl fmt::Display for CreateSinkStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        impl_fmt_display!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], v, self);
+        impl_fmt_display!(sink_name, v, self);
+        impl_fmt_display!([Keyword::FROM], v);
+        impl_fmt_display!(materialized_view, v, self);
+        impl_fmt_display!(with_properties, v, self);
+        v.iter().join(" ").fmt(f)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AstVec<T>(pub Vec<T>);
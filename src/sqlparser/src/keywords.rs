@@ -218,6 +218,7 @@ define_keywords!(
     FROM,
     FULL,
     FUNCTION,
+    FUNCTIONS,
     FUSION,
     GET,
     GLOBAL,
@@ -396,6 +397,8 @@ define_keywords!(
     SETS,
     SHOW,
     SIMILAR,
+    SINK,
+    SINKS,
     SMALLINT,
     SNAPSHOT,
     SOME,
@@ -416,6 +419,7 @@ define_keywords!(
     STDIN,
     STORED,
     STRING,
+    STRUCT,
     SUBMULTISET,
     SUBSTRING,
     SUBSTRING_REGEX,
@@ -482,6 +486,7 @@ define_keywords!(
     VIEW,
     VIEWS,
     VIRTUAL,
+    WATERMARK,
     WHEN,
     WHENEVER,
     WHERE,
@@ -524,6 +524,11 @@ impl Parser {
                     Box::new(Expr::Cast { expr, data_type }),
                     self.parse_field()?,
                 )),
+                // Parser expr like `SELECT (ROW(1, 2)).f1`
+                Expr::Row(exprs) => Ok(Expr::FieldIdentifier(
+                    Box::new(Expr::Row(exprs)),
+                    self.parse_field()?,
+                )),
                 // Parser expr like `SELECT ((foo.v1).v2).v3 from foo`
                 Expr::FieldIdentifier(expr, mut idents) => {
                     idents.extend(self.parse_field()?);
@@ -962,6 +967,12 @@ impl Parser {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::Arrow => Some(BinaryOperator::Arrow),
+            Token::LongArrow => Some(BinaryOperator::LongArrow),
+            Token::HashArrow => Some(BinaryOperator::HashArrow),
+            Token::HashLongArrow => Some(BinaryOperator::HashLongArrow),
+            Token::AtArrow => Some(BinaryOperator::AtArrow),
+            Token::ArrowAt => Some(BinaryOperator::ArrowAt),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
@@ -983,10 +994,52 @@ impl Parser {
         };
 
         if let Some(op) = regular_binary_operator {
+            if matches!(
+                op,
+                BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::GtEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::LtEq
+            ) {
+                if let Some(keyword) =
+                    self.parse_one_of_keywords(&[Keyword::ANY, Keyword::SOME, Keyword::ALL])
+                {
+                    self.expect_token(&Token::LParen)?;
+                    let query = Box::new(self.parse_query()?);
+                    self.expect_token(&Token::RParen)?;
+                    let right = match keyword {
+                        Keyword::ALL => Expr::AllSubquery(query),
+                        _ => Expr::AnySubquery(query),
+                    };
+                    return Ok(Expr::BinaryOp {
+                        left: Box::new(expr),
+                        op,
+                        right: Box::new(right),
+                    });
+                }
+            }
+            let right = self.parse_subexpr(precedence)?;
+            if matches!(
+                op,
+                BinaryOperator::Like
+                    | BinaryOperator::NotLike
+                    | BinaryOperator::ILike
+                    | BinaryOperator::NotILike
+            ) && self.parse_keyword(Keyword::ESCAPE)
+            {
+                return Ok(Expr::LikeEscape {
+                    expr: Box::new(expr),
+                    op,
+                    pattern: Box::new(right),
+                    escape: Box::new(self.parse_subexpr(precedence)?),
+                });
+            }
             Ok(Expr::BinaryOp {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(right),
             })
         } else if let Token::Word(w) = &tok {
             match w.keyword {
@@ -1058,13 +1111,10 @@ impl Parser {
             debug!("Tok: {}", tok);
             key_parts.push(key);
         }
-        match expr {
-            e @ Expr::Identifier(_) | e @ Expr::CompoundIdentifier(_) => Ok(Expr::MapAccess {
-                column: Box::new(e),
-                keys: key_parts,
-            }),
-            _ => Ok(expr),
-        }
+        Ok(Expr::MapAccess {
+            column: Box::new(expr),
+            keys: key_parts,
+        })
     }
 
     /// Parses the parens following the `[ NOT ] IN` operator
@@ -1150,6 +1200,12 @@ impl Parser {
             | Token::Spaceship => Ok(20),
             Token::Pipe => Ok(21),
             Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
+            Token::Arrow
+            | Token::LongArrow
+            | Token::HashArrow
+            | Token::HashLongArrow
+            | Token::AtArrow
+            | Token::ArrowAt => Ok(22),
             Token::Ampersand => Ok(23),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
             Token::Mul | Token::Div | Token::Mod | Token::StringConcat => Ok(40),
@@ -1375,6 +1431,8 @@ impl Parser {
             self.parse_create_source(false, or_replace)
         } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::SOURCE]) {
             self.parse_create_source(true, or_replace)
+        } else if self.parse_keyword(Keyword::SINK) {
+            self.parse_create_sink(or_replace)
         } else if or_replace {
             self.expected(
                 "[EXTERNAL] TABLE or [MATERIALIZED] VIEW after CREATE OR REPLACE",
@@ -1442,6 +1500,19 @@ impl Parser {
         })
     }
 
+    // CREATE [OR REPLACE]?
+    // SINK
+    // [IF NOT EXISTS]?
+    // <sink_name: Ident>
+    // FROM
+    // <materialized_view: Ident>
+    // [WITH (properties)]?
+    pub fn parse_create_sink(&mut self, _or_replace: bool) -> Result<Statement, ParserError> {
+        Ok(Statement::CreateSink {
+            stmt: CreateSinkStatement::parse_to(self)?,
+        })
+    }
+
     fn parse_with_properties(&mut self) -> Result<Vec<SqlOption>, ParserError> {
         Ok(self.parse_options(Keyword::WITH)?.to_vec())
     }
@@ -1507,14 +1578,37 @@ impl Parser {
     }
 
     pub fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
+        let (columns, constraints, _) = self.parse_columns_inner(false)?;
+        Ok((columns, constraints))
+    }
+
+    /// Parses the columns of a source, which may contain `WATERMARK FOR <column> AS <expr>`.
+    #[allow(clippy::type_complexity)]
+    pub fn parse_columns_with_watermark(
+        &mut self,
+    ) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>, Vec<SourceWatermark>), ParserError> {
+        self.parse_columns_inner(true)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn parse_columns_inner(
+        &mut self,
+        allow_watermark: bool,
+    ) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>, Vec<SourceWatermark>), ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
+        let mut watermarks = vec![];
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
-            return Ok((columns, constraints));
+            return Ok((columns, constraints, watermarks));
         }
 
         loop {
-            if let Some(constraint) = self.parse_optional_table_constraint()? {
+            if allow_watermark && self.parse_keywords(&[Keyword::WATERMARK, Keyword::FOR]) {
+                let column = self.parse_identifier()?;
+                self.expect_keyword(Keyword::AS)?;
+                let expr = self.parse_expr()?;
+                watermarks.push(SourceWatermark { column, expr });
+            } else if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Token::Word(_) = self.peek_token() {
                 columns.push(self.parse_column_def()?);
@@ -1530,7 +1624,7 @@ impl Parser {
             }
         }
 
-        Ok((columns, constraints))
+        Ok((columns, constraints, watermarks))
     }
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
@@ -1987,6 +2081,7 @@ impl Parser {
                     }
                 }
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::STRUCT => Ok(DataType::Struct(self.parse_struct_fields()?)),
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(DataType::Decimal(precision, scale))
@@ -2001,6 +2096,28 @@ impl Parser {
         }
     }
 
+    /// Parse the fields of `STRUCT<name type, ...>`, after the `STRUCT` keyword.
+    fn parse_struct_fields(&mut self) -> Result<Vec<StructField>, ParserError> {
+        self.expect_token(&Token::Lt)?;
+        let fields = self.parse_comma_separated(|parser| {
+            Ok(StructField {
+                name: parser.parse_identifier()?,
+                data_type: parser.parse_data_type()?,
+            })
+        })?;
+        match self.next_token() {
+            Token::Gt => Ok(fields),
+            // The `>>` closing nested structs is tokenized as a shift. Consume one `>` of it and
+            // leave the other for the enclosing struct.
+            Token::ShiftRight => {
+                self.prev_token();
+                self.tokens[self.index] = Token::Gt;
+                Ok(fields)
+            }
+            unexpected => self.expected(">", unexpected),
+        }
+    }
+
     /// Parse `AS identifier` (or simply `identifier` if it's not a reserved keyword)
     /// Some examples with aliases: `SELECT 1 foo`, `SELECT COUNT(*) AS cnt`,
     /// `SELECT ... FROM t1 foo, t2 bar`, `SELECT ... FROM (...) AS bar`
@@ -2493,8 +2610,8 @@ impl Parser {
         }
     }
 
-    /// If have `databases`,`tables`,`columns`,`schemas` and `materialized views` after show,
-    /// return `Statement::ShowCommand` or `Statement::ShowColumn`,
+    /// If have `databases`,`tables`,`columns`,`schemas`, `materialized views`, `views`, `sinks`
+    /// and `functions` after show, return `Statement::ShowCommand` or `Statement::ShowColumn`,
     /// otherwise, return `Statement::ShowVariable`.
     pub fn parse_show(&mut self) -> Result<Statement, ParserError> {
         let index = self.index;
@@ -2520,6 +2637,19 @@ impl Parser {
                         return self.expected("views after materialized", self.peek_token());
                     }
                 }
+                Keyword::VIEWS => {
+                    return Ok(Statement::ShowCommand(ShowCommandObject::View(
+                        self.parse_from_and_identifier()?,
+                    )));
+                }
+                Keyword::SINKS => {
+                    return Ok(Statement::ShowCommand(ShowCommandObject::Sink(
+                        self.parse_from_and_identifier()?,
+                    )));
+                }
+                Keyword::FUNCTIONS => {
+                    return Ok(Statement::ShowCommand(ShowCommandObject::Function));
+                }
                 Keyword::COLUMNS => {
                     if self.parse_keyword(Keyword::FROM) {
                         return Ok(Statement::ShowColumn {
@@ -2538,8 +2668,8 @@ impl Parser {
         })
     }
 
-    /// Parser `from schema` after `show tables` and `show materialized views`, if not conclude
-    /// `from` then use default schema name.
+    /// Parser `from schema` after `show tables`, `show materialized views` and `show views`, if
+    /// not conclude `from` then use default schema name.
     pub fn parse_from_and_identifier(&mut self) -> Result<Option<Ident>, ParserError> {
         if self.parse_keyword(Keyword::FROM) {
             Ok(Some(self.parse_identifier()?))
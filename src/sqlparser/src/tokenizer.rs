@@ -138,6 +138,18 @@ pub enum Token {
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
     PGCubeRoot,
+    /// `->`, used as a JSON object field access operator in PostgreSQL
+    Arrow,
+    /// `->>`, used as a JSON object field access as text operator in PostgreSQL
+    LongArrow,
+    /// `#>`, used as a JSON path access operator in PostgreSQL
+    HashArrow,
+    /// `#>>`, used as a JSON path access as text operator in PostgreSQL
+    HashLongArrow,
+    /// `@>`, a containment operator in PostgreSQL
+    AtArrow,
+    /// `<@`, a reverse containment operator in PostgreSQL
+    ArrowAt,
 }
 
 impl fmt::Display for Token {
@@ -193,6 +205,12 @@ impl fmt::Display for Token {
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
             Token::PGCubeRoot => f.write_str("||/"),
+            Token::Arrow => f.write_str("->"),
+            Token::LongArrow => f.write_str("->>"),
+            Token::HashArrow => f.write_str("#>"),
+            Token::HashLongArrow => f.write_str("#>>"),
+            Token::AtArrow => f.write_str("@>"),
+            Token::ArrowAt => f.write_str("<@"),
         }
     }
 }
@@ -475,6 +493,13 @@ impl<'a> Tokenizer<'a> {
                                 comment,
                             })))
                         }
+                        Some('>') => {
+                            chars.next(); // consume the '>'
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::LongArrow),
+                                _ => Ok(Some(Token::Arrow)),
+                            }
+                        }
                         // a regular '-' operator
                         _ => Ok(Some(Token::Minus)),
                     }
@@ -543,6 +568,7 @@ impl<'a> Tokenizer<'a> {
                         }
                         Some('>') => self.consume_and_return(chars, Token::Neq),
                         Some('<') => self.consume_and_return(chars, Token::ShiftLeft),
+                        Some('@') => self.consume_and_return(chars, Token::ArrowAt),
                         _ => Ok(Some(Token::Lt)),
                     }
                 }
@@ -576,8 +602,26 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Tilde)),
                     }
                 }
-                '#' => self.consume_and_return(chars, Token::Sharp),
-                '@' => self.consume_and_return(chars, Token::AtSign),
+                '#' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('>') => {
+                            chars.next();
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::HashLongArrow),
+                                _ => Ok(Some(Token::HashArrow)),
+                            }
+                        }
+                        _ => Ok(Some(Token::Sharp)),
+                    }
+                }
+                '@' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::AtArrow),
+                        _ => Ok(Some(Token::AtSign)),
+                    }
+                }
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
             None => Ok(None),
@@ -836,6 +880,41 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_json_op() {
+        let sql = String::from("SELECT a->'b'->>'c', a#>'{b}'#>>'{c}', a@>b, a<@b");
+        let mut tokenizer = Tokenizer::new(&sql);
+        let tokens = tokenizer.tokenize().unwrap();
+
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("a", None),
+            Token::Arrow,
+            Token::SingleQuotedString(String::from("b")),
+            Token::LongArrow,
+            Token::SingleQuotedString(String::from("c")),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("a", None),
+            Token::HashArrow,
+            Token::SingleQuotedString(String::from("{b}")),
+            Token::HashLongArrow,
+            Token::SingleQuotedString(String::from("{c}")),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("a", None),
+            Token::AtArrow,
+            Token::make_word("b", None),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("a", None),
+            Token::ArrowAt,
+            Token::make_word("b", None),
+        ];
+        compare(expected, tokens);
+    }
+
     #[test]
     fn tokenize_logical_xor() {
         let sql =
@@ -760,6 +760,21 @@ fn parse_ilike() {
     chk(true);
 }
 
+#[test]
+fn parse_like_escape() {
+    let sql = "SELECT * FROM customers WHERE name NOT ILIKE '%a!%' ESCAPE '!'";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::LikeEscape {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            op: BinaryOperator::NotILike,
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a!%".to_string()))),
+            escape: Box::new(Expr::Value(Value::SingleQuotedString("!".to_string()))),
+        },
+        select.selection.unwrap()
+    );
+}
+
 #[test]
 fn parse_in_list() {
     fn chk(negated: bool) {
@@ -798,6 +813,40 @@ fn parse_in_subquery() {
     );
 }
 
+#[test]
+fn parse_any_all_subquery() {
+    let sql = "SELECT * FROM customers WHERE segment > ANY (SELECT segm FROM bar)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("segment"))),
+            op: BinaryOperator::Gt,
+            right: Box::new(Expr::AnySubquery(Box::new(verified_query(
+                "SELECT segm FROM bar"
+            )))),
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM customers WHERE segment <> ALL (SELECT segm FROM bar)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("segment"))),
+            op: BinaryOperator::NotEq,
+            right: Box::new(Expr::AllSubquery(Box::new(verified_query(
+                "SELECT segm FROM bar"
+            )))),
+        },
+        select.selection.unwrap()
+    );
+
+    one_statement_parses_to(
+        "SELECT * FROM customers WHERE segment = SOME (SELECT segm FROM bar)",
+        "SELECT * FROM customers WHERE segment = ANY (SELECT segm FROM bar)",
+    );
+}
+
 #[test]
 fn parse_string_agg() {
     let sql = "SELECT a || b";
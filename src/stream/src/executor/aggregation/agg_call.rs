@@ -59,4 +59,6 @@ pub struct AggCall {
     pub args: AggArgs,
     /// The return type of aggregation function.
     pub return_type: DataType,
+    /// Whether the arguments are deduplicated before being aggregated, as in `COUNT(DISTINCT v)`.
+    pub distinct: bool,
 }
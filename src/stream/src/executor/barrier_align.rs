@@ -17,7 +17,7 @@ use futures::StreamExt;
 use risingwave_common::error::Result;
 use tokio::select;
 
-use super::{Barrier, Executor, Message, StreamChunk};
+use super::{Barrier, Executor, Message, StreamChunk, Watermark};
 use crate::executor::BoxedExecutorStream;
 
 #[derive(Debug, PartialEq)]
@@ -32,6 +32,8 @@ pub enum AlignedMessage {
     Left(Result<StreamChunk>),
     Right(Result<StreamChunk>),
     Barrier(Barrier),
+    LeftWatermark(Watermark),
+    RightWatermark(Watermark),
 }
 
 impl<'a> TryFrom<&'a AlignedMessage> for &'a Barrier {
@@ -83,6 +85,7 @@ impl BarrierAligner {
                 match message.unwrap() {
                     Ok(message) => match message {
                         Message::Chunk(chunk) => break AlignedMessage::Left(Ok(chunk)),
+                            Message::Watermark(watermark) => break AlignedMessage::LeftWatermark(watermark),
                             Message::Barrier(barrier) => {
                                 match self.state {
                                     BarrierWaitState::Left => {
@@ -103,6 +106,7 @@ impl BarrierAligner {
                     match message.unwrap() {
                         Ok(message) => match message {
                             Message::Chunk(chunk) => break AlignedMessage::Right(Ok(chunk)),
+                            Message::Watermark(watermark) => break AlignedMessage::RightWatermark(watermark),
                             Message::Barrier(barrier) => match self.state {
                                 BarrierWaitState::Right => {
                                     self.state = BarrierWaitState::Either;
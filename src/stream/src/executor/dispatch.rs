@@ -27,7 +27,7 @@ use risingwave_common::util::addr::{is_local_address, HostAddr};
 use risingwave_common::util::hash_util::CRC32FastBuilder;
 use tracing::event;
 
-use super::{Barrier, Executor, Message, Mutation, Result, StreamChunk, StreamConsumer};
+use super::{Barrier, Executor, Message, Mutation, Result, StreamChunk, StreamConsumer, Watermark};
 use crate::task::{ActorId, SharedContext};
 
 /// `Output` provides an interface for `Dispatcher` to send data into downstream actors.
@@ -173,11 +173,14 @@ impl DispatchExecutor {
                 self.inner.dispatch_barrier(barrier).await?;
                 self.post_mutate_outputs(&mutation).await?;
             }
+            Message::Watermark(watermark) => {
+                self.inner.dispatch_watermark(watermark).await?;
+            }
         };
         Ok(())
     }
 
-    /// For `Add` and `Update`, update the outputs before we dispatch the barrier.
+    /// For `Add`, `Update` and `Replace`, update the outputs before we dispatch the barrier.
     async fn pre_mutate_outputs(&mut self, mutation: &Option<Arc<Mutation>>) -> Result<()> {
         match mutation.as_deref() {
             Some(Mutation::UpdateOutputs(updates)) => {
@@ -203,7 +206,10 @@ impl DispatchExecutor {
                     self.inner.set_outputs(new_outputs)
                 }
             }
-            Some(Mutation::AddOutput(adds)) => {
+            Some(Mutation::AddOutput(adds))
+            | Some(Mutation::Replace {
+                add_outputs: adds, ..
+            }) => {
                 if let Some(downstream_actor_infos) = adds.get(&self.actor_id) {
                     let mut outputs_to_add = Vec::with_capacity(downstream_actor_infos.len());
                     for downstream_actor_info in downstream_actor_infos {
@@ -225,17 +231,13 @@ impl DispatchExecutor {
         Ok(())
     }
 
-    /// For `Stop`, update the outputs after we dispatch the barrier.
+    /// For `Stop` and `Replace`, update the outputs after we dispatch the barrier.
     async fn post_mutate_outputs(&mut self, mutation: &Option<Arc<Mutation>>) -> Result<()> {
-        #[allow(clippy::single_match)]
-        match mutation.as_deref() {
-            Some(Mutation::Stop(stops)) => {
-                // Remove outputs only if this actor itself is not to be stopped.
-                if !stops.contains(&self.actor_id) {
-                    self.inner.remove_outputs(stops);
-                }
+        if let Some(stops) = mutation.as_deref().and_then(Mutation::actors_to_stop) {
+            // Remove outputs only if this actor itself is not to be stopped.
+            if !stops.contains(&self.actor_id) {
+                self.inner.remove_outputs(stops);
             }
-            _ => {}
         }
 
         Ok(())
@@ -280,6 +282,12 @@ macro_rules! impl_dispatcher {
                 }
             }
 
+            pub async fn dispatch_watermark(&mut self, watermark: Watermark) -> Result<()> {
+                match self {
+                    $( Self::$variant_name(inner) => inner.dispatch_watermark(watermark).await, )*
+                }
+            }
+
             pub fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>) {
                 match self {
                     $( Self::$variant_name(inner) => inner.set_outputs(outputs), )*
@@ -319,6 +327,7 @@ macro_rules! define_dispatcher_associated_types {
     () => {
         type DataFuture<'a> = impl DispatchFuture<'a>;
         type BarrierFuture<'a> = impl DispatchFuture<'a>;
+        type WatermarkFuture<'a> = impl DispatchFuture<'a>;
     };
 }
 
@@ -327,8 +336,10 @@ pub trait DispatchFuture<'a> = Future<Output = Result<()>> + Send;
 pub trait Dispatcher: Debug + 'static {
     type DataFuture<'a>: DispatchFuture<'a>;
     type BarrierFuture<'a>: DispatchFuture<'a>;
+    type WatermarkFuture<'a>: DispatchFuture<'a>;
     fn dispatch_data(&mut self, chunk: StreamChunk) -> Self::DataFuture<'_>;
     fn dispatch_barrier(&mut self, barrier: Barrier) -> Self::BarrierFuture<'_>;
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_>;
 
     fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>);
     fn add_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>);
@@ -376,6 +387,16 @@ impl Dispatcher for RoundRobinDataDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            // always broadcast watermark
+            for output in &mut self.outputs {
+                output.send(Message::Watermark(watermark.clone())).await?;
+            }
+            Ok(())
+        }
+    }
+
     fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>) {
         self.outputs = outputs.into_iter().collect();
         self.cur = self.cur.min(self.outputs.len() - 1);
@@ -447,6 +468,16 @@ impl Dispatcher for HashDataDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            // always broadcast watermark
+            for output in &mut self.outputs {
+                output.send(Message::Watermark(watermark.clone())).await?;
+            }
+            Ok(())
+        }
+    }
+
     fn dispatch_data(&mut self, chunk: StreamChunk) -> Self::DataFuture<'_> {
         async move {
             // A chunk can be shuffled into multiple output chunks that to be sent to downstreams.
@@ -614,6 +645,15 @@ impl Dispatcher for BroadcastDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            for output in self.outputs.values_mut() {
+                output.send(Message::Watermark(watermark.clone())).await?;
+            }
+            Ok(())
+        }
+    }
+
     fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>) {
         self.outputs = Self::into_pairs(outputs).collect()
     }
@@ -666,6 +706,13 @@ impl Dispatcher for SimpleDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            self.output.send(Message::Watermark(watermark)).await?;
+            Ok(())
+        }
+    }
+
     fn dispatch_data(&mut self, chunk: StreamChunk) -> Self::DataFuture<'_> {
         async move {
             self.output.send(Message::Chunk(chunk)).await?;
@@ -897,11 +944,18 @@ mod tests {
     async fn test_configuration_change() {
         let schema = Schema { fields: vec![] };
         let (mut tx, rx) = channel(16);
-        let input = Box::new(ReceiverExecutor::new(schema.clone(), vec![], rx)).v1();
-        let data_sink = Arc::new(Mutex::new(vec![]));
         let actor_id = 233;
-        let output = Box::new(MockOutput::new(actor_id, data_sink));
         let ctx = Arc::new(SharedContext::for_test());
+        let input = Box::new(ReceiverExecutor::new(
+            schema.clone(),
+            vec![],
+            actor_id,
+            ctx.clone(),
+            rx,
+        ))
+        .v1();
+        let data_sink = Arc::new(Mutex::new(vec![]));
+        let output = Box::new(MockOutput::new(actor_id, data_sink));
 
         let mut executor = Box::new(DispatchExecutor::new(
             Box::new(input),
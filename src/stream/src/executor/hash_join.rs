@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+
 use async_trait::async_trait;
 use itertools::Itertools;
 use risingwave_common::array::{Array, ArrayRef, DataChunk, Op, Row, RowRef, StreamChunk};
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 use risingwave_common::try_match_expand;
-use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_common::types::{DataType, ScalarImpl, ToOwnedDatum};
 use risingwave_expr::expr::RowExpression;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
@@ -26,7 +28,10 @@ use risingwave_storage::{Keyspace, StateStore};
 
 use super::barrier_align::{AlignedMessage, BarrierAligner};
 use super::managed_state::join::*;
-use super::{Executor, ExecutorState, Message, PkIndices, PkIndicesRef, StatefulExecutor};
+use super::managed_state::watermark::delete_behind_watermark;
+use super::{
+    Executor, ExecutorState, Message, PkIndices, PkIndicesRef, StatefulExecutor, Watermark,
+};
 use crate::common::StreamChunkBuilder;
 use crate::executor::ExecutorBuilder;
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
@@ -42,6 +47,8 @@ pub mod JoinType {
     pub const LeftOuter: JoinTypePrimitive = 1;
     pub const RightOuter: JoinTypePrimitive = 2;
     pub const FullOuter: JoinTypePrimitive = 3;
+    pub const LeftSemi: JoinTypePrimitive = 4;
+    pub const LeftAnti: JoinTypePrimitive = 5;
 }
 
 type SideTypePrimitive = u8;
@@ -67,6 +74,11 @@ const fn outer_side_null(join_type: JoinTypePrimitive, side_type: SideTypePrimit
         || (join_type == JoinType::RightOuter && side_type == SideType::Left)
 }
 
+/// Semi and anti joins only output the rows of the left side.
+const fn is_semi_or_anti(join_type: JoinTypePrimitive) -> bool {
+    join_type == JoinType::LeftSemi || join_type == JoinType::LeftAnti
+}
+
 pub struct JoinParams {
     /// Indices of the join columns
     key_indices: Vec<usize>,
@@ -91,6 +103,8 @@ struct JoinSide<S: StateStore> {
     start_pos: usize,
     /// The join side operates on this keyspace.
     keyspace: Keyspace<S>,
+    /// The latest watermark of each join key column
+    watermarks: Vec<Option<ScalarImpl>>,
 }
 
 impl<S: StateStore> std::fmt::Debug for JoinSide<S> {
@@ -118,6 +132,17 @@ impl<S: StateStore> JoinSide<S> {
         // TODO: not working with rearranged chain
         // self.ht.clear();
     }
+
+    fn apply_watermark(&mut self, watermark: Watermark) {
+        for (key_idx, &col_idx) in self.key_indices.iter().enumerate() {
+            if col_idx == watermark.col_idx {
+                let current = &mut self.watermarks[key_idx];
+                if !matches!(current, Some(v) if *v >= watermark.val) {
+                    *current = Some(watermark.val.clone());
+                }
+            }
+        }
+    }
 }
 
 pub struct HashJoinExecutorBuilder {}
@@ -141,6 +166,9 @@ pub struct HashJoinExecutor<S: StateStore, const T: JoinTypePrimitive> {
     aligner: BarrierAligner,
     /// the data types of the formed new columns
     output_data_types: Vec<DataType>,
+    /// The data types of the concatenation of both sides, on which the non-equi conditions are
+    /// evaluated
+    full_data_types: Vec<DataType>,
     /// The schema of the hash join executor
     schema: Schema,
     /// The primary key indices of the schema
@@ -151,6 +179,8 @@ pub struct HashJoinExecutor<S: StateStore, const T: JoinTypePrimitive> {
     side_r: JoinSide<S>,
     /// Optional non-equi join conditions
     cond: Option<RowExpression>,
+    /// Whether each pair of join keys matches `NULL` with `NULL`, i.e. `IS NOT DISTINCT FROM`
+    null_safe: Vec<bool>,
     /// Debug info for the left executor
     debug_l: String,
     /// Debug info for the right executor
@@ -167,6 +197,11 @@ pub struct HashJoinExecutor<S: StateStore, const T: JoinTypePrimitive> {
     #[allow(dead_code)]
     /// Indices of the columns on which key distribution depends.
     key_indices: Vec<usize>,
+
+    /// The watermark each join key column has been cleaned to
+    cleaned_watermarks: Vec<Option<ScalarImpl>>,
+    /// Watermarks of the output to send before the next message
+    pending_watermarks: VecDeque<Watermark>,
 }
 
 impl<S: StateStore, const T: JoinTypePrimitive> std::fmt::Debug for HashJoinExecutor<S, T> {
@@ -187,28 +222,42 @@ impl<S: StateStore, const T: JoinTypePrimitive> std::fmt::Debug for HashJoinExec
 #[async_trait]
 impl<S: StateStore, const T: JoinTypePrimitive> Executor for HashJoinExecutor<S, T> {
     async fn next(&mut self) -> Result<Message> {
-        let msg = self.aligner.next().await;
-        if let Some(barrier) = self.try_init_executor(&msg) {
-            self.side_l.ht.update_epoch(barrier.epoch.curr);
-            self.side_r.ht.update_epoch(barrier.epoch.curr);
-            return Ok(Message::Barrier(barrier));
+        if let Some(watermark) = self.pending_watermarks.pop_front() {
+            return Ok(Message::Watermark(watermark));
         }
-        match msg {
-            AlignedMessage::Left(message) => match message {
-                Ok(chunk) => self.consume_chunk_left(chunk).await,
-                Err(e) => Err(e),
-            },
-            AlignedMessage::Right(message) => match message {
-                Ok(chunk) => self.consume_chunk_right(chunk).await,
-                Err(e) => Err(e),
-            },
-            AlignedMessage::Barrier(barrier) => {
-                self.flush_data().await?;
-                let epoch = barrier.epoch.curr;
-                self.side_l.ht.update_epoch(epoch);
-                self.side_r.ht.update_epoch(epoch);
-                self.update_executor_state(ExecutorState::Active(barrier.epoch.curr));
-                Ok(Message::Barrier(barrier))
+        loop {
+            let msg = self.aligner.next().await;
+            if let Some(barrier) = self.try_init_executor(&msg) {
+                self.side_l.ht.update_epoch(barrier.epoch.curr);
+                self.side_r.ht.update_epoch(barrier.epoch.curr);
+                return Ok(Message::Barrier(barrier));
+            }
+            match msg {
+                AlignedMessage::Left(message) => {
+                    return match message {
+                        Ok(chunk) => self.consume_chunk_left(chunk).await,
+                        Err(e) => Err(e),
+                    }
+                }
+                AlignedMessage::Right(message) => {
+                    return match message {
+                        Ok(chunk) => self.consume_chunk_right(chunk).await,
+                        Err(e) => Err(e),
+                    }
+                }
+                // Watermarks are only recorded here, and the states behind them are cleaned on the
+                // next barrier.
+                AlignedMessage::LeftWatermark(watermark) => self.side_l.apply_watermark(watermark),
+                AlignedMessage::RightWatermark(watermark) => self.side_r.apply_watermark(watermark),
+                AlignedMessage::Barrier(barrier) => {
+                    self.flush_data().await?;
+                    self.clean_states().await?;
+                    let epoch = barrier.epoch.curr;
+                    self.side_l.ht.update_epoch(epoch);
+                    self.side_r.ht.update_epoch(epoch);
+                    self.update_executor_state(ExecutorState::Active(barrier.epoch.curr));
+                    return Ok(Message::Barrier(barrier));
+                }
             }
         }
     }
@@ -248,6 +297,7 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
         keyspace: Keyspace<S>,
         executor_id: u64,
         cond: Option<RowExpression>,
+        null_safe: Vec<bool>,
         op_info: String,
         key_indices: Vec<usize>,
     ) -> Self {
@@ -257,14 +307,23 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
         let new_column_n = input_l.schema().len() + input_r.schema().len();
         let side_l_column_n = input_l.schema().len();
 
-        let schema_fields = [
+        let full_schema_fields = [
             input_l.schema().fields.clone(),
             input_r.schema().fields.clone(),
         ]
         .concat();
 
-        assert_eq!(schema_fields.len(), new_column_n);
+        assert_eq!(full_schema_fields.len(), new_column_n);
 
+        let full_data_types = full_schema_fields
+            .iter()
+            .map(|field| field.data_type.clone())
+            .collect_vec();
+        let schema_fields = if is_semi_or_anti(T) {
+            input_l.schema().fields.clone()
+        } else {
+            full_schema_fields
+        };
         let output_data_types = schema_fields
             .iter()
             .map(|field| field.data_type.clone())
@@ -286,9 +345,12 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
 
         let ks_l = keyspace.append_u8(JOIN_LEFT_PATH);
         let ks_r = keyspace.append_u8(JOIN_RIGHT_PATH);
+        let join_key_n = params_l.key_indices.len();
+        assert_eq!(null_safe.len(), join_key_n);
         Self {
             aligner: BarrierAligner::new(input_l, input_r),
             output_data_types,
+            full_data_types,
             schema: Schema {
                 fields: schema_fields,
             },
@@ -304,6 +366,7 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
                 pk_indices: pk_indices_l,
                 start_pos: 0,
                 keyspace: ks_l,
+                watermarks: vec![None; join_key_n],
             },
             side_r: JoinSide {
                 ht: JoinHashMap::new(
@@ -317,15 +380,19 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
                 pk_indices: pk_indices_r,
                 start_pos: side_l_column_n,
                 keyspace: ks_r,
+                watermarks: vec![None; join_key_n],
             },
             pk_indices,
             cond,
+            null_safe,
             debug_l,
             debug_r,
             identity: format!("HashJoinExecutor {:X}", executor_id),
             op_info,
             executor_state: ExecutorState::Init,
             key_indices,
+            cleaned_watermarks: vec![None; join_key_n],
+            pending_watermarks: VecDeque::new(),
         }
     }
 
@@ -347,6 +414,64 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
         Ok(())
     }
 
+    /// Clean the states of the join keys behind the watermarks of both sides, which will never be
+    /// matched again, and output the watermarks on the join key columns of both sides.
+    async fn clean_states(&mut self) -> Result<()> {
+        let epoch = self.executor_state().epoch();
+        for key_idx in 0..self.cleaned_watermarks.len() {
+            let watermark = match (
+                &self.side_l.watermarks[key_idx],
+                &self.side_r.watermarks[key_idx],
+            ) {
+                (Some(l), Some(r)) => l.min(r).clone(),
+                _ => continue,
+            };
+            if self.cleaned_watermarks[key_idx].as_ref() >= Some(&watermark) {
+                continue;
+            }
+
+            for side in [&mut self.side_l, &mut self.side_r] {
+                let expired_keys = side
+                    .ht
+                    .iter()
+                    .filter(|(key, _)| matches!(&key[key_idx], Some(v) if v < &watermark))
+                    .map(|(key, _)| key.clone())
+                    .collect_vec();
+                for key in expired_keys {
+                    side.ht.pop(&key);
+                }
+
+                let key_data_types = side
+                    .key_indices
+                    .iter()
+                    .map(|idx| side.col_types[*idx].clone())
+                    .collect_vec();
+                let mut write_batch = side.keyspace.state_store().start_write_batch();
+                delete_behind_watermark(
+                    &side.keyspace,
+                    &key_data_types,
+                    key_idx,
+                    &watermark,
+                    epoch,
+                    &mut write_batch,
+                )
+                .await?;
+                write_batch.ingest(epoch).await?;
+
+                // The columns of the right side are not output by semi and anti joins.
+                if !is_semi_or_anti(T) || side.start_pos == 0 {
+                    self.pending_watermarks.push_back(Watermark::new(
+                        side.start_pos + side.key_indices[key_idx],
+                        key_data_types[key_idx].clone(),
+                        watermark.clone(),
+                    ));
+                }
+            }
+            self.cleaned_watermarks[key_idx] = Some(watermark);
+        }
+        Ok(())
+    }
+
     /// the data the hash table and match the coming
     /// data chunk with the executor state
     async fn hash_eq_match<'a>(
@@ -391,6 +516,14 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
         Row(new_row)
     }
 
+    /// Whether the join key has `NULL` in a column compared with `=`, so that it never matches.
+    fn has_null_unsafe_key(key: &HashKeyType, null_safe: &[bool]) -> bool {
+        key.0
+            .iter()
+            .zip_eq(null_safe)
+            .any(|(datum, null_safe)| datum.is_none() && !null_safe)
+    }
+
     fn bool_from_array_ref(array_ref: ArrayRef) -> bool {
         let bool_array = array_ref.as_ref().as_bool();
         bool_array.value_at(0).unwrap_or_else(|| {
@@ -402,12 +535,22 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
     }
 
     async fn consume_chunk_left(&mut self, chunk: StreamChunk) -> Result<Message> {
-        let result = self.eq_join_oneside::<{ SideType::Left }>(chunk).await?;
+        let result = if is_semi_or_anti(T) {
+            self.semi_anti_join_oneside::<{ SideType::Left }>(chunk)
+                .await?
+        } else {
+            self.eq_join_oneside::<{ SideType::Left }>(chunk).await?
+        };
         Ok(result)
     }
 
     async fn consume_chunk_right(&mut self, chunk: StreamChunk) -> Result<Message> {
-        let result = self.eq_join_oneside::<{ SideType::Right }>(chunk).await?;
+        let result = if is_semi_or_anti(T) {
+            self.semi_anti_join_oneside::<{ SideType::Right }>(chunk)
+                .await?
+        } else {
+            self.eq_join_oneside::<{ SideType::Right }>(chunk).await?
+        };
         Ok(result)
     }
 
@@ -447,6 +590,13 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
 
         for (row, op) in data_chunk.rows().zip_eq(ops.iter()) {
             let key = Self::hash_key_from_row_ref(&row, &side_update.key_indices);
+            if Self::has_null_unsafe_key(&key, &self.null_safe) {
+                // The row never matches, so it's not kept in the hash table.
+                if outer_side_keep(T, SIDE) {
+                    stream_chunk_builder.append_row_update(*op, &row)?;
+                }
+                continue;
+            }
             let value = Self::row_from_row_ref(&row);
             let pk = Self::pk_from_row_ref(&row, &side_update.pk_indices);
             let matched_rows = Self::hash_eq_match(&key, &mut side_match.ht).await;
@@ -469,7 +619,7 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
                             // if there are non-equi expressions
                             if let Some(ref mut cond) = self.cond {
                                 cond_match = Self::bool_from_array_ref(
-                                    cond.eval(&new_row, &self.output_data_types)?,
+                                    cond.eval(&new_row, &self.full_data_types)?,
                                 );
                             }
                             if cond_match {
@@ -514,7 +664,7 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
                                 // if there are non-equi expressions
                                 if let Some(ref mut cond) = self.cond {
                                     cond_match = Self::bool_from_array_ref(
-                                        cond.eval(&new_row, &self.output_data_types)?,
+                                        cond.eval(&new_row, &self.full_data_types)?,
                                     );
                                 }
                                 if cond_match {
@@ -575,6 +725,146 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<S, T> {
 
         Ok(Message::Chunk(new_chunk))
     }
+
+    /// The semi and anti join version of [`Self::eq_join_oneside`], which only outputs the left
+    /// rows. The degree of a left row is the number of right rows it matches, and a left row is
+    /// output by semi joins if its degree is not zero, or by anti joins if its degree is zero.
+    async fn semi_anti_join_oneside<const SIDE: SideTypePrimitive>(
+        &mut self,
+        chunk: StreamChunk,
+    ) -> Result<Message> {
+        let epoch = self.executor_state().epoch();
+        let chunk = chunk.compact()?;
+        let (ops, columns, visibility) = chunk.into_inner();
+
+        let data_chunk = {
+            let data_chunk_builder = DataChunk::builder().columns(columns);
+            if let Some(visibility) = visibility {
+                data_chunk_builder.visibility(visibility).build()
+            } else {
+                data_chunk_builder.build()
+            }
+        };
+
+        let (side_update, side_match) = if SIDE == SideType::Left {
+            (&mut self.side_l, &mut self.side_r)
+        } else {
+            (&mut self.side_r, &mut self.side_l)
+        };
+
+        let mut stream_chunk_builder = StreamChunkBuilder::new(
+            data_chunk.capacity(),
+            &self.output_data_types,
+            side_update.start_pos,
+            side_match.start_pos,
+        )?;
+
+        for (row, op) in data_chunk.rows().zip_eq(ops.iter()) {
+            // Only a part of an update may be output, so updates are output as deletes and inserts.
+            let is_insert = matches!(*op, Op::Insert | Op::UpdateInsert);
+            let key = Self::hash_key_from_row_ref(&row, &side_update.key_indices);
+            let matchable = !Self::has_null_unsafe_key(&key, &self.null_safe);
+            let value = Self::row_from_row_ref(&row);
+            let pk = Self::pk_from_row_ref(&row, &side_update.pk_indices);
+
+            if SIDE == SideType::Left {
+                let matched_rows = if matchable {
+                    Self::hash_eq_match(&key, &mut side_match.ht).await
+                } else {
+                    None
+                };
+                let mut degree = 0;
+                if let Some(matched_rows) = matched_rows {
+                    for matched_row in matched_rows.values_mut(epoch).await {
+                        let new_row = Self::row_concat(
+                            &row,
+                            side_update.start_pos,
+                            &matched_row.row,
+                            side_match.start_pos,
+                        );
+                        let mut cond_match = true;
+                        if let Some(ref mut cond) = self.cond {
+                            cond_match = Self::bool_from_array_ref(
+                                cond.eval(&new_row, &self.full_data_types)?,
+                            );
+                        }
+                        if cond_match {
+                            degree += 1;
+                        }
+                    }
+                }
+                if matchable {
+                    if is_insert {
+                        let entry_value = side_update.ht.get_or_init_without_cache(&key).await?;
+                        entry_value.insert(pk, JoinRow::new(value, degree));
+                    } else if let Some(v) = side_update.ht.get_mut_without_cached(&key).await {
+                        v.remove(pk);
+                    }
+                }
+                if (degree > 0) == (T == JoinType::LeftSemi) {
+                    let op = if is_insert { Op::Insert } else { Op::Delete };
+                    stream_chunk_builder.append_row_update(op, &row)?;
+                }
+            } else if matchable {
+                if is_insert {
+                    let entry_value = side_update.ht.get_or_init_without_cache(&key).await?;
+                    entry_value.insert(pk, JoinRow::new(value, 0));
+                } else if let Some(v) = side_update.ht.get_mut_without_cached(&key).await {
+                    v.remove(pk);
+                }
+
+                if let Some(matched_rows) = Self::hash_eq_match(&key, &mut side_match.ht).await {
+                    let mut updated_rows = vec![];
+                    for matched_row in matched_rows.values_mut(epoch).await {
+                        let new_row = Self::row_concat(
+                            &row,
+                            side_update.start_pos,
+                            &matched_row.row,
+                            side_match.start_pos,
+                        );
+                        let mut cond_match = true;
+                        if let Some(ref mut cond) = self.cond {
+                            cond_match = Self::bool_from_array_ref(
+                                cond.eval(&new_row, &self.full_data_types)?,
+                            );
+                        }
+                        if !cond_match {
+                            continue;
+                        }
+                        // The left row is output or retracted when its degree changes between zero
+                        // and non-zero.
+                        let degree_changed = if is_insert {
+                            matched_row.inc_degree() == 1
+                        } else {
+                            matched_row.dec_degree() == 0
+                        };
+                        if degree_changed {
+                            let op = if is_insert == (T == JoinType::LeftSemi) {
+                                Op::Insert
+                            } else {
+                                Op::Delete
+                            };
+                            stream_chunk_builder.append_row_matched(op, &matched_row.row)?;
+                        }
+                        updated_rows.push(matched_row.clone());
+                    }
+                    // Write the changed degrees back to the flush buffer.
+                    for matched_row in updated_rows {
+                        let pk = Row(side_match
+                            .pk_indices
+                            .iter()
+                            .map(|idx| matched_row.row[*idx].clone())
+                            .collect());
+                        matched_rows.insert(pk, matched_row);
+                    }
+                }
+            }
+        }
+
+        let new_chunk = stream_chunk_builder.finish()?;
+
+        Ok(Message::Chunk(new_chunk))
+    }
 }
 
 impl<S: StateStore, const T: JoinTypePrimitive> StatefulExecutor for HashJoinExecutor<S, T> {
@@ -592,7 +882,7 @@ mod tests {
     use itertools::Itertools;
     use risingwave_common::array::*;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::column_nonnull;
+    use risingwave_common::{column, column_nonnull};
     use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
     use risingwave_expr::expr::{InputRefExpression, RowExpression};
     use risingwave_pb::expr::expr_node::Type;
@@ -680,6 +970,7 @@ mod tests {
             keyspace,
             1,
             None,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -884,6 +1175,7 @@ mod tests {
             keyspace,
             1,
             None,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -1130,6 +1422,7 @@ mod tests {
             keyspace,
             1,
             None,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -1380,6 +1673,7 @@ mod tests {
             keyspace,
             1,
             None,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -1580,6 +1874,7 @@ mod tests {
             keyspace,
             1,
             None,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -1835,6 +2130,7 @@ mod tests {
             keyspace,
             1,
             cond,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -2090,6 +2386,7 @@ mod tests {
             keyspace,
             1,
             cond,
+            vec![false],
             "HashJoinExecutor".to_string(),
             vec![],
         );
@@ -2203,4 +2500,288 @@ mod tests {
             unreachable!();
         }
     }
+
+    #[tokio::test]
+    async fn test_streaming_hash_join_clean_states() {
+        let chunk_l1 = StreamChunk::new(
+            vec![Op::Insert, Op::Insert],
+            vec![
+                column_nonnull! { I64Array, [1, 2] },
+                column_nonnull! { I64Array, [4, 5] },
+            ],
+            None,
+        );
+        let chunk_r1 = StreamChunk::new(
+            vec![Op::Insert],
+            vec![
+                column_nonnull! { I64Array, [3] },
+                column_nonnull! { I64Array, [6] },
+            ],
+            None,
+        );
+        let chunk_r2 = StreamChunk::new(
+            vec![Op::Insert, Op::Insert],
+            vec![
+                column_nonnull! { I64Array, [1, 2] },
+                column_nonnull! { I64Array, [7, 8] },
+            ],
+            None,
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+
+        let (mut tx_l, rx_l) = unbounded_channel();
+        let (mut tx_r, rx_r) = unbounded_channel();
+
+        let source_l = MockAsyncSource::with_pk_indices(schema.clone(), rx_l, vec![0, 1]);
+        let source_r = MockAsyncSource::with_pk_indices(schema.clone(), rx_r, vec![0, 1]);
+
+        let mut hash_join = HashJoinExecutor::<_, { JoinType::Inner }>::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            JoinParams::new(vec![0]),
+            JoinParams::new(vec![0]),
+            vec![],
+            create_in_memory_keyspace(),
+            1,
+            None,
+            vec![false],
+            "HashJoinExecutor".to_string(),
+            vec![],
+        );
+
+        MockAsyncSource::push_barrier(&mut tx_l, 1, false);
+        MockAsyncSource::push_barrier(&mut tx_r, 1, false);
+        hash_join.next().await.unwrap();
+
+        MockAsyncSource::push_chunks(&mut tx_l, vec![chunk_l1]);
+        hash_join.next().await.unwrap();
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r1]);
+        hash_join.next().await.unwrap();
+
+        // The join keys behind the smaller watermark of both sides are cleaned on the barrier.
+        tx_l.send(Message::Watermark(Watermark::new(
+            0,
+            DataType::Int64,
+            ScalarImpl::Int64(3),
+        )))
+        .unwrap();
+        tx_r.send(Message::Watermark(Watermark::new(
+            0,
+            DataType::Int64,
+            ScalarImpl::Int64(2),
+        )))
+        .unwrap();
+        MockAsyncSource::push_barrier(&mut tx_l, 2, false);
+        MockAsyncSource::push_barrier(&mut tx_r, 2, false);
+        assert!(matches!(
+            hash_join.next().await.unwrap(),
+            Message::Barrier(_)
+        ));
+        assert_eq!(
+            hash_join.next().await.unwrap().into_watermark().unwrap(),
+            Watermark::new(0, DataType::Int64, ScalarImpl::Int64(2))
+        );
+        assert_eq!(
+            hash_join.next().await.unwrap().into_watermark().unwrap(),
+            Watermark::new(2, DataType::Int64, ScalarImpl::Int64(2))
+        );
+
+        // The left row with join key 1 is cleaned and no longer matches.
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r2]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_eq!(chunk.ops(), vec![Op::Insert]);
+        for (i, expected) in [2, 5, 2, 8].into_iter().enumerate() {
+            assert_eq!(
+                chunk
+                    .column_at(i)
+                    .array_ref()
+                    .as_int64()
+                    .iter()
+                    .collect_vec(),
+                vec![Some(expected)]
+            );
+        }
+    }
+
+    fn assert_chunk_eq(chunk: &StreamChunk, ops: Vec<Op>, columns: Vec<Vec<Option<i64>>>) {
+        assert_eq!(chunk.ops(), ops);
+        assert_eq!(chunk.columns().len(), columns.len());
+        for (i, column) in columns.into_iter().enumerate() {
+            assert_eq!(
+                chunk
+                    .column_at(i)
+                    .array_ref()
+                    .as_int64()
+                    .iter()
+                    .collect_vec(),
+                column
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_streaming_hash_left_semi_join() {
+        let chunk_l1 = StreamChunk::new(
+            vec![Op::Insert, Op::Insert, Op::Insert],
+            vec![
+                column! { I64Array, [Some(1), Some(2), None] },
+                column_nonnull! { I64Array, [4, 5, 6] },
+            ],
+            None,
+        );
+        let chunk_r1 = StreamChunk::new(
+            vec![Op::Insert, Op::Insert],
+            vec![
+                column! { I64Array, [Some(2), None] },
+                column_nonnull! { I64Array, [7, 8] },
+            ],
+            None,
+        );
+        let chunk_r2 = StreamChunk::new(
+            vec![Op::Insert],
+            vec![
+                column_nonnull! { I64Array, [2] },
+                column_nonnull! { I64Array, [9] },
+            ],
+            None,
+        );
+        let chunk_r3 = StreamChunk::new(
+            vec![Op::Delete, Op::Delete],
+            vec![
+                column_nonnull! { I64Array, [2, 2] },
+                column_nonnull! { I64Array, [7, 9] },
+            ],
+            None,
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+
+        let (mut tx_l, rx_l) = unbounded_channel();
+        let (mut tx_r, rx_r) = unbounded_channel();
+
+        let source_l = MockAsyncSource::with_pk_indices(schema.clone(), rx_l, vec![1]);
+        let source_r = MockAsyncSource::with_pk_indices(schema.clone(), rx_r, vec![1]);
+
+        let mut hash_join = HashJoinExecutor::<_, { JoinType::LeftSemi }>::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            JoinParams::new(vec![0]),
+            JoinParams::new(vec![0]),
+            vec![1],
+            create_in_memory_keyspace(),
+            1,
+            None,
+            vec![false],
+            "HashJoinExecutor".to_string(),
+            vec![],
+        );
+        assert_eq!(hash_join.schema().len(), 2);
+
+        MockAsyncSource::push_barrier(&mut tx_l, 1, false);
+        MockAsyncSource::push_barrier(&mut tx_r, 1, false);
+        hash_join.next().await.unwrap();
+
+        MockAsyncSource::push_chunks(&mut tx_l, vec![chunk_l1]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(&chunk, vec![], vec![vec![], vec![]]);
+
+        // The left row with a NULL key matches nothing, not even the right row with a NULL key.
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r1]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(&chunk, vec![Op::Insert], vec![vec![Some(2)], vec![Some(5)]]);
+
+        // The left row is output only once.
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r2]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(&chunk, vec![], vec![vec![], vec![]]);
+
+        // The left row is retracted when it matches no right rows.
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r3]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(&chunk, vec![Op::Delete], vec![vec![Some(2)], vec![Some(5)]]);
+    }
+
+    #[tokio::test]
+    async fn test_streaming_hash_left_anti_join_null_safe() {
+        let chunk_l1 = StreamChunk::new(
+            vec![Op::Insert, Op::Insert],
+            vec![
+                column! { I64Array, [Some(1), None] },
+                column_nonnull! { I64Array, [4, 6] },
+            ],
+            None,
+        );
+        let chunk_r1 = StreamChunk::new(
+            vec![Op::Insert],
+            vec![
+                column! { I64Array, [None::<i64>] },
+                column_nonnull! { I64Array, [8] },
+            ],
+            None,
+        );
+        let chunk_r2 = StreamChunk::new(
+            vec![Op::Delete],
+            vec![
+                column! { I64Array, [None::<i64>] },
+                column_nonnull! { I64Array, [8] },
+            ],
+            None,
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+
+        let (mut tx_l, rx_l) = unbounded_channel();
+        let (mut tx_r, rx_r) = unbounded_channel();
+
+        let source_l = MockAsyncSource::with_pk_indices(schema.clone(), rx_l, vec![1]);
+        let source_r = MockAsyncSource::with_pk_indices(schema.clone(), rx_r, vec![1]);
+
+        let mut hash_join = HashJoinExecutor::<_, { JoinType::LeftAnti }>::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            JoinParams::new(vec![0]),
+            JoinParams::new(vec![0]),
+            vec![1],
+            create_in_memory_keyspace(),
+            1,
+            None,
+            vec![true],
+            "HashJoinExecutor".to_string(),
+            vec![],
+        );
+
+        MockAsyncSource::push_barrier(&mut tx_l, 1, false);
+        MockAsyncSource::push_barrier(&mut tx_r, 1, false);
+        hash_join.next().await.unwrap();
+
+        MockAsyncSource::push_chunks(&mut tx_l, vec![chunk_l1]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(
+            &chunk,
+            vec![Op::Insert, Op::Insert],
+            vec![vec![Some(1), None], vec![Some(4), Some(6)]],
+        );
+
+        // NULL matches NULL with null-safe keys.
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r1]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(&chunk, vec![Op::Delete], vec![vec![None], vec![Some(6)]]);
+
+        MockAsyncSource::push_chunks(&mut tx_r, vec![chunk_r2]);
+        let chunk = hash_join.next().await.unwrap().into_chunk().unwrap();
+        assert_chunk_eq(&chunk, vec![Op::Insert], vec![vec![None], vec![Some(6)]]);
+    }
 }
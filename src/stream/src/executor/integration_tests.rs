@@ -54,6 +54,7 @@ impl StreamConsumer for MockConsumer {
         match self.input.next().await? {
             Message::Chunk(chunk) => self.data.lock().unwrap().push(chunk),
             Message::Barrier(barrier) => return Ok(Some(barrier)),
+            Message::Watermark(_) => {}
         }
         Ok(None)
     }
@@ -69,7 +70,14 @@ async fn test_merger_sum_aggr() {
         let schema = Schema {
             fields: vec![Field::unnamed(DataType::Int64)],
         };
-        let input = Box::new(ReceiverExecutor::new(schema, vec![], input_rx)).v1();
+        let input = Box::new(ReceiverExecutor::new(
+            schema,
+            vec![],
+            0,
+            Arc::new(SharedContext::for_test()),
+            input_rx,
+        ))
+        .v1();
         // for the local aggregator, we need two states: row count and sum
         let aggregator = Box::new(
             LocalSimpleAggExecutor::new_from_v1(
@@ -79,11 +87,13 @@ async fn test_merger_sum_aggr() {
                         kind: AggKind::RowCount,
                         args: AggArgs::None,
                         return_type: DataType::Int64,
+                        distinct: false,
                     },
                     AggCall {
                         kind: AggKind::Sum,
                         args: AggArgs::Unary(DataType::Int64, 0),
                         return_type: DataType::Int64,
+                        distinct: false,
                     },
                 ],
                 vec![],
@@ -124,7 +134,14 @@ async fn test_merger_sum_aggr() {
     let schema = Schema {
         fields: vec![Field::unnamed(DataType::Int64)],
     };
-    let receiver_op = Box::new(ReceiverExecutor::new(schema.clone(), vec![], rx)).v1();
+    let receiver_op = Box::new(ReceiverExecutor::new(
+        schema.clone(),
+        vec![],
+        0,
+        Arc::new(SharedContext::for_test()),
+        rx,
+    ))
+    .v1();
     let dispatcher = DispatchExecutor::new(
         Box::new(receiver_op),
         DispatcherImpl::RoundRobin(RoundRobinDataDispatcher::new(inputs)),
@@ -136,7 +153,14 @@ async fn test_merger_sum_aggr() {
     handles.push(tokio::spawn(actor.run()));
 
     // use a merge operator to collect data from dispatchers before sending them to aggregator
-    let merger = Box::new(MergeExecutor::new(schema, vec![], 0, outputs)).v1();
+    let merger = Box::new(MergeExecutor::new(
+        schema,
+        vec![],
+        0,
+        Arc::new(SharedContext::for_test()),
+        outputs,
+    ))
+    .v1();
 
     // for global aggregator, we need to sum data and sum row count
     let aggregator = Box::new(
@@ -147,11 +171,13 @@ async fn test_merger_sum_aggr() {
                     kind: AggKind::Sum,
                     args: AggArgs::Unary(DataType::Int64, 0),
                     return_type: DataType::Int64,
+                    distinct: false,
                 },
                 AggCall {
                     kind: AggKind::Sum,
                     args: AggArgs::Unary(DataType::Int64, 1),
                     return_type: DataType::Int64,
+                    distinct: false,
                 },
             ],
             create_in_memory_keyspace(),
@@ -171,6 +197,7 @@ async fn test_merger_sum_aggr() {
             // TODO: use the new streaming_if_null expression here, and add `None` tests
             Box::new(InputRefExpression::new(DataType::Int64, 1)),
         ],
+        vec![],
         3,
         "ProjectExecutor".to_string(),
     );
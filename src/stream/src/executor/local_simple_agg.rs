@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use itertools::Itertools;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::try_match_expand;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
@@ -40,6 +40,14 @@ impl ExecutorBuilder for LocalSimpleAggExecutorBuilder {
             .iter()
             .map(build_agg_call_from_prost)
             .try_collect()?;
+        // The partial results of a `DISTINCT` aggregation can't be merged.
+        if agg_calls.iter().any(|agg_call| agg_call.distinct) {
+            return Err(ErrorCode::NotImplemented(
+                "DISTINCT aggregation in local simple agg".to_string(),
+                None.into(),
+            )
+            .into());
+        }
         Ok(Box::new(
             Box::new(LocalSimpleAggExecutorV2::new_from_v1(
                 params.input.remove(0),
@@ -0,0 +1,69 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::{ColumnDesc, TableId};
+use risingwave_common::try_match_expand;
+use risingwave_common::util::sort_util::OrderPair;
+use risingwave_pb::stream_plan;
+use risingwave_pb::stream_plan::stream_node::Node;
+use risingwave_storage::{Keyspace, StateStore};
+
+use crate::executor::{Executor, ExecutorBuilder, Result};
+use crate::executor_v2::{Executor as ExecutorV2, LookupExecutor as LookupExecutorV2};
+use crate::task::{ExecutorParams, LocalStreamManagerCore};
+
+pub struct LookupExecutorBuilder;
+
+impl ExecutorBuilder for LookupExecutorBuilder {
+    fn new_boxed_executor(
+        mut params: ExecutorParams,
+        node: &stream_plan::StreamNode,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<Box<dyn Executor>> {
+        let node = try_match_expand!(node.get_node().unwrap(), Node::LookupNode)?;
+
+        let arrangement = params.input.remove(1);
+        let stream = params.input.remove(0);
+
+        let table_id = TableId::from(&node.arrangement_table_ref_id);
+        let keyspace = Keyspace::table_root(store, &table_id);
+
+        let arrangement_col_descs = node
+            .arrangement_column_descs
+            .iter()
+            .map(ColumnDesc::from)
+            .collect_vec();
+        let arrangement_order_rules = node
+            .arrangement_order
+            .iter()
+            .map(OrderPair::from_prost)
+            .collect_vec();
+
+        let v2 = Box::new(LookupExecutorV2::new_from_v1(
+            stream,
+            arrangement,
+            keyspace,
+            arrangement_col_descs,
+            arrangement_order_rules,
+            params.pk_indices,
+            node.use_current_epoch,
+            node.stream_key.iter().map(|key| *key as usize).collect(),
+            node.arrange_key.iter().map(|key| *key as usize).collect(),
+        ));
+
+        Ok(Box::new(v2.v1()))
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use itertools::Itertools;
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::{ArrayImpl, ListValue};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum};
+use risingwave_common::util::ordered::OrderedArraysSerializer;
+use risingwave_common::util::sort_util::{OrderPair, OrderType};
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::write_batch::WriteBatch;
+use risingwave_storage::{Keyspace, StateStore};
+
+use crate::executor::managed_state::aggregation::ManagedTableState;
+use crate::executor::managed_state::flush_status::BtreeMapFlushStatus as FlushStatus;
+use crate::executor::PkDataTypes;
+
+/// Manages the state of `array_agg`. All the values, including NULLs, are kept in the state with
+/// the primary key of the input row as the key, so the output list is in the order of the primary
+/// key. Like `ManagedStringAggState`, the cache is all-or-nothing.
+pub struct ManagedArrayAggState<S: StateStore> {
+    cache: BTreeMap<Bytes, FlushStatus<Datum>>,
+
+    /// A cached result.
+    result: Option<ListValue>,
+
+    /// Marks whether there are modifications, i.e. cache != storage
+    dirty: bool,
+
+    /// Number of items in the state.
+    total_count: usize,
+
+    /// Data type of the aggregated values.
+    value_type: DataType,
+
+    /// The keyspace to operate on.
+    keyspace: Keyspace<S>,
+
+    /// Serializer of the primary key columns, which are placed after the value column.
+    pk_serializer: OrderedArraysSerializer,
+}
+
+impl<S: StateStore> ManagedArrayAggState<S> {
+    /// Create a managed array agg state based on `Keyspace`.
+    pub async fn new(
+        keyspace: Keyspace<S>,
+        row_count: usize,
+        value_type: DataType,
+        pk_data_types: PkDataTypes,
+    ) -> Result<Self> {
+        let order_pairs = (0..pk_data_types.len())
+            .map(|i| OrderPair::new(i + 1, OrderType::Ascending))
+            .collect();
+        Ok(Self {
+            cache: BTreeMap::new(),
+            result: None,
+            dirty: false,
+            total_count: row_count,
+            value_type,
+            keyspace,
+            pk_serializer: OrderedArraysSerializer::new(order_pairs),
+        })
+    }
+
+    #[cfg(test)]
+    pub fn get_row_count(&self) -> usize {
+        self.total_count
+    }
+
+    async fn read_all_into_memory(&mut self, epoch: u64) -> Result<()> {
+        // We cannot read from storage into memory when the cache has not been flushed onto the
+        // storage.
+        assert!(!self.is_dirty());
+        let all_data = self.keyspace.scan_strip_prefix(None, epoch).await?;
+        for (raw_key, raw_value) in all_data {
+            let mut deserializer = value_encoding::Deserializer::new(raw_value);
+            let value = deserialize_cell(&mut deserializer, &self.value_type)?;
+            // Here we abuse the semantics of `DeleteInsert` for those values already existed on
+            // the storage, and now we are loading them into memory.
+            self.cache.insert(raw_key, FlushStatus::DeleteInsert(value));
+        }
+        self.dirty = false;
+        Ok(())
+    }
+
+    fn collect_cache_into_result(&mut self) {
+        if self.result.is_some() || self.total_count == 0 {
+            return;
+        }
+        let values = self
+            .cache
+            .values()
+            .filter_map(|value| value.as_option())
+            .cloned()
+            .collect_vec();
+        self.result = Some(ListValue::new(values));
+    }
+
+    fn get_result(&self) -> Datum {
+        self.result.clone().map(Into::into)
+    }
+}
+
+#[async_trait]
+impl<S: StateStore> ManagedTableState<S> for ManagedArrayAggState<S> {
+    async fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+        epoch: u64,
+    ) -> Result<()> {
+        debug_assert!(super::verify_batch(ops, visibility, data));
+
+        if self.total_count > self.cache.len() {
+            assert_eq!(self.cache.len(), 0);
+            // The current policy is all-or-nothing, so no values in the memory.
+            // It means the cache gets flushed onto disk.
+            self.read_all_into_memory(epoch).await?;
+        }
+
+        let mut row_keys = vec![];
+        self.pk_serializer.serialize(data, &mut row_keys);
+
+        for (row_idx, (op, key_bytes)) in ops.iter().zip_eq(row_keys.into_iter()).enumerate() {
+            let visible = visibility
+                .map(|x| x.is_set(row_idx).unwrap())
+                .unwrap_or(true);
+            if !visible {
+                continue;
+            }
+
+            match op {
+                Op::Insert | Op::UpdateInsert => {
+                    let value = data[0].datum_at(row_idx);
+                    FlushStatus::do_insert(self.cache.entry(key_bytes.into()), value);
+                    self.total_count += 1;
+                }
+                Op::Delete | Op::UpdateDelete => {
+                    FlushStatus::do_delete(self.cache.entry(key_bytes.into()));
+                    self.total_count -= 1;
+                }
+            }
+            self.dirty = true;
+            self.result = None;
+        }
+        Ok(())
+    }
+
+    async fn get_output(&mut self, epoch: u64) -> Result<Datum> {
+        if self.total_count == 0 {
+            return Ok(None);
+        }
+        // Since we have an all-or-nothing policy, a clean state either has all the values in the
+        // cache, or has to load them from the storage.
+        if !self.is_dirty() && self.cache.is_empty() {
+            self.read_all_into_memory(epoch).await?;
+        }
+        self.collect_cache_into_result();
+        Ok(self.get_result())
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn flush(&mut self, write_batch: &mut WriteBatch<S>) -> Result<()> {
+        if !self.is_dirty() {
+            return Ok(());
+        }
+
+        let mut local = write_batch.prefixify(&self.keyspace);
+
+        for (key, value) in std::mem::take(&mut self.cache) {
+            match value.into_option() {
+                Some(val) => {
+                    local.put(key, StorageValue::new_default_put(serialize_cell(&val)?));
+                }
+                None => {
+                    local.delete(key);
+                }
+            }
+        }
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I32Array, I64Array, Op};
+    use smallvec::smallvec;
+
+    use super::*;
+    use crate::executor::test_utils::create_in_memory_keyspace;
+
+    #[tokio::test]
+    async fn test_managed_array_agg_state() {
+        let keyspace = create_in_memory_keyspace();
+        let store = keyspace.state_store();
+        let mut managed_state = ManagedArrayAggState::new(
+            keyspace.clone(),
+            0,
+            DataType::Int32,
+            smallvec![DataType::Int64],
+        )
+        .await
+        .unwrap();
+        let epoch: u64 = 0;
+
+        managed_state
+            .apply_batch(
+                &[Op::Insert, Op::Insert, Op::Insert],
+                None,
+                &[
+                    &I32Array::from_slice(&[Some(3), None, Some(1)])
+                        .unwrap()
+                        .into(),
+                    &I64Array::from_slice(&[Some(2), Some(1), Some(0)])
+                        .unwrap()
+                        .into(),
+                ],
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert!(managed_state.is_dirty());
+        let expected: Datum =
+            Some(ListValue::new(vec![Some(1.into()), None, Some(3.into())]).into());
+        assert_eq!(managed_state.get_output(epoch).await.unwrap(), expected);
+
+        let mut write_batch = store.start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(epoch).await.unwrap();
+        assert!(!managed_state.is_dirty());
+
+        // Drop the state like machine crashes, and recover it from the storage.
+        let row_count = managed_state.get_row_count();
+        drop(managed_state);
+        let mut managed_state = ManagedArrayAggState::new(
+            keyspace.clone(),
+            row_count,
+            DataType::Int32,
+            smallvec![DataType::Int64],
+        )
+        .await
+        .unwrap();
+        assert_eq!(managed_state.get_output(epoch).await.unwrap(), expected);
+
+        managed_state
+            .apply_batch(
+                &[Op::Delete, Op::Delete],
+                None,
+                &[
+                    &I32Array::from_slice(&[Some(1), Some(3)]).unwrap().into(),
+                    &I64Array::from_slice(&[Some(0), Some(2)]).unwrap().into(),
+                ],
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            managed_state.get_output(epoch).await.unwrap(),
+            Some(ListValue::new(vec![None]).into())
+        );
+
+        managed_state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[
+                    &I32Array::from_slice(&[None]).unwrap().into(),
+                    &I64Array::from_slice(&[Some(1)]).unwrap().into(),
+                ],
+                epoch,
+            )
+            .await
+            .unwrap();
+        assert_eq!(managed_state.get_output(epoch).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::ArrayImpl;
+use risingwave_common::buffer::{Bitmap, BitmapBuilder};
+use risingwave_common::error::Result;
+use risingwave_common::types::{serialize_datum_into, DataType, ScalarImpl};
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::write_batch::WriteBatch;
+use risingwave_storage::{Keyspace, StateStore};
+
+/// Deduplicates the input of a `DISTINCT` aggregation within one group.
+///
+/// A reference count is kept for each distinct value of the aggregation argument, so that
+/// retractions work: only the first insert and the last delete of a value are forwarded to the
+/// underlying aggregator, and all other changes are made invisible.
+///
+/// In the state store, the counts are stored as `keyspace / memcomparable(value) -> count`. Entries
+/// whose count drops to zero are deleted.
+pub struct DistinctDeduplicater<S: StateStore> {
+    /// Reference counts of the values changed in the current epoch, keyed by the memcomparable
+    /// encoding of the values. The cache is cleared on flush.
+    counts: HashMap<Vec<u8>, i64>,
+
+    /// The keyspace to operate on.
+    keyspace: Keyspace<S>,
+}
+
+impl<S: StateStore> DistinctDeduplicater<S> {
+    pub fn new(keyspace: Keyspace<S>) -> Self {
+        Self {
+            counts: HashMap::new(),
+            keyspace,
+        }
+    }
+
+    /// Update the reference counts with the changes on `column` in `epoch`, and return the
+    /// visibility of the changes to be forwarded to the aggregator. `NULL`s are always forwarded,
+    /// as they are ignored by the aggregators anyway.
+    pub async fn dedup(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        column: &ArrayImpl,
+        epoch: u64,
+    ) -> Result<Bitmap> {
+        let mut builder = BitmapBuilder::with_capacity(ops.len());
+        for (idx, op) in ops.iter().enumerate() {
+            let visible = match visibility {
+                Some(visibility) => visibility.is_set(idx)?,
+                None => true,
+            };
+            let datum = column.datum_at(idx);
+            if !visible || datum.is_none() {
+                builder.append(visible);
+                continue;
+            }
+
+            let mut serializer = memcomparable::Serializer::new(vec![]);
+            serialize_datum_into(&datum, &mut serializer)?;
+            let count = self.get_count(serializer.into_inner(), epoch).await?;
+            let forward = match op {
+                Op::Insert | Op::UpdateInsert => {
+                    *count += 1;
+                    *count == 1
+                }
+                Op::Delete | Op::UpdateDelete => {
+                    *count -= 1;
+                    *count == 0
+                }
+            };
+            builder.append(forward);
+        }
+        Ok(builder.finish())
+    }
+
+    /// Get the reference count of the encoded value, loading it from the state store as of `epoch`
+    /// if not cached.
+    async fn get_count(&mut self, key: Vec<u8>, epoch: u64) -> Result<&mut i64> {
+        if !self.counts.contains_key(&key) {
+            let count = match self.keyspace.get(&key, epoch).await? {
+                Some(raw_data) => {
+                    let mut deserializer = value_encoding::Deserializer::new(raw_data);
+                    deserialize_cell(&mut deserializer, &DataType::Int64)?
+                        .map(|count| count.into_int64())
+                        .unwrap_or(0)
+                }
+                None => 0,
+            };
+            self.counts.insert(key.clone(), count);
+        }
+        Ok(self.counts.get_mut(&key).unwrap())
+    }
+
+    /// Flush the counts changed in the current epoch to a write batch.
+    pub fn flush(&mut self, write_batch: &mut WriteBatch<S>) -> Result<()> {
+        let mut local = write_batch.prefixify(&self.keyspace);
+        for (key, count) in self.counts.drain() {
+            if count == 0 {
+                local.delete(key);
+            } else {
+                debug_assert!(count > 0, "negative count of distinct value");
+                local.put(
+                    key,
+                    StorageValue::new_default_put(serialize_cell(&Some(ScalarImpl::Int64(count)))?),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::I64Array;
+
+    use super::*;
+    use crate::executor::test_utils::create_in_memory_keyspace;
+
+    #[tokio::test]
+    async fn test_distinct_deduplicater() {
+        let keyspace = create_in_memory_keyspace();
+        let mut dedup = DistinctDeduplicater::new(keyspace.clone());
+
+        let column: ArrayImpl = I64Array::from_slice(&[Some(1), Some(2), Some(1), None, Some(3)])
+            .unwrap()
+            .into();
+        let visibility = dedup
+            .dedup(
+                &[Op::Insert, Op::Insert, Op::Insert, Op::Insert, Op::Insert],
+                Some(&Bitmap::try_from(vec![true, true, true, true, false]).unwrap()),
+                &column,
+                1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            visibility.iter().collect::<Vec<_>>(),
+            vec![true, true, false, true, false]
+        );
+
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        dedup.flush(&mut write_batch).unwrap();
+        write_batch.ingest(1).await.unwrap();
+
+        // Reload the counts from the state store: `1` is only forwarded on its last delete, and `2`
+        // is forwarded again after being deleted.
+        let mut dedup = DistinctDeduplicater::new(keyspace);
+        let column: ArrayImpl = I64Array::from_slice(&[Some(1), Some(2), Some(1), Some(2)])
+            .unwrap()
+            .into();
+        let visibility = dedup
+            .dedup(
+                &[Op::Delete, Op::Delete, Op::Delete, Op::Insert],
+                None,
+                &column,
+                2,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            visibility.iter().collect::<Vec<_>>(),
+            vec![false, true, true, true]
+        );
+    }
+}
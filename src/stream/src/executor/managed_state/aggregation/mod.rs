@@ -16,8 +16,11 @@
 
 mod value;
 
+use array_agg::ManagedArrayAggState;
 use risingwave_expr::expr::AggKind;
 pub use value::*;
+mod array_agg;
+mod distinct;
 mod extreme;
 mod extreme_serializer;
 mod string_agg;
@@ -55,7 +58,7 @@ pub enum ManagedStateImpl<S: StateStore> {
     /// States as single scalar value e.g. `COUNT`, `SUM`
     Value(ManagedValueState<S>),
 
-    /// States as table structure e.g. `MAX`, `STRING_AGG`
+    /// States as table structure e.g. `MAX`, `STRING_AGG`, `ARRAY_AGG`
     Table(Box<dyn ManagedTableState<S>>),
 }
 
@@ -68,7 +71,7 @@ impl<S: StateStore> ManagedStateImpl<S> {
         epoch: u64,
     ) -> Result<()> {
         match self {
-            Self::Value(state) => state.apply_batch(ops, visibility, data).await,
+            Self::Value(state) => state.apply_batch(ops, visibility, data, epoch).await,
             Self::Table(state) => state.apply_batch(ops, visibility, data, epoch).await,
         }
     }
@@ -123,6 +126,26 @@ impl<S: StateStore> ManagedStateImpl<S> {
                     .await?,
                 ))
             }
+            AggKind::ArrayAgg => {
+                assert!(
+                    row_count.is_some(),
+                    "should set row_count for value states other than AggKind::RowCount"
+                );
+                // The list state keeps all the values, so it can't be deduplicated.
+                assert!(
+                    !agg_call.distinct,
+                    "array_agg with DISTINCT is not supported"
+                );
+                Ok(Self::Table(Box::new(
+                    ManagedArrayAggState::new(
+                        keyspace,
+                        row_count.unwrap(),
+                        agg_call.args.arg_types()[0].clone(),
+                        pk_data_types,
+                    )
+                    .await?,
+                )))
+            }
             AggKind::StringAgg => {
                 // TODO, It seems with `order by`, `StringAgg` needs more stuff from `AggCall`
                 unimplemented!()
@@ -22,6 +22,7 @@ use risingwave_storage::storage_value::StorageValue;
 use risingwave_storage::write_batch::WriteBatch;
 use risingwave_storage::{Keyspace, StateStore};
 
+use super::distinct::DistinctDeduplicater;
 use crate::executor::{create_streaming_agg_state, AggCall, StreamingAggStateImpl};
 
 /// A wrapper around [`StreamingAggStateImpl`], which fetches data from the state store and helps
@@ -37,6 +38,10 @@ pub struct ManagedValueState<S: StateStore> {
     /// Indicates whether this managed state is dirty. If this state is dirty, we cannot evict the
     /// state from memory.
     is_dirty: bool,
+
+    /// For `DISTINCT` aggregations, deduplicates the input so that each value is only applied to
+    /// `state` once.
+    distinct_dedup: Option<DistinctDeduplicater<S>>,
 }
 
 impl<S: StateStore> ManagedValueState<S> {
@@ -63,6 +68,14 @@ impl<S: StateStore> ManagedValueState<S> {
             None
         };
 
+        // The reference counts of distinct values are stored under the same keyspace, after the
+        // key of the aggregation result.
+        let distinct_dedup = if agg_call.distinct {
+            Some(DistinctDeduplicater::new(keyspace.clone()))
+        } else {
+            None
+        };
+
         // Create the internal state based on the value we get.
         Ok(Self {
             state: create_streaming_agg_state(
@@ -73,6 +86,7 @@ impl<S: StateStore> ManagedValueState<S> {
             )?,
             is_dirty: false,
             keyspace,
+            distinct_dedup,
         })
     }
 
@@ -82,10 +96,17 @@ impl<S: StateStore> ManagedValueState<S> {
         ops: Ops<'_>,
         visibility: Option<&Bitmap>,
         data: &[&ArrayImpl],
+        epoch: u64,
     ) -> Result<()> {
         debug_assert!(super::verify_batch(ops, visibility, data));
         self.is_dirty = true;
-        self.state.apply_batch(ops, visibility, data)
+        match &mut self.distinct_dedup {
+            Some(dedup) => {
+                let visibility = dedup.dedup(ops, visibility, data[0], epoch).await?;
+                self.state.apply_batch(ops, Some(&visibility), data)
+            }
+            None => self.state.apply_batch(ops, visibility, data),
+        }
     }
 
     /// Get the output of the state. Note that in our case, getting the output is very easy, as the
@@ -111,6 +132,9 @@ impl<S: StateStore> ManagedValueState<S> {
         let v = self.state.get_output()?;
         // TODO(Yuanxin): Implement value meta
         local.put_single(StorageValue::new_default_put(serialize_cell(&v)?));
+        if let Some(dedup) = &mut self.distinct_dedup {
+            dedup.flush(write_batch)?;
+        }
         self.is_dirty = false;
         Ok(())
     }
@@ -130,6 +154,7 @@ mod tests {
             kind: risingwave_expr::expr::AggKind::Count,
             args: AggArgs::Unary(DataType::Int64, 0),
             return_type: DataType::Int64,
+            distinct: false,
         }
     }
 
@@ -150,6 +175,7 @@ mod tests {
                 &[&I64Array::from_slice(&[Some(0), Some(1), Some(2), None])
                     .unwrap()
                     .into()],
+                0,
             )
             .await
             .unwrap();
@@ -176,4 +202,73 @@ mod tests {
             Some(ScalarImpl::Int64(3))
         );
     }
+
+    #[tokio::test]
+    async fn test_managed_distinct_value_state() {
+        let keyspace = create_in_memory_keyspace();
+        let agg_call = AggCall {
+            distinct: true,
+            ..create_test_count_state()
+        };
+        let mut managed_state = ManagedValueState::new(agg_call.clone(), keyspace.clone(), Some(0))
+            .await
+            .unwrap();
+
+        managed_state
+            .apply_batch(
+                &[Op::Insert, Op::Insert, Op::Insert, Op::Insert],
+                None,
+                &[&I64Array::from_slice(&[Some(1), Some(1), Some(2), None])
+                    .unwrap()
+                    .into()],
+                0,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(0).await.unwrap();
+        assert_eq!(
+            managed_state.get_output().await.unwrap(),
+            Some(ScalarImpl::Int64(2))
+        );
+
+        // reload the state, `1` is counted until both of its rows are deleted
+        let mut managed_state = ManagedValueState::new(agg_call, keyspace.clone(), None)
+            .await
+            .unwrap();
+        managed_state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&I64Array::from_slice(&[Some(1)]).unwrap().into()],
+                1,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(1).await.unwrap();
+        assert_eq!(
+            managed_state.get_output().await.unwrap(),
+            Some(ScalarImpl::Int64(2))
+        );
+
+        managed_state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&I64Array::from_slice(&[Some(1)]).unwrap().into()],
+                2,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(2).await.unwrap();
+        assert_eq!(
+            managed_state.get_output().await.unwrap(),
+            Some(ScalarImpl::Int64(1))
+        );
+    }
 }
@@ -15,4 +15,6 @@
 pub mod aggregation;
 pub mod flush_status;
 pub mod join;
+pub mod over_agg;
 pub mod top_n;
+pub mod watermark;
@@ -0,0 +1,260 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use risingwave_common::array::Row;
+use risingwave_common::catalog::{ColumnDesc, ColumnId};
+use risingwave_common::collection::evictable::EvictableHashMap;
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_common::util::ordered::*;
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_storage::cell_based_row_deserializer::CellBasedRowDeserializer;
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::{Keyspace, StateStore};
+
+use crate::executor::managed_state::flush_status::BtreeMapFlushStatus as FlushStatus;
+
+/// The rows of an `OverAggExecutor`, grouped by partitions.
+///
+/// Every row is stored under the key `partition key | order key | pk`, where all parts are
+/// memcomparable encoded, so that all rows of a partition can be scanned with the partition key as
+/// prefix, in the order of the window. Partitions are loaded into the cache as a whole, since any
+/// change of a partition requires evaluating the window functions on all its rows.
+pub struct ManagedOverAggState<S: StateStore> {
+    /// Cached partitions, keyed by the partition keys.
+    partitions: EvictableHashMap<Row, Partition>,
+    /// Buffer for updates, keyed by the encoded storage keys.
+    flush_buffer: BTreeMap<Vec<u8>, FlushStatus<Row>>,
+    /// The keyspace to operate on.
+    keyspace: Keyspace<S>,
+    /// `DataType`s of the rows.
+    data_types: Vec<DataType>,
+    /// Indices of the order keys followed by the pk.
+    sort_key_indices: Vec<usize>,
+    /// Order types of `sort_key_indices`.
+    sort_key_order_types: Vec<OrderType>,
+}
+
+/// A cached partition of [`ManagedOverAggState`].
+pub struct Partition {
+    /// The rows sorted by the order keys and then the pk.
+    pub rows: BTreeMap<OrderedRow, Row>,
+    /// The outputs of the window functions last evaluated on `rows`, which are kept by the
+    /// executor to find the changed outputs without evaluating the old rows again. `None` if not
+    /// evaluated since loaded.
+    pub outputs: Option<Vec<(Row, Row)>>,
+}
+
+impl<S: StateStore> ManagedOverAggState<S> {
+    pub fn new(
+        keyspace: Keyspace<S>,
+        data_types: Vec<DataType>,
+        sort_key_indices: Vec<usize>,
+        sort_key_order_types: Vec<OrderType>,
+        cache_capacity: usize,
+    ) -> Self {
+        assert_eq!(sort_key_indices.len(), sort_key_order_types.len());
+        Self {
+            partitions: EvictableHashMap::new(cache_capacity),
+            flush_buffer: BTreeMap::new(),
+            keyspace,
+            data_types,
+            sort_key_indices,
+            sort_key_order_types,
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        !self.flush_buffer.is_empty()
+    }
+
+    fn sort_key(&self, row: &Row) -> OrderedRow {
+        OrderedRow::new(
+            Row(self
+                .sort_key_indices
+                .iter()
+                .map(|i| row.0[*i].clone())
+                .collect()),
+            &self.sort_key_order_types,
+        )
+    }
+
+    /// Returns a partition, loading its rows from the storage if not cached.
+    pub async fn partition(&mut self, partition_key: &Row, epoch: u64) -> Result<&mut Partition> {
+        if !self.partitions.contains(partition_key) {
+            let rows = self.scan_from_storage(partition_key, epoch).await?;
+            self.partitions.put(
+                partition_key.clone(),
+                Partition {
+                    rows,
+                    outputs: None,
+                },
+            );
+        }
+        Ok(self.partitions.get_mut(partition_key).unwrap())
+    }
+
+    /// Inserts a row into a partition, which must have been loaded by [`Self::partition`].
+    pub fn insert(&mut self, partition_key: &Row, row: Row) -> Result<()> {
+        let sort_key = self.sort_key(&row);
+        let storage_key = [partition_key.serialize()?, sort_key.serialize()?].concat();
+        self.partitions
+            .get_mut(partition_key)
+            .expect("partition not loaded")
+            .rows
+            .insert(sort_key, row.clone());
+        FlushStatus::do_insert(self.flush_buffer.entry(storage_key), row);
+        Ok(())
+    }
+
+    /// Deletes a row from a partition, which must have been loaded by [`Self::partition`].
+    pub fn delete(&mut self, partition_key: &Row, row: &Row) -> Result<()> {
+        let sort_key = self.sort_key(row);
+        let storage_key = [partition_key.serialize()?, sort_key.serialize()?].concat();
+        self.partitions
+            .get_mut(partition_key)
+            .expect("partition not loaded")
+            .rows
+            .remove(&sort_key);
+        FlushStatus::do_delete(self.flush_buffer.entry(storage_key));
+        Ok(())
+    }
+
+    async fn scan_from_storage(
+        &self,
+        partition_key: &Row,
+        epoch: u64,
+    ) -> Result<BTreeMap<OrderedRow, Row>> {
+        let kv_pairs = self
+            .keyspace
+            .append(partition_key.serialize()?)
+            .scan_strip_prefix(None, epoch)
+            .await?;
+        let mut deserializer = CellBasedRowDeserializer::new(
+            self.data_types
+                .iter()
+                .enumerate()
+                .map(|(i, data_type)| {
+                    ColumnDesc::unnamed(ColumnId::from(i as i32), data_type.clone())
+                })
+                .collect(),
+        );
+        let mut rows = BTreeMap::new();
+        for (key, value) in kv_pairs {
+            if let Some((_, row)) = deserializer.deserialize(&key, &value)? {
+                rows.insert(self.sort_key(&row), row);
+            }
+        }
+        if let Some((_, row)) = deserializer.take() {
+            rows.insert(self.sort_key(&row), row);
+        }
+        Ok(rows)
+    }
+
+    /// `Flush` can be called by the executor when it receives a barrier and thus needs to
+    /// checkpoint. The partitions out of the cache capacity are evicted after flushing, since
+    /// their changes are all in the storage then.
+    pub async fn flush(&mut self, epoch: u64) -> Result<()> {
+        if !self.is_dirty() {
+            self.partitions.evict_to_target_cap();
+            return Ok(());
+        }
+
+        let column_ids = (0..self.data_types.len() as i32)
+            .map(ColumnId::from)
+            .collect::<Vec<_>>();
+        let mut write_batch = self.keyspace.state_store().start_write_batch();
+        let mut local = write_batch.prefixify(&self.keyspace);
+        for (storage_key, status) in std::mem::take(&mut self.flush_buffer) {
+            let row = status.into_option();
+            let bytes = serialize_pk_and_row(&storage_key, &row, &column_ids)?;
+            for (key, value) in bytes {
+                match value {
+                    Some(val) => local.put(key, StorageValue::new_default_put(val)),
+                    None => local.delete(key),
+                }
+            }
+        }
+        write_batch.ingest(epoch).await?;
+        self.partitions.evict_to_target_cap();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+
+    fn row(partition: i64, order: i64, pk: i64) -> Row {
+        Row(vec![
+            Some(ScalarImpl::Int64(partition)),
+            Some(ScalarImpl::Int64(order)),
+            Some(ScalarImpl::Int64(pk)),
+        ])
+    }
+
+    #[tokio::test]
+    async fn test_managed_over_agg_state() {
+        let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
+        let new_state = || {
+            ManagedOverAggState::new(
+                keyspace.clone(),
+                vec![DataType::Int64; 3],
+                vec![1, 2],
+                vec![OrderType::Descending, OrderType::Ascending],
+                1,
+            )
+        };
+        let p1 = Row(vec![Some(ScalarImpl::Int64(1))]);
+        let p2 = Row(vec![Some(ScalarImpl::Int64(2))]);
+
+        let mut state = new_state();
+        let mut epoch = 0;
+        assert!(state.partition(&p1, epoch).await.unwrap().rows.is_empty());
+        state.partition(&p2, epoch).await.unwrap();
+        state.insert(&p1, row(1, 10, 1)).unwrap();
+        state.insert(&p1, row(1, 30, 2)).unwrap();
+        state.insert(&p1, row(1, 20, 3)).unwrap();
+        state.insert(&p2, row(2, 10, 4)).unwrap();
+        assert!(state.is_dirty());
+        state.flush(epoch).await.unwrap();
+        assert!(!state.is_dirty());
+        // Only the most recently used partition is kept in the cache.
+        assert_eq!(state.partitions.len(), 1);
+        assert!(state.partitions.contains(&p2));
+
+        epoch += 1;
+        state.partition(&p1, epoch).await.unwrap();
+        state.delete(&p1, &row(1, 20, 3)).unwrap();
+        state.flush(epoch).await.unwrap();
+
+        // A new state reads the partitions from the storage.
+        let mut state = new_state();
+        let rows = state
+            .partition(&p1, epoch)
+            .await
+            .unwrap()
+            .rows
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec![row(1, 30, 2), row(1, 10, 1)]);
+        assert_eq!(state.partition(&p2, epoch).await.unwrap().rows.len(), 1);
+    }
+}
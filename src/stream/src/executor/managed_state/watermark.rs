@@ -0,0 +1,173 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::Result;
+use risingwave_common::types::{
+    deserialize_datum_from, serialize_datum_into, DataType, ScalarImpl,
+};
+use risingwave_storage::write_batch::WriteBatch;
+use risingwave_storage::{Keyspace, StateStore};
+
+/// Deletes the entries in `keyspace` behind a watermark. The keys of the entries must start with
+/// a row of `key_data_types` serialized by [`Row::serialize`], and an entry is deleted if the
+/// `key_idx`-th datum of the row is less than `watermark`. Rows with a NULL datum are kept.
+///
+/// If the watermark is on the leading datum, only the entries behind it are scanned. Otherwise the
+/// whole keyspace has to be scanned.
+///
+/// [`Row::serialize`]: risingwave_common::array::Row::serialize
+pub async fn delete_behind_watermark<S: StateStore>(
+    keyspace: &Keyspace<S>,
+    key_data_types: &[DataType],
+    key_idx: usize,
+    watermark: &ScalarImpl,
+    epoch: u64,
+    write_batch: &mut WriteBatch<S>,
+) -> Result<()> {
+    if key_idx == 0 {
+        // NULL datums are serialized with a leading `0` and non-NULL ones with a leading `1`, so
+        // the keys in `[1, watermark)` are exactly the non-NULL ones behind the watermark.
+        let mut serializer = memcomparable::Serializer::new(vec![]);
+        serialize_datum_into(&Some(watermark.clone()), &mut serializer)?;
+        let range = keyspace.prefixed_key([1u8])..keyspace.prefixed_key(serializer.into_inner());
+        let expired_data = keyspace.state_store().scan(range, None, epoch).await?;
+        let mut local = write_batch.prefixify(keyspace);
+        for (raw_key, _) in expired_data {
+            local.delete(&raw_key[keyspace.key().len()..]);
+        }
+        return Ok(());
+    }
+
+    let all_data = keyspace.scan_strip_prefix(None, epoch).await?;
+    let mut local = write_batch.prefixify(keyspace);
+    for (raw_key, _) in all_data {
+        let mut deserializer = memcomparable::Deserializer::new(&raw_key[..]);
+        let mut datum = None;
+        for data_type in &key_data_types[..=key_idx] {
+            datum = deserialize_datum_from(data_type, &mut deserializer)?;
+        }
+        if matches!(datum, Some(ref v) if v < watermark) {
+            local.delete(&raw_key);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use risingwave_common::array::Row;
+    use risingwave_storage::memory::MemoryStateStore;
+    use risingwave_storage::storage_value::StorageValue;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_behind_watermark() {
+        let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
+        let key = |window: Option<i64>, id: i64| {
+            Row(vec![
+                Some(ScalarImpl::Int32(1)),
+                window.map(ScalarImpl::Int64),
+                Some(ScalarImpl::Int64(id)),
+            ])
+            .serialize()
+            .unwrap()
+        };
+        let keys = [
+            key(Some(10), 1),
+            key(Some(20), 2),
+            key(Some(30), 3),
+            key(None, 4),
+        ];
+
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        let mut local = write_batch.prefixify(&keyspace);
+        for k in &keys {
+            local.put(k, StorageValue::new_default_put(Bytes::new()));
+        }
+        write_batch.ingest(1).await.unwrap();
+
+        // Only the second column of the key is known to the watermark.
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        delete_behind_watermark(
+            &keyspace,
+            &[DataType::Int32, DataType::Int64],
+            1,
+            &ScalarImpl::Int64(30),
+            2,
+            &mut write_batch,
+        )
+        .await
+        .unwrap();
+        write_batch.ingest(2).await.unwrap();
+
+        let remaining = keyspace
+            .scan_strip_prefix(None, 2)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(k, _)| k.to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, vec![keys[3].clone(), keys[2].clone()]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_behind_watermark_on_leading_key() {
+        let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
+        let key = |window: Option<i64>, id: i64| {
+            Row(vec![
+                window.map(ScalarImpl::Int64),
+                Some(ScalarImpl::Int64(id)),
+            ])
+            .serialize()
+            .unwrap()
+        };
+        let keys = [
+            key(None, 1),
+            key(Some(10), 2),
+            key(Some(20), 3),
+            key(Some(30), 4),
+        ];
+
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        let mut local = write_batch.prefixify(&keyspace);
+        for k in &keys {
+            local.put(k, StorageValue::new_default_put(Bytes::new()));
+        }
+        write_batch.ingest(1).await.unwrap();
+
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        delete_behind_watermark(
+            &keyspace,
+            &[DataType::Int64],
+            0,
+            &ScalarImpl::Int64(30),
+            2,
+            &mut write_batch,
+        )
+        .await
+        .unwrap();
+        write_batch.ingest(2).await.unwrap();
+
+        let remaining = keyspace
+            .scan_strip_prefix(None, 2)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(k, _)| k.to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, vec![keys[0].clone(), keys[3].clone()]);
+    }
+}
@@ -25,37 +25,46 @@ pub use chain::*;
 pub use debug::*;
 pub use dispatch::*;
 use enum_as_inner::EnumAsInner;
+pub use expand::*;
 pub use filter::*;
 use futures::Stream;
 pub use global_simple_agg::*;
+pub use group_top_n::*;
 pub use hash_agg::*;
 pub use hash_join::*;
+pub use hop_window::*;
 pub use local_simple_agg::*;
+pub use lookup::*;
 pub use merge::*;
 pub use monitor::*;
 pub use mview::*;
+pub use over_agg::*;
 pub use project::*;
+pub use project_set::*;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{ArrayImpl, ArrayRef, DataChunk, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation as ProstMutation;
 use risingwave_pb::data::stream_message::StreamMessage;
 use risingwave_pb::data::{
     Actors as MutationActors, AddMutation, Barrier as ProstBarrier, Epoch as ProstEpoch,
-    NothingMutation, StopMutation, StreamMessage as ProstStreamMessage, UpdateMutation,
+    NothingMutation, ReplaceMutation, StopMutation, StreamMessage as ProstStreamMessage,
+    UpdateMutation, UpstreamActors, Watermark as ProstWatermark,
 };
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
 use risingwave_storage::StateStore;
+pub use sink::*;
 use smallvec::SmallVec;
 pub use source::*;
 pub use top_n::*;
 pub use top_n_appendonly::*;
 use tracing::trace_span;
+pub use union::*;
 
 use crate::task::{ActorId, ExecutorParams, LocalStreamManagerCore, ENABLE_BARRIER_AGGREGATION};
 
@@ -66,19 +75,27 @@ mod batch_query;
 mod chain;
 mod debug;
 mod dispatch;
+mod expand;
 mod filter;
 mod global_simple_agg;
+mod group_top_n;
 mod hash_agg;
 mod hash_join;
+mod hop_window;
 mod local_simple_agg;
+mod lookup;
 pub(crate) mod managed_state;
 mod merge;
 pub mod monitor;
 mod mview;
+mod over_agg;
 mod project;
+mod project_set;
+mod sink;
 mod source;
 mod top_n;
 mod top_n_appendonly;
+mod union;
 
 #[cfg(test)]
 mod integration_tests;
@@ -98,6 +115,14 @@ pub enum Mutation {
     Stop(HashSet<ActorId>),
     UpdateOutputs(HashMap<ActorId, Vec<ActorInfo>>),
     AddOutput(HashMap<ActorId, Vec<ActorInfo>>),
+    /// Replace a materialized view in one barrier: stop its actors, add outputs from the new
+    /// upstream actors to the downstream actors, and switch the merge inputs of the downstream
+    /// actors to the new upstream actors.
+    Replace {
+        stop: HashSet<ActorId>,
+        add_outputs: HashMap<ActorId, Vec<ActorInfo>>,
+        update_inputs: HashMap<ActorId, Vec<ActorId>>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -176,7 +201,21 @@ impl Barrier {
     }
 
     pub fn is_to_stop_actor(&self, actor_id: ActorId) -> bool {
-        matches!(self.mutation.as_deref(), Some(Mutation::Stop(actors)) if actors.contains(&actor_id))
+        self.mutation
+            .as_deref()
+            .and_then(Mutation::actors_to_stop)
+            .map_or(false, |actors| actors.contains(&actor_id))
+    }
+
+    /// Returns the new upstream actors of `actor_id` if its merge inputs are to be replaced by this
+    /// barrier.
+    pub fn replaced_upstreams(&self, actor_id: ActorId) -> Option<&[ActorId]> {
+        match self.mutation.as_deref() {
+            Some(Mutation::Replace { update_inputs, .. }) => {
+                update_inputs.get(&actor_id).map(Vec::as_slice)
+            }
+            _ => None,
+        }
     }
 }
 
@@ -187,6 +226,14 @@ impl PartialEq for Barrier {
 }
 
 impl Mutation {
+    /// Returns the actors to be stopped by this mutation.
+    pub fn actors_to_stop(&self) -> Option<&HashSet<ActorId>> {
+        match self {
+            Mutation::Stop(actors) | Mutation::Replace { stop: actors, .. } => Some(actors),
+            _ => None,
+        }
+    }
+
     /// Return true if the mutation is stop.
     ///
     /// Note that this does not mean we will stop the current actor.
@@ -239,6 +286,35 @@ impl Barrier {
                         })
                         .collect(),
                 })),
+                Some(Mutation::Replace {
+                    stop,
+                    add_outputs,
+                    update_inputs,
+                }) => Some(ProstMutation::Replace(ReplaceMutation {
+                    stop: stop.iter().cloned().collect(),
+                    dispatchers: add_outputs
+                        .iter()
+                        .map(|(&id, actors)| {
+                            (
+                                id,
+                                MutationActors {
+                                    info: actors.clone(),
+                                },
+                            )
+                        })
+                        .collect(),
+                    merges: update_inputs
+                        .iter()
+                        .map(|(&id, actors)| {
+                            (
+                                id,
+                                UpstreamActors {
+                                    actors: actors.clone(),
+                                },
+                            )
+                        })
+                        .collect(),
+                })),
             },
             span: vec![],
         }
@@ -269,6 +345,22 @@ impl Barrier {
                 )
                 .into(),
             ),
+            ProstMutation::Replace(replace) => Some(
+                Mutation::Replace {
+                    stop: HashSet::from_iter(replace.get_stop().clone()),
+                    add_outputs: replace
+                        .dispatchers
+                        .iter()
+                        .map(|(&id, actors)| (id, actors.get_info().clone()))
+                        .collect(),
+                    update_inputs: replace
+                        .merges
+                        .iter()
+                        .map(|(&id, actors)| (id, actors.get_actors().clone()))
+                        .collect(),
+                }
+                .into(),
+            ),
         };
         let epoch = prost.get_epoch().unwrap();
         Ok(Barrier {
@@ -283,10 +375,54 @@ impl Barrier {
     }
 }
 
+/// A watermark of a column promises that no row with a smaller value in the column will come
+/// after it. It is generated by the sources with `WATERMARK FOR` and used to clean the states of
+/// the rows behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watermark {
+    pub col_idx: usize,
+    pub data_type: DataType,
+    pub val: ScalarImpl,
+}
+
+impl Watermark {
+    pub fn new(col_idx: usize, data_type: DataType, val: ScalarImpl) -> Self {
+        Self {
+            col_idx,
+            data_type,
+            val,
+        }
+    }
+
+    /// Returns the same watermark on another column.
+    #[must_use]
+    pub fn with_idx(self, col_idx: usize) -> Self {
+        Self { col_idx, ..self }
+    }
+
+    pub fn to_protobuf(&self) -> Result<ProstWatermark> {
+        let mut serializer = memcomparable::Serializer::new(vec![]);
+        self.val.serialize(&mut serializer)?;
+        Ok(ProstWatermark {
+            col_idx: self.col_idx as u32,
+            data_type: Some(self.data_type.to_protobuf()),
+            val: serializer.into_inner(),
+        })
+    }
+
+    pub fn from_protobuf(prost: &ProstWatermark) -> Result<Self> {
+        let data_type = DataType::from(prost.get_data_type()?);
+        let mut deserializer = memcomparable::Deserializer::new(prost.val.as_slice());
+        let val = ScalarImpl::deserialize(data_type.clone(), &mut deserializer)?;
+        Ok(Self::new(prost.col_idx as usize, data_type, val))
+    }
+}
+
 #[derive(Debug, EnumAsInner)]
 pub enum Message {
     Chunk(StreamChunk),
     Barrier(Barrier),
+    Watermark(Watermark),
 }
 
 impl<'a> TryFrom<&'a Message> for &'a Barrier {
@@ -294,8 +430,8 @@ impl<'a> TryFrom<&'a Message> for &'a Barrier {
 
     fn try_from(m: &'a Message) -> std::result::Result<Self, Self::Error> {
         match m {
-            Message::Chunk(_) => Err(()),
             Message::Barrier(b) => Ok(b),
+            _ => Err(()),
         }
     }
 }
@@ -323,6 +459,7 @@ impl Message {
                 StreamMessage::StreamChunk(prost_stream_chunk)
             }
             Self::Barrier(barrier) => StreamMessage::Barrier(barrier.clone().to_protobuf()),
+            Self::Watermark(watermark) => StreamMessage::Watermark(watermark.to_protobuf()?),
         };
         let prost_stream_msg = ProstStreamMessage {
             stream_message: Some(prost),
@@ -338,6 +475,9 @@ impl Message {
             StreamMessage::Barrier(ref barrier) => {
                 Message::Barrier(Barrier::from_protobuf(barrier)?)
             }
+            StreamMessage::Watermark(ref watermark) => {
+                Message::Watermark(Watermark::from_protobuf(watermark)?)
+            }
         };
         Ok(res)
     }
@@ -487,6 +627,7 @@ pub fn create_executor(
         Node::ProjectNode => ProjectExecutorBuilder,
         Node::TopNNode => TopNExecutorBuilder,
         Node::AppendOnlyTopNNode => AppendOnlyTopNExecutorBuilder,
+        Node::GroupTopNNode => GroupTopNExecutorBuilder,
         Node::LocalSimpleAggNode => LocalSimpleAggExecutorBuilder,
         Node::GlobalSimpleAggNode => SimpleAggExecutorBuilder,
         Node::HashAggNode => HashAggExecutorBuilder,
@@ -495,7 +636,14 @@ pub fn create_executor(
         Node::BatchPlanNode => BatchQueryExecutorBuilder,
         Node::MergeNode => MergeExecutorBuilder,
         Node::MaterializeNode => MaterializeExecutorBuilder,
-        Node::FilterNode => FilterExecutorBuilder
+        Node::FilterNode => FilterExecutorBuilder,
+        Node::UnionNode => UnionExecutorBuilder,
+        Node::HopWindowNode => HopWindowExecutorBuilder,
+        Node::OverAggNode => OverAggExecutorBuilder,
+        Node::ExpandNode => ExpandExecutorBuilder,
+        Node::ProjectSetNode => ProjectSetExecutorBuilder,
+        Node::SinkNode => SinkExecutorBuilder,
+        Node::LookupNode => LookupExecutorBuilder
     }?;
     Ok(real_executor)
 }
@@ -512,7 +660,7 @@ async fn simple_executor_next<E: SimpleExecutor>(executor: &mut E) -> Result<Mes
     match executor.input().next().await {
         Ok(message) => match message {
             Message::Chunk(chunk) => executor.consume_chunk(chunk),
-            Message::Barrier(_) => Ok(message),
+            Message::Barrier(_) | Message::Watermark(_) => Ok(message),
         },
         Err(e) => Err(e),
     }
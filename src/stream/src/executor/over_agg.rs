@@ -0,0 +1,70 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::Result;
+use risingwave_common::try_match_expand;
+use risingwave_common::util::sort_util::OrderPair;
+use risingwave_expr::vector_op::window_function::WindowFunction;
+use risingwave_pb::stream_plan;
+use risingwave_pb::stream_plan::stream_node::Node;
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::Executor;
+use crate::executor::ExecutorBuilder;
+use crate::executor_v2::{Executor as ExecutorV2, OverAggExecutor as OverAggExecutorV2};
+use crate::task::{ExecutorParams, LocalStreamManagerCore};
+
+pub struct OverAggExecutorBuilder;
+
+impl ExecutorBuilder for OverAggExecutorBuilder {
+    fn new_boxed_executor(
+        mut params: ExecutorParams,
+        node: &stream_plan::StreamNode,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<Box<dyn Executor>> {
+        let node = try_match_expand!(node.get_node().unwrap(), Node::OverAggNode)?;
+        let partition_by = node
+            .get_partition_by()
+            .iter()
+            .map(|i| *i as usize)
+            .collect();
+        let (order_by, order_types) = node
+            .get_order_by()
+            .iter()
+            .map(OrderPair::from_prost)
+            .map(|o| (o.column_idx, o.order_type))
+            .unzip();
+        let window_functions = node
+            .get_window_functions()
+            .iter()
+            .map(WindowFunction::from_prost)
+            .collect::<Result<_>>()?;
+        let keyspace = Keyspace::executor_root(store, params.executor_id);
+        Ok(Box::new(
+            Box::new(OverAggExecutorV2::new_from_v1(
+                params.input.remove(0),
+                params.pk_indices,
+                partition_by,
+                order_by,
+                order_types,
+                window_functions,
+                keyspace,
+                params.executor_id,
+                params.op_info,
+            ))
+            .v1(),
+        ))
+    }
+}
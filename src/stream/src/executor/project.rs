@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use itertools::Itertools;
 use risingwave_common::array::column::Column;
@@ -24,7 +27,7 @@ use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
 use risingwave_storage::StateStore;
 
-use super::{Executor, Message, PkIndicesRef, SimpleExecutor, StreamChunk};
+use super::{Executor, Message, PkIndicesRef, SimpleExecutor, StreamChunk, Watermark};
 use crate::executor::{ExecutorBuilder, PkIndices};
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
 
@@ -40,6 +43,14 @@ pub struct ProjectExecutor {
     /// Expressions of the current projection.
     exprs: Vec<BoxedExpression>,
 
+    /// Pairs of `(input_col, output_col)`, where the expression of the output column is a
+    /// non-decreasing function of the input column, so the watermarks of the input column can be
+    /// derived to the output column.
+    watermark_derivations: Vec<(usize, usize)>,
+
+    /// Derived watermarks to send before the next message
+    pending_watermarks: VecDeque<Watermark>,
+
     /// Identity string
     identity: String,
 
@@ -73,10 +84,17 @@ impl ExecutorBuilder for ProjectExecutorBuilder {
             .iter()
             .map(build_from_prost)
             .collect::<Result<Vec<_>>>()?;
+        let watermark_derivations = node
+            .get_watermark_input_cols()
+            .iter()
+            .zip_eq(node.get_watermark_output_cols())
+            .map(|(&input_col, &output_col)| (input_col as usize, output_col as usize))
+            .collect_vec();
         Ok(Box::new(ProjectExecutor::new(
             params.input.remove(0),
             params.pk_indices,
             project_exprs,
+            watermark_derivations,
             params.executor_id,
             params.op_info,
         )))
@@ -88,6 +106,7 @@ impl ProjectExecutor {
         input: Box<dyn Executor>,
         pk_indices: PkIndices,
         exprs: Vec<BoxedExpression>,
+        watermark_derivations: Vec<(usize, usize)>,
         executor_id: u64,
         op_info: String,
     ) -> Self {
@@ -102,16 +121,59 @@ impl ProjectExecutor {
             pk_indices,
             input,
             exprs,
+            watermark_derivations,
+            pending_watermarks: VecDeque::new(),
             identity: format!("ProjectExecutor {:X}", executor_id),
             op_info,
         }
     }
+
+    /// Derives the watermarks of the output columns from a watermark of the input, by evaluating
+    /// the expressions on a row with only the watermark column set.
+    fn derive_watermarks(&self, watermark: Watermark) -> Result<Vec<Watermark>> {
+        let mut derived = vec![];
+        for &(input_col, output_col) in &self.watermark_derivations {
+            if input_col != watermark.col_idx {
+                continue;
+            }
+            let columns = self
+                .input
+                .schema()
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| {
+                    let mut builder = field.data_type.create_array_builder(1)?;
+                    let datum = (idx == input_col).then(|| watermark.val.clone());
+                    builder.append_datum(&datum)?;
+                    Ok(Column::new(Arc::new(builder.finish()?)))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let data_chunk = DataChunk::builder().columns(columns).build();
+            let expr = &self.exprs[output_col];
+            if let Some(val) = expr.eval(&data_chunk)?.datum_at(0) {
+                derived.push(Watermark::new(output_col, expr.return_type(), val));
+            }
+        }
+        Ok(derived)
+    }
 }
 
 #[async_trait]
 impl Executor for ProjectExecutor {
     async fn next(&mut self) -> Result<Message> {
-        super::simple_executor_next(self).await
+        loop {
+            if let Some(watermark) = self.pending_watermarks.pop_front() {
+                return Ok(Message::Watermark(watermark));
+            }
+            match super::simple_executor_next(self).await? {
+                Message::Watermark(watermark) => {
+                    let derived = self.derive_watermarks(watermark)?;
+                    self.pending_watermarks.extend(derived);
+                }
+                message => return Ok(message),
+            }
+        }
     }
 
     fn schema(&self) -> &Schema {
@@ -169,13 +231,13 @@ mod tests {
     use risingwave_common::array::{I64Array, *};
     use risingwave_common::catalog::{Field, Schema};
     use risingwave_common::column_nonnull;
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
-    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_expr::expr::{InputRefExpression, LiteralExpression};
     use risingwave_pb::expr::expr_node::Type;
 
     use crate::executor::test_utils::MockSource;
-    use crate::executor::{Executor, Message, PkIndices, ProjectExecutor};
+    use crate::executor::{Executor, Message, PkIndices, ProjectExecutor, Watermark};
 
     #[tokio::test]
     async fn test_projection() {
@@ -216,6 +278,7 @@ mod tests {
             Box::new(source),
             vec![],
             vec![test_expr],
+            vec![],
             1,
             "ProjectExecutor".to_string(),
         );
@@ -254,4 +317,56 @@ mod tests {
 
         assert!(project.next().await.unwrap().is_stop());
     }
+
+    #[tokio::test]
+    async fn test_projection_watermark() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let watermark = |col_idx: usize, val: i64| {
+            Watermark::new(col_idx, DataType::Int64, ScalarImpl::Int64(val))
+        };
+        let source = MockSource::with_messages(
+            schema,
+            PkIndices::new(),
+            vec![
+                Message::Watermark(watermark(0, 15)),
+                Message::Watermark(watermark(1, 7)),
+            ],
+        );
+
+        let minus_expr = new_binary_expr(
+            Type::Subtract,
+            DataType::Int64,
+            Box::new(InputRefExpression::new(DataType::Int64, 0)),
+            Box::new(LiteralExpression::new(
+                DataType::Int64,
+                Some(ScalarImpl::Int64(10)),
+            )),
+        );
+        let mut project = ProjectExecutor::new(
+            Box::new(source),
+            vec![],
+            vec![
+                Box::new(InputRefExpression::new(DataType::Int64, 1)),
+                minus_expr,
+                Box::new(InputRefExpression::new(DataType::Int64, 0)),
+            ],
+            vec![(1, 0), (0, 1), (0, 2)],
+            1,
+            "ProjectExecutor".to_string(),
+        );
+
+        let mut watermarks = vec![];
+        for _ in 0..3 {
+            watermarks.push(project.next().await.unwrap().into_watermark().unwrap());
+        }
+        assert_eq!(
+            watermarks,
+            vec![watermark(1, 5), watermark(2, 15), watermark(0, 7)]
+        );
+    }
 }
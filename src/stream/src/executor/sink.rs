@@ -0,0 +1,56 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::try_match_expand;
+use risingwave_connector::sink::build_sink;
+use risingwave_pb::stream_plan;
+use risingwave_pb::stream_plan::stream_node::Node;
+use risingwave_storage::StateStore;
+
+use super::Executor;
+use crate::executor::ExecutorBuilder;
+use crate::executor_v2::{Executor as ExecutorV2, SinkExecutor as SinkExecutorV2};
+use crate::task::{ExecutorParams, LocalStreamManagerCore};
+
+pub struct SinkExecutorBuilder;
+
+impl ExecutorBuilder for SinkExecutorBuilder {
+    fn new_boxed_executor(
+        mut params: ExecutorParams,
+        node: &stream_plan::StreamNode,
+        _store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<Box<dyn Executor>> {
+        let node = try_match_expand!(node.get_node().unwrap(), Node::SinkNode)?;
+        let input = params.input.remove(0);
+        let sink = build_sink(
+            &node.properties,
+            input.schema().clone(),
+            params.pk_indices.clone(),
+        )
+        .map_err(|e| RwError::from(InternalError(e.to_string())))?;
+        Ok(Box::new(
+            Box::new(SinkExecutorV2::new_from_v1(
+                input,
+                params.pk_indices,
+                sink,
+                params.executor_id,
+                params.op_info,
+            ))
+            .v1(),
+        ))
+    }
+}
@@ -22,13 +22,17 @@ use either::Either;
 use futures::stream::{select_with_strategy, PollNext};
 use futures::{Future, Stream, StreamExt};
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::{ArrayBuilder, ArrayImpl, I64ArrayBuilder, StreamChunk};
+use risingwave_common::array::{ArrayBuilder, ArrayImpl, DataChunk, I64ArrayBuilder, StreamChunk};
+use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::{ColumnId, Field, Schema, TableId};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::try_match_expand;
+use risingwave_common::types::ScalarImpl;
 use risingwave_connector::state;
+use risingwave_expr::expr::{build_from_prost, BoxedExpression};
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
 use risingwave_source::connector_source::ConnectorStreamSource;
@@ -37,7 +41,7 @@ use risingwave_storage::{Keyspace, StateStore};
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 
 use crate::executor::monitor::StreamingMetrics;
-use crate::executor::{Executor, ExecutorBuilder, Message, PkIndices, PkIndicesRef};
+use crate::executor::{Executor, ExecutorBuilder, Message, PkIndices, PkIndicesRef, Watermark};
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
 
 struct SourceReader {
@@ -85,6 +89,16 @@ pub struct SourceExecutor {
     // monitor
     metrics: Arc<StreamingMetrics>,
     source_identify: String,
+
+    /// The index of the watermark column and the expression to generate watermarks from it, as
+    /// defined by `WATERMARK FOR` of the source.
+    watermark_generator: Option<(usize, BoxedExpression)>,
+
+    /// The current watermark. The rows behind it are late and dropped.
+    current_watermark: Option<ScalarImpl>,
+
+    /// The advanced watermark to send before the next message.
+    pending_watermark: Option<Watermark>,
 }
 
 pub struct SourceExecutorBuilder {}
@@ -181,6 +195,16 @@ impl SourceExecutor {
         op_info: String,
         streaming_metrics: Arc<StreamingMetrics>,
     ) -> Result<Self> {
+        let watermark_generator = if let Some(watermark_desc) = &source_desc.watermark_desc
+            && let Some(idx) = column_ids
+                .iter()
+                .position(|id| *id == ColumnId::from(watermark_desc.column_id))
+        {
+            Some((idx, build_from_prost(watermark_desc.get_expr()?)?))
+        } else {
+            None
+        };
+
         let source = source_desc.clone().source;
         let stream_reader_future: StreamReaderFuture = Box::pin(build_stream_reader(
             source,
@@ -206,9 +230,57 @@ impl SourceExecutor {
             reader_stream: None,
             metrics: streaming_metrics,
             source_identify: "Table_".to_string() + &source_id.table_id().to_string(),
+            watermark_generator,
+            current_watermark: None,
+            pending_watermark: None,
         })
     }
 
+    /// Drops the late rows behind the current watermark, and advances the watermark by the
+    /// maximum watermark generated from the rows.
+    fn apply_watermark(&mut self, chunk: StreamChunk) -> Result<StreamChunk> {
+        let (col_idx, expr) = match &self.watermark_generator {
+            Some((col_idx, expr)) => (*col_idx, expr),
+            None => return Ok(chunk),
+        };
+
+        let (ops, columns, visibility) = chunk.compact()?.into_inner();
+        assert!(visibility.is_none());
+        let array = columns[col_idx].array_ref();
+        let visibility = (0..array.len())
+            .map(|idx| {
+                !matches!(
+                    (array.datum_at(idx), &self.current_watermark),
+                    (Some(val), Some(watermark)) if &val < watermark
+                )
+            })
+            .collect_vec();
+
+        let data_chunk = DataChunk::builder()
+            .columns(vec![columns[col_idx].clone()])
+            .build();
+        let watermarks = expr.eval(&data_chunk)?;
+        let max_watermark = (0..watermarks.len())
+            .filter_map(|idx| watermarks.datum_at(idx))
+            .max();
+        if let Some(max_watermark) = max_watermark
+            && self.current_watermark.as_ref().map_or(true, |w| &max_watermark > w)
+        {
+            self.current_watermark = Some(max_watermark.clone());
+            self.pending_watermark = Some(Watermark::new(
+                col_idx,
+                expr.return_type(),
+                max_watermark,
+            ));
+        }
+
+        Ok(StreamChunk::new(
+            ops,
+            columns,
+            Some(Bitmap::try_from(visibility)?),
+        ))
+    }
+
     fn gen_row_column(&mut self, len: usize) -> Column {
         let mut builder = I64ArrayBuilder::new(len).unwrap();
 
@@ -296,6 +368,10 @@ impl Executor for SourceExecutor {
             self.reader_stream.replace(reader.into_stream().boxed());
         }
 
+        if let Some(watermark) = self.pending_watermark.take() {
+            return Ok(Message::Watermark(watermark));
+        }
+
         match self.reader_stream.as_mut().unwrap().next().await {
             // This branch will be preferred.
             Some(Either::Left(message)) => message,
@@ -312,6 +388,7 @@ impl Executor for SourceExecutor {
                 if !matches!(self.source_desc.source.as_ref(), SourceImpl::TableV2(_)) {
                     chunk = self.refill_row_id_column(chunk);
                 }
+                chunk = self.apply_watermark(chunk)?;
 
                 self.metrics
                     .source_output_row_count
@@ -362,6 +439,9 @@ mod tests {
     use risingwave_common::array_nonnull;
     use risingwave_common::catalog::{ColumnDesc, Field, Schema};
     use risingwave_common::types::DataType;
+    use risingwave_pb::catalog::WatermarkDesc;
+    use risingwave_pb::expr::expr_node::{RexNode, Type};
+    use risingwave_pb::expr::{ExprNode, InputRefExpr};
     use risingwave_source::*;
     use risingwave_storage::memory::MemoryStateStore;
     use tokio::sync::mpsc::unbounded_channel;
@@ -495,6 +575,7 @@ mod tests {
                 Message::Barrier(barrier) => {
                     assert_eq!(barrier.epoch, Epoch::new_test_epoch(1))
                 }
+                Message::Watermark(_) => unreachable!(),
             }
         }
 
@@ -519,6 +600,126 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_source_watermark() -> Result<()> {
+        let table_id = TableId::default();
+
+        let table_columns = vec![
+            ColumnDesc {
+                column_id: ColumnId::from(0),
+                data_type: DataType::Int64,
+                name: String::new(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            },
+            ColumnDesc {
+                column_id: ColumnId::from(1),
+                data_type: DataType::Int32,
+                name: String::new(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            },
+        ];
+        let source_manager = MemSourceManager::new();
+        source_manager.create_table_source_v2(&table_id, table_columns)?;
+        let mut source_desc = source_manager.get_source(&table_id)?;
+        let source = source_desc.clone().source;
+        // WATERMARK FOR v1 AS v1
+        source_desc.watermark_desc = Some(WatermarkDesc {
+            column_id: 1,
+            expr: Some(ExprNode {
+                expr_type: Type::InputRef as i32,
+                return_type: Some(DataType::Int32.to_protobuf()),
+                rex_node: Some(RexNode::InputRef(InputRefExpr { column_idx: 0 })),
+            }),
+        });
+
+        let chunk = |values: [i32; 3]| {
+            let rowid = Column::new(Arc::new(array_nonnull! { I64Array, [0, 0, 0] }.into()));
+            let col1 = Column::new(Arc::new(I32Array::from_slice(&values.map(Some))?.into()));
+            Ok::<_, RwError>(StreamChunk::new(
+                vec![Op::Insert; 3],
+                vec![rowid, col1],
+                None,
+            ))
+        };
+
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let column_ids = vec![0, 1].into_iter().map(ColumnId::from).collect();
+
+        let (barrier_sender, barrier_receiver) = unbounded_channel();
+        let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
+
+        let mut source_executor = SourceExecutor::new(
+            table_id,
+            source_desc,
+            keyspace,
+            column_ids,
+            schema,
+            vec![0],
+            barrier_receiver,
+            1,
+            1,
+            "SourceExecutor".to_string(),
+            Arc::new(StreamingMetrics::new(prometheus::Registry::new())),
+        )
+        .unwrap();
+
+        let write_chunk = |chunk: StreamChunk| {
+            let source = source.clone();
+            tokio::spawn(async move {
+                let table_source = source.as_table_v2().unwrap();
+                table_source.blocking_write_chunk(chunk).await.unwrap();
+            });
+        };
+
+        barrier_sender
+            .send(Message::Barrier(Barrier {
+                epoch: Epoch::new_test_epoch(1),
+                ..Barrier::default()
+            }))
+            .unwrap();
+        source_executor
+            .next()
+            .await
+            .unwrap()
+            .into_barrier()
+            .unwrap();
+
+        write_chunk(chunk([3, 1, 2])?);
+        let chunk1 = source_executor.next().await.unwrap().into_chunk().unwrap();
+        assert_eq!(chunk1.cardinality(), 3);
+        assert_eq!(
+            source_executor
+                .next()
+                .await
+                .unwrap()
+                .into_watermark()
+                .unwrap(),
+            Watermark::new(1, DataType::Int32, ScalarImpl::Int32(3))
+        );
+
+        // The late row behind the watermark is dropped.
+        write_chunk(chunk([4, 2, 6])?);
+        let chunk2 = source_executor.next().await.unwrap().into_chunk().unwrap();
+        assert_eq!(chunk2.cardinality(), 2);
+        assert_eq!(
+            source_executor
+                .next()
+                .await
+                .unwrap()
+                .into_watermark()
+                .unwrap(),
+            Watermark::new(1, DataType::Int32, ScalarImpl::Int32(6))
+        );
+
+        Ok(())
+    }
     #[tokio::test]
     async fn test_table_dropped() -> Result<()> {
         let table_id = TableId::default();
@@ -21,7 +21,7 @@ use risingwave_storage::{Keyspace, StateStore};
 use static_assertions::const_assert_eq;
 
 use crate::executor::managed_state::aggregation::ManagedStateImpl;
-use crate::executor::{AggCall, AggState, PkDataTypes, ROW_COUNT_COLUMN};
+use crate::executor::{AggCall, AggState, PkDataTypes, Watermark, ROW_COUNT_COLUMN};
 use crate::executor_v2::error::{
     StreamExecutorError, StreamExecutorResult, TracedStreamExecutorError,
 };
@@ -37,6 +37,16 @@ pub trait AggExecutor: Send + 'static {
     /// Flush the buffered chunk to the storage backend, and get the edits of the states. If there's
     /// no dirty states to flush, return `Ok(None)`.
     async fn flush_data(&mut self, epoch: u64) -> StreamExecutorResult<Option<StreamChunk>>;
+
+    /// Buffer a watermark of the input, which will be applied after the next flush. Watermarks
+    /// not on the group keys are dropped by default.
+    fn apply_watermark(&mut self, _watermark: Watermark) {}
+
+    /// Clean the states behind the buffered watermarks after flushing, and get the watermarks to
+    /// forward on the output.
+    async fn clean_states(&mut self, _epoch: u64) -> StreamExecutorResult<Vec<Watermark>> {
+        Ok(vec![])
+    }
 }
 
 /// The struct wraps a [`AggExecutor`]
@@ -78,6 +88,14 @@ where
     async fn flush_data(&mut self, epoch: u64) -> StreamExecutorResult<Option<StreamChunk>> {
         self.inner.flush_data(epoch).await
     }
+
+    fn apply_watermark(&mut self, watermark: Watermark) {
+        self.inner.apply_watermark(watermark)
+    }
+
+    async fn clean_states(&mut self, epoch: u64) -> StreamExecutorResult<Vec<Watermark>> {
+        self.inner.clean_states(epoch).await
+    }
 }
 
 impl<E> AggExecutorWrapper<E>
@@ -100,12 +118,18 @@ where
             let msg = msg?;
             match msg {
                 Message::Chunk(chunk) => self.inner.apply_chunk(chunk, epoch).await?,
+                Message::Watermark(watermark) => self.inner.apply_watermark(watermark),
                 Message::Barrier(barrier) => {
                     let next_epoch = barrier.epoch.curr;
                     if let Some(chunk) = self.inner.flush_data(epoch).await? {
                         assert_eq!(epoch, barrier.epoch.prev);
                         yield Message::Chunk(chunk);
                     }
+                    // The results behind the watermarks are final now, so their states can be
+                    // cleaned before forwarding the watermarks.
+                    for watermark in self.inner.clean_states(epoch).await? {
+                        yield Message::Watermark(watermark);
+                    }
                     yield Message::Barrier(barrier);
                     epoch = next_epoch;
                 }
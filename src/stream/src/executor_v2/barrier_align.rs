@@ -15,6 +15,7 @@
 use futures::StreamExt;
 use tokio::select;
 
+use crate::executor::Watermark;
 use crate::executor_v2::error::StreamExecutorResult;
 use crate::executor_v2::{Barrier, BoxedMessageStream, Executor, Message, StreamChunk};
 
@@ -30,6 +31,8 @@ pub enum AlignedMessage {
     Left(StreamExecutorResult<StreamChunk>),
     Right(StreamExecutorResult<StreamChunk>),
     Barrier(Barrier),
+    LeftWatermark(Watermark),
+    RightWatermark(Watermark),
 }
 
 impl<'a> TryFrom<&'a AlignedMessage> for &'a Barrier {
@@ -69,6 +72,7 @@ impl BarrierAligner {
                 match message.unwrap() {
                     Ok(message) => match message {
                         Message::Chunk(chunk) => break AlignedMessage::Left(Ok(chunk)),
+                            Message::Watermark(watermark) => break AlignedMessage::LeftWatermark(watermark),
                             Message::Barrier(barrier) => {
                                 match self.state {
                                     BarrierWaitState::Left => {
@@ -89,6 +93,7 @@ impl BarrierAligner {
                     match message.unwrap() {
                         Ok(message) => match message {
                             Message::Chunk(chunk) => break AlignedMessage::Right(Ok(chunk)),
+                            Message::Watermark(watermark) => break AlignedMessage::RightWatermark(watermark),
                             Message::Barrier(barrier) => match self.state {
                                 BarrierWaitState::Right => {
                                     self.state = BarrierWaitState::Either;
@@ -32,6 +32,9 @@ pub struct ChainExecutor {
 
     upstream_indices: Vec<usize>,
 
+    /// Whether to skip the snapshot and only forward the changes of the upstream.
+    upstream_only: bool,
+
     notifier: FinishCreateMviewNotifier,
 
     actor_id: ActorId,
@@ -39,7 +42,7 @@ pub struct ChainExecutor {
     info: ExecutorInfo,
 }
 
-fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
+fn mapping(upstream_indices: &[usize], msg: Message) -> Option<Message> {
     match msg {
         Message::Chunk(chunk) => {
             let (ops, columns, visibility) = chunk.into_inner();
@@ -47,9 +50,18 @@ fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
                 .iter()
                 .map(|&i| columns[i].clone())
                 .collect();
-            Message::Chunk(StreamChunk::new(ops, mapped_columns, visibility))
+            Some(Message::Chunk(StreamChunk::new(
+                ops,
+                mapped_columns,
+                visibility,
+            )))
         }
-        _ => msg,
+        // The watermark is dropped if its column is not chained.
+        Message::Watermark(watermark) => upstream_indices
+            .iter()
+            .position(|&i| i == watermark.col_idx)
+            .map(|idx| Message::Watermark(watermark.with_idx(idx))),
+        _ => Some(msg),
     }
 }
 
@@ -58,6 +70,7 @@ impl ChainExecutor {
         snapshot: BoxedExecutor,
         upstream: BoxedExecutor,
         upstream_indices: Vec<usize>,
+        upstream_only: bool,
         notifier: FinishCreateMviewNotifier,
         actor_id: ActorId,
         info: ExecutorInfo,
@@ -66,6 +79,7 @@ impl ChainExecutor {
             snapshot,
             upstream,
             upstream_indices,
+            upstream_only,
             notifier,
             actor_id,
             info,
@@ -84,6 +98,9 @@ impl ChainExecutor {
         let epoch = barrier.epoch;
 
         let to_consume_snapshot = match barrier.mutation.as_ref().cloned().as_deref() {
+            // Only the changes are required, the snapshot is never consumed.
+            _ if self.upstream_only => false,
+
             // If the barrier is a conf change of creating this mview, init snapshot from its epoch
             // and begin to consume the snapshot.
             Some(Mutation::AddOutput(map)) => map
@@ -119,7 +136,9 @@ impl ChainExecutor {
         #[for_await]
         for msg in upstream {
             let msg = msg?;
-            yield mapping(&self.upstream_indices, msg);
+            if let Some(msg) = mapping(&self.upstream_indices, msg) {
+                yield msg;
+            }
         }
     }
 }
@@ -209,6 +228,7 @@ mod test {
             first,
             second,
             vec![0],
+            false,
             notifier,
             0,
             ExecutorInfo {
@@ -42,8 +42,14 @@ pub enum StreamExecutorError {
     #[error("top n state error {0}")]
     TopNStateError(RwError),
 
+    #[error("over agg state error {0}")]
+    OverAggStateError(RwError),
+
     #[error("channel `{0}` closed")]
     ChannelClosed(String),
+
+    #[error("sink error {0}")]
+    SinkError(anyhow::Error),
 }
 
 impl StreamExecutorError {
@@ -71,9 +77,17 @@ impl StreamExecutorError {
         Self::TopNStateError(error.into()).into()
     }
 
+    pub fn over_agg_state_error(error: impl Into<RwError>) -> TracedStreamExecutorError {
+        Self::OverAggStateError(error.into()).into()
+    }
+
     pub fn channel_closed(name: impl Into<String>) -> TracedStreamExecutorError {
         Self::ChannelClosed(name.into()).into()
     }
+
+    pub fn sink_error(error: impl Into<anyhow::Error>) -> TracedStreamExecutorError {
+        Self::SinkError(error.into()).into()
+    }
 }
 
 #[derive(Error)]
@@ -0,0 +1,221 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{I64Array, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::error::{StreamExecutorError, TracedStreamExecutorError};
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+use crate::executor::PkIndices;
+
+/// [`ExpandExecutor`] emits every row once for each of the `column_subsets`, with the columns not
+/// in the subset set to NULL, followed by the original columns and the index of the subset as
+/// `flag`. The ops are kept, since all the copies of a row change together.
+pub struct ExpandExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+
+    column_subsets: Vec<Vec<usize>>,
+}
+
+impl ExpandExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        column_subsets: Vec<Vec<usize>>,
+        executor_id: u64,
+    ) -> Self {
+        let mut schema = input.schema().clone();
+        schema.fields.extend_from_slice(&input.schema().fields);
+        schema
+            .fields
+            .push(Field::with_name(DataType::Int64, "flag"));
+        Self {
+            input,
+            info: ExecutorInfo {
+                schema,
+                pk_indices,
+                identity: format!("ExpandExecutor {:X}", executor_id),
+            },
+            column_subsets,
+        }
+    }
+
+    fn expand(
+        columns: &[Column],
+        capacity: usize,
+        subset: &[usize],
+        flag: usize,
+    ) -> Result<Vec<Column>> {
+        let mut new_columns = Vec::with_capacity(columns.len() * 2 + 1);
+        for (i, column) in columns.iter().enumerate() {
+            if subset.contains(&i) {
+                new_columns.push(column.clone());
+            } else {
+                let mut builder = column.array_ref().create_builder(capacity)?;
+                for _ in 0..capacity {
+                    builder.append_null()?;
+                }
+                new_columns.push(Column::new(Arc::new(builder.finish()?)));
+            }
+        }
+        new_columns.extend_from_slice(columns);
+        let flags = I64Array::from_slice(&vec![Some(flag as i64); capacity])?;
+        new_columns.push(Column::new(Arc::new(flags.into())));
+        Ok(new_columns)
+    }
+
+    #[try_stream(ok = Message, error = TracedStreamExecutorError)]
+    async fn execute_inner(self) {
+        let Self {
+            input,
+            column_subsets,
+            ..
+        } = self;
+
+        #[for_await]
+        for msg in input.execute() {
+            let msg = msg?;
+            let chunk = match msg {
+                Message::Chunk(chunk) => chunk,
+                Message::Barrier(_) => {
+                    yield msg;
+                    continue;
+                }
+                // TODO: forward the watermarks of the columns in all subsets.
+                Message::Watermark(_) => continue,
+            };
+
+            let capacity = chunk.capacity();
+            let (ops, columns, visibility) = chunk.into_inner();
+            for (flag, subset) in column_subsets.iter().enumerate() {
+                let new_columns = Self::expand(&columns, capacity, subset, flag)
+                    .map_err(StreamExecutorError::eval_error)?;
+                yield Message::Chunk(StreamChunk::new(
+                    ops.clone(),
+                    new_columns,
+                    visibility.clone(),
+                ));
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for ExpandExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExpandExecutor")
+            .field("column_subsets", &self.column_subsets)
+            .finish()
+    }
+}
+
+impl Executor for ExpandExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::{Array, I32Array, Op};
+    use risingwave_common::column_nonnull;
+
+    use super::*;
+    use crate::executor_v2::test_utils::MockSource;
+
+    #[tokio::test]
+    async fn test_expand() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let chunk = StreamChunk::new(
+            vec![Op::Insert, Op::Delete],
+            vec![
+                column_nonnull! { I32Array, [1, 2] },
+                column_nonnull! { I32Array, [10, 20] },
+            ],
+            None,
+        );
+
+        let mut source = MockSource::new(schema, vec![0]);
+        source.push_chunks([chunk].into_iter());
+        source.push_barrier(1, false);
+
+        let expand = Box::new(ExpandExecutor::new(
+            Box::new(source),
+            vec![2, 4],
+            vec![vec![0], vec![]],
+            1,
+        ));
+        assert_eq!(expand.schema().len(), 5);
+        let mut expand = expand.execute();
+
+        for (flag, first) in [(0, [Some(1), Some(2)]), (1, [None, None])] {
+            if let Message::Chunk(chunk) = expand.next().await.unwrap().unwrap() {
+                assert_eq!(chunk.ops(), vec![Op::Insert, Op::Delete]);
+                let column = |i: usize| {
+                    chunk
+                        .column_at(i)
+                        .array_ref()
+                        .as_int32()
+                        .iter()
+                        .collect_vec()
+                };
+                assert_eq!(column(0), first.to_vec());
+                assert_eq!(column(1), vec![None, None]);
+                assert_eq!(column(2), vec![Some(1), Some(2)]);
+                assert_eq!(column(3), vec![Some(10), Some(20)]);
+                assert_eq!(
+                    chunk
+                        .column_at(4)
+                        .array_ref()
+                        .as_int64()
+                        .iter()
+                        .collect_vec(),
+                    vec![Some(flag); 2]
+                );
+            } else {
+                unreachable!();
+            }
+        }
+
+        assert!(matches!(
+            expand.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        ));
+    }
+}
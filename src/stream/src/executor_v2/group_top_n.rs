@@ -0,0 +1,416 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use risingwave_common::array::{Row, StreamChunk};
+use risingwave_common::catalog::Schema;
+use risingwave_common::collection::evictable::EvictableHashMap;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl, ToOwnedDatum};
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::{Keyspace, StateStore};
+
+use crate::executor_v2::error::{StreamExecutorError, StreamExecutorResult};
+use crate::executor_v2::top_n::InnerTopNExecutor;
+use crate::executor_v2::top_n_executor::{generate_output, TopNExecutorBase, TopNExecutorWrapper};
+use crate::executor_v2::{BoxedMessageStream, Executor, ExecutorInfo, PkIndices, PkIndicesRef};
+
+/// `GroupTopNExecutor` works like [`super::TopNExecutor`], but computes the top-n records within
+/// each group of the input, i.e. the rows sharing the same values on the group key columns.
+pub type GroupTopNExecutor<S> = TopNExecutorWrapper<InnerGroupTopNExecutor<S>>;
+
+impl<S: StateStore> GroupTopNExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input: Box<dyn Executor>,
+        pk_order_types: Vec<OrderType>,
+        offset_and_limit: (usize, Option<usize>),
+        pk_indices: PkIndices,
+        group_key_indices: Vec<usize>,
+        keyspace: Keyspace<S>,
+        cache_size: Option<usize>,
+        group_cache_size: usize,
+        executor_id: u64,
+        key_indices: Vec<usize>,
+    ) -> Result<Self> {
+        let info = input.info();
+        let schema = input.schema().clone();
+
+        Ok(TopNExecutorWrapper {
+            input,
+            inner: InnerGroupTopNExecutor::new(
+                info,
+                schema,
+                pk_order_types,
+                offset_and_limit,
+                pk_indices,
+                group_key_indices,
+                keyspace,
+                cache_size,
+                group_cache_size,
+                executor_id,
+                key_indices,
+            )?,
+        })
+    }
+}
+
+pub struct InnerGroupTopNExecutor<S: StateStore> {
+    info: ExecutorInfo,
+
+    /// Schema of the executor.
+    schema: Schema,
+
+    /// The ordering
+    pk_order_types: Vec<OrderType>,
+    /// `OFFSET XXX` and `LIMIT XXX` applied to each group.
+    offset_and_limit: (usize, Option<usize>),
+
+    /// The primary key indices of the `GroupTopNExecutor`
+    pk_indices: PkIndices,
+
+    /// The columns to group the input by.
+    group_key_indices: Vec<usize>,
+
+    /// The keyspace of the executor. The state of each group lives in a sub-keyspace prefixed
+    /// with the memcomparable encoding of its group key, along with the row counts of the group
+    /// which are needed to restore it after eviction.
+    keyspace: Keyspace<S>,
+
+    /// Number of rows to cache in each managed state of a group.
+    cache_size: Option<usize>,
+
+    /// The top-n states of the groups that are kept in memory. Groups exceeding the target
+    /// capacity are evicted after each flush.
+    groups: EvictableHashMap<Row, InnerTopNExecutor<S>>,
+
+    executor_id: u64,
+
+    /// Indices of the columns on which key distribution depends.
+    key_indices: Vec<usize>,
+}
+
+impl<S: StateStore> InnerGroupTopNExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_info: ExecutorInfo,
+        schema: Schema,
+        pk_order_types: Vec<OrderType>,
+        offset_and_limit: (usize, Option<usize>),
+        pk_indices: PkIndices,
+        group_key_indices: Vec<usize>,
+        keyspace: Keyspace<S>,
+        cache_size: Option<usize>,
+        group_cache_size: usize,
+        executor_id: u64,
+        key_indices: Vec<usize>,
+    ) -> Result<Self> {
+        Ok(Self {
+            info: ExecutorInfo {
+                schema: input_info.schema,
+                pk_indices: input_info.pk_indices,
+                identity: format!("GroupTopNExecutor {:X}", executor_id),
+            },
+            schema,
+            pk_order_types,
+            offset_and_limit,
+            pk_indices,
+            group_key_indices,
+            keyspace,
+            cache_size,
+            groups: EvictableHashMap::new(group_cache_size),
+            executor_id,
+            key_indices,
+        })
+    }
+
+    /// Returns the sub-keyspace holding the state of the given group.
+    fn group_keyspace(&self, group_key: &Row) -> StreamExecutorResult<Keyspace<S>> {
+        Ok(self.keyspace.append(
+            group_key
+                .serialize()
+                .map_err(StreamExecutorError::eval_error)?,
+        ))
+    }
+
+    /// Reads the row counts of a group persisted by `flush_data`. A group without counts
+    /// in the storage is empty.
+    async fn read_total_count(
+        keyspace: &Keyspace<S>,
+        epoch: u64,
+    ) -> StreamExecutorResult<(usize, usize, usize)> {
+        let raw_data = match keyspace.append_u8(b'c').value(epoch).await? {
+            Some(raw_data) => raw_data,
+            None => return Ok((0, 0, 0)),
+        };
+        let mut deserializer = value_encoding::Deserializer::new(raw_data);
+        let mut counts = [0; 3];
+        for count in &mut counts {
+            *count = deserialize_cell(&mut deserializer, &DataType::Int64)
+                .map_err(StreamExecutorError::top_n_state_error)?
+                .map(|datum| datum.into_int64() as usize)
+                .unwrap_or_default();
+        }
+        Ok((counts[0], counts[1], counts[2]))
+    }
+
+    /// Returns the top-n state of the given group, restoring it from the storage if it's not in
+    /// memory.
+    async fn get_group(
+        &mut self,
+        group_key: &Row,
+        epoch: u64,
+    ) -> StreamExecutorResult<&mut InnerTopNExecutor<S>> {
+        if !self.groups.contains(group_key) {
+            let keyspace = self.group_keyspace(group_key)?;
+            let total_count = Self::read_total_count(&keyspace, epoch).await?;
+            let state = InnerTopNExecutor::new(
+                ExecutorInfo {
+                    schema: self.info.schema.clone(),
+                    pk_indices: self.info.pk_indices.clone(),
+                    identity: self.info.identity.clone(),
+                },
+                self.schema.clone(),
+                self.pk_order_types.clone(),
+                self.offset_and_limit,
+                self.pk_indices.clone(),
+                keyspace,
+                self.cache_size,
+                total_count,
+                self.executor_id,
+                self.key_indices.clone(),
+            )
+            .map_err(StreamExecutorError::top_n_state_error)?;
+            self.groups.put(group_key.clone(), state);
+        }
+        let state = self.groups.get_mut(group_key).unwrap();
+        state.init_cache(epoch).await?;
+        Ok(state)
+    }
+}
+
+impl<S: StateStore> Executor for InnerGroupTopNExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        panic!("Should execute by wrapper");
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+
+    fn clear_cache(&mut self) -> Result<()> {
+        self.groups.clear();
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: StateStore> TopNExecutorBase for InnerGroupTopNExecutor<S> {
+    async fn apply_chunk(
+        &mut self,
+        chunk: StreamChunk,
+        epoch: u64,
+    ) -> StreamExecutorResult<StreamChunk> {
+        let mut new_ops = vec![];
+        let mut new_rows = vec![];
+
+        for row_ref in chunk.rows() {
+            let row = Row(row_ref
+                .values
+                .iter()
+                .map(|datum| datum.to_owned_datum())
+                .collect());
+            let group_key = Row(self
+                .group_key_indices
+                .iter()
+                .map(|idx| row[*idx].clone())
+                .collect());
+            self.get_group(&group_key, epoch)
+                .await?
+                .apply_row(row_ref.op(), row, epoch, &mut new_ops, &mut new_rows)
+                .await?;
+        }
+        generate_output(new_rows, new_ops, &self.schema)
+    }
+
+    async fn flush_data(&mut self, epoch: u64) -> StreamExecutorResult<()> {
+        let mut write_batch = self.keyspace.state_store().start_write_batch();
+        let mut dirty_groups = vec![];
+        for (group_key, state) in self.groups.iter_mut() {
+            if state.is_dirty() {
+                state.flush_inner(epoch).await?;
+                dirty_groups.push((group_key.clone(), state.total_count()));
+            }
+        }
+
+        // Persist the row counts of the changed groups, so that they can be restored after being
+        // evicted from memory.
+        for (group_key, (lowest, middle, highest)) in dirty_groups {
+            let keyspace = self.group_keyspace(&group_key)?.append_u8(b'c');
+            let mut local = write_batch.prefixify(&keyspace);
+            if (lowest, middle, highest) == (0, 0, 0) {
+                local.delete_single();
+            } else {
+                let mut value = vec![];
+                for count in [lowest, middle, highest] {
+                    value.extend(
+                        serialize_cell(&Some(ScalarImpl::Int64(count as i64)))
+                            .map_err(StreamExecutorError::top_n_state_error)?,
+                    );
+                }
+                local.put_single(StorageValue::new_default_put(value));
+            }
+        }
+        write_batch.ingest(epoch).await?;
+
+        self.groups.evict_to_target_cap();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use futures::StreamExt;
+    use itertools::Itertools;
+    use risingwave_common::array::{Array, I64Array, Op};
+    use risingwave_common::catalog::Field;
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::executor_v2::test_utils::{create_in_memory_keyspace, MockSource};
+    use crate::executor_v2::{Barrier, Message};
+
+    fn create_source() -> Box<MockSource> {
+        // Columns are (group, value, id), ordered by (value, id).
+        let chunk1 = StreamChunk::new(
+            vec![Op::Insert; 6],
+            vec![
+                column_nonnull! { I64Array, [1, 1, 2, 1, 2, 2] },
+                column_nonnull! { I64Array, [10, 5, 7, 8, 3, 9] },
+                column_nonnull! { I64Array, [0, 1, 2, 3, 4, 5] },
+            ],
+            None,
+        );
+        let chunk2 = StreamChunk::new(
+            vec![Op::Delete, Op::Insert],
+            vec![
+                column_nonnull! { I64Array, [2, 1] },
+                column_nonnull! { I64Array, [3, 1] },
+                column_nonnull! { I64Array, [4, 6] },
+            ],
+            None,
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        Box::new(MockSource::with_messages(
+            schema,
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        ))
+    }
+
+    fn assert_chunk(res: Message, expected_ops: Vec<Op>, expected_columns: Vec<Vec<i64>>) {
+        assert_matches!(res, Message::Chunk(_));
+        if let Message::Chunk(res) = res {
+            assert_eq!(res.ops(), expected_ops);
+            for (column, expected) in res.columns().iter().zip_eq(expected_columns) {
+                assert_eq!(
+                    column.array_ref().as_int64().iter().collect_vec(),
+                    expected.into_iter().map(Some).collect_vec()
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_group_top_n_executor() {
+        let keyspace = create_in_memory_keyspace();
+        // Keep at most one group in memory, so that the first group is evicted after the first
+        // barrier and restored from the storage afterwards.
+        let group_top_n_executor = Box::new(
+            GroupTopNExecutor::new(
+                create_source() as Box<dyn Executor>,
+                vec![OrderType::Ascending, OrderType::Ascending],
+                (0, Some(2)),
+                vec![1, 2],
+                vec![0],
+                keyspace,
+                Some(2),
+                1,
+                1,
+                vec![0],
+            )
+            .unwrap(),
+        );
+        let mut group_top_n_executor = group_top_n_executor.execute();
+
+        // consume the init barrier
+        group_top_n_executor.next().await.unwrap().unwrap();
+        assert_chunk(
+            group_top_n_executor.next().await.unwrap().unwrap(),
+            vec![
+                Op::Insert,
+                Op::Insert,
+                Op::Insert,
+                Op::Delete,
+                Op::Insert,
+                Op::Insert,
+            ],
+            vec![
+                vec![1, 1, 2, 1, 1, 2],
+                vec![10, 5, 7, 10, 8, 3],
+                vec![0, 1, 2, 0, 3, 4],
+            ],
+        );
+        // group 1: (5, 8), group 2: (3, 7)
+        assert_matches!(
+            group_top_n_executor.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        );
+        assert_chunk(
+            group_top_n_executor.next().await.unwrap().unwrap(),
+            vec![Op::Delete, Op::Insert, Op::Delete, Op::Insert],
+            vec![vec![2, 2, 1, 1], vec![3, 9, 8, 1], vec![4, 5, 3, 6]],
+        );
+        // group 1: (1, 5), group 2: (7, 9)
+        assert_matches!(
+            group_top_n_executor.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        );
+    }
+}
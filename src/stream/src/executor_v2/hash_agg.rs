@@ -25,11 +25,13 @@ use risingwave_common::catalog::Schema;
 use risingwave_common::collection::evictable::EvictableHashMap;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::hash::HashKey;
+use risingwave_common::types::ScalarImpl;
 use risingwave_storage::{Keyspace, StateStore};
 
 use super::{Executor, ExecutorInfo, StreamExecutorResult};
+use crate::executor::managed_state::watermark::delete_behind_watermark;
 use crate::executor::{
-    agg_input_arrays, pk_input_arrays, AggCall, AggState, PkDataTypes, PkIndicesRef,
+    agg_input_arrays, pk_input_arrays, AggCall, AggState, PkDataTypes, PkIndicesRef, Watermark,
 };
 use crate::executor_v2::agg::{
     generate_agg_schema, generate_agg_state, AggExecutor, AggExecutorWrapper,
@@ -97,6 +99,14 @@ pub struct AggHashAggExecutor<K: HashKey, S: StateStore> {
     /// Indices of the columns
     /// all of the aggregation functions in this executor should depend on same group of keys
     key_indices: Vec<usize>,
+
+    /// The watermarks received in this epoch, indexed by the position in the group key. The groups
+    /// behind them are cleaned after the next flush.
+    buffered_watermarks: Vec<Option<Watermark>>,
+
+    /// The latest watermark of each group key column. Input rows behind them are dropped, as their
+    /// groups may have been cleaned already.
+    current_watermarks: Vec<Option<ScalarImpl>>,
 }
 
 impl<K: HashKey, S: StateStore> AggHashAggExecutor<K, S> {
@@ -120,6 +130,8 @@ impl<K: HashKey, S: StateStore> AggHashAggExecutor<K, S> {
             keyspace,
             state_map: EvictableHashMap::new(1 << 16),
             agg_calls,
+            buffered_watermarks: vec![None; key_indices.len()],
+            current_watermarks: vec![None; key_indices.len()],
             key_indices,
         })
     }
@@ -173,6 +185,37 @@ impl<K: HashKey, S: StateStore> AggHashAggExecutor<K, S> {
         Ok(result)
     }
 
+    /// Hides the rows whose group key is behind the current watermarks in `visibility`.
+    fn hide_rows_behind_watermarks(
+        &self,
+        columns: &[Column],
+        visibility: Option<Bitmap>,
+    ) -> Result<Option<Bitmap>> {
+        if self.current_watermarks.iter().all(Option::is_none) {
+            return Ok(visibility);
+        }
+        let num_rows = columns[self.key_indices[0]].array_ref().len();
+        let mut vis_map = Vec::with_capacity(num_rows);
+        for row_idx in 0..num_rows {
+            let visible = match &visibility {
+                Some(vis_map) => vis_map.is_set(row_idx)?,
+                None => true,
+            };
+            let behind_watermark = self
+                .key_indices
+                .iter()
+                .zip_eq(&self.current_watermarks)
+                .any(|(&input_idx, watermark)| {
+                    matches!(
+                        (watermark, columns[input_idx].array_ref().datum_at(row_idx)),
+                        (Some(watermark), Some(v)) if &v < watermark
+                    )
+                });
+            vis_map.push(visible && !behind_watermark);
+        }
+        Ok(Some(vis_map.try_into()?))
+    }
+
     fn is_dirty(&self) -> bool {
         self.state_map
             .values()
@@ -215,6 +258,11 @@ impl<K: HashKey, S: StateStore> AggExecutor for AggHashAggExecutor<K, S> {
             K::build(&self.key_indices, &data_chunk).map_err(StreamExecutorError::eval_error)?;
         let (columns, visibility) = data_chunk.into_parts();
 
+        // --- Drop the rows behind the watermarks, whose groups may have been cleaned ---
+        let visibility = self
+            .hide_rows_behind_watermarks(&columns, visibility)
+            .map_err(StreamExecutorError::eval_error)?;
+
         // --- Find unique keys in this batch and generate visibility map for each key ---
         // TODO: this might be inefficient if there are not too many duplicated keys in one batch.
         let unique_keys = self
@@ -381,6 +429,73 @@ impl<K: HashKey, S: StateStore> AggExecutor for AggHashAggExecutor<K, S> {
         trace!("output_chunk: {:?}", &chunk);
         Ok(Some(chunk))
     }
+
+    fn apply_watermark(&mut self, watermark: Watermark) {
+        for (key_idx, &input_idx) in self.key_indices.iter().enumerate() {
+            if input_idx == watermark.col_idx {
+                let buffered = &mut self.buffered_watermarks[key_idx];
+                if !matches!(buffered, Some(w) if w.val >= watermark.val) {
+                    *buffered = Some(watermark.clone().with_idx(key_idx));
+                }
+                let current = &mut self.current_watermarks[key_idx];
+                if !matches!(current, Some(v) if v >= &watermark.val) {
+                    *current = Some(watermark.val.clone());
+                }
+            }
+        }
+    }
+
+    async fn clean_states(&mut self, epoch: u64) -> StreamExecutorResult<Vec<Watermark>> {
+        assert!(!self.is_dirty());
+        let key_data_types = &self.schema.data_types()[..self.key_indices.len()];
+        let mut watermarks = vec![];
+
+        for key_idx in 0..self.key_indices.len() {
+            let watermark = match self.buffered_watermarks[key_idx].take() {
+                Some(watermark) => watermark,
+                None => continue,
+            };
+
+            // --- Evict the expired groups from the cache ---
+            let mut expired_keys = vec![];
+            for (key, _) in self.state_map.iter() {
+                let key_row = key
+                    .clone()
+                    .deserialize(key_data_types.iter())
+                    .map_err(StreamExecutorError::eval_error)?;
+                if matches!(&key_row[key_idx], Some(v) if v < &watermark.val) {
+                    expired_keys.push(key.clone());
+                }
+            }
+            for key in expired_keys {
+                self.state_map.pop(&key);
+            }
+
+            // --- Delete the expired groups from the state store ---
+            // Every aggregation call stores its states under `agg_call_idx / [group_key]`.
+            let mut write_batch = self.keyspace.state_store().start_write_batch();
+            for agg_call_idx in 0..self.agg_calls.len() {
+                delete_behind_watermark(
+                    &self.keyspace.append_u16(agg_call_idx as u16),
+                    key_data_types,
+                    key_idx,
+                    &watermark.val,
+                    epoch,
+                    &mut write_batch,
+                )
+                .await
+                .map_err(StreamExecutorError::agg_state_error)?;
+            }
+            write_batch
+                .ingest(epoch)
+                .await
+                .map_err(StreamExecutorError::agg_state_error)?;
+
+            watermarks.push(watermark);
+        }
+
+        Ok(watermarks)
+    }
 }
 
 #[cfg(test)]
@@ -396,11 +511,11 @@ mod tests {
     use risingwave_common::column_nonnull;
     use risingwave_common::error::Result;
     use risingwave_common::hash::{calc_hash_key_kind, HashKey, HashKeyDispatcher};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::*;
     use risingwave_storage::{Keyspace, StateStore};
 
-    use crate::executor::{AggArgs, AggCall};
+    use crate::executor::{AggArgs, AggCall, Watermark};
     use crate::executor_v2::test_utils::*;
     use crate::executor_v2::{Executor, HashAggExecutor, Message, PkIndices};
     use crate::row_nonnull;
@@ -473,6 +588,11 @@ mod tests {
         test_local_hash_aggregation_max(create_in_memory_keyspace()).await
     }
 
+    #[tokio::test]
+    async fn test_hash_aggregation_clean_states_in_memory() {
+        test_hash_aggregation_clean_states(create_in_memory_keyspace()).await
+    }
+
     async fn test_local_hash_aggregation_count(keyspace: Keyspace<impl StateStore>) {
         let chunk1 = StreamChunk::new(
             vec![Op::Insert, Op::Insert, Op::Insert],
@@ -501,16 +621,19 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Count,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Count,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
@@ -610,17 +733,20 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             // This is local hash aggregation, so we add another sum state
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 2),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
@@ -734,11 +860,13 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Min,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
@@ -800,4 +928,93 @@ mod tests {
             unreachable!("unexpected message {:?}", msg);
         }
     }
+
+    async fn test_hash_aggregation_clean_states(keyspace: Keyspace<impl StateStore>) {
+        let chunk1 = StreamChunk::new(
+            vec![Op::Insert; 3],
+            vec![
+                // window start column
+                column_nonnull! { I64Array, [10, 10, 20] },
+                // id column
+                column_nonnull! { I64Array, [1, 1, 1] },
+            ],
+            None,
+        );
+        // The rows of window 10 arrive after the watermark, so they are dropped.
+        let chunk2 = StreamChunk::new(
+            vec![Op::Insert; 2],
+            vec![
+                column_nonnull! { I64Array, [10, 10] },
+                column_nonnull! { I64Array, [1, 1] },
+            ],
+            None,
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let mut source = MockSource::new(schema, PkIndices::new());
+        source.push_barrier(1, false);
+        source.push_chunks([chunk1].into_iter());
+        source.push_watermark(0, DataType::Int64, ScalarImpl::Int64(15));
+        source.push_barrier(2, false);
+        source.push_chunks([chunk2].into_iter());
+        source.push_barrier(3, false);
+
+        let keys = vec![1, 0];
+        let agg_calls = vec![AggCall {
+            kind: AggKind::RowCount,
+            args: AggArgs::None,
+            return_type: DataType::Int64,
+            distinct: false,
+        }];
+
+        let hash_agg =
+            new_boxed_hash_agg_executor(Box::new(source), agg_calls, keys, keyspace, vec![], 1);
+        let mut hash_agg = hash_agg.execute();
+
+        // Consume the init barrier
+        hash_agg.next().await.unwrap().unwrap();
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        let chunk = msg.into_chunk().unwrap();
+        let (data_chunk, ops) = chunk.into_parts();
+        let rows = ops
+            .into_iter()
+            .zip_eq(data_chunk.rows().map(Row::from))
+            .sorted()
+            .collect_vec();
+        let expected_rows = [
+            // id, window start, row count
+            (Op::Insert, row_nonnull![1i64, 10i64, 2i64]),
+            (Op::Insert, row_nonnull![1i64, 20i64, 1i64]),
+        ]
+        .into_iter()
+        .sorted()
+        .collect_vec();
+        assert_eq!(rows, expected_rows);
+
+        // The watermark is forwarded on the output column of the window start.
+        assert_eq!(
+            hash_agg
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_watermark()
+                .unwrap(),
+            Watermark::new(1, DataType::Int64, ScalarImpl::Int64(15))
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // No output for the late rows.
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+    }
 }
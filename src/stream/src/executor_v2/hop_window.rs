@@ -0,0 +1,232 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::{DataType, IntervalUnit};
+use risingwave_expr::vector_op::hop::{hop_window_bounds, hop_window_count};
+
+use super::error::{StreamExecutorError, TracedStreamExecutorError};
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+use crate::executor::PkIndices;
+
+/// [`HopWindowExecutor`] assigns every row to the hop windows it belongs to, i.e. each row is
+/// emitted `window_size / window_slide` times, with the `window_start` and `window_end` of a
+/// different window appended.
+///
+/// Since the old and the new values of an update may fall into different windows, updates are
+/// emitted as separate `Delete`s and `Insert`s.
+pub struct HopWindowExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+
+    time_col_idx: usize,
+    window_slide: IntervalUnit,
+    window_size: IntervalUnit,
+}
+
+impl HopWindowExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        time_col_idx: usize,
+        window_slide: IntervalUnit,
+        window_size: IntervalUnit,
+        executor_id: u64,
+    ) -> Self {
+        let mut schema = input.schema().clone();
+        schema.fields.extend([
+            Field::with_name(DataType::Timestamp, "window_start"),
+            Field::with_name(DataType::Timestamp, "window_end"),
+        ]);
+        Self {
+            input,
+            info: ExecutorInfo {
+                schema,
+                pk_indices,
+                identity: format!("HopWindowExecutor {:X}", executor_id),
+            },
+            time_col_idx,
+            window_slide,
+            window_size,
+        }
+    }
+
+    #[try_stream(ok = Message, error = TracedStreamExecutorError)]
+    async fn execute_inner(self) {
+        let Self {
+            input,
+            time_col_idx,
+            window_slide,
+            window_size,
+            ..
+        } = self;
+        let units =
+            hop_window_count(window_slide, window_size).map_err(StreamExecutorError::eval_error)?;
+
+        #[for_await]
+        for msg in input.execute() {
+            let msg = msg?;
+            let chunk = match msg {
+                Message::Chunk(chunk) => chunk,
+                // The input columns are kept at the same positions, so are their watermarks.
+                Message::Barrier(_) | Message::Watermark(_) => {
+                    yield msg;
+                    continue;
+                }
+            };
+
+            let (ops, columns, visibility) = chunk.into_inner();
+            let ops = ops
+                .into_iter()
+                .map(|op| match op {
+                    Op::Insert | Op::UpdateInsert => Op::Insert,
+                    Op::Delete | Op::UpdateDelete => Op::Delete,
+                })
+                .collect::<Vec<_>>();
+            let time_col = columns[time_col_idx].array_ref();
+
+            for i in 0..units {
+                let (window_start, window_end) =
+                    hop_window_bounds(time_col, window_slide, window_size, i)
+                        .map_err(StreamExecutorError::eval_error)?;
+                let mut new_columns = columns.clone();
+                new_columns.push(Column::new(Arc::new(window_start)));
+                new_columns.push(Column::new(Arc::new(window_end)));
+                yield Message::Chunk(StreamChunk::new(
+                    ops.clone(),
+                    new_columns,
+                    visibility.clone(),
+                ));
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for HopWindowExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HopWindowExecutor")
+            .field("time_col_idx", &self.time_col_idx)
+            .field("window_slide", &self.window_slide)
+            .field("window_size", &self.window_size)
+            .finish()
+    }
+}
+
+impl Executor for HopWindowExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use itertools::Itertools;
+    use risingwave_common::array::{Array, ArrayBuilder, I64Array, NaiveDateTimeArrayBuilder};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::NaiveDateTimeWrapper;
+
+    use super::*;
+    use crate::executor_v2::test_utils::MockSource;
+
+    fn timestamp(h: u32, m: u32) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper(NaiveDateTime::new(
+            NaiveDate::from_ymd(2022, 2, 22),
+            NaiveTime::from_hms(h, m, 0),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_hop_window() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Timestamp),
+            ],
+        };
+
+        let time_col = {
+            let mut builder = NaiveDateTimeArrayBuilder::new(2).unwrap();
+            builder.append(Some(timestamp(10, 5))).unwrap();
+            builder.append(Some(timestamp(10, 20))).unwrap();
+            Column::new(Arc::new(builder.finish().unwrap().into()))
+        };
+        let chunk = StreamChunk::new(
+            vec![Op::Insert, Op::UpdateInsert],
+            vec![column_nonnull! { I64Array, [1, 2] }, time_col],
+            None,
+        );
+
+        let mut source = MockSource::new(schema, vec![0]);
+        source.push_chunks([chunk].into_iter());
+        source.push_barrier(1, false);
+
+        let hop = Box::new(HopWindowExecutor::new(
+            Box::new(source),
+            vec![0, 2],
+            1,
+            IntervalUnit::from_millis(15 * 60 * 1000),
+            IntervalUnit::from_millis(30 * 60 * 1000),
+            1,
+        ));
+        assert_eq!(hop.schema().len(), 4);
+        let mut hop = hop.execute();
+
+        let mut window_starts = vec![];
+        for _ in 0..2 {
+            if let Message::Chunk(chunk) = hop.next().await.unwrap().unwrap() {
+                assert_eq!(chunk.ops(), vec![Op::Insert, Op::Insert]);
+                let array = chunk.column_at(2).array_ref().as_naivedatetime();
+                window_starts.extend(array.iter().collect_vec());
+            } else {
+                unreachable!();
+            }
+        }
+        assert_eq!(
+            window_starts,
+            [
+                timestamp(10, 0),
+                timestamp(10, 15),
+                timestamp(9, 45),
+                timestamp(10, 0)
+            ]
+            .into_iter()
+            .map(Some)
+            .collect_vec()
+        );
+
+        assert!(matches!(
+            hop.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        ));
+    }
+}
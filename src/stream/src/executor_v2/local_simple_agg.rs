@@ -204,6 +204,7 @@ mod tests {
             kind: AggKind::RowCount,
             args: AggArgs::None,
             return_type: DataType::Int64,
+            distinct: false,
         }];
 
         let simple_agg = Box::new(LocalSimpleAggExecutor::new(
@@ -267,16 +268,19 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
@@ -26,6 +26,7 @@ use crate::executor_v2::{Barrier, BoxedMessageStream, Executor, PkIndices, PkInd
 mod sides;
 use self::sides::*;
 mod impl_;
+pub use impl_::LookupExecutorParams;
 
 #[cfg(test)]
 mod tests;
@@ -75,7 +76,7 @@ impl<S: StateStore> Executor for LookupExecutor<S> {
     }
 
     fn identity(&self) -> &str {
-        "<unknown>"
+        "LookupExecutor"
     }
 
     fn clear_cache(&mut self) -> Result<()> {
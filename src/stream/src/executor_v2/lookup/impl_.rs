@@ -14,7 +14,7 @@
 
 use futures_async_stream::try_stream;
 use itertools::Itertools;
-use risingwave_common::array::{Row, RowRef, StreamChunk};
+use risingwave_common::array::{Op, Row, RowRef, StreamChunk};
 use risingwave_common::catalog::{ColumnDesc, Schema};
 use risingwave_common::error::Result;
 use risingwave_common::util::ordered::OrderedRowSerializer;
@@ -115,12 +115,12 @@ impl<S: StateStore> LookupExecutor<S> {
 
         let output_column_length = stream.schema().len() + arrangement.schema().len();
 
-        // output schema: | arrange | stream |
-        let schema_fields = arrangement
+        // output schema: | stream | arrange |
+        let schema_fields = stream
             .schema()
             .fields
             .iter()
-            .chain(stream.schema().fields.iter())
+            .chain(arrangement.schema().fields.iter())
             .cloned()
             .collect_vec();
 
@@ -265,8 +265,14 @@ impl<S: StateStore> LookupExecutor<S> {
         )?;
 
         for (op, row) in ops.iter().zip_eq(chunk.rows()) {
+            // An update of the stream row may change the matched rows, so the update pair is
+            // emitted as a plain delete and insert.
+            let op = match op {
+                Op::Insert | Op::UpdateInsert => Op::Insert,
+                Op::Delete | Op::UpdateDelete => Op::Delete,
+            };
             for matched_row in self.lookup_one_row(&row, lookup_epoch).await? {
-                builder.append_row(*op, &row, &matched_row)?;
+                builder.append_row(op, &row, &matched_row)?;
             }
             // TODO: support outer join (return null if no rows are matched)
         }
@@ -281,8 +287,8 @@ impl<S: StateStore> LookupExecutor<S> {
         // Serialize join key to a state store key.
         let key_prefix = {
             let row = RowRef(
-                self.arrangement
-                    .join_key_indices
+                self.stream
+                    .key_indices
                     .iter()
                     .map(|x| row.0[*x])
                     .collect_vec(),
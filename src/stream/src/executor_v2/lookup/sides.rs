@@ -125,6 +125,9 @@ pub async fn stream_lookup_arrange_prev_epoch(
                 yield ArrangeMessage::Arrange(std::mem::take(&mut arrange_updates));
                 yield ArrangeMessage::Barrier(barrier);
             }
+            // The lookup join keeps no state of the stream side to clean, and the arrangement is
+            // cleaned by its own upstream.
+            AlignedMessage::LeftWatermark(_) | AlignedMessage::RightWatermark(_) => {}
         }
     }
 }
@@ -166,6 +169,7 @@ pub async fn stream_lookup_arrange_this_epoch(
                 }
                 yield ArrangeMessage::Barrier(barrier);
             }
+            AlignedMessage::LeftWatermark(_) | AlignedMessage::RightWatermark(_) => {}
         }
     }
 }
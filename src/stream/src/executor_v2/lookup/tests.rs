@@ -227,7 +227,7 @@ async fn test_lookup_this_epoch() {
         arrangement_order_rules: arrangement_col_arrange_rules(),
         pk_indices: vec![1, 2],
         use_current_epoch: true,
-        stream_join_key_indices: vec![1],
+        stream_join_key_indices: vec![0],
         arrange_join_key_indices: vec![0],
     }));
     let mut lookup_executor = lookup_executor.execute();
@@ -299,7 +299,7 @@ async fn test_lookup_last_epoch() {
         arrangement_order_rules: arrangement_col_arrange_rules(),
         pk_indices: vec![1, 2],
         use_current_epoch: false,
-        stream_join_key_indices: vec![1],
+        stream_join_key_indices: vec![0],
         arrange_join_key_indices: vec![0],
     }));
     let mut lookup_executor = lookup_executor.execute();
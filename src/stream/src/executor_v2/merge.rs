@@ -12,24 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use futures::channel::mpsc::{Receiver, Sender};
 use futures::future::select_all;
-use futures::{SinkExt, StreamExt};
+use futures::{SinkExt, Stream, StreamExt};
 use futures_async_stream::{for_await, try_stream};
 use itertools::Itertools;
 use risingwave_common::catalog::Schema;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::task_service::GetStreamResponse;
 use risingwave_rpc_client::ComputeClient;
 use tonic::Streaming;
 use tracing_futures::Instrument;
 
 use super::{Executor, Message, PkIndicesRef};
-use crate::executor::PkIndices;
-use crate::executor_v2::error::TracedStreamExecutorError;
+use crate::executor::{Epoch, PkIndices};
+use crate::executor_v2::error::{StreamExecutorError, TracedStreamExecutorError};
+use crate::executor_v2::watermark::BufferedWatermarks;
 use crate::executor_v2::{BoxedMessageStream, ExecutorInfo};
-use crate::task::UpDownActorIds;
+use crate::task::{ActorId, SharedContext, UpDownActorIds};
 
 /// Receive data from `gRPC` and forwards to `MergerExecutor`/`ReceiverExecutor`
 pub struct RemoteInput {
@@ -78,8 +81,37 @@ impl RemoteInput {
     }
 }
 
+/// Take the channels from `upstream_ids` to `actor_id`, which the merge inputs of `actor_id` are
+/// switched to by the [`crate::executor::Mutation::Replace`] in the barrier of `epoch`.
+///
+/// The new upstreams have sent the same barrier as their first message. It's consumed here since
+/// the barrier has already been collected from the old upstreams.
+pub(crate) async fn take_replaced_upstreams(
+    context: &SharedContext,
+    actor_id: ActorId,
+    upstream_ids: &[ActorId],
+    epoch: Epoch,
+) -> Result<Vec<Receiver<Message>>> {
+    let mut upstreams = Vec::with_capacity(upstream_ids.len());
+    for &upstream_id in upstream_ids {
+        let mut upstream = context.take_receiver(&(upstream_id, actor_id))?;
+        match upstream.next().await {
+            Some(Message::Barrier(barrier)) if barrier.epoch == epoch => {}
+            message => {
+                return Err(ErrorCode::InternalError(format!(
+                    "expect barrier {:?} from new upstream {} of actor {}, got {:?}",
+                    epoch, upstream_id, actor_id, message
+                ))
+                .into())
+            }
+        }
+        upstreams.push(upstream);
+    }
+    Ok(upstreams)
+}
+
 /// `MergeExecutor` merges data from multiple channels. Dataflow from one channel
-/// will be stopped on barrier.
+/// will be stopped on barrier. The watermarks are merged as the minimum of all channels.
 pub struct MergeExecutor {
     /// Number of inputs.
     num_inputs: usize,
@@ -90,6 +122,9 @@ pub struct MergeExecutor {
     /// Belonged actor id.
     actor_id: u32,
 
+    /// Used to take the channels of new upstreams.
+    context: Arc<SharedContext>,
+
     info: ExecutorInfo,
 }
 
@@ -108,12 +143,14 @@ impl MergeExecutor {
         schema: Schema,
         pk_indices: PkIndices,
         actor_id: u32,
+        context: Arc<SharedContext>,
         inputs: Vec<Receiver<Message>>,
     ) -> Self {
         Self {
             num_inputs: inputs.len(),
             upstreams: inputs,
             actor_id,
+            context,
             info: ExecutorInfo {
                 schema,
                 pk_indices,
@@ -142,10 +179,20 @@ impl Executor for MergeExecutor {
     }
 }
 
+/// Tag the messages with the index of the upstream, to merge the watermarks.
+fn tag_upstreams(upstreams: Vec<Receiver<Message>>) -> Vec<impl Stream<Item = (usize, Message)>> {
+    upstreams
+        .into_iter()
+        .enumerate()
+        .map(|(idx, ch)| ch.map(move |msg| (idx, msg)))
+        .collect_vec()
+}
+
 impl MergeExecutor {
     #[try_stream(ok = Message, error = TracedStreamExecutorError)]
     async fn execute_inner(self) {
-        let mut upstreams = self.upstreams;
+        let mut upstreams = tag_upstreams(self.upstreams);
+        let mut buffered_watermarks = BufferedWatermarks::new(self.num_inputs);
 
         loop {
             // Futures of all active upstreams.
@@ -166,7 +213,7 @@ impl MergeExecutor {
                     .await;
 
                 // Panic on channel close.
-                let message = message.expect(
+                let (upstream_idx, message) = message.expect(
                     "upstream channel closed unexpectedly, please check error in upstream executors"
                 );
                 // Put back the remainings.
@@ -178,6 +225,14 @@ impl MergeExecutor {
                         active.push(from.into_future());
                         yield message;
                     }
+                    Message::Watermark(watermark) => {
+                        active.push(from.into_future());
+                        if let Some(watermark) =
+                            buffered_watermarks.handle_watermark(upstream_idx, watermark)
+                        {
+                            yield Message::Watermark(watermark);
+                        }
+                    }
                     Message::Barrier(barrier) => {
                         // Align the barrier.
                         if let Some(current_barrier) = current_barrier.as_ref() {
@@ -194,11 +249,21 @@ impl MergeExecutor {
             // 2. Yield the barrier to downstream once all barriers collected from upstream.
             let barrier = current_barrier.unwrap();
             let to_stop = barrier.is_to_stop_actor(self.actor_id);
+            let replaced = barrier
+                .replaced_upstreams(self.actor_id)
+                .map(|upstream_ids| (upstream_ids.to_vec(), barrier.epoch));
             yield Message::Barrier(barrier);
 
-            // 3. Put back the upstreams, or close the stream.
+            // 3. Put back the upstreams, switch to the new ones, or close the stream.
             if to_stop {
                 break;
+            } else if let Some((upstream_ids, epoch)) = replaced {
+                let new_upstreams =
+                    take_replaced_upstreams(&self.context, self.actor_id, &upstream_ids, epoch)
+                        .await
+                        .map_err(StreamExecutorError::input_error)?;
+                buffered_watermarks = BufferedWatermarks::new(new_upstreams.len());
+                upstreams = tag_upstreams(new_upstreams);
             } else {
                 upstreams = blocked;
             }
@@ -208,7 +273,7 @@ impl MergeExecutor {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
     use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::Arc;
     use std::thread::sleep;
@@ -251,7 +316,13 @@ mod tests {
             txs.push(tx);
             rxs.push(rx);
         }
-        let merger = MergeExecutor::new(Schema::default(), vec![], 0, rxs);
+        let merger = MergeExecutor::new(
+            Schema::default(),
+            vec![],
+            0,
+            Arc::new(SharedContext::for_test()),
+            rxs,
+        );
         let mut handles = Vec::with_capacity(CHANNEL_NUMBER);
 
         let epochs = (10..1000u64).step_by(10).collect_vec();
@@ -304,6 +375,44 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_merger_replace_upstreams() {
+        let actor_id = 233;
+        let context = Arc::new(SharedContext::for_test());
+        let (mut old_txs, old_rxs): (Vec<_>, Vec<_>) = (0..2).map(|_| channel(16)).unzip();
+        let mut new_txs = vec![];
+        for upstream_id in [1, 2, 3] {
+            let (tx, rx) = channel(16);
+            context.add_channel_pairs((upstream_id, actor_id), (None, Some(rx)));
+            new_txs.push(tx);
+        }
+        let merger = MergeExecutor::new(Schema::default(), vec![], actor_id, context, old_rxs);
+
+        let barrier = Barrier::new_test_barrier(1).with_mutation(Mutation::Replace {
+            stop: HashSet::default(),
+            add_outputs: HashMap::default(),
+            update_inputs: HashMap::from([(actor_id, vec![1, 2, 3])]),
+        });
+        for tx in &mut old_txs {
+            tx.send(Message::Barrier(barrier.clone())).await.unwrap();
+        }
+        // The new upstreams begin with the same barrier.
+        for tx in &mut new_txs {
+            tx.send(Message::Barrier(barrier.clone())).await.unwrap();
+            tx.send(Message::Chunk(build_test_chunk(2))).await.unwrap();
+        }
+
+        let mut merger = Box::new(merger).v1();
+        assert_matches!(merger.next().await.unwrap(), Message::Barrier(Barrier { epoch, .. }) => {
+            assert_eq!(epoch.curr, 1);
+        });
+        for _ in 0..new_txs.len() {
+            assert_matches!(merger.next().await.unwrap(), Message::Chunk(chunk) => {
+                assert_eq!(chunk.ops().len(), 2);
+            });
+        }
+    }
+
     struct FakeExchangeService {
         rpc_called: Arc<AtomicBool>,
     }
@@ -21,43 +21,58 @@ use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 
 pub use super::executor::{
-    Barrier, Executor as ExecutorV1, Message, Mutation, PkIndices, PkIndicesRef,
+    Barrier, Executor as ExecutorV1, Message, Mutation, PkIndices, PkIndicesRef, Watermark,
 };
 
 mod agg;
 mod barrier_align;
 mod batch_query;
 mod chain;
+mod expand;
 mod filter;
 mod global_simple_agg;
+mod group_top_n;
 mod hash_agg;
+mod hop_window;
 mod local_simple_agg;
 mod lookup;
 pub mod merge;
 pub(crate) mod mview;
+mod over_agg;
+mod project_set;
 #[allow(dead_code)]
 mod rearranged_chain;
 pub mod receiver;
 mod simple;
+mod sink;
 #[cfg(test)]
 mod test_utils;
 mod top_n;
 mod top_n_appendonly;
 mod top_n_executor;
+mod union;
 mod v1_compat;
+mod watermark;
 
 pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
+pub use expand::ExpandExecutor;
 pub use filter::FilterExecutor;
 pub use global_simple_agg::SimpleAggExecutor;
+pub use group_top_n::GroupTopNExecutor;
 pub use hash_agg::HashAggExecutor;
+pub use hop_window::HopWindowExecutor;
 pub use local_simple_agg::LocalSimpleAggExecutor;
 pub use lookup::*;
 pub use merge::MergeExecutor;
 pub use mview::*;
+pub use over_agg::OverAggExecutor;
+pub use project_set::ProjectSetExecutor;
 pub(crate) use simple::{SimpleExecutor, SimpleExecutorWrapper};
+pub use sink::SinkExecutor;
 pub use top_n::TopNExecutor;
 pub use top_n_appendonly::AppendOnlyTopNExecutor;
+pub use union::UnionExecutor;
 pub use v1_compat::StreamExecutorV1;
 
 pub type BoxedExecutor = Box<dyn Executor>;
@@ -121,6 +121,9 @@ impl<S: StateStore> MaterializeExecutor<S> {
                         .map_err(StreamExecutorError::ExecutorV1)?;
                     Message::Barrier(b)
                 }
+                // The materialized view keeps all its rows, and the watermark is forwarded to the
+                // chains on it.
+                Message::Watermark(w) => Message::Watermark(w),
             }
         }
     }
@@ -0,0 +1,499 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{Op, Row, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_expr::vector_op::window_function::{evaluate_partition, WindowFunction};
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::error::{StreamExecutorError, StreamExecutorResult, TracedStreamExecutorError};
+use super::top_n_executor::generate_output;
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+use crate::executor::managed_state::over_agg::{ManagedOverAggState, Partition};
+use crate::executor::PkIndices;
+
+/// The number of partitions to keep in the cache of [`OverAggExecutor`].
+const PARTITION_CACHE_CAPACITY: usize = 1 << 10;
+
+/// [`OverAggExecutor`] evaluates window functions on its input, and emits the input rows with one
+/// column appended for each window function.
+///
+/// A change of a row may change the results of all rows in its partition, e.g. the `rank` of all
+/// rows ordered after it. So for every partition touched by a chunk, the window functions are
+/// evaluated on the whole partition after applying the changes, and only the rows with results
+/// different from the last evaluation are emitted.
+pub struct OverAggExecutor<S: StateStore> {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+
+    /// Rows of the input grouped by partitions.
+    state: ManagedOverAggState<S>,
+    partition_by: Vec<usize>,
+    /// Indices of the order keys, which are followed by the pk in the state.
+    order_by: Vec<usize>,
+    window_functions: Vec<WindowFunction>,
+    input_types: Vec<DataType>,
+}
+
+impl<S: StateStore> OverAggExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        partition_by: Vec<usize>,
+        order_by: Vec<usize>,
+        order_types: Vec<OrderType>,
+        window_functions: Vec<WindowFunction>,
+        keyspace: Keyspace<S>,
+        executor_id: u64,
+    ) -> Self {
+        let input_types = input.schema().data_types();
+        let mut schema = input.schema().clone();
+        schema.fields.extend(
+            window_functions
+                .iter()
+                .map(|w| Field::unnamed(w.return_type())),
+        );
+
+        let sort_key_indices = order_by.iter().chain(&pk_indices).copied().collect();
+        let sort_key_order_types = order_types
+            .into_iter()
+            .chain(pk_indices.iter().map(|_| OrderType::Ascending))
+            .collect();
+        let state = ManagedOverAggState::new(
+            keyspace,
+            input_types.clone(),
+            sort_key_indices,
+            sort_key_order_types,
+            PARTITION_CACHE_CAPACITY,
+        );
+
+        Self {
+            input,
+            info: ExecutorInfo {
+                schema,
+                pk_indices,
+                identity: format!("OverAggExecutor {:X}", executor_id),
+            },
+            state,
+            partition_by,
+            order_by,
+            window_functions,
+            input_types,
+        }
+    }
+
+    /// Evaluates the window functions on the rows of a partition, and returns the output rows
+    /// keyed by the pk.
+    fn evaluate(
+        window_functions: &[WindowFunction],
+        input_types: &[DataType],
+        order_by: &[usize],
+        pk_indices: PkIndicesRef,
+        partition: &Partition,
+    ) -> StreamExecutorResult<Vec<(Row, Row)>> {
+        let rows = partition.rows.values().cloned().collect_vec();
+        let results = evaluate_partition(window_functions, &rows, input_types, order_by)
+            .map_err(StreamExecutorError::eval_error)?;
+        Ok(rows
+            .into_iter()
+            .zip_eq(results)
+            .map(|(Row(mut row), Row(result))| {
+                let pk = Row(pk_indices.iter().map(|i| row[*i].clone()).collect());
+                row.extend(result);
+                (pk, Row(row))
+            })
+            .collect())
+    }
+
+    async fn apply_chunk(
+        &mut self,
+        chunk: StreamChunk,
+        epoch: u64,
+    ) -> StreamExecutorResult<StreamChunk> {
+        // Group the changes by partitions, in the order of their first appearance.
+        let mut partitions: Vec<(Row, Vec<(Op, Row)>)> = vec![];
+        let mut partition_positions = HashMap::new();
+        for row_ref in chunk.rows() {
+            let row = Row(row_ref
+                .values
+                .iter()
+                .map(|datum| datum.to_owned_datum())
+                .collect());
+            let partition_key = Row(self
+                .partition_by
+                .iter()
+                .map(|i| row.0[*i].clone())
+                .collect());
+            let pos = *partition_positions
+                .entry(partition_key.clone())
+                .or_insert_with(|| {
+                    partitions.push((partition_key, vec![]));
+                    partitions.len() - 1
+                });
+            partitions[pos].1.push((row_ref.op(), row));
+        }
+
+        let mut new_ops = vec![];
+        let mut new_rows = vec![];
+        for (partition_key, changes) in partitions {
+            // Only a partition just loaded from the storage needs to be evaluated before applying
+            // the changes, otherwise the outputs of the last evaluation are reused.
+            let partition = self
+                .state
+                .partition(&partition_key, epoch)
+                .await
+                .map_err(StreamExecutorError::over_agg_state_error)?;
+            let old_outputs = match partition.outputs.take() {
+                Some(outputs) => outputs,
+                None => Self::evaluate(
+                    &self.window_functions,
+                    &self.input_types,
+                    &self.order_by,
+                    &self.info.pk_indices,
+                    partition,
+                )?,
+            };
+            for (op, row) in changes {
+                let res = match op {
+                    Op::Insert | Op::UpdateInsert => self.state.insert(&partition_key, row),
+                    Op::Delete | Op::UpdateDelete => self.state.delete(&partition_key, &row),
+                };
+                res.map_err(StreamExecutorError::over_agg_state_error)?;
+            }
+            let partition = self
+                .state
+                .partition(&partition_key, epoch)
+                .await
+                .map_err(StreamExecutorError::over_agg_state_error)?;
+            let new_outputs = Self::evaluate(
+                &self.window_functions,
+                &self.input_types,
+                &self.order_by,
+                &self.info.pk_indices,
+                partition,
+            )?;
+
+            let new_pks: HashSet<_> = new_outputs.iter().map(|(pk, _)| pk).collect();
+            let mut old_outputs: HashMap<_, _> = old_outputs
+                .into_iter()
+                .filter_map(|(pk, row)| {
+                    if new_pks.contains(&pk) {
+                        Some((pk, row))
+                    } else {
+                        // The row is deleted.
+                        new_ops.push(Op::Delete);
+                        new_rows.push(row);
+                        None
+                    }
+                })
+                .collect();
+            for (pk, row) in &new_outputs {
+                match old_outputs.remove(pk) {
+                    None => {
+                        new_ops.push(Op::Insert);
+                        new_rows.push(row.clone());
+                    }
+                    Some(old_row) if &old_row != row => {
+                        new_ops.push(Op::UpdateDelete);
+                        new_rows.push(old_row);
+                        new_ops.push(Op::UpdateInsert);
+                        new_rows.push(row.clone());
+                    }
+                    Some(_) => {}
+                }
+            }
+            partition.outputs = Some(new_outputs);
+        }
+
+        generate_output(new_rows, new_ops, &self.info.schema)
+    }
+
+    #[try_stream(ok = Message, error = TracedStreamExecutorError)]
+    async fn execute_inner(mut self) {
+        let mut input = self.input.execute();
+        let first_msg = input.next().await.unwrap()?;
+        let barrier = first_msg
+            .as_barrier()
+            .expect("the first message received by over agg executor must be a barrier");
+        let mut epoch = barrier.epoch.curr;
+        yield first_msg;
+
+        #[for_await]
+        for msg in input {
+            let msg = msg?;
+            match msg {
+                Message::Chunk(chunk) => {
+                    let chunk = self.apply_chunk(chunk, epoch).await?;
+                    if chunk.cardinality() > 0 {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                Message::Barrier(barrier) => {
+                    self.state
+                        .flush(epoch)
+                        .await
+                        .map_err(StreamExecutorError::over_agg_state_error)?;
+                    epoch = barrier.epoch.curr;
+                    yield Message::Barrier(barrier);
+                }
+                // The output columns of the input are at the same positions. No more rows will
+                // arrive in the partitions behind a watermark on a partition key, so their outputs
+                // are final. An input row may change the outputs of the rows before it in the
+                // partition, so the watermarks on other columns can't be forwarded.
+                Message::Watermark(watermark) => {
+                    if self.partition_by.contains(&watermark.col_idx) {
+                        yield Message::Watermark(watermark);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<S: StateStore> std::fmt::Debug for OverAggExecutor<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OverAggExecutor")
+            .field("partition_by", &self.partition_by)
+            .field("order_by", &self.order_by)
+            .field("window_functions", &self.window_functions)
+            .finish()
+    }
+}
+
+impl<S: StateStore> Executor for OverAggExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, I64Array};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::expr::window_frame::{Bound, BoundType, Type as FrameType};
+    use risingwave_pb::expr::window_function::Type;
+    use risingwave_pb::expr::{WindowFrame, WindowFunction as ProstWindowFunction};
+
+    use super::*;
+    use crate::executor::Watermark;
+    use crate::executor_v2::test_utils::{create_in_memory_keyspace, MockSource};
+
+    fn rank() -> WindowFunction {
+        WindowFunction::from_prost(&ProstWindowFunction {
+            r#type: Type::Rank as i32,
+            arg: None,
+            offset: 0,
+            agg_call: None,
+            frame: Some(WindowFrame {
+                r#type: FrameType::Range as i32,
+                start: Some(Bound {
+                    r#type: BoundType::UnboundedPreceding as i32,
+                    offset: 0,
+                }),
+                end: Some(Bound {
+                    r#type: BoundType::CurrentRow as i32,
+                    offset: 0,
+                }),
+            }),
+            return_type: Some(risingwave_pb::data::DataType {
+                type_name: TypeName::Int64 as i32,
+                ..Default::default()
+            }),
+        })
+        .unwrap()
+    }
+
+    async fn next_chunk(stream: &mut BoxedMessageStream) -> StreamChunk {
+        loop {
+            if let Message::Chunk(chunk) = stream.next().await.unwrap().unwrap() {
+                return chunk;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_over_agg() {
+        // (partition key, order key, pk)
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64); 3],
+        };
+        let chunk1 = StreamChunk::new(
+            vec![Op::Insert; 3],
+            vec![
+                column_nonnull! { I64Array, [1, 1, 2] },
+                column_nonnull! { I64Array, [20, 30, 10] },
+                column_nonnull! { I64Array, [1, 2, 3] },
+            ],
+            None,
+        );
+        // Inserting a row in front of the partition changes the ranks of the others.
+        let chunk2 = StreamChunk::new(
+            vec![Op::Insert],
+            vec![
+                column_nonnull! { I64Array, [1] },
+                column_nonnull! { I64Array, [10] },
+                column_nonnull! { I64Array, [4] },
+            ],
+            None,
+        );
+        // Deleting the last row of a partition does not change the others.
+        let chunk3 = StreamChunk::new(
+            vec![Op::Delete],
+            vec![
+                column_nonnull! { I64Array, [1] },
+                column_nonnull! { I64Array, [30] },
+                column_nonnull! { I64Array, [2] },
+            ],
+            None,
+        );
+
+        let mut source = MockSource::new(schema, vec![2]);
+        source.push_barrier(1, false);
+        source.push_chunks([chunk1, chunk2].into_iter());
+        source.push_barrier(2, false);
+        source.push_chunks([chunk3].into_iter());
+
+        let over_agg = Box::new(OverAggExecutor::new(
+            Box::new(source),
+            vec![2],
+            vec![0],
+            vec![1],
+            vec![OrderType::Ascending],
+            vec![rank()],
+            create_in_memory_keyspace(),
+            1,
+        ));
+        assert_eq!(over_agg.schema().len(), 4);
+        let mut over_agg = over_agg.execute();
+
+        let chunk = next_chunk(&mut over_agg).await;
+        assert_eq!(chunk.ops(), vec![Op::Insert; 3]);
+        assert_eq!(
+            chunk
+                .column_at(3)
+                .array_ref()
+                .as_int64()
+                .iter()
+                .collect_vec(),
+            vec![Some(1), Some(2), Some(1)]
+        );
+
+        let chunk = next_chunk(&mut over_agg).await;
+        assert_eq!(
+            chunk.ops(),
+            vec![
+                Op::Insert,
+                Op::UpdateDelete,
+                Op::UpdateInsert,
+                Op::UpdateDelete,
+                Op::UpdateInsert
+            ]
+        );
+        assert_eq!(
+            chunk
+                .column_at(2)
+                .array_ref()
+                .as_int64()
+                .iter()
+                .collect_vec(),
+            vec![Some(4), Some(1), Some(1), Some(2), Some(2)]
+        );
+        assert_eq!(
+            chunk
+                .column_at(3)
+                .array_ref()
+                .as_int64()
+                .iter()
+                .collect_vec(),
+            vec![Some(1), Some(1), Some(2), Some(2), Some(3)]
+        );
+
+        let chunk = next_chunk(&mut over_agg).await;
+        assert_eq!(chunk.ops(), vec![Op::Delete]);
+        assert_eq!(
+            chunk
+                .column_at(2)
+                .array_ref()
+                .as_int64()
+                .iter()
+                .collect_vec(),
+            vec![Some(2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_over_agg_forward_watermarks() {
+        // (partition key, order key, pk)
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64); 3],
+        };
+        let mut source = MockSource::new(schema, vec![2]);
+        source.push_barrier(1, false);
+        source.push_watermark(0, DataType::Int64, ScalarImpl::Int64(10));
+        source.push_watermark(1, DataType::Int64, ScalarImpl::Int64(20));
+        source.push_barrier(2, false);
+
+        let over_agg = Box::new(OverAggExecutor::new(
+            Box::new(source),
+            vec![2],
+            vec![0],
+            vec![1],
+            vec![OrderType::Ascending],
+            vec![rank()],
+            create_in_memory_keyspace(),
+            1,
+        ));
+        let mut over_agg = over_agg.execute();
+
+        // Consume the init barrier
+        over_agg.next().await.unwrap().unwrap();
+        // Only the watermark on the partition key is forwarded.
+        assert_eq!(
+            over_agg
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_watermark()
+                .unwrap(),
+            Watermark::new(0, DataType::Int64, ScalarImpl::Int64(10))
+        );
+        assert!(matches!(
+            over_agg.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        ));
+    }
+}
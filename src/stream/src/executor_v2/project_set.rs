@@ -0,0 +1,213 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::{eval_project_set, ProjectSetSelectItem};
+
+use super::error::{StreamExecutorError, TracedStreamExecutorError};
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+use crate::executor::PkIndices;
+
+/// [`ProjectSetExecutor`] evaluates a select list with set-returning functions on each row of the
+/// input. The row is emitted once for each value returned by the functions, with the index of the
+/// output row among those of the same input row as the first column `projected_row_id`. As an
+/// update may change the number of output rows, `UpdateDelete` and `UpdateInsert` are emitted as
+/// `Delete` and `Insert`.
+pub struct ProjectSetExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+
+    select_list: Vec<ProjectSetSelectItem>,
+}
+
+impl ProjectSetExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        select_list: Vec<ProjectSetSelectItem>,
+        executor_id: u64,
+    ) -> Self {
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+        Self {
+            input,
+            info: ExecutorInfo {
+                schema: Schema::new(fields),
+                pk_indices,
+                identity: format!("ProjectSetExecutor {:X}", executor_id),
+            },
+            select_list,
+        }
+    }
+
+    #[try_stream(ok = Message, error = TracedStreamExecutorError)]
+    async fn execute_inner(self) {
+        let Self {
+            input, select_list, ..
+        } = self;
+
+        #[for_await]
+        for msg in input.execute() {
+            let msg = msg?;
+            let chunk = match msg {
+                Message::Chunk(chunk) => chunk,
+                Message::Barrier(_) => {
+                    yield msg;
+                    continue;
+                }
+                Message::Watermark(_) => continue,
+            };
+
+            let chunk = chunk.compact().map_err(StreamExecutorError::eval_error)?;
+            let (data_chunk, ops) = chunk.into_parts();
+            let (output, input_row_indices) = eval_project_set(&select_list, &data_chunk)
+                .map_err(StreamExecutorError::eval_error)?;
+            if output.cardinality() == 0 {
+                continue;
+            }
+            let ops = input_row_indices
+                .into_iter()
+                .map(|row_idx| match ops[row_idx] {
+                    Op::Insert | Op::UpdateInsert => Op::Insert,
+                    Op::Delete | Op::UpdateDelete => Op::Delete,
+                })
+                .collect_vec();
+            yield Message::Chunk(StreamChunk::from_parts(ops, output));
+        }
+    }
+}
+
+impl std::fmt::Debug for ProjectSetExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProjectSetExecutor")
+            .field("select_list", &self.select_list)
+            .finish()
+    }
+}
+
+impl Executor for ProjectSetExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{I32Array, ListValue};
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_expr::table_function::TableFunction;
+    use risingwave_pb::plan::table_function_node::Type as TableFunctionType;
+
+    use super::*;
+    use crate::executor_v2::test_utils::MockSource;
+
+    #[tokio::test]
+    async fn test_project_set() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let mut builder = list_type.create_array_builder(2).unwrap();
+        for values in [vec![1, 2], vec![3]] {
+            let list = ListValue::new(values.into_iter().map(|v| Some(v.into())).collect());
+            builder.append_datum(&Some(list.into())).unwrap();
+        }
+        let chunk = StreamChunk::new(
+            vec![Op::UpdateDelete, Op::UpdateInsert],
+            vec![
+                column_nonnull! { I32Array, [1, 1] },
+                Column::new(Arc::new(builder.finish().unwrap())),
+            ],
+            None,
+        );
+
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(list_type.clone()),
+        ]);
+        let mut source = MockSource::new(schema, vec![0]);
+        source.push_chunks([chunk].into_iter());
+        source.push_barrier(1, false);
+
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(Box::new(InputRefExpression::new(DataType::Int32, 0))),
+            ProjectSetSelectItem::TableFunction(TableFunction::new(
+                TableFunctionType::Unnest,
+                vec![Box::new(InputRefExpression::new(list_type, 1))],
+                DataType::Int32,
+            )),
+        ];
+        let project_set = Box::new(ProjectSetExecutor::new(
+            Box::new(source),
+            vec![1, 0],
+            select_list,
+            1,
+        ));
+        assert_eq!(project_set.schema().len(), 3);
+        let mut project_set = project_set.execute();
+
+        if let Message::Chunk(chunk) = project_set.next().await.unwrap().unwrap() {
+            assert_eq!(chunk.ops(), vec![Op::Delete, Op::Delete, Op::Insert]);
+            let column = |i: usize| {
+                (0..chunk.cardinality())
+                    .map(|row| chunk.column_at(i).array_ref().datum_at(row))
+                    .collect_vec()
+            };
+            assert_eq!(
+                column(0),
+                vec![
+                    Some(ScalarImpl::Int64(0)),
+                    Some(ScalarImpl::Int64(1)),
+                    Some(ScalarImpl::Int64(0))
+                ]
+            );
+            assert_eq!(
+                column(2),
+                vec![Some(1.into()), Some(2.into()), Some(3.into())]
+            );
+        } else {
+            unreachable!();
+        }
+        assert!(matches!(
+            project_set.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        ));
+    }
+}
@@ -16,7 +16,7 @@ use std::sync::Arc;
 
 use futures::channel::{mpsc, oneshot};
 use futures::stream::select_with_strategy;
-use futures::{stream, Stream, StreamExt};
+use futures::{future, stream, Stream, StreamExt};
 use futures_async_stream::{for_await, try_stream};
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
@@ -48,7 +48,7 @@ pub struct RearrangedChainExecutor {
     info: ExecutorInfo,
 }
 
-fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
+fn mapping(upstream_indices: &[usize], msg: Message) -> Option<Message> {
     match msg {
         Message::Chunk(chunk) => {
             let (ops, columns, visibility) = chunk.into_inner();
@@ -56,9 +56,18 @@ fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
                 .iter()
                 .map(|&i| columns[i].clone())
                 .collect();
-            Message::Chunk(StreamChunk::new(ops, mapped_columns, visibility))
+            Some(Message::Chunk(StreamChunk::new(
+                ops,
+                mapped_columns,
+                visibility,
+            )))
         }
-        _ => msg,
+        // The watermark is dropped if its column is not chained.
+        Message::Watermark(watermark) => upstream_indices
+            .iter()
+            .position(|&i| i == watermark.col_idx)
+            .map(|idx| Message::Watermark(watermark.with_idx(idx))),
+        _ => Some(msg),
     }
 }
 
@@ -74,6 +83,7 @@ impl From<Message> for RearrangedMessage {
         match msg {
             Message::Chunk(chunk) => RearrangedMessage::Chunk(chunk),
             Message::Barrier(barrier) => RearrangedMessage::RearrangedBarrier(barrier),
+            Message::Watermark(_) => unreachable!("the snapshot contains no watermark"),
         }
     }
 }
@@ -101,10 +111,13 @@ impl RearrangedChainExecutor {
     async fn execute_inner(self) {
         // 0. Project the upstream with `upstream_indices`.
         let upstream_indices = self.upstream_indices.clone();
-        let mut upstream = self
-            .upstream
-            .execute()
-            .map(move |result| result.map(|msg| mapping(&upstream_indices, msg)));
+        let mut upstream = self.upstream.execute().filter_map(move |result| {
+            future::ready(
+                result
+                    .map(|msg| mapping(&upstream_indices, msg))
+                    .transpose(),
+            )
+        });
 
         // 1. Poll the upstream to get the first barrier.
         let first_msg = upstream.next().await.unwrap()?;
@@ -263,6 +276,10 @@ impl RearrangedChainExecutor {
                         .unbounded_send(RearrangedMessage::RearrangedBarrier(barrier))
                         .map_err(|_| StreamExecutorError::channel_closed("rearranged barrier"))?;
                 }
+
+                // The snapshot being rearranged may still contain rows behind the watermark, so it
+                // is not forwarded.
+                Message::Watermark(_) => {}
             };
 
             // Check that whether we should stop.
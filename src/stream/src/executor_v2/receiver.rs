@@ -1,16 +1,29 @@
+use std::sync::Arc;
+
 use futures::channel::mpsc::Receiver;
 use futures::StreamExt;
+use futures_async_stream::{for_await, try_stream};
 use risingwave_common::catalog::Schema;
 
+use crate::executor_v2::error::{StreamExecutorError, TracedStreamExecutorError};
+use crate::executor_v2::merge::take_replaced_upstreams;
 use crate::executor_v2::{
-    BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef,
+    BoxedMessageStream, Executor, ExecutorInfo, MergeExecutor, Message, PkIndices, PkIndicesRef,
 };
+use crate::task::{ActorId, SharedContext};
 
 /// `ReceiverExecutor` is used along with a channel. After creating a mpsc channel,
 /// there should be a `ReceiverExecutor` running in the background, so as to push
 /// messages down to the executors.
 pub struct ReceiverExecutor {
     receiver: Receiver<Message>,
+
+    /// Belonged actor id.
+    actor_id: ActorId,
+
+    /// Used to take the channels of new upstreams.
+    context: Arc<SharedContext>,
+
     /// Logical Operator Info
     info: ExecutorInfo,
 }
@@ -25,9 +38,17 @@ impl std::fmt::Debug for ReceiverExecutor {
 }
 
 impl ReceiverExecutor {
-    pub fn new(schema: Schema, pk_indices: PkIndices, receiver: Receiver<Message>) -> Self {
+    pub fn new(
+        schema: Schema,
+        pk_indices: PkIndices,
+        actor_id: ActorId,
+        context: Arc<SharedContext>,
+        receiver: Receiver<Message>,
+    ) -> Self {
         Self {
             receiver,
+            actor_id,
+            context,
             info: ExecutorInfo {
                 schema,
                 pk_indices,
@@ -35,11 +56,50 @@ impl ReceiverExecutor {
             },
         }
     }
+
+    #[try_stream(ok = Message, error = TracedStreamExecutorError)]
+    async fn execute_inner(self) {
+        let mut receiver = self.receiver;
+
+        while let Some(message) = receiver.next().await {
+            let replaced = match &message {
+                Message::Barrier(barrier) => barrier
+                    .replaced_upstreams(self.actor_id)
+                    .map(|upstream_ids| (upstream_ids.to_vec(), barrier.epoch)),
+                _ => None,
+            };
+            yield message;
+
+            if let Some((upstream_ids, epoch)) = replaced {
+                let mut upstreams =
+                    take_replaced_upstreams(&self.context, self.actor_id, &upstream_ids, epoch)
+                        .await
+                        .map_err(StreamExecutorError::input_error)?;
+                if upstreams.len() == 1 {
+                    receiver = upstreams.pop().unwrap();
+                } else {
+                    // Merge the new upstreams from now on.
+                    let merger = MergeExecutor::new(
+                        self.info.schema,
+                        self.info.pk_indices,
+                        self.actor_id,
+                        self.context,
+                        upstreams,
+                    );
+                    #[for_await]
+                    for message in Box::new(merger).execute() {
+                        yield message?;
+                    }
+                    break;
+                }
+            }
+        }
+    }
 }
 
 impl Executor for ReceiverExecutor {
     fn execute(self: Box<Self>) -> BoxedMessageStream {
-        self.receiver.map(Ok).boxed()
+        self.execute_inner().boxed()
     }
 
     fn schema(&self) -> &Schema {
@@ -0,0 +1,202 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::catalog::Schema;
+use risingwave_connector::sink::BoxedSink;
+
+use super::error::{StreamExecutorError, TracedStreamExecutorError};
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+use crate::executor::PkIndices;
+
+/// [`SinkExecutor`] delivers the changes of its input to an external system. Chunks are buffered
+/// until the next barrier, then written and committed with the epoch of the barrier, so that the
+/// external system only sees the changes of completed epochs. Messages are passed through.
+pub struct SinkExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+
+    sink: BoxedSink,
+}
+
+impl SinkExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        sink: BoxedSink,
+        executor_id: u64,
+    ) -> Self {
+        let schema = input.schema().clone();
+        Self {
+            input,
+            info: ExecutorInfo {
+                schema,
+                pk_indices,
+                identity: format!("SinkExecutor {:X}", executor_id),
+            },
+            sink,
+        }
+    }
+
+    #[try_stream(ok = Message, error = TracedStreamExecutorError)]
+    async fn execute_inner(self) {
+        let Self {
+            input, mut sink, ..
+        } = self;
+
+        let mut buffer = vec![];
+
+        #[for_await]
+        for msg in input.execute() {
+            let msg = msg?;
+            match &msg {
+                Message::Chunk(chunk) => buffer.push(chunk.clone()),
+                Message::Barrier(barrier) => {
+                    for chunk in buffer.drain(..) {
+                        sink.write_batch(chunk)
+                            .await
+                            .map_err(StreamExecutorError::sink_error)?;
+                    }
+                    sink.commit(barrier.epoch.prev)
+                        .await
+                        .map_err(StreamExecutorError::sink_error)?;
+                }
+                Message::Watermark(_) => {}
+            }
+            yield msg;
+        }
+    }
+}
+
+impl std::fmt::Debug for SinkExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SinkExecutor")
+            .field("pk_indices", &self.info.pk_indices)
+            .finish()
+    }
+}
+
+impl Executor for SinkExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use risingwave_common::array::{I32Array, Op, StreamChunk};
+    use risingwave_common::catalog::Field;
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::DataType;
+    use risingwave_connector::sink::Sink;
+
+    use super::*;
+    use crate::executor_v2::test_utils::MockSource;
+
+    /// Records the number of rows written and the committed epochs.
+    #[derive(Clone, Default)]
+    struct MockSink {
+        log: Arc<Mutex<Vec<(usize, u64)>>>,
+        pending: usize,
+    }
+
+    #[async_trait]
+    impl Sink for MockSink {
+        async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
+            self.pending += chunk.cardinality();
+            Ok(())
+        }
+
+        async fn commit(&mut self, epoch: u64) -> Result<()> {
+            self.log.lock().push((self.pending, epoch));
+            self.pending = 0;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sink() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int32)],
+        };
+        let chunk = || {
+            StreamChunk::new(
+                vec![Op::Insert, Op::Insert],
+                vec![column_nonnull! { I32Array, [1, 2] }],
+                None,
+            )
+        };
+
+        let mut source = MockSource::new(schema, vec![0]);
+        source.push_chunks([chunk(), chunk()].into_iter());
+        source.push_barrier(1, false);
+        source.push_chunks([chunk()].into_iter());
+        source.push_barrier(2, false);
+
+        let sink = MockSink::default();
+        let log = sink.log.clone();
+        let executor = Box::new(SinkExecutor::new(
+            Box::new(source),
+            vec![0],
+            Box::new(sink),
+            1,
+        ));
+        let mut stream = executor.execute();
+
+        // Chunks are passed through, but not written before the barrier.
+        assert!(matches!(
+            stream.next().await.unwrap().unwrap(),
+            Message::Chunk(_)
+        ));
+        assert!(matches!(
+            stream.next().await.unwrap().unwrap(),
+            Message::Chunk(_)
+        ));
+        assert!(log.lock().is_empty());
+
+        let epoch1 = match stream.next().await.unwrap().unwrap() {
+            Message::Barrier(barrier) => barrier.epoch.prev,
+            _ => panic!("expect barrier"),
+        };
+        assert_eq!(log.lock().clone(), vec![(4, epoch1)]);
+
+        assert!(matches!(
+            stream.next().await.unwrap().unwrap(),
+            Message::Chunk(_)
+        ));
+        let epoch2 = match stream.next().await.unwrap().unwrap() {
+            Message::Barrier(barrier) => barrier.epoch.prev,
+            _ => panic!("expect barrier"),
+        };
+        assert_eq!(log.lock().clone(), vec![(4, epoch1), (2, epoch2)]);
+    }
+}
@@ -17,11 +17,12 @@ use std::collections::{HashSet, VecDeque};
 use futures::StreamExt;
 use futures_async_stream::try_stream;
 use risingwave_common::catalog::Schema;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_storage::memory::MemoryStateStore;
 use risingwave_storage::Keyspace;
 
 use super::error::TracedStreamExecutorError;
-use super::{Barrier, Executor, Message, Mutation, PkIndices, StreamChunk};
+use super::{Barrier, Executor, Message, Mutation, PkIndices, StreamChunk, Watermark};
 
 pub struct MockSource {
     schema: Schema,
@@ -93,6 +94,12 @@ impl MockSource {
         }
         self.msgs.push_back(Message::Barrier(barrier));
     }
+
+    #[allow(dead_code)]
+    pub fn push_watermark(&mut self, col_idx: usize, data_type: DataType, val: ScalarImpl) {
+        self.msgs
+            .push_back(Message::Watermark(Watermark::new(col_idx, data_type, val)));
+    }
 }
 
 impl MockSource {
@@ -2,7 +2,6 @@ use async_trait::async_trait;
 use risingwave_common::array::{DataChunk, Op, Row, StreamChunk};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema};
 use risingwave_common::error::Result;
-use risingwave_common::types::ToOwnedDatum;
 use risingwave_common::util::ordered::{OrderedRow, OrderedRowDeserializer};
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_storage::cell_based_row_deserializer::CellBasedRowDeserializer;
@@ -162,7 +161,24 @@ impl<S: StateStore> InnerTopNExecutor<S> {
         })
     }
 
-    async fn flush_inner(&mut self, epoch: u64) -> StreamExecutorResult<()> {
+    /// Returns the number of rows in the `[0, offset)`, `[offset, offset+limit)` and
+    /// `[offset+limit, +inf)` ranges respectively.
+    pub(super) fn total_count(&self) -> (usize, usize, usize) {
+        (
+            self.managed_lowest_state.total_count(),
+            self.managed_middle_state.total_count(),
+            self.managed_highest_state.total_count(),
+        )
+    }
+
+    /// Returns whether any of the managed states has changes not yet flushed to the storage.
+    pub(super) fn is_dirty(&self) -> bool {
+        self.managed_lowest_state.is_dirty()
+            || self.managed_middle_state.is_dirty()
+            || self.managed_highest_state.is_dirty()
+    }
+
+    pub(super) async fn flush_inner(&mut self, epoch: u64) -> StreamExecutorResult<()> {
         self.managed_highest_state
             .flush(epoch)
             .await
@@ -176,6 +192,219 @@ impl<S: StateStore> InnerTopNExecutor<S> {
             .await
             .map_err(StreamExecutorError::top_n_state_error)
     }
+
+    /// Fills in the caches of the managed states from the storage if this is the first execution.
+    pub(super) async fn init_cache(&mut self, epoch: u64) -> StreamExecutorResult<()> {
+        if self.first_execution {
+            self.managed_lowest_state
+                .fill_in_cache(epoch)
+                .await
+                .map_err(StreamExecutorError::top_n_state_error)?;
+            self.managed_middle_state
+                .fill_in_cache(epoch)
+                .await
+                .map_err(StreamExecutorError::top_n_state_error)?;
+            self.managed_highest_state
+                .fill_in_cache(epoch)
+                .await
+                .map_err(StreamExecutorError::top_n_state_error)?;
+            self.first_execution = false;
+        }
+        Ok(())
+    }
+
+    /// Applies a single row to the managed states, and appends the resulting changes of the
+    /// top-n result to `new_ops` and `new_rows`.
+    pub(super) async fn apply_row(
+        &mut self,
+        op: Op,
+        row: Row,
+        epoch: u64,
+        new_ops: &mut Vec<Op>,
+        new_rows: &mut Vec<Row>,
+    ) -> StreamExecutorResult<()> {
+        let num_limit = self.limit.unwrap_or(usize::MAX);
+        let pk_row = Row(self
+            .pk_indices
+            .iter()
+            .map(|idx| row[*idx].clone())
+            .collect::<Vec<_>>());
+        let ordered_pk_row = OrderedRow::new(pk_row, &self.pk_order_types);
+        match op {
+            Op::Insert | Op::UpdateInsert => {
+                if self.managed_lowest_state.total_count() < self.offset {
+                    // `elem` is in the range of `[0, offset)`,
+                    // we ignored it for now as it is not in the result set.
+                    self.managed_lowest_state
+                        .insert(ordered_pk_row, row, epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?;
+                    return Ok(());
+                }
+
+                // We remark that when offset is 0, every input row has nothing to do with
+                // `managed_lower_state`.
+                let element_to_compare_with_middle = if self.offset > 0
+                    && &ordered_pk_row < self.managed_lowest_state.top_element().unwrap().0
+                {
+                    // If the new element is smaller than the largest element in [0, offset),
+                    // the largest element need to move to [offset, offset+limit).
+                    let res = self
+                        .managed_lowest_state
+                        .pop_top_element(epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?
+                        .unwrap();
+                    self.managed_lowest_state
+                        .insert(ordered_pk_row, row, epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?;
+                    res
+                } else {
+                    (ordered_pk_row, row)
+                };
+
+                if self.managed_middle_state.total_count() < num_limit {
+                    // `elem` is in the range of `[offset, offset+limit)`,
+                    self.managed_middle_state
+                        .insert(
+                            element_to_compare_with_middle.0,
+                            element_to_compare_with_middle.1.clone(),
+                        )
+                        .await;
+                    new_ops.push(Op::Insert);
+                    new_rows.push(element_to_compare_with_middle.1);
+                    return Ok(());
+                }
+
+                let element_to_compare_with_highest = if &element_to_compare_with_middle.0
+                    < self.managed_middle_state.top_element().unwrap().0
+                {
+                    let res = self
+                        .managed_middle_state
+                        .pop_top_element(epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?
+                        .unwrap();
+                    new_ops.push(Op::Delete);
+                    new_rows.push(res.1.clone());
+                    new_ops.push(Op::Insert);
+                    new_rows.push(element_to_compare_with_middle.1.clone());
+                    self.managed_middle_state
+                        .insert(
+                            element_to_compare_with_middle.0,
+                            element_to_compare_with_middle.1,
+                        )
+                        .await;
+                    res
+                } else {
+                    element_to_compare_with_middle
+                };
+
+                // `elem` is in the range of `[offset+limit, +inf)`.
+                self.managed_highest_state
+                    .insert(
+                        element_to_compare_with_highest.0,
+                        element_to_compare_with_highest.1,
+                        epoch,
+                    )
+                    .await
+                    .map_err(StreamExecutorError::top_n_state_error)?;
+            }
+            Op::Delete | Op::UpdateDelete => {
+                // The extra care we need to take for deletion is that when we delete an element
+                // from a managed state, we may need to move an element from
+                // a higher range to the current range. And this process may
+                // be recursive. Since this is a delete operator, the key
+                // must already exist in one of the three managed states. We
+                // first check whether the element is in the highest state.
+                if self.managed_middle_state.total_count() == num_limit
+                    && ordered_pk_row > *self.managed_middle_state.top_element().unwrap().0
+                {
+                    // The current element in in the range of `[offset+limit, +inf)`
+                    self.managed_highest_state
+                        .delete(&ordered_pk_row, epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?;
+                } else if self.managed_lowest_state.total_count() == self.offset
+                    && (self.offset == 0
+                        || ordered_pk_row > *self.managed_lowest_state.top_element().unwrap().0)
+                {
+                    // The current element in in the range of `[offset, offset+limit)`
+                    self.managed_middle_state
+                        .delete(&ordered_pk_row, epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?;
+                    new_ops.push(Op::Delete);
+                    new_rows.push(row.clone());
+                    // We need to bring one, if any, from highest to lowest.
+                    if self.managed_highest_state.total_count() > 0 {
+                        let smallest_element_from_highest_state = self
+                            .managed_highest_state
+                            .pop_top_element(epoch)
+                            .await
+                            .map_err(StreamExecutorError::top_n_state_error)?
+                            .unwrap();
+                        new_ops.push(Op::Insert);
+                        new_rows.push(smallest_element_from_highest_state.1.clone());
+                        self.managed_middle_state
+                            .insert(
+                                smallest_element_from_highest_state.0,
+                                smallest_element_from_highest_state.1,
+                            )
+                            .await;
+                    }
+                } else {
+                    // The current element in in the range of `[0, offset)`
+                    self.managed_lowest_state
+                        .delete(&ordered_pk_row, epoch)
+                        .await
+                        .map_err(StreamExecutorError::top_n_state_error)?;
+                    // We need to bring one, if any, from middle to lowest.
+                    if self.managed_middle_state.total_count() > 0 {
+                        let smallest_element_from_middle_state = self
+                            .managed_middle_state
+                            .pop_bottom_element(epoch)
+                            .await
+                            .map_err(StreamExecutorError::top_n_state_error)?
+                            .unwrap();
+                        new_ops.push(Op::Delete);
+                        new_rows.push(smallest_element_from_middle_state.1.clone());
+                        self.managed_lowest_state
+                            .insert(
+                                smallest_element_from_middle_state.0,
+                                smallest_element_from_middle_state.1,
+                                epoch,
+                            )
+                            .await
+                            .map_err(StreamExecutorError::top_n_state_error)?;
+                    }
+                    // We check whether we need to/can bring one from highest to middle.
+                    // We remark that if `self.limit` is Some, it cannot be 0 as this should be
+                    // optimized away in the frontend.
+                    if self.managed_middle_state.total_count() == (num_limit - 1)
+                        && self.managed_highest_state.total_count() > 0
+                    {
+                        let smallest_element_from_highest_state = self
+                            .managed_highest_state
+                            .pop_top_element(epoch)
+                            .await
+                            .map_err(StreamExecutorError::top_n_state_error)?
+                            .unwrap();
+                        new_ops.push(Op::Insert);
+                        new_rows.push(smallest_element_from_highest_state.1.clone());
+                        self.managed_middle_state
+                            .insert(
+                                smallest_element_from_highest_state.0,
+                                smallest_element_from_highest_state.1,
+                            )
+                            .await;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<S: StateStore> Executor for InnerTopNExecutor<S> {
@@ -212,216 +441,24 @@ impl<S: StateStore> TopNExecutorBase for InnerTopNExecutor<S> {
         chunk: StreamChunk,
         epoch: u64,
     ) -> StreamExecutorResult<StreamChunk> {
-        if self.first_execution {
-            self.managed_lowest_state
-                .fill_in_cache(epoch)
-                .await
-                .map_err(StreamExecutorError::top_n_state_error)?;
-            self.managed_middle_state
-                .fill_in_cache(epoch)
-                .await
-                .map_err(StreamExecutorError::top_n_state_error)?;
-            self.managed_highest_state
-                .fill_in_cache(epoch)
-                .await
-                .map_err(StreamExecutorError::top_n_state_error)?;
-            self.first_execution = false;
-        }
+        self.init_cache(epoch).await?;
 
         let chunk = chunk.compact().map_err(StreamExecutorError::eval_error)?;
 
         let (ops, columns, _visibility) = chunk.into_inner();
 
         let data_chunk = DataChunk::builder().columns(columns).build();
-        let num_limit = self.limit.unwrap_or(usize::MAX);
         let mut new_ops = vec![];
         let mut new_rows = vec![];
 
         for (row_idx, op) in ops.iter().enumerate().take(data_chunk.capacity()) {
-            let row_ref = data_chunk
+            let row = data_chunk
                 .row_at(row_idx)
                 .map_err(StreamExecutorError::eval_error)?
-                .0;
-            let pk_row = Row(self
-                .pk_indices
-                .iter()
-                .map(|idx| row_ref.0[*idx].to_owned_datum())
-                .collect::<Vec<_>>());
-            let ordered_pk_row = OrderedRow::new(pk_row, &self.pk_order_types);
-            let row = row_ref.into();
-            match *op {
-                Op::Insert | Op::UpdateInsert => {
-                    if self.managed_lowest_state.total_count() < self.offset {
-                        // `elem` is in the range of `[0, offset)`,
-                        // we ignored it for now as it is not in the result set.
-                        self.managed_lowest_state
-                            .insert(ordered_pk_row, row, epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?;
-                        continue;
-                    }
-
-                    // We remark that when offset is 0, every input row has nothing to do with
-                    // `managed_lower_state`.
-                    let element_to_compare_with_middle = if self.offset > 0
-                        && &ordered_pk_row < self.managed_lowest_state.top_element().unwrap().0
-                    {
-                        // If the new element is smaller than the largest element in [0, offset),
-                        // the largest element need to move to [offset, offset+limit).
-                        let res = self
-                            .managed_lowest_state
-                            .pop_top_element(epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?
-                            .unwrap();
-                        self.managed_lowest_state
-                            .insert(ordered_pk_row, row, epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?;
-                        res
-                    } else {
-                        (ordered_pk_row, row)
-                    };
-
-                    if self.managed_middle_state.total_count() < num_limit {
-                        // `elem` is in the range of `[offset, offset+limit)`,
-                        self.managed_middle_state
-                            .insert(
-                                element_to_compare_with_middle.0,
-                                element_to_compare_with_middle.1.clone(),
-                            )
-                            .await;
-                        new_ops.push(Op::Insert);
-                        new_rows.push(element_to_compare_with_middle.1);
-                        continue;
-                    }
-
-                    let element_to_compare_with_highest = if &element_to_compare_with_middle.0
-                        < self.managed_middle_state.top_element().unwrap().0
-                    {
-                        let res = self
-                            .managed_middle_state
-                            .pop_top_element(epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?
-                            .unwrap();
-                        new_ops.push(Op::Delete);
-                        new_rows.push(res.1.clone());
-                        new_ops.push(Op::Insert);
-                        new_rows.push(element_to_compare_with_middle.1.clone());
-                        self.managed_middle_state
-                            .insert(
-                                element_to_compare_with_middle.0,
-                                element_to_compare_with_middle.1,
-                            )
-                            .await;
-                        res
-                    } else {
-                        element_to_compare_with_middle
-                    };
-
-                    // `elem` is in the range of `[offset+limit, +inf)`.
-                    self.managed_highest_state
-                        .insert(
-                            element_to_compare_with_highest.0,
-                            element_to_compare_with_highest.1,
-                            epoch,
-                        )
-                        .await
-                        .map_err(StreamExecutorError::top_n_state_error)?;
-                }
-                Op::Delete | Op::UpdateDelete => {
-                    // The extra care we need to take for deletion is that when we delete an element
-                    // from a managed state, we may need to move an element from
-                    // a higher range to the current range. And this process may
-                    // be recursive. Since this is a delete operator, the key
-                    // must already exist in one of the three managed states. We
-                    // first check whether the element is in the highest state.
-                    if self.managed_middle_state.total_count() == num_limit
-                        && ordered_pk_row > *self.managed_middle_state.top_element().unwrap().0
-                    {
-                        // The current element in in the range of `[offset+limit, +inf)`
-                        self.managed_highest_state
-                            .delete(&ordered_pk_row, epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?;
-                    } else if self.managed_lowest_state.total_count() == self.offset
-                        && (self.offset == 0
-                            || ordered_pk_row > *self.managed_lowest_state.top_element().unwrap().0)
-                    {
-                        // The current element in in the range of `[offset, offset+limit)`
-                        self.managed_middle_state
-                            .delete(&ordered_pk_row, epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?;
-                        new_ops.push(Op::Delete);
-                        new_rows.push(row.clone());
-                        // We need to bring one, if any, from highest to lowest.
-                        if self.managed_highest_state.total_count() > 0 {
-                            let smallest_element_from_highest_state = self
-                                .managed_highest_state
-                                .pop_top_element(epoch)
-                                .await
-                                .map_err(StreamExecutorError::top_n_state_error)?
-                                .unwrap();
-                            new_ops.push(Op::Insert);
-                            new_rows.push(smallest_element_from_highest_state.1.clone());
-                            self.managed_middle_state
-                                .insert(
-                                    smallest_element_from_highest_state.0,
-                                    smallest_element_from_highest_state.1,
-                                )
-                                .await;
-                        }
-                    } else {
-                        // The current element in in the range of `[0, offset)`
-                        self.managed_lowest_state
-                            .delete(&ordered_pk_row, epoch)
-                            .await
-                            .map_err(StreamExecutorError::top_n_state_error)?;
-                        // We need to bring one, if any, from middle to lowest.
-                        if self.managed_middle_state.total_count() > 0 {
-                            let smallest_element_from_middle_state = self
-                                .managed_middle_state
-                                .pop_bottom_element(epoch)
-                                .await
-                                .map_err(StreamExecutorError::top_n_state_error)?
-                                .unwrap();
-                            new_ops.push(Op::Delete);
-                            new_rows.push(smallest_element_from_middle_state.1.clone());
-                            self.managed_lowest_state
-                                .insert(
-                                    smallest_element_from_middle_state.0,
-                                    smallest_element_from_middle_state.1,
-                                    epoch,
-                                )
-                                .await
-                                .map_err(StreamExecutorError::top_n_state_error)?;
-                        }
-                        // We check whether we need to/can bring one from highest to middle.
-                        // We remark that if `self.limit` is Some, it cannot be 0 as this should be
-                        // optimized away in the frontend.
-                        if self.managed_middle_state.total_count() == (num_limit - 1)
-                            && self.managed_highest_state.total_count() > 0
-                        {
-                            let smallest_element_from_highest_state = self
-                                .managed_highest_state
-                                .pop_top_element(epoch)
-                                .await
-                                .map_err(StreamExecutorError::top_n_state_error)?
-                                .unwrap();
-                            new_ops.push(Op::Insert);
-                            new_rows.push(smallest_element_from_highest_state.1.clone());
-                            self.managed_middle_state
-                                .insert(
-                                    smallest_element_from_highest_state.0,
-                                    smallest_element_from_highest_state.1,
-                                )
-                                .await;
-                        }
-                    }
-                }
-            }
+                .0
+                .into();
+            self.apply_row(*op, row, epoch, &mut new_ops, &mut new_rows)
+                .await?;
         }
         generate_output(new_rows, new_ops, &self.schema)
     }
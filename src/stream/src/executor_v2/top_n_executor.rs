@@ -101,6 +101,9 @@ where
                     epoch = barrier.epoch.curr;
                     yield Message::Barrier(barrier)
                 }
+                // A deletion may bring a row before the watermark back into the top n, so the
+                // watermark can't be forwarded.
+                Message::Watermark(_) => {}
             };
         }
     }
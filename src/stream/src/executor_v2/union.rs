@@ -0,0 +1,283 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::future::select_all;
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::catalog::Schema;
+
+use super::error::TracedStreamExecutorError;
+use super::watermark::BufferedWatermarks;
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+use crate::executor::PkIndices;
+
+/// `UnionExecutor` merges the messages from multiple inputs, with the barriers aligned.
+pub struct UnionExecutor {
+    inputs: Vec<BoxedExecutor>,
+    info: ExecutorInfo,
+    /// Whether to yield the chunks of each epoch input by input, in the order of `inputs`.
+    ordered: bool,
+}
+
+impl std::fmt::Debug for UnionExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnionExecutor")
+            .field("schema", &self.info.schema)
+            .field("pk_indices", &self.info.pk_indices)
+            .field("num_inputs", &self.inputs.len())
+            .field("ordered", &self.ordered)
+            .finish()
+    }
+}
+
+impl UnionExecutor {
+    pub fn new(pk_indices: PkIndices, inputs: Vec<BoxedExecutor>) -> Self {
+        assert!(!inputs.is_empty());
+        Self {
+            info: ExecutorInfo {
+                schema: inputs[0].schema().clone(),
+                pk_indices,
+                identity: "UnionExecutor".to_string(),
+            },
+            inputs,
+            ordered: false,
+        }
+    }
+
+    /// Creates a `UnionExecutor` which yields all the chunks of an input in an epoch before the
+    /// ones of the next input. The chunks of all inputs but the first are buffered until the
+    /// barrier of the epoch, as the inputs still need to be polled concurrently: they may be
+    /// dispatched by the same upstreams, which would otherwise be blocked.
+    pub fn new_ordered(pk_indices: PkIndices, inputs: Vec<BoxedExecutor>) -> Self {
+        Self {
+            ordered: true,
+            ..Self::new(pk_indices, inputs)
+        }
+    }
+}
+
+impl Executor for UnionExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        let streams = self.inputs.into_iter().map(|e| e.execute()).collect();
+        merge(streams, self.ordered).boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+/// Merges the messages of `inputs`. This is a generalization of
+/// [`BarrierAligner`](super::barrier_align::BarrierAligner) to any number of inputs: once a
+/// barrier is received from an input, the input is blocked until the same barrier is received from
+/// all other inputs, and then the barrier is yielded once. The watermarks are merged as the minimum
+/// of all inputs.
+///
+/// If `ordered` is set, the chunks of all inputs but the first are buffered, and yielded in the
+/// order of the inputs before the barrier.
+#[try_stream(ok = Message, error = TracedStreamExecutorError)]
+async fn merge(inputs: Vec<BoxedMessageStream>, ordered: bool) {
+    let mut buffered_watermarks = BufferedWatermarks::new(inputs.len());
+    let mut buffered_chunks = vec![vec![]; inputs.len()];
+    // Futures of all active inputs, with the messages tagged with the index of the input.
+    let mut active = inputs
+        .into_iter()
+        .enumerate()
+        .map(|(idx, s)| s.map(move |msg| msg.map(|msg| (idx, msg))).into_future())
+        .collect_vec();
+    // Inputs that're blocked by the barrier to align.
+    let mut blocked = Vec::with_capacity(active.len());
+    // The current barrier to align.
+    let mut current_barrier = None;
+
+    while !active.is_empty() {
+        // Poll inputs and get a message from the ready one.
+        let ((message, from), _id, remainings) = select_all(active).await;
+        active = remainings;
+
+        match message.transpose()? {
+            Some((input_idx, Message::Chunk(chunk))) => {
+                // We may still receive message from this input.
+                active.push(from.into_future());
+                if ordered && input_idx > 0 {
+                    buffered_chunks[input_idx].push(chunk);
+                } else {
+                    yield Message::Chunk(chunk);
+                }
+            }
+            Some((input_idx, Message::Watermark(watermark))) => {
+                active.push(from.into_future());
+                if let Some(watermark) = buffered_watermarks.handle_watermark(input_idx, watermark)
+                {
+                    yield Message::Watermark(watermark);
+                }
+            }
+            Some((_, Message::Barrier(barrier))) => {
+                // Align the barrier.
+                if let Some(current_barrier) = current_barrier.as_ref() {
+                    assert_eq!(&barrier, current_barrier);
+                } else {
+                    current_barrier = Some(barrier);
+                }
+                // We'll not receive message from this input during this epoch.
+                blocked.push(from);
+            }
+            // The input is finished and will never be polled again.
+            None => {}
+        }
+
+        if active.is_empty() {
+            // Yield the barrier to downstream once all barriers collected from inputs, and put
+            // back the inputs.
+            if let Some(barrier) = current_barrier.take() {
+                active = blocked.drain(..).map(|s| s.into_future()).collect();
+                let chunks = buffered_chunks
+                    .iter_mut()
+                    .flat_map(std::mem::take)
+                    .collect_vec();
+                for chunk in chunks {
+                    yield Message::Chunk(chunk);
+                }
+                yield Message::Barrier(barrier);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I64Array, Op, StreamChunk};
+    use risingwave_common::catalog::Field;
+    use risingwave_common::column_nonnull;
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use super::*;
+    use crate::executor_v2::test_utils::MockSource;
+
+    #[tokio::test]
+    async fn test_union() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+
+        let chunk = |op: Op, v: i64| {
+            StreamChunk::new(vec![op], vec![column_nonnull! { I64Array, [v] }], None)
+        };
+
+        let mut source_1 = MockSource::new(schema.clone(), vec![0]).stop_on_finish(false);
+        source_1.push_chunks([chunk(Op::Insert, 1)].into_iter());
+        source_1.push_barrier(1, false);
+        source_1.push_chunks([chunk(Op::Insert, 2)].into_iter());
+        source_1.push_barrier(2, false);
+
+        let mut source_2 = MockSource::new(schema, vec![0]).stop_on_finish(false);
+        source_2.push_barrier(1, false);
+        source_2.push_chunks([chunk(Op::Delete, 3)].into_iter());
+        source_2.push_barrier(2, false);
+
+        let union = Box::new(UnionExecutor::new(
+            vec![0],
+            vec![Box::new(source_1), Box::new(source_2)],
+        ));
+        let messages = union
+            .execute()
+            .map(|msg| msg.unwrap())
+            .collect::<Vec<_>>()
+            .await;
+
+        // The chunks of an epoch must be yielded between the barriers of the epoch.
+        let epochs = messages
+            .iter()
+            .filter_map(|msg| match msg {
+                Message::Barrier(barrier) => Some(barrier.epoch.curr),
+                _ => None,
+            })
+            .collect_vec();
+        assert_eq!(epochs, vec![1, 2]);
+
+        let chunks_before = |idx: usize| {
+            messages[..idx]
+                .iter()
+                .filter(|msg| matches!(msg, Message::Chunk(_)))
+                .count()
+        };
+        let barrier_positions = messages
+            .iter()
+            .positions(|msg| matches!(msg, Message::Barrier(_)))
+            .collect_vec();
+        assert_eq!(chunks_before(barrier_positions[0]), 1);
+        assert_eq!(chunks_before(barrier_positions[1]), 3);
+    }
+
+    #[tokio::test]
+    async fn test_ordered_union() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+
+        let chunk = |op: Op, v: i64| {
+            StreamChunk::new(vec![op], vec![column_nonnull! { I64Array, [v] }], None)
+        };
+
+        let mut source_1 = MockSource::new(schema.clone(), vec![0]).stop_on_finish(false);
+        source_1.push_chunks([chunk(Op::Delete, 1), chunk(Op::Insert, 2)].into_iter());
+        source_1.push_barrier(1, false);
+
+        let mut source_2 = MockSource::new(schema, vec![0]).stop_on_finish(false);
+        source_2.push_chunks([chunk(Op::Delete, 2), chunk(Op::Insert, 3)].into_iter());
+        source_2.push_barrier(1, false);
+
+        let union = Box::new(UnionExecutor::new_ordered(
+            vec![0],
+            vec![Box::new(source_1), Box::new(source_2)],
+        ));
+        let messages = union
+            .execute()
+            .map(|msg| msg.unwrap())
+            .collect::<Vec<_>>()
+            .await;
+
+        // The row inserted by the first input is deleted by the second one, so the changes of the
+        // second input must come later.
+        let changes = messages
+            .iter()
+            .map(|msg| match msg {
+                Message::Chunk(chunk) => {
+                    Some((chunk.ops()[0], chunk.column_at(0).array_ref().datum_at(0)))
+                }
+                _ => None,
+            })
+            .collect_vec();
+        let change = |op: Op, v: i64| Some((op, Some(ScalarImpl::Int64(v))));
+        assert_eq!(
+            changes,
+            vec![
+                change(Op::Delete, 1),
+                change(Op::Insert, 2),
+                change(Op::Delete, 2),
+                change(Op::Insert, 3),
+                None,
+            ]
+        );
+    }
+}
@@ -17,24 +17,31 @@ use std::fmt;
 use async_trait::async_trait;
 use futures::StreamExt;
 use futures_async_stream::try_stream;
-use risingwave_common::catalog::ColumnId;
 pub use risingwave_common::catalog::Schema;
+use risingwave_common::catalog::{ColumnDesc, ColumnId};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::hash::HashKey;
+use risingwave_common::types::IntervalUnit;
 use risingwave_common::util::sort_util::{OrderPair, OrderType};
+use risingwave_connector::sink::BoxedSink;
 use risingwave_expr::expr::BoxedExpression;
+use risingwave_expr::table_function::ProjectSetSelectItem;
+use risingwave_expr::vector_op::window_function::WindowFunction;
 use risingwave_storage::table::cell_based_table::CellBasedTable;
 use risingwave_storage::{Keyspace, StateStore};
 
 use super::error::{StreamExecutorError, TracedStreamExecutorError};
 use super::filter::SimpleFilterExecutor;
 use super::{
-    BatchQueryExecutor, BoxedExecutor, ChainExecutor, Executor, ExecutorInfo, FilterExecutor,
-    HashAggExecutor, LocalSimpleAggExecutor, MaterializeExecutor,
+    BatchQueryExecutor, BoxedExecutor, ChainExecutor, Executor, ExecutorInfo, ExpandExecutor,
+    FilterExecutor, HashAggExecutor, HopWindowExecutor, LocalSimpleAggExecutor, LookupExecutor,
+    LookupExecutorParams, MaterializeExecutor, OverAggExecutor, ProjectSetExecutor, SinkExecutor,
+    UnionExecutor,
 };
 pub use super::{BoxedMessageStream, ExecutorV1, Message, PkIndices, PkIndicesRef};
 use crate::executor::AggCall;
 use crate::executor_v2::global_simple_agg::SimpleAggExecutor;
+use crate::executor_v2::group_top_n::GroupTopNExecutor;
 use crate::executor_v2::top_n::TopNExecutor;
 use crate::executor_v2::top_n_appendonly::AppendOnlyTopNExecutor;
 use crate::task::FinishCreateMviewNotifier;
@@ -161,6 +168,7 @@ impl ChainExecutor {
         notifier: FinishCreateMviewNotifier,
         schema: Schema,
         column_idxs: Vec<usize>,
+        upstream_only: bool,
         _op_info: String,
     ) -> Self {
         let info = ExecutorInfo {
@@ -175,6 +183,7 @@ impl ChainExecutor {
             Box::new(ExecutorV1AsV2(snapshot)),
             Box::new(ExecutorV1AsV2(mview)),
             column_idxs,
+            upstream_only,
             notifier,
             actor_id,
             info,
@@ -307,6 +316,37 @@ impl<S: StateStore> TopNExecutor<S> {
     }
 }
 
+impl<S: StateStore> GroupTopNExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_v1(
+        input: Box<dyn ExecutorV1>,
+        pk_order_types: Vec<OrderType>,
+        offset_and_limit: (usize, Option<usize>),
+        pk_indices: PkIndices,
+        group_key_indices: Vec<usize>,
+        keyspace: Keyspace<S>,
+        cache_size: Option<usize>,
+        group_cache_size: usize,
+        executor_id: u64,
+        _op_info: String,
+        key_indices: Vec<usize>,
+    ) -> Result<Self> {
+        let input = Box::new(ExecutorV1AsV2(input));
+        Self::new(
+            input,
+            pk_order_types,
+            offset_and_limit,
+            pk_indices,
+            group_key_indices,
+            keyspace,
+            cache_size,
+            group_cache_size,
+            executor_id,
+            key_indices,
+        )
+    }
+}
+
 impl<S: StateStore> AppendOnlyTopNExecutor<S> {
     #[allow(clippy::too_many_arguments)]
     pub fn new_from_v1(
@@ -335,3 +375,137 @@ impl<S: StateStore> AppendOnlyTopNExecutor<S> {
         )
     }
 }
+
+impl ExpandExecutor {
+    pub fn new_from_v1(
+        input: Box<dyn ExecutorV1>,
+        pk_indices: PkIndices,
+        column_subsets: Vec<Vec<usize>>,
+        executor_id: u64,
+        _op_info: String,
+    ) -> Self {
+        let input = Box::new(ExecutorV1AsV2(input));
+        Self::new(input, pk_indices, column_subsets, executor_id)
+    }
+}
+
+impl ProjectSetExecutor {
+    pub fn new_from_v1(
+        input: Box<dyn ExecutorV1>,
+        pk_indices: PkIndices,
+        select_list: Vec<ProjectSetSelectItem>,
+        executor_id: u64,
+        _op_info: String,
+    ) -> Self {
+        let input = Box::new(ExecutorV1AsV2(input));
+        Self::new(input, pk_indices, select_list, executor_id)
+    }
+}
+
+impl SinkExecutor {
+    pub fn new_from_v1(
+        input: Box<dyn ExecutorV1>,
+        pk_indices: PkIndices,
+        sink: BoxedSink,
+        executor_id: u64,
+        _op_info: String,
+    ) -> Self {
+        let input = Box::new(ExecutorV1AsV2(input));
+        Self::new(input, pk_indices, sink, executor_id)
+    }
+}
+
+impl HopWindowExecutor {
+    pub fn new_from_v1(
+        input: Box<dyn ExecutorV1>,
+        pk_indices: PkIndices,
+        time_col_idx: usize,
+        window_slide: IntervalUnit,
+        window_size: IntervalUnit,
+        executor_id: u64,
+        _op_info: String,
+    ) -> Self {
+        let input = Box::new(ExecutorV1AsV2(input));
+        Self::new(
+            input,
+            pk_indices,
+            time_col_idx,
+            window_slide,
+            window_size,
+            executor_id,
+        )
+    }
+}
+
+impl<S: StateStore> OverAggExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_v1(
+        input: Box<dyn ExecutorV1>,
+        pk_indices: PkIndices,
+        partition_by: Vec<usize>,
+        order_by: Vec<usize>,
+        order_types: Vec<OrderType>,
+        window_functions: Vec<WindowFunction>,
+        keyspace: Keyspace<S>,
+        executor_id: u64,
+        _op_info: String,
+    ) -> Self {
+        let input = Box::new(ExecutorV1AsV2(input));
+        Self::new(
+            input,
+            pk_indices,
+            partition_by,
+            order_by,
+            order_types,
+            window_functions,
+            keyspace,
+            executor_id,
+        )
+    }
+}
+
+impl<S: StateStore> LookupExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_v1(
+        stream: Box<dyn ExecutorV1>,
+        arrangement: Box<dyn ExecutorV1>,
+        arrangement_keyspace: Keyspace<S>,
+        arrangement_col_descs: Vec<ColumnDesc>,
+        arrangement_order_rules: Vec<OrderPair>,
+        pk_indices: PkIndices,
+        use_current_epoch: bool,
+        stream_join_key_indices: Vec<usize>,
+        arrange_join_key_indices: Vec<usize>,
+    ) -> Self {
+        Self::new(LookupExecutorParams {
+            arrangement: Box::new(ExecutorV1AsV2(arrangement)),
+            stream: Box::new(ExecutorV1AsV2(stream)),
+            arrangement_keyspace,
+            arrangement_col_descs,
+            arrangement_order_rules,
+            pk_indices,
+            use_current_epoch,
+            stream_join_key_indices,
+            arrange_join_key_indices,
+        })
+    }
+}
+
+impl UnionExecutor {
+    pub fn new_from_v1(
+        inputs: Vec<Box<dyn ExecutorV1>>,
+        pk_indices: PkIndices,
+        _op_info: String,
+        ordered: bool,
+    ) -> Self {
+        let inputs = inputs
+            .into_iter()
+            .map(|input| Box::new(ExecutorV1AsV2(input)) as BoxedExecutor)
+            .collect();
+        if ordered {
+            Self::new_ordered(pk_indices, inputs)
+        } else {
+            Self::new(pk_indices, inputs)
+        }
+    }
+}
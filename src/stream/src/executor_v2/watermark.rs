@@ -0,0 +1,109 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use risingwave_common::types::ScalarImpl;
+
+use super::Watermark;
+
+/// Merges the watermarks from multiple inputs of an executor. The watermark of a column on the
+/// output is the minimum of the latest watermarks of all inputs on the column, so it is yielded
+/// only after all inputs have sent a watermark on the column.
+#[derive(Debug)]
+pub(super) struct BufferedWatermarks {
+    num_inputs: usize,
+
+    /// The latest watermarks of each input, by the column index.
+    latest: HashMap<usize, Vec<Option<ScalarImpl>>>,
+
+    /// The last yielded watermarks, by the column index.
+    yielded: HashMap<usize, ScalarImpl>,
+}
+
+impl BufferedWatermarks {
+    pub fn new(num_inputs: usize) -> Self {
+        Self {
+            num_inputs,
+            latest: HashMap::new(),
+            yielded: HashMap::new(),
+        }
+    }
+
+    /// Handles a watermark from the `input_idx`-th input, and returns the merged watermark to yield
+    /// if it advances.
+    pub fn handle_watermark(
+        &mut self,
+        input_idx: usize,
+        watermark: Watermark,
+    ) -> Option<Watermark> {
+        let num_inputs = self.num_inputs;
+        let latest = self
+            .latest
+            .entry(watermark.col_idx)
+            .or_insert_with(|| vec![None; num_inputs]);
+        if !matches!(&latest[input_idx], Some(v) if *v >= watermark.val) {
+            latest[input_idx] = Some(watermark.val.clone());
+        }
+
+        // `None` is less than any watermark, so there's no minimum until all inputs have one.
+        let min = latest.iter().min().cloned().flatten()?;
+        if matches!(self.yielded.get(&watermark.col_idx), Some(v) if *v >= min) {
+            return None;
+        }
+        self.yielded.insert(watermark.col_idx, min.clone());
+        Some(Watermark {
+            val: min,
+            ..watermark
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::DataType;
+
+    use super::*;
+
+    fn watermark(col_idx: usize, val: i64) -> Watermark {
+        Watermark::new(col_idx, DataType::Int64, ScalarImpl::Int64(val))
+    }
+
+    #[test]
+    fn test_buffered_watermarks() {
+        let mut buffered = BufferedWatermarks::new(2);
+
+        // Wait for the watermarks of all inputs.
+        assert_eq!(buffered.handle_watermark(0, watermark(0, 10)), None);
+        assert_eq!(
+            buffered.handle_watermark(1, watermark(0, 5)),
+            Some(watermark(0, 5))
+        );
+
+        // The minimum doesn't advance.
+        assert_eq!(buffered.handle_watermark(1, watermark(0, 3)), None);
+        assert_eq!(buffered.handle_watermark(0, watermark(0, 20)), None);
+        assert_eq!(
+            buffered.handle_watermark(1, watermark(0, 15)),
+            Some(watermark(0, 15))
+        );
+
+        // Columns are merged separately.
+        assert_eq!(buffered.handle_watermark(1, watermark(1, 1)), None);
+        assert_eq!(
+            buffered.handle_watermark(0, watermark(1, 2)),
+            Some(watermark(1, 1))
+        );
+    }
+}
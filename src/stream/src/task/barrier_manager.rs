@@ -185,7 +185,11 @@ impl LocalBarrierManager {
         }
 
         // Actors to stop should still accept this barrier, but won't get sent to in next times.
-        if let Some(Mutation::Stop(actors)) = barrier.mutation.as_deref() {
+        if let Some(actors) = barrier
+            .mutation
+            .as_deref()
+            .and_then(Mutation::actors_to_stop)
+        {
             trace!("remove actors {:?} from senders", actors);
             for actor in actors {
                 self.senders.remove(actor);
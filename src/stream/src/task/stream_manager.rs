@@ -326,6 +326,7 @@ pub fn build_agg_call_from_prost(agg_call_proto: &expr::AggCall) -> Result<AggCa
         kind: AggKind::try_from(agg_call_proto.get_type()?)?,
         args,
         return_type: DataType::from(agg_call_proto.get_return_type()?),
+        distinct: agg_call_proto.distinct,
     })
 }
 
@@ -587,6 +588,13 @@ impl LocalStreamManagerCore {
         };
         trace!("Join non-equi condition: {:?}", condition);
 
+        // The plans without the null-safety of keys compare all of them with `=`.
+        let null_safe = if node.get_null_safe().is_empty() {
+            vec![false; node.get_left_key().len()]
+        } else {
+            node.get_null_safe().clone()
+        };
+
         let key_indices = node
             .get_distribution_keys()
             .iter()
@@ -605,6 +613,7 @@ impl LocalStreamManagerCore {
                         Keyspace::shared_executor_root(store.clone(), params.operator_id),
                         params.executor_id,
                         condition,
+                        null_safe,
                         params.op_info,
                         key_indices,
                     )) as Box<dyn Executor>, )*
@@ -619,7 +628,9 @@ impl LocalStreamManagerCore {
                     { Inner, Inner },
                     { LeftOuter, LeftOuter },
                     { RightOuter, RightOuter },
-                    { FullOuter, FullOuter }
+                    { FullOuter, FullOuter },
+                    { LeftSemi, LeftSemi },
+                    { LeftAnti, LeftAnti }
                 }
             };
         }
@@ -644,6 +655,8 @@ impl LocalStreamManagerCore {
                 Box::new(ReceiverExecutor::new(
                     schema,
                     params.pk_indices,
+                    params.actor_id,
+                    self.context.clone(),
                     rxs.remove(0),
                 ))
                 .v1(),
@@ -654,6 +667,7 @@ impl LocalStreamManagerCore {
                     schema,
                     params.pk_indices,
                     params.actor_id,
+                    self.context.clone(),
                     rxs,
                 ))
                 .v1(),
@@ -676,32 +690,7 @@ impl LocalStreamManagerCore {
                 } else {
                     let upstream_addr = self.get_actor_info(up_id)?.get_host()?.into();
                     if !is_local_address(&upstream_addr, &self.context.addr) {
-                        // Get the sender for `RemoteInput` to forward received messages to
-                        // receivers in `ReceiverExecutor` or
-                        // `MergerExecutor`.
-                        let sender = self.context.take_sender(&(*up_id, actor_id))?;
-                        // spawn the `RemoteInput`
-                        let up_id = *up_id;
-
-                        let pool = self.compute_client_pool.clone();
-
-                        tokio::spawn(async move {
-                            let init_client = async move {
-                                let remote_input = RemoteInput::create(
-                                    pool.get_client_for_addr(upstream_addr).await?,
-                                    (up_id, actor_id),
-                                    sender,
-                                )
-                                .await?;
-                                Ok::<_, RwError>(remote_input)
-                            };
-                            match init_client.await {
-                                Ok(remote_input) => remote_input.run().await,
-                                Err(e) => {
-                                    error!("Spawn remote input fails:{}", e);
-                                }
-                            }
-                        });
+                        self.spawn_remote_input(upstream_addr, (*up_id, actor_id))?;
                     }
                     Ok::<_, RwError>(self.context.take_receiver(&(*up_id, actor_id))?)
                 }
@@ -720,6 +709,37 @@ impl LocalStreamManagerCore {
         Ok(rxs)
     }
 
+    /// Spawn a `RemoteInput` to forward the messages from the remote upstream actor to the receiver
+    /// in `ReceiverExecutor` or `MergerExecutor`.
+    fn spawn_remote_input(
+        &self,
+        upstream_addr: HostAddr,
+        up_down_ids: UpDownActorIds,
+    ) -> Result<()> {
+        let sender = self.context.take_sender(&up_down_ids)?;
+        let pool = self.compute_client_pool.clone();
+
+        tokio::spawn(async move {
+            let init_client = async move {
+                let remote_input = RemoteInput::create(
+                    pool.get_client_for_addr(upstream_addr).await?,
+                    up_down_ids,
+                    sender,
+                )
+                .await?;
+                Ok::<_, RwError>(remote_input)
+            };
+            match init_client.await {
+                Ok(remote_input) => remote_input.run().await,
+                Err(e) => {
+                    error!("Spawn remote input fails:{}", e);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
     fn build_actors(&mut self, actors: &[ActorId], env: StreamEnvironment) -> Result<()> {
         for actor_id in actors {
             let actor_id = *actor_id;
@@ -901,6 +921,14 @@ impl LocalStreamManagerCore {
                     let (tx, rx) = channel(LOCAL_OUTPUT_CHANNEL_SIZE);
                     self.context
                         .add_channel_pairs(up_down_ids, (Some(tx), Some(rx)));
+                    // A running downstream actor is going to switch to the remote upstream, which
+                    // is done by the `Replace` mutation. Connect to the upstream in advance.
+                    if let Some(upstream_addr) = &up.host {
+                        let upstream_addr: HostAddr = upstream_addr.into();
+                        if !is_local_address(&upstream_addr, &self.context.addr) {
+                            self.spawn_remote_input(upstream_addr, up_down_ids)?;
+                        }
+                    }
                 }
                 (
                     Some(ActorInfo {
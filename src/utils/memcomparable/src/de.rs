@@ -154,6 +154,15 @@ impl<B: Buf> Deserializer<B> {
         }
         Ok(byte_array)
     }
+
+    /// Read bytes prefixed with their length in `u32` (Do not include null tag). Used by value
+    /// encoding (`serialize_cell`).
+    pub fn read_bytes_v2(&mut self) -> Result<Vec<u8>> {
+        let len = self.input.get_u32() as usize;
+        let mut bytes = vec![0; len];
+        self.input.copy_to_slice(&mut bytes);
+        Ok(bytes)
+    }
 }
 
 // Format Reference:
@@ -609,6 +618,18 @@ impl<B: Buf> Deserializer<B> {
         let nsecs = self.input.get_u32();
         Ok((secs, nsecs))
     }
+
+    /// Deserialize a bytea value.
+    pub fn deserialize_bytea(&mut self) -> Result<Vec<u8>> {
+        self.read_bytes()
+    }
+
+    /// Deserialize a uuid value. Returns its 16 bytes in big endian.
+    pub fn deserialize_uuid(&mut self) -> Result<[u8; 16]> {
+        let mut bytes = [0u8; 16];
+        self.input.copy_to_slice(&mut bytes);
+        Ok(bytes)
+    }
 }
 
 #[cfg(test)]
@@ -857,4 +878,26 @@ mod tests {
         let mut deserializer = Deserializer::new(bytes);
         deserializer.deserialize_naivedatetime().unwrap()
     }
+
+    #[test]
+    fn test_bytea() {
+        let values: [&[u8]; 3] = [b"", b"\x00", b"\x01\x02\x03\x04\x05\x06\x07\x08\x09"];
+        for v in values {
+            let mut serializer = crate::Serializer::new(vec![]);
+            serializer.serialize_bytea(v).unwrap();
+            let bytes = serializer.into_inner();
+            let mut deserializer = Deserializer::new(bytes.as_slice());
+            assert_eq!(deserializer.deserialize_bytea().unwrap(), v);
+        }
+    }
+
+    #[test]
+    fn test_uuid() {
+        let uuid = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321u128.to_be_bytes();
+        let mut serializer = crate::Serializer::new(vec![]);
+        serializer.serialize_uuid(uuid).unwrap();
+        let bytes = serializer.into_inner();
+        let mut deserializer = Deserializer::new(bytes.as_slice());
+        assert_eq!(deserializer.deserialize_uuid().unwrap(), uuid);
+    }
 }
@@ -583,6 +583,17 @@ impl<B: BufMut> Serializer<B> {
         self.output.put_u32(nsecs);
         Ok(())
     }
+
+    /// Serialize a bytea value. The encoding is the same as `serialize_bytes`.
+    pub fn serialize_bytea(&mut self, v: &[u8]) -> Result<()> {
+        ser::Serializer::serialize_bytes(self, v)
+    }
+
+    /// Serialize a uuid value as its 16 bytes in big endian.
+    pub fn serialize_uuid(&mut self, bytes: [u8; 16]) -> Result<()> {
+        self.output.put_slice(&bytes);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -876,6 +887,34 @@ mod tests {
         serializer.into_inner()
     }
 
+    #[test]
+    fn test_bytea() {
+        let a = serialize_bytea(b"\x01\x02\xff");
+        let b = serialize_bytea(b"\x01\x02");
+        let c = serialize_bytea(b"");
+        assert!(a > b && b > c);
+    }
+
+    fn serialize_bytea(v: &[u8]) -> Vec<u8> {
+        let mut serializer = Serializer::new(vec![]);
+        serializer.serialize_bytea(v).unwrap();
+        serializer.into_inner()
+    }
+
+    #[test]
+    fn test_uuid() {
+        let a = serialize_uuid(u128::MAX.to_be_bytes());
+        let b = serialize_uuid(1u128.to_be_bytes());
+        let c = serialize_uuid(0u128.to_be_bytes());
+        assert!(a > b && b > c);
+    }
+
+    fn serialize_uuid(bytes: [u8; 16]) -> Vec<u8> {
+        let mut serializer = Serializer::new(vec![]);
+        serializer.serialize_uuid(bytes).unwrap();
+        serializer.into_inner()
+    }
+
     #[test]
     fn test_reverse_order() {
         // Order: (ASC, DESC)
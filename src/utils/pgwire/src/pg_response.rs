@@ -31,10 +31,14 @@ pub enum StatementType {
     EXPLAIN,
     CREATE_TABLE,
     CREATE_MATERIALIZED_VIEW,
+    CREATE_VIEW,
+    CREATE_SINK,
     CREATE_SOURCE,
     DESCRIBE_TABLE,
     DROP_TABLE,
     DROP_MATERIALIZED_VIEW,
+    DROP_VIEW,
+    DROP_SINK,
     DROP_STREAM,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
     // that Statement Type is not designed to be one to one mapping with SqlKind.
@@ -48,4 +48,10 @@ impl<B: Buf> Deserializer<B> {
     pub fn read_decimal_v2(&mut self) -> Result<Vec<u8>> {
         self.inner.read_decimal_v2()
     }
+
+    /// Read bytes prefixed with their length (Do not include null tag). Used by value encoding
+    /// (`serialize_cell` in risingwave_common crate).
+    pub fn read_bytes_v2(&mut self) -> Result<Vec<u8>> {
+        self.inner.read_bytes_v2()
+    }
 }